@@ -4,7 +4,9 @@ use anyhow::Context as _;
 use clap::Parser;
 use tokio::sync::watch;
 use zksync_config::configs::{ContractVerifierSecrets, DatabaseSecrets, PrometheusConfig};
-use zksync_contract_verifier_lib::{etherscan::EtherscanVerifier, ContractVerifier};
+use zksync_contract_verifier_lib::{
+    etherscan::EtherscanVerifier, similar_match::SimilarMatchPropagator, ContractVerifier,
+};
 use zksync_core_leftovers::temp_config_store::{load_general_config, read_yaml_repr};
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_env_config::FromEnv;
@@ -120,6 +122,7 @@ async fn main() -> anyhow::Result<()> {
     let mut tasks = vec![
         tokio::spawn(update_task),
         tokio::spawn(contract_verifier.run(stop_receiver.clone(), opt.jobs_number)),
+        tokio::spawn(SimilarMatchPropagator::new(pool.clone()).run(stop_receiver.clone())),
         tokio::spawn(
             PrometheusExporterConfig::pull(prometheus_config.listener_port)
                 .run(stop_receiver.clone()),
@@ -26,6 +26,7 @@ use zksync_config::{
 use zksync_consensus_crypto::TextFmt;
 use zksync_consensus_roles as roles;
 use zksync_core_leftovers::temp_config_store::read_yaml_repr;
+use zksync_dal::pruning_dal::PruningRetentionRules;
 #[cfg(test)]
 use zksync_dal::{ConnectionPool, Core};
 use zksync_env_config::da_client::{da_client_config_from_env, da_client_secrets_from_env};
@@ -38,7 +39,7 @@ use zksync_protobuf_config::proto;
 use zksync_snapshots_applier::SnapshotsApplierConfig;
 use zksync_types::{
     commitment::L1BatchCommitmentMode, url::SensitiveUrl, Address, L1BatchNumber, L1ChainId,
-    L2ChainId, SLChainId, ETHEREUM_ADDRESS,
+    L2ChainId, SLChainId, ETHEREUM_ADDRESS, H256,
 };
 use zksync_web3_decl::{
     client::{DynClient, L2},
@@ -459,6 +460,21 @@ pub(crate) struct OptionalENConfig {
     /// Number of requests per second allocated for the main node HTTP client. Default is 100 requests.
     #[serde(default = "OptionalENConfig::default_main_node_rate_limit_rps")]
     pub main_node_rate_limit_rps: NonZeroUsize,
+    /// URLs of fallback upstreams (e.g., other trusted external nodes) used instead of the main node if it's
+    /// unavailable or lagging. Fallback upstreams must serve the `en` namespace and are checked to have the same
+    /// L2 chain ID and genesis as this node; thus, they are not used if this node was recovered from a snapshot.
+    /// The rate limit for the main node client applies to each upstream.
+    #[serde(default)]
+    pub fallback_upstream_urls: Vec<SensitiveUrl>,
+    /// Maximum number of L2 blocks an upstream can lag behind the most advanced upstream to still be preferred.
+    /// Only has an effect if fallback upstreams are configured.
+    #[serde(default = "OptionalENConfig::default_upstream_max_lag_blocks")]
+    pub upstream_max_lag_blocks: u32,
+    /// Enables independent verification of L1 batch statuses (proven / executed) reported by the main node
+    /// on the settlement layer. Verified batch stages are exposed in `zks_getL1BatchDetails`; a mismatch
+    /// with the main node trips a circuit breaker.
+    #[serde(default)]
+    pub verify_batch_statuses: bool,
     /// Enables application-level snapshot recovery. Required to start a node that was recovered from a snapshot,
     /// or to initialize a node from a snapshot. Has no effect if a node that was initialized from a Postgres dump
     /// or was synced from genesis.
@@ -493,6 +509,15 @@ pub(crate) struct OptionalENConfig {
     /// If set to 0, L1 batches will not be retained based on their timestamp. The default value is 7 days.
     #[serde(default = "OptionalENConfig::default_pruning_data_retention_sec")]
     pruning_data_retention_sec: u64,
+    /// Addresses of accounts / contracts whose transactions and events are retained regardless of other pruning criteria.
+    /// A transaction is retained if it's initiated by or sent to one of these addresses, or if it emits an event
+    /// from one of these addresses.
+    #[serde(default)]
+    pub pruning_retained_addresses: Vec<Address>,
+    /// Event topics (matched against the first topic, i.e. the event signature) retained regardless of other
+    /// pruning criteria. Transactions emitting such events are retained as well.
+    #[serde(default)]
+    pub pruning_retained_event_topics: Vec<H256>,
     /// Gateway RPC URL, needed for operating during migration.
     pub gateway_url: Option<SensitiveUrl>,
     /// Interval for bridge addresses refreshing in seconds.
@@ -711,6 +736,16 @@ impl OptionalENConfig {
                 data_retention_sec,
                 default_pruning_data_retention_sec
             ),
+            pruning_retained_addresses: general_config
+                .pruning
+                .as_ref()
+                .map(|a| a.retained_addresses.clone())
+                .unwrap_or_default(),
+            pruning_retained_event_topics: general_config
+                .pruning
+                .as_ref()
+                .map(|a| a.retained_event_topics.clone())
+                .unwrap_or_default(),
             protective_reads_persistence_enabled: general_config
                 .db_config
                 .as_ref()
@@ -734,6 +769,11 @@ impl OptionalENConfig {
             main_node_rate_limit_rps: enconfig
                 .main_node_rate_limit_rps
                 .unwrap_or_else(Self::default_main_node_rate_limit_rps),
+            fallback_upstream_urls: enconfig.fallback_upstream_urls.clone(),
+            upstream_max_lag_blocks: enconfig
+                .upstream_max_lag_blocks
+                .unwrap_or_else(Self::default_upstream_max_lag_blocks),
+            verify_batch_statuses: enconfig.verify_batch_statuses.unwrap_or(false),
             api_namespaces,
             contracts_diamond_proxy_addr: None,
             gateway_url: secrets
@@ -864,6 +904,10 @@ impl OptionalENConfig {
         NonZeroUsize::new(100).unwrap()
     }
 
+    const fn default_upstream_max_lag_blocks() -> u32 {
+        10
+    }
+
     fn default_snapshots_recovery_postgres_max_concurrency() -> NonZeroUsize {
         SnapshotsApplierConfig::default().max_concurrency
     }
@@ -976,6 +1020,13 @@ impl OptionalENConfig {
         Duration::from_secs(self.pruning_data_retention_sec)
     }
 
+    pub fn pruning_retention_rules(&self) -> PruningRetentionRules {
+        PruningRetentionRules {
+            addresses: self.pruning_retained_addresses.clone(),
+            topics: self.pruning_retained_event_topics.clone(),
+        }
+    }
+
     pub fn bridge_addresses_refresh_interval(&self) -> Duration {
         self.bridge_addresses_refresh_interval_sec
             .map_or_else(|| Duration::from_secs(30), |n| Duration::from_secs(n.get()))
@@ -1134,6 +1185,20 @@ pub(crate) struct ExperimentalENConfig {
     /// This is a temporary flag that will eventually be removed together with version 0 snapshot support.
     #[serde(default)]
     pub snapshots_recovery_drop_storage_key_preimages: bool,
+    /// Enables applying the chain of delta snapshots published by the main node on top of the recovered snapshot.
+    #[serde(default)]
+    pub snapshots_recovery_apply_delta_snapshots: bool,
+    /// Enables reconstructing the snapshot from L1 data instead of downloading it from the main node.
+    /// The snapshot object store must be writable. For validium chains, the DA client must be configured
+    /// (i.e., the `da_fetcher` component must be enabled).
+    #[serde(default)]
+    pub snapshots_recovery_from_l1: bool,
+    /// L1 block to start looking for batch commit transactions from when recovering from L1.
+    #[serde(default)]
+    pub snapshots_recovery_l1_from_block: u64,
+    /// Maximum number of L1 blocks queried for commit events in a single request when recovering from L1.
+    #[serde(default = "ExperimentalENConfig::default_snapshots_recovery_l1_block_range")]
+    pub snapshots_recovery_l1_block_range: NonZeroU64,
     /// Approximate chunk size (measured in the number of entries) to recover in a single iteration.
     /// Reasonable values are order of 100,000 (meaning an iteration takes several seconds).
     ///
@@ -1163,6 +1228,10 @@ impl ExperimentalENConfig {
         MetadataCalculatorRecoveryConfig::default().desired_chunk_size
     }
 
+    fn default_snapshots_recovery_l1_block_range() -> NonZeroU64 {
+        NonZeroU64::new(10_000).unwrap()
+    }
+
     #[cfg(test)]
     fn mock() -> Self {
         Self {
@@ -1171,6 +1240,10 @@ impl ExperimentalENConfig {
             state_keeper_db_max_open_files: None,
             snapshots_recovery_l1_batch: None,
             snapshots_recovery_drop_storage_key_preimages: false,
+            snapshots_recovery_apply_delta_snapshots: false,
+            snapshots_recovery_from_l1: false,
+            snapshots_recovery_l1_from_block: 0,
+            snapshots_recovery_l1_block_range: Self::default_snapshots_recovery_l1_block_range(),
             snapshots_recovery_tree_chunk_size: Self::default_snapshots_recovery_tree_chunk_size(),
             snapshots_recovery_tree_parallel_persistence_buffer: None,
             commitment_generator_max_parallelism: None,
@@ -1207,6 +1280,23 @@ impl ExperimentalENConfig {
                 .snapshot_recovery
                 .as_ref()
                 .is_some_and(|config| config.drop_storage_key_preimages),
+            snapshots_recovery_apply_delta_snapshots: general_config
+                .snapshot_recovery
+                .as_ref()
+                .is_some_and(|config| config.apply_delta_snapshots),
+            snapshots_recovery_from_l1: general_config
+                .snapshot_recovery
+                .as_ref()
+                .is_some_and(|config| config.l1_recovery_enabled),
+            snapshots_recovery_l1_from_block: general_config
+                .snapshot_recovery
+                .as_ref()
+                .map_or(0, |config| config.l1_recovery_from_block),
+            snapshots_recovery_l1_block_range: load_optional_config_or_default!(
+                general_config.snapshot_recovery,
+                l1_recovery_block_range,
+                default_snapshots_recovery_l1_block_range
+            ),
             commitment_generator_max_parallelism: general_config
                 .commitment_generator
                 .as_ref()
@@ -1485,6 +1575,7 @@ impl From<&ExternalNodeConfig> for InternalApiConfigBase {
             dummy_verifier: config.remote.dummy_verifier,
             l1_batch_commit_data_generator_mode: config.remote.l1_batch_commit_data_generator_mode,
             l1_to_l2_txs_paused: false,
+            pruning_retention_rules: config.optional.pruning_retention_rules(),
         }
     }
 }
@@ -126,6 +126,14 @@ fn parsing_optional_config_from_env() {
             "zks_getProof=100,eth_call=2",
         ),
         ("EN_TIMESTAMP_ASSERTER_MIN_TIME_TILL_END_SEC", "2"),
+        (
+            "EN_PRUNING_RETAINED_ADDRESSES",
+            "0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002",
+        ),
+        (
+            "EN_FALLBACK_UPSTREAM_URLS",
+            "http://en-1.local:3060,http://en-2.local:3060",
+        ),
     ];
     let env_vars = env_vars
         .into_iter()
@@ -167,6 +175,20 @@ fn parsing_optional_config_from_env() {
             )
         ])
     );
+    assert_eq!(
+        config.pruning_retained_addresses,
+        [Address::from_low_u64_be(1), Address::from_low_u64_be(2)]
+    );
+    assert!(config.pruning_retained_event_topics.is_empty());
+    assert_eq!(
+        config.fallback_upstream_urls,
+        [
+            "http://en-1.local:3060".parse().unwrap(),
+            "http://en-2.local:3060".parse().unwrap()
+        ]
+    );
+    assert_eq!(config.upstream_max_lag_blocks, 10);
+    assert!(!config.verify_batch_statuses);
 }
 
 #[test]
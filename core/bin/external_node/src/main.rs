@@ -3,6 +3,7 @@ use std::{collections::HashSet, str::FromStr};
 use anyhow::Context as _;
 use clap::Parser;
 use node_builder::ExternalNodeBuilder;
+use zksync_node_framework::service::WiringGraphFormat;
 use zksync_web3_decl::client::{Client, DynClient, L2};
 
 use crate::config::{generate_consensus_secrets, ExternalNodeConfig};
@@ -58,6 +59,18 @@ struct Cli {
         requires = "enable_consensus"
     )]
     consensus_path: Option<std::path::PathBuf>,
+
+    /// Wire the node with the provided list of the components without starting any tasks, and then exit.
+    /// Can be used to catch issues with configuration.
+    #[arg(long)]
+    dry_run: bool,
+    /// Path to export the resolved wiring graph of the node to. The graph is exported after wiring,
+    /// even if wiring fails.
+    #[arg(long)]
+    export_wiring_graph: Option<std::path::PathBuf>,
+    /// Format of the exported wiring graph: `json` or `dot` (Graphviz).
+    #[arg(long, default_value = "json", requires = "export_wiring_graph")]
+    wiring_graph_format: WiringGraphFormat,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
@@ -173,8 +186,17 @@ fn main() -> anyhow::Result<()> {
         .block_on(config.fetch_remote(main_node_client.as_ref()))
         .context("failed fetching remote part of node config from main node")?;
 
-    let node = ExternalNodeBuilder::on_runtime(runtime, config)
+    let mut node = ExternalNodeBuilder::on_runtime(runtime, config)
         .build(opt.components.0.into_iter().collect())?;
+    if let Some(path) = opt.export_wiring_graph {
+        node.export_wiring_graph(path, opt.wiring_graph_format);
+    }
+    if opt.dry_run {
+        let (_wiring_graph, result) = node.dry_run();
+        result?;
+        tracing::info!("Node wired successfully; exiting due to --dry-run flag");
+        return Ok(());
+    }
     node.run(guard)?;
     anyhow::Ok(())
 }
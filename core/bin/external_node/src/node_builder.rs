@@ -3,20 +3,21 @@
 
 use anyhow::{bail, Context as _};
 use zksync_block_reverter::{node::BlockReverterLayer, NodeRole};
+use zksync_circuit_breaker::node::CircuitBreakerCheckerLayer;
 use zksync_commitment_generator::node::CommitmentGeneratorLayer;
 use zksync_config::{
     configs::{
         api::{HealthCheckConfig, MerkleTreeApiConfig},
-        chain::TimestampAsserterConfig,
+        chain::{CircuitBreakerConfig, TimestampAsserterConfig},
         database::MerkleTreeMode,
         DataAvailabilitySecrets, DatabaseSecrets,
     },
     DAClientConfig, PostgresConfig,
 };
-use zksync_consistency_checker::node::ConsistencyCheckerLayer;
+use zksync_consistency_checker::node::{BatchStatusVerifierLayer, ConsistencyCheckerLayer};
 use zksync_da_clients::node::{
     AvailWiringLayer, CelestiaWiringLayer, EigenWiringLayer, NoDAClientWiringLayer,
-    ObjectStorageClientWiringLayer,
+    ObjectStorageClientWiringLayer, SidecarWiringLayer,
 };
 use zksync_dal::node::{PoolsLayerBuilder, PostgresMetricsLayer};
 use zksync_eth_client::node::BridgeAddressesUpdaterLayer;
@@ -39,11 +40,11 @@ use zksync_node_fee_model::node::MainNodeFeeParamsFetcherLayer;
 use zksync_node_framework::service::{ZkStackService, ZkStackServiceBuilder};
 use zksync_node_storage_init::{
     node::{external_node_strategy::ExternalNodeInitStrategyLayer, NodeStorageInitializerLayer},
-    SnapshotRecoveryConfig,
+    L1RecoveryConfig, SnapshotRecoveryConfig,
 };
 use zksync_node_sync::node::{
     BatchStatusUpdaterLayer, DataAvailabilityFetcherLayer, ExternalIOLayer, SyncStateUpdaterLayer,
-    TreeDataFetcherLayer, ValidateChainIdsLayer,
+    TreeDataFetcherLayer, UpstreamHealthLayer, ValidateChainIdsLayer,
 };
 use zksync_reorg_detector::node::ReorgDetectorLayer;
 use zksync_state::RocksdbStorageOptions;
@@ -163,11 +164,20 @@ impl ExternalNodeBuilder {
             self.config.required.main_node_url.clone(),
             self.config.optional.main_node_rate_limit_rps,
             self.config.required.l2_chain_id,
+        )
+        .with_fallback_upstreams(
+            self.config.optional.fallback_upstream_urls.clone(),
+            self.config.optional.upstream_max_lag_blocks,
         );
         self.node.add_layer(layer);
         Ok(self)
     }
 
+    fn add_upstream_health_layer(mut self) -> anyhow::Result<Self> {
+        self.node.add_layer(UpstreamHealthLayer);
+        Ok(self)
+    }
+
     fn add_healthcheck_layer(mut self) -> anyhow::Result<Self> {
         let healthcheck_config = HealthCheckConfig {
             port: self.config.required.healthcheck_port,
@@ -268,7 +278,8 @@ impl ExternalNodeBuilder {
                 self.config.optional.pruning_removal_delay(),
                 self.config.optional.pruning_chunk_size,
                 self.config.optional.pruning_data_retention(),
-            );
+            )
+            .with_retention_rules(self.config.optional.pruning_retention_rules());
             self.node.add_layer(layer);
         } else {
             tracing::info!("Pruning is disabled");
@@ -307,6 +318,25 @@ impl ExternalNodeBuilder {
         Ok(self)
     }
 
+    fn add_batch_status_verifier_layer(mut self) -> anyhow::Result<Self> {
+        if !self.config.optional.verify_batch_statuses {
+            return Ok(self);
+        }
+
+        let max_batches_to_recheck = 10;
+        self.node
+            .add_layer(BatchStatusVerifierLayer::new(max_batches_to_recheck));
+        // Stops the node if the main node reports a batch status contradicting the settlement layer.
+        self.node
+            .add_layer(CircuitBreakerCheckerLayer(CircuitBreakerConfig {
+                sync_interval_ms: 30_000,
+                http_req_max_retry_number: 10,
+                http_req_retry_interval_sec: 2,
+                replication_lag_limit_sec: None,
+            }));
+        Ok(self)
+    }
+
     fn add_tree_data_fetcher_layer(mut self) -> anyhow::Result<Self> {
         self.node.add_layer(TreeDataFetcherLayer);
         Ok(self)
@@ -328,6 +358,11 @@ impl ExternalNodeBuilder {
             return Ok(self);
         }
 
+        if let DAClientConfig::Sidecar(config) = da_client_config {
+            self.node.add_layer(SidecarWiringLayer::new(config));
+            return Ok(self);
+        }
+
         let da_client_secrets = da_client_secrets.context("DA client secrets are missing")?;
         match (da_client_config, da_client_secrets) {
             (DAClientConfig::Avail(config), DataAvailabilitySecrets::Avail(secret)) => {
@@ -577,6 +612,16 @@ impl ExternalNodeBuilder {
     /// the precondition will prevent node from starting until the database is initialized.
     fn add_storage_initialization_layer(mut self, kind: LayerKind) -> anyhow::Result<Self> {
         let config = &self.config;
+        if config.optional.snapshots_recovery_enabled
+            && config.experimental.snapshots_recovery_from_l1
+        {
+            // Fail fast on unsupported setups rather than after replaying a part of the chain history.
+            anyhow::ensure!(
+                config.optional.gateway_url.is_none(),
+                "snapshot recovery from L1 is not supported for chains settling on Gateway; \
+                 unset `gateway_url` or disable `snapshots_recovery_from_l1`"
+            );
+        }
         let snapshot_recovery_config =
             config
                 .optional
@@ -586,7 +631,17 @@ impl ExternalNodeBuilder {
                     drop_storage_key_preimages: config
                         .experimental
                         .snapshots_recovery_drop_storage_key_preimages,
+                    apply_delta_snapshots: config
+                        .experimental
+                        .snapshots_recovery_apply_delta_snapshots,
                     object_store_config: config.optional.snapshots_recovery_object_store.clone(),
+                    l1_recovery: config.experimental.snapshots_recovery_from_l1.then(|| {
+                        L1RecoveryConfig {
+                            l1_from_block: config.experimental.snapshots_recovery_l1_from_block,
+                            l1_block_range: config.experimental.snapshots_recovery_l1_block_range,
+                            commitment_mode: config.remote.l1_batch_commit_data_generator_mode,
+                        }
+                    }),
                 });
         self.node.add_layer(ExternalNodeInitStrategyLayer {
             l2_chain_id: self.config.required.l2_chain_id,
@@ -612,6 +667,7 @@ impl ExternalNodeBuilder {
             .add_prometheus_exporter_layer()?
             .add_pools_layer()?
             .add_main_node_client_layer()?
+            .add_upstream_health_layer()?
             .add_query_eth_client_layer()?
             .add_settlement_layer_data()?
             .add_settlement_layer_client_layer()?
@@ -702,6 +758,7 @@ impl ExternalNodeBuilder {
                         .add_consistency_checker_layer()?
                         .add_commitment_generator_layer()?
                         .add_batch_status_updater_layer()?
+                        .add_batch_status_verifier_layer()?
                         .add_logs_bloom_backfill_layer()?;
                 }
             }
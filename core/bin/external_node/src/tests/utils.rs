@@ -196,6 +196,7 @@ pub(super) fn mock_l2_client(env: &TestEnvironment) -> MockClient<L2> {
             Ok(api::L1BatchDetails {
                 number: L1BatchNumber(0),
                 base: utils::block_details_base(genesis_root_hash),
+                verified_stage: None,
             })
         })
         .method("eth_blockNumber", || Ok(U64::from(0)))
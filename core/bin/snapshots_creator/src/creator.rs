@@ -25,6 +25,8 @@ use crate::tests::HandleEvent;
 struct SnapshotProgress {
     version: SnapshotVersion,
     l1_batch_number: L1BatchNumber,
+    /// L1 batch of the base snapshot if the snapshot is a delta one.
+    base_l1_batch_number: Option<L1BatchNumber>,
     /// `true` if the snapshot is new (i.e., its progress is not recovered from Postgres).
     is_new_snapshot: bool,
     chunk_count: u64,
@@ -32,10 +34,16 @@ struct SnapshotProgress {
 }
 
 impl SnapshotProgress {
-    fn new(version: SnapshotVersion, l1_batch_number: L1BatchNumber, chunk_count: u64) -> Self {
+    fn new(
+        version: SnapshotVersion,
+        l1_batch_number: L1BatchNumber,
+        base_l1_batch_number: Option<L1BatchNumber>,
+        chunk_count: u64,
+    ) -> Self {
         Self {
             version,
             l1_batch_number,
+            base_l1_batch_number,
             is_new_snapshot: true,
             chunk_count,
             remaining_chunk_ids: (0..chunk_count).collect(),
@@ -53,6 +61,7 @@ impl SnapshotProgress {
         Self {
             version: snapshot.version,
             l1_batch_number: snapshot.l1_batch_number,
+            base_l1_batch_number: snapshot.base_l1_batch_number,
             is_new_snapshot: false,
             chunk_count: snapshot.storage_logs_filepaths.len() as u64,
             remaining_chunk_ids,
@@ -82,6 +91,7 @@ impl SnapshotCreator {
         semaphore: &Semaphore,
         progress: &SnapshotProgress,
         l2_block_number: L2BlockNumber,
+        base_l2_block_number: Option<L2BlockNumber>,
         chunk_id: u64,
     ) -> anyhow::Result<()> {
         let chunk_count = progress.chunk_count;
@@ -121,11 +131,21 @@ impl SnapshotCreator {
                     .await?
             }
             SnapshotVersion::Version1 => {
-                let logs = conn
-                    .snapshots_creator_dal()
-                    .get_storage_logs_chunk(l2_block_number, l1_batch_number, hashed_keys_range)
-                    .await
-                    .context("error fetching storage logs")?;
+                let logs = if let Some(base_l2_block_number) = base_l2_block_number {
+                    conn.snapshots_creator_dal()
+                        .get_storage_logs_delta_chunk(
+                            base_l2_block_number,
+                            l2_block_number,
+                            l1_batch_number,
+                            hashed_keys_range,
+                        )
+                        .await
+                } else {
+                    conn.snapshots_creator_dal()
+                        .get_storage_logs_chunk(l2_block_number, l1_batch_number, hashed_keys_range)
+                        .await
+                };
+                let logs = logs.context("error fetching storage logs")?;
                 drop(conn);
 
                 let latency = latency.observe();
@@ -189,6 +209,7 @@ impl SnapshotCreator {
     async fn process_factory_deps(
         &self,
         l2_block_number: L2BlockNumber,
+        base_l2_block_number: Option<L2BlockNumber>,
         l1_batch_number: L1BatchNumber,
     ) -> anyhow::Result<String> {
         let mut conn = self.connect_to_replica().await?;
@@ -196,10 +217,15 @@ impl SnapshotCreator {
         tracing::info!("Loading factory deps from Postgres...");
         let latency =
             METRICS.factory_deps_processing_duration[&FactoryDepsStage::LoadFromPostgres].start();
-        let factory_deps = conn
-            .snapshots_creator_dal()
-            .get_all_factory_deps(l2_block_number)
-            .await?;
+        let factory_deps = if let Some(base_l2_block_number) = base_l2_block_number {
+            conn.snapshots_creator_dal()
+                .get_factory_deps_in_l2_blocks(base_l2_block_number + 1..=l2_block_number)
+                .await?
+        } else {
+            conn.snapshots_creator_dal()
+                .get_all_factory_deps(l2_block_number)
+                .await?
+        };
         drop(conn);
         let latency = latency.observe();
         tracing::info!("Loaded {} factory deps in {latency:?}", factory_deps.len());
@@ -255,10 +281,33 @@ impl SnapshotCreator {
                 )
             })?;
 
-        let distinct_storage_logs_keys_count = conn
-            .snapshots_creator_dal()
-            .get_distinct_storage_logs_keys_count(l1_batch_number)
-            .await?;
+        let base_l1_batch_number = if config.create_delta_snapshots {
+            Self::select_base_snapshot(l1_batch_number, conn).await?
+        } else {
+            None
+        };
+        let distinct_storage_logs_keys_count = if let Some(base_l1_batch_number) =
+            base_l1_batch_number
+        {
+            anyhow::ensure!(
+                snapshot_version == SnapshotVersion::Version1,
+                "delta snapshots are only supported for version {:?}",
+                SnapshotVersion::Version1
+            );
+            let base_l2_block_number =
+                Self::last_l2_block_number(conn, base_l1_batch_number).await?;
+            let l2_block_number = Self::last_l2_block_number(conn, l1_batch_number).await?;
+            tracing::info!(
+                "Creating delta snapshot for L1 batch {l1_batch_number} on top of snapshot for L1 batch {base_l1_batch_number}"
+            );
+            conn.snapshots_creator_dal()
+                .get_storage_logs_count_in_l2_blocks(base_l2_block_number + 1..=l2_block_number)
+                .await?
+        } else {
+            conn.snapshots_creator_dal()
+                .get_distinct_storage_logs_keys_count(l1_batch_number)
+                .await?
+        };
         let chunk_size = config.storage_logs_chunk_size;
         // We force the minimum number of chunks to avoid situations where only one chunk is created in tests.
         let chunk_count = distinct_storage_logs_keys_count
@@ -272,10 +321,45 @@ impl SnapshotCreator {
         Ok(Some(SnapshotProgress::new(
             snapshot_version,
             l1_batch_number,
+            base_l1_batch_number,
             chunk_count,
         )))
     }
 
+    /// Selects the newest complete snapshot (either full or delta) preceding `l1_batch_number` as a base
+    /// for a delta snapshot.
+    async fn select_base_snapshot(
+        l1_batch_number: L1BatchNumber,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<Option<L1BatchNumber>> {
+        let all_snapshots = conn.snapshots_dal().get_all_complete_snapshots().await?;
+        let base_l1_batch_number = all_snapshots
+            .snapshots_l1_batch_numbers
+            .iter()
+            .chain(&all_snapshots.delta_snapshots_l1_batch_numbers)
+            .copied()
+            .filter(|&number| number < l1_batch_number)
+            .max();
+        if base_l1_batch_number.is_none() {
+            tracing::info!(
+                "There are no complete snapshots before L1 batch {l1_batch_number}; creating a full snapshot"
+            );
+        }
+        Ok(base_l1_batch_number)
+    }
+
+    async fn last_l2_block_number(
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<L2BlockNumber> {
+        let (_, last_l2_block_number) = conn
+            .blocks_dal()
+            .get_l2_block_range_of_l1_batch(l1_batch_number)
+            .await?
+            .with_context(|| format!("No L2 blocks for L1 batch #{l1_batch_number}"))?;
+        Ok(last_l2_block_number)
+    }
+
     /// Returns `Ok(None)` if a snapshot should not be created / resumed.
     async fn load_or_initialize_snapshot_progress(
         &self,
@@ -364,11 +448,14 @@ impl SnapshotCreator {
         };
 
         let mut conn = self.connect_to_replica().await?;
-        let (_, last_l2_block_number_in_batch) = conn
-            .blocks_dal()
-            .get_l2_block_range_of_l1_batch(progress.l1_batch_number)
-            .await?
-            .context("No L2 blocks for L1 batch")?;
+        let last_l2_block_number_in_batch =
+            Self::last_l2_block_number(&mut conn, progress.l1_batch_number).await?;
+        let base_l2_block_number = if let Some(base_l1_batch_number) = progress.base_l1_batch_number
+        {
+            Some(Self::last_l2_block_number(&mut conn, base_l1_batch_number).await?)
+        } else {
+            None
+        };
         drop(conn);
 
         METRICS.storage_logs_chunks_count.set(progress.chunk_count);
@@ -380,22 +467,36 @@ impl SnapshotCreator {
 
         if progress.is_new_snapshot {
             let factory_deps_output_file = self
-                .process_factory_deps(last_l2_block_number_in_batch, progress.l1_batch_number)
+                .process_factory_deps(
+                    last_l2_block_number_in_batch,
+                    base_l2_block_number,
+                    progress.l1_batch_number,
+                )
                 .await?;
 
             let mut master_conn = self
                 .master_pool
                 .connection_tagged("snapshots_creator")
                 .await?;
-            master_conn
-                .snapshots_dal()
-                .add_snapshot(
+            let mut dal = master_conn.snapshots_dal();
+            if let Some(base_l1_batch_number) = progress.base_l1_batch_number {
+                dal.add_delta_snapshot(
+                    progress.version,
+                    progress.l1_batch_number,
+                    base_l1_batch_number,
+                    progress.chunk_count,
+                    &factory_deps_output_file,
+                )
+                .await?;
+            } else {
+                dal.add_snapshot(
                     progress.version,
                     progress.l1_batch_number,
                     progress.chunk_count,
                     &factory_deps_output_file,
                 )
                 .await?;
+            }
         }
 
         METRICS
@@ -411,6 +512,7 @@ impl SnapshotCreator {
                     &semaphore,
                     &progress,
                     last_l2_block_number_in_batch,
+                    base_l2_block_number,
                     chunk_id,
                 )
             });
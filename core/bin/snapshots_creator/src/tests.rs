@@ -29,6 +29,7 @@ use super::*;
 const TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
     version: 1,
     l1_batch_number: None,
+    create_delta_snapshots: false,
     storage_logs_chunk_size: 1_000_000,
     concurrent_queries_count: 10,
     object_store: None,
@@ -629,3 +630,87 @@ async fn creator_fails_if_specified_l1_batch_is_missing() {
         .await
         .unwrap_err();
 }
+
+#[tokio::test]
+async fn creating_delta_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    let expected_outputs = prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let base_l1_batch_number = L1BatchNumber(4);
+    let delta_config = SnapshotsCreatorConfig {
+        create_delta_snapshots: true,
+        ..TEST_CONFIG
+    };
+    // Without a base snapshot, a full snapshot should be created.
+    let config = SnapshotsCreatorConfig {
+        l1_batch_number: Some(base_l1_batch_number),
+        ..delta_config.clone()
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let base_metadata = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(base_l1_batch_number)
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert!(base_metadata.is_complete());
+    assert!(!base_metadata.is_delta());
+
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(delta_config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    let snapshots = conn
+        .snapshots_dal()
+        .get_all_complete_snapshots()
+        .await
+        .unwrap();
+    assert_eq!(snapshots.snapshots_l1_batch_numbers, [base_l1_batch_number]);
+    assert_eq!(
+        snapshots.delta_snapshots_l1_batch_numbers,
+        [snapshot_l1_batch_number]
+    );
+    let snapshot_metadata = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(snapshot_l1_batch_number)
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert_eq!(
+        snapshot_metadata.base_l1_batch_number,
+        Some(base_l1_batch_number)
+    );
+
+    let mut actual_logs = HashSet::new();
+    for chunk_id in 0..MIN_CHUNK_COUNT {
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: snapshot_l1_batch_number,
+            chunk_id,
+        };
+        let chunk: SnapshotStorageLogsChunk = object_store.get(key).await.unwrap();
+        actual_logs.extend(chunk.storage_logs);
+    }
+    // All generated keys are unique, so the delta must contain exactly the keys written after the base snapshot.
+    let expected_logs: HashSet<_> = expected_outputs
+        .storage_logs
+        .iter()
+        .filter(|log| log.l1_batch_number_of_initial_write > base_l1_batch_number)
+        .cloned()
+        .collect();
+    assert_eq!(actual_logs, expected_logs);
+
+    let SnapshotFactoryDependencies { factory_deps } =
+        object_store.get(snapshot_l1_batch_number).await.unwrap();
+    // Each L2 block in `(4, 8]` has 10 factory deps.
+    assert_eq!(factory_deps.len(), 40);
+    for dep in &factory_deps {
+        assert!(expected_outputs.deps.contains(dep), "{dep:?}");
+    }
+}
@@ -0,0 +1,288 @@
+//! Snapshot verifier utility. Checks that a snapshot stored in an object store is complete and
+//! consistent before it is published or used for node recovery.
+//!
+//! The verifier checks that all storage log chunks and the factory deps object are present and decode,
+//! sanity-checks their contents, and (for full snapshots) rebuilds the Merkle tree from the storage logs
+//! in a temporary RocksDB instance, comparing the resulting root hash with the reference one obtained
+//! from the main node and / or L1.
+
+use std::time::Instant;
+
+use anyhow::Context as _;
+use clap::{ArgGroup, Parser};
+use zksync_config::{configs::ObservabilityConfig, ObjectStoreConfig};
+use zksync_contracts::hyperchain_contract;
+use zksync_env_config::{object_store::SnapshotsObjectStoreConfig, FromEnv};
+use zksync_eth_client::EthInterface;
+use zksync_object_store::ObjectStoreFactory;
+use zksync_types::{
+    snapshots::SnapshotVersion,
+    url::SensitiveUrl,
+    web3::{BlockNumber, FilterBuilder},
+    Address, L1BatchNumber, H256,
+};
+use zksync_web3_decl::{
+    client::{Client, L1, L2},
+    namespaces::{SnapshotsNamespaceClient, ZksNamespaceClient},
+};
+
+use crate::verifier::{SnapshotLayout, SnapshotVerifier, VerificationReport};
+
+#[cfg(test)]
+mod tests;
+mod verifier;
+
+#[derive(Debug, Parser)]
+#[command(
+    author = "Matter Labs",
+    version,
+    about = "Snapshot verification and inspection tool",
+    long_about = None
+)]
+#[command(group(
+    ArgGroup::new("root_hash_source")
+        .args(["main_node_url", "l1_rpc_url", "expected_root_hash", "skip_tree"])
+        .required(true)
+        .multiple(true)
+))]
+struct Cli {
+    /// L1 batch number of the snapshot to verify.
+    #[arg(long)]
+    l1_batch: u32,
+    /// Main node JSON-RPC URL. If specified, the snapshot header and the reference root hash
+    /// are fetched from the main node.
+    #[arg(long)]
+    main_node_url: Option<SensitiveUrl>,
+    /// Number of storage log chunks in the snapshot. Required if `--main-node-url` is not specified.
+    #[arg(long, conflicts_with = "main_node_url")]
+    chunk_count: Option<u64>,
+    /// Snapshot version. Only used if `--main-node-url` is not specified.
+    #[arg(long, default_value_t = 1, conflicts_with = "main_node_url")]
+    snapshot_version: u16,
+    /// L1 JSON-RPC URL. If specified together with `--diamond-proxy-addr`, the reference root hash
+    /// is taken from the `BlockCommit` event for the snapshot L1 batch.
+    #[arg(long, requires = "diamond_proxy_addr")]
+    l1_rpc_url: Option<SensitiveUrl>,
+    /// Address of the chain diamond proxy contract on L1.
+    #[arg(long, requires = "l1_rpc_url")]
+    diamond_proxy_addr: Option<Address>,
+    /// First L1 block to search for the `BlockCommit` event in. Can be used to narrow the search
+    /// if the L1 node limits the block range for `eth_getLogs` queries.
+    #[arg(long, default_value_t = 0)]
+    l1_from_block: u64,
+    /// Reference root hash of the Merkle tree after the snapshot L1 batch.
+    #[arg(long)]
+    expected_root_hash: Option<H256>,
+    /// Skip rebuilding the Merkle tree; only check snapshot objects. If not set, at least one source
+    /// of the reference root hash (`--main-node-url`, `--l1-rpc-url` or `--expected-root-hash`) must be specified.
+    #[arg(long)]
+    skip_tree: bool,
+}
+
+impl Cli {
+    async fn layout(
+        &self,
+        main_node_client: Option<&Client<L2>>,
+    ) -> anyhow::Result<SnapshotLayout> {
+        let l1_batch_number = L1BatchNumber(self.l1_batch);
+        if let Some(client) = main_node_client {
+            let header = client
+                .get_snapshot_by_l1_batch_number(l1_batch_number)
+                .await
+                .context("failed fetching snapshot header from main node")?
+                .with_context(|| {
+                    format!("main node doesn't have a snapshot for L1 batch #{l1_batch_number}")
+                })?;
+            return SnapshotLayout::from_header(&header);
+        }
+
+        let chunk_count = self
+            .chunk_count
+            .context("`--chunk-count` must be specified if `--main-node-url` is not")?;
+        anyhow::ensure!(chunk_count > 0, "`--chunk-count` must be positive");
+        let version = SnapshotVersion::try_from(self.snapshot_version)
+            .with_context(|| format!("unrecognized snapshot version: {}", self.snapshot_version))?;
+        Ok(SnapshotLayout {
+            l1_batch_number,
+            version,
+            chunk_count,
+            base_l1_batch_number: None,
+        })
+    }
+
+    async fn expected_root_hashes(
+        &self,
+        main_node_client: Option<&Client<L2>>,
+    ) -> anyhow::Result<Vec<(&'static str, H256)>> {
+        let l1_batch_number = L1BatchNumber(self.l1_batch);
+        let mut hashes = vec![];
+        if let Some(hash) = self.expected_root_hash {
+            hashes.push(("command line", hash));
+        }
+
+        if let Some(client) = main_node_client {
+            let details = client
+                .get_l1_batch_details(l1_batch_number)
+                .await
+                .context("failed fetching L1 batch details from main node")?
+                .with_context(|| format!("main node doesn't have L1 batch #{l1_batch_number}"))?;
+            let hash = details.base.root_hash.with_context(|| {
+                format!("main node doesn't have root hash for L1 batch #{l1_batch_number}")
+            })?;
+            hashes.push(("main node", hash));
+        }
+
+        if let (Some(url), Some(diamond_proxy_addr)) = (&self.l1_rpc_url, self.diamond_proxy_addr) {
+            let client: Client<L1> = Client::http(url.clone())
+                .context("failed creating L1 client")?
+                .build();
+            let hash = fetch_committed_root_hash(
+                &client,
+                diamond_proxy_addr,
+                l1_batch_number,
+                self.l1_from_block,
+            )
+            .await?;
+            hashes.push(("L1", hash));
+        }
+        Ok(hashes)
+    }
+
+    async fn run(self, object_store_config: ObjectStoreConfig) -> anyhow::Result<()> {
+        let main_node_client: Option<Client<L2>> = self
+            .main_node_url
+            .clone()
+            .map(|url| anyhow::Ok(Client::http(url)?.build()))
+            .transpose()
+            .context("failed creating main node client")?;
+
+        let layout = self.layout(main_node_client.as_ref()).await?;
+        tracing::info!("Verifying snapshot: {layout:?}");
+        let expected_root_hashes = if self.skip_tree {
+            vec![]
+        } else {
+            self.expected_root_hashes(main_node_client.as_ref()).await?
+        };
+
+        let blob_store = ObjectStoreFactory::new(object_store_config)
+            .create_store()
+            .await?;
+        let verifier = SnapshotVerifier::new(blob_store);
+        let temp_dir = tempfile::TempDir::new().context("failed creating temporary directory")?;
+        let tree_path = (!self.skip_tree).then(|| temp_dir.path());
+
+        let started_at = Instant::now();
+        let mut report = verifier.verify(layout, tree_path).await?;
+        tracing::info!("Verified snapshot in {:?}", started_at.elapsed());
+
+        if let Some(root_hash) = report.root_hash {
+            for (source, expected_hash) in expected_root_hashes {
+                if root_hash == expected_hash {
+                    tracing::info!("Root hash {root_hash:?} matches the one from {source}");
+                } else {
+                    report.issues.push(format!(
+                        "root hash of the rebuilt tree {root_hash:?} differs from the one from {source}: {expected_hash:?}"
+                    ));
+                }
+            }
+        }
+        print_report(&layout, &report);
+        anyhow::ensure!(
+            report.is_ok(),
+            "snapshot for L1 batch #{} has {} issue(s)",
+            layout.l1_batch_number,
+            report.issues.len()
+        );
+        Ok(())
+    }
+}
+
+/// Fetches the state root hash for the specified L1 batch from the `BlockCommit` event emitted
+/// by the diamond proxy. If the batch was committed several times (e.g., because of a revert),
+/// the latest commitment is used.
+async fn fetch_committed_root_hash(
+    client: &Client<L1>,
+    diamond_proxy_addr: Address,
+    l1_batch_number: L1BatchNumber,
+    from_block: u64,
+) -> anyhow::Result<H256> {
+    let contract = hyperchain_contract();
+    let event = contract
+        .event("BlockCommit")
+        .context("`BlockCommit` event not found for ZKsync L1 contract")?;
+    let filter = FilterBuilder::default()
+        .address(vec![diamond_proxy_addr])
+        .from_block(BlockNumber::Number(from_block.into()))
+        .to_block(BlockNumber::Latest)
+        .topics(
+            Some(vec![event.signature()]),
+            Some(vec![H256::from_low_u64_be(l1_batch_number.0.into())]),
+            None,
+            None,
+        )
+        .build();
+    let logs = client
+        .logs(&filter)
+        .await
+        .context("failed fetching `BlockCommit` events from L1")?;
+    let log = logs.last().with_context(|| {
+        format!("no `BlockCommit` event found on L1 for L1 batch #{l1_batch_number}")
+    })?;
+    // Event signature: `BlockCommit(uint256 indexed batchNumber, bytes32 indexed batchHash, bytes32 indexed commitment)`,
+    // where `batchHash` is the state root hash.
+    log.topics
+        .get(2)
+        .copied()
+        .context("`BlockCommit` event has unexpected number of topics")
+}
+
+fn print_report(layout: &SnapshotLayout, report: &VerificationReport) {
+    println!(
+        "Snapshot for L1 batch #{} (version {:?}, {} chunk(s){})",
+        layout.l1_batch_number,
+        layout.version,
+        layout.chunk_count,
+        layout
+            .base_l1_batch_number
+            .map(|base| format!(", delta relative to L1 batch #{base}"))
+            .unwrap_or_default()
+    );
+    for chunk in &report.chunks {
+        println!("  {chunk}");
+    }
+    let log_counts = report.chunks.iter().map(|chunk| chunk.log_count);
+    println!(
+        "Storage logs: {} in {} readable chunk(s); min / max per chunk: {} / {}",
+        report.total_log_count(),
+        report.chunks.len(),
+        log_counts.clone().min().unwrap_or(0),
+        log_counts.max().unwrap_or(0)
+    );
+    println!(
+        "Factory deps: {} ({} bytes in total)",
+        report.factory_deps_count, report.factory_deps_total_size
+    );
+    if let Some(root_hash) = report.root_hash {
+        println!("Rebuilt tree root hash: {root_hash:?}");
+    }
+    if report.issues.is_empty() {
+        println!("No issues found");
+    } else {
+        println!("Issues ({}):", report.issues.len());
+        for issue in &report.issues {
+            println!("  - {issue}");
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let observability_config =
+        ObservabilityConfig::from_env().context("ObservabilityConfig::from_env()")?;
+    let _observability_guard = observability_config.install()?;
+
+    let object_store_config = SnapshotsObjectStoreConfig::from_env()
+        .context("SnapshotsObjectStoreConfig::from_env()")?
+        .0;
+    Cli::parse().run(object_store_config).await
+}
@@ -0,0 +1,295 @@
+//! Tests for the snapshot verifier.
+
+use std::sync::Arc;
+
+use clap::Parser;
+use zksync_merkle_tree::{MerkleTree, PatchSet, TreeEntry};
+use zksync_object_store::{Bucket, MockObjectStore, ObjectStore, StoredObject};
+use zksync_types::{
+    bytecode::BytecodeHash,
+    h256_to_u256,
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotFactoryDependency,
+        SnapshotStorageLog, SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey,
+        SnapshotVersion,
+    },
+    web3::Bytes,
+    L1BatchNumber, StorageValue, H256,
+};
+
+use super::{
+    verifier::{SnapshotLayout, SnapshotVerifier},
+    Cli,
+};
+
+const L1_BATCH_NUMBER: L1BatchNumber = L1BatchNumber(42);
+
+fn test_layout(chunk_count: u64) -> SnapshotLayout {
+    SnapshotLayout {
+        l1_batch_number: L1_BATCH_NUMBER,
+        version: SnapshotVersion::Version1,
+        chunk_count,
+        base_l1_batch_number: None,
+    }
+}
+
+fn random_storage_logs(count: u64) -> Vec<SnapshotStorageLog> {
+    (0..count)
+        .map(|i| SnapshotStorageLog {
+            key: H256::random(),
+            value: StorageValue::random(),
+            l1_batch_number_of_initial_write: L1BatchNumber(i as u32 % L1_BATCH_NUMBER.0 + 1),
+            enumeration_index: i + 1,
+        })
+        .collect()
+}
+
+fn mock_factory_deps() -> SnapshotFactoryDependencies {
+    let bytecode: Vec<u8> = (0..32).collect();
+    SnapshotFactoryDependencies {
+        factory_deps: vec![SnapshotFactoryDependency {
+            hash: Some(BytecodeHash::for_bytecode(&bytecode).value()),
+            bytecode: Bytes(bytecode),
+        }],
+    }
+}
+
+/// Splits logs into chunks in the same way as the snapshot creator and puts them into the store.
+async fn prepare_snapshot(logs: &[SnapshotStorageLog], chunk_count: u64) -> Arc<dyn ObjectStore> {
+    let object_store = MockObjectStore::arc();
+    object_store
+        .put(L1_BATCH_NUMBER, &mock_factory_deps())
+        .await
+        .unwrap();
+
+    for chunk_id in 0..chunk_count {
+        let key_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
+        let chunk = SnapshotStorageLogsChunk {
+            storage_logs: logs
+                .iter()
+                .filter(|log| key_range.contains(&log.key))
+                .cloned()
+                .collect(),
+        };
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: L1_BATCH_NUMBER,
+            chunk_id,
+        };
+        object_store.put(key, &chunk).await.unwrap();
+    }
+    object_store
+}
+
+fn expected_root_hash(logs: &[SnapshotStorageLog]) -> H256 {
+    let entries = logs
+        .iter()
+        .map(|log| TreeEntry::new(h256_to_u256(log.key), log.enumeration_index, log.value))
+        .collect();
+    let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+    tree.extend(entries).unwrap().root_hash
+}
+
+#[tokio::test]
+async fn verifying_valid_snapshot() {
+    let logs = random_storage_logs(200);
+    let object_store = prepare_snapshot(&logs, 4).await;
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(test_layout(4), Some(temp_dir.path()))
+        .await
+        .unwrap();
+
+    assert!(report.is_ok(), "{:?}", report.issues);
+    assert_eq!(report.chunks.len(), 4);
+    assert_eq!(report.total_log_count(), logs.len());
+    for (chunk_id, chunk) in report.chunks.iter().enumerate() {
+        assert_eq!(chunk.chunk_id, chunk_id as u64);
+        let (start, end) = chunk.key_range.unwrap();
+        let expected_range = uniform_hashed_keys_chunk(chunk_id as u64, 4);
+        assert!(expected_range.contains(&start) && expected_range.contains(&end));
+    }
+    assert_eq!(report.factory_deps_count, 1);
+    assert_eq!(report.factory_deps_total_size, 32);
+    assert_eq!(report.root_hash, Some(expected_root_hash(&logs)));
+}
+
+#[tokio::test]
+async fn verifying_snapshot_without_tree() {
+    let logs = random_storage_logs(50);
+    let object_store = prepare_snapshot(&logs, 2).await;
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(test_layout(2), None)
+        .await
+        .unwrap();
+
+    assert!(report.is_ok(), "{:?}", report.issues);
+    assert_eq!(report.total_log_count(), logs.len());
+    assert_eq!(report.root_hash, None);
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_missing_objects() {
+    let logs = random_storage_logs(100);
+    let object_store = prepare_snapshot(&logs, 3).await;
+    let missing_chunk_key =
+        SnapshotStorageLogsChunk::<H256>::encode_key(SnapshotStorageLogsStorageKey {
+            l1_batch_number: L1_BATCH_NUMBER,
+            chunk_id: 1,
+        });
+    object_store
+        .remove_raw(Bucket::StorageSnapshot, &missing_chunk_key)
+        .await
+        .unwrap();
+    let factory_deps_key = SnapshotFactoryDependencies::encode_key(L1_BATCH_NUMBER);
+    object_store
+        .remove_raw(Bucket::StorageSnapshot, &factory_deps_key)
+        .await
+        .unwrap();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(test_layout(3), Some(temp_dir.path()))
+        .await
+        .unwrap();
+
+    assert_eq!(report.issues.len(), 2, "{:?}", report.issues);
+    assert!(
+        report.issues[0].contains("factory deps"),
+        "{:?}",
+        report.issues
+    );
+    assert!(report.issues[1].contains("chunk #1"), "{:?}", report.issues);
+    let chunk_ids: Vec<_> = report.chunks.iter().map(|chunk| chunk.chunk_id).collect();
+    assert_eq!(chunk_ids, [0, 2]);
+    assert_eq!(report.root_hash, None);
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_undecodable_chunk() {
+    let logs = random_storage_logs(100);
+    let object_store = prepare_snapshot(&logs, 2).await;
+    let chunk_key = SnapshotStorageLogsChunk::<H256>::encode_key(SnapshotStorageLogsStorageKey {
+        l1_batch_number: L1_BATCH_NUMBER,
+        chunk_id: 0,
+    });
+    object_store
+        .put_raw(Bucket::StorageSnapshot, &chunk_key, b"garbage".to_vec())
+        .await
+        .unwrap();
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(test_layout(2), None)
+        .await
+        .unwrap();
+
+    assert_eq!(report.issues.len(), 1, "{:?}", report.issues);
+    assert!(
+        report.issues[0].contains("failed decoding storage logs chunk #0"),
+        "{:?}",
+        report.issues
+    );
+    assert_eq!(report.chunks.len(), 1);
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_invalid_logs() {
+    let mut logs = random_storage_logs(100);
+    let object_store = prepare_snapshot(&logs, 2).await;
+
+    // Put a log into a wrong chunk and corrupt its metadata.
+    let misplaced_log = &mut logs[0];
+    misplaced_log.key = *uniform_hashed_keys_chunk(1, 2).start();
+    misplaced_log.enumeration_index = 0;
+    misplaced_log.l1_batch_number_of_initial_write = L1_BATCH_NUMBER + 1;
+    let chunk = SnapshotStorageLogsChunk {
+        storage_logs: vec![misplaced_log.clone()],
+    };
+    let chunk_key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: L1_BATCH_NUMBER,
+        chunk_id: 0,
+    };
+    object_store.put(chunk_key, &chunk).await.unwrap();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(test_layout(2), Some(temp_dir.path()))
+        .await
+        .unwrap();
+
+    assert_eq!(report.issues.len(), 3, "{:?}", report.issues);
+    assert!(report.issues[0].contains("outside its range"));
+    assert!(report.issues[1].contains("zero enumeration index"));
+    assert!(report.issues[2].contains("after the snapshot L1 batch"));
+    assert_eq!(report.chunks[0].log_count, 1);
+    assert_eq!(report.root_hash, None);
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_mismatched_factory_dep_hash() {
+    let logs = random_storage_logs(10);
+    let object_store = prepare_snapshot(&logs, 1).await;
+    let mut factory_deps = mock_factory_deps();
+    factory_deps.factory_deps[0].bytecode.0.reverse();
+    object_store
+        .put(L1_BATCH_NUMBER, &factory_deps)
+        .await
+        .unwrap();
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(test_layout(1), None)
+        .await
+        .unwrap();
+
+    assert_eq!(report.issues.len(), 1, "{:?}", report.issues);
+    assert!(report.issues[0].contains("doesn't match the bytecode hash"));
+}
+
+#[tokio::test]
+async fn verifying_delta_snapshot() {
+    let logs = random_storage_logs(30);
+    let object_store = prepare_snapshot(&logs, 2).await;
+    let layout = SnapshotLayout {
+        base_l1_batch_number: Some(L1BatchNumber(10)),
+        ..test_layout(2)
+    };
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let report = SnapshotVerifier::new(object_store)
+        .verify(layout, Some(temp_dir.path()))
+        .await
+        .unwrap();
+
+    assert!(report.is_ok(), "{:?}", report.issues);
+    assert_eq!(report.total_log_count(), logs.len());
+    // The tree cannot be rebuilt from a delta snapshot alone.
+    assert_eq!(report.root_hash, None);
+}
+
+#[test]
+fn cli_requires_root_hash_source() {
+    let args = [
+        "snapshots_verifier",
+        "--l1-batch",
+        "42",
+        "--chunk-count",
+        "2",
+    ];
+    let err = Cli::try_parse_from(args).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        clap::error::ErrorKind::MissingRequiredArgument,
+        "{err}"
+    );
+
+    let hash = format!("{:?}", H256::repeat_byte(1));
+    let cli = Cli::try_parse_from(
+        args.into_iter()
+            .chain(["--expected-root-hash", hash.as_str()]),
+    )
+    .unwrap();
+    assert_eq!(cli.expected_root_hash, Some(H256::repeat_byte(1)));
+    let cli = Cli::try_parse_from(args.into_iter().chain(["--skip-tree"])).unwrap();
+    assert!(cli.skip_tree);
+}
@@ -0,0 +1,361 @@
+//! Snapshot verification logic.
+
+use std::{fmt, path::Path, sync::Arc, time::Instant};
+
+use anyhow::Context as _;
+use zksync_merkle_tree::{recovery::MerkleTreeRecovery, RocksDBWrapper, TreeEntry};
+use zksync_object_store::{ObjectStore, ObjectStoreError, StoredObject};
+use zksync_types::{
+    bytecode::{BytecodeHash, BytecodeMarker},
+    h256_to_u256,
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotHeader, SnapshotStorageLog,
+        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey, SnapshotVersion,
+    },
+    L1BatchNumber, StorageKey, H256,
+};
+
+/// Parameters of a snapshot necessary to locate and decode its objects in the object store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SnapshotLayout {
+    pub l1_batch_number: L1BatchNumber,
+    pub version: SnapshotVersion,
+    pub chunk_count: u64,
+    /// Set for delta snapshots.
+    pub base_l1_batch_number: Option<L1BatchNumber>,
+}
+
+impl SnapshotLayout {
+    pub fn from_header(header: &SnapshotHeader) -> anyhow::Result<Self> {
+        let version = SnapshotVersion::try_from(header.version)
+            .with_context(|| format!("unrecognized snapshot version: {}", header.version))?;
+        for (i, chunk) in header.storage_logs_chunks.iter().enumerate() {
+            anyhow::ensure!(
+                chunk.chunk_id == i as u64,
+                "snapshot header lists chunks out of order: chunk #{} at position {i}",
+                chunk.chunk_id
+            );
+        }
+        anyhow::ensure!(
+            !header.storage_logs_chunks.is_empty(),
+            "snapshot header doesn't list any storage log chunks"
+        );
+
+        Ok(Self {
+            l1_batch_number: header.l1_batch_number,
+            version,
+            chunk_count: header.storage_logs_chunks.len() as u64,
+            base_l1_batch_number: header.base_l1_batch_number,
+        })
+    }
+}
+
+/// Statistics for a single storage logs chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ChunkStats {
+    pub chunk_id: u64,
+    pub log_count: usize,
+    /// Range of hashed keys present in the chunk; `None` for empty chunks.
+    pub key_range: Option<(H256, H256)>,
+    /// Range of enumeration indices present in the chunk; `None` for empty chunks.
+    pub enumeration_index_range: Option<(u64, u64)>,
+    pub compressed_size: usize,
+}
+
+impl fmt::Display for ChunkStats {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "chunk #{}: {} logs, {} bytes",
+            self.chunk_id, self.log_count, self.compressed_size
+        )?;
+        if let Some((start, end)) = &self.key_range {
+            write!(formatter, ", keys {start:?}..={end:?}")?;
+        }
+        if let Some((start, end)) = &self.enumeration_index_range {
+            write!(formatter, ", enum indices {start}..={end}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of snapshot verification.
+#[derive(Debug, Default)]
+pub(crate) struct VerificationReport {
+    pub chunks: Vec<ChunkStats>,
+    pub factory_deps_count: usize,
+    pub factory_deps_total_size: usize,
+    /// Root hash of the Merkle tree rebuilt from the snapshot. `None` if the tree wasn't rebuilt
+    /// (e.g., for delta snapshots or if some chunks are broken).
+    pub root_hash: Option<H256>,
+    /// Human-readable descriptions of all problems encountered.
+    pub issues: Vec<String>,
+}
+
+impl VerificationReport {
+    pub fn total_log_count(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.log_count).sum()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn add_issue(&mut self, issue: String) {
+        tracing::warn!("{issue}");
+        self.issues.push(issue);
+    }
+}
+
+/// Verifies snapshot objects stored in an object store.
+#[derive(Debug)]
+pub(crate) struct SnapshotVerifier {
+    blob_store: Arc<dyn ObjectStore>,
+}
+
+impl SnapshotVerifier {
+    pub fn new(blob_store: Arc<dyn ObjectStore>) -> Self {
+        Self { blob_store }
+    }
+
+    /// Checks the snapshot with the specified layout. If `tree_path` is specified, the Merkle tree
+    /// is rebuilt from the snapshot storage logs in a RocksDB instance at this path.
+    ///
+    /// Returns an error only on internal / non-recoverable failures (e.g., a transient object store error).
+    /// Problems with the snapshot itself are recorded in the returned report.
+    pub async fn verify(
+        &self,
+        layout: SnapshotLayout,
+        tree_path: Option<&Path>,
+    ) -> anyhow::Result<VerificationReport> {
+        let mut report = VerificationReport::default();
+        self.verify_factory_deps(layout.l1_batch_number, &mut report)
+            .await?;
+
+        let mut tree = if let Some(path) = tree_path {
+            if let Some(base) = layout.base_l1_batch_number {
+                tracing::info!(
+                    "Snapshot for L1 batch #{} is a delta snapshot relative to L1 batch #{base}; \
+                     the Merkle tree cannot be rebuilt from it alone",
+                    layout.l1_batch_number
+                );
+                None
+            } else {
+                let db = RocksDBWrapper::new(path).context("failed initializing tree RocksDB")?;
+                Some(MerkleTreeRecovery::new(
+                    db,
+                    layout.l1_batch_number.0.into(),
+                )?)
+            }
+        } else {
+            None
+        };
+
+        for chunk_id in 0..layout.chunk_count {
+            let entries = self.verify_chunk(layout, chunk_id, &mut report).await?;
+            let Some(entries) = entries else {
+                // The chunk is broken, so the rebuilt tree would be incomplete anyway.
+                tree = None;
+                continue;
+            };
+            if let Some(tree) = &mut tree {
+                let started_at = Instant::now();
+                tree.extend_random(entries)
+                    .with_context(|| format!("failed extending tree with chunk #{chunk_id}"))?;
+                tracing::debug!(
+                    "Extended tree with chunk #{chunk_id} in {:?}",
+                    started_at.elapsed()
+                );
+            }
+        }
+
+        if let Some(tree) = tree {
+            report.root_hash = Some(tree.root_hash());
+            tree.finalize().context("failed finalizing tree recovery")?;
+        }
+        Ok(report)
+    }
+
+    async fn verify_factory_deps(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        report: &mut VerificationReport,
+    ) -> anyhow::Result<()> {
+        let factory_deps: SnapshotFactoryDependencies =
+            match self.blob_store.get(l1_batch_number).await {
+                Ok(deps) => deps,
+                Err(err) => {
+                    let err = Self::classify_error(err, "factory deps")?;
+                    report.add_issue(err);
+                    return Ok(());
+                }
+            };
+
+        report.factory_deps_count = factory_deps.factory_deps.len();
+        for dep in &factory_deps.factory_deps {
+            report.factory_deps_total_size += dep.bytecode.0.len();
+            let Some(hash) = dep.hash else {
+                // Old snapshots don't contain hashes; they are restored during recovery.
+                continue;
+            };
+            let parsed_hash = match BytecodeHash::try_from(hash) {
+                Ok(hash) => hash,
+                Err(err) => {
+                    report.add_issue(format!("factory dep has invalid hash {hash:?}: {err:#}"));
+                    continue;
+                }
+            };
+            let restored_hash = match parsed_hash.marker() {
+                BytecodeMarker::EraVm => BytecodeHash::for_bytecode(&dep.bytecode.0),
+                BytecodeMarker::Evm => {
+                    BytecodeHash::for_evm_bytecode(parsed_hash.len_in_bytes(), &dep.bytecode.0)
+                }
+            };
+            if restored_hash != parsed_hash {
+                report.add_issue(format!(
+                    "factory dep hash {hash:?} doesn't match the bytecode hash {:?}",
+                    restored_hash.value()
+                ));
+            }
+        }
+        tracing::info!(
+            "Verified {} factory deps ({} bytes in total)",
+            report.factory_deps_count,
+            report.factory_deps_total_size
+        );
+        Ok(())
+    }
+
+    /// Returns tree entries for the chunk, or `None` if the chunk is broken.
+    async fn verify_chunk(
+        &self,
+        layout: SnapshotLayout,
+        chunk_id: u64,
+        report: &mut VerificationReport,
+    ) -> anyhow::Result<Option<Vec<TreeEntry>>> {
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: layout.l1_batch_number,
+            chunk_id,
+        };
+        let object_name = format!("storage logs chunk #{chunk_id}");
+        let raw_object = match self
+            .blob_store
+            .get_raw(
+                SnapshotStorageLogsChunk::<H256>::BUCKET,
+                &SnapshotStorageLogsChunk::<H256>::encode_key(key),
+            )
+            .await
+        {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                report.add_issue(Self::classify_error(err, &object_name)?);
+                return Ok(None);
+            }
+        };
+        let compressed_size = raw_object.len();
+
+        let logs = match Self::decode_chunk(raw_object, layout.version) {
+            Ok(logs) => logs,
+            Err(err) => {
+                report.add_issue(format!("failed decoding {object_name}: {err:#}"));
+                return Ok(None);
+            }
+        };
+
+        let mut is_valid = true;
+        let expected_key_range = uniform_hashed_keys_chunk(chunk_id, layout.chunk_count);
+        let mut entries = Vec::with_capacity(logs.len());
+        for log in &logs {
+            if !expected_key_range.contains(&log.key) {
+                report.add_issue(format!(
+                    "{object_name} contains key {:?} outside its range {expected_key_range:?}",
+                    log.key
+                ));
+                is_valid = false;
+            }
+            if log.enumeration_index == 0 {
+                report.add_issue(format!(
+                    "{object_name} contains key {:?} with zero enumeration index",
+                    log.key
+                ));
+                is_valid = false;
+            }
+            if log.l1_batch_number_of_initial_write > layout.l1_batch_number {
+                report.add_issue(format!(
+                    "{object_name} contains key {:?} initially written in L1 batch #{}, \
+                     which is after the snapshot L1 batch",
+                    log.key, log.l1_batch_number_of_initial_write
+                ));
+                is_valid = false;
+            }
+            entries.push(TreeEntry::new(
+                h256_to_u256(log.key),
+                log.enumeration_index,
+                log.value,
+            ));
+        }
+
+        let mut sorted_keys: Vec<_> = logs.iter().map(|log| log.key).collect();
+        sorted_keys.sort_unstable();
+        if let Some(window) = sorted_keys.windows(2).find(|window| window[0] == window[1]) {
+            report.add_issue(format!(
+                "{object_name} contains duplicate key {:?}",
+                window[0]
+            ));
+            is_valid = false;
+        }
+
+        let stats = ChunkStats {
+            chunk_id,
+            log_count: logs.len(),
+            key_range: sorted_keys
+                .first()
+                .copied()
+                .zip(sorted_keys.last().copied()),
+            enumeration_index_range: logs
+                .iter()
+                .map(|log| log.enumeration_index)
+                .min()
+                .zip(logs.iter().map(|log| log.enumeration_index).max()),
+            compressed_size,
+        };
+        tracing::info!("Verified {stats}");
+        report.chunks.push(stats);
+        Ok(is_valid.then_some(entries))
+    }
+
+    fn decode_chunk(
+        raw_object: Vec<u8>,
+        version: SnapshotVersion,
+    ) -> anyhow::Result<Vec<SnapshotStorageLog>> {
+        Ok(match version {
+            SnapshotVersion::Version0 => {
+                let chunk = SnapshotStorageLogsChunk::<StorageKey>::deserialize(raw_object)
+                    .map_err(|err| anyhow::anyhow!(err))?;
+                chunk
+                    .storage_logs
+                    .into_iter()
+                    .map(SnapshotStorageLog::drop_key_preimage)
+                    .collect()
+            }
+            SnapshotVersion::Version1 => {
+                SnapshotStorageLogsChunk::<H256>::deserialize(raw_object)
+                    .map_err(|err| anyhow::anyhow!(err))?
+                    .storage_logs
+            }
+        })
+    }
+
+    /// Converts "not found" and serialization errors into issues and propagates other errors.
+    fn classify_error(err: ObjectStoreError, object_name: &str) -> anyhow::Result<String> {
+        match err {
+            ObjectStoreError::KeyNotFound(err) => {
+                Ok(format!("{object_name} is missing from object store: {err}"))
+            }
+            ObjectStoreError::Serialization(err) => {
+                Ok(format!("failed decoding {object_name}: {err}"))
+            }
+            err => Err(anyhow::Error::from(err).context(format!("failed fetching {object_name}"))),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Offline tool replaying VM dumps produced by the shadow VM or the API sandbox on VM divergences.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::{Parser, ValueEnum};
+use zksync_multivm::interface::utils::VmDump;
+
+use crate::{
+    replay::{replay_on_fast_vm, replay_on_legacy_vm},
+    report::ReplayReport,
+};
+
+mod replay;
+mod report;
+#[cfg(test)]
+mod tests;
+
+/// VM(s) to replay a dump on.
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum VmKind {
+    Legacy,
+    Fast,
+    Both,
+}
+
+impl VmKind {
+    fn includes_legacy(self) -> bool {
+        matches!(self, Self::Legacy | Self::Both)
+    }
+
+    fn includes_fast(self) -> bool {
+        matches!(self, Self::Fast | Self::Both)
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    author = "Matter Labs",
+    version,
+    about = "Replays VM dumps on the legacy and / or fast VM",
+    long_about = None
+)]
+struct Cli {
+    /// Path to the VM dump in the JSON format (e.g., downloaded from the `vm_dumps` object store bucket).
+    dump: PathBuf,
+    /// VM(s) to replay the dump on. If both VMs are selected, their outputs are compared for each transaction.
+    #[arg(long, value_enum, default_value_t = VmKind::Both)]
+    vm: VmKind,
+    /// Collects call traces for all transactions. If both VMs are selected, call traces are compared as well.
+    #[arg(long)]
+    call_tracer: bool,
+    /// Path to write the full report in the JSON format to. The report includes call traces if they are collected.
+    #[arg(long)]
+    output_json: Option<PathBuf>,
+}
+
+impl Cli {
+    fn run(self) -> anyhow::Result<()> {
+        let raw_dump = fs::read(&self.dump)
+            .with_context(|| format!("failed reading VM dump from `{}`", self.dump.display()))?;
+        let dump: VmDump =
+            serde_json::from_slice(&raw_dump).context("failed deserializing VM dump")?;
+        tracing::info!(
+            "Loaded VM dump for L1 batch #{} (protocol version {:?}) with {} L2 block(s)",
+            dump.l1_batch_number(),
+            dump.system_env.version,
+            dump.l2_blocks.len()
+        );
+
+        let legacy_outputs = if self.vm.includes_legacy() {
+            tracing::info!("Replaying dump on the legacy VM");
+            Some(replay_on_legacy_vm(dump.clone(), self.call_tracer))
+        } else {
+            None
+        };
+        let fast_outputs = if self.vm.includes_fast() {
+            tracing::info!("Replaying dump on the fast VM");
+            Some(replay_on_fast_vm(dump.clone(), self.call_tracer)?)
+        } else {
+            None
+        };
+
+        let report = ReplayReport::new(&dump, legacy_outputs, fast_outputs);
+        print!("{report}");
+        if let Some(path) = &self.output_json {
+            let json = serde_json::to_vec_pretty(&report).context("failed serializing report")?;
+            fs::write(path, json)
+                .with_context(|| format!("failed writing report to `{}`", path.display()))?;
+            tracing::info!("Saved JSON report to `{}`", path.display());
+        }
+
+        let diverged_tx_count = report.diverged_tx_count();
+        anyhow::ensure!(
+            diverged_tx_count == 0,
+            "VM execution diverged for {diverged_tx_count} transaction(s)"
+        );
+        Ok(())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let _observability_guard = zksync_vlog::ObservabilityBuilder::new().build();
+    Cli::parse().run()
+}
@@ -0,0 +1,127 @@
+//! Replaying VM dumps transaction by transaction.
+
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+use zksync_multivm::{
+    interface::{
+        storage::{ImmutableStorageView, StorageSnapshot, StorageView},
+        utils::VmDump,
+        Call, L2BlockEnv, VmExecutionResultAndLogs, VmFactory, VmInterface,
+    },
+    is_supported_by_fast_vm,
+    tracers::CallTracer,
+    vm_fast::{self, FastValidationTracer},
+    vm_latest::HistoryDisabled,
+    LegacyVmInstance, MultiVmTracer,
+};
+use zksync_types::{L2BlockNumber, Transaction, H256};
+
+type FastVm<Tr> = vm_fast::Vm<ImmutableStorageView<StorageSnapshot>, Tr, FastValidationTracer>;
+
+/// Output of a single transaction replayed from a VM dump.
+#[derive(Debug)]
+pub(crate) struct TxOutput {
+    pub l2_block_number: L2BlockNumber,
+    pub tx_hash: H256,
+    /// Error compressing bytecodes of the transaction, if any.
+    pub compression_error: Option<String>,
+    pub result: VmExecutionResultAndLogs,
+    /// Only collected if call tracing is enabled.
+    pub call_traces: Option<Vec<Call>>,
+}
+
+/// Replays the dump on the VM using the provided closure to execute each transaction. Unlike [`VmDump::play_back()`],
+/// doesn't panic on bytecode compression errors and returns outputs for all executed transactions.
+fn replay<Vm>(
+    dump: VmDump,
+    mut execute_tx: impl FnMut(
+        &mut Vm,
+        Transaction,
+    ) -> (Option<String>, VmExecutionResultAndLogs, Option<Vec<Call>>),
+) -> Vec<TxOutput>
+where
+    Vm: VmFactory<StorageView<StorageSnapshot>>,
+{
+    let storage = StorageView::new(dump.storage).to_rc_ptr();
+    let mut vm = Vm::new(dump.l1_batch_env, dump.system_env, storage);
+    let mut outputs = vec![];
+
+    for (i, l2_block) in dump.l2_blocks.into_iter().enumerate() {
+        if i > 0 {
+            // First block is already set.
+            vm.start_new_l2_block(L2BlockEnv {
+                number: l2_block.number.0,
+                timestamp: l2_block.timestamp,
+                prev_block_hash: l2_block.prev_block_hash,
+                max_virtual_blocks_to_create: l2_block.virtual_blocks,
+            });
+        }
+
+        for tx in l2_block.txs {
+            let tx_hash = tx.hash();
+            let (compression_error, result, call_traces) = execute_tx(&mut vm, tx);
+            if let Some(err) = &compression_error {
+                tracing::warn!("Failed compressing bytecodes for transaction {tx_hash:?}: {err}");
+            }
+            outputs.push(TxOutput {
+                l2_block_number: l2_block.number,
+                tx_hash,
+                compression_error,
+                result,
+                call_traces,
+            });
+        }
+    }
+    outputs
+}
+
+/// Replays the dump on the legacy VM of the version corresponding to the dumped protocol version.
+pub(crate) fn replay_on_legacy_vm(dump: VmDump, trace_calls: bool) -> Vec<TxOutput> {
+    replay::<LegacyVmInstance<StorageSnapshot, HistoryDisabled>>(dump, |vm, tx| {
+        let calls_result = Arc::new(OnceCell::default());
+        let tracers = if trace_calls {
+            vec![CallTracer::new(calls_result.clone()).into_tracer_pointer()]
+        } else {
+            vec![]
+        };
+        let mut tracer = tracers.into();
+        let (compression_result, result) =
+            vm.inspect_transaction_with_bytecode_compression(&mut tracer, tx, true);
+        let compression_error = compression_result.err().map(|err| err.to_string());
+        let call_traces = trace_calls.then(|| calls_result.get().cloned().unwrap_or_default());
+        (compression_error, result, call_traces)
+    })
+}
+
+/// Replays the dump on the fast VM. Errors if the dumped protocol version is not supported by the fast VM.
+pub(crate) fn replay_on_fast_vm(dump: VmDump, trace_calls: bool) -> anyhow::Result<Vec<TxOutput>> {
+    let protocol_version = dump.system_env.version;
+    anyhow::ensure!(
+        is_supported_by_fast_vm(protocol_version),
+        "protocol version {protocol_version:?} is not supported by the fast VM"
+    );
+
+    Ok(if trace_calls {
+        replay_on_fast_vm_with::<vm_fast::CallTracer>(dump, |tracer| Some(tracer.into_result()))
+    } else {
+        replay_on_fast_vm_with::<()>(dump, |()| None)
+    })
+}
+
+fn replay_on_fast_vm_with<Tr>(
+    dump: VmDump,
+    into_call_traces: fn(Tr) -> Option<Vec<Call>>,
+) -> Vec<TxOutput>
+where
+    Tr: vm_fast::interface::Tracer + Default,
+{
+    replay::<FastVm<Tr>>(dump, |vm, tx| {
+        let mut tracer = (Tr::default(), FastValidationTracer::default());
+        let (compression_result, result) =
+            vm.inspect_transaction_with_bytecode_compression(&mut tracer, tx, true);
+        let compression_error = compression_result.err().map(|err| err.to_string());
+        let (call_tracer, _) = tracer;
+        (compression_error, result, into_call_traces(call_tracer))
+    })
+}
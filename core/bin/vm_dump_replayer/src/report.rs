@@ -0,0 +1,206 @@
+//! Replay report comparing outputs of the legacy and fast VMs.
+
+use std::fmt;
+
+use serde::Serialize;
+use zksync_multivm::interface::{
+    utils::{CheckDivergence, Divergence, VmDump},
+    Call,
+};
+use zksync_types::{L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256};
+
+use crate::replay::TxOutput;
+
+/// Summary of a transaction execution on a single VM.
+#[derive(Debug, Serialize)]
+pub(crate) struct TxSummary {
+    result: String,
+    gas_used: u64,
+    gas_refunded: u64,
+    operator_suggested_refund: u64,
+    events: usize,
+    storage_writes: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compression_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    call_traces: Option<Vec<Call>>,
+}
+
+impl From<&TxOutput> for TxSummary {
+    fn from(output: &TxOutput) -> Self {
+        let result = &output.result;
+        Self {
+            result: format!("{:?}", result.result),
+            gas_used: result.statistics.gas_used,
+            gas_refunded: result.refunds.gas_refunded,
+            operator_suggested_refund: result.refunds.operator_suggested_refund,
+            events: result.logs.events.len(),
+            storage_writes: result
+                .logs
+                .storage_logs
+                .iter()
+                .filter(|log| log.log.is_write())
+                .count(),
+            compression_error: output.compression_error.clone(),
+            call_traces: output.call_traces.clone(),
+        }
+    }
+}
+
+impl fmt::Display for TxSummary {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{}; gas used: {}, gas refunded: {}, operator suggested refund: {}, events: {}, storage writes: {}",
+            self.result,
+            self.gas_used,
+            self.gas_refunded,
+            self.operator_suggested_refund,
+            self.events,
+            self.storage_writes
+        )?;
+        if let Some(calls) = &self.call_traces {
+            write!(formatter, ", top-level calls: {}", calls.len())?;
+        }
+        if let Some(err) = &self.compression_error {
+            write!(formatter, ", bytecode compression error: {err}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Report for a single replayed transaction.
+#[derive(Debug, Serialize)]
+pub(crate) struct TxReport {
+    l2_block_number: L2BlockNumber,
+    tx_hash: H256,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    legacy: Option<TxSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fast: Option<TxSummary>,
+    /// Divergences between the legacy (main) and fast (shadow) VM outputs. Only computed if the transaction
+    /// was replayed on both VMs.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    divergences: Vec<Divergence>,
+}
+
+impl TxReport {
+    fn new(legacy: Option<TxOutput>, fast: Option<TxOutput>) -> Self {
+        let (l2_block_number, tx_hash) = legacy
+            .as_ref()
+            .or(fast.as_ref())
+            .map(|output| (output.l2_block_number, output.tx_hash))
+            .expect("transaction must be replayed on at least one VM");
+
+        let mut divergences = vec![];
+        if let (Some(legacy), Some(fast)) = (&legacy, &fast) {
+            assert_eq!(
+                legacy.tx_hash, fast.tx_hash,
+                "replayed transactions mismatch"
+            );
+
+            let mut errors = legacy.result.check_divergence(&fast.result);
+            if let (Some(legacy_calls), Some(fast_calls)) = (&legacy.call_traces, &fast.call_traces)
+            {
+                errors.extend(
+                    legacy_calls
+                        .as_slice()
+                        .check_divergence(fast_calls.as_slice()),
+                );
+            }
+            divergences.extend_from_slice(errors.divergences());
+
+            if legacy.compression_error != fast.compression_error {
+                divergences.push(Divergence {
+                    field: "compression_error".to_owned(),
+                    diff: format!(
+                        "{:?} vs {:?}",
+                        legacy.compression_error, fast.compression_error
+                    ),
+                });
+            }
+        }
+
+        Self {
+            l2_block_number,
+            tx_hash,
+            legacy: legacy.as_ref().map(TxSummary::from),
+            fast: fast.as_ref().map(TxSummary::from),
+            divergences,
+        }
+    }
+}
+
+/// Full report produced by replaying a VM dump.
+#[derive(Debug, Serialize)]
+pub(crate) struct ReplayReport {
+    l1_batch_number: L1BatchNumber,
+    protocol_version: ProtocolVersionId,
+    transactions: Vec<TxReport>,
+}
+
+impl ReplayReport {
+    pub fn new(
+        dump: &VmDump,
+        legacy_outputs: Option<Vec<TxOutput>>,
+        fast_outputs: Option<Vec<TxOutput>>,
+    ) -> Self {
+        let tx_count = legacy_outputs
+            .as_ref()
+            .or(fast_outputs.as_ref())
+            .map_or(0, Vec::len);
+        let mut legacy_outputs = legacy_outputs.map(Vec::into_iter);
+        let mut fast_outputs = fast_outputs.map(Vec::into_iter);
+        let transactions = (0..tx_count)
+            .map(|_| {
+                let legacy = legacy_outputs.as_mut().and_then(Iterator::next);
+                let fast = fast_outputs.as_mut().and_then(Iterator::next);
+                TxReport::new(legacy, fast)
+            })
+            .collect();
+
+        Self {
+            l1_batch_number: dump.l1_batch_number(),
+            protocol_version: dump.system_env.version,
+            transactions,
+        }
+    }
+
+    pub fn diverged_tx_count(&self) -> usize {
+        self.transactions
+            .iter()
+            .filter(|tx| !tx.divergences.is_empty())
+            .count()
+    }
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "L1 batch #{} (protocol version {:?}): replayed {} transaction(s), {} diverged",
+            self.l1_batch_number,
+            self.protocol_version,
+            self.transactions.len(),
+            self.diverged_tx_count()
+        )?;
+
+        for tx in &self.transactions {
+            writeln!(
+                formatter,
+                "\nL2 block #{}, transaction {:?}",
+                tx.l2_block_number, tx.tx_hash
+            )?;
+            if let Some(legacy) = &tx.legacy {
+                writeln!(formatter, "  legacy VM: {legacy}")?;
+            }
+            if let Some(fast) = &tx.fast {
+                writeln!(formatter, "  fast VM: {fast}")?;
+            }
+            for divergence in &tx.divergences {
+                writeln!(formatter, "  diverged {divergence}")?;
+            }
+        }
+        Ok(())
+    }
+}
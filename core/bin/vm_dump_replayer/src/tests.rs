@@ -0,0 +1,51 @@
+use std::{fs, path::Path};
+
+use zksync_multivm::interface::{utils::VmDump, ExecutionResult};
+
+use super::{
+    replay::{replay_on_fast_vm, replay_on_legacy_vm},
+    report::ReplayReport,
+};
+
+fn load_vm_dump(name: &str) -> VmDump {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../lib/multivm/tests/vm_dumps")
+        .join(format!("{name}.json"));
+    let raw = fs::read_to_string(path).unwrap();
+    serde_json::from_str(&raw).unwrap()
+}
+
+#[test]
+fn replaying_dump_on_both_vms() {
+    let dump = load_vm_dump("estimate_fee_for_transfer_to_self");
+    let legacy_outputs = replay_on_legacy_vm(dump.clone(), false);
+    let fast_outputs = replay_on_fast_vm(dump.clone(), false).unwrap();
+    assert_eq!(legacy_outputs.len(), 1);
+    assert_eq!(fast_outputs.len(), 1);
+    assert!(
+        matches!(
+            legacy_outputs[0].result.result,
+            ExecutionResult::Revert { .. }
+        ),
+        "{:?}",
+        legacy_outputs[0].result.result
+    );
+    assert!(legacy_outputs[0].call_traces.is_none());
+
+    let report = ReplayReport::new(&dump, Some(legacy_outputs), Some(fast_outputs));
+    assert_eq!(report.diverged_tx_count(), 0, "{report}");
+    serde_json::to_string(&report).unwrap();
+}
+
+#[test]
+fn replaying_dump_with_call_tracer() {
+    let dump = load_vm_dump("validation_adjacent_storage_slots");
+    let outputs = replay_on_legacy_vm(dump.clone(), true);
+    assert!(!outputs.is_empty());
+    assert!(outputs.iter().all(|output| output.call_traces.is_some()));
+
+    let outputs = replay_on_fast_vm(dump.clone(), true).unwrap();
+    assert!(outputs.iter().all(|output| output.call_traces.is_some()));
+    let report = ReplayReport::new(&dump, None, Some(outputs));
+    assert_eq!(report.diverged_tx_count(), 0);
+}
@@ -0,0 +1,155 @@
+//! Hot reload of the dynamic part of the node configuration.
+
+use std::{path::PathBuf, time::Duration};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_config::configs::dynamic::DynamicConfig;
+use zksync_core_leftovers::temp_config_store::read_yaml_repr;
+use zksync_node_framework::{
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+use zksync_shared_resources::dynamic_config::DynamicConfigHandle;
+
+/// Task watching the general config file and applying changes in it to the [`DynamicConfig`].
+///
+/// The file is polled for modifications. Changes to params outside [`DynamicConfig`] are ignored
+/// (they still require a node restart to take effect).
+#[derive(Debug)]
+pub(crate) struct ConfigReloader {
+    path: PathBuf,
+    poll_interval: Duration,
+    handle: DynamicConfigHandle,
+}
+
+impl ConfigReloader {
+    fn load(&self) -> anyhow::Result<DynamicConfig> {
+        let config =
+            read_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(&self.path)?;
+        Ok(DynamicConfig::from_general_config(&config))
+    }
+
+    async fn modified_at(&self) -> anyhow::Result<std::time::SystemTime> {
+        let metadata = tokio::fs::metadata(&self.path)
+            .await
+            .with_context(|| format!("failed getting metadata for {}", self.path.display()))?;
+        metadata
+            .modified()
+            .context("failed getting modification time")
+    }
+
+    async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let source = self.path.display().to_string();
+        let mut last_modified = self.modified_at().await.ok();
+        tracing::info!(
+            "Watching config file {source} for dynamic config updates with {:?} interval",
+            self.poll_interval
+        );
+
+        while !*stop_receiver.borrow_and_update() {
+            match self.modified_at().await {
+                Ok(modified) if last_modified != Some(modified) => {
+                    last_modified = Some(modified);
+                    match self.load() {
+                        Ok(new_config) => {
+                            // Errors are logged by `update()`; the node continues running with the previous config.
+                            self.handle.update(new_config, &source).ok();
+                        }
+                        Err(err) => {
+                            tracing::warn!("Rejected dynamic config update from {source}: {err:#}");
+                        }
+                    }
+                }
+                Ok(_) => { /* The file wasn't modified */ }
+                Err(err) => {
+                    tracing::warn!("Failed checking config file for updates: {err:#}");
+                }
+            }
+
+            tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop request received, config reloader is shut down");
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for ConfigReloader {
+    fn id(&self) -> TaskId {
+        "config_reloader".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
+
+/// Wiring layer for [`ConfigReloader`].
+///
+/// If the config path is not specified, the layer only provides the [`DynamicConfigHandle`] without watching
+/// the config file; the dynamic config can still be updated by other components (e.g., the admin API).
+///
+/// ## Adds resources
+///
+/// - `DynamicConfigHandle`
+///
+/// ## Adds tasks
+///
+/// - `ConfigReloader` (if the config path is specified)
+#[derive(Debug)]
+pub(crate) struct ConfigReloaderLayer {
+    path: Option<PathBuf>,
+    initial_config: DynamicConfig,
+    poll_interval: Duration,
+}
+
+impl ConfigReloaderLayer {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn new(path: Option<PathBuf>, initial_config: DynamicConfig) -> Self {
+        Self {
+            path,
+            initial_config,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = zksync_node_framework)]
+pub(crate) struct Output {
+    dynamic_config: DynamicConfigHandle,
+    #[context(task)]
+    reloader: Option<ConfigReloader>,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ConfigReloaderLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "config_reloader_layer"
+    }
+
+    async fn wire(self, (): Self::Input) -> Result<Self::Output, WiringError> {
+        self.initial_config
+            .validate()
+            .map_err(|err| WiringError::Configuration(format!("{err:#}")))?;
+        let dynamic_config = DynamicConfigHandle::new(self.initial_config);
+        let reloader = self.path.map(|path| ConfigReloader {
+            path,
+            poll_interval: self.poll_interval,
+            handle: dynamic_config.clone(),
+        });
+        Ok(Output {
+            dynamic_config,
+            reloader,
+        })
+    }
+}
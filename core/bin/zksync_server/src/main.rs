@@ -10,11 +10,12 @@ use zksync_config::{
             TimestampAsserterConfig,
         },
         house_keeper::HouseKeeperConfig,
-        BasicWitnessInputProducerConfig, ContractVerifierSecrets, DataAvailabilitySecrets,
-        DatabaseSecrets, ExperimentalVmConfig, ExternalPriceApiClientConfig,
-        FriProofCompressorConfig, FriProverConfig, FriProverGatewayConfig,
-        FriWitnessGeneratorConfig, L1Secrets, ObservabilityConfig, PrometheusConfig,
-        ProofDataHandlerConfig, ProtectiveReadsWriterConfig, Secrets, TeeProofDataHandlerConfig,
+        AdminApiSecrets, BasicWitnessInputProducerConfig, ContractVerifierSecrets,
+        DataAvailabilitySecrets, DatabaseSecrets, ExperimentalVmConfig,
+        ExternalPriceApiClientConfig, FriProofCompressorConfig, FriProverConfig,
+        FriProverGatewayConfig, FriWitnessGeneratorConfig, L1Secrets, ObservabilityConfig,
+        PrometheusConfig, ProofDataHandlerConfig, ProtectiveReadsWriterConfig, Secrets,
+        TeeProofDataHandlerConfig,
     },
     ApiConfig, BaseTokenAdjusterConfig, ContractVerifierConfig, ContractsConfig, DAClientConfig,
     DADispatcherConfig, DBConfig, EthConfig, EthWatchConfig, ExternalProofIntegrationApiConfig,
@@ -25,10 +26,12 @@ use zksync_core_leftovers::{
     Component, Components,
 };
 use zksync_env_config::FromEnv;
+use zksync_node_framework::service::WiringGraphFormat;
 
 use crate::node_builder::MainNodeBuilder;
 
 mod config;
+mod config_reloader;
 mod node_builder;
 
 #[cfg(not(target_env = "msvc"))]
@@ -49,6 +52,10 @@ struct Cli {
     /// Path to the yaml config. If set, it will be used instead of env vars.
     #[arg(long)]
     config_path: Option<std::path::PathBuf>,
+    /// Watch the yaml config for changes and apply updates to the dynamic part of the config
+    /// (e.g., gas pricing params and seal criteria) without restarting the node.
+    #[arg(long, requires = "config_path")]
+    watch_config: bool,
     /// Path to the yaml with secrets. If set, it will be used instead of env vars.
     #[arg(long)]
     secrets_path: Option<std::path::PathBuf>,
@@ -70,6 +77,17 @@ struct Cli {
     /// Can be used to catch issues with configuration.
     #[arg(long, conflicts_with = "genesis")]
     no_run: bool,
+    /// Wire the node with the provided list of the components without starting any tasks, and then exit.
+    /// Unlike `--no-run`, this runs all wiring layers and reports any wiring errors.
+    #[arg(long, conflicts_with_all = ["genesis", "no_run"])]
+    dry_run: bool,
+    /// Path to export the resolved wiring graph of the node to. The graph is exported after wiring,
+    /// even if wiring fails.
+    #[arg(long, conflicts_with = "genesis")]
+    export_wiring_graph: Option<std::path::PathBuf>,
+    /// Format of the exported wiring graph: `json` or `dot` (Graphviz).
+    #[arg(long, default_value = "json", requires = "export_wiring_graph")]
+    wiring_graph_format: WiringGraphFormat,
 }
 
 #[derive(Debug, Clone)]
@@ -94,17 +112,15 @@ fn main() -> anyhow::Result<()> {
     // Load env config and use it if file config is not provided
     let tmp_config = load_env_config()?;
 
-    let configs = match opt.config_path {
+    let configs = match &opt.config_path {
         None => {
             let mut configs = tmp_config.general();
             configs.consensus_config =
                 config::read_consensus_config().context("read_consensus_config()")?;
             configs
         }
-        Some(path) => {
-            read_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(&path)
-                .context("failed decoding general YAML config")?
-        }
+        Some(path) => read_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(path)
+            .context("failed decoding general YAML config")?,
     };
 
     let wallets = match opt.wallets_path {
@@ -122,6 +138,7 @@ fn main() -> anyhow::Result<()> {
             l1: L1Secrets::from_env().ok(),
             data_availability: DataAvailabilitySecrets::from_env().ok(),
             contract_verifier: ContractVerifierSecrets::from_env().ok(),
+            admin_api: AdminApiSecrets::from_env().ok(),
         },
     };
 
@@ -141,7 +158,7 @@ fn main() -> anyhow::Result<()> {
         .clone()
         .context("observability config")?;
 
-    let node = MainNodeBuilder::new(
+    let mut node = MainNodeBuilder::new(
         configs,
         wallets,
         genesis,
@@ -154,6 +171,11 @@ fn main() -> anyhow::Result<()> {
         Some(contracts_config.settlement_layer_specific_contracts()),
         Some(contracts_config.l1_multicall3_addr),
     )?;
+    if opt.watch_config {
+        // `requires` constraint on the CLI arg guarantees that the path is set
+        let config_path = opt.config_path.context("no config path")?;
+        node = node.with_config_watch(config_path);
+    }
 
     let observability_guard = {
         // Observability initialization should be performed within tokio context.
@@ -167,12 +189,21 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let node = node.build(opt.components.0)?;
+    let mut node = node.build(opt.components.0)?;
 
     if opt.no_run {
         tracing::info!("Node composed successfully; exiting due to --no-run flag");
         return Ok(());
     }
+    if let Some(path) = opt.export_wiring_graph {
+        node.export_wiring_graph(path, opt.wiring_graph_format);
+    }
+    if opt.dry_run {
+        let (_wiring_graph, result) = node.dry_run();
+        result?;
+        tracing::info!("Node wired successfully; exiting due to --dry-run flag");
+        return Ok(());
+    }
 
     node.run(observability_guard)?;
     Ok(())
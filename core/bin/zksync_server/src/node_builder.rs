@@ -1,7 +1,7 @@
 //! This module provides a "builder" for the main node,
 //! as well as an interface to run the node with the specified components.
 
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use anyhow::{bail, Context};
 use zksync_base_token_adjuster::node::{
@@ -17,6 +17,7 @@ use zksync_config::{
             chain::L2Contracts, ecosystem::L1SpecificContracts, SettlementLayerSpecificContracts,
         },
         da_client::DAClientConfig,
+        dynamic::DynamicConfig,
         secrets::DataAvailabilitySecrets,
         wallets::Wallets,
         GeneralConfig, Secrets,
@@ -27,7 +28,7 @@ use zksync_contract_verification_server::node::ContractVerificationApiLayer;
 use zksync_core_leftovers::Component;
 use zksync_da_clients::node::{
     AvailWiringLayer, CelestiaWiringLayer, EigenWiringLayer, NoDAClientWiringLayer,
-    ObjectStorageClientWiringLayer,
+    ObjectStorageClientWiringLayer, SidecarWiringLayer,
 };
 use zksync_da_dispatcher::node::DataAvailabilityDispatcherLayer;
 use zksync_dal::node::{PoolsLayerBuilder, PostgresMetricsLayer};
@@ -47,9 +48,9 @@ use zksync_metadata_calculator::{
 };
 use zksync_node_api_server::{
     node::{
-        DeploymentAllowListLayer, HealthCheckLayer, MasterPoolSinkLayer, MempoolCacheLayer,
-        PostgresStorageCachesConfig, TxSenderLayer, Web3ServerLayer, Web3ServerOptionalConfig,
-        WhitelistedMasterPoolSinkLayer,
+        AdminApiLayer, DeploymentAllowListLayer, HealthCheckLayer, MasterPoolSinkLayer,
+        MempoolCacheLayer, PostgresStorageCachesConfig, TxSenderLayer, Web3ServerLayer,
+        Web3ServerOptionalConfig, WhitelistedMasterPoolSinkLayer,
     },
     tx_sender::TxSenderConfig,
     web3::{state::InternalApiConfigBase, Namespace},
@@ -80,6 +81,8 @@ use zksync_vm_runner::node::{
     BasicWitnessInputProducerLayer, ProtectiveReadsWriterLayer, VmPlaygroundLayer,
 };
 
+use crate::config_reloader::ConfigReloaderLayer;
+
 /// Macro that looks into a path to fetch an optional config,
 /// and clones it into a variable.
 macro_rules! try_load_config {
@@ -100,6 +103,8 @@ pub struct MainNodeBuilder {
     l1_sl_contracts: Option<SettlementLayerSpecificContracts>,
     l2_contracts: L2Contracts,
     multicall3: Option<Address>,
+    /// Path to the general config file to watch for dynamic config updates.
+    config_watch_path: Option<PathBuf>,
 }
 
 impl MainNodeBuilder {
@@ -124,9 +129,16 @@ impl MainNodeBuilder {
             l1_sl_contracts,
             l2_contracts,
             multicall3,
+            config_watch_path: None,
         })
     }
 
+    /// Enables watching the general config file at the specified path for dynamic config updates.
+    pub fn with_config_watch(mut self, path: PathBuf) -> Self {
+        self.config_watch_path = Some(path);
+        self
+    }
+
     pub fn runtime_handle(&self) -> tokio::runtime::Handle {
         self.node.runtime_handle()
     }
@@ -144,6 +156,7 @@ impl MainNodeBuilder {
                 DAClientConfig::Celestia(_) => PubdataType::Celestia,
                 DAClientConfig::Eigen(_) => PubdataType::Eigen,
                 DAClientConfig::ObjectStore(_) => PubdataType::ObjectStore,
+                DAClientConfig::Sidecar(_) => PubdataType::Sidecar,
                 DAClientConfig::NoDA => PubdataType::NoDA,
             }),
         }
@@ -154,6 +167,22 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_config_reloader_layer(mut self) -> anyhow::Result<Self> {
+        // The admin API updates the dynamic config, so the config handle is required even if the config file isn't watched.
+        let admin_api_enabled = self
+            .configs
+            .api_config
+            .as_ref()
+            .is_some_and(|config| config.admin.is_some());
+        if self.config_watch_path.is_some() || admin_api_enabled {
+            let path = self.config_watch_path.clone();
+            let initial_config = DynamicConfig::from_general_config(&self.configs);
+            self.node
+                .add_layer(ConfigReloaderLayer::new(path, initial_config));
+        }
+        Ok(self)
+    }
+
     fn add_pools_layer(mut self) -> anyhow::Result<Self> {
         let config = try_load_config!(self.configs.postgres_config);
         let secrets = try_load_config!(self.secrets.database);
@@ -344,11 +373,19 @@ impl MainNodeBuilder {
 
     fn add_proof_data_handler_layer(mut self) -> anyhow::Result<Self> {
         let gateway_config = try_load_config!(self.configs.prover_gateway);
+        // Proofs are verified against the same verifier type that `eth_sender` submits them to.
+        let is_verifier_pre_fflonk = self
+            .configs
+            .eth
+            .as_ref()
+            .and_then(|eth| eth.get_eth_sender_config_for_sender_layer_data_layer())
+            .map_or(true, |sender| sender.is_verifier_pre_fflonk);
         self.node.add_layer(ProofDataHandlerLayer::new(
             try_load_config!(self.configs.proof_data_handler_config),
             self.genesis_config.l1_batch_commit_data_generator_mode,
             self.genesis_config.l2_chain_id,
             gateway_config.api_mode,
+            is_verifier_pre_fflonk,
         ));
         Ok(self)
     }
@@ -362,6 +399,24 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_admin_api_layer(mut self) -> anyhow::Result<Self> {
+        let Some(config) = self
+            .configs
+            .api_config
+            .as_ref()
+            .and_then(|config| config.admin.clone())
+        else {
+            return Ok(self);
+        };
+        let secrets = self
+            .secrets
+            .admin_api
+            .clone()
+            .context("admin API is enabled, but its auth token is not set in secrets")?;
+        self.node.add_layer(AdminApiLayer::new(config, secrets));
+        Ok(self)
+    }
+
     fn add_healthcheck_layer(mut self) -> anyhow::Result<Self> {
         let healthcheck_config = try_load_config!(self.configs.api_config).healthcheck;
         self.node.add_layer(HealthCheckLayer(healthcheck_config));
@@ -548,6 +603,25 @@ impl MainNodeBuilder {
     }
 
     fn add_eth_tx_aggregator_layer(mut self) -> anyhow::Result<Self> {
+        let require_tee_finalization = self
+            .configs
+            .eth
+            .as_ref()
+            .and_then(|config| config.get_eth_sender_config_for_sender_layer_data_layer())
+            .is_some_and(|config| config.require_tee_finalization);
+        if require_tee_finalization {
+            // Without a quorum policy, batches are never marked as TEE-finalized, so execution would be blocked forever.
+            let has_tee_quorum = self
+                .configs
+                .tee_proof_data_handler_config
+                .as_ref()
+                .is_some_and(|config| config.quorum.is_some());
+            anyhow::ensure!(
+                has_tee_quorum,
+                "`eth.sender.require_tee_finalization` is set, but `tee_proof_data_handler.quorum` is not configured"
+            );
+        }
+
         self.node.add_layer(EthTxAggregatorLayer::new(
             self.genesis_config.l2_chain_id,
             self.genesis_config.l1_batch_commit_data_generator_mode,
@@ -581,7 +655,10 @@ impl MainNodeBuilder {
 
     fn add_contract_verification_api_layer(mut self) -> anyhow::Result<Self> {
         let config = try_load_config!(self.configs.contract_verifier);
-        self.node.add_layer(ContractVerificationApiLayer(config));
+        self.node.add_layer(ContractVerificationApiLayer::new(
+            config,
+            self.genesis_config.l2_chain_id,
+        ));
         Ok(self)
     }
 
@@ -631,6 +708,11 @@ impl MainNodeBuilder {
             return Ok(self);
         }
 
+        if let DAClientConfig::Sidecar(config) = da_client_config {
+            self.node.add_layer(SidecarWiringLayer::new(config));
+            return Ok(self);
+        }
+
         let da_client_secrets = try_load_config!(self.secrets.data_availability);
         match (da_client_config, da_client_secrets) {
             (DAClientConfig::Avail(config), DataAvailabilitySecrets::Avail(secret)) => {
@@ -790,6 +872,7 @@ impl MainNodeBuilder {
         // Add "base" layers (resources and helper tasks).
         self = self
             .add_sigint_handler_layer()?
+            .add_config_reloader_layer()?
             .add_pools_layer()?
             .add_object_store_layer()?
             .add_circuit_breaker_checker_layer()?
@@ -915,6 +998,8 @@ impl MainNodeBuilder {
                 }
             }
         }
+        // The admin API uses resources provided by component layers (e.g., state keeper control), so it's added last.
+        self = self.add_admin_api_layer()?;
         Ok(self.node.build())
     }
 }
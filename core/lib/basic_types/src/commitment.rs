@@ -24,7 +24,8 @@ impl From<PubdataType> for L1BatchCommitmentMode {
             | PubdataType::Avail
             | PubdataType::Celestia
             | PubdataType::Eigen
-            | PubdataType::ObjectStore => L1BatchCommitmentMode::Validium,
+            | PubdataType::ObjectStore
+            | PubdataType::Sidecar => L1BatchCommitmentMode::Validium,
         }
     }
 }
@@ -80,6 +81,7 @@ pub enum PubdataType {
     Celestia,
     Eigen,
     ObjectStore,
+    Sidecar,
 }
 
 impl FromStr for PubdataType {
@@ -93,7 +95,8 @@ impl FromStr for PubdataType {
             "Celestia" => Ok(Self::Celestia),
             "Eigen" => Ok(Self::Eigen),
             "ObjectStore" => Ok(Self::ObjectStore),
-            _ => Err("Incorrect DA client type; expected one of `Rollup`, `NoDA`, `Avail`, `Celestia`, `Eigen`, `ObjectStore`"),
+            "Sidecar" => Ok(Self::Sidecar),
+            _ => Err("Incorrect DA client type; expected one of `Rollup`, `NoDA`, `Avail`, `Celestia`, `Eigen`, `ObjectStore`, `Sidecar`"),
         }
     }
 }
@@ -28,16 +28,32 @@ pub struct DataAvailabilityBlob {
     pub dispatch_request_id: String,
     pub blob_id: Option<String>,
     pub inclusion_data: Option<Vec<u8>>,
+    /// Number of chunks if the pubdata was dispatched as several blobs. In this case, `dispatch_request_id`
+    /// and `blob_id` are not set; the corresponding values are stored for each chunk separately.
+    pub chunk_count: Option<u32>,
     pub sent_at: DateTime<Utc>,
 }
 
+/// Represents a chunk of pubdata that was dispatched to the data availability layer as a separate blob
+/// because the pubdata of the batch exceeds the blob size limit of the DA layer.
+#[derive(Debug, Clone)]
+pub struct DataAvailabilityChunk {
+    pub chunk_index: u32,
+    pub dispatch_request_id: String,
+    pub blob_id: Option<String>,
+    pub inclusion_data: Option<Vec<u8>>,
+}
+
 /// Represents the data availability details of a certain batch. Intended to be used in the API.
 /// This struct is only used once blob_id is confirmed.
 #[derive(Debug, Clone)]
 pub struct DataAvailabilityDetails {
+    /// Empty if the pubdata was dispatched as several blobs.
     pub blob_id: String,
     pub pubdata_type: Option<PubdataType>,
     pub inclusion_data: Option<Vec<u8>>,
     pub sent_at: DateTime<Utc>,
     pub l2_da_validator: Option<Address>,
+    /// Blob IDs of the chunks ordered by the chunk index if the pubdata was dispatched as several blobs.
+    pub chunk_blob_ids: Option<Vec<String>>,
 }
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +21,19 @@ impl fmt::Display for TeeType {
     }
 }
 
+impl FromStr for TeeType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "sgx" => Ok(Self::Sgx),
+            "tdx" => Ok(Self::Tdx),
+            _ => Err("Incorrect TEE type; expected one of `none`, `sgx`, `tdx`"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json;
@@ -64,4 +77,12 @@ mod tests {
         assert_eq!(TeeType::Sgx.to_string(), "sgx");
         assert_eq!(TeeType::Tdx.to_string(), "tdx");
     }
+
+    #[test]
+    fn test_parse_teetype() {
+        for tee_type in [TeeType::None, TeeType::Sgx, TeeType::Tdx] {
+            assert_eq!(tee_type.to_string().parse::<TeeType>(), Ok(tee_type));
+        }
+        assert!("SGX".parse::<TeeType>().is_err());
+    }
 }
@@ -37,6 +37,8 @@ pub enum CircuitBreakerError {
     FailedL1Transaction,
     #[error("Replication lag ({lag:?}) is above the threshold ({threshold:?})")]
     ReplicationLag { lag: Duration, threshold: Duration },
+    #[error("Main node has reported status of L1 batch #{l1_batch} contradicting the settlement layer: {reason}")]
+    BatchStatusMismatch { l1_batch: u32, reason: String },
     #[error("Internal error running circuit breaker checks")]
     Internal(#[from] anyhow::Error),
 }
@@ -24,6 +24,8 @@ pub struct ApiConfig {
     pub healthcheck: HealthCheckConfig,
     /// Configuration options for Merkle tree API.
     pub merkle_tree: MerkleTreeApiConfig,
+    /// Configuration options for the admin API. If not set, the admin API is disabled.
+    pub admin: Option<AdminApiConfig>,
 }
 
 /// Response size limits for specific RPC methods.
@@ -402,6 +404,21 @@ impl MerkleTreeApiConfig {
     }
 }
 
+/// Configuration for the admin API, which allows operators to manage the node (e.g., pause the state keeper)
+/// via JSON-RPC. The API is served on a separate port and requires authentication using the token
+/// specified in [`AdminApiSecrets`](crate::configs::secrets::AdminApiSecrets).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AdminApiConfig {
+    /// Port to bind the admin API server to.
+    pub port: u16,
+}
+
+impl AdminApiConfig {
+    pub fn bind_addr(&self) -> SocketAddr {
+        SocketAddr::new("0.0.0.0".parse().unwrap(), self.port)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
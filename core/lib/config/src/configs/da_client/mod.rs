@@ -1,15 +1,20 @@
 use serde::Deserialize;
 
-use crate::{AvailConfig, CelestiaConfig, EigenConfig, ObjectStoreConfig};
+use crate::{
+    configs::da_client::sidecar::SidecarConfig, AvailConfig, CelestiaConfig, EigenConfig,
+    ObjectStoreConfig,
+};
 
 pub mod avail;
 pub mod celestia;
 pub mod eigen;
+pub mod sidecar;
 
 pub const AVAIL_CLIENT_CONFIG_NAME: &str = "Avail";
 pub const CELESTIA_CLIENT_CONFIG_NAME: &str = "Celestia";
 pub const EIGEN_CLIENT_CONFIG_NAME: &str = "Eigen";
 pub const OBJECT_STORE_CLIENT_CONFIG_NAME: &str = "ObjectStore";
+pub const SIDECAR_CLIENT_CONFIG_NAME: &str = "Sidecar";
 pub const NO_DA_CLIENT_CONFIG_NAME: &str = "NoDA";
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -18,6 +23,7 @@ pub enum DAClientConfig {
     Celestia(CelestiaConfig),
     Eigen(EigenConfig),
     ObjectStore(ObjectStoreConfig),
+    Sidecar(SidecarConfig),
     NoDA,
 }
 
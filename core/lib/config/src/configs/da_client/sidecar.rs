@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+pub const DEFAULT_SIDECAR_TIMEOUT_MS: u64 = 30_000;
+
+/// Configuration for the generic DA client that talks to a DA sidecar process over HTTP.
+///
+/// The sidecar is responsible for all interaction with the DA layer (including signing); the server
+/// only speaks a small HTTP / JSON protocol with it, so no secrets are required on the server side.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SidecarConfig {
+    /// Base URL of the sidecar, e.g. `http://127.0.0.1:3100`.
+    pub server_url: String,
+    /// Timeout for a single request to the sidecar.
+    pub timeout_ms: Option<u64>,
+}
+
+impl SidecarConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms.unwrap_or(DEFAULT_SIDECAR_TIMEOUT_MS))
+    }
+}
@@ -13,6 +13,8 @@ pub const DEFAULT_MAX_RETRIES: u16 = 5;
 pub const DEFAULT_USE_DUMMY_INCLUSION_DATA: bool = false;
 /// The default value for the inclusion_verification_transition_enabled flag.
 pub const DEFAULT_INCLUSION_VERIFICATION_TRANSITION_ENABLED: bool = false;
+/// Split pubdata exceeding the blob size limit of the DA layer into several blobs.
+pub const DEFAULT_USE_BLOB_CHUNKING: bool = false;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DADispatcherConfig {
@@ -28,6 +30,10 @@ pub struct DADispatcherConfig {
     /// It will make the dispatcher stop polling for inclusion data and ensure all the old batches
     /// have at least dummy inclusion data.
     pub inclusion_verification_transition_enabled: Option<bool>,
+    /// Split pubdata exceeding the blob size limit of the DA client into several blobs which are dispatched
+    /// in parallel. The inclusion data of the chunks is combined by the DA client, so this requires
+    /// a DA client supporting chunked pubdata and a matching L1 DA validator.
+    pub use_blob_chunking: Option<bool>,
 }
 
 impl DADispatcherConfig {
@@ -40,6 +46,7 @@ impl DADispatcherConfig {
             inclusion_verification_transition_enabled: Some(
                 DEFAULT_INCLUSION_VERIFICATION_TRANSITION_ENABLED,
             ),
+            use_blob_chunking: Some(DEFAULT_USE_BLOB_CHUNKING),
         }
     }
 
@@ -68,4 +75,8 @@ impl DADispatcherConfig {
         self.inclusion_verification_transition_enabled
             .unwrap_or(DEFAULT_INCLUSION_VERIFICATION_TRANSITION_ENABLED)
     }
+
+    pub fn use_blob_chunking(&self) -> bool {
+        self.use_blob_chunking.unwrap_or(DEFAULT_USE_BLOB_CHUNKING)
+    }
 }
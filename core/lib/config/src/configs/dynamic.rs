@@ -0,0 +1,462 @@
+//! Subset of the node configuration that can be changed without restarting the node.
+
+use std::{fmt, num::NonZeroU32};
+
+use anyhow::Context as _;
+
+use crate::configs::{
+    api::Web3JsonRpcConfig, chain::StateKeeperConfig, eth_sender::SenderConfig, GasAdjusterConfig,
+    GeneralConfig,
+};
+
+/// Change of a single dynamic config parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Dot-separated path to the parameter, e.g. `eth_sender.tx_aggregation_paused`.
+    pub path: &'static str,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{}: {} -> {}",
+            self.path, self.old_value, self.new_value
+        )
+    }
+}
+
+/// Collects changes between two config sections field by field.
+macro_rules! collect_changes {
+    ($changes:ident, $section:literal, $old:expr, $new:expr, [$($field:ident),+]) => {
+        $(
+        if $old.$field != $new.$field {
+            $changes.push(ConfigChange {
+                path: concat!($section, ".", stringify!($field)),
+                old_value: format!("{:?}", $old.$field),
+                new_value: format!("{:?}", $new.$field),
+            });
+        }
+        )+
+    };
+}
+
+/// Dynamic part of [`SenderConfig`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicEthSenderConfig {
+    pub max_acceptable_priority_fee_in_gwei: u64,
+    pub tx_aggregation_paused: bool,
+}
+
+impl From<&SenderConfig> for DynamicEthSenderConfig {
+    fn from(config: &SenderConfig) -> Self {
+        Self {
+            max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
+            tx_aggregation_paused: config.tx_aggregation_paused,
+        }
+    }
+}
+
+impl DynamicEthSenderConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.max_acceptable_priority_fee_in_gwei > 0,
+            "max_acceptable_priority_fee_in_gwei must be positive"
+        );
+        Ok(())
+    }
+
+    fn collect_changes(&self, new: &Self, changes: &mut Vec<ConfigChange>) {
+        collect_changes!(
+            changes,
+            "eth_sender",
+            self,
+            new,
+            [max_acceptable_priority_fee_in_gwei, tx_aggregation_paused]
+        );
+    }
+}
+
+/// Dynamic part of [`GasAdjusterConfig`]: parameters affecting pricing, but not sampling.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicGasAdjusterConfig {
+    pub pricing_formula_parameter_a: f64,
+    pub pricing_formula_parameter_b: f64,
+    pub internal_l1_pricing_multiplier: f64,
+    pub internal_pubdata_pricing_multiplier: f64,
+    pub internal_enforced_l1_gas_price: Option<u64>,
+    pub internal_enforced_pubdata_price: Option<u64>,
+    pub max_l1_gas_price: Option<u64>,
+    pub max_blob_base_fee: Option<u64>,
+}
+
+impl From<&GasAdjusterConfig> for DynamicGasAdjusterConfig {
+    fn from(config: &GasAdjusterConfig) -> Self {
+        Self {
+            pricing_formula_parameter_a: config.pricing_formula_parameter_a,
+            pricing_formula_parameter_b: config.pricing_formula_parameter_b,
+            internal_l1_pricing_multiplier: config.internal_l1_pricing_multiplier,
+            internal_pubdata_pricing_multiplier: config.internal_pubdata_pricing_multiplier,
+            internal_enforced_l1_gas_price: config.internal_enforced_l1_gas_price,
+            internal_enforced_pubdata_price: config.internal_enforced_pubdata_price,
+            max_l1_gas_price: config.max_l1_gas_price,
+            max_blob_base_fee: config.max_blob_base_fee,
+        }
+    }
+}
+
+impl DynamicGasAdjusterConfig {
+    /// Overrides dynamic params in the provided config.
+    pub fn apply_to(&self, config: &mut GasAdjusterConfig) {
+        config.pricing_formula_parameter_a = self.pricing_formula_parameter_a;
+        config.pricing_formula_parameter_b = self.pricing_formula_parameter_b;
+        config.internal_l1_pricing_multiplier = self.internal_l1_pricing_multiplier;
+        config.internal_pubdata_pricing_multiplier = self.internal_pubdata_pricing_multiplier;
+        config.internal_enforced_l1_gas_price = self.internal_enforced_l1_gas_price;
+        config.internal_enforced_pubdata_price = self.internal_enforced_pubdata_price;
+        config.max_l1_gas_price = self.max_l1_gas_price;
+        config.max_blob_base_fee = self.max_blob_base_fee;
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        let multipliers = [
+            (
+                "pricing_formula_parameter_a",
+                self.pricing_formula_parameter_a,
+            ),
+            (
+                "pricing_formula_parameter_b",
+                self.pricing_formula_parameter_b,
+            ),
+            (
+                "internal_l1_pricing_multiplier",
+                self.internal_l1_pricing_multiplier,
+            ),
+            (
+                "internal_pubdata_pricing_multiplier",
+                self.internal_pubdata_pricing_multiplier,
+            ),
+        ];
+        for (name, value) in multipliers {
+            anyhow::ensure!(
+                value.is_finite() && value > 0.0,
+                "{name} must be a positive finite number, got {value}"
+            );
+        }
+        anyhow::ensure!(
+            self.max_l1_gas_price != Some(0),
+            "max_l1_gas_price must be positive"
+        );
+        anyhow::ensure!(
+            self.max_blob_base_fee != Some(0),
+            "max_blob_base_fee must be positive"
+        );
+        Ok(())
+    }
+
+    fn collect_changes(&self, new: &Self, changes: &mut Vec<ConfigChange>) {
+        collect_changes!(
+            changes,
+            "gas_adjuster",
+            self,
+            new,
+            [
+                pricing_formula_parameter_a,
+                pricing_formula_parameter_b,
+                internal_l1_pricing_multiplier,
+                internal_pubdata_pricing_multiplier,
+                internal_enforced_l1_gas_price,
+                internal_enforced_pubdata_price,
+                max_l1_gas_price,
+                max_blob_base_fee
+            ]
+        );
+    }
+}
+
+/// Dynamic part of [`StateKeeperConfig`]: thresholds used by L1 batch seal criteria.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicSealCriteriaConfig {
+    pub transaction_slots: usize,
+    pub reject_tx_at_geometry_percentage: f64,
+    pub reject_tx_at_eth_params_percentage: f64,
+    pub reject_tx_at_gas_percentage: f64,
+    pub close_block_at_geometry_percentage: f64,
+    pub close_block_at_eth_params_percentage: f64,
+    pub close_block_at_gas_percentage: f64,
+}
+
+impl From<&StateKeeperConfig> for DynamicSealCriteriaConfig {
+    fn from(config: &StateKeeperConfig) -> Self {
+        Self {
+            transaction_slots: config.transaction_slots,
+            reject_tx_at_geometry_percentage: config.reject_tx_at_geometry_percentage,
+            reject_tx_at_eth_params_percentage: config.reject_tx_at_eth_params_percentage,
+            reject_tx_at_gas_percentage: config.reject_tx_at_gas_percentage,
+            close_block_at_geometry_percentage: config.close_block_at_geometry_percentage,
+            close_block_at_eth_params_percentage: config.close_block_at_eth_params_percentage,
+            close_block_at_gas_percentage: config.close_block_at_gas_percentage,
+        }
+    }
+}
+
+impl DynamicSealCriteriaConfig {
+    /// Overrides dynamic params in the provided config.
+    pub fn apply_to(&self, config: &mut StateKeeperConfig) {
+        config.transaction_slots = self.transaction_slots;
+        config.reject_tx_at_geometry_percentage = self.reject_tx_at_geometry_percentage;
+        config.reject_tx_at_eth_params_percentage = self.reject_tx_at_eth_params_percentage;
+        config.reject_tx_at_gas_percentage = self.reject_tx_at_gas_percentage;
+        config.close_block_at_geometry_percentage = self.close_block_at_geometry_percentage;
+        config.close_block_at_eth_params_percentage = self.close_block_at_eth_params_percentage;
+        config.close_block_at_gas_percentage = self.close_block_at_gas_percentage;
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.transaction_slots > 0,
+            "transaction_slots must be positive"
+        );
+        let percentages = [
+            (
+                "reject_tx_at_geometry_percentage",
+                self.reject_tx_at_geometry_percentage,
+            ),
+            (
+                "reject_tx_at_eth_params_percentage",
+                self.reject_tx_at_eth_params_percentage,
+            ),
+            (
+                "reject_tx_at_gas_percentage",
+                self.reject_tx_at_gas_percentage,
+            ),
+            (
+                "close_block_at_geometry_percentage",
+                self.close_block_at_geometry_percentage,
+            ),
+            (
+                "close_block_at_eth_params_percentage",
+                self.close_block_at_eth_params_percentage,
+            ),
+            (
+                "close_block_at_gas_percentage",
+                self.close_block_at_gas_percentage,
+            ),
+        ];
+        for (name, value) in percentages {
+            anyhow::ensure!(
+                value > 0.0 && value <= 1.0,
+                "{name} must be in (0, 1], got {value}"
+            );
+        }
+        Ok(())
+    }
+
+    fn collect_changes(&self, new: &Self, changes: &mut Vec<ConfigChange>) {
+        collect_changes!(
+            changes,
+            "state_keeper",
+            self,
+            new,
+            [
+                transaction_slots,
+                reject_tx_at_geometry_percentage,
+                reject_tx_at_eth_params_percentage,
+                reject_tx_at_gas_percentage,
+                close_block_at_geometry_percentage,
+                close_block_at_eth_params_percentage,
+                close_block_at_gas_percentage
+            ]
+        );
+    }
+}
+
+/// Dynamic part of [`Web3JsonRpcConfig`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicApiConfig {
+    /// Applies to WebSocket connections established after the update.
+    pub websocket_requests_per_minute_limit: NonZeroU32,
+}
+
+impl From<&Web3JsonRpcConfig> for DynamicApiConfig {
+    fn from(config: &Web3JsonRpcConfig) -> Self {
+        Self {
+            websocket_requests_per_minute_limit: config.websocket_requests_per_minute_limit(),
+        }
+    }
+}
+
+impl DynamicApiConfig {
+    fn collect_changes(&self, new: &Self, changes: &mut Vec<ConfigChange>) {
+        collect_changes!(
+            changes,
+            "api",
+            self,
+            new,
+            [websocket_requests_per_minute_limit]
+        );
+    }
+}
+
+/// Runtime overrides of [`DynamicConfig`] params set by the node operator (e.g., via the admin API).
+/// Overrides take precedence over the config file and persist until the node is restarted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynamicConfigOverrides {
+    /// Overrides `eth_sender.tx_aggregation_paused`.
+    pub tx_aggregation_paused: Option<bool>,
+}
+
+impl DynamicConfigOverrides {
+    /// Applies overrides to the provided config. Errors if an overridden section is missing from the config.
+    pub fn apply_to(&self, config: &mut DynamicConfig) -> anyhow::Result<()> {
+        if let Some(paused) = self.tx_aggregation_paused {
+            let eth_sender = config.eth_sender.as_mut().context(
+                "cannot override `eth_sender.tx_aggregation_paused`: section is missing",
+            )?;
+            eth_sender.tx_aggregation_paused = paused;
+        }
+        Ok(())
+    }
+}
+
+/// Subset of the node configuration that can be changed without restarting the node.
+///
+/// Sections are `None` if the corresponding config section is not present. A section cannot appear or disappear
+/// on config reload.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynamicConfig {
+    pub eth_sender: Option<DynamicEthSenderConfig>,
+    pub gas_adjuster: Option<DynamicGasAdjusterConfig>,
+    pub state_keeper: Option<DynamicSealCriteriaConfig>,
+    pub api: Option<DynamicApiConfig>,
+}
+
+impl DynamicConfig {
+    /// Extracts the dynamic part of the general config.
+    pub fn from_general_config(config: &GeneralConfig) -> Self {
+        let eth = config.eth.as_ref();
+        Self {
+            eth_sender: eth
+                .and_then(|eth| eth.get_eth_sender_config_for_sender_layer_data_layer())
+                .map(Into::into),
+            gas_adjuster: eth
+                .and_then(|eth| eth.gas_adjuster.as_ref())
+                .map(Into::into),
+            state_keeper: config.state_keeper_config.as_ref().map(Into::into),
+            api: config
+                .api_config
+                .as_ref()
+                .map(|api| (&api.web3_json_rpc).into()),
+        }
+    }
+
+    /// Validates all sections of this config.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if let Some(eth_sender) = &self.eth_sender {
+            eth_sender.validate().context("eth_sender")?;
+        }
+        if let Some(gas_adjuster) = &self.gas_adjuster {
+            gas_adjuster.validate().context("gas_adjuster")?;
+        }
+        if let Some(state_keeper) = &self.state_keeper {
+            state_keeper.validate().context("state_keeper")?;
+        }
+        Ok(())
+    }
+
+    /// Returns changes between this config and the `new` one. Errors if the configs have different sets of sections.
+    pub fn changes(&self, new: &Self) -> anyhow::Result<Vec<ConfigChange>> {
+        fn section_pair<'a, T>(
+            name: &str,
+            old: &'a Option<T>,
+            new: &'a Option<T>,
+        ) -> anyhow::Result<Option<(&'a T, &'a T)>> {
+            match (old, new) {
+                (Some(old), Some(new)) => Ok(Some((old, new))),
+                (None, None) => Ok(None),
+                (Some(_), None) => anyhow::bail!("section `{name}` cannot be removed"),
+                (None, Some(_)) => anyhow::bail!("section `{name}` cannot be added"),
+            }
+        }
+
+        let mut changes = vec![];
+        if let Some((old, new)) = section_pair("eth_sender", &self.eth_sender, &new.eth_sender)? {
+            old.collect_changes(new, &mut changes);
+        }
+        if let Some((old, new)) =
+            section_pair("gas_adjuster", &self.gas_adjuster, &new.gas_adjuster)?
+        {
+            old.collect_changes(new, &mut changes);
+        }
+        if let Some((old, new)) =
+            section_pair("state_keeper", &self.state_keeper, &new.state_keeper)?
+        {
+            old.collect_changes(new, &mut changes);
+        }
+        if let Some((old, new)) = section_pair("api", &self.api, &new.api)? {
+            old.collect_changes(new, &mut changes);
+        }
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::EthConfig;
+
+    fn sample_config() -> DynamicConfig {
+        let eth_config = EthConfig::for_tests();
+        DynamicConfig {
+            eth_sender: eth_config
+                .get_eth_sender_config_for_sender_layer_data_layer()
+                .map(Into::into),
+            gas_adjuster: eth_config.gas_adjuster.as_ref().map(Into::into),
+            state_keeper: Some((&StateKeeperConfig::for_tests()).into()),
+            api: None,
+        }
+    }
+
+    #[test]
+    fn collecting_changes() {
+        let config = sample_config();
+        assert_eq!(config.changes(&config).unwrap(), []);
+
+        let mut new_config = config.clone();
+        new_config
+            .eth_sender
+            .as_mut()
+            .unwrap()
+            .tx_aggregation_paused = true;
+        new_config.state_keeper.as_mut().unwrap().transaction_slots = 100;
+        let changes = config.changes(&new_config).unwrap();
+        assert_eq!(changes.len(), 2, "{changes:?}");
+        assert_eq!(changes[0].path, "eth_sender.tx_aggregation_paused");
+        assert_eq!(changes[0].old_value, "false");
+        assert_eq!(changes[0].new_value, "true");
+        assert_eq!(changes[1].path, "state_keeper.transaction_slots");
+        assert_eq!(changes[1].new_value, "100");
+
+        new_config.api = Some(DynamicApiConfig {
+            websocket_requests_per_minute_limit: NonZeroU32::new(10).unwrap(),
+        });
+        let err = config.changes(&new_config).unwrap_err().to_string();
+        assert!(err.contains("cannot be added"), "{err}");
+    }
+
+    #[test]
+    fn validating_config() {
+        let mut config = sample_config();
+        config.validate().unwrap();
+
+        config
+            .state_keeper
+            .as_mut()
+            .unwrap()
+            .close_block_at_gas_percentage = 1.5;
+        let err = format!("{:#}", config.validate().unwrap_err());
+        assert!(err.contains("close_block_at_gas_percentage"), "{err}");
+    }
+}
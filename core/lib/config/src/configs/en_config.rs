@@ -13,6 +13,12 @@ pub struct ENConfig {
     // Main node configuration
     pub main_node_url: SensitiveUrl,
     pub main_node_rate_limit_rps: Option<NonZeroUsize>,
+    /// Fallback upstreams (e.g., trusted external nodes) used if the main node is unavailable or lagging.
+    #[serde(default)]
+    pub fallback_upstream_urls: Vec<SensitiveUrl>,
+    pub upstream_max_lag_blocks: Option<u32>,
+    /// Whether to verify L1 batch statuses reported by the main node on the settlement layer.
+    pub verify_batch_statuses: Option<bool>,
 
     pub bridge_addresses_refresh_interval_sec: Option<NonZeroU64>,
 
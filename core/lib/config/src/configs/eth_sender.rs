@@ -56,6 +56,7 @@ impl EthConfig {
                 is_verifier_pre_fflonk: true,
                 gas_limit_mode: GasLimitMode::Maximum,
                 max_acceptable_base_fee_in_wei: 100000000000,
+                require_tee_finalization: false,
             }),
             gas_adjuster: Some(GasAdjusterConfig {
                 default_priority_fee_per_gas: 1000000000,
@@ -152,6 +153,10 @@ pub struct SenderConfig {
     /// Max acceptable base fee the sender is allowed to use to send L1 txs.
     #[serde(default = "SenderConfig::default_max_acceptable_base_fee_in_wei")]
     pub max_acceptable_base_fee_in_wei: u64,
+    /// If set, L1 batches are only executed after they are TEE-finalized, i.e. after their TEE proofs
+    /// satisfy the quorum policy of the TEE proof data handler. Requires the quorum policy to be configured.
+    #[serde(default)]
+    pub require_tee_finalization: bool,
 }
 
 impl SenderConfig {
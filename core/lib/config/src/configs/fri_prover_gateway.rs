@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use serde::Deserialize;
+use zksync_basic_types::L2ChainId;
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct FriProverGatewayConfig {
@@ -15,6 +16,23 @@ pub struct FriProverGatewayConfig {
     pub api_mode: ApiMode,
     #[serde(default)]
     pub port: Option<u16>,
+    /// Chains served by the gateway. In the legacy API mode, if empty, the gateway serves a single chain
+    /// with the proof data handler at `api_url`. In the prover cluster API mode, if non-empty, requests
+    /// for other chains are rejected.
+    #[serde(default)]
+    pub chains: Vec<ProverGatewayChainConfig>,
+}
+
+/// Configuration of a chain served by the prover gateway.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ProverGatewayChainConfig {
+    pub chain_id: L2ChainId,
+    /// URL of the proof data handler of the chain. Not used in the prover cluster API mode.
+    pub api_url: String,
+    /// Priority of the chain jobs. Jobs of chains with a higher priority are preferred by provers; each priority point
+    /// is equivalent to 10 minutes of batch age.
+    #[serde(default)]
+    pub priority: i32,
 }
 
 impl FriProverGatewayConfig {
@@ -8,6 +8,7 @@ pub use self::{
     da_client::{avail::AvailConfig, celestia::CelestiaConfig, eigen::EigenConfig, DAClientConfig},
     da_dispatcher::DADispatcherConfig,
     database::{DBConfig, PostgresConfig},
+    dynamic::DynamicConfig,
     eth_sender::{EthConfig, GasAdjusterConfig},
     eth_watch::EthWatchConfig,
     experimental::{ExperimentalDBConfig, ExperimentalVmConfig, ExperimentalVmPlaygroundConfig},
@@ -25,11 +26,14 @@ pub use self::{
     prover_job_monitor::ProverJobMonitorConfig,
     pruning::PruningConfig,
     secrets::{
-        ContractVerifierSecrets, DataAvailabilitySecrets, DatabaseSecrets, L1Secrets, Secrets,
+        AdminApiSecrets, ContractVerifierSecrets, DataAvailabilitySecrets, DatabaseSecrets,
+        L1Secrets, Secrets,
     },
     snapshot_recovery::SnapshotRecoveryConfig,
     snapshots_creator::SnapshotsCreatorConfig,
-    tee_proof_data_handler::TeeProofDataHandlerConfig,
+    tee_proof_data_handler::{
+        TeeAttestationVerificationConfig, TeeProofDataHandlerConfig, TeeQuorumConfig,
+    },
     utils::PrometheusConfig,
     vm_runner::{BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig},
 };
@@ -44,6 +48,7 @@ pub mod contracts;
 pub mod da_client;
 pub mod da_dispatcher;
 pub mod database;
+pub mod dynamic;
 pub mod en_config;
 pub mod eth_sender;
 pub mod eth_watch;
@@ -14,6 +14,10 @@ pub struct ProofDataHandlerConfig {
     pub proof_gen_data_submit_interval_in_secs: u16,
     #[serde(default = "ProofDataHandlerConfig::default_fetch_zero_chain_id_proofs")]
     pub fetch_zero_chain_id_proofs: bool,
+    /// Path to the keystore directory with SNARK wrapper verification keys. If set, final proofs are
+    /// verified before being accepted, and batches with invalid proofs are requeued for proving.
+    #[serde(default)]
+    pub verification_keys_path: Option<String>,
 }
 
 impl ProofDataHandlerConfig {
@@ -1,6 +1,7 @@
 use std::num::NonZeroU64;
 
 use serde::Deserialize;
+use zksync_basic_types::{Address, H256};
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct PruningConfig {
@@ -16,4 +17,13 @@ pub struct PruningConfig {
     /// the retention period greater than that implicitly imposed by other criteria (e.g., 7 or 30 days).
     /// If set to 0, L1 batches will not be retained based on their timestamp. The default value is 1 hour.
     pub data_retention_sec: Option<u64>,
+    /// Addresses of accounts / contracts whose transactions and events are retained regardless of other pruning criteria.
+    /// A transaction is retained if it's initiated by or sent to one of these addresses, or if it emits an event
+    /// from one of these addresses.
+    #[serde(default)]
+    pub retained_addresses: Vec<Address>,
+    /// Event topics (matched against the first topic, i.e. the event signature) retained regardless of other
+    /// pruning criteria. Transactions emitting such events are retained as well.
+    #[serde(default)]
+    pub retained_event_topics: Vec<H256>,
 }
@@ -33,6 +33,12 @@ pub struct ContractVerifierSecrets {
     pub etherscan_api_key: Option<APIKey>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdminApiSecrets {
+    /// Bearer token required to access the admin API.
+    pub auth_token: APIKey,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Secrets {
     pub consensus: Option<ConsensusSecrets>,
@@ -40,6 +46,7 @@ pub struct Secrets {
     pub l1: Option<L1Secrets>,
     pub data_availability: Option<DataAvailabilitySecrets>,
     pub contract_verifier: Option<ContractVerifierSecrets>,
+    pub admin_api: Option<AdminApiSecrets>,
 }
 
 impl DatabaseSecrets {
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU64, NonZeroUsize};
 
 use serde::Deserialize;
 use zksync_basic_types::L1BatchNumber;
@@ -42,6 +42,18 @@ pub struct SnapshotRecoveryConfig {
     /// This is a temporary flag that will eventually be removed together with version 0 snapshot support.
     #[serde(default)]
     pub drop_storage_key_preimages: bool,
+    /// Enables applying the chain of delta snapshots published by the main node on top of the recovered snapshot.
+    #[serde(default)]
+    pub apply_delta_snapshots: bool,
+    /// Enables reconstructing the snapshot from L1 data (commit transactions and, for validiums, the DA layer)
+    /// instead of downloading it from the main node. The object store must be writable in this case.
+    #[serde(default)]
+    pub l1_recovery_enabled: bool,
+    /// L1 block to start looking for commit transactions from during L1 recovery.
+    #[serde(default)]
+    pub l1_recovery_from_block: u64,
+    /// Maximum number of L1 blocks queried for commit events in a single request during L1 recovery.
+    pub l1_recovery_block_range: Option<NonZeroU64>,
     pub tree: TreeRecoveryConfig,
     pub postgres: PostgresRecoveryConfig,
     pub object_store: Option<ObjectStoreConfig>,
@@ -16,6 +16,11 @@ pub struct SnapshotsCreatorConfig {
     /// - If a snapshot with this L1 batch exists and is incomplete, the creator will continue creating it,
     ///   regardless of whether the specified snapshot `version` matches.
     pub l1_batch_number: Option<L1BatchNumber>,
+    /// If set, the creator produces a delta snapshot on top of the newest complete snapshot preceding the requested
+    /// L1 batch (if any). Delta snapshots only contain storage logs and factory deps changed since the base snapshot
+    /// and require `version` 1.
+    #[serde(default)]
+    pub create_delta_snapshots: bool,
     #[serde(default = "SnapshotsCreatorConfig::storage_logs_chunk_size_default")]
     pub storage_logs_chunk_size: u64,
     #[serde(default = "SnapshotsCreatorConfig::concurrent_queries_count")]
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use serde::Deserialize;
-use zksync_basic_types::L1BatchNumber;
+use zksync_basic_types::{tee_types::TeeType, L1BatchNumber};
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct TeeProofDataHandlerConfig {
@@ -19,6 +19,67 @@ pub struct TeeProofDataHandlerConfig {
         default = "TeeProofDataHandlerConfig::default_tee_batch_permanently_ignored_timeout_in_hours"
     )]
     pub batch_permanently_ignored_timeout_in_hours: u16,
+    /// Settings for verifying TEE attestations and proof signatures. If not set, attestations and proofs
+    /// are accepted without verification.
+    #[serde(default)]
+    pub attestation_verification: Option<TeeAttestationVerificationConfig>,
+    /// Policy defining when an L1 batch is considered TEE-finalized. If not set, batches are never
+    /// marked as TEE-finalized.
+    #[serde(default)]
+    pub quorum: Option<TeeQuorumConfig>,
+}
+
+/// Quorum policy for TEE proofs. An L1 batch is TEE-finalized once it has generated proofs from all
+/// `required_tee_types`, signed by at least `min_distinct_keys` distinct attested keys.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct TeeQuorumConfig {
+    /// TEE types that must each provide a proof for a batch.
+    #[serde(default)]
+    pub required_tee_types: Vec<TeeType>,
+    /// Minimum number of distinct attested keys that must sign proofs for a batch.
+    #[serde(default = "TeeQuorumConfig::default_min_distinct_keys")]
+    pub min_distinct_keys: usize,
+    /// Interval between quorum checks in milliseconds.
+    #[serde(default = "TeeQuorumConfig::default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+}
+
+impl TeeQuorumConfig {
+    pub const fn default_min_distinct_keys() -> usize {
+        1
+    }
+
+    pub const fn default_poll_interval_ms() -> u64 {
+        1_000
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+}
+
+/// Settings for verifying TEE attestation quotes and proof signatures.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct TeeAttestationVerificationConfig {
+    /// Path to a JSON file with a list of pre-fetched quote verification collaterals (PCK CRLs, TCB info
+    /// and QE identity), one for each platform used by TEE provers. Allows verifying quotes without
+    /// accessing Intel PCS.
+    pub collateral_path: String,
+    /// Hex-encoded trusted SGX `MRENCLAVE` values.
+    #[serde(default)]
+    pub sgx_mrenclaves: Vec<String>,
+    /// Hex-encoded trusted SGX `MRSIGNER` values. An SGX quote is accepted if either its `MRENCLAVE`
+    /// or `MRSIGNER` is trusted.
+    #[serde(default)]
+    pub sgx_mrsigners: Vec<String>,
+    /// Trusted TDX measurements, each being a hex-encoded concatenation of `MRTD` and `RTMR0`..`RTMR3`
+    /// (5 * 48 bytes).
+    #[serde(default)]
+    pub tdx_measurements: Vec<String>,
+    /// TCB statuses of the attested platform accepted in addition to `UpToDate`
+    /// (e.g., `SWHardeningNeeded`).
+    #[serde(default)]
+    pub allowed_tcb_levels: Vec<String>,
 }
 
 impl TeeProofDataHandlerConfig {
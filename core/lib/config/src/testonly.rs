@@ -6,6 +6,7 @@ use zksync_basic_types::{
     protocol_version::{ProtocolSemanticVersion, ProtocolVersionId, VersionPatch},
     pubdata_da::PubdataSendingMode,
     secrets::{APIKey, SeedPhrase},
+    tee_types::TeeType,
     vm::FastVmMode,
     L1BatchNumber, L1ChainId, L2ChainId, SLChainId,
 };
@@ -42,6 +43,7 @@ impl Distribution<configs::ApiConfig> for EncodeDist {
             prometheus: self.sample(rng),
             healthcheck: self.sample(rng),
             merkle_tree: self.sample(rng),
+            admin: self.sample_opt(|| self.sample(rng)),
         }
     }
 }
@@ -122,6 +124,14 @@ impl Distribution<configs::api::MerkleTreeApiConfig> for EncodeDist {
     }
 }
 
+impl Distribution<configs::api::AdminApiConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::api::AdminApiConfig {
+        configs::api::AdminApiConfig {
+            port: self.sample(rng),
+        }
+    }
+}
+
 impl Distribution<configs::PrometheusConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::PrometheusConfig {
         configs::PrometheusConfig {
@@ -402,6 +412,7 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             is_verifier_pre_fflonk: self.sample(rng),
             gas_limit_mode: self.sample(rng),
             max_acceptable_base_fee_in_wei: self.sample(rng),
+            require_tee_finalization: self.sample(rng),
         }
     }
 }
@@ -472,6 +483,20 @@ impl Distribution<configs::FriProverGatewayConfig> for EncodeDist {
             prometheus_push_interval_ms: self.sample(rng),
             api_mode: self.sample(rng),
             port: self.sample(rng),
+            chains: self.sample_collect(rng),
+        }
+    }
+}
+
+impl Distribution<configs::fri_prover_gateway::ProverGatewayChainConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::fri_prover_gateway::ProverGatewayChainConfig {
+        configs::fri_prover_gateway::ProverGatewayChainConfig {
+            chain_id: L2ChainId::from(rng.gen::<u32>()),
+            api_url: self.sample(rng),
+            priority: self.sample(rng),
         }
     }
 }
@@ -552,6 +577,7 @@ impl Distribution<configs::ProofDataHandlerConfig> for EncodeDist {
             proof_fetch_interval_in_secs: self.sample(rng),
             proof_gen_data_submit_interval_in_secs: self.sample(rng),
             fetch_zero_chain_id_proofs: self.sample(rng),
+            verification_keys_path: self.sample(rng),
         }
     }
 }
@@ -563,6 +589,36 @@ impl Distribution<configs::TeeProofDataHandlerConfig> for EncodeDist {
             first_processed_batch: L1BatchNumber(rng.gen()),
             proof_generation_timeout_in_secs: self.sample(rng),
             batch_permanently_ignored_timeout_in_hours: self.sample(rng),
+            attestation_verification: self.sample(rng),
+            quorum: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::TeeQuorumConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::TeeQuorumConfig {
+        configs::TeeQuorumConfig {
+            required_tee_types: self
+                .sample_range(rng)
+                .map(|_| match rng.gen_range(0..2) {
+                    0 => TeeType::Sgx,
+                    _ => TeeType::Tdx,
+                })
+                .collect(),
+            min_distinct_keys: self.sample(rng),
+            poll_interval_ms: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::TeeAttestationVerificationConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::TeeAttestationVerificationConfig {
+        configs::TeeAttestationVerificationConfig {
+            collateral_path: self.sample(rng),
+            sgx_mrenclaves: self.sample_range(rng).map(|_| self.sample(rng)).collect(),
+            sgx_mrsigners: self.sample_range(rng).map(|_| self.sample(rng)).collect(),
+            tdx_measurements: self.sample_range(rng).map(|_| self.sample(rng)).collect(),
+            allowed_tcb_levels: self.sample_range(rng).map(|_| self.sample(rng)).collect(),
         }
     }
 }
@@ -572,6 +628,7 @@ impl Distribution<configs::SnapshotsCreatorConfig> for EncodeDist {
         configs::SnapshotsCreatorConfig {
             l1_batch_number: self.sample_opt(|| L1BatchNumber(rng.gen())),
             version: if rng.gen() { 0 } else { 1 },
+            create_delta_snapshots: self.sample(rng),
             storage_logs_chunk_size: self.sample(rng),
             concurrent_queries_count: self.sample(rng),
             object_store: self.sample(rng),
@@ -735,6 +792,7 @@ impl Distribution<configs::secrets::Secrets> for EncodeDist {
             l1: self.sample_opt(|| self.sample(rng)),
             data_availability: self.sample_opt(|| self.sample(rng)),
             contract_verifier: self.sample_opt(|| self.sample(rng)),
+            admin_api: self.sample_opt(|| self.sample(rng)),
         }
     }
 }
@@ -793,6 +851,11 @@ impl Distribution<configs::en_config::ENConfig> for EncodeDist {
             l1_chain_id: L1ChainId(rng.gen()),
             main_node_url: format!("localhost:{}", rng.gen::<u16>()).parse().unwrap(),
             main_node_rate_limit_rps: self.sample_opt(|| rng.gen()),
+            fallback_upstream_urls: (0..rng.gen_range(0..3))
+                .map(|_| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap())
+                .collect(),
+            upstream_max_lag_blocks: self.sample_opt(|| rng.gen()),
+            verify_batch_statuses: self.sample_opt(|| rng.gen()),
             bridge_addresses_refresh_interval_sec: self.sample_opt(|| rng.gen()),
             gateway_chain_id: self.sample_opt(|| SLChainId(rng.gen())),
         }
@@ -831,6 +894,7 @@ impl Distribution<configs::da_dispatcher::DADispatcherConfig> for EncodeDist {
             max_retries: self.sample(rng),
             use_dummy_inclusion_data: self.sample(rng),
             inclusion_verification_transition_enabled: self.sample(rng),
+            use_blob_chunking: self.sample(rng),
         }
     }
 }
@@ -903,6 +967,18 @@ impl Distribution<configs::snapshot_recovery::SnapshotRecoveryConfig> for Encode
             enabled: self.sample(rng),
             l1_batch: self.sample_opt(|| L1BatchNumber(rng.gen())),
             drop_storage_key_preimages: (tree != TreeRecoveryConfig::default()) && self.sample(rng),
+            apply_delta_snapshots: (tree != TreeRecoveryConfig::default()) && self.sample(rng),
+            l1_recovery_enabled: (tree != TreeRecoveryConfig::default()) && self.sample(rng),
+            l1_recovery_from_block: if tree != TreeRecoveryConfig::default() {
+                self.sample(rng)
+            } else {
+                0
+            },
+            l1_recovery_block_range: if tree != TreeRecoveryConfig::default() {
+                self.sample_opt(|| rng.gen())
+            } else {
+                None
+            },
             tree,
             postgres: self.sample(rng),
             object_store: self.sample(rng),
@@ -917,6 +993,8 @@ impl Distribution<configs::pruning::PruningConfig> for EncodeDist {
             chunk_size: self.sample(rng),
             removal_delay_sec: self.sample_opt(|| rng.gen()),
             data_retention_sec: self.sample(rng),
+            retained_addresses: self.sample_range(rng).map(|_| rng.gen()).collect(),
+            retained_event_topics: self.sample_range(rng).map(|_| rng.gen()).collect(),
         }
     }
 }
@@ -1058,3 +1136,11 @@ impl Distribution<configs::secrets::ContractVerifierSecrets> for EncodeDist {
         }
     }
 }
+
+impl Distribution<configs::secrets::AdminApiSecrets> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::secrets::AdminApiSecrets {
+        configs::secrets::AdminApiSecrets {
+            auth_token: <APIKey as From<String>>::from(self.sample(rng)),
+        }
+    }
+}
@@ -37,6 +37,7 @@ pub mod error;
 pub mod etherscan;
 mod metrics;
 mod resolver;
+pub mod similar_match;
 #[cfg(test)]
 mod tests;
 
@@ -19,6 +19,8 @@ pub(crate) struct ApiContractVerifierMetrics {
     pub failed_verifications: LabeledFamily<&'static str, Counter, 1>,
     #[metrics(labels = ["service_name"])]
     pub successful_verifications: LabeledFamily<&'static str, Counter, 1>,
+    /// Number of contracts that received verification info from a verified contract with the identical bytecode.
+    pub similar_matches: Counter,
 }
 
 #[vise::register]
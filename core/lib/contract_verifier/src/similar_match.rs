@@ -0,0 +1,338 @@
+//! Propagation of verification info to contracts with the bytecode identical to a verified contract
+//! (so-called similar matches).
+
+use std::time::Duration;
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_types::L2BlockNumber;
+
+use crate::metrics::API_CONTRACT_VERIFIER_METRICS;
+
+/// Background task propagating verification info to contracts deployed with the same bytecode hash
+/// as a verified contract.
+///
+/// The task consists of 2 parts:
+///
+/// - **Backfill.** For each newly verified contract, all previous deployments of its bytecode are recorded
+///   as similar matches.
+/// - **Forward scan.** New L2 blocks are scanned for deployments of bytecodes belonging to verified contracts.
+///   Scanning starts from the latest sealed L2 block on the first launch; deployments in earlier blocks are handled
+///   by the backfill.
+///
+/// Contracts having their own verification info are never recorded as similar matches.
+#[derive(Debug)]
+pub struct SimilarMatchPropagator {
+    connection_pool: ConnectionPool<Core>,
+    poll_interval: Duration,
+    backfill_batch_size: usize,
+    max_l2_blocks_per_iteration: u32,
+}
+
+impl SimilarMatchPropagator {
+    pub fn new(connection_pool: ConnectionPool<Core>) -> Self {
+        Self {
+            connection_pool,
+            poll_interval: Duration::from_secs(5),
+            backfill_batch_size: 100,
+            max_l2_blocks_per_iteration: 1_000,
+        }
+    }
+
+    /// Performs a single iteration of the task. Returns `true` if there is more work pending.
+    async fn run_once(&self) -> anyhow::Result<bool> {
+        let mut storage = self
+            .connection_pool
+            .connection_tagged("similar_match_propagator")
+            .await?;
+        if !storage
+            .contract_verification_dal()
+            .is_verification_info_migration_performed()
+            .await?
+        {
+            tracing::debug!("Verification info migration is not performed yet; skipping iteration");
+            return Ok(false);
+        }
+
+        let has_more_contracts = self.backfill(&mut storage).await?;
+        let has_more_blocks = self.scan_new_l2_blocks(&mut storage).await?;
+        Ok(has_more_contracts || has_more_blocks)
+    }
+
+    async fn backfill(&self, storage: &mut Connection<'_, Core>) -> anyhow::Result<bool> {
+        let verified_contracts = storage
+            .contract_verification_dal()
+            .get_contracts_pending_similar_matches_backfill(self.backfill_batch_size)
+            .await?;
+
+        for &address in &verified_contracts {
+            let deployed_contract = storage
+                .contract_verification_dal()
+                .get_contract_info_for_verification(address)
+                .await?;
+            let Some(deployed_contract) = deployed_contract else {
+                tracing::warn!(
+                    ?address,
+                    "Deployment of verified contract is not found; skipping similar matches backfill"
+                );
+                storage
+                    .contract_verification_dal()
+                    .mark_similar_matches_backfilled(address)
+                    .await?;
+                continue;
+            };
+
+            let inserted_count = storage
+                .contract_verification_dal()
+                .backfill_similar_matches(address, deployed_contract.bytecode_hash)
+                .await?;
+            tracing::debug!(
+                ?address,
+                bytecode_hash = ?deployed_contract.bytecode_hash,
+                inserted_count,
+                "Backfilled similar matches for verified contract"
+            );
+            API_CONTRACT_VERIFIER_METRICS
+                .similar_matches
+                .inc_by(inserted_count as u64);
+        }
+        Ok(verified_contracts.len() == self.backfill_batch_size)
+    }
+
+    async fn scan_new_l2_blocks(&self, storage: &mut Connection<'_, Core>) -> anyhow::Result<bool> {
+        let Some(sealed_l2_block) = storage.blocks_dal().get_sealed_l2_block_number().await? else {
+            return Ok(false);
+        };
+        let Some(last_processed_l2_block) = storage
+            .contract_verification_dal()
+            .get_similar_matches_cursor()
+            .await?
+        else {
+            tracing::info!(
+                %sealed_l2_block,
+                "Initializing similar matches cursor; earlier deployments are handled by backfill"
+            );
+            storage
+                .contract_verification_dal()
+                .set_similar_matches_cursor(sealed_l2_block)
+                .await?;
+            return Ok(false);
+        };
+        if last_processed_l2_block >= sealed_l2_block {
+            return Ok(false);
+        }
+
+        let first_l2_block = last_processed_l2_block + 1;
+        let last_l2_block = sealed_l2_block
+            .0
+            .min(first_l2_block.0 + self.max_l2_blocks_per_iteration - 1);
+        let last_l2_block = L2BlockNumber(last_l2_block);
+
+        let mut transaction = storage.start_transaction().await?;
+        let inserted_count = transaction
+            .contract_verification_dal()
+            .insert_similar_matches_for_l2_blocks(first_l2_block..=last_l2_block)
+            .await?;
+        transaction
+            .contract_verification_dal()
+            .set_similar_matches_cursor(last_l2_block)
+            .await?;
+        transaction.commit().await?;
+
+        tracing::debug!(
+            "Scanned L2 blocks {first_l2_block}..={last_l2_block} for similar matches; inserted {inserted_count}"
+        );
+        API_CONTRACT_VERIFIER_METRICS
+            .similar_matches
+            .inc_by(inserted_count as u64);
+        Ok(last_l2_block < sealed_l2_block)
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow() {
+            let has_more_work = self
+                .run_once()
+                .await
+                .context("failed propagating similar matches")?;
+            if has_more_work {
+                continue;
+            }
+            tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop request received, shutting down similar match propagator");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use zksync_node_test_utils::create_l2_block;
+    use zksync_types::{
+        address_to_h256,
+        bytecode::BytecodeHash,
+        contract_verification::api::{
+            CompilationArtifacts, VerificationIncomingRequest, VerificationInfo,
+            VerificationRequest,
+        },
+        tx::IncludedTxLocation,
+        Address, L1BatchNumber, ProtocolVersion, CONTRACT_DEPLOYER_ADDRESS, H256,
+    };
+    use zksync_vm_interface::VmEvent;
+
+    use super::*;
+
+    const BYTECODE: [u8; 32] = [1; 32];
+
+    async fn mock_deployments(
+        storage: &mut Connection<'_, Core>,
+        l2_block: L2BlockNumber,
+        addresses: &[Address],
+    ) {
+        storage
+            .blocks_dal()
+            .insert_l2_block(&create_l2_block(l2_block.0))
+            .await
+            .unwrap();
+        let bytecode_hash = BytecodeHash::for_bytecode(&BYTECODE).value();
+        storage
+            .factory_deps_dal()
+            .insert_factory_deps(
+                l2_block,
+                &HashMap::from([(bytecode_hash, BYTECODE.to_vec())]),
+            )
+            .await
+            .unwrap();
+
+        let events: Vec<_> = addresses
+            .iter()
+            .map(|address| VmEvent {
+                location: (L1BatchNumber(0), 0),
+                address: CONTRACT_DEPLOYER_ADDRESS,
+                indexed_topics: vec![
+                    VmEvent::DEPLOY_EVENT_SIGNATURE,
+                    address_to_h256(&Address::repeat_byte(0xff)),
+                    bytecode_hash,
+                    address_to_h256(address),
+                ],
+                value: vec![],
+            })
+            .collect();
+        let location = IncludedTxLocation {
+            tx_hash: H256::repeat_byte(l2_block.0 as u8),
+            tx_index_in_l2_block: 0,
+        };
+        storage
+            .events_dal()
+            .save_events(l2_block, &[(location, events.iter().collect())])
+            .await
+            .unwrap();
+    }
+
+    async fn mock_verification(storage: &mut Connection<'_, Core>, address: Address) {
+        let request: VerificationIncomingRequest = serde_json::from_value(serde_json::json!({
+            "contractAddress": address,
+            "sourceCode": "contract Test {}",
+            "contractName": "Test",
+            "compilerZksolcVersion": "1.5.6",
+            "compilerSolcVersion": "0.8.27",
+            "optimizationUsed": true,
+        }))
+        .unwrap();
+        let id = storage
+            .contract_verification_dal()
+            .add_contract_verification_request(&request)
+            .await
+            .unwrap();
+        let info = VerificationInfo {
+            request: VerificationRequest { id, req: request },
+            artifacts: CompilationArtifacts {
+                bytecode: BYTECODE.to_vec(),
+                deployed_bytecode: None,
+                abi: Default::default(),
+                immutable_refs: Default::default(),
+            },
+            verified_at: Default::default(),
+            verification_problems: vec![],
+        };
+        storage
+            .contract_verification_dal()
+            .save_verification_info(info, H256::repeat_byte(1), H256::repeat_byte(2))
+            .await
+            .unwrap();
+    }
+
+    async fn similar_match_source(
+        storage: &mut Connection<'_, Core>,
+        address: Address,
+    ) -> Option<Address> {
+        storage
+            .contract_verification_dal()
+            .get_similar_match_verification_info(address)
+            .await
+            .unwrap()
+            .map(|info| info.request.req.contract_address)
+    }
+
+    #[tokio::test]
+    async fn propagating_similar_matches() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        storage
+            .protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let verified_address = Address::repeat_byte(1);
+        let old_address = Address::repeat_byte(2);
+        mock_deployments(
+            &mut storage,
+            L2BlockNumber(0),
+            &[verified_address, old_address],
+        )
+        .await;
+        mock_verification(&mut storage, verified_address).await;
+
+        let propagator = SimilarMatchPropagator::new(pool.clone());
+        let has_more_work = propagator.run_once().await.unwrap();
+        assert!(!has_more_work);
+        assert_eq!(
+            similar_match_source(&mut storage, old_address).await,
+            Some(verified_address)
+        );
+        assert_eq!(
+            similar_match_source(&mut storage, verified_address).await,
+            None
+        );
+        let cursor = storage
+            .contract_verification_dal()
+            .get_similar_matches_cursor()
+            .await
+            .unwrap();
+        assert_eq!(cursor, Some(L2BlockNumber(0)));
+
+        // New deployments should be picked up by the forward scan.
+        let new_address = Address::repeat_byte(3);
+        mock_deployments(&mut storage, L2BlockNumber(1), &[new_address]).await;
+        propagator.run_once().await.unwrap();
+        assert_eq!(
+            similar_match_source(&mut storage, new_address).await,
+            Some(verified_address)
+        );
+
+        // A contract with its own verification info should no longer be a similar match.
+        mock_verification(&mut storage, old_address).await;
+        assert_eq!(similar_match_source(&mut storage, old_address).await, None);
+        propagator.run_once().await.unwrap();
+        assert_eq!(similar_match_source(&mut storage, old_address).await, None);
+        assert_eq!(
+            similar_match_source(&mut storage, new_address).await,
+            Some(verified_address)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use async_trait::async_trait;
+use futures::future;
 
 use crate::types::{ClientType, DAError, DispatchResponse, FinalityResponse, InclusionData};
 
@@ -27,6 +28,12 @@ pub trait DataAvailabilityClient: Sync + Send + fmt::Debug {
     /// Fetches the inclusion data for a given blob_id.
     async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError>;
 
+    /// Fetches the pubdata dispatched for the specified L1 batch, if the DA layer supports retrieving it.
+    /// Used to recover node state from DA. Returns `None` if the pubdata is not available.
+    async fn get_pubdata(&self, _batch_number: u32) -> Result<Option<Vec<u8>>, DAError> {
+        Ok(None)
+    }
+
     /// Clones the client and wraps it in a Box.
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient>;
 
@@ -38,6 +45,48 @@ pub trait DataAvailabilityClient: Sync + Send + fmt::Debug {
 
     /// Returns the balance of the operator account.
     async fn balance(&self) -> Result<u64, DAError>;
+
+    /// Returns whether the client supports pubdata dispatched as several blobs, i.e. implements
+    /// [`Self::combine_inclusion_data()`].
+    fn supports_chunked_inclusion(&self) -> bool {
+        false
+    }
+
+    /// Combines the inclusion data of the chunks of pubdata that was dispatched as several blobs
+    /// (ordered by the chunk index) into the inclusion data passed to the L1 DA validator. The format
+    /// of the combined data is specific to the L1 DA validator used with the client.
+    ///
+    /// Must be implemented if [`Self::supports_chunked_inclusion()`] returns `true`.
+    async fn combine_inclusion_data(
+        &self,
+        _chunks: Vec<InclusionData>,
+    ) -> Result<InclusionData, DAError> {
+        Err(DAError {
+            error: anyhow::anyhow!(
+                "DA client doesn't support combining inclusion data for pubdata dispatched as several blobs"
+            ),
+            is_retriable: false,
+        })
+    }
+}
+
+/// Fetches the inclusion data for pubdata dispatched as several blobs with the specified IDs (ordered
+/// by the chunk index) and combines it. Returns `None` if the inclusion data for any of the chunks is not available yet.
+pub async fn fetch_chunks_inclusion_data(
+    client: &dyn DataAvailabilityClient,
+    chunk_blob_ids: &[String],
+) -> Result<Option<InclusionData>, DAError> {
+    let chunk_futures = chunk_blob_ids
+        .iter()
+        .map(|chunk_blob_id| client.get_inclusion_data(chunk_blob_id));
+    let chunks: Option<Vec<_>> = future::try_join_all(chunk_futures)
+        .await?
+        .into_iter()
+        .collect();
+    match chunks {
+        Some(chunks) => client.combine_inclusion_data(chunks).await.map(Some),
+        None => Ok(None),
+    }
 }
 
 impl Clone for Box<dyn DataAvailabilityClient> {
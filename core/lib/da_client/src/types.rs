@@ -61,6 +61,7 @@ pub enum ClientType {
     Celestia,
     Eigen,
     ObjectStore,
+    Sidecar,
 }
 
 impl ClientType {
@@ -71,6 +72,7 @@ impl ClientType {
             ClientType::Celestia => PubdataType::Celestia,
             ClientType::Eigen => PubdataType::Eigen,
             ClientType::ObjectStore => PubdataType::ObjectStore,
+            ClientType::Sidecar => PubdataType::Sidecar,
         }
     }
 }
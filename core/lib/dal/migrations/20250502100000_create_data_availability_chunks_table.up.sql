@@ -0,0 +1,16 @@
+-- Chunks of pubdata dispatched as separate blobs for batches which pubdata exceeds the blob size limit of the DA layer.
+-- The corresponding `data_availability` row holds the number of chunks and no dispatch request or blob ID.
+CREATE TABLE IF NOT EXISTS data_availability_chunks
+(
+    l1_batch_number     BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    chunk_index         INT       NOT NULL,
+
+    dispatch_request_id TEXT      NOT NULL,
+    blob_id             TEXT,
+    inclusion_data      BYTEA,
+    sent_at             TIMESTAMP NOT NULL,
+
+    created_at          TIMESTAMP NOT NULL,
+    updated_at          TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, chunk_index)
+);
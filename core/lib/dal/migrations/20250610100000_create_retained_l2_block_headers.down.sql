@@ -0,0 +1 @@
+DROP TABLE IF EXISTS retained_l2_block_headers;
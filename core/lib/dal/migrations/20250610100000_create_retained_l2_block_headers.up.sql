@@ -0,0 +1,9 @@
+-- Headers of hard-pruned L2 blocks that contain transactions / events retained by pruning retention rules.
+-- Allows to return block hash, L1 batch number and timestamp for retained data after the `miniblocks` row is removed.
+CREATE TABLE IF NOT EXISTS retained_l2_block_headers (
+    number BIGINT PRIMARY KEY,
+    hash BYTEA NOT NULL,
+    l1_batch_number BIGINT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
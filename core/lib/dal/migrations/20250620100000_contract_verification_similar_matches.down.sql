@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS contract_verification_similar_matches_cursor;
+ALTER TABLE contract_verification_info_v2 DROP COLUMN IF EXISTS similar_matches_backfilled;
+DROP TABLE IF EXISTS contract_verification_similar_matches;
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS contract_verification_similar_matches (
+    contract_address BYTEA NOT NULL PRIMARY KEY,
+    verified_contract_address BYTEA NOT NULL REFERENCES contract_verification_info_v2 (initial_contract_addr) ON DELETE CASCADE,
+    bytecode_hash BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS contract_verification_similar_matches_verified_contract_address_idx
+    ON contract_verification_similar_matches (verified_contract_address);
+
+-- Whether deployments of the same bytecode preceding the verification were scanned for similar matches.
+ALTER TABLE contract_verification_info_v2
+    ADD COLUMN IF NOT EXISTS similar_matches_backfilled BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Single-row table with the last L2 block scanned for deployments of verified bytecodes.
+CREATE TABLE IF NOT EXISTS contract_verification_similar_matches_cursor (
+    id BOOLEAN NOT NULL PRIMARY KEY DEFAULT TRUE CHECK (id),
+    last_processed_l2_block BIGINT NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
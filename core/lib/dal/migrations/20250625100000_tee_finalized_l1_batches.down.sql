@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_tee_proof_generation_details_generated_updated_at;
+DROP TABLE IF EXISTS tee_finalized_l1_batches;
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS tee_finalized_l1_batches (
+    l1_batch_number BIGINT PRIMARY KEY,
+    -- TEE types that provided proofs satisfying the quorum policy
+    tee_types TEXT [] NOT NULL,
+    -- Number of distinct attested keys that signed the proofs
+    distinct_keys INT NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+-- Used to find batches with newly generated TEE proofs.
+CREATE INDEX IF NOT EXISTS idx_tee_proof_generation_details_generated_updated_at
+    ON tee_proof_generation_details (updated_at) WHERE status = 'generated';
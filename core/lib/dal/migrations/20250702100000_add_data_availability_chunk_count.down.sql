@@ -0,0 +1 @@
+ALTER TABLE data_availability DROP COLUMN IF EXISTS chunk_count;
@@ -0,0 +1,3 @@
+-- Number of chunks for batches which pubdata was dispatched as several blobs (see `data_availability_chunks`).
+-- `NULL` for batches dispatched as a single blob.
+ALTER TABLE data_availability ADD COLUMN IF NOT EXISTS chunk_count INT;
@@ -583,6 +583,7 @@ impl proto::PubdataType {
             Self::Celestia => PubdataType::Celestia,
             Self::Eigen => PubdataType::Eigen,
             Self::ObjectStore => PubdataType::ObjectStore,
+            Self::Sidecar => PubdataType::Sidecar,
         }
     }
 }
@@ -2,6 +2,7 @@
 
 use std::{
     fmt::{Display, Formatter},
+    ops,
     time::Duration,
 };
 
@@ -19,8 +20,9 @@ use zksync_types::{
             VerificationRequestStatus,
         },
         contract_identifier::ContractIdentifier,
+        proxy::{ImplementationChange, UPGRADED_EVENT_SIGNATURE},
     },
-    web3, Address, CONTRACT_DEPLOYER_ADDRESS, H256,
+    h256_to_address, web3, Address, L2BlockNumber, CONTRACT_DEPLOYER_ADDRESS, H256,
 };
 use zksync_vm_interface::VmEvent;
 
@@ -265,7 +267,8 @@ impl ContractVerificationDal<'_, '_> {
             SET
             bytecode_keccak256 = $2,
             bytecode_without_metadata_keccak256 = $3,
-            verification_info = $4
+            verification_info = $4,
+            similar_matches_backfilled = FALSE
             "#,
             address.as_bytes(),
             bytecode_keccak256.as_bytes(),
@@ -278,6 +281,21 @@ impl ContractVerificationDal<'_, '_> {
         .execute(&mut transaction)
         .await?;
 
+        // The contract has its own verification info now, so it's no longer a similar match.
+        sqlx::query!(
+            r#"
+            DELETE FROM contract_verification_similar_matches
+            WHERE
+                contract_address = $1
+            "#,
+            address.as_bytes(),
+        )
+        .instrument("save_verification_info#remove_similar_match")
+        .with_arg("id", &id)
+        .with_arg("address", &address)
+        .execute(&mut transaction)
+        .await?;
+
         transaction.commit().await
     }
 
@@ -358,6 +376,29 @@ impl ContractVerificationDal<'_, '_> {
         .await
     }
 
+    /// Returns the address of the contract targeted by the specified verification request.
+    pub async fn get_verification_request_contract_address(
+        &mut self,
+        id: usize,
+    ) -> DalResult<Option<Address>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                contract_address
+            FROM
+                contract_verification_requests
+            WHERE
+                id = $1
+            "#,
+            id as i64,
+        )
+        .instrument("get_verification_request_contract_address")
+        .with_arg("id", &id)
+        .fetch_optional(self.storage)
+        .await
+        .map(|row| row.map(|row| Address::from_slice(&row.contract_address)))
+    }
+
     /// Returns bytecode and calldata from the contract and the transaction that created it.
     pub async fn get_contract_info_for_verification(
         &mut self,
@@ -422,6 +463,51 @@ impl ContractVerificationDal<'_, '_> {
         .await
     }
 
+    /// Returns up to `limit` latest implementation changes (i.e., `Upgraded` events) emitted by the specified
+    /// proxy or beacon contract, in chronological order.
+    pub async fn get_proxy_implementation_changes(
+        &mut self,
+        address: Address,
+        limit: usize,
+    ) -> DalResult<Vec<ImplementationChange>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                topic2,
+                miniblock_number,
+                tx_hash
+            FROM
+                events
+            WHERE
+                address = $1
+                AND topic1 = $2
+            ORDER BY
+                miniblock_number DESC,
+                event_index_in_block DESC
+            LIMIT
+                $3
+            "#,
+            address.as_bytes(),
+            UPGRADED_EVENT_SIGNATURE.as_bytes(),
+            limit as i64,
+        )
+        .instrument("get_proxy_implementation_changes")
+        .with_arg("address", &address)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .rev()
+            .map(|row| ImplementationChange {
+                implementation_address: h256_to_address(&H256::from_slice(&row.topic2)),
+                l2_block_number: L2BlockNumber(row.miniblock_number as u32),
+                tx_hash: H256::from_slice(&row.tx_hash),
+            })
+            .collect())
+    }
+
     async fn get_compiler_versions(&mut self, compiler: Compiler) -> DalResult<Vec<String>> {
         let compiler = format!("{compiler}");
         let versions: Vec<_> = sqlx::query!(
@@ -699,6 +785,248 @@ impl ContractVerificationDal<'_, '_> {
         .await
     }
 
+    /// Returns verification info propagated to the contract from a verified contract with the identical bytecode.
+    pub async fn get_similar_match_verification_info(
+        &mut self,
+        address: Address,
+    ) -> DalResult<Option<VerificationInfo>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                contract_verification_info_v2.verification_info
+            FROM
+                contract_verification_similar_matches
+            JOIN contract_verification_info_v2
+                ON
+                    contract_verification_info_v2.initial_contract_addr
+                    = contract_verification_similar_matches.verified_contract_address
+            WHERE
+                contract_verification_similar_matches.contract_address = $1
+            "#,
+            address.as_bytes(),
+        )
+        .try_map(|row| {
+            serde_json::from_value(row.verification_info).decode_column("verification_info")
+        })
+        .instrument("get_similar_match_verification_info")
+        .with_arg("address", &address)
+        .fetch_optional(self.storage)
+        .await
+    }
+
+    /// Returns addresses of verified contracts for which previously deployed contracts with the identical bytecode
+    /// were not searched for yet.
+    pub async fn get_contracts_pending_similar_matches_backfill(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<Address>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                initial_contract_addr
+            FROM
+                contract_verification_info_v2
+            WHERE
+                NOT similar_matches_backfilled
+            ORDER BY
+                created_at
+            LIMIT
+                $1
+            "#,
+            limit as i64,
+        )
+        .instrument("get_contracts_pending_similar_matches_backfill")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Address::from_slice(&row.initial_contract_addr))
+            .collect())
+    }
+
+    /// Records all deployed contracts with the specified bytecode hash (other than the verified contract itself
+    /// and contracts having their own verification info) as similar matches of the verified contract, and marks
+    /// the verified contract as backfilled. Returns the number of recorded similar matches.
+    pub async fn backfill_similar_matches(
+        &mut self,
+        verified_address: Address,
+        bytecode_hash: H256,
+    ) -> DalResult<usize> {
+        let mut transaction = self.storage.start_transaction().await?;
+        let inserted_count = sqlx::query!(
+            r#"
+            INSERT INTO
+            contract_verification_similar_matches (
+                contract_address, verified_contract_address, bytecode_hash
+            )
+            SELECT DISTINCT
+                SUBSTRING(events.topic4 FROM 13),
+                $3::BYTEA,
+                events.topic3
+            FROM
+                events
+            WHERE
+                events.address = $1
+                AND events.topic1 = $2
+                AND events.topic3 = $4
+                AND SUBSTRING(events.topic4 FROM 13) != $3
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        contract_verification_info_v2
+                    WHERE
+                        initial_contract_addr = SUBSTRING(events.topic4 FROM 13)
+                )
+            ON CONFLICT (contract_address) DO NOTHING
+            "#,
+            CONTRACT_DEPLOYER_ADDRESS.as_bytes(),
+            VmEvent::DEPLOY_EVENT_SIGNATURE.as_bytes(),
+            verified_address.as_bytes(),
+            bytecode_hash.as_bytes(),
+        )
+        .instrument("backfill_similar_matches#insert")
+        .with_arg("verified_address", &verified_address)
+        .with_arg("bytecode_hash", &bytecode_hash)
+        .execute(&mut transaction)
+        .await?
+        .rows_affected();
+
+        self.mark_similar_matches_backfilled_inner(&mut transaction, verified_address)
+            .await?;
+        transaction.commit().await?;
+        Ok(inserted_count as usize)
+    }
+
+    /// Marks the verified contract as backfilled without recording any similar matches. Used if the bytecode
+    /// of the contract cannot be determined.
+    pub async fn mark_similar_matches_backfilled(
+        &mut self,
+        verified_address: Address,
+    ) -> DalResult<()> {
+        Self::mark_similar_matches_backfilled_inner(self.storage, verified_address).await
+    }
+
+    async fn mark_similar_matches_backfilled_inner(
+        storage: &mut Connection<'_, Core>,
+        verified_address: Address,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE contract_verification_info_v2
+            SET
+                similar_matches_backfilled = TRUE
+            WHERE
+                initial_contract_addr = $1
+            "#,
+            verified_address.as_bytes(),
+        )
+        .instrument("mark_similar_matches_backfilled")
+        .with_arg("verified_address", &verified_address)
+        .execute(storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Records contracts deployed in the specified L2 block range that have the same bytecode hash as a verified
+    /// contract as similar matches of the latter. Returns the number of recorded similar matches.
+    pub async fn insert_similar_matches_for_l2_blocks(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<usize> {
+        let inserted_count = sqlx::query!(
+            r#"
+            INSERT INTO
+            contract_verification_similar_matches (
+                contract_address, verified_contract_address, bytecode_hash
+            )
+            SELECT DISTINCT
+            ON (SUBSTRING(new_deployments.topic4 FROM 13))
+                SUBSTRING(new_deployments.topic4 FROM 13),
+                contract_verification_info_v2.initial_contract_addr,
+                new_deployments.topic3
+            FROM
+                events new_deployments
+            JOIN events verified_deployments
+                ON
+                    verified_deployments.topic3 = new_deployments.topic3
+                    AND verified_deployments.address = $1
+                    AND verified_deployments.topic1 = $2
+            JOIN contract_verification_info_v2
+                ON
+                    contract_verification_info_v2.initial_contract_addr
+                    = SUBSTRING(verified_deployments.topic4 FROM 13)
+            WHERE
+                new_deployments.address = $1
+                AND new_deployments.topic1 = $2
+                AND new_deployments.miniblock_number BETWEEN $3 AND $4
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        contract_verification_info_v2 own_info
+                    WHERE
+                        own_info.initial_contract_addr = SUBSTRING(new_deployments.topic4 FROM 13)
+                )
+            ORDER BY
+                SUBSTRING(new_deployments.topic4 FROM 13),
+                contract_verification_info_v2.created_at
+            ON CONFLICT (contract_address) DO NOTHING
+            "#,
+            CONTRACT_DEPLOYER_ADDRESS.as_bytes(),
+            VmEvent::DEPLOY_EVENT_SIGNATURE.as_bytes(),
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0),
+        )
+        .instrument("insert_similar_matches_for_l2_blocks")
+        .with_arg("l2_blocks", &l2_blocks)
+        .execute(self.storage)
+        .await?
+        .rows_affected();
+        Ok(inserted_count as usize)
+    }
+
+    /// Returns the last L2 block scanned for deployments of verified bytecodes.
+    pub async fn get_similar_matches_cursor(&mut self) -> DalResult<Option<L2BlockNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                last_processed_l2_block
+            FROM
+                contract_verification_similar_matches_cursor
+            "#
+        )
+        .instrument("get_similar_matches_cursor")
+        .fetch_optional(self.storage)
+        .await?;
+        Ok(row.map(|row| L2BlockNumber(row.last_processed_l2_block as u32)))
+    }
+
+    /// Sets the last L2 block scanned for deployments of verified bytecodes.
+    pub async fn set_similar_matches_cursor(&mut self, l2_block: L2BlockNumber) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            contract_verification_similar_matches_cursor (id, last_processed_l2_block)
+            VALUES
+            (TRUE, $1)
+            ON CONFLICT (id) DO
+            UPDATE
+            SET
+            last_processed_l2_block = $1,
+            updated_at = NOW()
+            "#,
+            i64::from(l2_block.0),
+        )
+        .instrument("set_similar_matches_cursor")
+        .with_arg("l2_block", &l2_block)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
     /// Checks if migration from `contracts_verification_info` to `contract_verification_info_v2` is performed
     /// by checking if the latter has more or equal number of rows.
     pub async fn is_verification_info_migration_performed(&mut self) -> DalResult<bool> {
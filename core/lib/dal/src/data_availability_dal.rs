@@ -6,12 +6,14 @@ use zksync_db_connection::{
 use zksync_types::{
     commitment::PubdataType,
     l2_to_l1_log::L2ToL1Log,
-    pubdata_da::{DataAvailabilityBlob, DataAvailabilityDetails},
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityChunk, DataAvailabilityDetails},
     Address, L1BatchNumber,
 };
 
 use crate::{
-    models::storage_data_availability::{L1BatchDA, StorageDABlob, StorageDADetails},
+    models::storage_data_availability::{
+        L1BatchDA, StorageDABlob, StorageDAChunk, StorageDADetails,
+    },
     Core,
 };
 
@@ -203,11 +205,27 @@ impl DataAvailabilityDal<'_, '_> {
                 dispatch_request_id,
                 blob_id,
                 inclusion_data,
+                chunk_count,
                 sent_at
             FROM
                 data_availability
             WHERE
-                blob_id IS NULL
+                (
+                    chunk_count IS NULL
+                    AND blob_id IS NULL
+                )
+                OR (
+                    chunk_count IS NOT NULL
+                    AND (
+                        SELECT
+                            COUNT(*)
+                        FROM
+                            data_availability_chunks
+                        WHERE
+                            data_availability_chunks.l1_batch_number = data_availability.l1_batch_number
+                            AND data_availability_chunks.blob_id IS NOT NULL
+                    ) < chunk_count
+                )
             ORDER BY
                 l1_batch_number
             LIMIT
@@ -268,6 +286,7 @@ impl DataAvailabilityDal<'_, '_> {
     }
 
     /// Assumes that the L1 batches are sorted by number, and returns the first one that is ready for DA dispatch.
+    /// Batches dispatched as several blobs are returned once all their chunks are final.
     pub async fn get_first_da_blob_awaiting_inclusion(
         &mut self,
     ) -> DalResult<Option<DataAvailabilityBlob>> {
@@ -279,12 +298,27 @@ impl DataAvailabilityDal<'_, '_> {
                 blob_id,
                 dispatch_request_id,
                 inclusion_data,
+                chunk_count,
                 sent_at
             FROM
                 data_availability
             WHERE
                 inclusion_data IS NULL
-                AND blob_id IS NOT NULL
+                AND (
+                    blob_id IS NOT NULL
+                    OR (
+                        chunk_count IS NOT NULL
+                        AND (
+                            SELECT
+                                COUNT(*)
+                            FROM
+                                data_availability_chunks
+                            WHERE
+                                data_availability_chunks.l1_batch_number = data_availability.l1_batch_number
+                                AND data_availability_chunks.blob_id IS NOT NULL
+                        ) = chunk_count
+                    )
+                )
             ORDER BY
                 l1_batch_number
             LIMIT
@@ -348,6 +382,8 @@ impl DataAvailabilityDal<'_, '_> {
             .collect())
     }
 
+    /// Returns DA details for the given L1 batch once its blob (or all chunks of its pubdata if it was dispatched
+    /// as several blobs) is final.
     pub async fn get_da_details_by_batch_number(
         &mut self,
         number: L1BatchNumber,
@@ -356,16 +392,43 @@ impl DataAvailabilityDal<'_, '_> {
             StorageDADetails,
             r#"
             SELECT
-                blob_id AS "blob_id!",
+                COALESCE(blob_id, '') AS "blob_id!",
                 client_type,
                 inclusion_data,
                 sent_at,
-                l2_da_validator_address
+                l2_da_validator_address,
+                CASE
+                    WHEN chunk_count IS NULL THEN NULL
+                    ELSE ARRAY(
+                        SELECT
+                            data_availability_chunks.blob_id
+                        FROM
+                            data_availability_chunks
+                        WHERE
+                            data_availability_chunks.l1_batch_number = data_availability.l1_batch_number
+                        ORDER BY
+                            chunk_index
+                    )
+                END AS chunk_blob_ids
             FROM
                 data_availability
             WHERE
                 l1_batch_number = $1
-                AND blob_id IS NOT NULL
+                AND (
+                    blob_id IS NOT NULL
+                    OR (
+                        chunk_count IS NOT NULL
+                        AND (
+                            SELECT
+                                COUNT(*)
+                            FROM
+                                data_availability_chunks
+                            WHERE
+                                data_availability_chunks.l1_batch_number = data_availability.l1_batch_number
+                                AND data_availability_chunks.blob_id IS NOT NULL
+                        ) = chunk_count
+                    )
+                )
             "#,
             i64::from(number.0),
         )
@@ -521,4 +584,494 @@ impl DataAvailabilityDal<'_, '_> {
 
         Ok(())
     }
+
+    /// Inserts the DA entry for the given L1 batch which pubdata was dispatched as several blobs, together with
+    /// the dispatch request IDs of its chunks. `dispatch_request_ids` must be ordered by the chunk index.
+    /// Should be called inside a transaction.
+    pub async fn insert_l1_batch_da_chunks(
+        &mut self,
+        number: L1BatchNumber,
+        dispatch_request_ids: &[String],
+        sent_at: chrono::NaiveDateTime,
+        pubdata_type: PubdataType,
+        l2_validator_address: Option<Address>,
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            INSERT INTO
+            data_availability (
+                l1_batch_number,
+                chunk_count,
+                client_type,
+                l2_da_validator_address,
+                sent_at,
+                created_at,
+                updated_at
+            )
+            VALUES
+            ($1, $2, $3, $4, $5, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            dispatch_request_ids.len() as i32,
+            pubdata_type.to_string(),
+            l2_validator_address.map(|addr| addr.as_bytes().to_vec()),
+            sent_at,
+        )
+        .instrument("insert_l1_batch_da_chunks#parent")
+        .with_arg("number", &number)
+        .with_arg("chunk_count", &dispatch_request_ids.len())
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            tracing::error!(
+                "L1 batch #{number}: chunked batch DA was attempted to be inserted twice"
+            );
+        }
+
+        let chunk_indices: Vec<i32> = (0..dispatch_request_ids.len() as i32).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            data_availability_chunks (
+                l1_batch_number,
+                chunk_index,
+                dispatch_request_id,
+                sent_at,
+                created_at,
+                updated_at
+            )
+            SELECT
+                $1,
+                chunk_index,
+                dispatch_request_id,
+                $4,
+                NOW(),
+                NOW()
+            FROM
+                UNNEST($2::INT [], $3::TEXT []) AS u (chunk_index, dispatch_request_id)
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            &chunk_indices,
+            dispatch_request_ids,
+            sent_at,
+        )
+        .instrument("insert_l1_batch_da_chunks")
+        .with_arg("number", &number)
+        .with_arg("chunk_count", &dispatch_request_ids.len())
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Returns all chunks of the pubdata of the given L1 batch ordered by the chunk index. Returns an empty vector
+    /// if the pubdata was dispatched as a single blob.
+    pub async fn get_l1_batch_da_chunks(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Vec<DataAvailabilityChunk>> {
+        let rows = sqlx::query_as!(
+            StorageDAChunk,
+            r#"
+            SELECT
+                chunk_index,
+                dispatch_request_id,
+                blob_id,
+                inclusion_data
+            FROM
+                data_availability_chunks
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                chunk_index
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_chunks")
+        .with_arg("number", &number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.into_iter().map(DataAvailabilityChunk::from).collect())
+    }
+
+    /// Inserts the DA entry for the given L1 batch which pubdata was dispatched as several blobs with the specified
+    /// blob IDs (ordered by the chunk index), along with the combined inclusion data. Used on external nodes,
+    /// which don't know dispatch request IDs. Should be called inside a transaction.
+    pub async fn insert_l1_batch_da_with_chunks(
+        &mut self,
+        number: L1BatchNumber,
+        chunk_blob_ids: &[String],
+        sent_at: chrono::NaiveDateTime,
+        pubdata_type: PubdataType,
+        da_inclusion_data: Option<&[u8]>,
+        l2_validator_address: Option<Address>,
+    ) -> DalResult<()> {
+        let instrumentation = Instrumented::new("insert_l1_batch_da_with_chunks")
+            .with_arg("number", &number)
+            .with_arg("chunk_blob_ids", &chunk_blob_ids)
+            .with_arg("sent_at", &sent_at)
+            .with_arg("pubdata_type", &pubdata_type)
+            .with_arg("da_inclusion_data", &da_inclusion_data)
+            .with_arg("l2_validator_address", &l2_validator_address);
+
+        let query = sqlx::query!(
+            r#"
+            INSERT INTO
+            data_availability (
+                l1_batch_number,
+                chunk_count,
+                inclusion_data,
+                client_type,
+                l2_da_validator_address,
+                sent_at,
+                created_at,
+                updated_at
+            )
+            VALUES
+            ($1, $2, $3, $4, $5, $6, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            chunk_blob_ids.len() as i32,
+            da_inclusion_data,
+            pubdata_type.to_string(),
+            l2_validator_address.map(|addr| addr.as_bytes().to_vec()),
+            sent_at,
+        );
+        let update_result = instrumentation
+            .clone()
+            .with(query)
+            .report_latency()
+            .execute(self.storage)
+            .await?;
+
+        if update_result.rows_affected() == 0 {
+            let err = instrumentation.constraint_error(anyhow::anyhow!(
+                "L1 batch #{number}: batch DA was attempted to be inserted twice"
+            ));
+            return Err(err);
+        }
+
+        let chunk_indices: Vec<i32> = (0..chunk_blob_ids.len() as i32).collect();
+        let query = sqlx::query!(
+            r#"
+            INSERT INTO
+            data_availability_chunks (
+                l1_batch_number,
+                chunk_index,
+                dispatch_request_id,
+                blob_id,
+                sent_at,
+                created_at,
+                updated_at
+            )
+            SELECT
+                $1,
+                chunk_index,
+                '',
+                blob_id,
+                $4,
+                NOW(),
+                NOW()
+            FROM
+                UNNEST($2::INT [], $3::TEXT []) AS u (chunk_index, blob_id)
+            "#,
+            i64::from(number.0),
+            &chunk_indices,
+            chunk_blob_ids,
+            sent_at,
+        );
+        instrumentation
+            .with(query)
+            .report_latency()
+            .execute(self.storage)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_chunk_blob_id(
+        &mut self,
+        number: L1BatchNumber,
+        chunk_index: u32,
+        blob_id: &str,
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            UPDATE data_availability_chunks
+            SET
+                blob_id = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND chunk_index = $3
+            "#,
+            blob_id,
+            i64::from(number.0),
+            chunk_index as i32,
+        )
+        .instrument("set_chunk_blob_id")
+        .with_arg("number", &number)
+        .with_arg("chunk_index", &chunk_index)
+        .with_arg("blob_id", &blob_id)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            tracing::error!("L1 batch #{number}: blob_id for chunk #{chunk_index} wasn't updated");
+        }
+        Ok(())
+    }
+
+    pub async fn save_chunk_inclusion_data(
+        &mut self,
+        number: L1BatchNumber,
+        chunk_index: u32,
+        da_inclusion_data: &[u8],
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_availability_chunks
+            SET
+                inclusion_data = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND chunk_index = $3
+                AND inclusion_data IS NULL
+            "#,
+            da_inclusion_data,
+            i64::from(number.0),
+            chunk_index as i32,
+        )
+        .instrument("save_chunk_inclusion_data")
+        .with_arg("number", &number)
+        .with_arg("chunk_index", &chunk_index)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_data_availability_chunks(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM data_availability_chunks
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("remove_data_availability_chunks")
+        .with_arg("number", &number)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::ProtocolVersion;
+
+    use super::*;
+    use crate::{tests::create_l1_batch_header, ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn data_availability_chunks_workflow() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        for number in [1, 2] {
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&create_l1_batch_header(number))
+                .await
+                .unwrap();
+        }
+
+        let sent_at = chrono::Utc::now().naive_utc();
+        let request_ids: Vec<_> = (0..3).map(|i| format!("request-{i}")).collect();
+        for number in [L1BatchNumber(1), L1BatchNumber(2)] {
+            conn.data_availability_dal()
+                .insert_l1_batch_da_chunks(
+                    number,
+                    &request_ids,
+                    sent_at,
+                    PubdataType::Avail,
+                    Some(Address::repeat_byte(1)),
+                )
+                .await
+                .unwrap();
+        }
+        let blob = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_finality()
+            .await
+            .unwrap()
+            .expect("no blob awaiting finality");
+        assert_eq!(blob.l1_batch_number, L1BatchNumber(1));
+        assert_eq!(blob.chunk_count, Some(3));
+        assert_eq!(blob.blob_id, None);
+
+        let chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 3);
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.chunk_index, i as u32);
+            assert_eq!(chunk.dispatch_request_id, request_ids[i]);
+            assert_eq!(chunk.blob_id, None);
+            assert_eq!(chunk.inclusion_data, None);
+        }
+
+        conn.data_availability_dal()
+            .set_chunk_blob_id(L1BatchNumber(1), 1, "blob-1")
+            .await
+            .unwrap();
+        conn.data_availability_dal()
+            .save_chunk_inclusion_data(L1BatchNumber(1), 1, b"inclusion-1")
+            .await
+            .unwrap();
+        // Already saved inclusion data must not be overwritten.
+        conn.data_availability_dal()
+            .save_chunk_inclusion_data(L1BatchNumber(1), 1, b"other")
+            .await
+            .unwrap();
+
+        let chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(chunks[1].blob_id.as_deref(), Some("blob-1"));
+        assert_eq!(
+            chunks[1].inclusion_data.as_deref(),
+            Some(&b"inclusion-1"[..])
+        );
+        for chunk in [&chunks[0], &chunks[2]] {
+            assert_eq!(chunk.blob_id, None);
+            assert_eq!(chunk.inclusion_data, None);
+        }
+        // The batch isn't awaiting inclusion until all its chunks are final.
+        let blob = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_inclusion()
+            .await
+            .unwrap();
+        assert!(blob.is_none());
+        let details = conn
+            .data_availability_dal()
+            .get_da_details_by_batch_number(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(details.is_none());
+
+        for chunk_index in [0, 2] {
+            conn.data_availability_dal()
+                .set_chunk_blob_id(
+                    L1BatchNumber(1),
+                    chunk_index,
+                    &format!("blob-{chunk_index}"),
+                )
+                .await
+                .unwrap();
+        }
+        let blob = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_finality()
+            .await
+            .unwrap()
+            .expect("no blob awaiting finality");
+        assert_eq!(blob.l1_batch_number, L1BatchNumber(2));
+        let blob = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_inclusion()
+            .await
+            .unwrap()
+            .expect("no blob awaiting inclusion");
+        assert_eq!(blob.l1_batch_number, L1BatchNumber(1));
+        let details = conn
+            .data_availability_dal()
+            .get_da_details_by_batch_number(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .expect("no DA details");
+        assert_eq!(details.blob_id, "");
+        let expected_blob_ids: Vec<_> = (0..3).map(|i| format!("blob-{i}")).collect();
+        assert_eq!(details.chunk_blob_ids, Some(expected_blob_ids));
+
+        conn.data_availability_dal()
+            .remove_data_availability_chunks(L1BatchNumber(1))
+            .await
+            .unwrap();
+        let chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(chunks.is_empty());
+        // Chunks of other batches must be retained.
+        let chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(L1BatchNumber(2))
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn inserting_da_with_chunks() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+
+        let blob_ids: Vec<_> = (0..2).map(|i| format!("blob-{i}")).collect();
+        conn.data_availability_dal()
+            .insert_l1_batch_da_with_chunks(
+                L1BatchNumber(1),
+                &blob_ids,
+                chrono::Utc::now().naive_utc(),
+                PubdataType::Avail,
+                Some(&b"combined"[..]),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let details = conn
+            .data_availability_dal()
+            .get_da_details_by_batch_number(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .expect("no DA details");
+        assert_eq!(details.blob_id, "");
+        assert_eq!(details.chunk_blob_ids, Some(blob_ids));
+        assert_eq!(details.inclusion_data.as_deref(), Some(&b"combined"[..]));
+        assert_eq!(details.pubdata_type, Some(PubdataType::Avail));
+    }
 }
@@ -1,3 +1,5 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
 use sqlx::{
     postgres::PgArguments,
     query::{Query, QueryAs},
@@ -72,7 +74,8 @@ impl EventsWeb3Dal<'_, '_> {
         Ok(log.map(|row| L2BlockNumber(row.get::<i64, _>("miniblock_number") as u32)))
     }
 
-    /// Returns logs for given filter.
+    /// Returns logs for given filter. Logs retained during pruning (see [`PruningRetentionRules`](crate::pruning_dal::PruningRetentionRules))
+    /// are returned as well.
     #[allow(clippy::type_complexity)]
     pub async fn get_logs(&mut self, filter: GetLogsFilter, limit: usize) -> DalResult<Vec<Log>> {
         let (where_sql, arg_index) = self.build_get_logs_where_clause(&filter);
@@ -88,10 +91,16 @@ impl EventsWeb3Dal<'_, '_> {
                 ORDER BY miniblock_number ASC, event_index_in_block ASC
                 LIMIT ${}
             )
-            SELECT miniblocks.hash as "block_hash", miniblocks.l1_batch_number as "l1_batch_number",
-                miniblocks.timestamp as block_timestamp, events_select.*
+            SELECT
+                COALESCE(miniblocks.hash, retained.hash) as "block_hash",
+                COALESCE(miniblocks.l1_batch_number, retained.l1_batch_number) as "l1_batch_number",
+                COALESCE(miniblocks.timestamp, retained.timestamp) as block_timestamp,
+                events_select.*
             FROM events_select
-            INNER JOIN miniblocks ON events_select.miniblock_number = miniblocks.number
+            LEFT JOIN miniblocks ON events_select.miniblock_number = miniblocks.number
+            LEFT JOIN retained_l2_block_headers AS retained
+                ON events_select.miniblock_number = retained.number
+            WHERE miniblocks.number IS NOT NULL OR retained.number IS NOT NULL
             ORDER BY miniblock_number ASC, event_index_in_block ASC
             "#,
             where_sql, arg_index
@@ -252,6 +261,44 @@ impl EventsWeb3Dal<'_, '_> {
         Ok(logs)
     }
 
+    /// Returns distinct addresses from indexed topics (`topic2` and `topic3`) of events emitted by the specified `address`
+    /// in the specified L2 block range. Topics not representing an address are skipped.
+    pub async fn get_topic_addresses_in_l2_blocks(
+        &mut self,
+        address: Address,
+        l2_block_numbers: RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<HashSet<Address>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                topic2,
+                topic3
+            FROM
+                events
+            WHERE
+                address = $1
+                AND miniblock_number BETWEEN $2 AND $3
+            "#,
+            address.as_bytes(),
+            i64::from(l2_block_numbers.start().0),
+            i64::from(l2_block_numbers.end().0)
+        )
+        .instrument("get_topic_addresses_in_l2_blocks")
+        .with_arg("address", &address)
+        .with_arg("l2_block_numbers", &l2_block_numbers)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        let topics = rows
+            .into_iter()
+            .flat_map(|row| [row.topic2, row.topic3])
+            .filter(|topic| topic.len() == 32 && topic[..12].iter().all(|&byte| byte == 0));
+        Ok(topics
+            .map(|topic| h256_to_address(&H256::from_slice(&topic)))
+            .collect())
+    }
+
     /// Gets all contract deployment logs for the specified block. The returned logs are ordered by their execution order.
     pub async fn get_contract_deployment_logs(
         &mut self,
@@ -1,7 +1,7 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 use zksync_types::{
     l2_to_l1_log::L2ToL1Log,
-    pubdata_da::{DataAvailabilityBlob, DataAvailabilityDetails},
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityChunk, DataAvailabilityDetails},
     Address, L1BatchNumber,
 };
 
@@ -12,6 +12,7 @@ pub(crate) struct StorageDABlob {
     pub dispatch_request_id: String,
     pub blob_id: Option<String>,
     pub inclusion_data: Option<Vec<u8>>,
+    pub chunk_count: Option<i32>,
     pub sent_at: NaiveDateTime,
 }
 
@@ -22,11 +23,32 @@ impl From<StorageDABlob> for DataAvailabilityBlob {
             dispatch_request_id: blob.dispatch_request_id,
             blob_id: blob.blob_id,
             inclusion_data: blob.inclusion_data,
+            chunk_count: blob.chunk_count.map(|count| count as u32),
             sent_at: blob.sent_at.and_utc(),
         }
     }
 }
 
+/// Represents a chunk of pubdata dispatched as a separate blob.
+#[derive(Debug, Clone)]
+pub(crate) struct StorageDAChunk {
+    pub chunk_index: i32,
+    pub dispatch_request_id: String,
+    pub blob_id: Option<String>,
+    pub inclusion_data: Option<Vec<u8>>,
+}
+
+impl From<StorageDAChunk> for DataAvailabilityChunk {
+    fn from(chunk: StorageDAChunk) -> DataAvailabilityChunk {
+        DataAvailabilityChunk {
+            chunk_index: chunk.chunk_index as u32,
+            dispatch_request_id: chunk.dispatch_request_id,
+            blob_id: chunk.blob_id,
+            inclusion_data: chunk.inclusion_data,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StorageDADetails {
     pub blob_id: String,
@@ -34,12 +56,13 @@ pub struct StorageDADetails {
     pub inclusion_data: Option<Vec<u8>>,
     pub sent_at: NaiveDateTime,
     pub l2_da_validator_address: Option<Vec<u8>>,
+    pub chunk_blob_ids: Option<Vec<String>>,
 }
 
 impl From<StorageDADetails> for DataAvailabilityDetails {
     fn from(row: StorageDADetails) -> DataAvailabilityDetails {
         DataAvailabilityDetails {
-            // safe to unwrap because query is guaranteed to return a non-null blob_id
+            // empty for batches dispatched as several blobs; their blob IDs are in `chunk_blob_ids`
             blob_id: row.blob_id,
             // safe to unwrap because the value in the database is assumed to be always correct
             pubdata_type: row.client_type.map(|t| t.parse().unwrap()),
@@ -48,6 +71,7 @@ impl From<StorageDADetails> for DataAvailabilityDetails {
             l2_da_validator: row
                 .l2_da_validator_address
                 .map(|addr| Address::from_slice(addr.as_slice())),
+            chunk_blob_ids: row.chunk_blob_ids,
         }
     }
 }
@@ -1,7 +1,7 @@
-use std::ops;
+use std::{collections::HashSet, ops};
 
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
-use zksync_types::{L1BatchNumber, L2BlockNumber, H256};
+use zksync_types::{Address, L1BatchNumber, L2BlockNumber, H256};
 
 use crate::Core;
 
@@ -40,6 +40,41 @@ impl PruningInfo {
     }
 }
 
+/// Rules specifying which transactions (together with their events and call traces) are retained
+/// during hard pruning.
+///
+/// Retention works on the transaction level: a transaction is retained if it's initiated by or sent to
+/// one of [`Self::addresses`], or if it has emitted an event matching the rules (i.e., the event is emitted
+/// by one of [`Self::addresses`], or its first topic is one of [`Self::topics`]). All events and the call trace
+/// of a retained transaction are retained as well, so that its receipt is complete. L2-to-L1 logs
+/// are not retained.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PruningRetentionRules {
+    /// Retained contract / account addresses.
+    pub addresses: Vec<Address>,
+    /// Retained event topics. Matched against the first topic of an event (i.e., the event signature
+    /// for Solidity events).
+    pub topics: Vec<H256>,
+}
+
+impl PruningRetentionRules {
+    /// Returns `true` if these rules don't retain any data.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty() && self.topics.is_empty()
+    }
+
+    /// Checks whether all events matching the specified `eth_getLogs`-like filter are retained
+    /// by these rules. `topics` are specified as (1-based topic index, allowed values) tuples.
+    pub fn covers_logs_filter(&self, addresses: &[Address], topics: &[(u32, Vec<H256>)]) -> bool {
+        let covered_by_address =
+            !addresses.is_empty() && addresses.iter().all(|addr| self.addresses.contains(addr));
+        let covered_by_topic = topics.iter().any(|(idx, values)| {
+            *idx == 1 && !values.is_empty() && values.iter().all(|val| self.topics.contains(val))
+        });
+        covered_by_address || covered_by_topic
+    }
+}
+
 /// Statistics about a single hard pruning iteration.
 #[derive(Debug, Default)]
 pub struct HardPruningStats {
@@ -49,6 +84,8 @@ pub struct HardPruningStats {
     pub deleted_events: u64,
     pub deleted_call_traces: u64,
     pub deleted_l2_to_l1_logs: u64,
+    /// Number of transactions retained according to [`PruningRetentionRules`].
+    pub retained_transactions: u64,
 }
 
 #[derive(Debug)]
@@ -193,6 +230,23 @@ impl PruningDal<'_, '_> {
         &mut self,
         last_l1_batch_to_prune: L1BatchNumber,
         last_l2_block_to_prune: L2BlockNumber,
+    ) -> DalResult<HardPruningStats> {
+        self.hard_prune_batches_range_with_retention(
+            last_l1_batch_to_prune,
+            last_l2_block_to_prune,
+            &PruningRetentionRules::default(),
+        )
+        .await
+    }
+
+    /// Same as [`Self::hard_prune_batches_range()`], but retains data matching the provided `retention_rules`.
+    /// Headers of L2 blocks containing retained transactions are moved to a separate table, so that retained data
+    /// can still be returned with the block hash, L1 batch number and timestamp.
+    pub async fn hard_prune_batches_range_with_retention(
+        &mut self,
+        last_l1_batch_to_prune: L1BatchNumber,
+        last_l2_block_to_prune: L2BlockNumber,
+        retention_rules: &PruningRetentionRules,
     ) -> DalResult<HardPruningStats> {
         let row = sqlx::query!(
             r#"
@@ -217,17 +271,41 @@ impl PruningDal<'_, '_> {
 
         let first_l2_block_to_prune = L2BlockNumber(first_l2_block_to_prune as u32);
 
+        let retained_txs = if retention_rules.is_empty() {
+            vec![]
+        } else {
+            self.get_retained_transactions(
+                first_l2_block_to_prune..=last_l2_block_to_prune,
+                retention_rules,
+            )
+            .await?
+        };
+        let retained_l2_blocks: HashSet<_> =
+            retained_txs.iter().map(|&(_, number)| number).collect();
+        let retained_l2_blocks: Vec<_> = retained_l2_blocks.into_iter().collect();
+        self.retain_l2_block_headers(&retained_l2_blocks).await?;
+        let retained_tx_hashes: Vec<_> = retained_txs.iter().map(|&(hash, _)| hash).collect();
+
         let deleted_events = self
-            .delete_events(first_l2_block_to_prune..=last_l2_block_to_prune)
+            .delete_events(
+                first_l2_block_to_prune..=last_l2_block_to_prune,
+                &retained_tx_hashes,
+            )
             .await?;
         let deleted_l2_to_l1_logs = self
             .delete_l2_to_l1_logs(first_l2_block_to_prune..=last_l2_block_to_prune)
             .await?;
         let deleted_call_traces = self
-            .delete_call_traces(first_l2_block_to_prune..=last_l2_block_to_prune)
-            .await?;
-        self.clear_transaction_fields(first_l2_block_to_prune..=last_l2_block_to_prune)
+            .delete_call_traces(
+                first_l2_block_to_prune..=last_l2_block_to_prune,
+                &retained_tx_hashes,
+            )
             .await?;
+        self.clear_transaction_fields(
+            first_l2_block_to_prune..=last_l2_block_to_prune,
+            &retained_tx_hashes,
+        )
+        .await?;
 
         let deleted_storage_logs = self
             .prune_storage_logs(first_l2_block_to_prune..=last_l2_block_to_prune)
@@ -242,25 +320,124 @@ impl PruningDal<'_, '_> {
             deleted_l2_to_l1_logs,
             deleted_call_traces,
             deleted_storage_logs,
+            retained_transactions: retained_txs.len() as u64,
         };
         Ok(stats)
     }
 
+    /// Returns hashes of transactions retained according to `retention_rules`, together with the containing L2 blocks.
+    async fn get_retained_transactions(
+        &mut self,
+        l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retention_rules: &PruningRetentionRules,
+    ) -> DalResult<Vec<(H256, L2BlockNumber)>> {
+        let addresses: Vec<_> = retention_rules
+            .addresses
+            .iter()
+            .map(Address::as_bytes)
+            .collect();
+        let topics: Vec<_> = retention_rules.topics.iter().map(H256::as_bytes).collect();
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                hash AS "hash!",
+                miniblock_number AS "miniblock_number!"
+            FROM
+                transactions
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+                AND (
+                    initiator_address = ANY($3)
+                    OR contract_address = ANY($3)
+                )
+            
+            UNION
+            
+            SELECT DISTINCT
+                tx_hash AS "hash!",
+                miniblock_number AS "miniblock_number!"
+            FROM
+                events
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+                AND (
+                    address = ANY($3)
+                    OR topic1 = ANY($4)
+                )
+            "#,
+            i64::from(l2_blocks_to_prune.start().0),
+            i64::from(l2_blocks_to_prune.end().0),
+            &addresses as &[&[u8]],
+            &topics as &[&[u8]]
+        )
+        .instrument("hard_prune_batches_range#get_retained_transactions")
+        .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
+        .with_arg("retention_rules", retention_rules)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let number = L2BlockNumber(row.miniblock_number as u32);
+                (H256::from_slice(&row.hash), number)
+            })
+            .collect())
+    }
+
+    async fn retain_l2_block_headers(&mut self, l2_blocks: &[L2BlockNumber]) -> DalResult<u64> {
+        if l2_blocks.is_empty() {
+            return Ok(0);
+        }
+
+        let l2_block_numbers: Vec<_> = l2_blocks.iter().map(|number| i64::from(number.0)).collect();
+        let execution_result = sqlx::query!(
+            r#"
+            INSERT INTO
+            retained_l2_block_headers (number, hash, l1_batch_number, timestamp, created_at)
+            SELECT
+                number,
+                hash,
+                l1_batch_number,
+                timestamp,
+                NOW()
+            FROM
+                miniblocks
+            WHERE
+                number = ANY($1)
+            ON CONFLICT (number) DO NOTHING
+            "#,
+            &l2_block_numbers
+        )
+        .instrument("hard_prune_batches_range#retain_l2_block_headers")
+        .with_arg("l2_blocks.len", &l2_blocks.len())
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(execution_result.rows_affected())
+    }
+
     async fn delete_events(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_tx_hashes: &[H256],
     ) -> DalResult<u64> {
+        let retained_tx_hashes: Vec<_> = retained_tx_hashes.iter().map(H256::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             DELETE FROM events
             WHERE
                 miniblock_number BETWEEN $1 AND $2
+                AND NOT (tx_hash = ANY($3))
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_tx_hashes as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#delete_events")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
+        .with_arg("retained_tx_hashes.len", &retained_tx_hashes.len())
         .report_latency()
         .execute(self.storage)
         .await?;
@@ -294,7 +471,9 @@ impl PruningDal<'_, '_> {
     async fn delete_call_traces(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_tx_hashes: &[H256],
     ) -> DalResult<u64> {
+        let retained_tx_hashes: Vec<_> = retained_tx_hashes.iter().map(H256::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             DELETE FROM call_traces
@@ -306,13 +485,16 @@ impl PruningDal<'_, '_> {
                         transactions
                     WHERE
                         miniblock_number BETWEEN $1 AND $2
+                        AND NOT (hash = ANY($3))
                 )
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_tx_hashes as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#delete_call_traces")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
+        .with_arg("retained_tx_hashes.len", &retained_tx_hashes.len())
         .report_latency()
         .execute(self.storage)
         .await?;
@@ -330,7 +512,9 @@ impl PruningDal<'_, '_> {
     async fn clear_transaction_fields(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_tx_hashes: &[H256],
     ) -> DalResult<u64> {
+        let retained_tx_hashes: Vec<_> = retained_tx_hashes.iter().map(H256::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             UPDATE transactions
@@ -342,12 +526,15 @@ impl PruningDal<'_, '_> {
             WHERE
                 miniblock_number BETWEEN $1 AND $2
                 AND upgrade_id IS NULL
+                AND NOT (hash = ANY($3))
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_tx_hashes as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#clear_transaction_fields")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
+        .with_arg("retained_tx_hashes.len", &retained_tx_hashes.len())
         .report_latency()
         .execute(self.storage)
         .await?;
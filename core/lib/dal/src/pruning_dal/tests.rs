@@ -2,8 +2,8 @@ use std::ops;
 
 use zksync_db_connection::connection::Connection;
 use zksync_types::{
-    tx::IncludedTxLocation, AccountTreeId, L1BatchNumber, L2BlockNumber, L2ChainId,
-    ProtocolVersion, ProtocolVersionId, StorageKey, StorageLog, H256,
+    api::GetLogsFilter, tx::IncludedTxLocation, AccountTreeId, Address, L1BatchNumber,
+    L2BlockNumber, L2ChainId, ProtocolVersion, ProtocolVersionId, StorageKey, StorageLog, H256,
 };
 use zksync_vm_interface::{tracer::ValidationTraces, TransactionExecutionMetrics};
 
@@ -432,6 +432,87 @@ async fn l1_batches_can_be_hard_pruned() {
     assert_l1_batches_not_exist(&mut transaction, L1BatchNumber(1)..=L1BatchNumber(9)).await;
 }
 
+#[tokio::test]
+async fn hard_pruning_honors_retention_rules() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut conn = pool.connection().await.unwrap();
+    insert_realistic_l1_batches(&mut conn, 4).await;
+
+    // Retains the second transaction in each L2 block, which emits events from addresses 2, 3 and 4.
+    let retention_rules = PruningRetentionRules {
+        addresses: vec![Address::repeat_byte(3)],
+        topics: vec![],
+    };
+    let stats = conn
+        .pruning_dal()
+        .hard_prune_batches_range_with_retention(
+            L1BatchNumber(2),
+            L2BlockNumber(5),
+            &retention_rules,
+        )
+        .await
+        .unwrap();
+    assert_eq!(stats.deleted_l1_batches, 3);
+    assert_eq!(stats.deleted_l2_blocks, 6);
+    assert_eq!(stats.deleted_events, 12);
+    assert_eq!(stats.retained_transactions, 6);
+    assert_l1_batches_not_exist(&mut conn, L1BatchNumber(1)..=L1BatchNumber(2)).await;
+
+    let retained_logs = conn
+        .events_web3_dal()
+        .get_logs(
+            GetLogsFilter {
+                from_block: L2BlockNumber(0),
+                to_block: L2BlockNumber(7),
+                addresses: vec![Address::repeat_byte(2)],
+                topics: vec![],
+            },
+            100,
+        )
+        .await
+        .unwrap();
+    assert_eq!(retained_logs.len(), 8, "{retained_logs:?}");
+    for log in &retained_logs {
+        let block_number = log.block_number.unwrap().as_u32();
+        let expected_hash = create_l2_block_header(block_number).hash;
+        assert_eq!(log.block_hash, Some(expected_hash));
+        assert_eq!(log.transaction_hash, Some(H256([2; 32])));
+    }
+
+    let pruned_logs = conn
+        .events_web3_dal()
+        .get_logs(
+            GetLogsFilter {
+                from_block: L2BlockNumber(0),
+                to_block: L2BlockNumber(7),
+                addresses: vec![Address::repeat_byte(0)],
+                topics: vec![],
+            },
+            100,
+        )
+        .await
+        .unwrap();
+    let log_blocks: Vec<_> = pruned_logs
+        .iter()
+        .map(|log| log.block_number.unwrap().as_u32())
+        .collect();
+    assert_eq!(log_blocks, [6, 7]);
+}
+
+#[test]
+fn checking_logs_filter_coverage_by_retention_rules() {
+    let rules = PruningRetentionRules {
+        addresses: vec![Address::repeat_byte(1), Address::repeat_byte(2)],
+        topics: vec![H256::repeat_byte(0xaa)],
+    };
+    assert!(rules.covers_logs_filter(&[Address::repeat_byte(1)], &[]));
+    assert!(!rules.covers_logs_filter(&[Address::repeat_byte(1), Address::repeat_byte(3)], &[]));
+    assert!(!rules.covers_logs_filter(&[], &[]));
+    assert!(rules.covers_logs_filter(&[], &[(1, vec![H256::repeat_byte(0xaa)])]));
+    assert!(!rules.covers_logs_filter(&[], &[(2, vec![H256::repeat_byte(0xaa)])]));
+    assert!(!PruningRetentionRules::default().covers_logs_filter(&[Address::repeat_byte(1)], &[]));
+}
+
 #[tokio::test]
 async fn transactions_are_handled_correctly_after_pruning() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -470,10 +551,15 @@ async fn transactions_are_handled_correctly_after_pruning() {
 
     let affected_count = conn
         .pruning_dal()
-        .clear_transaction_fields(L2BlockNumber(1)..=L2BlockNumber(1))
+        .clear_transaction_fields(L2BlockNumber(1)..=L2BlockNumber(1), &[])
         .await
         .unwrap();
     assert_eq!(affected_count, 1);
+    assert!(conn
+        .transactions_web3_dal()
+        .is_transaction_pruned(tx_hash)
+        .await
+        .unwrap());
 
     let api_transactions = conn
         .transactions_web3_dal()
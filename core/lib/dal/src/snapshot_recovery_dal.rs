@@ -50,6 +50,41 @@ impl SnapshotRecoveryDal<'_, '_> {
         Ok(())
     }
 
+    /// Overwrites the recovery status. Used when applying delta snapshots on top of the recovered snapshot.
+    pub async fn update_recovery_status(
+        &mut self,
+        status: &SnapshotRecoveryStatus,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE snapshot_recovery
+            SET
+                l1_batch_number = $1,
+                l1_batch_timestamp = $2,
+                l1_batch_root_hash = $3,
+                miniblock_number = $4,
+                miniblock_timestamp = $5,
+                miniblock_hash = $6,
+                protocol_version = $7,
+                storage_logs_chunks_processed = $8,
+                updated_at = NOW()
+            "#,
+            i64::from(status.l1_batch_number.0),
+            status.l1_batch_timestamp as i64,
+            status.l1_batch_root_hash.as_bytes(),
+            i64::from(status.l2_block_number.0),
+            status.l2_block_timestamp as i64,
+            status.l2_block_hash.as_bytes(),
+            status.protocol_version as i32,
+            &status.storage_logs_chunks_processed,
+        )
+        .instrument("update_recovery_status")
+        .with_arg("status.l1_batch_number", &status.l1_batch_number)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
     pub async fn mark_storage_logs_chunk_as_processed(&mut self, chunk_id: u64) -> DalResult<()> {
         sqlx::query!(
             r#"
@@ -156,5 +191,19 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(status, updated_status_from_db.unwrap());
+
+        status.l1_batch_number += 10;
+        status.l2_block_number += 20;
+        status.l1_batch_root_hash = H256::random();
+        status.storage_logs_chunks_processed = vec![true];
+        applied_status_dal
+            .update_recovery_status(&status)
+            .await
+            .unwrap();
+        let updated_status_from_db = applied_status_dal
+            .get_applied_snapshot_status()
+            .await
+            .unwrap();
+        assert_eq!(status, updated_status_from_db.unwrap());
     }
 }
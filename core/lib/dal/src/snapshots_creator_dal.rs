@@ -1,3 +1,5 @@
+use std::ops;
+
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
 use zksync_types::{
     snapshots::SnapshotStorageLog, AccountTreeId, Address, L1BatchNumber, L2BlockNumber,
@@ -47,7 +49,7 @@ impl SnapshotsCreatorDal<'_, '_> {
         &mut self,
         l2_block_number: L2BlockNumber,
         l1_batch_number: L1BatchNumber,
-        hashed_keys_range: std::ops::RangeInclusive<H256>,
+        hashed_keys_range: ops::RangeInclusive<H256>,
     ) -> DalResult<Vec<SnapshotStorageLog>> {
         // We need to filter the returned logs by `l1_batch_number` in order to not return "phantom writes", i.e.,
         // logs that have deduplicated writes (e.g., a write to a non-zero value and back to zero in the same L1 batch)
@@ -117,7 +119,7 @@ impl SnapshotsCreatorDal<'_, '_> {
         &mut self,
         l2_block_number: L2BlockNumber,
         l1_batch_number: L1BatchNumber,
-        hashed_keys_range: std::ops::RangeInclusive<H256>,
+        hashed_keys_range: ops::RangeInclusive<H256>,
     ) -> DalResult<Vec<SnapshotStorageLog<StorageKey>>> {
         let storage_logs = sqlx::query!(
             r#"
@@ -180,6 +182,106 @@ impl SnapshotsCreatorDal<'_, '_> {
         Ok(storage_logs)
     }
 
+    /// Returns an upper bound on the number of distinct storage keys modified in the specified L2 blocks.
+    /// Used to choose chunking for delta snapshots.
+    pub async fn get_storage_logs_count_in_l2_blocks(
+        &mut self,
+        l2_block_numbers: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<u64> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                storage_logs
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+            "#,
+            i64::from(l2_block_numbers.start().0),
+            i64::from(l2_block_numbers.end().0)
+        )
+        .instrument("get_storage_logs_count_in_l2_blocks")
+        .with_arg("l2_block_numbers", &l2_block_numbers)
+        .report_latency()
+        .expect_slow_query()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.count as u64)
+    }
+
+    /// Constructs a delta `storage_logs` chunk containing the state AFTER processing `[0..l1_batch_number]` batches
+    /// for all keys modified in the `(base_l2_block_number, l2_block_number]` L2 block range. `l2_block_number`
+    /// MUST be the last L2 block of the `l1_batch_number` batch, and `base_l2_block_number` MUST be the last L2 block
+    /// of the base snapshot batch.
+    pub async fn get_storage_logs_delta_chunk(
+        &mut self,
+        base_l2_block_number: L2BlockNumber,
+        l2_block_number: L2BlockNumber,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys_range: ops::RangeInclusive<H256>,
+    ) -> DalResult<Vec<SnapshotStorageLog>> {
+        // Since only keys modified in the range are selected, the latest log for each key in the range
+        // is the latest log overall. Phantom writes are filtered out in the same way as for full snapshots.
+        let storage_logs = sqlx::query!(
+            r#"
+            SELECT
+                storage_logs.hashed_key AS "hashed_key!",
+                storage_logs.value AS "value!",
+                storage_logs.miniblock_number AS "miniblock_number!",
+                initial_writes.l1_batch_number AS "l1_batch_number!",
+                initial_writes.index
+            FROM
+                (
+                    SELECT
+                        hashed_key,
+                        MAX(ARRAY[miniblock_number, operation_number]::INT []) AS op
+                    FROM
+                        storage_logs
+                    WHERE
+                        miniblock_number > $1
+                        AND miniblock_number <= $2
+                        AND hashed_key >= $4
+                        AND hashed_key <= $5
+                    GROUP BY
+                        hashed_key
+                    ORDER BY
+                        hashed_key
+                ) AS keys
+            INNER JOIN storage_logs
+                ON
+                    keys.hashed_key = storage_logs.hashed_key
+                    AND storage_logs.miniblock_number = keys.op[1]
+                    AND storage_logs.operation_number = keys.op[2]
+            INNER JOIN initial_writes ON keys.hashed_key = initial_writes.hashed_key
+            WHERE
+                initial_writes.l1_batch_number <= $3
+            "#,
+            i64::from(base_l2_block_number.0),
+            i64::from(l2_block_number.0),
+            i64::from(l1_batch_number.0),
+            hashed_keys_range.start().as_bytes(),
+            hashed_keys_range.end().as_bytes()
+        )
+        .instrument("get_storage_logs_delta_chunk")
+        .with_arg("base_l2_block_number", &base_l2_block_number)
+        .with_arg("l2_block_number", &l2_block_number)
+        .with_arg("min_hashed_key", &hashed_keys_range.start())
+        .with_arg("max_hashed_key", &hashed_keys_range.end())
+        .report_latency()
+        .expect_slow_query()
+        .fetch_all(self.storage)
+        .await?
+        .iter()
+        .map(|row| SnapshotStorageLog {
+            key: H256::from_slice(&row.hashed_key),
+            value: H256::from_slice(&row.value),
+            l1_batch_number_of_initial_write: L1BatchNumber(row.l1_batch_number as u32),
+            enumeration_index: row.index as u64,
+        })
+        .collect();
+        Ok(storage_logs)
+    }
+
     /// Returns all factory dependencies up to and including the specified `l2_block_number`.
     pub async fn get_all_factory_deps(
         &mut self,
@@ -208,6 +310,36 @@ impl SnapshotsCreatorDal<'_, '_> {
             .map(|row| (H256::from_slice(&row.bytecode_hash), row.bytecode))
             .collect())
     }
+
+    /// Returns factory dependencies added in the specified L2 blocks.
+    pub async fn get_factory_deps_in_l2_blocks(
+        &mut self,
+        l2_block_numbers: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<Vec<(H256, Vec<u8>)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                bytecode_hash,
+                bytecode
+            FROM
+                factory_deps
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+            "#,
+            i64::from(l2_block_numbers.start().0),
+            i64::from(l2_block_numbers.end().0)
+        )
+        .instrument("get_factory_deps_in_l2_blocks")
+        .with_arg("l2_block_numbers", &l2_block_numbers)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (H256::from_slice(&row.bytecode_hash), row.bytecode))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -381,4 +513,90 @@ mod tests {
         assert_eq!(logs[0].value, real_write.value);
         assert_eq!(logs[0].l1_batch_number_of_initial_write, L1BatchNumber(2));
     }
+
+    #[tokio::test]
+    async fn getting_storage_log_delta_chunks() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        let logs: Vec<_> = (0..10)
+            .map(|i| {
+                let key = StorageKey::new(AccountTreeId::default(), H256::from_low_u64_be(i));
+                StorageLog::new_write_log(key, H256::repeat_byte(1))
+            })
+            .collect();
+        conn.storage_logs_dal()
+            .insert_storage_logs(L2BlockNumber(1), &logs)
+            .await
+            .unwrap();
+        let written_keys: Vec<_> = logs.iter().map(|log| log.key.hashed_key()).collect();
+        conn.storage_logs_dedup_dal()
+            .insert_initial_writes(L1BatchNumber(1), &written_keys)
+            .await
+            .unwrap();
+
+        let new_log = StorageLog::new_write_log(
+            StorageKey::new(AccountTreeId::default(), H256::from_low_u64_be(100)),
+            H256::repeat_byte(2),
+        );
+        let updated_logs = [logs[0], logs[3]].map(|log| StorageLog {
+            value: H256::repeat_byte(23),
+            ..log
+        });
+        let mut new_logs = vec![new_log];
+        new_logs.extend(updated_logs);
+        conn.storage_logs_dal()
+            .insert_storage_logs(L2BlockNumber(2), &new_logs)
+            .await
+            .unwrap();
+        conn.storage_logs_dedup_dal()
+            .insert_initial_writes(L1BatchNumber(2), &[new_log.key.hashed_key()])
+            .await
+            .unwrap();
+
+        let count = conn
+            .snapshots_creator_dal()
+            .get_storage_logs_count_in_l2_blocks(L2BlockNumber(2)..=L2BlockNumber(2))
+            .await
+            .unwrap();
+        assert_eq!(count, new_logs.len() as u64);
+
+        let mut delta_logs = conn
+            .snapshots_creator_dal()
+            .get_storage_logs_delta_chunk(
+                L2BlockNumber(1),
+                L2BlockNumber(2),
+                L1BatchNumber(2),
+                H256::zero()..=H256::repeat_byte(0xff),
+            )
+            .await
+            .unwrap();
+        delta_logs.sort_unstable_by_key(|log| log.key);
+        new_logs.sort_unstable_by_key(|log| log.key.hashed_key());
+        assert_eq!(delta_logs.len(), new_logs.len());
+        for (log, expected_log) in delta_logs.iter().zip(&new_logs) {
+            assert_eq!(log.key, expected_log.key.hashed_key());
+            assert_eq!(log.value, expected_log.value);
+        }
+        let new_log_in_delta = delta_logs
+            .iter()
+            .find(|log| log.key == new_log.key.hashed_key())
+            .unwrap();
+        assert_eq!(
+            new_log_in_delta.l1_batch_number_of_initial_write,
+            L1BatchNumber(2)
+        );
+
+        let delta_logs = conn
+            .snapshots_creator_dal()
+            .get_storage_logs_delta_chunk(
+                L2BlockNumber(2),
+                L2BlockNumber(2),
+                L1BatchNumber(2),
+                H256::zero()..=H256::repeat_byte(0xff),
+            )
+            .await
+            .unwrap();
+        assert_eq!(delta_logs, []);
+    }
 }
@@ -14,6 +14,7 @@ use crate::Core;
 struct StorageSnapshotMetadata {
     version: i32,
     l1_batch_number: i64,
+    base_l1_batch_number: Option<i64>,
     storage_logs_filepaths: Vec<String>,
     factory_deps_filepath: String,
 }
@@ -28,6 +29,9 @@ impl TryFrom<StorageSnapshotMetadata> for SnapshotMetadata {
         Ok(Self {
             version,
             l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+            base_l1_batch_number: row
+                .base_l1_batch_number
+                .map(|number| L1BatchNumber(number as u32)),
             storage_logs_filepaths: row
                 .storage_logs_filepaths
                 .into_iter()
@@ -50,6 +54,43 @@ impl SnapshotsDal<'_, '_> {
         l1_batch_number: L1BatchNumber,
         storage_logs_chunk_count: u64,
         factory_deps_filepaths: &str,
+    ) -> DalResult<()> {
+        self.insert_snapshot(
+            version,
+            l1_batch_number,
+            None,
+            storage_logs_chunk_count,
+            factory_deps_filepaths,
+        )
+        .await
+    }
+
+    /// Adds a delta snapshot on top of the snapshot for `base_l1_batch_number`.
+    pub async fn add_delta_snapshot(
+        &mut self,
+        version: SnapshotVersion,
+        l1_batch_number: L1BatchNumber,
+        base_l1_batch_number: L1BatchNumber,
+        storage_logs_chunk_count: u64,
+        factory_deps_filepaths: &str,
+    ) -> DalResult<()> {
+        self.insert_snapshot(
+            version,
+            l1_batch_number,
+            Some(base_l1_batch_number),
+            storage_logs_chunk_count,
+            factory_deps_filepaths,
+        )
+        .await
+    }
+
+    async fn insert_snapshot(
+        &mut self,
+        version: SnapshotVersion,
+        l1_batch_number: L1BatchNumber,
+        base_l1_batch_number: Option<L1BatchNumber>,
+        storage_logs_chunk_count: u64,
+        factory_deps_filepaths: &str,
     ) -> DalResult<()> {
         sqlx::query!(
             r#"
@@ -57,22 +98,25 @@ impl SnapshotsDal<'_, '_> {
             snapshots (
                 version,
                 l1_batch_number,
+                base_l1_batch_number,
                 storage_logs_filepaths,
                 factory_deps_filepath,
                 created_at,
                 updated_at
             )
             VALUES
-            ($1, $2, ARRAY_FILL(''::TEXT, ARRAY[$3::INTEGER]), $4, NOW(), NOW())
+            ($1, $2, $3, ARRAY_FILL(''::TEXT, ARRAY[$4::INTEGER]), $5, NOW(), NOW())
             "#,
             version as i32,
             l1_batch_number.0 as i32,
+            base_l1_batch_number.map(|number| i64::from(number.0)),
             storage_logs_chunk_count as i32,
             factory_deps_filepaths,
         )
         .instrument("add_snapshot")
         .with_arg("version", &version)
         .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("base_l1_batch_number", &base_l1_batch_number)
         .report_latency()
         .execute(self.storage)
         .await?;
@@ -111,7 +155,8 @@ impl SnapshotsDal<'_, '_> {
         let rows = sqlx::query!(
             r#"
             SELECT
-                l1_batch_number
+                l1_batch_number,
+                base_l1_batch_number
             FROM
                 snapshots
             WHERE
@@ -125,13 +170,20 @@ impl SnapshotsDal<'_, '_> {
         .fetch_all(self.storage)
         .await?;
 
-        let snapshots_l1_batch_numbers = rows
-            .into_iter()
-            .map(|row| L1BatchNumber(row.l1_batch_number as u32))
-            .collect();
+        let mut snapshots_l1_batch_numbers = vec![];
+        let mut delta_snapshots_l1_batch_numbers = vec![];
+        for row in rows {
+            let l1_batch_number = L1BatchNumber(row.l1_batch_number as u32);
+            if row.base_l1_batch_number.is_some() {
+                delta_snapshots_l1_batch_numbers.push(l1_batch_number);
+            } else {
+                snapshots_l1_batch_numbers.push(l1_batch_number);
+            }
+        }
 
         Ok(AllSnapshots {
             snapshots_l1_batch_numbers,
+            delta_snapshots_l1_batch_numbers,
         })
     }
 
@@ -142,6 +194,7 @@ impl SnapshotsDal<'_, '_> {
             SELECT
                 VERSION,
                 L1_BATCH_NUMBER,
+                BASE_L1_BATCH_NUMBER,
                 FACTORY_DEPS_FILEPATH,
                 STORAGE_LOGS_FILEPATHS
             FROM
@@ -169,6 +222,7 @@ impl SnapshotsDal<'_, '_> {
             SELECT
                 VERSION,
                 L1_BATCH_NUMBER,
+                BASE_L1_BATCH_NUMBER,
                 FACTORY_DEPS_FILEPATH,
                 STORAGE_LOGS_FILEPATHS
             FROM
@@ -200,6 +254,7 @@ impl SnapshotsDal<'_, '_> {
             RETURNING
             version,
             l1_batch_number,
+            base_l1_batch_number,
             factory_deps_filepath,
             storage_logs_filepaths
             "#,
@@ -267,6 +322,74 @@ mod tests {
         assert_eq!(snapshot_metadata.l1_batch_number, l1_batch_number);
     }
 
+    #[tokio::test]
+    async fn adding_delta_snapshot() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.snapshots_dal();
+        let base_l1_batch_number = L1BatchNumber(100);
+        let l1_batch_number = L1BatchNumber(110);
+        dal.add_snapshot(
+            SnapshotVersion::Version1,
+            base_l1_batch_number,
+            1,
+            "gs:///bucket/factory_deps.bin",
+        )
+        .await
+        .unwrap();
+        dal.add_storage_logs_filepath_for_snapshot(
+            base_l1_batch_number,
+            0,
+            "gs:///bucket/chunk.bin",
+        )
+        .await
+        .unwrap();
+        dal.add_delta_snapshot(
+            SnapshotVersion::Version1,
+            l1_batch_number,
+            base_l1_batch_number,
+            1,
+            "gs:///bucket/delta_factory_deps.bin",
+        )
+        .await
+        .unwrap();
+
+        let snapshots = dal.get_all_complete_snapshots().await.unwrap();
+        assert_eq!(snapshots.snapshots_l1_batch_numbers, [base_l1_batch_number]);
+        assert_eq!(snapshots.delta_snapshots_l1_batch_numbers, []);
+
+        dal.add_storage_logs_filepath_for_snapshot(l1_batch_number, 0, "gs:///bucket/delta.bin")
+            .await
+            .unwrap();
+        let snapshots = dal.get_all_complete_snapshots().await.unwrap();
+        assert_eq!(snapshots.snapshots_l1_batch_numbers, [base_l1_batch_number]);
+        assert_eq!(
+            snapshots.delta_snapshots_l1_batch_numbers,
+            [l1_batch_number]
+        );
+
+        let snapshot_metadata = dal
+            .get_snapshot_metadata(l1_batch_number)
+            .await
+            .unwrap()
+            .expect("snapshot is not persisted");
+        assert!(snapshot_metadata.is_complete());
+        assert_eq!(
+            snapshot_metadata.base_l1_batch_number,
+            Some(base_l1_batch_number)
+        );
+        let newest_snapshot_metadata = dal.get_newest_snapshot_metadata().await.unwrap().unwrap();
+        assert_eq!(newest_snapshot_metadata.l1_batch_number, l1_batch_number);
+        assert!(newest_snapshot_metadata.is_delta());
+
+        let base_metadata = dal
+            .get_snapshot_metadata(base_l1_batch_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!base_metadata.is_delta());
+    }
+
     #[tokio::test]
     async fn deleting_snapshots() {
         let pool = ConnectionPool::<Core>::test_pool().await;
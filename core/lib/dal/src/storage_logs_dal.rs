@@ -8,7 +8,7 @@ use zksync_db_connection::{
     write_str, writeln_str,
 };
 use zksync_types::{
-    get_code_key, h256_to_u256, snapshots::SnapshotStorageLog, u256_to_h256, AccountTreeId,
+    api, get_code_key, h256_to_u256, snapshots::SnapshotStorageLog, u256_to_h256, AccountTreeId,
     Address, L1BatchNumber, L2BlockNumber, StorageKey, StorageLog,
     FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH, H160, H256,
 };
@@ -152,6 +152,28 @@ impl StorageLogsDal<'_, '_> {
         copy.send(buffer.as_bytes()).await
     }
 
+    /// Removes all storage logs for the specified hashed keys. Used when applying delta snapshots, so that
+    /// the storage contains a single log per key, as it does after recovering from a full snapshot.
+    pub async fn remove_storage_logs_for_hashed_keys(
+        &mut self,
+        hashed_keys: &[H256],
+    ) -> DalResult<()> {
+        let hashed_keys: Vec<_> = hashed_keys.iter().map(H256::as_bytes).collect();
+        sqlx::query!(
+            r#"
+            DELETE FROM storage_logs
+            WHERE
+                hashed_key = ANY($1)
+            "#,
+            &hashed_keys as &[&[u8]]
+        )
+        .instrument("remove_storage_logs_for_hashed_keys")
+        .with_arg("hashed_keys.len", &hashed_keys.len())
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
     pub async fn append_storage_logs(
         &mut self,
         block_number: L2BlockNumber,
@@ -209,6 +231,87 @@ impl StorageLogsDal<'_, '_> {
             .collect())
     }
 
+    /// Returns changes of storage slots written to in the specified L2 block range, ordered by the hashed key.
+    /// Only slots with hashed keys strictly greater than `after_hashed_key` are returned, which allows paginating
+    /// the output. Previous slot values are taken from the last write preceding the range.
+    pub async fn get_storage_changes_in_l2_blocks(
+        &mut self,
+        l2_block_numbers: ops::RangeInclusive<L2BlockNumber>,
+        after_hashed_key: Option<H256>,
+        limit: usize,
+    ) -> DalResult<Vec<api::StorageSlotChange>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                changes.hashed_key AS "hashed_key!",
+                changes.address AS "address?",
+                changes.key AS "key?",
+                changes.value AS "value!",
+                (
+                    SELECT
+                        value
+                    FROM
+                        storage_logs AS prev
+                    WHERE
+                        prev.hashed_key = changes.hashed_key
+                        AND prev.miniblock_number < $1
+                    ORDER BY
+                        prev.miniblock_number DESC,
+                        prev.operation_number DESC
+                    LIMIT
+                        1
+                ) AS "previous_value?"
+            FROM
+                (
+                    SELECT DISTINCT
+                    ON (hashed_key)
+                        hashed_key,
+                        address,
+                        key,
+                        value
+                    FROM
+                        storage_logs
+                    WHERE
+                        miniblock_number BETWEEN $1 AND $2
+                        AND (
+                            $3::BYTEA IS NULL
+                            OR hashed_key > $3
+                        )
+                    ORDER BY
+                        hashed_key,
+                        miniblock_number DESC,
+                        operation_number DESC
+                    LIMIT
+                        $4
+                ) AS changes
+            ORDER BY
+                changes.hashed_key
+            "#,
+            i64::from(l2_block_numbers.start().0),
+            i64::from(l2_block_numbers.end().0),
+            after_hashed_key.as_ref().map(H256::as_bytes),
+            limit as i64
+        )
+        .instrument("get_storage_changes_in_l2_blocks")
+        .with_arg("l2_block_numbers", &l2_block_numbers)
+        .with_arg("after_hashed_key", &after_hashed_key)
+        .with_arg("limit", &limit)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| api::StorageSlotChange {
+                hashed_key: H256::from_slice(&row.hashed_key),
+                address: row.address.as_deref().map(H160::from_slice),
+                key: row.key.as_deref().map(H256::from_slice),
+                previous_value: row.previous_value.as_deref().map(H256::from_slice),
+                new_value: H256::from_slice(&row.value),
+            })
+            .collect())
+    }
+
     /// Removes all storage logs with a L2 block number strictly greater than the specified `block_number`.
     pub async fn roll_back_storage_logs(&mut self, block_number: L2BlockNumber) -> DalResult<()> {
         sqlx::query!(
@@ -930,6 +1033,77 @@ mod tests {
         test_revert(&mut conn, first_key, second_key).await;
     }
 
+    #[tokio::test]
+    async fn getting_storage_changes_in_l2_blocks() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let account = AccountTreeId::new(Address::repeat_byte(1));
+        let keys: Vec<_> = (0..3)
+            .map(|i| StorageKey::new(account, H256::from_low_u64_be(i)))
+            .collect();
+        let logs = vec![
+            StorageLog::new_write_log(keys[0], H256::repeat_byte(1)),
+            StorageLog::new_write_log(keys[1], H256::repeat_byte(2)),
+        ];
+        insert_l2_block(&mut conn, 1, logs).await;
+        let logs = vec![
+            StorageLog::new_write_log(keys[0], H256::repeat_byte(3)),
+            StorageLog::new_write_log(keys[2], H256::repeat_byte(4)),
+            StorageLog::new_write_log(keys[0], H256::repeat_byte(5)),
+        ];
+        insert_l2_block(&mut conn, 2, logs).await;
+
+        let changes = conn
+            .storage_logs_dal()
+            .get_storage_changes_in_l2_blocks(L2BlockNumber(2)..=L2BlockNumber(2), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(changes[0].hashed_key < changes[1].hashed_key);
+        let change = changes
+            .iter()
+            .find(|change| change.hashed_key == keys[0].hashed_key())
+            .unwrap();
+        assert_eq!(change.address, Some(*account.address()));
+        assert_eq!(change.key, Some(*keys[0].key()));
+        assert_eq!(change.previous_value, Some(H256::repeat_byte(1)));
+        assert_eq!(change.new_value, H256::repeat_byte(5));
+        let change = changes
+            .iter()
+            .find(|change| change.hashed_key == keys[2].hashed_key())
+            .unwrap();
+        assert_eq!(change.previous_value, None);
+        assert_eq!(change.new_value, H256::repeat_byte(4));
+
+        // Check pagination.
+        let all_changes = conn
+            .storage_logs_dal()
+            .get_storage_changes_in_l2_blocks(L2BlockNumber(1)..=L2BlockNumber(2), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(all_changes.len(), 3);
+        let mut paginated_changes = vec![];
+        let mut cursor = None;
+        loop {
+            let page = conn
+                .storage_logs_dal()
+                .get_storage_changes_in_l2_blocks(L2BlockNumber(1)..=L2BlockNumber(2), cursor, 2)
+                .await
+                .unwrap();
+            if page.is_empty() {
+                break;
+            }
+            cursor = Some(page.last().unwrap().hashed_key);
+            paginated_changes.extend(page);
+        }
+        assert_eq!(paginated_changes, all_changes);
+    }
+
     async fn test_revert(conn: &mut Connection<'_, Core>, key: StorageKey, second_key: StorageKey) {
         let new_account = AccountTreeId::new(Address::repeat_byte(2));
         let new_key = StorageKey::new(new_account, H256::zero());
@@ -1,5 +1,5 @@
 #![doc = include_str!("../doc/TeeProofGenerationDal.md")]
-use std::time::Duration;
+use std::{ops::RangeInclusive, time::Duration};
 
 use chrono::{DateTime, Utc};
 use strum::{Display, EnumString};
@@ -55,6 +55,23 @@ pub struct LockedBatch {
     pub created_at: DateTime<Utc>,
 }
 
+/// Generated TEE proof used to evaluate the TEE quorum policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedTeeProof {
+    pub l1_batch_number: L1BatchNumber,
+    pub tee_type: TeeType,
+    pub pubkey: Vec<u8>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Information about an L1 batch with TEE proofs satisfying the quorum policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeeFinalizedBatch {
+    pub tee_types: Vec<TeeType>,
+    pub distinct_keys: usize,
+    pub finalized_at: DateTime<Utc>,
+}
+
 impl TeeProofGenerationDal<'_, '_> {
     pub async fn lock_batch_for_proving(
         &mut self,
@@ -268,6 +285,27 @@ impl TeeProofGenerationDal<'_, '_> {
         Ok(())
     }
 
+    /// Returns the attestation quote registered for the specified public key.
+    pub async fn get_attestation(&mut self, pubkey: &[u8]) -> DalResult<Option<Vec<u8>>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                attestation
+            FROM
+                tee_attestations
+            WHERE
+                pubkey = $1
+            "#,
+            pubkey
+        )
+        .instrument("get_attestation")
+        .with_arg("pubkey", &pubkey)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.and_then(|row| row.attestation))
+    }
+
     pub async fn get_tee_proofs(
         &mut self,
         batch_number: L1BatchNumber,
@@ -310,6 +348,191 @@ impl TeeProofGenerationDal<'_, '_> {
         Ok(proofs)
     }
 
+    /// Returns generated proofs for L1 batches that are not TEE-finalized yet and have at least one proof
+    /// generated at or after `updated_since`. Proofs are ordered by the L1 batch number.
+    pub async fn get_generated_proofs_for_unfinalized_batches(
+        &mut self,
+        updated_since: Option<DateTime<Utc>>,
+        min_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<GeneratedTeeProof>> {
+        let updated_since = updated_since.map(|timestamp| timestamp.naive_utc());
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                tp.l1_batch_number,
+                tp.tee_type,
+                tp.pubkey AS "pubkey!",
+                tp.updated_at
+            FROM
+                tee_proof_generation_details tp
+            WHERE
+                tp.status = $1
+                AND tp.pubkey IS NOT NULL
+                AND tp.l1_batch_number >= $2
+                AND tp.l1_batch_number IN (
+                    SELECT
+                        l1_batch_number
+                    FROM
+                        tee_proof_generation_details
+                    WHERE
+                        status = $1
+                        AND (
+                            $3::TIMESTAMP IS NULL
+                            OR updated_at >= $3
+                        )
+                )
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        tee_finalized_l1_batches f
+                    WHERE
+                        f.l1_batch_number = tp.l1_batch_number
+                )
+            ORDER BY
+                tp.l1_batch_number,
+                tp.tee_type
+            "#,
+            TeeProofGenerationJobStatus::Generated.to_string(),
+            i64::from(min_batch_number.0),
+            updated_since
+        )
+        .instrument("get_generated_proofs_for_unfinalized_batches")
+        .with_arg("updated_since", &updated_since)
+        .with_arg("min_batch_number", &min_batch_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let Ok(tee_type) = row.tee_type.parse() else {
+                    tracing::warn!(
+                        "Unknown TEE type `{}` for L1 batch #{}",
+                        row.tee_type,
+                        row.l1_batch_number
+                    );
+                    return None;
+                };
+                Some(GeneratedTeeProof {
+                    l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+                    tee_type,
+                    pubkey: row.pubkey,
+                    updated_at: DateTime::<Utc>::from_naive_utc_and_offset(row.updated_at, Utc),
+                })
+            })
+            .collect())
+    }
+
+    /// Marks the L1 batch as TEE-finalized. Returns `false` if the batch is already finalized.
+    pub async fn mark_l1_batch_tee_finalized(
+        &mut self,
+        batch_number: L1BatchNumber,
+        tee_types: &[TeeType],
+        distinct_keys: usize,
+    ) -> DalResult<bool> {
+        let tee_types: Vec<_> = tee_types.iter().map(ToString::to_string).collect();
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO
+            tee_finalized_l1_batches (l1_batch_number, tee_types, distinct_keys, created_at)
+            VALUES
+            ($1, $2, $3, NOW())
+            ON CONFLICT (l1_batch_number) DO NOTHING
+            "#,
+            i64::from(batch_number.0),
+            &tee_types,
+            distinct_keys as i32
+        )
+        .instrument("mark_l1_batch_tee_finalized")
+        .with_arg("batch_number", &batch_number)
+        .with_arg("tee_types", &tee_types)
+        .with_arg("distinct_keys", &distinct_keys)
+        .execute(self.storage)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    pub async fn get_tee_finalized_batch(
+        &mut self,
+        batch_number: L1BatchNumber,
+    ) -> DalResult<Option<TeeFinalizedBatch>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                tee_types,
+                distinct_keys,
+                created_at
+            FROM
+                tee_finalized_l1_batches
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(batch_number.0)
+        )
+        .instrument("get_tee_finalized_batch")
+        .with_arg("batch_number", &batch_number)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| TeeFinalizedBatch {
+            tee_types: row
+                .tee_types
+                .iter()
+                .filter_map(|tee_type| tee_type.parse().ok())
+                .collect(),
+            distinct_keys: row.distinct_keys as usize,
+            finalized_at: DateTime::<Utc>::from_naive_utc_and_offset(row.created_at, Utc),
+        }))
+    }
+
+    /// Returns TEE-finalized L1 batch numbers in the specified range in ascending order.
+    pub async fn get_tee_finalized_batch_numbers(
+        &mut self,
+        range: RangeInclusive<L1BatchNumber>,
+    ) -> DalResult<Vec<L1BatchNumber>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number
+            FROM
+                tee_finalized_l1_batches
+            WHERE
+                l1_batch_number BETWEEN $1 AND $2
+            ORDER BY
+                l1_batch_number
+            "#,
+            i64::from(range.start().0),
+            i64::from(range.end().0)
+        )
+        .instrument("get_tee_finalized_batch_numbers")
+        .with_arg("range", &range)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+            .collect())
+    }
+
+    pub async fn get_last_tee_finalized_batch(&mut self) -> DalResult<Option<L1BatchNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(l1_batch_number) AS "number"
+            FROM
+                tee_finalized_l1_batches
+            "#
+        )
+        .instrument("get_last_tee_finalized_batch")
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(row.number.map(|number| L1BatchNumber(number as u32)))
+    }
+
     /// For testing purposes only.
     pub async fn insert_tee_proof_generation_job(
         &mut self,
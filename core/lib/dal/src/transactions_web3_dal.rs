@@ -70,18 +70,29 @@ impl TransactionsWeb3Dal<'_, '_> {
                 transactions.refunded_gas,
                 transactions.gas_limit,
                 transactions.nonce,
-                miniblocks.hash AS "block_hash",
-                miniblocks.l1_batch_number AS "l1_batch_number?",
-                miniblocks.timestamp AS "block_timestamp?"
+                COALESCE(miniblocks.hash, retained_l2_block_headers.hash) AS "block_hash!",
+                COALESCE(
+                    miniblocks.l1_batch_number, retained_l2_block_headers.l1_batch_number
+                ) AS "l1_batch_number?",
+                COALESCE(
+                    miniblocks.timestamp, retained_l2_block_headers.timestamp
+                ) AS "block_timestamp?"
             FROM
                 transactions
-            JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+            LEFT JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+            LEFT JOIN
+                retained_l2_block_headers
+                ON retained_l2_block_headers.number = transactions.miniblock_number
             WHERE
                 transactions.hash = ANY($1)
                 AND transactions.data != '{}'::jsonb
+                AND (
+                    miniblocks.number IS NOT NULL
+                    OR retained_l2_block_headers.number IS NOT NULL
+                )
             "#,
             // ^ Filter out transactions with pruned data, which would lead to potentially incomplete / bogus
-            // transaction info.
+            // transaction info. Transactions retained during pruning have their block headers stored separately.
             &hash_bytes as &[&[u8]],
         )
         .instrument("get_transaction_receipts")
@@ -199,7 +210,7 @@ impl TransactionsWeb3Dal<'_, '_> {
                 SELECT
                     transactions.hash AS tx_hash,
                     transactions.index_in_block AS index_in_block,
-                    miniblocks.number AS block_number,
+                    COALESCE(miniblocks.number, retained_l2_block_headers.number) AS block_number,
                     transactions.nonce AS nonce,
                     transactions.signature AS signature,
                     transactions.initiator_address AS initiator_address,
@@ -213,9 +224,11 @@ impl TransactionsWeb3Dal<'_, '_> {
                     transactions.l1_batch_tx_index AS l1_batch_tx_index,
                     transactions.data->'contractAddress' AS "execute_contract_address",
                     transactions.data->'calldata' AS "calldata",
-                    miniblocks.hash AS "block_hash"
+                    COALESCE(miniblocks.hash, retained_l2_block_headers.hash) AS "block_hash"
                 FROM transactions
                 LEFT JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+                LEFT JOIN retained_l2_block_headers
+                    ON retained_l2_block_headers.number = transactions.miniblock_number
                 WHERE
                 "#,
                 _, // WHERE condition
@@ -327,6 +340,32 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(row.map(Into::into))
     }
 
+    /// Checks whether the transaction with the specified hash was included into an L2 block, but its data
+    /// was subsequently removed by hard pruning (i.e., the transaction wasn't retained by the pruning retention rules).
+    pub async fn is_transaction_pruned(&mut self, hash: H256) -> DalResult<bool> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        transactions
+                    WHERE
+                        hash = $1
+                        AND miniblock_number IS NOT NULL
+                        AND data = '{}'::jsonb
+                ) AS "exists!"
+            "#,
+            hash.as_bytes()
+        )
+        .instrument("is_transaction_pruned")
+        .with_arg("hash", &hash)
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.exists)
+    }
+
     /// Returns hashes of txs which were received after `from_timestamp` and the time of receiving the last tx.
     pub async fn get_pending_txs_hashes_after(
         &mut self,
@@ -1,8 +1,10 @@
 use anyhow::Context as _;
 use zksync_config::configs::{
     api::{
-        ContractVerificationApiConfig, HealthCheckConfig, MerkleTreeApiConfig, Web3JsonRpcConfig,
+        AdminApiConfig, ContractVerificationApiConfig, HealthCheckConfig, MerkleTreeApiConfig,
+        Web3JsonRpcConfig,
     },
+    secrets::AdminApiSecrets,
     ApiConfig, PrometheusConfig,
 };
 
@@ -15,6 +17,7 @@ impl FromEnv for ApiConfig {
             prometheus: PrometheusConfig::from_env().context("PrometheusConfig")?,
             healthcheck: HealthCheckConfig::from_env().context("HealthCheckConfig")?,
             merkle_tree: MerkleTreeApiConfig::from_env().context("MerkleTreeApiConfig")?,
+            admin: AdminApiConfig::from_env().ok(),
         })
     }
 }
@@ -44,6 +47,22 @@ impl FromEnv for MerkleTreeApiConfig {
     }
 }
 
+impl FromEnv for AdminApiConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("admin_api", "API_ADMIN_")
+    }
+}
+
+impl FromEnv for AdminApiSecrets {
+    fn from_env() -> anyhow::Result<Self> {
+        Ok(Self {
+            auth_token: std::env::var("API_ADMIN_AUTH_TOKEN")
+                .context("API_ADMIN_AUTH_TOKEN")?
+                .into(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::{NonZeroU32, NonZeroUsize};
@@ -107,6 +126,7 @@ mod tests {
                 hard_time_limit_ms: Some(2_000),
             },
             merkle_tree: MerkleTreeApiConfig { port: 8082 },
+            admin: Some(AdminApiConfig { port: 8083 }),
         }
     }
 
@@ -152,6 +172,7 @@ mod tests {
             API_HEALTHCHECK_SLOW_TIME_LIMIT_MS=250
             API_HEALTHCHECK_HARD_TIME_LIMIT_MS=2000
             API_MERKLE_TREE_PORT=8082
+            API_ADMIN_PORT=8083
         "#;
         lock.set_env(config);
 
@@ -13,6 +13,7 @@ use zksync_config::{
             eigen::EigenSecrets,
             DAClientConfig, AVAIL_CLIENT_CONFIG_NAME, CELESTIA_CLIENT_CONFIG_NAME,
             EIGEN_CLIENT_CONFIG_NAME, NO_DA_CLIENT_CONFIG_NAME, OBJECT_STORE_CLIENT_CONFIG_NAME,
+            SIDECAR_CLIENT_CONFIG_NAME,
         },
         secrets::DataAvailabilitySecrets,
         AvailConfig,
@@ -82,6 +83,7 @@ pub fn da_client_config_from_env(prefix: &str) -> anyhow::Result<DAClientConfig>
         OBJECT_STORE_CLIENT_CONFIG_NAME => {
             DAClientConfig::ObjectStore(envy_load("da_object_store", prefix)?)
         }
+        SIDECAR_CLIENT_CONFIG_NAME => DAClientConfig::Sidecar(envy_load("da_sidecar", prefix)?),
         NO_DA_CLIENT_CONFIG_NAME => DAClientConfig::NoDA,
         _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
     };
@@ -150,6 +152,7 @@ mod tests {
             da_client::{
                 avail::{AvailClientConfig, AvailDefaultConfig},
                 eigen::PointsSource,
+                sidecar::SidecarConfig,
                 DAClientConfig::{self, ObjectStore},
             },
             object_store::ObjectStoreMode::GCS,
@@ -275,6 +278,26 @@ mod tests {
         })
     }
 
+    #[test]
+    fn from_env_sidecar_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Sidecar"
+            DA_SERVER_URL="http://127.0.0.1:3100"
+            DA_TIMEOUT_MS="5000"
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig::Sidecar(SidecarConfig {
+                server_url: "http://127.0.0.1:3100".to_owned(),
+                timeout_ms: Some(5_000),
+            })
+        );
+    }
+
     #[test]
     fn from_env_celestia_client() {
         let mut lock = MUTEX.lock();
@@ -28,6 +28,7 @@ mod tests {
             max_retries: Some(max_retries),
             use_dummy_inclusion_data: Some(true),
             inclusion_verification_transition_enabled: None,
+            use_blob_chunking: Some(true),
         }
     }
 
@@ -39,6 +40,7 @@ mod tests {
             DA_DISPATCHER_MAX_ROWS_TO_DISPATCH=60
             DA_DISPATCHER_MAX_RETRIES=7
             DA_DISPATCHER_USE_DUMMY_INCLUSION_DATA="true"
+            DA_DISPATCHER_USE_BLOB_CHUNKING="true"
         "#;
         lock.set_env(config);
         let actual = DADispatcherConfig::from_env().unwrap();
@@ -26,6 +26,7 @@ mod tests {
             prometheus_push_interval_ms: Some(100),
             api_mode: ApiMode::Legacy,
             port: None,
+            chains: vec![],
         }
     }
 
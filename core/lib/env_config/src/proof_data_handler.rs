@@ -25,6 +25,7 @@ mod tests {
             proof_gen_data_submit_interval_in_secs:
                 ProofDataHandlerConfig::default_proof_gen_data_submit_interval_in_secs(),
             fetch_zero_chain_id_proofs: false,
+            verification_keys_path: Some("/etc/keys".to_owned()),
         }
     }
 
@@ -34,6 +35,7 @@ mod tests {
             PROOF_DATA_HANDLER_PROOF_GENERATION_TIMEOUT_IN_SECS="18000"
             PROOF_DATA_HANDLER_HTTP_PORT="3320"
             PROOF_DATA_HANDLER_FETCH_ZERO_CHAIN_ID_PROOFS="false"
+            PROOF_DATA_HANDLER_VERIFICATION_KEYS_PATH="/etc/keys"
         "#;
         let mut lock = MUTEX.lock();
         lock.set_env(config);
@@ -23,6 +23,8 @@ mod tests {
             first_processed_batch: L1BatchNumber(1337),
             proof_generation_timeout_in_secs: 600,
             batch_permanently_ignored_timeout_in_hours: 240,
+            attestation_verification: None,
+            quorum: None,
         }
     }
 
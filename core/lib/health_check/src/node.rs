@@ -2,9 +2,13 @@
 
 use std::sync::Arc;
 
-use zksync_node_framework::Resource;
+use serde::Serialize;
+use zksync_node_framework::{
+    task::{SupervisionEvent, SupervisionObserver},
+    Resource, TaskId,
+};
 
-use crate::AppHealthCheck;
+use crate::{AppHealthCheck, Health, HealthStatus, HealthUpdater};
 
 /// A resource that provides [`AppHealthCheck`] to the service.
 #[derive(Debug, Clone, Default)]
@@ -15,3 +19,65 @@ impl Resource for AppHealthCheckResource {
         "common/app_health_check".into()
     }
 }
+
+#[derive(Debug, Serialize)]
+struct SupervisionDetails {
+    restarts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_error: Option<String>,
+    gave_up: bool,
+}
+
+/// Reports the state of a supervised task as its health. The health is [`HealthStatus::Ready`] while the task
+/// is running without prior failures, and [`HealthStatus::Affected`] after any failure.
+impl SupervisionObserver for HealthUpdater {
+    fn observe(&self, _task_id: &TaskId, event: SupervisionEvent<'_>) {
+        let (status, details) = match event {
+            SupervisionEvent::Started { restarts } => {
+                // Keep the `Affected` status after restarts so that the last error stays visible.
+                if restarts > 0 {
+                    return;
+                }
+                let details = SupervisionDetails {
+                    restarts,
+                    last_error: None,
+                    gave_up: false,
+                };
+                (HealthStatus::Ready, details)
+            }
+            SupervisionEvent::Completed { restarts } => {
+                let details = SupervisionDetails {
+                    restarts,
+                    last_error: None,
+                    gave_up: false,
+                };
+                (HealthStatus::Ready, details)
+            }
+            SupervisionEvent::Failed {
+                restarts, error, ..
+            } => {
+                let details = SupervisionDetails {
+                    restarts,
+                    last_error: Some(format!("{error:#}")),
+                    gave_up: false,
+                };
+                (HealthStatus::Affected, details)
+            }
+            SupervisionEvent::GaveUp { failures, error } => {
+                let details = SupervisionDetails {
+                    restarts: failures - 1,
+                    last_error: Some(format!("{error:#}")),
+                    gave_up: true,
+                };
+                (HealthStatus::Affected, details)
+            }
+        };
+        self.update(Health::from(status).with_details(details));
+    }
+
+    fn on_oneshot_exit(self: Box<Self>) {
+        // Freeze the health check in its last status, so that the exited task isn't marked as "shut down",
+        // which would lead to the app considered unhealthy.
+        self.freeze();
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::{hash_map, BTreeSet, HashMap};
 
 use zksync_types::{
     l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction,
-    TransactionTimeRangeConstraint,
+    TransactionTimeRangeConstraint, H256,
 };
 
 use crate::types::{AccountTransactions, L2TxFilter, MempoolScore};
@@ -246,6 +246,25 @@ impl MempoolStore {
         }
     }
 
+    /// Removes an L2 transaction with the specified hash from the mempool. Returns `None` if the mempool doesn't contain
+    /// such a transaction. Subsequent transactions of the same account are kept, but won't be returned
+    /// until the nonce gap is filled.
+    pub fn remove_l2_transaction(&mut self, tx_hash: H256) -> Option<L2Tx> {
+        for account_txs in self.l2_transactions_per_account.values_mut() {
+            if let Some((transaction, score)) = account_txs.remove(tx_hash) {
+                if let Some(score) = score {
+                    self.l2_priority_queue.remove(&score);
+                }
+                self.size = self
+                    .size
+                    .checked_sub(1)
+                    .expect("mempool size can't be negative");
+                return Some(transaction);
+            }
+        }
+        None
+    }
+
     pub fn get_mempool_info(&mut self) -> MempoolInfo {
         MempoolInfo {
             stashed_accounts: std::mem::take(&mut self.stashed_accounts),
@@ -241,6 +241,54 @@ fn mempool_size() {
     assert_eq!(mempool.stats().l2_transaction_count, 3);
 }
 
+#[test]
+fn removing_tx() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let transactions: Vec<_> = [(account0, 0), (account0, 1), (account1, 0), (account1, 1)]
+        .into_iter()
+        .map(|(account, nonce)| gen_l2_tx_with_hash(account, Nonce(nonce)))
+        .collect();
+    let hashes: Vec<_> = transactions.iter().map(Transaction::hash).collect();
+    mempool.insert_without_constraints(transactions, HashMap::new());
+
+    assert!(mempool
+        .remove_l2_transaction(H256::repeat_byte(0xff))
+        .is_none());
+    assert_eq!(mempool.stats().l2_transaction_count, 4);
+
+    // Removing a non-executable transaction doesn't affect the priority queue.
+    let removed = mempool.remove_l2_transaction(hashes[3]).unwrap();
+    assert_eq!(removed.hash(), hashes[3]);
+    assert_eq!(mempool.stats().l2_transaction_count, 3);
+    assert_eq!(mempool.stats().l2_priority_queue_size, 2);
+    assert!(mempool.remove_l2_transaction(hashes[3]).is_none());
+
+    // Removing the next transaction for an account blocks the account until the nonce gap is filled.
+    mempool.remove_l2_transaction(hashes[0]).unwrap();
+    assert_eq!(mempool.stats().l2_transaction_count, 2);
+    assert_eq!(mempool.stats().l2_priority_queue_size, 1);
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+    assert!(mempool.next_transaction(&L2TxFilter::default()).is_none());
+
+    mempool.insert_without_constraints(
+        vec![gen_l2_tx_with_hash(account0, Nonce(0))],
+        HashMap::new(),
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 1)
+    );
+}
+
 /// Checks whether filtering transactions based on their fee works as expected.
 #[test]
 fn filtering() {
@@ -427,6 +475,15 @@ fn gen_l2_tx_with_timestamp(address: Address, nonce: Nonce, received_at_ms: u64)
     txn.into()
 }
 
+fn gen_l2_tx_with_hash(address: Address, nonce: Nonce) -> Transaction {
+    let mut txn = gen_l2_tx(address, nonce);
+    let ExecuteTransactionCommon::L2(data) = &mut txn.common_data else {
+        unreachable!("expected L2 transaction");
+    };
+    data.set_input(vec![], H256::random());
+    txn
+}
+
 fn gen_l1_tx(priority_id: PriorityOpId) -> Transaction {
     let execute = Execute {
         contract_address: Some(Address::repeat_byte(0x11)),
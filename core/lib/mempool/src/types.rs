@@ -2,7 +2,7 @@ use std::{cmp::Ordering, collections::HashMap};
 
 use zksync_types::{
     fee::Fee, fee_model::BatchFeeInput, l2::L2Tx, Address, Nonce, Transaction,
-    TransactionTimeRangeConstraint, U256,
+    TransactionTimeRangeConstraint, H256, U256,
 };
 
 /// Pending mempool transactions of account
@@ -79,6 +79,18 @@ impl AccountTransactions {
             .map(|(tx, c)| (Self::score_for_transaction(tx), c.clone()))
     }
 
+    /// Removes a transaction with the specified hash. Returns the removed transaction and, if it was the next transaction
+    /// to be executed for the account, its score.
+    pub fn remove(&mut self, tx_hash: H256) -> Option<(L2Tx, Option<MempoolScore>)> {
+        let nonce = self
+            .transactions
+            .iter()
+            .find_map(|(nonce, (tx, _))| (tx.hash() == tx_hash).then_some(*nonce))?;
+        let (transaction, _) = self.transactions.remove(&nonce)?;
+        let score = (nonce == self.nonce).then(|| Self::score_for_transaction(&transaction));
+        Some((transaction, score))
+    }
+
     pub fn len(&self) -> usize {
         self.transactions.len()
     }
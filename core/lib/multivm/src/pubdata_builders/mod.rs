@@ -19,8 +19,7 @@ pub fn pubdata_params_to_builder(params: PubdataParams) -> Rc<dyn PubdataBuilder
         | PubdataType::Avail
         | PubdataType::Celestia
         | PubdataType::Eigen
-        | PubdataType::ObjectStore => {
-            Rc::new(FullPubdataBuilder::new(params.l2_da_validator_address))
-        }
+        | PubdataType::ObjectStore
+        | PubdataType::Sidecar => Rc::new(FullPubdataBuilder::new(params.l2_da_validator_address)),
     }
 }
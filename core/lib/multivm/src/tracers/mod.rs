@@ -10,6 +10,6 @@ mod call_tracer;
 pub mod dynamic;
 mod multivm_dispatcher;
 pub mod old;
-mod prestate_tracer;
+pub(crate) mod prestate_tracer;
 mod storage_invocation;
 mod validator;
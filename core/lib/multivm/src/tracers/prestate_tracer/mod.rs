@@ -45,7 +45,7 @@ impl fmt::Display for Account {
     }
 }
 
-type State = HashMap<Address, Account>;
+pub(crate) type State = HashMap<Address, Account>;
 
 #[derive(Debug, Clone)]
 pub struct PrestateTracer {
@@ -109,7 +109,7 @@ where
         .collect::<State>()
 }
 
-fn get_balance_key(account: &AccountTreeId) -> StorageKey {
+pub(crate) fn get_balance_key(account: &AccountTreeId) -> StorageKey {
     let address_h256 = address_to_h256(account.address());
     let bytes = [address_h256.as_bytes(), &[0; 32]].concat();
     let balance_key: H256 = keccak256(&bytes).into();
@@ -128,7 +128,8 @@ fn get_storage_if_present(
         .collect()
 }
 
-fn process_result(result: &Arc<OnceCell<(State, State)>>, mut pre: State, post: State) {
+/// Retains only pre-execution account states that differ from the post-execution ones.
+pub(crate) fn retain_changed_accounts(pre: &mut State, post: &State) {
     pre.retain(|k, v| {
         if let Some(post_v) = post.get(k) {
             if v != post_v {
@@ -137,6 +138,10 @@ fn process_result(result: &Arc<OnceCell<(State, State)>>, mut pre: State, post:
         }
         false
     });
+}
+
+fn process_result(result: &Arc<OnceCell<(State, State)>>, mut pre: State, post: State) {
+    retain_changed_accounts(&mut pre, &post);
     result.set((pre, post)).unwrap();
 }
 
@@ -27,8 +27,8 @@ use crate::{
 mod tests;
 
 type ReferenceVm<S = InMemoryStorage> = vm_latest::Vm<StorageView<S>, HistoryEnabled>;
-type ShadowedFastVm<S = InMemoryStorage, Tr = ()> =
-    crate::vm_instance::ShadowedFastVm<S, Tr, FastValidationTracer>;
+type ShadowedFastVm<S = InMemoryStorage, Tr = (), Val = FastValidationTracer> =
+    crate::vm_instance::ShadowedFastVm<S, Tr, Val>;
 
 fn hash_block(block_env: L2BlockEnv, tx_hashes: &[H256]) -> H256 {
     let mut hasher = L2BlockHasher::new(
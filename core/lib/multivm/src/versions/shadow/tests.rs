@@ -2,7 +2,7 @@
 
 use std::{collections::HashSet, fmt, rc::Rc};
 
-use zksync_types::{writes::StateDiffRecord, StorageKey, Transaction, H256, U256};
+use zksync_types::{l2::L2Tx, writes::StateDiffRecord, StorageKey, Transaction, H256, U256};
 use zksync_vm2::interface::Tracer;
 use zksync_vm_interface::{
     utils::{CheckDivergence, DivergenceErrors},
@@ -13,17 +13,23 @@ use super::ShadowedFastVm;
 use crate::{
     interface::{
         pubdata::{PubdataBuilder, PubdataInput},
-        storage::InMemoryStorage,
+        storage::{InMemoryStorage, StorageView},
+        tracer::ViolatedValidationRule,
         utils::{ShadowMut, ShadowRef},
         CurrentExecutionState, L2BlockEnv, VmExecutionResultAndLogs,
     },
-    versions::testonly::{TestedVm, TestedVmWithCallTracer},
-    vm_fast,
+    tracers::prestate_tracer::State,
+    versions::testonly::{
+        TestedVm, TestedVmForValidation, TestedVmWithCallTracer, TestedVmWithPrestateTracer,
+        TestedVmWithStorageLimit,
+    },
+    vm_fast::{self, FullValidationTracer, ValidationTracer},
 };
 
-impl<Tr> TestedVm for ShadowedFastVm<InMemoryStorage, Tr>
+impl<Tr, Val> TestedVm for ShadowedFastVm<InMemoryStorage, Tr, Val>
 where
     Tr: Tracer + Default + fmt::Debug + 'static,
+    Val: ValidationTracer + fmt::Debug + 'static,
 {
     type StateDump = ();
 
@@ -183,6 +189,130 @@ impl TestedVmWithCallTracer for ShadowedFastVm<InMemoryStorage, vm_fast::CallTra
     }
 }
 
+#[derive(Debug)]
+struct ExecutionResultAndPrestate {
+    result: VmExecutionResultAndLogs,
+    pre: State,
+    post: State,
+}
+
+impl From<(VmExecutionResultAndLogs, (State, State))> for ExecutionResultAndPrestate {
+    fn from((result, (pre, post)): (VmExecutionResultAndLogs, (State, State))) -> Self {
+        Self { result, pre, post }
+    }
+}
+
+impl From<ExecutionResultAndPrestate> for (VmExecutionResultAndLogs, (State, State)) {
+    fn from(value: ExecutionResultAndPrestate) -> Self {
+        (value.result, (value.pre, value.post))
+    }
+}
+
+impl CheckDivergence for ExecutionResultAndPrestate {
+    fn check_divergence(&self, other: &Self) -> DivergenceErrors {
+        let mut errors = self.result.check_divergence(&other.result);
+        errors.check_match("prestate.pre", &self.pre, &other.pre);
+        errors.check_match("prestate.post", &self.post, &other.post);
+        errors
+    }
+}
+
+impl TestedVmWithPrestateTracer for ShadowedFastVm<InMemoryStorage, vm_fast::PrestateTracer> {
+    fn inspect_with_prestate_tracer(
+        &mut self,
+        diff_mode: bool,
+    ) -> (VmExecutionResultAndLogs, (State, State)) {
+        self.get_custom_mut("inspect_with_prestate_tracer", |r| {
+            ExecutionResultAndPrestate::from(match r {
+                ShadowMut::Main(vm) => vm.inspect_with_prestate_tracer(diff_mode),
+                ShadowMut::Shadow(vm) => vm.inspect_with_prestate_tracer(diff_mode),
+            })
+        })
+        .into()
+    }
+}
+
+#[derive(Debug)]
+struct ExecutionResultAndValidation {
+    result: VmExecutionResultAndLogs,
+    violated_rule: Option<ViolatedValidationRule>,
+}
+
+impl From<(VmExecutionResultAndLogs, Option<ViolatedValidationRule>)>
+    for ExecutionResultAndValidation
+{
+    fn from(
+        (result, violated_rule): (VmExecutionResultAndLogs, Option<ViolatedValidationRule>),
+    ) -> Self {
+        Self {
+            result,
+            violated_rule,
+        }
+    }
+}
+
+impl From<ExecutionResultAndValidation>
+    for (VmExecutionResultAndLogs, Option<ViolatedValidationRule>)
+{
+    fn from(value: ExecutionResultAndValidation) -> Self {
+        (value.result, value.violated_rule)
+    }
+}
+
+impl CheckDivergence for ExecutionResultAndValidation {
+    fn check_divergence(&self, other: &Self) -> DivergenceErrors {
+        let mut errors = self.result.check_divergence(&other.result);
+        errors.check_match("violated_rule", &self.violated_rule, &other.violated_rule);
+        errors
+    }
+}
+
+impl TestedVmForValidation for ShadowedFastVm<InMemoryStorage, (), FullValidationTracer> {
+    fn run_validation(
+        &mut self,
+        tx: L2Tx,
+        timestamp: u64,
+    ) -> (VmExecutionResultAndLogs, Option<ViolatedValidationRule>) {
+        self.get_custom_mut("run_validation", |r| {
+            ExecutionResultAndValidation::from(match r {
+                ShadowMut::Main(vm) => vm.run_validation(tx.clone(), timestamp),
+                ShadowMut::Shadow(vm) => vm.run_validation(tx.clone(), timestamp),
+            })
+        })
+        .into()
+    }
+}
+
+type StorageLimiter = vm_fast::StorageInvocationsTracer<StorageView<InMemoryStorage>>;
+
+impl TestedVmWithStorageLimit for ShadowedFastVm<InMemoryStorage, StorageLimiter> {
+    fn execute_with_storage_limit(&mut self, limit: usize) -> VmExecutionResultAndLogs {
+        self.get_custom_mut("execute_with_storage_limit", |r| match r {
+            ShadowMut::Main(vm) => vm.execute_with_storage_limit(limit),
+            ShadowMut::Shadow(vm) => vm.execute_with_storage_limit(limit),
+        })
+    }
+}
+
+mod account_validation_rules {
+    use crate::versions::testonly::account_validation_rules::*;
+
+    #[test]
+    fn account_validation_rules() {
+        test_account_validation_rules::<super::ShadowedFastVm<_, (), _>>();
+    }
+
+    #[test]
+    fn validation_out_of_gas_with_full_tracer() {
+        test_validation_out_of_gas_with_full_tracer::<super::ShadowedFastVm<_, (), _>>();
+    }
+
+    #[test]
+    fn validation_out_of_gas_with_fast_tracer() {
+        test_validation_out_of_gas_with_fast_tracer::<super::ShadowedFastVm>();
+    }
+}
+
 mod block_tip {
     use crate::versions::testonly::block_tip::*;
 
@@ -610,6 +740,25 @@ mod precompiles {
     }
 }
 
+mod prestate_tracer {
+    use crate::versions::testonly::prestate_tracer::*;
+
+    #[test]
+    fn prestate_tracer() {
+        test_prestate_tracer::<super::ShadowedFastVm<_, _>>();
+    }
+
+    #[test]
+    fn prestate_tracer_diff_mode() {
+        test_prestate_tracer_diff_mode::<super::ShadowedFastVm<_, _>>();
+    }
+
+    #[test]
+    fn prestate_tracer_with_revert() {
+        test_prestate_tracer_with_revert::<super::ShadowedFastVm<_, _>>();
+    }
+}
+
 mod refunds {
     use crate::versions::testonly::refunds::*;
 
@@ -707,6 +856,18 @@ mod storage {
     fn transient_storage_behavior() {
         test_transient_storage_behavior::<super::ShadowedFastVm>();
     }
+
+    #[test]
+    fn limiting_storage_writes() {
+        test_limiting_storage_writes::<super::ShadowedFastVm<_, _>>(false);
+        test_limiting_storage_writes::<super::ShadowedFastVm<_, _>>(true);
+    }
+
+    #[test]
+    fn limiting_storage_reads() {
+        test_limiting_storage_reads::<super::ShadowedFastVm<_, _>>(false);
+        test_limiting_storage_reads::<super::ShadowedFastVm<_, _>>(true);
+    }
 }
 
 mod tracing_execution_error {
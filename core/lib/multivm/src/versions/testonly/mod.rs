@@ -31,7 +31,7 @@ use zksync_types::{
 
 pub(super) use self::tester::{
     validation_params, TestedVm, TestedVmForValidation, TestedVmWithCallTracer,
-    TestedVmWithStorageLimit, VmTester, VmTesterBuilder,
+    TestedVmWithPrestateTracer, TestedVmWithStorageLimit, VmTester, VmTesterBuilder,
 };
 use crate::{
     interface::{
@@ -64,6 +64,7 @@ pub(super) mod l2_blocks;
 pub(super) mod mock_evm;
 pub(super) mod nonce_holder;
 pub(super) mod precompiles;
+pub(super) mod prestate_tracer;
 pub(super) mod refunds;
 pub(super) mod require_eip712;
 pub(super) mod rollbacks;
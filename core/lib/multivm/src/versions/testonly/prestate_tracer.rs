@@ -0,0 +1,79 @@
+//! Prestate tracer tests. The tests only check the post-state of the called contract since the complete output
+//! depends on system contract internals.
+
+use ethabi::Token;
+use zksync_test_contracts::TestContract;
+use zksync_types::{Address, Execute, H256};
+
+use super::{ContractToDeploy, TestedVmWithPrestateTracer, VmTester, VmTesterBuilder};
+use crate::{interface::TxExecutionMode, vm_latest::constants::BATCH_COMPUTATIONAL_GAS_LIMIT};
+
+fn counter_address() -> Address {
+    Address::repeat_byte(0xA5)
+}
+
+fn prepare_vm<VM: TestedVmWithPrestateTracer>(calldata: Vec<u8>) -> VmTester<VM> {
+    let bytecode = TestContract::counter().bytecode.to_vec();
+    let mut vm: VmTester<VM> = VmTesterBuilder::new()
+        .with_rich_accounts(1)
+        .with_bootloader_gas_limit(BATCH_COMPUTATIONAL_GAS_LIMIT)
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_custom_contracts(vec![ContractToDeploy::new(bytecode, counter_address())])
+        .build();
+
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(counter_address()),
+            calldata,
+            value: 0.into(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+    vm.vm.push_transaction(tx);
+    vm
+}
+
+fn increment_calldata(should_revert: bool) -> Vec<u8> {
+    TestContract::counter()
+        .function("incrementWithRevert")
+        .encode_input(&[Token::Uint(6.into()), Token::Bool(should_revert)])
+        .unwrap()
+}
+
+pub(crate) fn test_prestate_tracer<VM: TestedVmWithPrestateTracer>() {
+    let mut vm = prepare_vm::<VM>(increment_calldata(false));
+    let (res, (pre, post)) = vm.vm.inspect_with_prestate_tracer(false);
+    assert!(!res.result.is_failed(), "{:#?}", res.result);
+
+    assert!(pre.is_empty(), "{pre:?}");
+    let counter_state = &post[&counter_address()];
+    let counter_storage = counter_state.storage.as_ref().unwrap();
+    assert_eq!(counter_storage[&H256::zero()], H256::from_low_u64_be(6));
+    assert_ne!(counter_state.code, Some(0.into()));
+}
+
+pub(crate) fn test_prestate_tracer_diff_mode<VM: TestedVmWithPrestateTracer>() {
+    let mut vm = prepare_vm::<VM>(increment_calldata(false));
+    let (res, (pre, post)) = vm.vm.inspect_with_prestate_tracer(true);
+    assert!(!res.result.is_failed(), "{:#?}", res.result);
+
+    let counter_storage = post[&counter_address()].storage.as_ref().unwrap();
+    assert_eq!(counter_storage[&H256::zero()], H256::from_low_u64_be(6));
+    for (address, pre_state) in &pre {
+        assert_ne!(*pre_state, post[address], "{address:?}");
+    }
+}
+
+pub(crate) fn test_prestate_tracer_with_revert<VM: TestedVmWithPrestateTracer>() {
+    let mut vm = prepare_vm::<VM>(increment_calldata(true));
+    let (res, (_, post)) = vm.vm.inspect_with_prestate_tracer(true);
+    assert!(res.result.is_failed(), "{:#?}", res.result);
+
+    // The counter write is rolled back, so the slot value must be reset.
+    if let Some(counter_state) = post.get(&counter_address()) {
+        let counter_storage = counter_state.storage.as_ref().unwrap();
+        assert_eq!(counter_storage[&H256::zero()], H256::zero());
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
         TxExecutionMode, VmExecutionResultAndLogs, VmFactory, VmInterfaceExt,
         VmInterfaceHistoryEnabled,
     },
+    tracers::prestate_tracer::State,
     versions::testonly::{
         default_l1_batch, default_system_env, make_address_rich, ContractToDeploy,
     },
@@ -310,6 +311,13 @@ pub(crate) trait TestedVmWithCallTracer: TestedVm {
     fn inspect_with_call_tracer(&mut self) -> (VmExecutionResultAndLogs, Vec<Call>);
 }
 
+pub(crate) trait TestedVmWithPrestateTracer: TestedVm {
+    fn inspect_with_prestate_tracer(
+        &mut self,
+        diff_mode: bool,
+    ) -> (VmExecutionResultAndLogs, (State, State));
+}
+
 pub(crate) trait TestedVmWithStorageLimit: TestedVm {
     fn execute_with_storage_limit(&mut self, limit: usize) -> VmExecutionResultAndLogs;
 }
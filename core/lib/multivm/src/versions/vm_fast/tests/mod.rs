@@ -14,12 +14,14 @@ use crate::{
         Call, CurrentExecutionState, InspectExecutionMode, L2BlockEnv, VmExecutionMode,
         VmExecutionResultAndLogs, VmInterface,
     },
+    tracers::prestate_tracer::State,
     versions::testonly::{
         validation_params, TestedVm, TestedVmForValidation, TestedVmWithCallTracer,
-        TestedVmWithStorageLimit,
+        TestedVmWithPrestateTracer, TestedVmWithStorageLimit,
     },
     vm_fast::{
-        tracers::WithBuiltinTracers, CallTracer, FastValidationTracer, StorageInvocationsTracer,
+        tracers::WithBuiltinTracers, CallTracer, FastValidationTracer, PrestateTracer,
+        StorageInvocationsTracer,
     },
 };
 
@@ -41,6 +43,7 @@ mod l2_blocks;
 mod mock_evm;
 mod nonce_holder;
 mod precompiles;
+mod prestate_tracer;
 mod refunds;
 mod require_eip712;
 mod rollbacks;
@@ -208,6 +211,20 @@ impl TestedVmWithCallTracer for TestedFastVm<CallTracer, FastValidationTracer> {
     }
 }
 
+impl TestedVmWithPrestateTracer for TestedFastVm<PrestateTracer, FastValidationTracer> {
+    fn inspect_with_prestate_tracer(
+        &mut self,
+        diff_mode: bool,
+    ) -> (VmExecutionResultAndLogs, (State, State)) {
+        let mut tracer = (
+            PrestateTracer::new(diff_mode),
+            FastValidationTracer::default(),
+        );
+        let result = self.inspect(&mut tracer, InspectExecutionMode::OneTx);
+        (result, tracer.0.into_result())
+    }
+}
+
 type TestStorageLimiter = StorageInvocationsTracer<StorageView<InMemoryStorage>>;
 
 impl TestedVmWithStorageLimit for TestedFastVm<TestStorageLimiter, FastValidationTracer> {
@@ -0,0 +1,16 @@
+use crate::versions::{testonly::prestate_tracer, vm_fast::Vm};
+
+#[test]
+fn prestate_tracer() {
+    prestate_tracer::test_prestate_tracer::<Vm<_, _, _>>();
+}
+
+#[test]
+fn prestate_tracer_diff_mode() {
+    prestate_tracer::test_prestate_tracer_diff_mode::<Vm<_, _, _>>();
+}
+
+#[test]
+fn prestate_tracer_with_revert() {
+    prestate_tracer::test_prestate_tracer_with_revert::<Vm<_, _, _>>();
+}
@@ -5,6 +5,7 @@ use zksync_vm2::interface::{CycleStats, GlobalStateInterface, OpcodeType, Should
 pub(super) use self::evm_deploy::DynamicBytecodes;
 pub use self::{
     calls::CallTracer,
+    prestate::PrestateTracer,
     storage::StorageInvocationsTracer,
     validation::{FastValidationTracer, FullValidationTracer, ValidationTracer},
 };
@@ -14,6 +15,7 @@ use crate::interface::CircuitStatistic;
 mod calls;
 mod circuits;
 mod evm_deploy;
+mod prestate;
 mod storage;
 mod validation;
 
@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet};
+
+use zksync_types::{
+    get_code_key, get_nonce_key, h256_to_u256, u256_to_h256, AccountTreeId, Address, StorageKey,
+    H256,
+};
+use zksync_vm2::interface::{
+    CallframeInterface, GlobalStateInterface, Opcode, OpcodeType, ReturnType, ShouldStop, Tracer,
+};
+
+use crate::{
+    tracers::prestate_tracer::{get_balance_key, retain_changed_accounts, Account, State},
+    vm_fast::utils::read_storage_slot,
+};
+
+/// Prestate tracer for the fast VM. Mirrors the legacy [`PrestateTracer`](crate::tracers::PrestateTracer):
+///
+/// - In the default mode, the post-state contains all accounts owning storage slots accessed during execution.
+/// - In the diff mode, the post-state contains accounts owning modified storage slots, and the pre-state contains
+///   the states of changed accounts recorded on their first modification.
+///
+/// Unlike the legacy tracer, only storage accesses observed by this tracer are taken into account; modifications
+/// made by transactions executed before the tracer was attached are not reflected in the output.
+#[derive(Debug, Default)]
+pub struct PrestateTracer {
+    diff_mode: bool,
+    /// Owners of all accessed storage slots.
+    accounts: HashSet<Address>,
+    /// Storage slots modified during execution.
+    written_slots: HashSet<StorageKey>,
+    /// Current values of modified slots and of the account fields (balance, code hash, nonce) for tracked accounts.
+    values: HashMap<StorageKey, H256>,
+    /// Slot written by the currently executed `StorageWrite` instruction.
+    pending_write: Option<StorageKey>,
+    pre: State,
+}
+
+impl PrestateTracer {
+    pub fn new(diff_mode: bool) -> Self {
+        Self {
+            diff_mode,
+            ..Self::default()
+        }
+    }
+
+    /// Converts this tracer into the `(pre, post)` states.
+    pub fn into_result(self) -> (State, State) {
+        if self.diff_mode {
+            let modified_accounts: HashSet<_> = self
+                .written_slots
+                .iter()
+                .map(|key| *key.address())
+                .collect();
+            let post = modified_accounts
+                .into_iter()
+                .map(|address| (address, self.account(address)))
+                .collect();
+            let mut pre = self.pre;
+            retain_changed_accounts(&mut pre, &post);
+            (pre, post)
+        } else {
+            let post = self
+                .accounts
+                .iter()
+                .map(|&address| (address, self.account(address)))
+                .collect();
+            (State::default(), post)
+        }
+    }
+
+    fn account_keys(address: Address) -> [StorageKey; 3] {
+        [
+            get_balance_key(&AccountTreeId::new(address)),
+            get_code_key(&address),
+            get_nonce_key(&address),
+        ]
+    }
+
+    fn read_value<S: GlobalStateInterface>(state: &mut S, key: &StorageKey) -> H256 {
+        u256_to_h256(state.get_storage(*key.address(), h256_to_u256(*key.key())))
+    }
+
+    fn track_account<S: GlobalStateInterface>(&mut self, state: &mut S, address: Address) {
+        if self.accounts.insert(address) {
+            for key in Self::account_keys(address) {
+                let value = Self::read_value(state, &key);
+                self.values.insert(key, value);
+            }
+        }
+    }
+
+    fn account(&self, address: Address) -> Account {
+        let [balance_key, code_key, nonce_key] = Self::account_keys(address);
+        let get_value =
+            |key: &StorageKey| h256_to_u256(self.values.get(key).copied().unwrap_or_default());
+        let storage = self
+            .written_slots
+            .iter()
+            .filter(|key| *key.address() == address)
+            .map(|key| (*key.key(), self.values[key]))
+            .collect();
+
+        Account {
+            balance: Some(get_value(&balance_key)),
+            code: Some(get_value(&code_key)),
+            nonce: Some(get_value(&nonce_key)),
+            storage: Some(storage),
+        }
+    }
+}
+
+impl Tracer for PrestateTracer {
+    fn before_instruction<OP: OpcodeType, S: GlobalStateInterface>(&mut self, state: &mut S) {
+        if !matches!(OP::VALUE, Opcode::StorageRead | Opcode::StorageWrite) {
+            return;
+        }
+
+        let slot = read_storage_slot(state);
+        let address = state.current_frame().address();
+        self.track_account(state, address);
+        if matches!(OP::VALUE, Opcode::StorageWrite) {
+            let key = StorageKey::new(AccountTreeId::new(address), u256_to_h256(slot));
+            self.pending_write = Some(key);
+        }
+    }
+
+    fn after_instruction<OP: OpcodeType, S: GlobalStateInterface>(
+        &mut self,
+        state: &mut S,
+    ) -> ShouldStop {
+        match OP::VALUE {
+            Opcode::StorageWrite => {
+                if let Some(key) = self.pending_write.take() {
+                    let value = Self::read_value(state, &key);
+                    self.written_slots.insert(key);
+                    self.values.insert(key, value);
+
+                    let address = *key.address();
+                    if self.diff_mode && !self.pre.contains_key(&address) {
+                        self.pre.insert(address, self.account(address));
+                    }
+                }
+            }
+            Opcode::Ret(ReturnType::Revert | ReturnType::Panic) => {
+                // Storage changes in the exited frame are rolled back, so tracked values need to be refreshed.
+                for (key, value) in &mut self.values {
+                    *value = Self::read_value(state, key);
+                }
+            }
+            _ => {}
+        }
+        ShouldStop::Continue
+    }
+}
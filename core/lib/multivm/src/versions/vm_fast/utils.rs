@@ -1,5 +1,8 @@
 use zksync_types::U256;
-use zksync_vm2::{interface::StateInterface, FatPointer};
+use zksync_vm2::{
+    interface::{CallframeInterface, StateInterface},
+    FatPointer,
+};
 
 pub(super) fn read_raw_fat_pointer<S: StateInterface>(state: &S, raw: U256) -> Vec<u8> {
     read_fat_pointer(state, FatPointer::from(raw))
@@ -14,3 +17,14 @@ pub(super) fn read_fat_pointer<S: StateInterface>(state: &S, pointer: FatPointer
     }
     result
 }
+
+/// Reads the storage slot operand of the current `StorageRead` / `StorageWrite` instruction. Must be called
+/// before the instruction is executed.
+pub(super) fn read_storage_slot<S: StateInterface>(state: &mut S) -> U256 {
+    // Can unwrap because the instruction pointer does not point to a panic instruction
+    let pc = state.current_frame().program_counter().unwrap();
+    let word = pc / 4;
+    let part = pc % 4;
+    let instruction = state.current_frame().read_contract_code(word).0[3 - part as usize];
+    state.read_register((instruction >> 16) as u8 & 0b1111).0
+}
@@ -24,11 +24,13 @@ use crate::{
         tracer::ViolatedValidationRule,
         CurrentExecutionState, L2BlockEnv, VmExecutionMode, VmExecutionResultAndLogs,
     },
-    tracers::{CallTracer, StorageInvocations, ValidationTracer},
+    tracers::{
+        prestate_tracer::State, CallTracer, PrestateTracer, StorageInvocations, ValidationTracer,
+    },
     utils::bytecode::bytes_to_be_words,
     versions::testonly::{
         filter_out_base_system_contracts, validation_params, TestedVm, TestedVmForValidation,
-        TestedVmWithCallTracer, TestedVmWithStorageLimit,
+        TestedVmWithCallTracer, TestedVmWithPrestateTracer, TestedVmWithStorageLimit,
     },
     vm_latest::{
         constants::BOOTLOADER_HEAP_PAGE,
@@ -357,6 +359,19 @@ impl TestedVmWithCallTracer for TestedLatestVm {
     }
 }
 
+impl TestedVmWithPrestateTracer for TestedLatestVm {
+    fn inspect_with_prestate_tracer(
+        &mut self,
+        diff_mode: bool,
+    ) -> (VmExecutionResultAndLogs, (State, State)) {
+        let result = Arc::new(OnceCell::new());
+        let prestate_tracer = PrestateTracer::new(diff_mode, result.clone()).into_tracer_pointer();
+        let res = self.inspect(&mut prestate_tracer.into(), InspectExecutionMode::OneTx);
+        let state = result.get().unwrap().clone();
+        (res, state)
+    }
+}
+
 impl TestedVmWithStorageLimit for TestedLatestVm {
     fn execute_with_storage_limit(&mut self, limit: usize) -> VmExecutionResultAndLogs {
         let tracer = StorageInvocations::new(limit).into_tracer_pointer();
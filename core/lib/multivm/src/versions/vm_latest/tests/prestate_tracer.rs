@@ -8,7 +8,7 @@ use super::TestedLatestVm;
 use crate::{
     interface::{InspectExecutionMode, TxExecutionMode, VmInterface, VmInterfaceExt},
     tracers::PrestateTracer,
-    versions::testonly::VmTesterBuilder,
+    versions::testonly::{prestate_tracer, VmTesterBuilder},
     vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, ToTracerPointer},
 };
 
@@ -127,3 +127,18 @@ fn test_prestate_tracer_diff_mode() {
         Some(U256::from(200000))
     );
 }
+
+#[test]
+fn prestate_tracer_with_custom_contract() {
+    prestate_tracer::test_prestate_tracer::<TestedLatestVm>();
+}
+
+#[test]
+fn prestate_tracer_diff_mode_with_custom_contract() {
+    prestate_tracer::test_prestate_tracer_diff_mode::<TestedLatestVm>();
+}
+
+#[test]
+fn prestate_tracer_with_revert() {
+    prestate_tracer::test_prestate_tracer_with_revert::<TestedLatestVm>();
+}
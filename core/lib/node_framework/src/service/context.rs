@@ -1,6 +1,9 @@
 use std::any::type_name;
 
-use super::shutdown_hook::ShutdownHook;
+use super::{
+    shutdown_hook::ShutdownHook,
+    wiring_graph::{ResourceNode, ResourceRequestStatus},
+};
 use crate::{
     resource::{Resource, ResourceId, StoredResource},
     service::{named_future::NamedFuture, ZkStackService},
@@ -52,7 +55,9 @@ impl<'a> ServiceContext<'a> {
     /// Added tasks will be launched after the wiring process will be finished and all the preconditions
     /// are met.
     pub fn add_task<T: Task>(&mut self, task: T) -> &mut Self {
-        tracing::info!("Layer {} has added a new task: {}", self.layer, task.id());
+        let id = task.id();
+        tracing::info!("Layer {} has added a new task: {id}", self.layer);
+        self.service.wiring_graph.record_task(&id, task.kind());
         self.service.runnables.tasks.push(Box::new(task));
         self
     }
@@ -68,6 +73,7 @@ impl<'a> ServiceContext<'a> {
             self.layer,
             hook.id
         );
+        self.service.wiring_graph.record_shutdown_hook(&hook.id);
         self.service
             .runnables
             .shutdown_hooks
@@ -81,6 +87,24 @@ impl<'a> ServiceContext<'a> {
     ///
     /// Panics if the resource with the specified [`ResourceId`] exists, but is not of the requested type.
     pub fn get_resource<T: Resource + Clone>(&mut self) -> Result<T, WiringError> {
+        let resource = self.find_resource::<T>();
+        let status = if resource.is_ok() {
+            ResourceRequestStatus::Available
+        } else {
+            ResourceRequestStatus::Missing
+        };
+        self.service
+            .wiring_graph
+            .record_request(resource_node::<T>(), status);
+        resource
+    }
+
+    /// Marks the last resource request of the layer as optional; used for `Option<_>` layer inputs.
+    pub(super) fn mark_last_request_optional(&mut self) {
+        self.service.wiring_graph.mark_last_request_optional();
+    }
+
+    fn find_resource<T: Resource + Clone>(&self) -> Result<T, WiringError> {
         // Implementation details:
         // Internally the resources are stored as [`std::any::Any`], and this method does the downcasting
         // on behalf of the caller.
@@ -130,7 +154,10 @@ impl<'a> ServiceContext<'a> {
         &mut self,
         f: F,
     ) -> T {
-        if let Ok(resource) = self.get_resource::<T>() {
+        if let Ok(resource) = self.find_resource::<T>() {
+            self.service
+                .wiring_graph
+                .record_request(resource_node::<T>(), ResourceRequestStatus::Available);
             return resource;
         }
 
@@ -139,6 +166,12 @@ impl<'a> ServiceContext<'a> {
         self.service
             .resources
             .insert(ResourceId::of::<T>(), Box::new(resource.clone()));
+        self.service
+            .wiring_graph
+            .record_request(resource_node::<T>(), ResourceRequestStatus::Inserted);
+        self.service
+            .wiring_graph
+            .record_provision(resource_node::<T>(), false);
         tracing::info!(
             "Layer {} has created a new resource {}",
             self.layer,
@@ -165,12 +198,18 @@ impl<'a> ServiceContext<'a> {
                 T::name(),
                 type_name::<T>()
             );
+            self.service
+                .wiring_graph
+                .record_provision(resource_node::<T>(), true);
             return Err(WiringError::ResourceAlreadyProvided {
                 id: ResourceId::of::<T>(),
                 name: T::name(),
             });
         }
         self.service.resources.insert(id, Box::new(resource));
+        self.service
+            .wiring_graph
+            .record_provision(resource_node::<T>(), false);
         tracing::info!(
             "Layer {} has provided a new resource {}",
             self.layer,
@@ -179,3 +218,10 @@ impl<'a> ServiceContext<'a> {
         Ok(())
     }
 }
+
+fn resource_node<T: Resource>() -> ResourceNode {
+    ResourceNode {
+        name: T::name(),
+        type_name: type_name::<T>(),
+    }
+}
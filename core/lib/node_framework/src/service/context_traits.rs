@@ -51,7 +51,10 @@ impl<T: FromContext> FromContext for Option<T> {
     fn from_context(context: &mut ServiceContext<'_>) -> Result<Self, WiringError> {
         match T::from_context(context) {
             Ok(inner) => Ok(Some(inner)),
-            Err(WiringError::ResourceLacking { .. }) => Ok(None),
+            Err(WiringError::ResourceLacking { .. }) => {
+                context.mark_last_request_optional();
+                Ok(None)
+            }
             Err(err) => Err(err),
         }
     }
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 
 use futures::future::Fuse;
 use tokio::{runtime::Runtime, sync::watch, task::JoinHandle};
@@ -10,6 +10,10 @@ pub use self::{
     error::{TaskError, ZkStackServiceError},
     shutdown_hook::ShutdownHook,
     stop_receiver::StopReceiver,
+    wiring_graph::{
+        LayerNode, ResourceNode, ResourceProvision, ResourceRequest, ResourceRequestStatus,
+        TaskNode, WiringDiagnostic, WiringGraph, WiringGraphFormat,
+    },
 };
 use crate::{
     resource::{ResourceId, StoredResource},
@@ -30,6 +34,7 @@ mod shutdown_hook;
 mod stop_receiver;
 #[cfg(test)]
 mod tests;
+mod wiring_graph;
 
 // A reasonable amount of time for any task to finish the shutdown process
 const TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
@@ -109,6 +114,8 @@ impl ZkStackServiceBuilder {
             stop_sender,
             runtime: self.runtime,
             errors: Vec::new(),
+            wiring_graph: WiringGraph::default(),
+            wiring_graph_export: None,
         }
     }
 }
@@ -131,11 +138,40 @@ pub struct ZkStackService {
 
     /// Collector for the task errors met during the service execution.
     errors: Vec<TaskError>,
+
+    /// Wiring graph collected during the wiring phase.
+    wiring_graph: WiringGraph,
+    /// Location to export the wiring graph to after the wiring phase.
+    wiring_graph_export: Option<(PathBuf, WiringGraphFormat)>,
 }
 
 type TaskFuture = NamedFuture<Fuse<JoinHandle<anyhow::Result<()>>>>;
 
 impl ZkStackService {
+    /// Configures the service to export its [`WiringGraph`] to the specified file once the wiring phase is complete.
+    /// The graph is exported even if wiring fails, which can be used to debug wiring errors.
+    pub fn export_wiring_graph(&mut self, path: PathBuf, format: WiringGraphFormat) -> &mut Self {
+        self.wiring_graph_export = Some((path, format));
+        self
+    }
+
+    /// Performs wiring of the service without running any tasks. Returns the resolved wiring graph
+    /// (which is returned even if wiring fails) and the wiring result. All tasks and shutdown hooks
+    /// added by the wiring layers are dropped.
+    ///
+    /// This can be used to validate the node configuration. Note that wiring layers may perform I/O
+    /// during wiring (e.g., connect to the database), so this check is not entirely side-effect-free.
+    pub fn dry_run(mut self) -> (WiringGraph, Result<(), ZkStackServiceError>) {
+        let wiring_result = self.wire();
+        // Make sure that tasks and resources are dropped within the Tokio context.
+        {
+            let _rt_guard = self.runtime.enter();
+            drop(std::mem::take(&mut self.runnables));
+            self.resources.clear();
+        }
+        (std::mem::take(&mut self.wiring_graph), wiring_result)
+    }
+
     /// Runs the system.
     ///
     /// In case of errors during wiring phase, will return the list of all the errors that happened, in the order
@@ -181,9 +217,13 @@ impl ZkStackService {
         let runtime_handle = self.runtime.handle().clone();
         for (name, WireFn(wire_fn)) in wiring_layers {
             // We must process wiring layers sequentially and in the same order as they were added.
+            self.wiring_graph.layers.push(LayerNode::new(name));
             let mut context = ServiceContext::new(name, self);
             let task_result = wire_fn(&runtime_handle, &mut context);
             if let Err(err) = task_result {
+                if let Some(layer) = self.wiring_graph.current_layer() {
+                    layer.error = Some(format!("{err:#}"));
+                }
                 // We don't want to bail on the first error, since it'll provide worse DevEx:
                 // People likely want to fix as much problems as they can in one go, rather than have
                 // to fix them one by one.
@@ -192,11 +232,22 @@ impl ZkStackService {
             };
         }
 
+        if let Some((path, format)) = &self.wiring_graph_export {
+            let exported = self.wiring_graph.export(*format);
+            match std::fs::write(path, exported) {
+                Ok(()) => tracing::info!("Exported wiring graph to {path:?}"),
+                Err(err) => tracing::warn!("Failed exporting wiring graph to {path:?}: {err}"),
+            }
+        }
+
         // Report all the errors we've met during the init.
         if !errors.is_empty() {
             for (layer, error) in &errors {
                 tracing::error!("Wiring layer {layer} can't be initialized: {error:?}");
             }
+            for diagnostic in self.wiring_graph.diagnostics() {
+                tracing::info!("Wiring diagnostic: {diagnostic}");
+            }
             return Err(ZkStackServiceError::Wiring(errors));
         }
 
@@ -5,9 +5,13 @@ use assert_matches::assert_matches;
 use tokio::{runtime::Runtime, sync::Barrier};
 
 use crate::{
-    service::{StopReceiver, WiringError, WiringLayer, ZkStackServiceBuilder, ZkStackServiceError},
-    task::{Task, TaskId},
-    IntoContext,
+    resource::Resource,
+    service::{
+        ResourceRequestStatus, StopReceiver, WiringDiagnostic, WiringError, WiringGraphFormat,
+        WiringLayer, ZkStackServiceBuilder, ZkStackServiceError,
+    },
+    task::{Task, TaskId, TaskKind},
+    FromContext, IntoContext,
 };
 
 // `ZkStack` Service's `new()` method has to have a check for nested runtime.
@@ -252,3 +256,222 @@ fn test_task_run() {
     let res2 = *remaining_task_was_run.lock().unwrap();
     assert!(res2, "Incorrect resource value");
 }
+
+#[derive(Debug, Clone)]
+struct TestResource;
+
+impl Resource for TestResource {
+    fn name() -> String {
+        "test/resource".into()
+    }
+}
+
+#[derive(Debug)]
+struct ProviderLayer;
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+struct ProviderLayerOutput {
+    resource: TestResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ProviderLayer {
+    type Input = ();
+    type Output = ProviderLayerOutput;
+
+    fn layer_name(&self) -> &'static str {
+        "provider_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        Ok(ProviderLayerOutput {
+            resource: TestResource,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ConsumerLayer {
+    task_was_run: Arc<Mutex<bool>>,
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+struct ConsumerLayerInput {
+    _resource: TestResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+struct ConsumerLayerOutput {
+    #[context(task)]
+    task: SuccessfulTask,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ConsumerLayer {
+    type Input = ConsumerLayerInput;
+    type Output = ConsumerLayerOutput;
+
+    fn layer_name(&self) -> &'static str {
+        "consumer_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let task = SuccessfulTask(Arc::new(Barrier::new(1)), self.task_was_run);
+        Ok(ConsumerLayerOutput { task })
+    }
+}
+
+#[test]
+fn dry_run_collects_wiring_graph_without_running_tasks() {
+    let task_was_run = Arc::new(Mutex::new(false));
+    let mut builder = ZkStackServiceBuilder::new().unwrap();
+    builder.add_layer(ProviderLayer).add_layer(ConsumerLayer {
+        task_was_run: task_was_run.clone(),
+    });
+    let (graph, result) = builder.build().dry_run();
+    result.unwrap();
+    assert!(!*task_was_run.lock().unwrap());
+
+    assert_eq!(graph.layers.len(), 2);
+    let provider = &graph.layers[0];
+    assert_eq!(provider.name, "provider_layer");
+    assert!(provider.requests.is_empty());
+    assert_eq!(provider.provides.len(), 1);
+    assert_eq!(provider.provides[0].resource.name, "test/resource");
+    assert!(!provider.provides[0].duplicate);
+
+    let consumer = &graph.layers[1];
+    assert_eq!(consumer.requests.len(), 1);
+    assert_eq!(
+        consumer.requests[0].status,
+        ResourceRequestStatus::Available
+    );
+    assert_eq!(consumer.tasks.len(), 1);
+    assert_eq!(consumer.tasks[0].id, "successful_task");
+    assert_eq!(consumer.tasks[0].kind, TaskKind::Task);
+    assert!(consumer.error.is_none());
+    assert!(graph.diagnostics().is_empty());
+
+    let dot = graph.export(WiringGraphFormat::Dot);
+    assert!(dot.starts_with("digraph wiring {"), "{dot}");
+    assert!(dot.contains("layer_0 -> \"test/resource\""), "{dot}");
+    assert!(dot.contains("\"test/resource\" -> layer_1"), "{dot}");
+    let json: serde_json::Value =
+        serde_json::from_str(&graph.export(WiringGraphFormat::Json)).unwrap();
+    assert_eq!(json["layers"][1]["tasks"][0]["kind"], "task");
+}
+
+#[test]
+fn wiring_graph_diagnoses_misordered_and_duplicate_layers() {
+    #[derive(Debug)]
+    struct DuplicateProviderLayer;
+
+    #[async_trait::async_trait]
+    impl WiringLayer for DuplicateProviderLayer {
+        type Input = ();
+        type Output = ProviderLayerOutput;
+
+        fn layer_name(&self) -> &'static str {
+            "duplicate_provider_layer"
+        }
+
+        async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+            Ok(ProviderLayerOutput {
+                resource: TestResource,
+            })
+        }
+    }
+
+    let mut builder = ZkStackServiceBuilder::new().unwrap();
+    builder
+        .add_layer(ConsumerLayer {
+            task_was_run: Arc::default(),
+        })
+        .add_layer(ProviderLayer)
+        .add_layer(DuplicateProviderLayer);
+    let (graph, result) = builder.build().dry_run();
+    assert_matches!(result.unwrap_err(), ZkStackServiceError::Wiring(errors) if errors.len() == 2);
+
+    assert_eq!(graph.layers.len(), 3);
+    assert!(graph.layers[0].error.is_some());
+    assert!(graph.layers[2].error.is_some());
+    assert!(graph.layers[2].provides[0].duplicate);
+
+    let diagnostics = graph.diagnostics();
+    assert_eq!(
+        diagnostics,
+        [
+            WiringDiagnostic::MissingResource {
+                layer: "consumer_layer".into(),
+                resource: "test/resource".into(),
+                provided_by: Some("provider_layer".into()),
+            },
+            WiringDiagnostic::DuplicateResource {
+                layer: "duplicate_provider_layer".into(),
+                resource: "test/resource".into(),
+                provided_by: Some("provider_layer".into()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn wiring_graph_distinguishes_optional_resources() {
+    #[derive(Debug)]
+    struct OptionalConsumerLayer;
+
+    #[derive(Debug, FromContext)]
+    #[context(crate = crate)]
+    struct OptionalConsumerLayerInput {
+        _resource: Option<TestResource>,
+    }
+
+    #[async_trait::async_trait]
+    impl WiringLayer for OptionalConsumerLayer {
+        type Input = OptionalConsumerLayerInput;
+        type Output = ();
+
+        fn layer_name(&self) -> &'static str {
+            "optional_consumer_layer"
+        }
+
+        async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+            Ok(())
+        }
+    }
+
+    let mut builder = ZkStackServiceBuilder::new().unwrap();
+    builder.add_layer(OptionalConsumerLayer);
+    let (graph, result) = builder.build().dry_run();
+    result.unwrap();
+    assert_eq!(
+        graph.layers[0].requests[0].status,
+        ResourceRequestStatus::OptionalNotProvided
+    );
+    assert!(graph.diagnostics().is_empty());
+    let json: serde_json::Value =
+        serde_json::from_str(&graph.export(WiringGraphFormat::Json)).unwrap();
+    assert_eq!(
+        json["layers"][0]["requests"][0]["status"],
+        "optional_not_provided"
+    );
+
+    // If the optional resource is provided by a layer wired later, it's likely a layer ordering issue.
+    let mut builder = ZkStackServiceBuilder::new().unwrap();
+    builder
+        .add_layer(OptionalConsumerLayer)
+        .add_layer(ProviderLayer);
+    let (graph, result) = builder.build().dry_run();
+    result.unwrap();
+    assert_eq!(
+        graph.diagnostics(),
+        [WiringDiagnostic::OptionalResourceProvidedLater {
+            layer: "optional_consumer_layer".into(),
+            resource: "test/resource".into(),
+            provided_by: "provider_layer".into(),
+        }]
+    );
+}
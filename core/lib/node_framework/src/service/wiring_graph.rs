@@ -0,0 +1,435 @@
+//! Introspection of the service wiring: which layers provide and consume which resources, and which tasks
+//! they add.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{self, Write as _},
+    str::FromStr,
+};
+
+use serde::Serialize;
+
+use crate::task::{TaskId, TaskKind};
+
+/// Outcome of a resource request made by a wiring layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceRequestStatus {
+    /// The resource was available in the context.
+    Available,
+    /// The required resource wasn't available.
+    Missing,
+    /// The resource wasn't available, but the layer requested it as optional (e.g., as an `Option<_>` input field).
+    OptionalNotProvided,
+    /// The resource wasn't available and was inserted by the requesting layer (e.g., using the default value).
+    Inserted,
+}
+
+/// Resource requested or provided by a wiring layer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResourceNode {
+    /// Resource name as returned by [`Resource::name()`](crate::resource::Resource::name()).
+    pub name: String,
+    /// Rust type of the resource.
+    pub type_name: &'static str,
+}
+
+/// Resource requested by a wiring layer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResourceRequest {
+    #[serde(flatten)]
+    pub resource: ResourceNode,
+    pub status: ResourceRequestStatus,
+}
+
+/// Resource provided by a wiring layer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResourceProvision {
+    #[serde(flatten)]
+    pub resource: ResourceNode,
+    /// Set if the resource was already provided by another layer, i.e., the provision has failed.
+    pub duplicate: bool,
+}
+
+/// Task added by a wiring layer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskNode {
+    pub id: String,
+    pub kind: TaskKind,
+}
+
+/// Information about a single wiring layer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LayerNode {
+    /// Layer name as returned by [`WiringLayer::layer_name()`](crate::wiring_layer::WiringLayer::layer_name()).
+    pub name: String,
+    /// Resources requested by the layer, in the order of requests.
+    pub requests: Vec<ResourceRequest>,
+    /// Resources provided by the layer, in the order of provision.
+    pub provides: Vec<ResourceProvision>,
+    /// Tasks added by the layer.
+    pub tasks: Vec<TaskNode>,
+    /// IDs of shutdown hooks added by the layer.
+    pub shutdown_hooks: Vec<String>,
+    /// Wiring error for the layer, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl LayerNode {
+    pub(super) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            requests: vec![],
+            provides: vec![],
+            tasks: vec![],
+            shutdown_hooks: vec![],
+            error: None,
+        }
+    }
+}
+
+/// Potential wiring issue detected by analyzing [`WiringGraph`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WiringDiagnostic {
+    /// Layer requested a resource that wasn't available at the time of the request.
+    /// If `provided_by` is set, the resource is provided by a layer that was wired *after* the requesting layer,
+    /// which usually means that the layers are added in the wrong order.
+    MissingResource {
+        layer: String,
+        resource: String,
+        provided_by: Option<String>,
+    },
+    /// Layer requested an optional resource that wasn't available at the time of the request, but is provided
+    /// by a layer wired *after* the requesting layer. Unlike [`Self::MissingResource`], this doesn't lead
+    /// to a wiring error, but the requesting layer silently works without the resource.
+    OptionalResourceProvidedLater {
+        layer: String,
+        resource: String,
+        provided_by: String,
+    },
+    /// Layer attempted to provide a resource that was already provided by another layer.
+    DuplicateResource {
+        layer: String,
+        resource: String,
+        provided_by: Option<String>,
+    },
+}
+
+impl fmt::Display for WiringDiagnostic {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingResource {
+                layer,
+                resource,
+                provided_by,
+            } => {
+                write!(
+                    formatter,
+                    "layer `{layer}` requested resource `{resource}` which wasn't available"
+                )?;
+                if let Some(provided_by) = provided_by {
+                    write!(
+                        formatter,
+                        "; it is provided by layer `{provided_by}` that was wired later"
+                    )?;
+                }
+                Ok(())
+            }
+            Self::OptionalResourceProvidedLater {
+                layer,
+                resource,
+                provided_by,
+            } => write!(
+                formatter,
+                "layer `{layer}` requested optional resource `{resource}` which wasn't available; \
+                 it is provided by layer `{provided_by}` that was wired later"
+            ),
+            Self::DuplicateResource {
+                layer,
+                resource,
+                provided_by,
+            } => {
+                write!(
+                    formatter,
+                    "layer `{layer}` attempted to provide resource `{resource}`"
+                )?;
+                if let Some(provided_by) = provided_by {
+                    write!(formatter, " already provided by layer `{provided_by}`")?;
+                } else {
+                    write!(formatter, " which was already provided")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Format for exporting a [`WiringGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WiringGraphFormat {
+    /// JSON including layers and diagnostics.
+    #[default]
+    Json,
+    /// Graphviz DOT.
+    Dot,
+}
+
+impl FromStr for WiringGraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "dot" => Ok(Self::Dot),
+            _ => Err(format!(
+                "unknown wiring graph format `{s}`; expected `json` or `dot`"
+            )),
+        }
+    }
+}
+
+/// Resolved wiring graph of the service, i.e. the wiring layers in the order of wiring together with resources
+/// each layer requests and provides, and tasks it adds.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WiringGraph {
+    pub layers: Vec<LayerNode>,
+}
+
+impl WiringGraph {
+    pub(super) fn current_layer(&mut self) -> Option<&mut LayerNode> {
+        self.layers.last_mut()
+    }
+
+    pub(super) fn record_request(&mut self, resource: ResourceNode, status: ResourceRequestStatus) {
+        if let Some(layer) = self.current_layer() {
+            layer.requests.push(ResourceRequest { resource, status });
+        }
+    }
+
+    /// Marks the last request of the current layer as optional if the requested resource wasn't available.
+    pub(super) fn mark_last_request_optional(&mut self) {
+        let last_request = self
+            .current_layer()
+            .and_then(|layer| layer.requests.last_mut());
+        if let Some(request) = last_request {
+            if request.status == ResourceRequestStatus::Missing {
+                request.status = ResourceRequestStatus::OptionalNotProvided;
+            }
+        }
+    }
+
+    pub(super) fn record_provision(&mut self, resource: ResourceNode, duplicate: bool) {
+        if let Some(layer) = self.current_layer() {
+            layer.provides.push(ResourceProvision {
+                resource,
+                duplicate,
+            });
+        }
+    }
+
+    pub(super) fn record_task(&mut self, id: &TaskId, kind: TaskKind) {
+        if let Some(layer) = self.current_layer() {
+            layer.tasks.push(TaskNode {
+                id: id.to_string(),
+                kind,
+            });
+        }
+    }
+
+    pub(super) fn record_shutdown_hook(&mut self, id: &TaskId) {
+        if let Some(layer) = self.current_layer() {
+            layer.shutdown_hooks.push(id.to_string());
+        }
+    }
+
+    /// Returns the first layer successfully providing each resource.
+    fn resource_providers(&self) -> HashMap<&str, &str> {
+        let mut providers = HashMap::new();
+        for layer in &self.layers {
+            for provision in &layer.provides {
+                if !provision.duplicate {
+                    providers
+                        .entry(provision.resource.name.as_str())
+                        .or_insert(layer.name.as_str());
+                }
+            }
+        }
+        providers
+    }
+
+    /// Analyzes the graph for potential wiring issues.
+    ///
+    /// Optional resources that are not provided by any layer are not reported.
+    pub fn diagnostics(&self) -> Vec<WiringDiagnostic> {
+        let providers = self.resource_providers();
+        let mut diagnostics = vec![];
+        for layer in &self.layers {
+            for request in &layer.requests {
+                let name = &request.resource.name;
+                let provided_by = providers.get(name.as_str()).copied();
+                if provided_by == Some(layer.name.as_str()) {
+                    // The layer has checked whether the resource is present, and provided it itself.
+                    continue;
+                }
+                match request.status {
+                    ResourceRequestStatus::Missing => {
+                        diagnostics.push(WiringDiagnostic::MissingResource {
+                            layer: layer.name.clone(),
+                            resource: name.clone(),
+                            provided_by: provided_by.map(str::to_owned),
+                        });
+                    }
+                    ResourceRequestStatus::OptionalNotProvided => {
+                        if let Some(provided_by) = provided_by {
+                            diagnostics.push(WiringDiagnostic::OptionalResourceProvidedLater {
+                                layer: layer.name.clone(),
+                                resource: name.clone(),
+                                provided_by: provided_by.to_owned(),
+                            });
+                        }
+                    }
+                    ResourceRequestStatus::Available | ResourceRequestStatus::Inserted => {}
+                }
+            }
+            for provision in &layer.provides {
+                if provision.duplicate {
+                    let name = &provision.resource.name;
+                    diagnostics.push(WiringDiagnostic::DuplicateResource {
+                        layer: layer.name.clone(),
+                        resource: name.clone(),
+                        provided_by: providers.get(name.as_str()).map(|&s| s.to_owned()),
+                    });
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Exports this graph in the specified format.
+    pub fn export(&self, format: WiringGraphFormat) -> String {
+        match format {
+            WiringGraphFormat::Json => self.to_json(),
+            WiringGraphFormat::Dot => self.to_dot(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct JsonGraph<'a> {
+            layers: &'a [LayerNode],
+            diagnostics: Vec<WiringDiagnostic>,
+        }
+
+        let graph = JsonGraph {
+            layers: &self.layers,
+            diagnostics: self.diagnostics(),
+        };
+        serde_json::to_string_pretty(&graph).expect("failed serializing wiring graph")
+    }
+
+    fn to_dot(&self) -> String {
+        const MISSING_COLOR: &str = "red";
+        const OPTIONAL_COLOR: &str = "gray";
+
+        let providers = self.resource_providers();
+        let required: BTreeSet<_> = self
+            .layers
+            .iter()
+            .flat_map(|layer| &layer.requests)
+            .filter(|req| req.status != ResourceRequestStatus::OptionalNotProvided)
+            .map(|req| &req.resource.name)
+            .collect();
+        let resources: BTreeSet<_> = self
+            .layers
+            .iter()
+            .flat_map(|layer| {
+                let requested = layer.requests.iter().map(|req| &req.resource.name);
+                let provided = layer.provides.iter().map(|prov| &prov.resource.name);
+                requested.chain(provided)
+            })
+            .collect();
+
+        // `write!` to a `String` is infallible, so results are ignored below.
+        let mut dot = String::from("digraph wiring {\n    rankdir=LR;\n");
+        for (i, layer) in self.layers.iter().enumerate() {
+            let color = if layer.error.is_some() {
+                MISSING_COLOR
+            } else {
+                "black"
+            };
+            writeln!(
+                dot,
+                "    layer_{i} [shape=box, color={color}, label={}];",
+                escape(&layer.name)
+            )
+            .ok();
+        }
+        for resource in &resources {
+            let color = if providers.contains_key(resource.as_str()) {
+                "black"
+            } else if required.contains(resource) {
+                MISSING_COLOR
+            } else {
+                OPTIONAL_COLOR
+            };
+            writeln!(
+                dot,
+                "    {} [shape=ellipse, color={color}];",
+                escape(resource)
+            )
+            .ok();
+        }
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            for request in &layer.requests {
+                let (style, color) = match request.status {
+                    ResourceRequestStatus::Available => ("solid", "black"),
+                    ResourceRequestStatus::Missing => ("dashed", MISSING_COLOR),
+                    ResourceRequestStatus::OptionalNotProvided => ("dashed", OPTIONAL_COLOR),
+                    ResourceRequestStatus::Inserted => ("dotted", "black"),
+                };
+                writeln!(
+                    dot,
+                    "    {} -> layer_{i} [style={style}, color={color}];",
+                    escape(&request.resource.name)
+                )
+                .ok();
+            }
+            for provision in &layer.provides {
+                let color = if provision.duplicate {
+                    MISSING_COLOR
+                } else {
+                    "black"
+                };
+                writeln!(
+                    dot,
+                    "    layer_{i} -> {} [color={color}];",
+                    escape(&provision.resource.name)
+                )
+                .ok();
+            }
+            for (j, task) in layer.tasks.iter().enumerate() {
+                let shape = if task.kind.is_oneshot() {
+                    "hexagon"
+                } else {
+                    "octagon"
+                };
+                writeln!(
+                    dot,
+                    "    task_{i}_{j} [shape={shape}, label={}];\n    layer_{i} -> task_{i}_{j};",
+                    escape(&task.id)
+                )
+                .ok();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Escapes a string to be used as a DOT identifier.
+fn escape(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
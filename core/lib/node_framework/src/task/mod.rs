@@ -7,9 +7,15 @@ use std::{
 
 use tokio::sync::Barrier;
 
-pub use self::types::{TaskId, TaskKind};
+pub use self::{
+    supervision::{
+        ExponentialBackoff, Supervised, SupervisionEvent, SupervisionObserver, SupervisionPolicy,
+    },
+    types::{TaskId, TaskKind},
+};
 use crate::service::StopReceiver;
 
+mod supervision;
 mod types;
 
 /// A task implementation.
@@ -56,6 +62,12 @@ mod types;
 /// A task that can run without waiting for preconditions and can exit without stopping the service.
 /// Usually such tasks may be used for satisfying a precondition, for example, they can perform the database
 /// setup.
+///
+/// ## Supervision
+///
+/// By default, a failure of any task (except for oneshot tasks exiting successfully) stops the service.
+/// Non-critical tasks can be wrapped in [`Supervised`] to be restarted with an exponential backoff according
+/// to a [`SupervisionPolicy`] instead.
 #[async_trait::async_trait]
 pub trait Task: 'static + Send {
     /// Returns the kind of the task.
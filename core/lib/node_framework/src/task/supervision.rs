@@ -0,0 +1,412 @@
+//! Supervision of tasks: restarting failed non-critical tasks instead of stopping the service.
+
+use std::{fmt, num::NonZeroU32, time::Duration};
+
+use vise::{Counter, Gauge, LabeledFamily, Metrics};
+use zksync_utils::panic_extractor::try_extract_panic_message;
+
+use super::{Task, TaskId, TaskKind};
+use crate::service::StopReceiver;
+
+/// Exponential backoff used when restarting failed tasks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialBackoff {
+    /// Delay before the first restart.
+    pub initial_delay: Duration,
+    /// Maximum delay between restarts.
+    pub max_delay: Duration,
+    /// Multiplier applied to the delay after each failure.
+    pub multiplier: f64,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(300),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    /// Returns the delay before the restart following the specified number of failures (1-based).
+    pub fn delay(&self, failures: u32) -> Duration {
+        let exponent = failures.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_delay
+        }
+    }
+}
+
+/// Policy determining what happens when a task fails (returns an error or panics).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SupervisionPolicy {
+    /// Task failure stops the service. This is the default behavior for all tasks.
+    #[default]
+    Critical,
+    /// Task is restarted after a failure, with the delay between restarts determined by `backoff`.
+    /// If `max_failures` is set, the task gives up after this many failures; giving up does not stop the service.
+    Restart {
+        backoff: ExponentialBackoff,
+        max_failures: Option<NonZeroU32>,
+    },
+}
+
+impl SupervisionPolicy {
+    /// Restarts the task indefinitely with the default backoff.
+    pub fn restart() -> Self {
+        Self::Restart {
+            backoff: ExponentialBackoff::default(),
+            max_failures: None,
+        }
+    }
+
+    /// Restarts the task with the default backoff, giving up after `max_failures` failures.
+    pub fn restart_with_limit(max_failures: NonZeroU32) -> Self {
+        Self::Restart {
+            backoff: ExponentialBackoff::default(),
+            max_failures: Some(max_failures),
+        }
+    }
+}
+
+/// Event emitted by a [`Supervised`] task.
+#[derive(Debug)]
+pub enum SupervisionEvent<'a> {
+    /// The task was (re)started. `restarts` is the number of restarts performed so far.
+    Started { restarts: u32 },
+    /// The task has completed successfully.
+    Completed { restarts: u32 },
+    /// The task has failed and will be restarted after `delay`.
+    Failed {
+        restarts: u32,
+        error: &'a anyhow::Error,
+        delay: Duration,
+    },
+    /// The task has failed too many times and won't be restarted.
+    GaveUp {
+        failures: u32,
+        error: &'a anyhow::Error,
+    },
+}
+
+/// Observer of [`SupervisionEvent`]s, e.g. a health check updater.
+pub trait SupervisionObserver: 'static + Send + Sync + fmt::Debug {
+    /// Handles an event for the task with the specified ID.
+    fn observe(&self, task_id: &TaskId, event: SupervisionEvent<'_>);
+
+    /// Called when a oneshot task has exited without stopping the service, i.e. has either completed
+    /// or given up. By default, simply drops the observer.
+    fn on_oneshot_exit(self: Box<Self>) {}
+}
+
+/// Wrapper enforcing a [`SupervisionPolicy`] for a task. Restarting a task requires creating a fresh copy of it,
+/// hence the `Clone` requirement.
+///
+/// If a long-running task gives up, it waits for the stop request instead of exiting, so that the service
+/// is not stopped. A oneshot task that gives up exits successfully.
+#[derive(Debug)]
+pub struct Supervised<T> {
+    inner: T,
+    policy: SupervisionPolicy,
+    observer: Option<Box<dyn SupervisionObserver>>,
+}
+
+impl<T: Task + Clone> Supervised<T> {
+    pub fn new(inner: T, policy: SupervisionPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            observer: None,
+        }
+    }
+
+    /// Sets an observer for supervision events.
+    pub fn with_observer(mut self, observer: Box<dyn SupervisionObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    fn observe(&self, task_id: &TaskId, event: SupervisionEvent<'_>) {
+        if let Some(observer) = &self.observer {
+            observer.observe(task_id, event);
+        }
+    }
+
+    fn finish(mut self: Box<Self>) {
+        if let Some(observer) = self.observer.take() {
+            if self.inner.kind().is_oneshot() {
+                observer.on_oneshot_exit();
+            }
+        }
+    }
+
+    async fn run_inner(&self, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        // Spawn the task so that panics are caught and can be handled as failures.
+        let task = Box::new(self.inner.clone());
+        match tokio::spawn(task.run(stop_receiver)).await {
+            Ok(result) => result,
+            Err(err) => {
+                let panic_msg = try_extract_panic_message(err);
+                Err(anyhow::format_err!("task panicked: {panic_msg}"))
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Task + Clone> Task for Supervised<T> {
+    fn kind(&self) -> TaskKind {
+        self.inner.kind()
+    }
+
+    fn id(&self) -> TaskId {
+        self.inner.id()
+    }
+
+    async fn run(self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        let SupervisionPolicy::Restart {
+            backoff,
+            max_failures,
+        } = self.policy
+        else {
+            return Box::new(self.inner).run(stop_receiver).await;
+        };
+
+        let task_id = self.id();
+        let labels = task_id.to_string();
+        let mut failures = 0_u32;
+        loop {
+            self.observe(&task_id, SupervisionEvent::Started { restarts: failures });
+            let err = match self.run_inner(stop_receiver.clone()).await {
+                Ok(()) => {
+                    self.observe(&task_id, SupervisionEvent::Completed { restarts: failures });
+                    self.finish();
+                    return Ok(());
+                }
+                Err(err) => err,
+            };
+            if *stop_receiver.0.borrow() {
+                // The task has failed during shutdown; there's no point in restarting it.
+                return Err(err);
+            }
+            failures += 1;
+
+            if max_failures.is_some_and(|max| failures >= max.get()) {
+                tracing::error!(
+                    "Task {task_id} failed {failures} times, giving up: {err:?}. The task won't be restarted"
+                );
+                METRICS.gave_up[&labels].set(1);
+                self.observe(
+                    &task_id,
+                    SupervisionEvent::GaveUp {
+                        failures,
+                        error: &err,
+                    },
+                );
+                if !self.inner.kind().is_oneshot() {
+                    // Exiting a long-running task would stop the service.
+                    stop_receiver.0.changed().await.ok();
+                }
+                self.finish();
+                return Ok(());
+            }
+
+            let delay = backoff.delay(failures);
+            tracing::warn!("Task {task_id} failed: {err:?}. Restarting in {delay:?}");
+            self.observe(
+                &task_id,
+                SupervisionEvent::Failed {
+                    restarts: failures,
+                    error: &err,
+                    delay,
+                },
+            );
+            if tokio::time::timeout(delay, stop_receiver.0.changed())
+                .await
+                .is_ok()
+            {
+                // Stop request received while waiting for the restart.
+                return Ok(());
+            }
+            METRICS.restarts[&labels].inc();
+        }
+    }
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "node_framework_supervision")]
+struct SupervisionMetrics {
+    /// Number of restarts of supervised tasks after failures.
+    #[metrics(labels = ["task"])]
+    restarts: LabeledFamily<String, Counter>,
+    /// Set to 1 if a supervised task has given up after too many failures.
+    #[metrics(labels = ["task"])]
+    gave_up: LabeledFamily<String, Gauge<u64>>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<SupervisionMetrics> = vise::Global::new();
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    };
+
+    use assert_matches::assert_matches;
+    use tokio::sync::watch;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct FailingTask {
+        kind: TaskKind,
+        runs: Arc<AtomicU32>,
+        succeed_on_run: Option<u32>,
+    }
+
+    impl FailingTask {
+        fn new(kind: TaskKind, succeed_on_run: Option<u32>) -> Self {
+            Self {
+                kind,
+                runs: Arc::default(),
+                succeed_on_run,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Task for FailingTask {
+        fn kind(&self) -> TaskKind {
+            self.kind
+        }
+
+        fn id(&self) -> TaskId {
+            "failing".into()
+        }
+
+        async fn run(self: Box<Self>, _stop_receiver: StopReceiver) -> anyhow::Result<()> {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+            if self.succeed_on_run == Some(run) {
+                Ok(())
+            } else if run % 2 == 0 {
+                panic!("run #{run} panicked");
+            } else {
+                anyhow::bail!("run #{run} failed")
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct EventsRecorder(Mutex<Vec<String>>);
+
+    impl SupervisionObserver for Arc<EventsRecorder> {
+        fn observe(&self, _task_id: &TaskId, event: SupervisionEvent<'_>) {
+            let event = match event {
+                SupervisionEvent::Started { restarts } => format!("started:{restarts}"),
+                SupervisionEvent::Completed { restarts } => format!("completed:{restarts}"),
+                SupervisionEvent::Failed { restarts, .. } => format!("failed:{restarts}"),
+                SupervisionEvent::GaveUp { failures, .. } => format!("gave_up:{failures}"),
+            };
+            self.0.lock().unwrap().push(event);
+        }
+
+        fn on_oneshot_exit(self: Box<Self>) {
+            self.0.lock().unwrap().push("exited".to_owned());
+        }
+    }
+
+    const FAST_BACKOFF: ExponentialBackoff = ExponentialBackoff {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+        multiplier: 2.0,
+    };
+
+    #[test]
+    fn backoff_delays() {
+        let backoff = ExponentialBackoff::default();
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2), Duration::from_secs(2));
+        assert_eq!(backoff.delay(5), Duration::from_secs(16));
+        assert_eq!(backoff.delay(100), Duration::from_secs(300));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(300));
+    }
+
+    #[tokio::test]
+    async fn critical_task_failure_is_propagated() {
+        let task = FailingTask::new(TaskKind::Task, None);
+        let runs = task.runs.clone();
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        let supervised = Box::new(Supervised::new(task, SupervisionPolicy::Critical));
+        let err = supervised
+            .run(StopReceiver(stop_receiver))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("run #1 failed"), "{err}");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn task_is_restarted_until_success() {
+        let task = FailingTask::new(TaskKind::OneshotTask, Some(4));
+        let runs = task.runs.clone();
+        let recorder = Arc::new(EventsRecorder::default());
+        let policy = SupervisionPolicy::Restart {
+            backoff: FAST_BACKOFF,
+            max_failures: None,
+        };
+        let supervised = Supervised::new(task, policy).with_observer(Box::new(recorder.clone()));
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        Box::new(supervised)
+            .run(StopReceiver(stop_receiver))
+            .await
+            .unwrap();
+
+        assert_eq!(runs.load(Ordering::SeqCst), 4);
+        let events = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            events,
+            [
+                "started:0",
+                "failed:1",
+                "started:1",
+                "failed:2",
+                "started:2",
+                "failed:3",
+                "started:3",
+                "completed:3",
+                "exited"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn long_running_task_gives_up_without_stopping_service() {
+        let task = FailingTask::new(TaskKind::Task, None);
+        let runs = task.runs.clone();
+        let recorder = Arc::new(EventsRecorder::default());
+        let policy = SupervisionPolicy::Restart {
+            backoff: FAST_BACKOFF,
+            max_failures: Some(NonZeroU32::new(3).unwrap()),
+        };
+        let supervised = Supervised::new(task, policy).with_observer(Box::new(recorder.clone()));
+        let (stop_sender, stop_receiver) = watch::channel(false);
+        let supervised_handle = tokio::spawn(Box::new(supervised).run(StopReceiver(stop_receiver)));
+
+        while !recorder.0.lock().unwrap().contains(&"gave_up:3".to_owned()) {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert!(!supervised_handle.is_finished());
+
+        stop_sender.send_replace(true);
+        let result = supervised_handle.await.unwrap();
+        assert_matches!(result, Ok(()));
+    }
+}
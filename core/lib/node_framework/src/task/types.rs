@@ -3,9 +3,12 @@ use std::{
     ops::Deref,
 };
 
+use serde::Serialize;
+
 /// Task kind.
 /// See [`Task`](super::Task) documentation for more details.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum TaskKind {
     Task,
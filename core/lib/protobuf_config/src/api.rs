@@ -7,7 +7,7 @@ use zksync_protobuf::{
     required,
 };
 
-use crate::{parse_h160, proto::api as proto};
+use crate::{parse_h160, proto::api as proto, read_optional_repr};
 
 impl ProtoRepr for proto::Api {
     type Type = ApiConfig;
@@ -17,6 +17,7 @@ impl ProtoRepr for proto::Api {
             prometheus: read_required_repr(&self.prometheus).context("prometheus")?,
             healthcheck: read_required_repr(&self.healthcheck).context("healthcheck")?,
             merkle_tree: read_required_repr(&self.merkle_tree).context("merkle_tree")?,
+            admin: read_optional_repr(&self.admin),
         })
     }
 
@@ -26,6 +27,7 @@ impl ProtoRepr for proto::Api {
             prometheus: Some(ProtoRepr::build(&this.prometheus)),
             healthcheck: Some(ProtoRepr::build(&this.healthcheck)),
             merkle_tree: Some(ProtoRepr::build(&this.merkle_tree)),
+            admin: this.admin.as_ref().map(ProtoRepr::build),
         }
     }
 }
@@ -255,3 +257,19 @@ impl ProtoRepr for proto::MerkleTreeApi {
         }
     }
 }
+
+impl ProtoRepr for proto::AdminApi {
+    type Type = api::AdminApiConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            port: required(&self.port)
+                .and_then(|p| Ok((*p).try_into()?))
+                .context("port")?,
+        })
+    }
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            port: Some(this.port.into()),
+        }
+    }
+}
@@ -7,7 +7,8 @@ use zksync_config::configs::{
         avail::{AvailClientConfig, AvailConfig, AvailDefaultConfig, AvailGasRelayConfig},
         celestia::CelestiaConfig,
         eigen::EigenConfig,
-        DAClientConfig::{Avail, Celestia, Eigen, NoDA, ObjectStore},
+        sidecar::SidecarConfig,
+        DAClientConfig::{Avail, Celestia, Eigen, NoDA, ObjectStore, Sidecar},
     },
 };
 use zksync_protobuf::{required, ProtoRepr};
@@ -107,6 +108,10 @@ impl ProtoRepr for proto::DataAvailabilityClient {
             proto::data_availability_client::Config::ObjectStore(conf) => {
                 ObjectStore(object_store_proto::ObjectStore::read(conf)?)
             }
+            proto::data_availability_client::Config::Sidecar(conf) => Sidecar(SidecarConfig {
+                server_url: required(&conf.server_url).context("server_url")?.clone(),
+                timeout_ms: conf.timeout_ms,
+            }),
             proto::data_availability_client::Config::NoDa(_) => NoDA,
         };
 
@@ -180,6 +185,12 @@ impl ProtoRepr for proto::DataAvailabilityClient {
             ObjectStore(config) => proto::data_availability_client::Config::ObjectStore(
                 object_store_proto::ObjectStore::build(config),
             ),
+            Sidecar(config) => {
+                proto::data_availability_client::Config::Sidecar(proto::SidecarConfig {
+                    server_url: Some(config.server_url.clone()),
+                    timeout_ms: config.timeout_ms,
+                })
+            }
             NoDA => proto::data_availability_client::Config::NoDa(proto::NoDaConfig {}),
         };
 
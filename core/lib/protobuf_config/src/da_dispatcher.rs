@@ -14,6 +14,7 @@ impl ProtoRepr for proto::DataAvailabilityDispatcher {
             use_dummy_inclusion_data: self.use_dummy_inclusion_data,
             inclusion_verification_transition_enabled: self
                 .inclusion_verification_transition_enabled,
+            use_blob_chunking: self.use_blob_chunking,
         })
     }
 
@@ -25,6 +26,7 @@ impl ProtoRepr for proto::DataAvailabilityDispatcher {
             use_dummy_inclusion_data: this.use_dummy_inclusion_data,
             inclusion_verification_transition_enabled: this
                 .inclusion_verification_transition_enabled,
+            use_blob_chunking: this.use_blob_chunking,
         }
     }
 }
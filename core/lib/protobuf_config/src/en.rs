@@ -27,6 +27,17 @@ impl ProtoRepr for proto::ExternalNode {
             main_node_rate_limit_rps: self
                 .main_node_rate_limit_rps
                 .and_then(|a| NonZeroUsize::new(a as usize)),
+            fallback_upstream_urls: self
+                .fallback_upstream_urls
+                .iter()
+                .enumerate()
+                .map(|(i, url)| {
+                    SensitiveUrl::from_str(url)
+                        .with_context(|| format!("fallback_upstream_urls[{i}]"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            upstream_max_lag_blocks: self.upstream_max_lag_blocks,
+            verify_batch_statuses: self.verify_batch_statuses,
             bridge_addresses_refresh_interval_sec: self
                 .bridge_addresses_refresh_interval_sec
                 .and_then(NonZeroU64::new),
@@ -40,6 +51,13 @@ impl ProtoRepr for proto::ExternalNode {
             l1_chain_id: Some(this.l1_chain_id.0),
             l2_chain_id: Some(this.l2_chain_id.as_u64()),
             main_node_rate_limit_rps: this.main_node_rate_limit_rps.map(|a| a.get() as u64),
+            fallback_upstream_urls: this
+                .fallback_upstream_urls
+                .iter()
+                .map(|url| url.expose_str().to_owned())
+                .collect(),
+            upstream_max_lag_blocks: this.upstream_max_lag_blocks,
+            verify_batch_statuses: this.verify_batch_statuses,
             bridge_addresses_refresh_interval_sec: this
                 .bridge_addresses_refresh_interval_sec
                 .map(|a| a.get()),
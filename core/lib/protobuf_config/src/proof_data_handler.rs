@@ -30,6 +30,7 @@ impl ProtoRepr for proto::ProofDataHandler {
             fetch_zero_chain_id_proofs: self.fetch_zero_chain_id_proofs.unwrap_or_else(
                 configs::ProofDataHandlerConfig::default_fetch_zero_chain_id_proofs,
             ),
+            verification_keys_path: self.verification_keys_path.clone(),
         })
     }
 
@@ -43,6 +44,7 @@ impl ProtoRepr for proto::ProofDataHandler {
                 this.proof_gen_data_submit_interval_in_secs.into(),
             ),
             fetch_zero_chain_id_proofs: Some(this.fetch_zero_chain_id_proofs),
+            verification_keys_path: this.verification_keys_path.clone(),
         }
     }
 }
@@ -1,6 +1,9 @@
 use anyhow::Context as _;
-use zksync_basic_types::basic_fri_types::CircuitIdRoundTuple;
-use zksync_config::configs::{self, fri_prover_gateway::ApiMode};
+use zksync_basic_types::{basic_fri_types::CircuitIdRoundTuple, L2ChainId};
+use zksync_config::configs::{
+    self,
+    fri_prover_gateway::{ApiMode, ProverGatewayChainConfig},
+};
 use zksync_protobuf::{repr::ProtoRepr, required};
 
 use crate::proto::prover::{self as proto};
@@ -97,6 +100,13 @@ impl ProtoRepr for proto::ProverGateway {
             prometheus_push_interval_ms: self.prometheus_push_interval_ms,
             api_mode,
             port: self.port.map(|x| x as u16),
+            chains: self
+                .chains
+                .iter()
+                .enumerate()
+                .map(|(i, chain)| chain.read().context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("chains")?,
         })
     }
 
@@ -114,6 +124,28 @@ impl ProtoRepr for proto::ProverGateway {
             prometheus_push_interval_ms: this.prometheus_push_interval_ms,
             api_mode: Some(api_mode),
             port: this.port.map(|x| x as u32),
+            chains: this.chains.iter().map(ProtoRepr::build).collect(),
+        }
+    }
+}
+
+impl ProtoRepr for proto::ProverGatewayChain {
+    type Type = ProverGatewayChainConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            chain_id: required(&self.chain_id)
+                .and_then(|x| L2ChainId::try_from(*x).map_err(|a| anyhow::anyhow!(a)))
+                .context("chain_id")?,
+            api_url: required(&self.api_url).context("api_url")?.clone(),
+            priority: self.priority.unwrap_or_default(),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            chain_id: Some(this.chain_id.inner()),
+            api_url: Some(this.api_url.clone()),
+            priority: Some(this.priority),
         }
     }
 }
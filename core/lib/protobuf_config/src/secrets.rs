@@ -9,7 +9,7 @@ use zksync_basic_types::{
 use zksync_config::configs::{
     consensus::{ConsensusSecrets, NodeSecretKey, ValidatorSecretKey},
     da_client::{avail::AvailSecrets, celestia::CelestiaSecrets, eigen::EigenSecrets},
-    secrets::{DataAvailabilitySecrets, Secrets},
+    secrets::{AdminApiSecrets, DataAvailabilitySecrets, Secrets},
     ContractVerifierSecrets, DatabaseSecrets, L1Secrets,
 };
 use zksync_protobuf::{required, ProtoRepr};
@@ -29,6 +29,7 @@ impl ProtoRepr for proto::Secrets {
             l1: read_optional_repr(&self.l1),
             data_availability: read_optional_repr(&self.da),
             contract_verifier: read_optional_repr(&self.contract_verifier),
+            admin_api: read_optional_repr(&self.admin_api),
         })
     }
 
@@ -39,6 +40,7 @@ impl ProtoRepr for proto::Secrets {
             consensus: this.consensus.as_ref().map(ProtoRepr::build),
             da: this.data_availability.as_ref().map(ProtoRepr::build),
             contract_verifier: this.contract_verifier.as_ref().map(ProtoRepr::build),
+            admin_api: this.admin_api.as_ref().map(ProtoRepr::build),
         }
     }
 }
@@ -262,3 +264,19 @@ impl ProtoRepr for proto::ContractVerifierSecrets {
         Self { etherscan_api_key }
     }
 }
+
+impl ProtoRepr for proto::AdminApiSecrets {
+    type Type = AdminApiSecrets;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(AdminApiSecrets {
+            auth_token: APIKey::from(required(&self.auth_token).context("auth_token")?.as_str()),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            auth_token: Some(this.auth_token.0.expose_secret().to_string()),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU64, NonZeroUsize};
 
 use zksync_basic_types::L1BatchNumber;
 use zksync_config::configs::{
@@ -62,6 +62,26 @@ impl ProtoRepr for proto::SnapshotRecovery {
                 .as_ref()
                 .and_then(|experimental| experimental.drop_storage_key_preimages)
                 .unwrap_or_default(),
+            apply_delta_snapshots: self
+                .experimental
+                .as_ref()
+                .and_then(|experimental| experimental.apply_delta_snapshots)
+                .unwrap_or_default(),
+            l1_recovery_enabled: self
+                .experimental
+                .as_ref()
+                .and_then(|experimental| experimental.l1_recovery_enabled)
+                .unwrap_or_default(),
+            l1_recovery_from_block: self
+                .experimental
+                .as_ref()
+                .and_then(|experimental| experimental.l1_recovery_from_block)
+                .unwrap_or_default(),
+            l1_recovery_block_range: self
+                .experimental
+                .as_ref()
+                .and_then(|experimental| experimental.l1_recovery_block_range)
+                .and_then(NonZeroU64::new),
         })
     }
 
@@ -79,6 +99,10 @@ impl ProtoRepr for proto::SnapshotRecovery {
                         .parallel_persistence_buffer
                         .map(|a| a.get() as u64),
                     drop_storage_key_preimages: Some(this.drop_storage_key_preimages),
+                    apply_delta_snapshots: Some(this.apply_delta_snapshots),
+                    l1_recovery_enabled: Some(this.l1_recovery_enabled),
+                    l1_recovery_from_block: Some(this.l1_recovery_from_block),
+                    l1_recovery_block_range: this.l1_recovery_block_range.map(NonZeroU64::get),
                 }),
             )
         };
@@ -1,9 +1,9 @@
 use anyhow::Context as _;
 use zksync_config::configs;
 use zksync_protobuf::{repr::ProtoRepr, required};
-use zksync_types::L1BatchNumber;
+use zksync_types::{tee_types::TeeType, L1BatchNumber};
 
-use crate::proto::prover as proto;
+use crate::{proto::prover as proto, read_optional_repr};
 
 impl ProtoRepr for proto::TeeProofDataHandler {
     type Type = configs::TeeProofDataHandlerConfig;
@@ -27,7 +27,10 @@ impl ProtoRepr for proto::TeeProofDataHandler {
                 .map(|x| x as u16)
                 .unwrap_or_else(
                     configs::TeeProofDataHandlerConfig::default_tee_batch_permanently_ignored_timeout_in_hours,
-                ), })
+                ),
+            attestation_verification: read_optional_repr(&self.attestation_verification),
+            quorum: read_optional_repr(&self.quorum),
+        })
     }
 
     fn build(this: &Self::Type) -> Self {
@@ -38,6 +41,69 @@ impl ProtoRepr for proto::TeeProofDataHandler {
             batch_permanently_ignored_timeout_in_hours: Some(
                 this.batch_permanently_ignored_timeout_in_hours.into(),
             ),
+            attestation_verification: this.attestation_verification.as_ref().map(ProtoRepr::build),
+            quorum: this.quorum.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::TeeAttestationVerification {
+    type Type = configs::TeeAttestationVerificationConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            collateral_path: required(&self.collateral_path)
+                .context("collateral_path")?
+                .clone(),
+            sgx_mrenclaves: self.sgx_mrenclaves.clone(),
+            sgx_mrsigners: self.sgx_mrsigners.clone(),
+            tdx_measurements: self.tdx_measurements.clone(),
+            allowed_tcb_levels: self.allowed_tcb_levels.clone(),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            collateral_path: Some(this.collateral_path.clone()),
+            sgx_mrenclaves: this.sgx_mrenclaves.clone(),
+            sgx_mrsigners: this.sgx_mrsigners.clone(),
+            tdx_measurements: this.tdx_measurements.clone(),
+            allowed_tcb_levels: this.allowed_tcb_levels.clone(),
+        }
+    }
+}
+
+impl ProtoRepr for proto::TeeQuorum {
+    type Type = configs::TeeQuorumConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        let required_tee_types = self
+            .required_tee_types
+            .iter()
+            .map(|tee_type| tee_type.parse::<TeeType>().map_err(anyhow::Error::msg))
+            .collect::<anyhow::Result<_>>()
+            .context("required_tee_types")?;
+        Ok(Self::Type {
+            required_tee_types,
+            min_distinct_keys: self
+                .min_distinct_keys
+                .map(|x| x.try_into())
+                .transpose()
+                .context("min_distinct_keys")?
+                .unwrap_or_else(Self::Type::default_min_distinct_keys),
+            poll_interval_ms: self
+                .poll_interval_ms
+                .unwrap_or_else(Self::Type::default_poll_interval_ms),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            required_tee_types: this
+                .required_tee_types
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            min_distinct_keys: Some(this.min_distinct_keys as u64),
+            poll_interval_ms: Some(this.poll_interval_ms),
         }
     }
 }
@@ -59,5 +59,14 @@ pub struct PollGeneratedProofsResponse {
     pub proof: JsonL1BatchProofForL1,
 }
 
+/// Notifies the prover gateway that the proof for the batch didn't pass verification and must be generated again.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RejectProofRequest {
+    pub l1_batch_id: L1BatchId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectProofResponse;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VerifyProofRequest(pub Box<JsonL1BatchProofForL1>);
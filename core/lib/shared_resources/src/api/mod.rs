@@ -2,9 +2,9 @@
 
 use std::sync::Arc;
 
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 use zksync_node_framework::Resource;
-use zksync_types::{api, Address};
+use zksync_types::{api, Address, L1BatchNumber};
 
 pub use self::sync_state::{SyncState, SyncStateData};
 
@@ -46,3 +46,54 @@ impl Resource for BridgeAddressesHandle {
         "api/bridge_addresses".into()
     }
 }
+
+/// Latest L1 batches independently verified by the node to be committed, proven and executed on the settlement layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SettlementLayerFinality {
+    pub last_committed_batch: L1BatchNumber,
+    pub last_proven_batch: L1BatchNumber,
+    pub last_executed_batch: L1BatchNumber,
+}
+
+impl SettlementLayerFinality {
+    /// Returns the verified stage of the specified batch, or `None` if the batch is not committed.
+    pub fn batch_stage(&self, number: L1BatchNumber) -> Option<api::VerifiedL1BatchStage> {
+        if number <= self.last_executed_batch {
+            Some(api::VerifiedL1BatchStage::Executed)
+        } else if number <= self.last_proven_batch {
+            Some(api::VerifiedL1BatchStage::Proven)
+        } else if number <= self.last_committed_batch {
+            Some(api::VerifiedL1BatchStage::Committed)
+        } else {
+            None
+        }
+    }
+}
+
+/// Shared handle to the [`SettlementLayerFinality`] updated by the component verifying batch statuses
+/// on the settlement layer.
+#[derive(Debug, Clone)]
+pub struct SettlementLayerFinalityHandle(Arc<watch::Sender<Option<SettlementLayerFinality>>>);
+
+impl Default for SettlementLayerFinalityHandle {
+    fn default() -> Self {
+        Self(Arc::new(watch::channel(None).0))
+    }
+}
+
+impl SettlementLayerFinalityHandle {
+    pub fn update(&self, finality: SettlementLayerFinality) {
+        self.0.send_replace(Some(finality));
+    }
+
+    /// Returns the latest verified finality, or `None` if it wasn't verified yet.
+    pub fn get(&self) -> Option<SettlementLayerFinality> {
+        *self.0.borrow()
+    }
+}
+
+impl Resource for SettlementLayerFinalityHandle {
+    fn name() -> String {
+        "api/settlement_layer_finality".into()
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    ops,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::watch;
+use zksync_config::configs::dynamic::{ConfigChange, DynamicConfig, DynamicConfigOverrides};
+use zksync_node_framework::Resource;
+
+/// Layers the effective [`DynamicConfig`] is assembled from.
+#[derive(Debug, Clone)]
+struct ConfigLayers {
+    /// Config loaded from the config file (possibly, modified by [`DynamicConfigHandle::modify()`]).
+    base: DynamicConfig,
+    overrides: DynamicConfigOverrides,
+}
+
+impl ConfigLayers {
+    fn effective_config(&self) -> anyhow::Result<DynamicConfig> {
+        let mut config = self.base.clone();
+        self.overrides.apply_to(&mut config)?;
+        Ok(config)
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    layers: Mutex<ConfigLayers>,
+    sender: watch::Sender<DynamicConfig>,
+}
+
+/// Shared handle to the [`DynamicConfig`], i.e. the part of the node configuration that can be updated
+/// without restarting the node.
+///
+/// Components that support dynamic config updates should [subscribe](Self::subscribe()) to the config;
+/// updates are delivered as a whole via a watch channel. Updates are validated before being applied;
+/// an invalid update is rejected atomically, i.e., no part of it is applied.
+///
+/// The effective config consists of the base config (e.g., loaded from the config file) and
+/// [runtime overrides](DynamicConfigOverrides) set by the node operator. Overrides take precedence and are retained
+/// when the base config is updated.
+#[derive(Debug, Clone)]
+pub struct DynamicConfigHandle(Arc<Inner>);
+
+impl Resource for DynamicConfigHandle {
+    fn name() -> String {
+        "common/dynamic_config".into()
+    }
+}
+
+impl DynamicConfigHandle {
+    pub fn new(initial: DynamicConfig) -> Self {
+        let layers = ConfigLayers {
+            base: initial.clone(),
+            overrides: DynamicConfigOverrides::default(),
+        };
+        Self(Arc::new(Inner {
+            layers: Mutex::new(layers),
+            sender: watch::channel(initial).0,
+        }))
+    }
+
+    /// Returns the effective config.
+    pub fn borrow(&self) -> impl ops::Deref<Target = DynamicConfig> + '_ {
+        self.0.sender.borrow()
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<DynamicConfig> {
+        self.0.sender.subscribe()
+    }
+
+    /// Validates and applies the base config update. `source` describes the update origin (e.g., a config file path)
+    /// and is used for auditing. Returns the list of applied changes to the effective config.
+    pub fn update(&self, new: DynamicConfig, source: &str) -> anyhow::Result<Vec<ConfigChange>> {
+        self.modify(source, |config| {
+            *config = new;
+            Ok(())
+        })
+    }
+
+    /// Atomically modifies the base config using the provided closure, validates the result and applies it.
+    /// Unlike [`Self::update()`] with a config obtained via [`Self::borrow()`], concurrent modifications are never lost.
+    pub fn modify(
+        &self,
+        source: &str,
+        modify: impl FnOnce(&mut DynamicConfig) -> anyhow::Result<()>,
+    ) -> anyhow::Result<Vec<ConfigChange>> {
+        self.modify_layers(source, |layers| modify(&mut layers.base))
+    }
+
+    /// Atomically modifies runtime overrides. Unlike base config modifications, overrides are not reset
+    /// on base config updates (e.g., when the config file is reloaded).
+    pub fn modify_overrides(
+        &self,
+        source: &str,
+        modify: impl FnOnce(&mut DynamicConfigOverrides),
+    ) -> anyhow::Result<Vec<ConfigChange>> {
+        self.modify_layers(source, |layers| {
+            modify(&mut layers.overrides);
+            Ok(())
+        })
+    }
+
+    fn modify_layers(
+        &self,
+        source: &str,
+        modify: impl FnOnce(&mut ConfigLayers) -> anyhow::Result<()>,
+    ) -> anyhow::Result<Vec<ConfigChange>> {
+        // Holding the lock serializes updates, so validation and application are atomic w.r.t. other updates.
+        let mut layers = self.0.layers.lock().unwrap();
+        let mut new_layers = layers.clone();
+        let result = modify(&mut new_layers).and_then(|()| {
+            let new = new_layers.effective_config()?;
+            new.validate()?;
+            let changes = self.0.sender.borrow().changes(&new)?;
+            Ok((new, changes))
+        });
+        let (new, changes) = match result {
+            Ok(output) => output,
+            Err(err) => {
+                tracing::warn!("Rejected dynamic config update from {source}: {err:#}");
+                return Err(err);
+            }
+        };
+        // Layers must be updated even if the effective config doesn't change (e.g., if the changed params
+        // are overridden).
+        *layers = new_layers;
+
+        if changes.is_empty() {
+            tracing::debug!("Dynamic config update from {source} contains no changes");
+            return Ok(changes);
+        }
+        for change in &changes {
+            tracing::info!("Dynamic config updated from {source}: {change}");
+        }
+        self.0.sender.send_replace(new);
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::configs::dynamic::DynamicEthSenderConfig;
+
+    use super::*;
+
+    #[test]
+    fn updating_dynamic_config() {
+        let config = DynamicConfig {
+            eth_sender: Some(DynamicEthSenderConfig {
+                max_acceptable_priority_fee_in_gwei: 100,
+                tx_aggregation_paused: false,
+            }),
+            ..DynamicConfig::default()
+        };
+        let handle = DynamicConfigHandle::new(config.clone());
+        let mut receiver = handle.subscribe();
+
+        let mut new_config = config.clone();
+        new_config
+            .eth_sender
+            .as_mut()
+            .unwrap()
+            .tx_aggregation_paused = true;
+        let changes = handle.update(new_config.clone(), "test").unwrap();
+        assert_eq!(changes.len(), 1);
+        assert!(receiver.has_changed().unwrap());
+        assert_eq!(*receiver.borrow_and_update(), new_config);
+
+        // Invalid update must be rejected as a whole.
+        let mut invalid_config = new_config.clone();
+        let eth_sender = invalid_config.eth_sender.as_mut().unwrap();
+        eth_sender.tx_aggregation_paused = false;
+        eth_sender.max_acceptable_priority_fee_in_gwei = 0;
+        handle.update(invalid_config, "test").unwrap_err();
+        assert!(!receiver.has_changed().unwrap());
+        assert_eq!(*handle.borrow(), new_config);
+    }
+
+    #[test]
+    fn concurrent_modifications_are_not_lost() {
+        const THREAD_COUNT: u64 = 4;
+        const MODIFICATIONS_PER_THREAD: u64 = 100;
+
+        let handle = DynamicConfigHandle::new(DynamicConfig {
+            eth_sender: Some(DynamicEthSenderConfig {
+                max_acceptable_priority_fee_in_gwei: 1,
+                tx_aggregation_paused: false,
+            }),
+            ..DynamicConfig::default()
+        });
+
+        std::thread::scope(|scope| {
+            for _ in 0..THREAD_COUNT {
+                scope.spawn(|| {
+                    for _ in 0..MODIFICATIONS_PER_THREAD {
+                        handle
+                            .modify("test", |config| {
+                                let eth_sender = config.eth_sender.as_mut().unwrap();
+                                eth_sender.max_acceptable_priority_fee_in_gwei += 1;
+                                Ok(())
+                            })
+                            .unwrap();
+                    }
+                });
+            }
+        });
+
+        let fee = handle
+            .borrow()
+            .eth_sender
+            .as_ref()
+            .unwrap()
+            .max_acceptable_priority_fee_in_gwei;
+        assert_eq!(fee, 1 + THREAD_COUNT * MODIFICATIONS_PER_THREAD);
+    }
+
+    #[test]
+    fn overrides_are_retained_on_base_config_update() {
+        let config = DynamicConfig {
+            eth_sender: Some(DynamicEthSenderConfig {
+                max_acceptable_priority_fee_in_gwei: 100,
+                tx_aggregation_paused: false,
+            }),
+            ..DynamicConfig::default()
+        };
+        let handle = DynamicConfigHandle::new(config.clone());
+        let changes = handle
+            .modify_overrides("test", |overrides| {
+                overrides.tx_aggregation_paused = Some(true);
+            })
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert!(
+            handle
+                .borrow()
+                .eth_sender
+                .as_ref()
+                .unwrap()
+                .tx_aggregation_paused
+        );
+
+        // Simulate reloading the unchanged config file, and then the file with a changed param.
+        let changes = handle.update(config.clone(), "test").unwrap();
+        assert_eq!(changes, []);
+        let mut new_config = config.clone();
+        new_config
+            .eth_sender
+            .as_mut()
+            .unwrap()
+            .max_acceptable_priority_fee_in_gwei = 200;
+        let changes = handle.update(new_config, "test").unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].path,
+            "eth_sender.max_acceptable_priority_fee_in_gwei"
+        );
+
+        let eth_sender = handle.borrow().eth_sender.clone().unwrap();
+        assert!(eth_sender.tx_aggregation_paused);
+        assert_eq!(eth_sender.max_acceptable_priority_fee_in_gwei, 200);
+
+        handle
+            .modify_overrides("test", |overrides| {
+                overrides.tx_aggregation_paused = None;
+            })
+            .unwrap();
+        let eth_sender = handle.borrow().eth_sender.clone().unwrap();
+        assert!(!eth_sender.tx_aggregation_paused);
+        assert_eq!(eth_sender.max_acceptable_priority_fee_in_gwei, 200);
+    }
+}
@@ -10,13 +10,15 @@ use serde::Serialize;
 use tokio::sync::{watch, Semaphore};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError, SqlxError};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_merkle_tree::{recovery::MerkleTreeRecovery, RocksDBWrapper, TreeEntry};
 use zksync_object_store::{ObjectStore, ObjectStoreError};
 use zksync_types::{
     api,
     bytecode::{BytecodeHash, BytecodeMarker},
     snapshots::{
-        SnapshotFactoryDependencies, SnapshotHeader, SnapshotRecoveryStatus, SnapshotStorageLog,
-        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey, SnapshotVersion,
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotHeader,
+        SnapshotRecoveryStatus, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsStorageKey, SnapshotVersion,
     },
     tokens::TokenInfo,
     L1BatchNumber, L2BlockNumber, OrStopped, StorageKey, H256,
@@ -34,6 +36,10 @@ mod metrics;
 #[cfg(test)]
 mod tests;
 
+/// Number of hashed key ranges used to load the storage state when checking the tree root hash after a delta snapshot.
+/// Bounds the number of tree entries held in memory at once.
+const ROOT_HASH_KEY_CHUNK_COUNT: u64 = 256;
+
 #[derive(Debug, Serialize)]
 struct SnapshotsApplierHealthDetails {
     snapshot_l2_block: L2BlockNumber,
@@ -136,6 +142,11 @@ pub trait SnapshotsApplierMainNodeClient: fmt::Debug + Send + Sync {
         l1_batch_number: L1BatchNumber,
     ) -> EnrichedClientResult<Option<SnapshotHeader>>;
 
+    /// Fetches L1 batch numbers of all complete delta snapshots in no particular order.
+    async fn fetch_delta_snapshot_l1_batch_numbers(
+        &self,
+    ) -> EnrichedClientResult<Vec<L1BatchNumber>>;
+
     async fn fetch_tokens(
         &self,
         at_l2_block: L2BlockNumber,
@@ -184,6 +195,16 @@ impl SnapshotsApplierMainNodeClient for Box<DynClient<L2>> {
             .await
     }
 
+    async fn fetch_delta_snapshot_l1_batch_numbers(
+        &self,
+    ) -> EnrichedClientResult<Vec<L1BatchNumber>> {
+        let snapshots = self
+            .get_all_snapshots()
+            .rpc_context("get_all_snapshots")
+            .await?;
+        Ok(snapshots.delta_snapshots_l1_batch_numbers)
+    }
+
     async fn fetch_tokens(
         &self,
         at_l2_block: L2BlockNumber,
@@ -253,6 +274,7 @@ pub struct SnapshotApplierTaskStats {
 pub struct SnapshotsApplierTask {
     snapshot_l1_batch: Option<L1BatchNumber>,
     drop_storage_key_preimages: bool,
+    apply_delta_snapshots: bool,
     config: SnapshotsApplierConfig,
     health_updater: HealthUpdater,
     connection_pool: ConnectionPool<Core>,
@@ -270,6 +292,7 @@ impl SnapshotsApplierTask {
         Self {
             snapshot_l1_batch: None,
             drop_storage_key_preimages: false,
+            apply_delta_snapshots: false,
             config,
             health_updater: ReactiveHealthCheck::new("snapshot_recovery").1,
             connection_pool,
@@ -328,6 +351,14 @@ impl SnapshotsApplierTask {
         self.drop_storage_key_preimages = true;
     }
 
+    /// Enables applying a chain of delta snapshots published by the main node on top of the recovered snapshot.
+    /// Delta snapshots are applied one at a time; after each step, storage logs are checked for consistency, and the tree root hash
+    /// of the resulting state is computed and compared with the root hash of the delta snapshot L1 batch. Only then the recovery
+    /// status is advanced to the delta snapshot L1 batch.
+    pub fn apply_delta_snapshots(&mut self) {
+        self.apply_delta_snapshots = true;
+    }
+
     /// Returns the health check for snapshot recovery.
     pub fn health_check(&self) -> ReactiveHealthCheck {
         self.health_updater.subscribe()
@@ -495,8 +526,25 @@ impl SnapshotRecoveryStrategy {
             version = snapshot.version,
             chunk_count = snapshot.storage_logs_chunks.len()
         );
+        if let Some(base_l1_batch_number) = snapshot.base_l1_batch_number {
+            let err = anyhow::anyhow!(
+                "snapshot for L1 batch #{l1_batch_number} is a delta snapshot on top of L1 batch #{base_l1_batch_number}; \
+                 it cannot be recovered from on its own"
+            );
+            return Err(err.into());
+        }
         let snapshot_version = Self::check_snapshot_version(snapshot.version)?;
+        let status = Self::recovery_status_for_snapshot(main_node_client, &snapshot).await?;
+        Ok((status, snapshot_version))
+    }
 
+    /// Creates a recovery status for the specified snapshot using L1 batch and L2 block data from the main node.
+    async fn recovery_status_for_snapshot(
+        main_node_client: &dyn SnapshotsApplierMainNodeClient,
+        snapshot: &SnapshotHeader,
+    ) -> Result<SnapshotRecoveryStatus, SnapshotsApplierError> {
+        let l1_batch_number = snapshot.l1_batch_number;
+        let l2_block_number = snapshot.l2_block_number;
         let l1_batch = main_node_client
             .fetch_l1_batch_details(l1_batch_number)
             .await?
@@ -533,7 +581,7 @@ impl SnapshotRecoveryStrategy {
             protocol_version,
             storage_logs_chunks_processed: vec![false; snapshot.storage_logs_chunks.len()],
         };
-        Ok((status, snapshot_version))
+        Ok(status)
     }
 
     fn check_snapshot_version(raw_version: u16) -> anyhow::Result<SnapshotVersion> {
@@ -673,6 +721,9 @@ impl<'a> SnapshotsApplierOrStatus<'a> {
         {
             *is_chunk_processed = true;
         }
+        if task.apply_delta_snapshots {
+            applier.recover_delta_snapshots(stop_receiver).await?;
+        }
 
         applier.recover_tokens().await?;
         applier.tokens_recovered = true;
@@ -794,7 +845,7 @@ impl<'a> SnapshotsApplier<'a> {
     }
 
     async fn recover_factory_deps(
-        &mut self,
+        &self,
         storage: &mut Connection<'_, Core>,
     ) -> Result<(), SnapshotsApplierError> {
         let latency = METRICS.initial_stage_duration[&InitialStage::ApplyFactoryDeps].start();
@@ -1015,6 +1066,13 @@ impl<'a> SnapshotsApplier<'a> {
             .connection_pool
             .connection_tagged("snapshots_applier")
             .await?;
+        self.check_storage_logs_consistency(&mut storage).await
+    }
+
+    async fn check_storage_logs_consistency(
+        &self,
+        storage: &mut Connection<'_, Core>,
+    ) -> Result<(), SnapshotsApplierError> {
         // This DB query is slow, but this is fine for verification purposes.
         let total_log_count = storage
             .storage_logs_dal()
@@ -1038,6 +1096,233 @@ impl<'a> SnapshotsApplier<'a> {
         Ok(())
     }
 
+    /// Applies the chain of delta snapshots on top of the currently applied snapshot. Needs to run after
+    /// recovering storage logs.
+    async fn recover_delta_snapshots(
+        &mut self,
+        stop_receiver: &mut watch::Receiver<bool>,
+    ) -> StopResult<()> {
+        let mut delta_l1_batch_numbers = self
+            .main_node_client
+            .fetch_delta_snapshot_l1_batch_numbers()
+            .await
+            .map_err(SnapshotsApplierError::from)?;
+        delta_l1_batch_numbers.sort_unstable();
+        tracing::info!(
+            "Main node has {} delta snapshot(s): {delta_l1_batch_numbers:?}",
+            delta_l1_batch_numbers.len()
+        );
+
+        for l1_batch_number in delta_l1_batch_numbers {
+            if l1_batch_number <= self.applied_snapshot_status.l1_batch_number {
+                continue;
+            }
+            if *stop_receiver.borrow() {
+                return Err(OrStopped::Stopped);
+            }
+
+            let snapshot = self
+                .main_node_client
+                .fetch_snapshot(l1_batch_number)
+                .await
+                .map_err(SnapshotsApplierError::from)?
+                .with_context(|| {
+                    format!("delta snapshot for L1 batch #{l1_batch_number} is not present on main node")
+                })
+                .map_err(SnapshotsApplierError::Fatal)?;
+            // Delta snapshots on top of other snapshots are not a part of the chain; skip them.
+            if snapshot.base_l1_batch_number != Some(self.applied_snapshot_status.l1_batch_number) {
+                tracing::info!(
+                    "Skipping snapshot for L1 batch #{l1_batch_number} with base {:?}",
+                    snapshot.base_l1_batch_number
+                );
+                continue;
+            }
+            self.apply_delta_snapshot(&snapshot).await?;
+            self.update_health();
+        }
+        Ok(())
+    }
+
+    async fn apply_delta_snapshot(
+        &mut self,
+        snapshot: &SnapshotHeader,
+    ) -> Result<(), SnapshotsApplierError> {
+        let l1_batch_number = snapshot.l1_batch_number;
+        let latency = METRICS.delta_snapshot_duration.start();
+        let version = SnapshotRecoveryStrategy::check_snapshot_version(snapshot.version)?;
+        if version != SnapshotVersion::Version1 {
+            let err = anyhow::anyhow!(
+                "delta snapshot for L1 batch #{l1_batch_number} has unsupported version {version:?}"
+            );
+            return Err(err.into());
+        }
+        let mut new_status =
+            SnapshotRecoveryStrategy::recovery_status_for_snapshot(self.main_node_client, snapshot)
+                .await?;
+        tracing::info!(
+            "Applying delta snapshot for L1 batch #{l1_batch_number}, L2 block #{} on top of L1 batch #{}",
+            new_status.l2_block_number,
+            self.applied_snapshot_status.l1_batch_number
+        );
+
+        let base_l1_batch_number = self.applied_snapshot_status.l1_batch_number;
+        new_status.storage_logs_chunks_processed.fill(true);
+        let base_status = mem::replace(&mut self.applied_snapshot_status, new_status);
+        let result = self
+            .save_delta_snapshot(base_l1_batch_number, snapshot.storage_logs_chunks.len())
+            .await;
+        if result.is_err() {
+            self.applied_snapshot_status = base_status;
+        }
+        result?;
+
+        let latency = latency.observe();
+        tracing::info!("Applied delta snapshot for L1 batch #{l1_batch_number} in {latency:?}");
+        Ok(())
+    }
+
+    /// Saves a delta snapshot with the already updated `applied_snapshot_status` to Postgres atomically.
+    /// Storage log chunks are fetched from the object store and applied one by one, so that only a single chunk
+    /// is held in memory at a time.
+    async fn save_delta_snapshot(
+        &self,
+        base_l1_batch_number: L1BatchNumber,
+        storage_logs_chunk_count: usize,
+    ) -> Result<(), SnapshotsApplierError> {
+        let status = &self.applied_snapshot_status;
+        let mut storage = self
+            .connection_pool
+            .connection_tagged("snapshots_applier")
+            .await?;
+        let mut storage_transaction = storage.start_transaction().await?;
+
+        // `recover_factory_deps()` loads factory deps for the updated status, i.e. for the delta snapshot.
+        self.recover_factory_deps(&mut storage_transaction).await?;
+
+        let mut storage_logs_count = 0;
+        for chunk_id in 0..storage_logs_chunk_count as u64 {
+            let storage_key = SnapshotStorageLogsStorageKey {
+                chunk_id,
+                l1_batch_number: status.l1_batch_number,
+            };
+            let chunk: SnapshotStorageLogsChunk =
+                self.blob_store.get(storage_key).await.map_err(|err| {
+                    let context =
+                        format!("cannot fetch storage logs {storage_key:?} from object store");
+                    SnapshotsApplierError::object_store(err, context)
+                })?;
+            let storage_logs = chunk.storage_logs;
+            StorageLogs::validate_inner(&storage_logs, status)?;
+            storage_logs_count += storage_logs.len();
+
+            let hashed_keys: Vec<_> = storage_logs.iter().map(|log| log.key).collect();
+            storage_transaction
+                .storage_logs_dal()
+                .remove_storage_logs_for_hashed_keys(&hashed_keys)
+                .await?;
+            storage_transaction
+                .storage_logs_dal()
+                .insert_storage_logs_from_snapshot(status.l2_block_number, &storage_logs)
+                .await?;
+            // Keys initially written before the base snapshot already have initial writes.
+            let new_initial_writes: Vec<_> = storage_logs
+                .into_iter()
+                .filter(|log| log.l1_batch_number_of_initial_write > base_l1_batch_number)
+                .collect();
+            self.insert_initial_writes_chunk(&new_initial_writes, &mut storage_transaction)
+                .await?;
+        }
+        tracing::info!(
+            "Applied {storage_logs_count} storage logs from {storage_logs_chunk_count} delta snapshot chunk(s)"
+        );
+
+        self.check_storage_logs_consistency(&mut storage_transaction)
+            .await?;
+        Self::check_root_hash(&mut storage_transaction, status).await?;
+
+        storage_transaction
+            .snapshot_recovery_dal()
+            .update_recovery_status(status)
+            .await?;
+        storage_transaction
+            .pruning_dal()
+            .insert_soft_pruning_log(status.l1_batch_number, status.l2_block_number)
+            .await?;
+        storage_transaction
+            .pruning_dal()
+            .insert_hard_pruning_log(
+                status.l1_batch_number,
+                status.l2_block_number,
+                status.l1_batch_root_hash,
+            )
+            .await?;
+        storage_transaction.commit().await?;
+        Ok(())
+    }
+
+    /// Checks that the Merkle tree root hash of the entire storage state matches the root hash of the L1 batch
+    /// in `status`. Doesn't trust root hashes reported by the main node for delta snapshots, since a delta
+    /// only contains a part of the state.
+    ///
+    /// The tree is built in a temporary RocksDB instance, and the state is loaded from Postgres in chunks,
+    /// so that neither the tree nor the state is held in memory entirely.
+    async fn check_root_hash(
+        storage: &mut Connection<'_, Core>,
+        status: &SnapshotRecoveryStatus,
+    ) -> Result<(), SnapshotsApplierError> {
+        let latency = METRICS.delta_root_hash_check_duration.start();
+        let tree_dir = tempfile::TempDir::new()
+            .context("failed creating temporary directory for Merkle tree")?;
+        let db = RocksDBWrapper::new(tree_dir.path()).with_context(|| {
+            format!(
+                "failed creating temporary Merkle tree RocksDB at {}",
+                tree_dir.path().display()
+            )
+        })?;
+        let mut tree_recovery = MerkleTreeRecovery::new(db, status.l1_batch_number.0.into())?;
+        for chunk_id in 0..ROOT_HASH_KEY_CHUNK_COUNT {
+            let key_range = uniform_hashed_keys_chunk(chunk_id, ROOT_HASH_KEY_CHUNK_COUNT);
+            let entries = storage
+                .storage_logs_dal()
+                .get_tree_entries_for_l2_block(status.l2_block_number, key_range)
+                .await?;
+            let entries = entries
+                .into_iter()
+                .map(|entry| TreeEntry {
+                    key: entry.tree_key(),
+                    value: entry.value,
+                    leaf_index: entry.leaf_index,
+                })
+                .collect();
+            // Key ranges are processed in the ascending order, and entries in each range are sorted by key.
+            tree_recovery = tokio::task::spawn_blocking(move || {
+                tree_recovery.extend_linear(entries)?;
+                anyhow::Ok(tree_recovery)
+            })
+            .await
+            .context("panicked while extending Merkle tree")??;
+        }
+
+        let root_hash = tree_recovery.root_hash();
+        drop(tree_recovery);
+        let latency = latency.observe();
+        tracing::info!(
+            "Computed tree root hash {root_hash:?} for L1 batch #{} in {latency:?}",
+            status.l1_batch_number
+        );
+        if root_hash != status.l1_batch_root_hash {
+            let err = anyhow::anyhow!(
+                "tree root hash {root_hash:?} after applying delta snapshot for L1 batch #{} doesn't match \
+                 the L1 batch root hash {:?}; the snapshot may be corrupted",
+                status.l1_batch_number,
+                status.l1_batch_root_hash
+            );
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
     /// Needs to run after recovering storage logs.
     async fn recover_tokens(&self) -> Result<(), SnapshotsApplierError> {
         // Check whether tokens are already recovered.
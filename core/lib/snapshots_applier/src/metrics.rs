@@ -38,6 +38,13 @@ pub(crate) struct SnapshotsApplierMetrics {
     /// Latency of storage log chunk processing split by stage.
     #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
     pub storage_logs_chunks_duration: Family<StorageLogsChunksStage, Histogram<Duration>>,
+
+    /// Latency of applying a single delta snapshot.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub delta_snapshot_duration: Histogram<Duration>,
+    /// Latency of computing the tree root hash after applying a delta snapshot.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub delta_root_hash_check_duration: Histogram<Duration>,
 }
 
 #[vise::register]
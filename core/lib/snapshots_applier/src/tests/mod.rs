@@ -18,7 +18,8 @@ use zksync_types::{
 
 use self::utils::{
     mock_l2_block_header, mock_recovery_status, mock_snapshot_header, mock_tokens, prepare_clients,
-    random_storage_logs, MockMainNodeClient, ObjectStoreWithErrors,
+    prepare_delta_snapshot, random_storage_logs, tree_root_hash, MockMainNodeClient,
+    ObjectStoreWithErrors,
 };
 use super::*;
 use crate::tests::utils::{mock_factory_deps, HangingObjectStore};
@@ -456,6 +457,13 @@ async fn health_status_immediately_after_task_start() {
             future::pending().await
         }
 
+        async fn fetch_delta_snapshot_l1_batch_numbers(
+            &self,
+        ) -> EnrichedClientResult<Vec<L1BatchNumber>> {
+            self.0.wait().await;
+            future::pending().await
+        }
+
         async fn fetch_tokens(
             &self,
             _at_l2_block: L2BlockNumber,
@@ -756,3 +764,176 @@ async fn snapshot_applier_can_be_canceled() {
         .unwrap_err();
     assert_matches!(err, OrStopped::Stopped);
 }
+
+/// Generates a delta snapshot on top of `base_status` that updates some of `storage_logs` and adds new ones.
+/// Returns the delta snapshot status (with the root hash of the updated state) and logs.
+fn delta_snapshot(
+    base_status: &SnapshotRecoveryStatus,
+    storage_logs: &[SnapshotStorageLog],
+) -> (SnapshotRecoveryStatus, Vec<SnapshotStorageLog>) {
+    let mut delta_status = SnapshotRecoveryStatus {
+        l1_batch_number: base_status.l1_batch_number + 5,
+        l1_batch_root_hash: H256::zero(), // set below
+        l1_batch_timestamp: base_status.l1_batch_timestamp,
+        l2_block_number: base_status.l2_block_number + 10,
+        l2_block_hash: H256::random(),
+        l2_block_timestamp: base_status.l2_block_timestamp,
+        protocol_version: base_status.protocol_version,
+        storage_logs_chunks_processed: vec![true; 2],
+    };
+    let updated_logs = storage_logs
+        .iter()
+        .step_by(4)
+        .map(|log| SnapshotStorageLog {
+            value: H256::random(),
+            ..log.clone()
+        });
+    let new_logs = random_storage_logs::<H256>(delta_status.l1_batch_number, 20)
+        .into_iter()
+        .map(|log| SnapshotStorageLog {
+            enumeration_index: log.enumeration_index + storage_logs.len() as u64,
+            ..log
+        });
+    let delta_logs: Vec<_> = updated_logs.chain(new_logs).collect();
+
+    let mut state: HashMap<_, _> = storage_logs.iter().map(|log| (log.key, log)).collect();
+    state.extend(delta_logs.iter().map(|log| (log.key, log)));
+    delta_status.l1_batch_root_hash = tree_root_hash(state.into_values());
+    (delta_status, delta_logs)
+}
+
+#[tokio::test]
+async fn applying_delta_snapshots() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let base_status = mock_recovery_status();
+    let factory_deps = mock_factory_deps(None);
+    let storage_logs = random_storage_logs::<H256>(base_status.l1_batch_number, 200);
+    let (object_store, mut client) =
+        prepare_clients(&base_status, &factory_deps, &storage_logs).await;
+
+    let (delta_status, delta_logs) = delta_snapshot(&base_status, &storage_logs);
+    prepare_delta_snapshot(
+        &*object_store,
+        &mut client,
+        base_status.l1_batch_number,
+        &delta_status,
+        &mock_factory_deps(Some(BytecodeMarker::EraVm)),
+        &delta_logs,
+    )
+    .await;
+    // Add a delta snapshot that is not a part of the chain; it should be ignored.
+    let mut unrelated_header = mock_snapshot_header(1, &delta_status);
+    unrelated_header.l1_batch_number = delta_status.l1_batch_number - 1;
+    unrelated_header.base_l1_batch_number = Some(base_status.l1_batch_number - 1);
+    client.delta_snapshot_responses.push(unrelated_header);
+
+    let mut task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool.clone(),
+        Box::new(client.clone()),
+        object_store,
+    );
+    task.apply_delta_snapshots();
+    let task_health = task.health_check();
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let stats = task.run(stop_receiver).await.unwrap();
+    assert!(stats.done_work);
+    assert_matches!(
+        task_health.check_health().await.status(),
+        HealthStatus::Ready
+    );
+
+    let mut storage = pool.connection().await.unwrap();
+    let current_db_status = storage
+        .snapshot_recovery_dal()
+        .get_applied_snapshot_status()
+        .await
+        .unwrap();
+    assert_eq!(current_db_status.unwrap(), delta_status);
+
+    let mut expected_logs: HashMap<_, _> = storage_logs
+        .iter()
+        .map(|log| (log.key, (log, base_status.l2_block_number)))
+        .collect();
+    for log in &delta_logs {
+        expected_logs.insert(log.key, (log, delta_status.l2_block_number));
+    }
+    let all_storage_logs = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    assert_eq!(all_storage_logs.len(), expected_logs.len());
+    for db_log in all_storage_logs {
+        let (expected_log, expected_l2_block_number) = expected_logs[&db_log.hashed_key];
+        assert_eq!(db_log.value, expected_log.value);
+        assert_eq!(db_log.l2_block_number, expected_l2_block_number);
+    }
+
+    let all_initial_writes = storage
+        .storage_logs_dedup_dal()
+        .dump_all_initial_writes_for_tests()
+        .await;
+    assert_eq!(all_initial_writes.len(), expected_logs.len());
+    for initial_write in all_initial_writes {
+        let (expected_log, _) = expected_logs[&initial_write.hashed_key];
+        assert_eq!(
+            initial_write.l1_batch_number,
+            expected_log.l1_batch_number_of_initial_write
+        );
+        assert_eq!(initial_write.index, expected_log.enumeration_index);
+    }
+}
+
+#[tokio::test]
+async fn tampered_delta_snapshot_is_rejected() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let base_status = mock_recovery_status();
+    let storage_logs = random_storage_logs::<H256>(base_status.l1_batch_number, 200);
+    let (object_store, mut client) =
+        prepare_clients(&base_status, &mock_factory_deps(None), &storage_logs).await;
+
+    let (delta_status, mut delta_logs) = delta_snapshot(&base_status, &storage_logs);
+    // The root hash in the L1 batch header no longer corresponds to the snapshot data.
+    delta_logs[0].value = H256::random();
+    prepare_delta_snapshot(
+        &*object_store,
+        &mut client,
+        base_status.l1_batch_number,
+        &delta_status,
+        &mock_factory_deps(Some(BytecodeMarker::EraVm)),
+        &delta_logs,
+    )
+    .await;
+
+    let mut task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool.clone(),
+        Box::new(client),
+        object_store,
+    );
+    task.apply_delta_snapshots();
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let err = task.run(stop_receiver).await.unwrap_err();
+    assert!(format!("{err:#}").contains("tree root hash"), "{err:#}");
+
+    // The delta snapshot must not be applied.
+    let mut storage = pool.connection().await.unwrap();
+    let current_db_status = storage
+        .snapshot_recovery_dal()
+        .get_applied_snapshot_status()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        current_db_status.l1_batch_number,
+        base_status.l1_batch_number
+    );
+    let all_storage_logs = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    assert_eq!(all_storage_logs.len(), storage_logs.len());
+    assert!(all_storage_logs
+        .iter()
+        .all(|log| log.l2_block_number == base_status.l2_block_number));
+}
@@ -8,6 +8,7 @@ use std::{
 
 use async_trait::async_trait;
 use tokio::sync::watch;
+use zksync_merkle_tree::{recovery::MerkleTreeRecovery, PatchSet, TreeEntry};
 use zksync_object_store::{Bucket, MockObjectStore, ObjectStore, ObjectStoreError, StoredObject};
 use zksync_types::{
     api,
@@ -21,7 +22,7 @@ use zksync_types::{
     tokens::{TokenInfo, TokenMetadata},
     web3::Bytes,
     AccountTreeId, Address, L1BatchNumber, L2BlockNumber, ProtocolVersionId, StorageKey,
-    StorageValue, H256,
+    StorageValue, H256, U256,
 };
 use zksync_web3_decl::error::{EnrichedClientError, EnrichedClientResult};
 
@@ -54,6 +55,7 @@ pub(super) struct MockMainNodeClient {
     pub fetch_l1_batch_responses: HashMap<L1BatchNumber, api::L1BatchDetails>,
     pub fetch_l2_block_responses: HashMap<L2BlockNumber, api::BlockDetails>,
     pub fetch_newest_snapshot_response: Option<SnapshotHeader>,
+    pub delta_snapshot_responses: Vec<SnapshotHeader>,
     pub tokens_response: Vec<TokenInfo>,
     pub tokens_response_error: Arc<RwLock<Option<EnrichedClientError>>>,
 }
@@ -101,8 +103,20 @@ impl SnapshotsApplierMainNodeClient for MockMainNodeClient {
     ) -> EnrichedClientResult<Option<SnapshotHeader>> {
         Ok(self
             .fetch_newest_snapshot_response
-            .clone()
-            .filter(|response| response.l1_batch_number == l1_batch_number))
+            .iter()
+            .chain(&self.delta_snapshot_responses)
+            .find(|response| response.l1_batch_number == l1_batch_number)
+            .cloned())
+    }
+
+    async fn fetch_delta_snapshot_l1_batch_numbers(
+        &self,
+    ) -> EnrichedClientResult<Vec<L1BatchNumber>> {
+        Ok(self
+            .delta_snapshot_responses
+            .iter()
+            .map(|response| response.l1_batch_number)
+            .collect())
     }
 
     async fn fetch_tokens(
@@ -228,6 +242,7 @@ fn l1_batch_details(number: L1BatchNumber, root_hash: H256) -> api::L1BatchDetai
     api::L1BatchDetails {
         number,
         base: block_details_base(root_hash),
+        verified_stage: None,
     }
 }
 
@@ -263,6 +278,21 @@ pub(super) fn random_storage_logs<K: SnapshotLogKey>(
         .collect()
 }
 
+/// Computes the Merkle tree root hash for the storage state consisting of `logs`.
+pub(super) fn tree_root_hash<'a>(logs: impl IntoIterator<Item = &'a SnapshotStorageLog>) -> H256 {
+    let entries = logs
+        .into_iter()
+        .map(|log| TreeEntry {
+            key: U256::from_little_endian(log.key.as_bytes()),
+            value: log.value,
+            leaf_index: log.enumeration_index,
+        })
+        .collect();
+    let mut tree_recovery = MerkleTreeRecovery::new(PatchSet::default(), 0).unwrap();
+    tree_recovery.extend_random(entries).unwrap();
+    tree_recovery.root_hash()
+}
+
 pub(super) fn mock_recovery_status() -> SnapshotRecoveryStatus {
     SnapshotRecoveryStatus {
         l1_batch_number: L1BatchNumber(123),
@@ -307,6 +337,7 @@ pub(super) fn mock_snapshot_header(
         version,
         l1_batch_number: status.l1_batch_number,
         l2_block_number: status.l2_block_number,
+        base_l1_batch_number: None,
         storage_logs_chunks: (0..status.storage_logs_chunks_processed.len() as u64)
             .map(|chunk_id| SnapshotStorageLogsChunkMetadata {
                 chunk_id,
@@ -368,6 +399,50 @@ where
     (object_store, client)
 }
 
+/// Adds a delta snapshot on top of the snapshot for `base_l1_batch_number` to the object store and the mock client.
+pub(super) async fn prepare_delta_snapshot(
+    object_store: &dyn ObjectStore,
+    client: &mut MockMainNodeClient,
+    base_l1_batch_number: L1BatchNumber,
+    status: &SnapshotRecoveryStatus,
+    factory_deps: &SnapshotFactoryDependencies,
+    logs: &[SnapshotStorageLog],
+) {
+    object_store
+        .put(status.l1_batch_number, factory_deps)
+        .await
+        .unwrap();
+    let chunk_size = logs
+        .len()
+        .div_ceil(status.storage_logs_chunks_processed.len());
+    for (chunk_id, chunk) in logs.chunks(chunk_size).enumerate() {
+        let chunk_key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: status.l1_batch_number,
+            chunk_id: chunk_id as u64,
+        };
+        let chunk = SnapshotStorageLogsChunk {
+            storage_logs: chunk.to_vec(),
+        };
+        object_store.put(chunk_key, &chunk).await.unwrap();
+    }
+
+    let mut header = mock_snapshot_header(SnapshotVersion::Version1.into(), status);
+    header.base_l1_batch_number = Some(base_l1_batch_number);
+    client.delta_snapshot_responses.push(header);
+    client.fetch_l1_batch_responses.insert(
+        status.l1_batch_number,
+        l1_batch_details(status.l1_batch_number, status.l1_batch_root_hash),
+    );
+    client.fetch_l2_block_responses.insert(
+        status.l2_block_number,
+        l2_block_details(
+            status.l2_block_number,
+            status.l1_batch_number,
+            status.l2_block_hash,
+        ),
+    );
+}
+
 /// Object store wrapper that hangs up after processing the specified number of requests.
 /// Used to emulate the snapshot applier being restarted since, if it's configured to have concurrency 1,
 /// the applier will request an object from the store strictly after fully processing all previously requested objects.
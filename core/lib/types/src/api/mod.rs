@@ -897,12 +897,36 @@ pub struct BlockDetails {
     pub protocol_version: Option<ProtocolVersionId>,
 }
 
+/// Stage of an L1 batch independently verified by the node on the settlement layer (i.e., without trusting
+/// the main node).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize
+)]
+#[serde(rename_all = "camelCase")]
+pub enum VerifiedL1BatchStage {
+    Committed,
+    Proven,
+    Executed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct L1BatchDetails {
     pub number: L1BatchNumber,
     #[serde(flatten)]
     pub base: BlockDetailsBase,
+    /// Batch stage verified by the node on the settlement layer. Only set by nodes that independently verify
+    /// batch statuses, and only if the batch is at least committed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified_stage: Option<VerifiedL1BatchStage>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -939,6 +963,18 @@ pub struct TeeProof {
     pub attestation: Option<Vec<u8>>,
 }
 
+/// Information about an L1 batch with TEE proofs satisfying the TEE quorum policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeeFinalization {
+    pub l1_batch_number: L1BatchNumber,
+    /// TEE types that provided proofs for the batch.
+    pub tee_types: Vec<TeeType>,
+    /// Number of distinct attested keys that signed proofs for the batch.
+    pub distinct_keys: usize,
+    pub finalized_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionDetailedResult {
@@ -979,10 +1015,14 @@ pub struct FeeHistory {
 #[serde(rename_all = "camelCase")]
 pub struct DataAvailabilityDetails {
     pub pubdata_type: Option<PubdataType>,
+    /// Empty if the pubdata was dispatched as several blobs.
     pub blob_id: String,
     pub inclusion_data: Option<Vec<u8>>,
     pub sent_at: DateTime<Utc>,
     pub l2_da_validator: Option<Address>,
+    /// Blob IDs of the chunks ordered by the chunk index if the pubdata was dispatched as several blobs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_blob_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -1000,6 +1040,51 @@ pub struct GatewayMigrationStatus {
     pub settlement_layer: Option<SettlementLayer>,
 }
 
+/// Change of a single storage slot in a state diff.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSlotChange {
+    pub hashed_key: H256,
+    /// Address owning the slot. May be missing if the key preimage is unknown to the node (e.g., for nodes
+    /// recovered from a snapshot).
+    pub address: Option<Address>,
+    /// Slot key. May be missing if the key preimage is unknown to the node.
+    pub key: Option<H256>,
+    /// Slot value before the diff. `None` if the slot was never written to before.
+    pub previous_value: Option<H256>,
+    pub new_value: H256,
+}
+
+/// Contract deployed (i.e., with the bytecode hash changed) in a state diff.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeployedContract {
+    pub address: Address,
+    pub bytecode_hash: H256,
+}
+
+/// Change of the base token balance in a state diff.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    pub address: Address,
+    pub previous_balance: U256,
+    pub new_balance: U256,
+}
+
+/// Page of a state diff for an L2 block or an L1 batch returned by `unstable_getL2BlockStateDiff`
+/// and `unstable_getL1BatchStateDiff`. Storage changes are ordered by the hashed key.
+/// Deployed contracts and balance changes are decoded from the storage changes on the page.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDiff {
+    pub storage_changes: Vec<StorageSlotChange>,
+    pub deployed_contracts: Vec<DeployedContract>,
+    pub balance_changes: Vec<BalanceChange>,
+    /// Cursor to pass to get the next page. `None` if this page is the last one.
+    pub next_cursor: Option<H256>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct EcosystemContracts {
     pub bridgehub_proxy_addr: Address,
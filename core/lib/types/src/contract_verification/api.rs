@@ -7,6 +7,7 @@ use serde::{
 };
 use zksync_basic_types::bytecode::BytecodeMarker;
 
+use super::proxy::ProxyInfo;
 pub use crate::Execute as ExecuteData;
 use crate::{web3::Bytes, Address};
 
@@ -278,6 +279,9 @@ impl CompilationArtifacts {
 pub enum VerificationProblem {
     /// The bytecode is correct, but metadata hash is different.
     IncorrectMetadata,
+    /// The contract was not verified directly; verification info is propagated from a verified contract
+    /// with the identical bytecode. The address of the verified contract is available as `request.req.contract_address`.
+    SimilarMatch,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -290,6 +294,17 @@ pub struct VerificationInfo {
     pub verification_problems: Vec<VerificationProblem>,
 }
 
+/// Verification info returned by the API, extended with information about the proxy implementation
+/// if the contract is a proxy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractVerificationInfo {
+    #[serde(flatten)]
+    pub info: VerificationInfo,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyInfo>,
+}
+
 impl VerificationInfo {
     pub fn is_perfect_match(&self) -> bool {
         self.verification_problems.is_empty()
@@ -1,3 +1,5 @@
 pub mod api;
 pub mod contract_identifier;
 pub mod etherscan;
+pub mod proxy;
+pub mod sourcify;
@@ -0,0 +1,192 @@
+//! Types and helpers for detecting upgradeable proxy contracts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{h256_to_address, Address, L2BlockNumber, H256};
+
+/// EIP-1967 implementation slot: `keccak256("eip1967.proxy.implementation") - 1`.
+pub const EIP1967_IMPLEMENTATION_SLOT: H256 = H256([
+    0x36, 0x08, 0x94, 0xa1, 0x3b, 0xa1, 0xa3, 0x21, 0x06, 0x67, 0xc8, 0x28, 0x49, 0x2d, 0xb9, 0x8d,
+    0xca, 0x3e, 0x20, 0x76, 0xcc, 0x37, 0x35, 0xa9, 0x20, 0xa3, 0xca, 0x50, 0x5d, 0x38, 0x2b, 0xbc,
+]);
+/// EIP-1967 beacon slot: `keccak256("eip1967.proxy.beacon") - 1`.
+pub const EIP1967_BEACON_SLOT: H256 = H256([
+    0xa3, 0xf0, 0xad, 0x74, 0xe5, 0x42, 0x3a, 0xeb, 0xfd, 0x80, 0xd3, 0xef, 0x43, 0x46, 0x57, 0x83,
+    0x35, 0xa9, 0xa7, 0x2a, 0xea, 0xee, 0x59, 0xff, 0x6c, 0xb3, 0x58, 0x2b, 0x35, 0x13, 0x3d, 0x50,
+]);
+/// EIP-1967 admin slot: `keccak256("eip1967.proxy.admin") - 1`.
+pub const EIP1967_ADMIN_SLOT: H256 = H256([
+    0xb5, 0x31, 0x27, 0x68, 0x4a, 0x56, 0x8b, 0x31, 0x73, 0xae, 0x13, 0xb9, 0xf8, 0xa6, 0x01, 0x6e,
+    0x24, 0x3e, 0x63, 0xb6, 0xe8, 0xee, 0x11, 0x78, 0xd6, 0xa7, 0x17, 0x85, 0x0b, 0x5d, 0x61, 0x03,
+]);
+/// EIP-1822 (legacy UUPS) implementation slot: `keccak256("PROXIABLE")`.
+pub const EIP1822_IMPLEMENTATION_SLOT: H256 = H256([
+    0xc5, 0xf1, 0x6f, 0x0f, 0xcc, 0x63, 0x9f, 0xa4, 0x8a, 0x69, 0x47, 0x83, 0x6d, 0x98, 0x50, 0xf5,
+    0x04, 0x79, 0x85, 0x23, 0xbf, 0x8c, 0x9a, 0x3a, 0x87, 0xd5, 0x87, 0x6c, 0xf6, 0x22, 0xbc, 0xf7,
+]);
+/// Storage slot of the implementation address in OpenZeppelin `UpgradeableBeacon` (it follows
+/// the `Ownable` owner stored in slot 0).
+pub const BEACON_IMPLEMENTATION_SLOT: H256 = H256([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+]);
+/// Signature of the `Upgraded(address indexed implementation)` event emitted by proxies and beacons.
+pub const UPGRADED_EVENT_SIGNATURE: H256 = H256([
+    0xbc, 0x7c, 0xd7, 0x5a, 0x20, 0xee, 0x27, 0xfd, 0x9a, 0xde, 0xba, 0xb3, 0x20, 0x41, 0xf7, 0x55,
+    0x21, 0x4d, 0xbc, 0x6b, 0xff, 0xa9, 0x0c, 0xc0, 0x22, 0x5b, 0x39, 0xda, 0x2e, 0x5c, 0x2d, 0x3b,
+]);
+
+/// Kind of upgradeable proxy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProxyKind {
+    /// EIP-1967 proxy without an admin, e.g. a UUPS proxy (OpenZeppelin `ERC1967Proxy`).
+    Eip1967,
+    /// EIP-1967 proxy with an admin (OpenZeppelin `TransparentUpgradeableProxy`).
+    Transparent,
+    /// EIP-1967 beacon proxy; the implementation is provided by the beacon contract.
+    Beacon,
+    /// Legacy UUPS proxy using the EIP-1822 `PROXIABLE` slot.
+    Eip1822,
+}
+
+/// Values of the proxy-related storage slots of a contract.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProxySlots {
+    pub implementation: H256,
+    pub admin: H256,
+    pub beacon: H256,
+    pub eip1822_implementation: H256,
+}
+
+impl ProxySlots {
+    /// Storage slots to read, in the order expected by [`Self::from_values()`].
+    pub const SLOTS: [H256; 4] = [
+        EIP1967_IMPLEMENTATION_SLOT,
+        EIP1967_ADMIN_SLOT,
+        EIP1967_BEACON_SLOT,
+        EIP1822_IMPLEMENTATION_SLOT,
+    ];
+
+    pub fn from_values(values: [H256; 4]) -> Self {
+        let [implementation, admin, beacon, eip1822_implementation] = values;
+        Self {
+            implementation,
+            admin,
+            beacon,
+            eip1822_implementation,
+        }
+    }
+
+    /// Detects the proxy kind. Returns the kind together with the implementation address, or the beacon address
+    /// for [`ProxyKind::Beacon`]. Returns `None` if the slots don't correspond to a proxy.
+    pub fn detect(&self) -> Option<(ProxyKind, Address)> {
+        if !self.beacon.is_zero() {
+            Some((ProxyKind::Beacon, h256_to_address(&self.beacon)))
+        } else if !self.implementation.is_zero() {
+            let kind = if self.admin.is_zero() {
+                ProxyKind::Eip1967
+            } else {
+                ProxyKind::Transparent
+            };
+            Some((kind, h256_to_address(&self.implementation)))
+        } else if !self.eip1822_implementation.is_zero() {
+            Some((
+                ProxyKind::Eip1822,
+                h256_to_address(&self.eip1822_implementation),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Change of the proxy implementation recorded by an `Upgraded` event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImplementationChange {
+    pub implementation_address: Address,
+    pub l2_block_number: L2BlockNumber,
+    pub tx_hash: H256,
+}
+
+/// Information about a proxy contract returned together with its verification info.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyInfo {
+    pub kind: ProxyKind,
+    /// Current implementation address.
+    pub implementation_address: Address,
+    /// Beacon address; only set for [`ProxyKind::Beacon`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beacon_address: Option<Address>,
+    /// ABI of the implementation contract, or `None` if the implementation is not verified.
+    pub implementation_abi: Option<serde_json::Value>,
+    /// Implementation changes in chronological order, taken from `Upgraded` events.
+    pub implementation_history: Vec<ImplementationChange>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{address_to_h256, web3::keccak256, U256};
+
+    fn eip1967_slot(name: &str) -> H256 {
+        let hash = U256::from_big_endian(&keccak256(name.as_bytes()));
+        let mut slot = H256::zero();
+        (hash - 1).to_big_endian(slot.as_bytes_mut());
+        slot
+    }
+
+    #[test]
+    fn proxy_constants_are_correct() {
+        assert_eq!(
+            EIP1967_IMPLEMENTATION_SLOT,
+            eip1967_slot("eip1967.proxy.implementation")
+        );
+        assert_eq!(EIP1967_BEACON_SLOT, eip1967_slot("eip1967.proxy.beacon"));
+        assert_eq!(EIP1967_ADMIN_SLOT, eip1967_slot("eip1967.proxy.admin"));
+        assert_eq!(EIP1822_IMPLEMENTATION_SLOT, H256(keccak256(b"PROXIABLE")));
+        assert_eq!(
+            UPGRADED_EVENT_SIGNATURE,
+            H256(keccak256(b"Upgraded(address)"))
+        );
+    }
+
+    #[test]
+    fn detecting_proxy_kind() {
+        let implementation = Address::repeat_byte(1);
+        let other = Address::repeat_byte(2);
+
+        assert_eq!(ProxySlots::default().detect(), None);
+
+        let slots = ProxySlots {
+            implementation: address_to_h256(&implementation),
+            ..ProxySlots::default()
+        };
+        assert_eq!(slots.detect(), Some((ProxyKind::Eip1967, implementation)));
+
+        let slots = ProxySlots {
+            implementation: address_to_h256(&implementation),
+            admin: address_to_h256(&other),
+            ..ProxySlots::default()
+        };
+        assert_eq!(
+            slots.detect(),
+            Some((ProxyKind::Transparent, implementation))
+        );
+
+        let slots = ProxySlots {
+            beacon: address_to_h256(&other),
+            ..ProxySlots::default()
+        };
+        assert_eq!(slots.detect(), Some((ProxyKind::Beacon, other)));
+
+        let slots = ProxySlots::from_values([
+            H256::zero(),
+            H256::zero(),
+            H256::zero(),
+            address_to_h256(&implementation),
+        ]);
+        assert_eq!(slots.detect(), Some((ProxyKind::Eip1822, implementation)));
+    }
+}
@@ -0,0 +1,403 @@
+//! Types for the Sourcify-compatible (v2) verification API.
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Context as _;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api::{
+        CompilerVersions, SourceCodeData, VerificationEvmSettings, VerificationIncomingRequest,
+        VerificationInfo,
+    },
+    contract_identifier::Match,
+};
+use crate::{web3::keccak256, Address, H256};
+
+/// Sourcify match status of a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourcifyMatch {
+    /// Bytecode matches, including the metadata hash. Corresponds to [`Match::Full`].
+    ExactMatch,
+    /// Bytecode matches except for the metadata hash. Corresponds to [`Match::Partial`].
+    Match,
+}
+
+impl SourcifyMatch {
+    pub fn from_match(value: Match) -> Option<Self> {
+        match value {
+            Match::Full => Some(Self::ExactMatch),
+            Match::Partial => Some(Self::Match),
+            Match::None => None,
+        }
+    }
+
+    pub fn for_verification_info(info: &VerificationInfo) -> Self {
+        if info.is_perfect_match() {
+            Self::ExactMatch
+        } else {
+            Self::Match
+        }
+    }
+}
+
+/// Request to verify a contract based on its Solidity metadata (`POST /v2/verify/metadata/{chainId}/{address}`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourcifyVerificationRequest {
+    /// Source files keyed by their path as specified in the metadata.
+    pub sources: HashMap<String, String>,
+    /// Contents of `metadata.json` emitted by the compiler.
+    pub metadata: serde_json::Value,
+    /// Hash of the contract creation transaction. Accepted for compatibility, but not used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creation_transaction_hash: Option<H256>,
+    /// Version of `zksolc` used to compile an EraVM contract. Not a part of the Sourcify API; may be omitted
+    /// if the metadata is emitted by `zksolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zksolc_version: Option<String>,
+}
+
+/// Subset of the Solidity contract metadata (`metadata.json`) used for verification.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SolidityMetadata {
+    compiler: MetadataCompiler,
+    language: String,
+    settings: MetadataSettings,
+    sources: BTreeMap<String, MetadataSource>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataCompiler {
+    version: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MetadataSettings {
+    compilation_target: BTreeMap<String, String>,
+    #[serde(default)]
+    libraries: BTreeMap<String, String>,
+    /// Other settings (optimizer, EVM version, remappings etc.) copied to the standard JSON input as is.
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataSource {
+    keccak256: Option<H256>,
+    content: Option<String>,
+}
+
+/// Metadata emitted by `zksolc`, which wraps the original `solc` metadata.
+#[derive(Debug, Deserialize)]
+struct ZkSolcMetadata {
+    solc_metadata: serde_json::Value,
+    solc_version: Option<String>,
+    solc_zkvm_edition: Option<String>,
+    zk_version: Option<String>,
+}
+
+impl SourcifyVerificationRequest {
+    /// Converts the request to a `VerificationIncomingRequest` with the standard JSON input reconstructed
+    /// from the metadata.
+    pub fn to_verification_request(
+        self,
+        contract_address: Address,
+    ) -> anyhow::Result<VerificationIncomingRequest> {
+        let mut metadata = self.metadata;
+        if let serde_json::Value::String(raw) = &metadata {
+            metadata = serde_json::from_str(raw).context("metadata is not valid JSON")?;
+        }
+
+        let mut zksolc_version = self.zksolc_version;
+        let mut solc_version_override = None;
+        if metadata.get("solc_metadata").is_some() {
+            let zk_metadata: ZkSolcMetadata =
+                serde_json::from_value(metadata).context("invalid zksolc metadata")?;
+            metadata = match zk_metadata.solc_metadata {
+                serde_json::Value::String(raw) => {
+                    serde_json::from_str(&raw).context("`solc_metadata` is not valid JSON")?
+                }
+                value => value,
+            };
+            zksolc_version = zksolc_version.or(zk_metadata.zk_version);
+            solc_version_override = zk_metadata
+                .solc_version
+                .zip(zk_metadata.solc_zkvm_edition)
+                .map(|(version, edition)| format!("zkVM-{version}-{edition}"));
+        }
+        let metadata: SolidityMetadata =
+            serde_json::from_value(metadata).context("invalid contract metadata")?;
+        anyhow::ensure!(
+            metadata.language == "Solidity",
+            "unsupported language `{}`; only Solidity is supported",
+            metadata.language
+        );
+
+        let mut compilation_target = metadata.settings.compilation_target.into_iter();
+        let (target_path, target_name) = compilation_target
+            .next()
+            .context("metadata doesn't specify compilation target")?;
+        anyhow::ensure!(
+            compilation_target.next().is_none(),
+            "metadata must specify exactly one compilation target"
+        );
+
+        let mut sources = serde_json::Map::new();
+        for (path, source) in metadata.sources {
+            let content = self
+                .sources
+                .get(&path)
+                .cloned()
+                .or(source.content)
+                .with_context(|| format!("missing source file `{path}`"))?;
+            if let Some(expected_hash) = source.keccak256 {
+                anyhow::ensure!(
+                    H256(keccak256(content.as_bytes())) == expected_hash,
+                    "keccak256 hash of source file `{path}` differs from the one in metadata"
+                );
+            }
+            sources.insert(path, serde_json::json!({ "content": content }));
+        }
+
+        // Metadata specifies libraries as `path:Name => address`, while the standard JSON input
+        // groups them by path.
+        let mut libraries = serde_json::Map::new();
+        for (qualified_name, address) in metadata.settings.libraries {
+            let (path, name) = qualified_name
+                .rsplit_once(':')
+                .unwrap_or(("", &qualified_name));
+            let entry = libraries
+                .entry(path.to_owned())
+                .or_insert_with(|| serde_json::json!({}));
+            entry[name] = address.into();
+        }
+        let mut settings = metadata.settings.other;
+        let optimization_used = settings
+            .get("optimizer")
+            .and_then(|optimizer| optimizer.get("enabled"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        if !libraries.is_empty() {
+            settings.insert("libraries".to_owned(), libraries.into());
+        }
+
+        let standard_json = serde_json::json!({
+            "language": "Solidity",
+            "sources": sources,
+            "settings": settings,
+        });
+        let serde_json::Value::Object(standard_json) = standard_json else {
+            unreachable!();
+        };
+
+        let compiler_solc_version = solc_version_override.unwrap_or_else(|| {
+            // Extract the short version from the full version string, e.g. "0.8.24+commit.e11b9ed9" -> "0.8.24"
+            let version = &metadata.compiler.version;
+            let version = version.strip_prefix('v').unwrap_or(version);
+            version.split('+').next().unwrap_or(version).to_owned()
+        });
+        Ok(VerificationIncomingRequest {
+            contract_address,
+            source_code_data: SourceCodeData::StandardJsonInput(standard_json),
+            contract_name: format!("{target_path}:{target_name}"),
+            compiler_versions: CompilerVersions::Solc {
+                compiler_zksolc_version: zksolc_version,
+                compiler_solc_version,
+            },
+            optimization_used,
+            optimizer_mode: None,
+            constructor_arguments: Default::default(),
+            is_system: false,
+            force_evmla: false,
+            evm_specific: VerificationEvmSettings::default(),
+        })
+    }
+}
+
+/// Response to a verification request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourcifyVerificationResponse {
+    pub verification_id: String,
+}
+
+/// Verification status of a contract.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourcifyContractStatus {
+    #[serde(rename = "match")]
+    pub match_status: Option<SourcifyMatch>,
+    /// Always `null` for EraVM contracts since they have no separate creation bytecode.
+    pub creation_match: Option<SourcifyMatch>,
+    pub runtime_match: Option<SourcifyMatch>,
+    pub chain_id: String,
+    pub address: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+/// Error returned by the Sourcify-compatible API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourcifyError {
+    pub custom_code: String,
+    pub message: String,
+}
+
+/// Status of a verification job (`GET /v2/verify/{verificationId}`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourcifyJobStatus {
+    pub is_job_completed: bool,
+    pub verification_id: String,
+    pub contract: SourcifyContractStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SourcifyError>,
+}
+
+/// Source file returned by the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourcifySource {
+    pub content: String,
+}
+
+/// Verified contract details (`GET /v2/contract/{chainId}/{address}`). Optional fields are only returned
+/// if requested via the `fields` query parameter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourcifyContract {
+    #[serde(flatten)]
+    pub status: SourcifyContractStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<BTreeMap<String, SourcifySource>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub std_json_input: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "contract Counter { uint256 value; }";
+
+    fn metadata() -> serde_json::Value {
+        serde_json::json!({
+            "compiler": { "version": "0.8.24+commit.e11b9ed9" },
+            "language": "Solidity",
+            "output": { "abi": [] },
+            "settings": {
+                "compilationTarget": { "src/Counter.sol": "Counter" },
+                "evmVersion": "cancun",
+                "libraries": { "src/Lib.sol:Lib": "0x0000000000000000000000000000000000000001" },
+                "metadata": { "bytecodeHash": "ipfs" },
+                "optimizer": { "enabled": true, "runs": 200 },
+                "remappings": []
+            },
+            "sources": {
+                "src/Counter.sol": {
+                    "keccak256": H256(keccak256(SOURCE.as_bytes())),
+                    "urls": []
+                }
+            },
+            "version": 1
+        })
+    }
+
+    #[test]
+    fn converting_metadata_request() {
+        let request = SourcifyVerificationRequest {
+            sources: HashMap::from([("src/Counter.sol".to_owned(), SOURCE.to_owned())]),
+            metadata: metadata(),
+            creation_transaction_hash: None,
+            zksolc_version: Some("1.5.6".to_owned()),
+        };
+        let request = request
+            .to_verification_request(Address::repeat_byte(1))
+            .unwrap();
+
+        assert_eq!(request.contract_name, "src/Counter.sol:Counter");
+        assert!(request.optimization_used);
+        assert_eq!(
+            request.compiler_versions,
+            CompilerVersions::Solc {
+                compiler_zksolc_version: Some("1.5.6".to_owned()),
+                compiler_solc_version: "0.8.24".to_owned(),
+            }
+        );
+        let SourceCodeData::StandardJsonInput(input) = &request.source_code_data else {
+            panic!(
+                "unexpected source code data: {:?}",
+                request.source_code_data
+            );
+        };
+        assert_eq!(input["sources"]["src/Counter.sol"]["content"], SOURCE);
+        assert_eq!(input["settings"]["evmVersion"], "cancun");
+        assert_eq!(
+            input["settings"]["libraries"]["src/Lib.sol"]["Lib"],
+            "0x0000000000000000000000000000000000000001"
+        );
+        assert!(input["settings"].get("compilationTarget").is_none());
+    }
+
+    #[test]
+    fn converting_zksolc_metadata_request() {
+        let request = SourcifyVerificationRequest {
+            sources: HashMap::from([("src/Counter.sol".to_owned(), SOURCE.to_owned())]),
+            metadata: serde_json::json!({
+                "solc_metadata": metadata().to_string(),
+                "solc_version": "0.8.24",
+                "solc_zkvm_edition": "1.0.1",
+                "zk_version": "1.5.6",
+            }),
+            creation_transaction_hash: None,
+            zksolc_version: None,
+        };
+        let request = request
+            .to_verification_request(Address::repeat_byte(1))
+            .unwrap();
+        assert_eq!(
+            request.compiler_versions,
+            CompilerVersions::Solc {
+                compiler_zksolc_version: Some("1.5.6".to_owned()),
+                compiler_solc_version: "zkVM-0.8.24-1.0.1".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn metadata_request_with_mismatched_source() {
+        let request = SourcifyVerificationRequest {
+            sources: HashMap::from([(
+                "src/Counter.sol".to_owned(),
+                "contract Other {}".to_owned(),
+            )]),
+            metadata: metadata(),
+            creation_transaction_hash: None,
+            zksolc_version: None,
+        };
+        let err = request
+            .to_verification_request(Address::repeat_byte(1))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("differs from the one in metadata"), "{err}");
+
+        let request = SourcifyVerificationRequest {
+            sources: HashMap::new(),
+            metadata: metadata(),
+            creation_transaction_hash: None,
+            zksolc_version: None,
+        };
+        let err = request
+            .to_verification_request(Address::repeat_byte(1))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("missing source file"), "{err}");
+    }
+}
@@ -10,9 +10,13 @@ use crate::{u256_to_h256, utils, web3::Bytes, ProtocolVersionId, StorageKey, Sto
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AllSnapshots {
-    /// L1 batch numbers for complete snapshots. Ordered by descending number (i.e., 0th element
+    /// L1 batch numbers for complete full snapshots. Ordered by descending number (i.e., 0th element
     /// corresponds to the newest snapshot).
     pub snapshots_l1_batch_numbers: Vec<L1BatchNumber>,
+    /// L1 batch numbers for complete delta snapshots. Ordered by descending number. Delta snapshots are listed separately
+    /// so that nodes unaware of them never try to recover from a delta snapshot alone.
+    #[serde(default)]
+    pub delta_snapshots_l1_batch_numbers: Vec<L1BatchNumber>,
 }
 
 /// Version of snapshot influencing the format of data stored in GCS.
@@ -33,6 +37,9 @@ pub struct SnapshotMetadata {
     pub version: SnapshotVersion,
     /// L1 batch for the snapshot. The data in the snapshot captures node storage at the end of this batch.
     pub l1_batch_number: L1BatchNumber,
+    /// L1 batch of the base snapshot for delta snapshots; `None` for full snapshots. A delta snapshot only contains
+    /// storage logs and factory deps changed after the base L1 batch.
+    pub base_l1_batch_number: Option<L1BatchNumber>,
     /// Path to the factory dependencies blob.
     pub factory_deps_filepath: String,
     /// Paths to the storage log blobs. Ordered by the chunk ID. If a certain chunk is not produced yet,
@@ -45,6 +52,11 @@ impl SnapshotMetadata {
     pub fn is_complete(&self) -> bool {
         self.storage_logs_filepaths.iter().all(Option::is_some)
     }
+
+    /// Checks whether this is a delta snapshot (i.e., it needs to be applied on top of its base snapshot).
+    pub fn is_delta(&self) -> bool {
+        self.base_l1_batch_number.is_some()
+    }
 }
 
 /// Snapshot data returned by using JSON-RPC API.
@@ -58,6 +70,9 @@ pub struct SnapshotHeader {
     pub l1_batch_number: L1BatchNumber,
     #[serde(rename = "miniblockNumber")] // legacy naming
     pub l2_block_number: L2BlockNumber,
+    /// L1 batch of the base snapshot if this is a delta snapshot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_l1_batch_number: Option<L1BatchNumber>,
     /// Ordered by chunk IDs.
     pub storage_logs_chunks: Vec<SnapshotStorageLogsChunkMetadata>,
     pub factory_deps_filepath: String,
@@ -187,6 +187,34 @@ pub fn compress_with_best_strategy(prev_value: U256, new_value: U256) -> Vec<u8>
         })
 }
 
+/// Decompresses a storage value compressed using [`compress_with_best_strategy()`].
+///
+/// Returns the new value together with the number of bytes of `data` occupied by the compressed value,
+/// or `None` if `data` is not a valid compressed value.
+pub fn decompress_with_strategy(prev_value: U256, data: &[u8]) -> Option<(U256, usize)> {
+    let (&metadata, data) = data.split_first()?;
+    let operation_id = (metadata & 7) as usize;
+    let output_size = (metadata >> 3) as usize;
+
+    if operation_id == 0 {
+        // Uncompressed values (see `CompressionByteNone`) always occupy 32 bytes, and the metadata byte is zero.
+        if output_size != 0 {
+            return None;
+        }
+        let value = data.get(..32)?;
+        return Some((U256::from_big_endian(value), 33));
+    }
+
+    let diff = U256::from_big_endian(data.get(..output_size)?);
+    let new_value = match operation_id {
+        1 => prev_value.overflowing_add(diff).0,
+        2 => prev_value.overflowing_sub(diff).0,
+        3 => diff,
+        _ => return None,
+    };
+    Some((new_value, output_size + 1))
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::{Add, BitAnd, Shr, Sub};
@@ -352,6 +380,37 @@ mod tests {
         assert!((((compressed_val.bits() as f64) / 8f64).ceil() as usize) == 1);
     }
 
+    #[test]
+    fn decompressing_values() {
+        let values = [
+            U256::zero(),
+            U256::one(),
+            U256::from(255438218),
+            U256::from(255438638),
+            U256::MAX,
+            U256::MAX - 1,
+            U256::from(1) << 200,
+            U256::from_big_endian(&[0xab; 32]),
+        ];
+        for prev_value in values {
+            for new_value in values {
+                let compressed = compress_with_best_strategy(prev_value, new_value);
+                let (decompressed, len) = decompress_with_strategy(prev_value, &compressed)
+                    .unwrap_or_else(|| panic!("failed decompressing {compressed:?}"));
+                assert_eq!(decompressed, new_value, "prev_value={prev_value}");
+                assert_eq!(len, compressed.len());
+            }
+        }
+
+        assert_eq!(decompress_with_strategy(U256::zero(), &[]), None);
+        assert_eq!(decompress_with_strategy(U256::zero(), &[0; 10]), None);
+        assert_eq!(decompress_with_strategy(U256::zero(), &[4]), None);
+        assert_eq!(
+            decompress_with_strategy(U256::zero(), &[(2 << 3) | 1, 1]),
+            None
+        );
+    }
+
     fn verify_add_is_none(initial_val: U256, final_val: U256) {
         let compression_add_strategy = CompressionByteAdd {
             prev_value: initial_val,
@@ -108,13 +108,9 @@ impl MainOneshotExecutor {
         self.execution_latency_histogram = Some(histogram);
     }
 
-    fn select_fast_vm_mode(
-        &self,
-        env: &OneshotEnv,
-        tracing_params: &OneshotTracingParams,
-    ) -> FastVmMode {
-        if tracing_params.trace_calls || !is_supported_by_fast_vm(env.system.version) {
-            FastVmMode::Old // the fast VM doesn't support call tracing or old protocol versions
+    fn select_fast_vm_mode(&self, env: &OneshotEnv) -> FastVmMode {
+        if !is_supported_by_fast_vm(env.system.version) {
+            FastVmMode::Old // the fast VM doesn't support old protocol versions
         } else {
             self.fast_vm_mode
         }
@@ -141,7 +137,7 @@ where
             }
         };
         let sandbox = VmSandbox {
-            fast_vm_mode: self.select_fast_vm_mode(&env, &tracing_params),
+            fast_vm_mode: self.select_fast_vm_mode(&env),
             vm_divergence_handler: self.vm_divergence_handler.clone(),
             storage,
             env,
@@ -152,14 +148,29 @@ where
         let current_span = tracing::Span::current();
         tokio::task::spawn_blocking(move || {
             let _entered_span = current_span.entered();
-            sandbox.execute_in_vm(|vm, transaction| {
-                vm.inspect_transaction_with_bytecode_compression(
-                    missed_storage_invocation_limit,
-                    tracing_params,
-                    transaction,
-                    true,
+            if tracing_params.trace_calls {
+                sandbox.execute_in_vm::<_, CallTracingFastTracer<_>, FastValidationTracer>(
+                    |vm, transaction| {
+                        vm.inspect_transaction_with_bytecode_compression(
+                            missed_storage_invocation_limit,
+                            tracing_params,
+                            transaction,
+                            true,
+                        )
+                    },
                 )
-            })
+            } else {
+                sandbox.execute_in_vm::<_, FastStorageLimiter<_>, FastValidationTracer>(
+                    |vm, transaction| {
+                        vm.inspect_transaction_with_bytecode_compression(
+                            missed_storage_invocation_limit,
+                            tracing_params,
+                            transaction,
+                            true,
+                        )
+                    },
+                )
+            }
         })
         .await
         .context("VM execution panicked")
@@ -186,11 +197,7 @@ where
 
         let l1_batch_env = env.l1_batch.clone();
         let sandbox = VmSandbox {
-            fast_vm_mode: if !is_supported_by_fast_vm(env.system.version) {
-                FastVmMode::Old // the fast VM doesn't support old protocol versions
-            } else {
-                self.fast_vm_mode
-            },
+            fast_vm_mode: self.select_fast_vm_mode(&env),
             vm_divergence_handler: self.vm_divergence_handler.clone(),
             storage,
             env,
@@ -243,7 +250,39 @@ enum Vm<S: ReadStorage, Tr, Val> {
     Fast(StoragePtr<StorageView<S>>, FastVmInstance<S, Tr, Val>),
 }
 
-impl<S: ReadStorage> Vm<S, StorageInvocationsTracer<StorageView<S>>, FastValidationTracer> {
+/// Storage invocations limiter for the fast VM.
+type FastStorageLimiter<S> = StorageInvocationsTracer<StorageView<S>>;
+/// Storage invocations limiter combined with the call tracer for the fast VM.
+type CallTracingFastTracer<S> = (FastStorageLimiter<S>, vm_fast::CallTracer);
+
+/// Fast VM tracer used for oneshot execution. Always includes the storage invocations limiter and may trace calls.
+trait OneshotFastTracer<S: ReadStorage>: vm_fast::interface::Tracer + Default {
+    fn new(storage_limiter: FastStorageLimiter<S>) -> Self;
+
+    fn into_call_traces(self) -> Option<Vec<Call>>;
+}
+
+impl<S: ReadStorage> OneshotFastTracer<S> for FastStorageLimiter<S> {
+    fn new(storage_limiter: FastStorageLimiter<S>) -> Self {
+        storage_limiter
+    }
+
+    fn into_call_traces(self) -> Option<Vec<Call>> {
+        None
+    }
+}
+
+impl<S: ReadStorage> OneshotFastTracer<S> for CallTracingFastTracer<S> {
+    fn new(storage_limiter: FastStorageLimiter<S>) -> Self {
+        (storage_limiter, vm_fast::CallTracer::default())
+    }
+
+    fn into_call_traces(self) -> Option<Vec<Call>> {
+        Some(self.1.into_result())
+    }
+}
+
+impl<S: ReadStorage, Tr: OneshotFastTracer<S>> Vm<S, Tr, FastValidationTracer> {
     fn inspect_transaction_with_bytecode_compression(
         &mut self,
         missed_storage_invocation_limit: usize,
@@ -252,52 +291,74 @@ impl<S: ReadStorage> Vm<S, StorageInvocationsTracer<StorageView<S>>, FastValidat
         with_compression: bool,
     ) -> OneshotTransactionExecutionResult {
         let mut calls_result = Arc::<OnceCell<_>>::default();
+        let mut fast_call_traces = None;
         let (compression_result, tx_result) = match self {
             Self::Legacy(vm) => {
                 let mut tracers = Self::create_legacy_tracers(
                     missed_storage_invocation_limit,
                     params.trace_calls.then(|| calls_result.clone()),
                 );
-                vm.inspect_transaction_with_bytecode_compression(&mut tracers, tx, with_compression)
+                let (compression_result, tx_result) = vm
+                    .inspect_transaction_with_bytecode_compression(
+                        &mut tracers,
+                        tx,
+                        with_compression,
+                    );
+                (compression_result.map(drop), tx_result)
             }
             Self::Fast(storage, vm) => {
-                assert!(
-                    !params.trace_calls,
-                    "Call tracing is not supported by fast VM yet"
-                );
                 let legacy_tracers = Self::create_legacy_tracers::<HistoryEnabled>(
                     missed_storage_invocation_limit,
-                    None,
+                    params.trace_calls.then(|| calls_result.clone()),
                 );
-                let tracer =
-                    StorageInvocationsTracer::new(storage.clone(), missed_storage_invocation_limit);
+                let tracer = Tr::new(StorageInvocationsTracer::new(
+                    storage.clone(),
+                    missed_storage_invocation_limit,
+                ));
                 let mut full_tracer = (
                     legacy_tracers.into(),
                     (tracer, FastValidationTracer::default()),
                 );
-                let mut result = vm.inspect_transaction_with_bytecode_compression(
-                    &mut full_tracer,
-                    tx,
-                    with_compression,
-                );
+                let (compression_result, mut tx_result) = vm
+                    .inspect_transaction_with_bytecode_compression(
+                        &mut full_tracer,
+                        tx,
+                        with_compression,
+                    );
+                let compression_result = compression_result.map(drop);
 
                 if let ExecutionResult::Halt {
                     reason: Halt::TracerCustom(msg),
-                } = &mut result.1.result
+                } = &mut tx_result.result
                 {
                     // Patch the halt message to be more specific; the fast VM provides a generic one since it doesn't know
                     // which tracer(s) are run. Here, we do know that the only tracer capable of stopping VM execution is the storage limiter.
                     *msg = "Storage invocations limit reached".to_owned();
                 }
 
-                result
+                let (_, (tracer, _)) = full_tracer;
+                fast_call_traces = tracer.into_call_traces();
+                (compression_result, tx_result)
+            }
+        };
+
+        let legacy_call_traces = Arc::make_mut(&mut calls_result).take().unwrap_or_default();
+        let call_traces = match (self, fast_call_traces) {
+            (Self::Fast(_, FastVmInstance::Fast(_)), Some(fast_call_traces)) => fast_call_traces,
+            (Self::Fast(_, FastVmInstance::Shadowed(vm)), Some(fast_call_traces)) => {
+                vm.get_custom_mut("call_traces", |r| match r {
+                    ShadowMut::Main(_) => legacy_call_traces.as_slice(),
+                    ShadowMut::Shadow(_) => fast_call_traces.as_slice(),
+                });
+                fast_call_traces
             }
+            _ => legacy_call_traces,
         };
 
         OneshotTransactionExecutionResult {
             tx_result: Box::new(tx_result),
-            compression_result: compression_result.map(drop),
-            call_traces: Arc::make_mut(&mut calls_result).take().unwrap_or_default(),
+            compression_result,
+            call_traces,
         }
     }
 
@@ -27,17 +27,13 @@ fn selecting_vm_for_execution() {
             l1_batch: default_l1_batch_env(1),
             current_block: None,
         };
-        let mode = executor.select_fast_vm_mode(&env, &OneshotTracingParams::default());
+        let mode = executor.select_fast_vm_mode(&env);
         assert_matches!(mode, FastVmMode::New);
 
-        // Tracing calls is not supported by the new VM.
-        let mode = executor.select_fast_vm_mode(&env, &OneshotTracingParams { trace_calls: true });
-        assert_matches!(mode, FastVmMode::Old);
-
-        // Old protocol versions are not supported either.
+        // Old protocol versions are not supported by the new VM.
         let mut old_env = env.clone();
         old_env.system.version = ProtocolVersionId::Version22;
-        let mode = executor.select_fast_vm_mode(&old_env, &OneshotTracingParams::default());
+        let mode = executor.select_fast_vm_mode(&old_env);
         assert_matches!(mode, FastVmMode::Old);
     }
 }
@@ -73,6 +69,26 @@ fn setting_up_nonce_and_balance_in_storage() {
 #[test_casing(9, Product((EXEC_MODES, FAST_VM_MODES)))]
 #[tokio::test]
 async fn inspecting_transfer(exec_mode: TxExecutionMode, fast_vm_mode: FastVmMode) {
+    let result = inspect_transfer(exec_mode, fast_vm_mode, OneshotTracingParams::default()).await;
+    assert!(result.call_traces.is_empty());
+}
+
+#[test_casing(9, Product((EXEC_MODES, FAST_VM_MODES)))]
+#[tokio::test]
+async fn inspecting_transfer_with_call_tracing(
+    exec_mode: TxExecutionMode,
+    fast_vm_mode: FastVmMode,
+) {
+    let tracing = OneshotTracingParams { trace_calls: true };
+    let result = inspect_transfer(exec_mode, fast_vm_mode, tracing).await;
+    assert!(!result.call_traces.is_empty());
+}
+
+async fn inspect_transfer(
+    exec_mode: TxExecutionMode,
+    fast_vm_mode: FastVmMode,
+    tracing: OneshotTracingParams,
+) -> OneshotTransactionExecutionResult {
     let tx = create_l2_transaction(1_000_000_000.into(), Nonce(0));
     let mut storage = InMemoryStorage::with_system_contracts();
     storage.set_value(
@@ -92,15 +108,16 @@ async fn inspecting_transfer(exec_mode: TxExecutionMode, fast_vm_mode: FastVmMod
         l1_batch,
     };
     let args = TxExecutionArgs::for_gas_estimate(tx.into());
-    let tracing = OneshotTracingParams::default();
 
     let mut executor = MainOneshotExecutor::new(usize::MAX);
     executor.set_fast_vm_mode(fast_vm_mode);
+    executor.set_divergence_handler(DivergenceHandler::new(|err, _| panic!("{err}")));
     let result = executor
         .inspect_transaction_with_bytecode_compression(storage, env, args, tracing)
         .await
         .unwrap();
-    result.compression_result.unwrap();
-    let exec_result = result.tx_result.result;
+    result.compression_result.as_ref().unwrap();
+    let exec_result = &result.tx_result.result;
     assert!(!exec_result.is_failed(), "{exec_result:?}");
+    result
 }
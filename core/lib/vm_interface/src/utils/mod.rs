@@ -3,7 +3,8 @@
 pub use self::{
     dump::VmDump,
     shadow::{
-        CheckDivergence, DivergenceErrors, DivergenceHandler, ShadowMut, ShadowRef, ShadowVm,
+        CheckDivergence, Divergence, DivergenceErrors, DivergenceHandler, ShadowMut, ShadowRef,
+        ShadowVm,
     },
 };
 
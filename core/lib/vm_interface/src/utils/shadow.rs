@@ -7,6 +7,7 @@ use std::{
     sync::Arc,
 };
 
+use serde::Serialize;
 use zksync_types::{
     Address, StorageKey, StorageLog, StorageLogWithPreviousValue, Transaction, U256,
 };
@@ -607,38 +608,65 @@ where
     }
 }
 
-#[derive(Debug)]
+/// Single divergence between the main and shadow VM outputs.
+#[derive(Debug, Clone, Serialize)]
+pub struct Divergence {
+    /// Path to the diverging field, e.g. `logs.storage_logs`.
+    pub field: String,
+    /// Human-readable diff between the main and shadow values.
+    pub diff: String,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "`{}` mismatch: {}", self.field, self.diff)
+    }
+}
+
+#[derive(Debug, Default)]
 pub struct DivergenceErrors {
-    divergences: Vec<String>,
+    divergences: Vec<Divergence>,
     context: Option<String>,
 }
 
 impl fmt::Display for DivergenceErrors {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let divergences = self
+            .divergences
+            .iter()
+            .map(Divergence::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
         if let Some(context) = &self.context {
             write!(
                 formatter,
-                "VM execution diverged: {context}: [{}]",
-                self.divergences.join(", ")
+                "VM execution diverged: {context}: [{divergences}]"
             )
         } else {
-            write!(
-                formatter,
-                "VM execution diverged: [{}]",
-                self.divergences.join(", ")
-            )
+            write!(formatter, "VM execution diverged: [{divergences}]")
         }
     }
 }
 
 impl DivergenceErrors {
-    fn new() -> Self {
+    /// Creates an empty set of errors.
+    pub fn new() -> Self {
         Self {
             divergences: vec![],
             context: None,
         }
     }
 
+    /// Checks whether there are no divergences.
+    pub fn is_empty(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    /// Returns all recorded divergences.
+    pub fn divergences(&self) -> &[Divergence] {
+        &self.divergences
+    }
+
     /// Extends this instance from another set of errors.
     pub fn extend(&mut self, from: Self) {
         self.divergences.extend(from.divergences);
@@ -649,11 +677,14 @@ impl DivergenceErrors {
         self
     }
 
-    fn check_match<T: fmt::Debug + PartialEq>(&mut self, context: &str, main: &T, shadow: &T) {
+    /// Records a divergence for the specified `context` if `main` and `shadow` values differ.
+    pub fn check_match<T: fmt::Debug + PartialEq>(&mut self, context: &str, main: &T, shadow: &T) {
         if main != shadow {
             let comparison = pretty_assertions::Comparison::new(main, shadow);
-            let err = format!("`{context}` mismatch: {comparison}");
-            self.divergences.push(err);
+            self.divergences.push(Divergence {
+                field: context.to_owned(),
+                diff: comparison.to_string(),
+            });
         }
     }
 
@@ -0,0 +1,629 @@
+//! L2 client failing over between several upstreams (e.g., the main node and trusted external nodes).
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use jsonrpsee::core::{
+    client::{BatchResponse, ClientT, Error},
+    params::BatchRequestBuilder,
+    traits::ToRpcParams,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::watch;
+use zksync_types::{L2BlockNumber, L2ChainId, H256};
+
+use super::{boxed::RawParams, DynClient, ForWeb3Network, TaggedClient, L2};
+use crate::namespaces::{EnNamespaceClient, EthNamespaceClient};
+
+/// Result of checking an upstream for consistency with the expected chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpstreamConsistency {
+    /// The upstream wasn't checked yet, or the check has failed because of a transient error.
+    Unchecked,
+    /// The upstream has the expected L2 chain ID and genesis.
+    Consistent,
+    /// The upstream has an unexpected L2 chain ID or genesis. Such an upstream is never used.
+    Inconsistent,
+}
+
+/// Status of a single upstream as seen by [`FailoverClient`].
+#[derive(Debug, Clone, Serialize)]
+pub struct UpstreamStatus {
+    /// Redacted upstream URL.
+    pub url: String,
+    pub consistency: UpstreamConsistency,
+    /// Whether the last health check or request to the upstream has succeeded.
+    pub is_healthy: bool,
+    /// Latest L2 block reported by the upstream during the last health check.
+    pub latest_block: Option<L2BlockNumber>,
+}
+
+#[derive(Debug)]
+struct SharedState {
+    statuses: Mutex<Vec<UpstreamStatus>>,
+}
+
+/// L2 JSON-RPC client distributing requests among several upstreams with failover.
+///
+/// Upstreams are ordered by priority; the first upstream is considered to be the main node. Requests are sent
+/// to the highest-priority upstream that is healthy and doesn't lag behind the other upstreams by more than
+/// the configured number of L2 blocks. If a request fails because of a transport error (e.g., the upstream is
+/// unreachable or timed out), it's transparently retried with the next upstream. Application-level errors
+/// (i.e., error responses returned by the upstream) are returned to the caller as is.
+///
+/// Upstreams are checked for having the expected L2 chain ID and genesis by [`Self::run_health_checks()`],
+/// which also tracks upstream health and lag. The expected genesis is provided by the caller (e.g., taken from
+/// the local node storage), so that all upstreams including the main node are checked against it. Upstreams other than the main node are only used once they have passed
+/// this check; for this reason, they must have the `en` namespace enabled. An upstream failing the check is never used.
+#[derive(Clone)]
+pub struct FailoverClient {
+    upstreams: Vec<Box<DynClient<L2>>>,
+    state: Arc<SharedState>,
+    l2_chain_id: L2ChainId,
+    max_lag: u32,
+    component_name: &'static str,
+}
+
+impl fmt::Debug for FailoverClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("FailoverClient")
+            .field("upstreams", &self.upstreams)
+            .field("l2_chain_id", &self.l2_chain_id)
+            .field("max_lag", &self.max_lag)
+            .field("component_name", &self.component_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FailoverClient {
+    /// Creates a client for the provided upstreams, which are specified in the priority order together with
+    /// their (redacted) URLs used for logging.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upstreams` is empty.
+    pub fn new(
+        upstreams: Vec<(String, Box<DynClient<L2>>)>,
+        l2_chain_id: L2ChainId,
+        max_lag: u32,
+    ) -> Self {
+        assert!(!upstreams.is_empty(), "no upstreams provided");
+
+        let (statuses, upstreams) = upstreams
+            .into_iter()
+            .map(|(url, client)| {
+                let status = UpstreamStatus {
+                    url,
+                    consistency: UpstreamConsistency::Unchecked,
+                    is_healthy: true,
+                    latest_block: None,
+                };
+                (status, client)
+            })
+            .unzip();
+        Self {
+            upstreams,
+            state: Arc::new(SharedState {
+                statuses: Mutex::new(statuses),
+            }),
+            l2_chain_id,
+            max_lag,
+            component_name: "",
+        }
+    }
+
+    /// Returns current statuses of all upstreams in the priority order.
+    pub fn upstream_statuses(&self) -> Vec<UpstreamStatus> {
+        self.state.statuses.lock().unwrap().clone()
+    }
+
+    /// Returns indices of upstreams that can be used for a request, with the most preferred upstream first.
+    fn candidates(&self) -> Vec<usize> {
+        let statuses = self.state.statuses.lock().unwrap();
+        let is_usable = |i: usize| match statuses[i].consistency {
+            UpstreamConsistency::Consistent => true,
+            // The main node is trusted until proven otherwise.
+            UpstreamConsistency::Unchecked => i == 0,
+            UpstreamConsistency::Inconsistent => false,
+        };
+        let max_block = (0..statuses.len())
+            .filter(|&i| is_usable(i) && statuses[i].is_healthy)
+            .filter_map(|i| statuses[i].latest_block)
+            .max();
+
+        let mut candidates: Vec<_> = (0..statuses.len()).filter(|&i| is_usable(i)).collect();
+        candidates.sort_by_key(|&i| {
+            let status = &statuses[i];
+            let is_lagging = match (max_block, status.latest_block) {
+                (Some(max_block), Some(block)) => {
+                    max_block.0.saturating_sub(block.0) > self.max_lag
+                }
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+            (!status.is_healthy, is_lagging, i)
+        });
+        candidates
+    }
+
+    fn set_healthy(&self, idx: usize, is_healthy: bool) {
+        let mut statuses = self.state.statuses.lock().unwrap();
+        let status = &mut statuses[idx];
+        if status.is_healthy && !is_healthy {
+            tracing::warn!(
+                component = self.component_name,
+                "Upstream #{idx} ({}) has become unhealthy",
+                status.url
+            );
+        } else if !status.is_healthy && is_healthy {
+            tracing::info!(
+                component = self.component_name,
+                "Upstream #{idx} ({}) has become healthy",
+                status.url
+            );
+        }
+        status.is_healthy = is_healthy;
+    }
+
+    /// Checks whether an error warrants retrying the call with another upstream.
+    fn is_failover_error(err: &Error) -> bool {
+        matches!(
+            err,
+            Error::Transport(_) | Error::RestartNeeded(_) | Error::RequestTimeout
+        )
+    }
+
+    async fn call_with_failover<'a, T, F>(&'a self, call: F) -> Result<T, Error>
+    where
+        F: Fn(&'a DynClient<L2>) -> BoxFuture<'a, Result<T, Error>>,
+    {
+        let candidates = self.candidates();
+        let Some((&last_idx, _)) = candidates.split_last() else {
+            return Err(Error::Custom(
+                "no consistent upstreams are available".to_owned(),
+            ));
+        };
+
+        for idx in candidates {
+            match call(self.upstreams[idx].as_ref()).await {
+                Err(err) if Self::is_failover_error(&err) && idx != last_idx => {
+                    tracing::info!(
+                        component = self.component_name,
+                        "Request to upstream #{idx} failed, failing over: {err}"
+                    );
+                    self.set_healthy(idx, false);
+                }
+                Err(err) => {
+                    if Self::is_failover_error(&err) {
+                        self.set_healthy(idx, false);
+                    }
+                    return Err(err);
+                }
+                Ok(value) => return Ok(value),
+            }
+        }
+        unreachable!("loop above always returns on the last candidate")
+    }
+
+    async fn check_upstream(&self, idx: usize, expected_genesis_root_hash: H256) {
+        let client = self.upstreams[idx].as_ref();
+        let consistency = self.state.statuses.lock().unwrap()[idx].consistency;
+        if consistency == UpstreamConsistency::Inconsistent {
+            return;
+        }
+
+        if consistency == UpstreamConsistency::Unchecked {
+            match self
+                .check_consistency(client, expected_genesis_root_hash)
+                .await
+            {
+                Ok(consistency) => {
+                    let mut statuses = self.state.statuses.lock().unwrap();
+                    let status = &mut statuses[idx];
+                    status.consistency = consistency;
+                    if consistency == UpstreamConsistency::Inconsistent {
+                        tracing::error!(
+                            component = self.component_name,
+                            "Upstream #{idx} ({}) is inconsistent with the expected chain; it will not be used",
+                            status.url
+                        );
+                        return;
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        component = self.component_name,
+                        "Failed checking consistency of upstream #{idx}: {err}"
+                    );
+                }
+            }
+        }
+
+        match client.get_block_number().await {
+            Ok(block_number) => {
+                self.state.statuses.lock().unwrap()[idx].latest_block =
+                    Some(L2BlockNumber(block_number.as_u32()));
+                self.set_healthy(idx, true);
+            }
+            Err(err) => {
+                tracing::warn!(
+                    component = self.component_name,
+                    "Health check for upstream #{idx} failed: {err}"
+                );
+                self.set_healthy(idx, false);
+            }
+        }
+    }
+
+    async fn check_consistency(
+        &self,
+        client: &DynClient<L2>,
+        expected_genesis_root_hash: H256,
+    ) -> Result<UpstreamConsistency, Error> {
+        let chain_id = client.chain_id().await?;
+        if chain_id.as_u64() != self.l2_chain_id.as_u64() {
+            tracing::error!(
+                component = self.component_name,
+                "Upstream has unexpected L2 chain ID {chain_id}; expected {}",
+                self.l2_chain_id.as_u64()
+            );
+            return Ok(UpstreamConsistency::Inconsistent);
+        }
+
+        let genesis_root_hash = client.genesis_config().await?.genesis_root_hash;
+        if genesis_root_hash != expected_genesis_root_hash {
+            tracing::error!(
+                component = self.component_name,
+                "Upstream has unexpected genesis root hash {genesis_root_hash:?}; expected {expected_genesis_root_hash:?}"
+            );
+            return Ok(UpstreamConsistency::Inconsistent);
+        }
+        Ok(UpstreamConsistency::Consistent)
+    }
+
+    /// Periodically checks all upstreams for consistency with the expected genesis, health and lag. Should be run
+    /// as a background task for the client to pick upstreams other than the main node.
+    pub async fn run_health_checks(
+        self,
+        interval: Duration,
+        expected_genesis_root_hash: H256,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let this = Self {
+            upstreams: self
+                .upstreams
+                .iter()
+                .map(|client| client.clone().for_component("upstream_health_check"))
+                .collect(),
+            component_name: "upstream_health_check",
+            ..self
+        };
+
+        while !*stop_receiver.borrow_and_update() {
+            for idx in 0..this.upstreams.len() {
+                this.check_upstream(idx, expected_genesis_root_hash).await;
+            }
+            if let Some(&idx) = this.candidates().first() {
+                tracing::debug!("Preferred upstream: #{idx}");
+            }
+
+            tokio::time::timeout(interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop request received, upstream health checks are shut down");
+        Ok(())
+    }
+}
+
+impl ForWeb3Network for FailoverClient {
+    type Net = L2;
+
+    fn network(&self) -> Self::Net {
+        self.l2_chain_id.into()
+    }
+
+    fn component(&self) -> &'static str {
+        self.component_name
+    }
+}
+
+impl TaggedClient for FailoverClient {
+    fn set_component(&mut self, component_name: &'static str) {
+        self.component_name = component_name;
+        for upstream in &mut self.upstreams {
+            *upstream = upstream.clone().for_component(component_name);
+        }
+    }
+}
+
+#[async_trait]
+impl ClientT for FailoverClient {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        let params = params.to_rpc_params()?;
+        self.call_with_failover(|client| {
+            Box::pin(client.generic_notification(method, RawParams(params.clone())))
+        })
+        .await
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = params.to_rpc_params()?;
+        let raw_response = self
+            .call_with_failover(|client| {
+                Box::pin(client.generic_request(method, RawParams(params.clone())))
+            })
+            .await?;
+        serde_json::from_value(raw_response).map_err(Error::ParseError)
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, Error>
+    where
+        R: DeserializeOwned + fmt::Debug + 'a,
+    {
+        let raw_responses = self
+            .call_with_failover(|client| Box::pin(client.generic_batch_request(batch.clone())))
+            .await?;
+
+        let mut successful_calls = 0;
+        let mut failed_calls = 0;
+        let mut responses = Vec::with_capacity(raw_responses.len());
+        for raw_response in raw_responses {
+            responses.push(match raw_response {
+                Ok(json) => {
+                    successful_calls += 1;
+                    Ok(serde_json::from_value::<R>(json)?)
+                }
+                Err(err) => {
+                    failed_calls += 1;
+                    Err(err)
+                }
+            })
+        }
+        Ok(BatchResponse::new(
+            successful_calls,
+            responses,
+            failed_calls,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use zksync_types::{
+        commitment::L1BatchCommitmentMode, protocol_version::ProtocolSemanticVersion, Address,
+        L1ChainId, U64,
+    };
+
+    use super::*;
+    use crate::{client::MockClient, types::GenesisConfigDto};
+
+    const GENESIS_ROOT_HASH: H256 = H256([1; 32]);
+
+    fn mock_genesis_config(genesis_root_hash: H256) -> GenesisConfigDto {
+        GenesisConfigDto {
+            protocol_version: ProtocolSemanticVersion::default(),
+            genesis_root_hash,
+            rollup_last_leaf_index: 26,
+            genesis_commitment: H256::zero(),
+            bootloader_hash: H256::zero(),
+            default_aa_hash: H256::zero(),
+            evm_emulator_hash: None,
+            l1_chain_id: L1ChainId(9),
+            l2_chain_id: L2ChainId::default(),
+            snark_wrapper_vk_hash: H256::zero(),
+            fflonk_snark_wrapper_vk_hash: None,
+            fee_account: Address::zero(),
+            dummy_verifier: false,
+            l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
+        }
+    }
+
+    fn mock_upstream(
+        chain_id: u64,
+        block_number: u64,
+        is_available: bool,
+        calls: Arc<AtomicUsize>,
+    ) -> Box<DynClient<L2>> {
+        mock_upstream_with_genesis(
+            chain_id,
+            GENESIS_ROOT_HASH,
+            block_number,
+            is_available,
+            calls,
+        )
+    }
+
+    fn mock_upstream_with_genesis(
+        chain_id: u64,
+        genesis_root_hash: H256,
+        block_number: u64,
+        is_available: bool,
+        calls: Arc<AtomicUsize>,
+    ) -> Box<DynClient<L2>> {
+        let client = MockClient::builder(L2::default())
+            .method("eth_chainId", move || Ok(U64::from(chain_id)))
+            .method("en_genesisConfig", move || {
+                Ok(mock_genesis_config(genesis_root_hash))
+            })
+            .method("eth_blockNumber", move || {
+                calls.fetch_add(1, Ordering::Relaxed);
+                if is_available {
+                    Ok(U64::from(block_number))
+                } else {
+                    Err(Error::RequestTimeout)
+                }
+            })
+            .build();
+        Box::new(client)
+    }
+
+    #[tokio::test]
+    async fn failing_over_on_transport_errors() {
+        let main_calls = Arc::new(AtomicUsize::new(0));
+        let fallback_calls = Arc::new(AtomicUsize::new(0));
+        let client = FailoverClient::new(
+            vec![
+                (
+                    "main".into(),
+                    mock_upstream(270, 10, false, main_calls.clone()),
+                ),
+                (
+                    "fallback".into(),
+                    mock_upstream(270, 10, true, fallback_calls.clone()),
+                ),
+            ],
+            L2ChainId::default(),
+            5,
+        );
+        // Mark the fallback upstream as consistent, as if it has passed health checks.
+        client.state.statuses.lock().unwrap()[1].consistency = UpstreamConsistency::Consistent;
+
+        let block_number = client.get_block_number().await.unwrap();
+        assert_eq!(block_number, 10.into());
+        assert_eq!(main_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(fallback_calls.load(Ordering::Relaxed), 1);
+        assert!(!client.upstream_statuses()[0].is_healthy);
+
+        // The unhealthy main node should not be queried until it's healthy again.
+        client.get_block_number().await.unwrap();
+        assert_eq!(main_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(fallback_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn unchecked_upstreams_are_not_used() {
+        let fallback_calls = Arc::new(AtomicUsize::new(0));
+        let client = FailoverClient::new(
+            vec![
+                ("main".into(), mock_upstream(270, 10, false, Arc::default())),
+                (
+                    "fallback".into(),
+                    mock_upstream(270, 10, true, fallback_calls.clone()),
+                ),
+            ],
+            L2ChainId::default(),
+            5,
+        );
+
+        let err = client.get_block_number().await.unwrap_err();
+        assert!(matches!(err, Error::RequestTimeout), "{err:?}");
+        assert_eq!(fallback_calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn selecting_upstream_by_lag() {
+        let main_calls = Arc::new(AtomicUsize::new(0));
+        let client = FailoverClient::new(
+            vec![
+                (
+                    "main".into(),
+                    mock_upstream(270, 10, true, main_calls.clone()),
+                ),
+                (
+                    "fallback".into(),
+                    mock_upstream(270, 100, true, Arc::default()),
+                ),
+            ],
+            L2ChainId::default(),
+            5,
+        );
+        {
+            let mut statuses = client.state.statuses.lock().unwrap();
+            statuses[0].latest_block = Some(L2BlockNumber(10));
+            statuses[1].latest_block = Some(L2BlockNumber(100));
+            statuses[1].consistency = UpstreamConsistency::Consistent;
+        }
+
+        let block_number = client.get_block_number().await.unwrap();
+        assert_eq!(block_number, 100.into());
+        assert_eq!(main_calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn upstream_with_wrong_chain_id_is_inconsistent() {
+        let client = FailoverClient::new(
+            vec![("main".into(), mock_upstream(1, 10, true, Arc::default()))],
+            L2ChainId::default(),
+            5,
+        );
+        client.check_upstream(0, GENESIS_ROOT_HASH).await;
+        let status = &client.upstream_statuses()[0];
+        assert_eq!(status.consistency, UpstreamConsistency::Inconsistent);
+        assert_eq!(status.latest_block, None);
+        client.get_block_number().await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn upstreams_are_checked_against_expected_genesis() {
+        let main_calls = Arc::new(AtomicUsize::new(0));
+        let fallback_calls = Arc::new(AtomicUsize::new(0));
+        let client = FailoverClient::new(
+            vec![
+                (
+                    "main".into(),
+                    mock_upstream_with_genesis(
+                        270,
+                        H256::repeat_byte(2),
+                        10,
+                        true,
+                        main_calls.clone(),
+                    ),
+                ),
+                (
+                    "fallback".into(),
+                    mock_upstream(270, 10, true, fallback_calls.clone()),
+                ),
+                (
+                    "other_fallback".into(),
+                    mock_upstream_with_genesis(270, H256::repeat_byte(3), 10, true, Arc::default()),
+                ),
+            ],
+            L2ChainId::default(),
+            5,
+        );
+        for idx in 0..3 {
+            client.check_upstream(idx, GENESIS_ROOT_HASH).await;
+        }
+
+        // The highest-priority upstream must not define the expected genesis.
+        let consistencies: Vec<_> = client
+            .upstream_statuses()
+            .into_iter()
+            .map(|status| status.consistency)
+            .collect();
+        assert_eq!(
+            consistencies,
+            [
+                UpstreamConsistency::Inconsistent,
+                UpstreamConsistency::Consistent,
+                UpstreamConsistency::Inconsistent
+            ]
+        );
+
+        let block_number = client.get_block_number().await.unwrap();
+        assert_eq!(block_number, 10.into());
+        assert_eq!(main_calls.load(Ordering::Relaxed), 0);
+        // One call is made during the health check, and another one is the request above.
+        assert_eq!(fallback_calls.load(Ordering::Relaxed), 2);
+    }
+}
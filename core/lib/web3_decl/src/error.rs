@@ -14,7 +14,7 @@ use std::{
 use jsonrpsee::{core::ClientError, types::error::ErrorCode};
 use pin_project_lite::pin_project;
 use thiserror::Error;
-use zksync_types::{api::SerializationTransactionError, L1BatchNumber, L2BlockNumber};
+use zksync_types::{api::SerializationTransactionError, L1BatchNumber, L2BlockNumber, H256};
 
 /// Server-side representation of the RPC error.
 #[derive(Debug, Error)]
@@ -25,6 +25,8 @@ pub enum Web3Error {
     PrunedBlock(L2BlockNumber),
     #[error("L1 batch with such an ID is pruned; the first retained L1 batch is {0}")]
     PrunedL1Batch(L1BatchNumber),
+    #[error("Transaction {0:?} is pruned and is not covered by node retention rules")]
+    PrunedTransaction(H256),
     #[error("{}", _0.as_ref())]
     ProxyError(#[from] EnrichedClientError),
     #[error("{0}")]
@@ -0,0 +1,47 @@
+#[cfg_attr(not(feature = "server"), allow(unused_imports))]
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use zksync_types::H256;
+
+use crate::client::{ForWeb3Network, L2};
+
+/// Administrative RPCs for node operators. These RPCs are served on a separate, authenticated server
+/// and are never exposed on the public API servers.
+#[cfg_attr(
+    feature = "server",
+    rpc(server, client, namespace = "admin", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+#[cfg_attr(
+    not(feature = "server"),
+    rpc(client, namespace = "admin", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+pub trait AdminNamespace {
+    /// Stops the state keeper from taking new transactions from the mempool.
+    #[method(name = "pauseStateKeeper")]
+    async fn pause_state_keeper(&self) -> RpcResult<()>;
+
+    #[method(name = "resumeStateKeeper")]
+    async fn resume_state_keeper(&self) -> RpcResult<()>;
+
+    /// Stops `eth_tx_aggregator` from creating new L1 transactions. The setting takes precedence over
+    /// `eth_sender.tx_aggregation_paused` in the config file until the node is restarted.
+    #[method(name = "pauseTxAggregation")]
+    async fn pause_tx_aggregation(&self) -> RpcResult<()>;
+
+    /// Resumes `eth_tx_aggregator` after [`Self::pause_tx_aggregation()`]. Like pausing, the setting takes precedence
+    /// over the config file until the node is restarted.
+    #[method(name = "resumeTxAggregation")]
+    async fn resume_tx_aggregation(&self) -> RpcResult<()>;
+
+    /// Requests the state keeper to seal the current L1 batch.
+    #[method(name = "sealL1Batch")]
+    async fn seal_l1_batch(&self) -> RpcResult<()>;
+
+    /// Evicts a transaction from the mempool and marks it as rejected. Fails if the transaction is not in the mempool.
+    #[method(name = "evictTransaction")]
+    async fn evict_transaction(&self, tx_hash: H256) -> RpcResult<()>;
+
+    /// Returns health of the node components in the same format as the healthcheck server.
+    #[method(name = "health")]
+    async fn health(&self) -> RpcResult<serde_json::Value>;
+}
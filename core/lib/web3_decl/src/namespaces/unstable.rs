@@ -3,11 +3,11 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
     api::{
-        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, TeeProof,
-        TransactionExecutionInfo,
+        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, StateDiff,
+        TeeFinalization, TeeProof, TransactionExecutionInfo,
     },
     tee_types::TeeType,
-    L1BatchNumber, L2ChainId, H256,
+    L1BatchNumber, L2BlockNumber, L2ChainId, H256,
 };
 
 use crate::client::{ForWeb3Network, L2};
@@ -35,6 +35,13 @@ pub trait UnstableNamespace {
         tee_type: Option<TeeType>,
     ) -> RpcResult<Vec<TeeProof>>;
 
+    /// Returns TEE finalization info for the specified L1 batch, or `null` if the batch is not TEE-finalized.
+    #[method(name = "getTeeFinalization")]
+    async fn tee_finalization(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> RpcResult<Option<TeeFinalization>>;
+
     #[method(name = "getChainLogProof")]
     async fn get_chain_log_proof(
         &self,
@@ -59,4 +66,24 @@ pub trait UnstableNamespace {
 
     #[method(name = "gatewayMigrationStatus")]
     async fn gateway_migration_status(&self) -> RpcResult<GatewayMigrationStatus>;
+
+    /// Returns a page of the state diff for the specified L2 block. `cursor` is the `nextCursor` value
+    /// from the previous page; `limit` caps the number of storage changes on the page.
+    #[method(name = "getL2BlockStateDiff")]
+    async fn get_l2_block_state_diff(
+        &self,
+        block_number: L2BlockNumber,
+        cursor: Option<H256>,
+        limit: Option<usize>,
+    ) -> RpcResult<Option<StateDiff>>;
+
+    /// Returns a page of the state diff for the specified L1 batch. Pagination works the same way as
+    /// for `unstable_getL2BlockStateDiff`.
+    #[method(name = "getL1BatchStateDiff")]
+    async fn get_l1_batch_state_diff(
+        &self,
+        batch_number: L1BatchNumber,
+        cursor: Option<H256>,
+        limit: Option<usize>,
+    ) -> RpcResult<Option<StateDiff>>;
 }
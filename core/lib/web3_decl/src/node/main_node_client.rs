@@ -9,18 +9,33 @@ use zksync_node_framework::{
 };
 use zksync_types::{url::SensitiveUrl, L2ChainId};
 
-use super::resources::MainNodeClientResource;
+use super::resources::{FailoverClientResource, MainNodeClientResource};
 use crate::{
-    client::{Client, DynClient, L2},
+    client::{Client, DynClient, FailoverClient, L2},
     namespaces::EthNamespaceClient,
 };
 
 /// Wiring layer for main node client.
+///
+/// If fallback upstreams are configured, the provided client is a [`FailoverClient`] with the main node
+/// as the highest-priority upstream. Upstream health checks must be run by the consumer of `FailoverClientResource`,
+/// since they require the genesis root hash of the local node.
+///
+/// ## Requests resources
+///
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds resources
+///
+/// - `MainNodeClientResource`
+/// - `FailoverClientResource` (if fallback upstreams are configured)
 #[derive(Debug)]
 pub struct MainNodeClientLayer {
     url: SensitiveUrl,
     rate_limit_rps: NonZeroUsize,
     l2_chain_id: L2ChainId,
+    fallback_urls: Vec<SensitiveUrl>,
+    max_upstream_lag: u32,
 }
 
 #[derive(Debug, FromContext)]
@@ -32,6 +47,7 @@ pub struct Input {
 #[derive(Debug, IntoContext)]
 pub struct Output {
     pub main_node_client: MainNodeClientResource,
+    pub failover_client: Option<FailoverClientResource>,
 }
 
 impl MainNodeClientLayer {
@@ -40,8 +56,27 @@ impl MainNodeClientLayer {
             url,
             rate_limit_rps,
             l2_chain_id,
+            fallback_urls: vec![],
+            max_upstream_lag: 0,
         }
     }
+
+    /// Adds fallback upstreams (e.g., trusted external nodes) used if the main node is unavailable or lags
+    /// behind them by more than `max_lag` L2 blocks.
+    pub fn with_fallback_upstreams(mut self, urls: Vec<SensitiveUrl>, max_lag: u32) -> Self {
+        self.fallback_urls = urls;
+        self.max_upstream_lag = max_lag;
+        self
+    }
+
+    fn build_client(&self, url: SensitiveUrl) -> anyhow::Result<Box<DynClient<L2>>> {
+        let client = Client::http(url)
+            .context("failed creating JSON-RPC client for main node")?
+            .for_network(self.l2_chain_id.into())
+            .with_allowed_requests_per_second(self.rate_limit_rps)
+            .build();
+        Ok(Box::new(client))
+    }
 }
 
 #[async_trait::async_trait]
@@ -54,35 +89,51 @@ impl WiringLayer for MainNodeClientLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
-        let main_node_client = Client::http(self.url)
-            .context("failed creating JSON-RPC client for main node")?
-            .for_network(self.l2_chain_id.into())
-            .with_allowed_requests_per_second(self.rate_limit_rps)
-            .build();
-
-        let client = Box::new(main_node_client) as Box<DynClient<L2>>;
+        let main_node_client = self.build_client(self.url.clone())?;
+        let (client, failover_client) = if self.fallback_urls.is_empty() {
+            (main_node_client, None)
+        } else {
+            // Only URL origins are logged, since other URL parts may contain sensitive data (e.g., API keys).
+            let mut upstreams = vec![(url_origin(&self.url), main_node_client)];
+            for url in &self.fallback_urls {
+                upstreams.push((url_origin(url), self.build_client(url.clone())?));
+            }
+            tracing::info!(
+                "Using {} fallback upstream(s) for the main node client with max lag {} L2 blocks",
+                self.fallback_urls.len(),
+                self.max_upstream_lag
+            );
+            let client = FailoverClient::new(upstreams, self.l2_chain_id, self.max_upstream_lag);
+            (Box::new(client.clone()) as Box<DynClient<L2>>, Some(client))
+        };
 
         // Insert healthcheck
+        let health_check = MainNodeHealthCheck {
+            client: client.clone().for_component("main_node_health_check"),
+            failover_client: failover_client.clone(),
+        };
         input
             .app_health
             .0
-            .insert_custom_component(Arc::new(MainNodeHealthCheck::from(client.clone())))
+            .insert_custom_component(Arc::new(health_check))
             .map_err(WiringError::internal)?;
 
         Ok(Output {
             main_node_client: client.into(),
+            failover_client: failover_client.map(FailoverClientResource),
         })
     }
 }
 
+fn url_origin(url: &SensitiveUrl) -> String {
+    url.expose_url().origin().ascii_serialization()
+}
+
 /// Main node health check.
 #[derive(Debug)]
-struct MainNodeHealthCheck(Box<DynClient<L2>>);
-
-impl From<Box<DynClient<L2>>> for MainNodeHealthCheck {
-    fn from(client: Box<DynClient<L2>>) -> Self {
-        Self(client.for_component("main_node_health_check"))
-    }
+struct MainNodeHealthCheck {
+    client: Box<DynClient<L2>>,
+    failover_client: Option<FailoverClient>,
 }
 
 #[async_trait]
@@ -92,13 +143,24 @@ impl CheckHealth for MainNodeHealthCheck {
     }
 
     async fn check_health(&self) -> Health {
-        if let Err(err) = self.0.get_block_number().await {
+        let upstreams = self
+            .failover_client
+            .as_ref()
+            .map(FailoverClient::upstream_statuses);
+        if let Err(err) = self.client.get_block_number().await {
             tracing::warn!("Health-check call to main node HTTP RPC failed: {err}");
             let details = serde_json::json!({
                 "error": err.to_string(),
+                "upstreams": upstreams,
             });
             return Health::from(HealthStatus::NotReady).with_details(details);
         }
-        HealthStatus::Ready.into()
+
+        let health = Health::from(HealthStatus::Ready);
+        if let Some(upstreams) = upstreams {
+            health.with_details(serde_json::json!({ "upstreams": upstreams }))
+        } else {
+            health
+        }
     }
 }
@@ -2,8 +2,8 @@ pub use self::{
     main_node_client::MainNodeClientLayer,
     query_eth_client::QueryEthClientLayer,
     resources::{
-        EthInterfaceResource, L2InterfaceResource, MainNodeClientResource, SettlementLayerClient,
-        SettlementModeResource,
+        EthInterfaceResource, FailoverClientResource, L2InterfaceResource, MainNodeClientResource,
+        SettlementLayerClient, SettlementModeResource,
     },
     settlement_layer_client::SettlementLayerClientLayer,
 };
@@ -1,7 +1,7 @@
 use zksync_node_framework::resource::Resource;
 use zksync_types::settlement::{SettlementLayer, WorkingSettlementLayer};
 
-use crate::client::{DynClient, L1, L2};
+use crate::client::{DynClient, FailoverClient, L1, L2};
 
 #[derive(Debug, Clone)]
 pub struct SettlementModeResource(WorkingSettlementLayer);
@@ -79,3 +79,14 @@ impl<T: Into<Box<DynClient<L2>>>> From<T> for MainNodeClientResource {
         Self(client.into())
     }
 }
+
+/// A resource that provides the [`FailoverClient`] used as the main node client if fallback upstreams
+/// are configured.
+#[derive(Debug, Clone)]
+pub struct FailoverClientResource(pub FailoverClient);
+
+impl Resource for FailoverClientResource {
+    fn name() -> String {
+        "external_node/main_node_failover_client".into()
+    }
+}
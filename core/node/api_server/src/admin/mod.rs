@@ -0,0 +1,197 @@
+//! Admin JSON-RPC API. Exposes the `admin` namespace allowing node operators to control node components
+//! (e.g., pause the state keeper or force-seal the current L1 batch).
+//!
+//! The API is served on a separate HTTP listener and requires each request to be authenticated
+//! with a bearer token. All method calls are written to the audit log (the `admin_audit` tracing target).
+
+use std::{collections::HashSet, marker::PhantomData, net::SocketAddr, sync::Arc};
+
+use anyhow::Context as _;
+use http::{header, HeaderValue, Request, Response, StatusCode};
+use tokio::sync::watch;
+use tower_http::validate_request::{ValidateRequest, ValidateRequestHeaderLayer};
+use zksync_health_check::AppHealthCheck;
+use zksync_shared_resources::dynamic_config::DynamicConfigHandle;
+use zksync_state_keeper::StateKeeperControl;
+use zksync_web3_decl::{
+    jsonrpsee::server::{RpcServiceBuilder, ServerBuilder},
+    namespaces::AdminNamespaceServer,
+};
+
+use self::namespace::AdminNamespace;
+use crate::web3::backend_jsonrpsee::{MetadataLayer, MethodTracer};
+
+mod namespace;
+
+/// Checks that the request has the expected bearer token. Unauthorized requests are logged to the audit log.
+struct BearerAuth<ResBody> {
+    expected: HeaderValue,
+    _body: PhantomData<fn() -> ResBody>,
+}
+
+impl<ResBody> Clone for BearerAuth<ResBody> {
+    fn clone(&self) -> Self {
+        Self {
+            expected: self.expected.clone(),
+            _body: PhantomData,
+        }
+    }
+}
+
+impl<ResBody> BearerAuth<ResBody> {
+    fn new(token: &str) -> anyhow::Result<Self> {
+        let mut expected = HeaderValue::from_str(&format!("Bearer {token}"))
+            .context("admin API auth token is not a valid header value")?;
+        expected.set_sensitive(true);
+        Ok(Self {
+            expected,
+            _body: PhantomData,
+        })
+    }
+
+    /// Compares the provided header value with the expected one in constant time (w.r.t. value contents).
+    fn is_authorized(&self, value: Option<&HeaderValue>) -> bool {
+        let Some(value) = value else {
+            return false;
+        };
+        let (expected, actual) = (self.expected.as_bytes(), value.as_bytes());
+        expected.len() == actual.len()
+            && expected
+                .iter()
+                .zip(actual)
+                .fold(0_u8, |acc, (x, y)| acc | (x ^ y))
+                == 0
+    }
+}
+
+impl<B, ResBody: Default> ValidateRequest<B> for BearerAuth<ResBody> {
+    type ResponseBody = ResBody;
+
+    fn validate(&mut self, request: &mut Request<B>) -> Result<(), Response<ResBody>> {
+        if self.is_authorized(request.headers().get(header::AUTHORIZATION)) {
+            return Ok(());
+        }
+        tracing::warn!(
+            target: namespace::AUDIT_TARGET,
+            uri = %request.uri(),
+            "Rejected unauthorized admin API request"
+        );
+        let mut response = Response::new(ResBody::default());
+        *response.status_mut() = StatusCode::UNAUTHORIZED;
+        Err(response)
+    }
+}
+
+/// Admin API server.
+#[derive(Debug)]
+pub struct AdminApiServer {
+    bind_addr: SocketAddr,
+    auth_token: String,
+    namespace: AdminNamespace,
+}
+
+impl AdminApiServer {
+    pub fn new(bind_addr: SocketAddr, auth_token: String, app_health: Arc<AppHealthCheck>) -> Self {
+        Self {
+            bind_addr,
+            auth_token,
+            namespace: AdminNamespace::new(app_health),
+        }
+    }
+
+    /// Enables state keeper–related methods (pausing, forced L1 batch sealing, transaction eviction).
+    pub fn with_state_keeper_control(mut self, control: StateKeeperControl) -> Self {
+        self.namespace.set_state_keeper_control(control);
+        self
+    }
+
+    /// Enables methods changing the dynamic config (e.g., pausing transaction aggregation in the ETH sender).
+    pub fn with_dynamic_config(mut self, dynamic_config: DynamicConfigHandle) -> Self {
+        self.namespace.set_dynamic_config(dynamic_config);
+        self
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let rpc = self.namespace.into_rpc();
+        let registered_method_names = Arc::new(rpc.method_names().collect::<HashSet<_>>());
+        let metadata_layer =
+            MetadataLayer::new(registered_method_names, Arc::new(MethodTracer::default()));
+        let auth = BearerAuth::new(&self.auth_token)?;
+
+        let server = ServerBuilder::default()
+            .http_only()
+            .set_http_middleware(
+                tower::ServiceBuilder::new().layer(ValidateRequestHeaderLayer::custom(auth)),
+            )
+            .set_rpc_middleware(RpcServiceBuilder::new().layer(metadata_layer))
+            .build(self.bind_addr)
+            .await
+            .context("Failed building admin JSON-RPC server")?;
+        let local_addr = server
+            .local_addr()
+            .context("Failed getting local address for admin JSON-RPC server")?;
+        tracing::info!("Initialized admin API on {local_addr:?}");
+        let server_handle = server.start(rpc);
+
+        let stopped = server_handle.clone().stopped();
+        tokio::select! {
+            () = stopped => anyhow::bail!("Admin JSON-RPC server stopped unexpectedly"),
+            res = stop_receiver.changed() => {
+                if res.is_err() {
+                    tracing::warn!("Stop request sender for admin JSON-RPC server was dropped without sending a signal");
+                }
+            }
+        }
+        tracing::info!("Stop request received, admin JSON-RPC server is shutting down");
+        server_handle.stop().ok();
+        server_handle.stopped().await;
+        tracing::info!("Admin JSON-RPC server stopped");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_dal::{ConnectionPool, Core};
+    use zksync_state_keeper::MempoolGuard;
+    use zksync_types::H256;
+    use zksync_web3_decl::jsonrpsee::types::error::ErrorCode;
+
+    use super::*;
+
+    #[test]
+    fn bearer_auth_checks_token() {
+        let auth = BearerAuth::<()>::new("secret").unwrap();
+        assert!(auth.is_authorized(Some(&HeaderValue::from_static("Bearer secret"))));
+        assert!(!auth.is_authorized(Some(&HeaderValue::from_static("Bearer secreT"))));
+        assert!(!auth.is_authorized(Some(&HeaderValue::from_static("Bearer secret2"))));
+        assert!(!auth.is_authorized(Some(&HeaderValue::from_static("secret"))));
+        assert!(!auth.is_authorized(None));
+    }
+
+    #[tokio::test]
+    async fn admin_methods_control_state_keeper() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let mempool = MempoolGuard::from_storage(&mut storage, 100).await;
+        drop(storage);
+        let control = StateKeeperControl::new(mempool, pool);
+        let mut namespace = AdminNamespace::new(Arc::new(AppHealthCheck::default()));
+        namespace.pause_state_keeper().await.unwrap_err();
+
+        namespace.set_state_keeper_control(control.clone());
+        namespace.pause_state_keeper().await.unwrap();
+        assert!(control.is_paused());
+        namespace.resume_state_keeper().await.unwrap();
+        assert!(!control.is_paused());
+
+        let err = namespace
+            .evict_transaction(H256::repeat_byte(1))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidParams.code());
+
+        // Dynamic config is not available, so the method should fail.
+        namespace.pause_tx_aggregation().await.unwrap_err();
+    }
+}
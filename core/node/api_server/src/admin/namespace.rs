@@ -0,0 +1,161 @@
+//! Implementation of the `admin` namespace.
+
+use std::sync::Arc;
+
+use zksync_health_check::AppHealthCheck;
+use zksync_shared_resources::dynamic_config::DynamicConfigHandle;
+use zksync_state_keeper::{EvictionError, StateKeeperControl};
+use zksync_types::H256;
+use zksync_web3_decl::{
+    jsonrpsee::{
+        core::{async_trait, RpcResult},
+        types::{error::ErrorCode, ErrorObjectOwned},
+    },
+    namespaces::AdminNamespaceServer,
+};
+
+/// Tracing target used for the audit log of admin API calls.
+pub(super) const AUDIT_TARGET: &str = "admin_audit";
+
+#[derive(Debug, thiserror::Error)]
+enum AdminError {
+    #[error("{0} is not available on this node")]
+    Unavailable(&'static str),
+    #[error("failed updating dynamic config: {0:#}")]
+    DynamicConfig(anyhow::Error),
+    #[error(transparent)]
+    Eviction(#[from] EvictionError),
+    #[error("internal error: {0:#}")]
+    Internal(anyhow::Error),
+}
+
+impl From<AdminError> for ErrorObjectOwned {
+    fn from(err: AdminError) -> Self {
+        let code = match &err {
+            AdminError::Unavailable(_) | AdminError::DynamicConfig(_) => {
+                ErrorCode::InvalidRequest.code()
+            }
+            AdminError::Eviction(EvictionError::NotFound(_)) => ErrorCode::InvalidParams.code(),
+            AdminError::Eviction(EvictionError::Dal(_)) | AdminError::Internal(_) => {
+                ErrorCode::InternalError.code()
+            }
+        };
+        ErrorObjectOwned::owned(code, err.to_string(), None::<()>)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct AdminNamespace {
+    app_health: Arc<AppHealthCheck>,
+    state_keeper_control: Option<StateKeeperControl>,
+    dynamic_config: Option<DynamicConfigHandle>,
+}
+
+impl AdminNamespace {
+    pub fn new(app_health: Arc<AppHealthCheck>) -> Self {
+        Self {
+            app_health,
+            state_keeper_control: None,
+            dynamic_config: None,
+        }
+    }
+
+    pub fn set_state_keeper_control(&mut self, control: StateKeeperControl) {
+        self.state_keeper_control = Some(control);
+    }
+
+    pub fn set_dynamic_config(&mut self, dynamic_config: DynamicConfigHandle) {
+        self.dynamic_config = Some(dynamic_config);
+    }
+
+    /// Writes the call outcome to the audit log and converts it into an RPC result.
+    fn audit<T>(
+        method: &'static str,
+        details: &str,
+        result: Result<T, AdminError>,
+    ) -> RpcResult<T> {
+        match &result {
+            Ok(_) => {
+                tracing::info!(target: AUDIT_TARGET, method, details, "Admin API call succeeded")
+            }
+            Err(err) => {
+                tracing::warn!(target: AUDIT_TARGET, method, details, "Admin API call failed: {err}");
+            }
+        }
+        result.map_err(Into::into)
+    }
+
+    fn state_keeper_control(&self) -> Result<&StateKeeperControl, AdminError> {
+        self.state_keeper_control
+            .as_ref()
+            .ok_or(AdminError::Unavailable("state keeper"))
+    }
+
+    fn set_tx_aggregation_paused(&self, paused: bool) -> Result<(), AdminError> {
+        let dynamic_config = self
+            .dynamic_config
+            .as_ref()
+            .ok_or(AdminError::Unavailable("dynamic config"))?;
+        if dynamic_config.borrow().eth_sender.is_none() {
+            return Err(AdminError::Unavailable("eth sender config"));
+        }
+        // Set the param as an override, so that it's not reset when the config file is reloaded.
+        dynamic_config
+            .modify_overrides("admin API", |overrides| {
+                overrides.tx_aggregation_paused = Some(paused);
+            })
+            .map_err(AdminError::DynamicConfig)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AdminNamespaceServer for AdminNamespace {
+    async fn pause_state_keeper(&self) -> RpcResult<()> {
+        let result = self.state_keeper_control().map(StateKeeperControl::pause);
+        Self::audit("admin_pauseStateKeeper", "", result)
+    }
+
+    async fn resume_state_keeper(&self) -> RpcResult<()> {
+        let result = self.state_keeper_control().map(StateKeeperControl::resume);
+        Self::audit("admin_resumeStateKeeper", "", result)
+    }
+
+    async fn pause_tx_aggregation(&self) -> RpcResult<()> {
+        let result = self.set_tx_aggregation_paused(true);
+        Self::audit("admin_pauseTxAggregation", "", result)
+    }
+
+    async fn resume_tx_aggregation(&self) -> RpcResult<()> {
+        let result = self.set_tx_aggregation_paused(false);
+        Self::audit("admin_resumeTxAggregation", "", result)
+    }
+
+    async fn seal_l1_batch(&self) -> RpcResult<()> {
+        let result = self
+            .state_keeper_control()
+            .map(StateKeeperControl::request_l1_batch_seal);
+        Self::audit("admin_sealL1Batch", "", result)
+    }
+
+    async fn evict_transaction(&self, tx_hash: H256) -> RpcResult<()> {
+        let result = match self.state_keeper_control() {
+            Ok(control) => control
+                .evict_transaction(tx_hash)
+                .await
+                .map_err(AdminError::from),
+            Err(err) => Err(err),
+        };
+        Self::audit(
+            "admin_evictTransaction",
+            &format!("tx_hash={tx_hash:?}"),
+            result,
+        )
+    }
+
+    async fn health(&self) -> RpcResult<serde_json::Value> {
+        let health = self.app_health.check_health().await;
+        let result = serde_json::to_value(health).map_err(|err| AdminError::Internal(err.into()));
+        Self::audit("admin_health", "", result)
+    }
+}
@@ -0,0 +1,91 @@
+use secrecy::ExposeSecret;
+use zksync_config::configs::{api::AdminApiConfig, secrets::AdminApiSecrets};
+use zksync_health_check::node::AppHealthCheckResource;
+use zksync_node_framework::{
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+use zksync_shared_resources::dynamic_config::DynamicConfigHandle;
+use zksync_state_keeper::node::StateKeeperControlResource;
+
+use crate::admin::AdminApiServer;
+
+/// Wiring layer for the admin JSON-RPC API server.
+///
+/// The set of available admin methods depends on the components running on the node; e.g., state keeper control
+/// is only available if the state keeper is wired *before* this layer.
+///
+/// ## Requests resources
+///
+/// - `AppHealthCheckResource`
+/// - `StateKeeperControlResource` (optional)
+/// - `DynamicConfigHandle` (optional)
+///
+/// ## Adds tasks
+///
+/// - `AdminApiServer`
+#[derive(Debug)]
+pub struct AdminApiLayer {
+    config: AdminApiConfig,
+    secrets: AdminApiSecrets,
+}
+
+impl AdminApiLayer {
+    pub fn new(config: AdminApiConfig, secrets: AdminApiSecrets) -> Self {
+        Self { config, secrets }
+    }
+}
+
+#[derive(Debug, FromContext)]
+pub struct Input {
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+    pub state_keeper_control: Option<StateKeeperControlResource>,
+    pub dynamic_config: Option<DynamicConfigHandle>,
+}
+
+#[derive(Debug, IntoContext)]
+pub struct Output {
+    #[context(task)]
+    pub server: AdminApiServer,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for AdminApiLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "admin_api_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let auth_token = self.secrets.auth_token.0.expose_secret().to_owned();
+        let mut server =
+            AdminApiServer::new(self.config.bind_addr(), auth_token, input.app_health.0);
+        if let Some(StateKeeperControlResource(control)) = input.state_keeper_control {
+            server = server.with_state_keeper_control(control);
+        } else {
+            tracing::info!(
+                "State keeper is not running on this node; state keeper admin methods are disabled"
+            );
+        }
+        if let Some(dynamic_config) = input.dynamic_config {
+            server = server.with_dynamic_config(dynamic_config);
+        }
+        Ok(Output { server })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for AdminApiServer {
+    fn id(&self) -> TaskId {
+        "admin_api_server".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -1,4 +1,5 @@
 pub use self::{
+    admin::AdminApiLayer,
     allow_list::DeploymentAllowListLayer,
     caches::MempoolCacheLayer,
     healtcheck_server::HealthCheckLayer,
@@ -8,6 +9,7 @@ pub use self::{
     tx_sink::{MasterPoolSinkLayer, ProxySinkLayer, WhitelistedMasterPoolSinkLayer},
 };
 
+mod admin;
 mod allow_list;
 mod caches;
 mod healtcheck_server;
@@ -15,11 +15,12 @@ use zksync_node_framework::{
     FromContext, IntoContext,
 };
 use zksync_shared_resources::{
-    api::{BridgeAddressesHandle, SyncState},
+    api::{BridgeAddressesHandle, SettlementLayerFinalityHandle, SyncState},
     contracts::{
         L1ChainContractsResource, L1EcosystemContractsResource, L2ContractsResource,
         SettlementLayerContractsResource,
     },
+    dynamic_config::DynamicConfigHandle,
 };
 use zksync_web3_decl::node::{
     EthInterfaceResource, MainNodeClientResource, SettlementModeResource,
@@ -100,6 +101,8 @@ enum Transport {
 /// - `PoolResource<ReplicaPool>`
 /// - `TxSenderResource`
 /// - `SyncState` (optional)
+/// - `SettlementLayerFinalityHandle` (optional)
+/// - `DynamicConfigHandle` (optional)
 /// - `TreeApiClientResource` (optional)
 /// - `MempoolCacheResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
@@ -124,6 +127,8 @@ pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
     pub tx_sender: TxSenderResource,
     pub sync_state: Option<SyncState>,
+    pub settlement_layer_finality: Option<SettlementLayerFinalityHandle>,
+    pub dynamic_config: Option<DynamicConfigHandle>,
     pub tree_api_client: Option<TreeApiClientResource>,
     pub mempool_cache: MempoolCacheResource,
     #[context(default)]
@@ -241,9 +246,15 @@ impl WiringLayer for Web3ServerLayer {
         if let Some(sync_state) = sync_state {
             api_builder = api_builder.with_sync_state(sync_state);
         }
+        if let Some(dynamic_config) = input.dynamic_config {
+            api_builder = api_builder.with_dynamic_config(dynamic_config.subscribe());
+        }
         if let Some(main_node_client) = input.main_node_client {
             api_builder = api_builder.with_l2_l1_log_proof_handler(main_node_client.0)
         }
+        if let Some(finality) = input.settlement_layer_finality {
+            api_builder = api_builder.with_settlement_layer_finality(finality);
+        }
         let replication_lag_limit = self.optional_config.replication_lag_limit;
         api_builder = self.optional_config.apply(api_builder);
 
@@ -36,6 +36,7 @@ impl MethodTracer {
             Web3Error::NoBlock
             | Web3Error::PrunedBlock(_)
             | Web3Error::PrunedL1Batch(_)
+            | Web3Error::PrunedTransaction(_)
             | Web3Error::TooManyTopics
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFilterBlockHash
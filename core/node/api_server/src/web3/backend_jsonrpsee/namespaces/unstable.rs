@@ -1,10 +1,10 @@
 use zksync_types::{
     api::{
-        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, TeeProof,
-        TransactionExecutionInfo,
+        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, StateDiff,
+        TeeFinalization, TeeProof, TransactionExecutionInfo,
     },
     tee_types::TeeType,
-    L1BatchNumber, L2ChainId, H256,
+    L1BatchNumber, L2BlockNumber, L2ChainId, H256,
 };
 use zksync_web3_decl::{
     jsonrpsee::core::{async_trait, RpcResult},
@@ -34,6 +34,15 @@ impl UnstableNamespaceServer for UnstableNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn tee_finalization(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> RpcResult<Option<TeeFinalization>> {
+        self.get_tee_finalization_impl(l1_batch_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_chain_log_proof(
         &self,
         l1_batch_number: L1BatchNumber,
@@ -74,4 +83,26 @@ impl UnstableNamespaceServer for UnstableNamespace {
             .await
             .map_err(|err| self.current_method().map_err(err))
     }
+
+    async fn get_l2_block_state_diff(
+        &self,
+        block_number: L2BlockNumber,
+        cursor: Option<H256>,
+        limit: Option<usize>,
+    ) -> RpcResult<Option<StateDiff>> {
+        self.get_l2_block_state_diff_impl(block_number, cursor, limit)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_l1_batch_state_diff(
+        &self,
+        batch_number: L1BatchNumber,
+        cursor: Option<H256>,
+        limit: Option<usize>,
+    ) -> RpcResult<Option<StateDiff>> {
+        self.get_l1_batch_state_diff_impl(batch_number, cursor, limit)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
 }
@@ -178,7 +178,9 @@ impl Web3ErrorKind {
     fn new(err: &Web3Error) -> Self {
         match err {
             Web3Error::NoBlock => Self::NoBlock,
-            Web3Error::PrunedBlock(_) | Web3Error::PrunedL1Batch(_) => Self::Pruned,
+            Web3Error::PrunedBlock(_)
+            | Web3Error::PrunedL1Batch(_)
+            | Web3Error::PrunedTransaction(_) => Self::Pruned,
             Web3Error::SubmitTransactionError(..) => Self::SubmitTransaction,
             Web3Error::ProxyError(_) => Self::Proxy,
             Web3Error::SerializationError(_) => Self::TransactionSerialization,
@@ -9,11 +9,16 @@ use tokio::{
     task::JoinHandle,
 };
 use tower_http::{cors::CorsLayer, metrics::InFlightRequestsLayer};
-use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
+use zksync_config::configs::{
+    api::{MaxResponseSize, MaxResponseSizeOverrides},
+    dynamic::DynamicConfig,
+};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_metadata_calculator::api_server::TreeApiClient;
-use zksync_shared_resources::api::{BridgeAddressesHandle, SyncState};
+use zksync_shared_resources::api::{
+    BridgeAddressesHandle, SettlementLayerFinalityHandle, SyncState,
+};
 use zksync_types::{try_stoppable, L2BlockNumber, StopContext};
 use zksync_web3_decl::{
     client::{DynClient, L2},
@@ -134,11 +139,13 @@ struct OptionalApiParams {
     batch_request_size_limit: Option<usize>,
     response_body_size_limit: Option<MaxResponseSize>,
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    dynamic_config: Option<watch::Receiver<DynamicConfig>>,
     tree_api: Option<Arc<dyn TreeApiClient>>,
     mempool_cache: Option<MempoolCache>,
     extended_tracing: bool,
     pub_sub_events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
     l2_l1_log_proof_handler: Option<Box<DynClient<L2>>>,
+    settlement_layer_finality: Option<SettlementLayerFinalityHandle>,
 }
 
 /// Structure capable of spawning a configured Web3 API server along with all the required
@@ -246,6 +253,13 @@ impl ApiBuilder {
         self
     }
 
+    /// Makes the server use params from the provided dynamic config (e.g., the WebSocket rate limit), so that they can be
+    /// updated without restarting the server. Updates only apply to WebSocket sessions opened after the update.
+    pub fn with_dynamic_config(mut self, dynamic_config: watch::Receiver<DynamicConfig>) -> Self {
+        self.optional.dynamic_config = Some(dynamic_config);
+        self
+    }
+
     pub fn with_sync_state(mut self, sync_state: SyncState) -> Self {
         self.optional.sync_state = Some(sync_state);
         self
@@ -306,6 +320,15 @@ impl ApiBuilder {
         self
     }
 
+    /// Enables reporting batch stages independently verified on the settlement layer in `zks_getL1BatchDetails`.
+    pub fn with_settlement_layer_finality(
+        mut self,
+        settlement_layer_finality: SettlementLayerFinalityHandle,
+    ) -> Self {
+        self.optional.settlement_layer_finality = Some(settlement_layer_finality);
+        self
+    }
+
     // Intended for tests only.
     #[doc(hidden)]
     fn with_pub_sub_events(mut self, sender: mpsc::UnboundedSender<PubSubEvent>) -> Self {
@@ -391,6 +414,7 @@ impl ApiServer {
             bridge_addresses_handle: self.bridge_addresses_handle,
             tree_api: self.optional.tree_api,
             l2_l1_log_proof_handler: self.optional.l2_l1_log_proof_handler,
+            settlement_layer_finality: self.optional.settlement_layer_finality,
         })
     }
 
@@ -633,6 +657,7 @@ impl ApiServer {
                 (u32::MAX, MaxResponseSizeOverrides::empty())
             };
         let websocket_requests_per_minute_limit = self.optional.websocket_requests_per_minute_limit;
+        let dynamic_config = self.optional.dynamic_config.clone();
         let subscriptions_limit = self.optional.subscriptions_limit;
         let vm_barrier = self.optional.vm_barrier.clone();
         let health_updater = self.health_updater.clone();
@@ -702,7 +727,17 @@ impl ApiServer {
             // We want to capture limit middleware errors with `metadata_layer`; hence, `LimitMiddleware` is placed after it.
             .option_layer((!is_http).then(|| {
                 tower::layer::layer_fn(move |svc| {
-                    LimitMiddleware::new(svc, websocket_requests_per_minute_limit)
+                    // The limit is determined once per WS session, so that dynamic updates apply to new sessions.
+                    let dynamic_limit = dynamic_config.as_ref().and_then(|config| {
+                        Some(
+                            config
+                                .borrow()
+                                .api
+                                .as_ref()?
+                                .websocket_requests_per_minute_limit,
+                        )
+                    });
+                    LimitMiddleware::new(svc, dynamic_limit.or(websocket_requests_per_minute_limit))
                 })
             }));
 
@@ -199,6 +199,14 @@ impl EthNamespace {
         self.state.resolve_filter_block_hash(&mut filter).await?;
         let (from_block, to_block) = self.state.resolve_filter_block_range(&filter).await?;
 
+        // If the entire requested range is pruned, logs can only be returned if they are retained by the node.
+        let mut storage = self.state.acquire_connection().await?;
+        let first_l2_block = self.state.start_info.first_l2_block(&mut storage).await?;
+        drop(storage);
+        if to_block < first_l2_block && !self.is_logs_filter_retained(&filter) {
+            return Err(Web3Error::PrunedBlock(first_l2_block));
+        }
+
         filter.to_block = Some(BlockNumber::Number(to_block.0.into()));
         let changes = self
             .filter_changes(&mut TypedFilter::Events(filter, from_block))
@@ -209,6 +217,24 @@ impl EthNamespace {
         })
     }
 
+    /// Checks whether all logs matching the filter are retained during pruning.
+    fn is_logs_filter_retained(&self, filter: &Filter) -> bool {
+        let rules = &self.state.api_config.pruning_retention_rules;
+        if rules.is_empty() {
+            return false;
+        }
+        let addresses = filter
+            .address
+            .as_ref()
+            .map_or(&[][..], |addresses| addresses.0.as_slice());
+        let first_topics = filter
+            .topics
+            .as_ref()
+            .and_then(|topics| topics.first()?.as_ref())
+            .map(|topics| (1, topics.0.clone()));
+        rules.covers_logs_filter(addresses, first_topics.as_slice())
+    }
+
     pub async fn get_filter_logs_impl(&self, idx: U256) -> Result<FilterChanges, Web3Error> {
         let installed_filters = self
             .state
@@ -507,11 +533,23 @@ impl EthNamespace {
 
         let chain_id = self.state.api_config.l2_chain_id;
         let mut transaction = match id {
-            TransactionId::Hash(hash) => storage
-                .transactions_web3_dal()
-                .get_transaction_by_hash(hash, chain_id)
-                .await
-                .map_err(DalError::generalize)?,
+            TransactionId::Hash(hash) => {
+                let transaction = storage
+                    .transactions_web3_dal()
+                    .get_transaction_by_hash(hash, chain_id)
+                    .await
+                    .map_err(DalError::generalize)?;
+                if transaction.is_none()
+                    && storage
+                        .transactions_web3_dal()
+                        .is_transaction_pruned(hash)
+                        .await
+                        .map_err(DalError::generalize)?
+                {
+                    return Err(Web3Error::PrunedTransaction(hash));
+                }
+                transaction
+            }
 
             TransactionId::Block(block_id, idx) => {
                 if matches!(block_id, BlockId::Number(BlockNumber::Pending)) {
@@ -554,6 +592,15 @@ impl EthNamespace {
             .get_transaction_receipts(&[hash])
             .await
             .context("get_transaction_receipts")?;
+        if receipts.is_empty()
+            && storage
+                .transactions_web3_dal()
+                .is_transaction_pruned(hash)
+                .await
+                .map_err(DalError::generalize)?
+        {
+            return Err(Web3Error::PrunedTransaction(hash));
+        }
         let receipts = fill_transaction_receipts(&mut storage, receipts).await?;
         Ok(receipts.into_iter().next())
     }
@@ -8,17 +8,19 @@ use zksync_dal::{CoreDal, DalError};
 use zksync_mini_merkle_tree::MiniMerkleTree;
 use zksync_types::{
     api::{
-        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, TeeProof,
-        TransactionExecutionInfo,
+        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, StateDiff,
+        TeeFinalization, TeeProof, TransactionExecutionInfo,
     },
     server_notification::GatewayMigrationState,
     tee_types::TeeType,
-    L1BatchNumber, L2ChainId,
+    L1BatchNumber, L2BlockNumber, L2ChainId,
 };
 use zksync_web3_decl::{error::Web3Error, types::H256};
 
+use self::state_diff::load_state_diff;
 use crate::web3::{backend_jsonrpsee::MethodTracer, RpcState};
 
+mod state_diff;
 mod utils;
 
 #[derive(Debug)]
@@ -75,6 +77,24 @@ impl UnstableNamespace {
         Ok(proofs)
     }
 
+    pub async fn get_tee_finalization_impl(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> Result<Option<TeeFinalization>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let finalized_batch = storage
+            .tee_proof_generation_dal()
+            .get_tee_finalized_batch(l1_batch_number)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(finalized_batch.map(|batch| TeeFinalization {
+            l1_batch_number,
+            tee_types: batch.tee_types,
+            distinct_keys: batch.distinct_keys,
+            finalized_at: batch.finalized_at,
+        }))
+    }
+
     pub async fn get_chain_log_proof_impl(
         &self,
         l1_batch_number: L1BatchNumber,
@@ -176,6 +196,7 @@ impl UnstableNamespace {
             inclusion_data: da_details.inclusion_data,
             sent_at: da_details.sent_at,
             l2_da_validator: da_details.l2_da_validator,
+            chunk_blob_ids: da_details.chunk_blob_ids,
         }))
     }
 
@@ -217,4 +238,68 @@ impl UnstableNamespace {
             settlement_layer: self.state.api_config.settlement_layer,
         })
     }
+
+    fn state_diff_page_limit(&self, limit: Option<usize>) -> usize {
+        let max_limit = self.state.api_config.req_entities_limit;
+        limit.map_or(max_limit, |limit| limit.clamp(1, max_limit))
+    }
+
+    pub async fn get_l2_block_state_diff_impl(
+        &self,
+        block_number: L2BlockNumber,
+        cursor: Option<H256>,
+        limit: Option<usize>,
+    ) -> Result<Option<StateDiff>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        self.state
+            .start_info
+            .ensure_not_pruned(block_number, &mut connection)
+            .await?;
+
+        let sealed_l2_block_number = connection
+            .blocks_dal()
+            .get_sealed_l2_block_number()
+            .await
+            .map_err(DalError::generalize)?;
+        if sealed_l2_block_number.is_none_or(|sealed| block_number > sealed) {
+            return Ok(None);
+        }
+
+        let limit = self.state_diff_page_limit(limit);
+        let diff =
+            load_state_diff(&mut connection, block_number..=block_number, cursor, limit).await?;
+        Ok(Some(diff))
+    }
+
+    pub async fn get_l1_batch_state_diff_impl(
+        &self,
+        batch_number: L1BatchNumber,
+        cursor: Option<H256>,
+        limit: Option<usize>,
+    ) -> Result<Option<StateDiff>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        self.state
+            .start_info
+            .ensure_not_pruned(batch_number, &mut connection)
+            .await?;
+
+        let Some((first_l2_block, last_l2_block)) = connection
+            .blocks_dal()
+            .get_l2_block_range_of_l1_batch(batch_number)
+            .await
+            .map_err(DalError::generalize)?
+        else {
+            return Ok(None);
+        };
+
+        let limit = self.state_diff_page_limit(limit);
+        let diff = load_state_diff(
+            &mut connection,
+            first_l2_block..=last_l2_block,
+            cursor,
+            limit,
+        )
+        .await?;
+        Ok(Some(diff))
+    }
 }
@@ -0,0 +1,104 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops,
+};
+
+use zksync_dal::{Connection, Core, CoreDal, DalError};
+use zksync_system_constants::{ACCOUNT_CODE_STORAGE_ADDRESS, L2_BASE_TOKEN_ADDRESS};
+use zksync_types::{
+    api::{BalanceChange, DeployedContract, StateDiff, StorageSlotChange},
+    h256_to_address, h256_to_u256,
+    utils::storage_key_for_eth_balance,
+    Address, L2BlockNumber, H256,
+};
+use zksync_web3_decl::error::Web3Error;
+
+/// Loads a page of the state diff for the specified L2 block range.
+///
+/// Since balance slots are keyed by a hash of the account address, balance changes can only be decoded for known
+/// candidate addresses. Candidates are taken from the indexed topics of `L2BaseToken` events (transfers, mints
+/// and withdrawals) emitted in the range, and from the owners of changed slots.
+pub(super) async fn load_state_diff(
+    connection: &mut Connection<'_, Core>,
+    l2_block_numbers: ops::RangeInclusive<L2BlockNumber>,
+    cursor: Option<H256>,
+    limit: usize,
+) -> Result<StateDiff, Web3Error> {
+    let storage_changes = connection
+        .storage_logs_dal()
+        .get_storage_changes_in_l2_blocks(l2_block_numbers.clone(), cursor, limit)
+        .await
+        .map_err(DalError::generalize)?;
+    // The cursor is based on the loaded changes rather than the returned ones; some loaded changes may be filtered out
+    // because they don't change the slot value.
+    let next_cursor = if storage_changes.len() == limit {
+        storage_changes.last().map(|change| change.hashed_key)
+    } else {
+        None
+    };
+
+    let candidate_addresses = connection
+        .events_web3_dal()
+        .get_topic_addresses_in_l2_blocks(L2_BASE_TOKEN_ADDRESS, l2_block_numbers)
+        .await
+        .map_err(DalError::generalize)?;
+    Ok(decode_state_diff(
+        storage_changes,
+        candidate_addresses,
+        next_cursor,
+    ))
+}
+
+fn decode_state_diff(
+    storage_changes: Vec<StorageSlotChange>,
+    mut candidate_addresses: HashSet<Address>,
+    next_cursor: Option<H256>,
+) -> StateDiff {
+    let storage_changes: Vec<_> = storage_changes
+        .into_iter()
+        .filter(|change| change.previous_value.unwrap_or_default() != change.new_value)
+        .collect();
+
+    let mut deployed_contracts = vec![];
+    for change in &storage_changes {
+        if let Some(address) = change.address {
+            candidate_addresses.insert(address);
+        }
+        if change.address != Some(ACCOUNT_CODE_STORAGE_ADDRESS) {
+            continue;
+        }
+        if let Some(key) = change.key {
+            // Zero bytecode hash corresponds to a failed deployment.
+            if !change.new_value.is_zero() {
+                deployed_contracts.push(DeployedContract {
+                    address: h256_to_address(&key),
+                    bytecode_hash: change.new_value,
+                });
+            }
+        }
+    }
+    candidate_addresses.extend(deployed_contracts.iter().map(|contract| contract.address));
+
+    let address_by_balance_key: HashMap<_, _> = candidate_addresses
+        .into_iter()
+        .map(|address| (storage_key_for_eth_balance(&address).hashed_key(), address))
+        .collect();
+    let balance_changes = storage_changes
+        .iter()
+        .filter_map(|change| {
+            let address = *address_by_balance_key.get(&change.hashed_key)?;
+            Some(BalanceChange {
+                address,
+                previous_balance: h256_to_u256(change.previous_value.unwrap_or_default()),
+                new_balance: h256_to_u256(change.new_value),
+            })
+        })
+        .collect();
+
+    StateDiff {
+        storage_changes,
+        deployed_contracts,
+        balance_changes,
+        next_cursor,
+    }
+}
@@ -571,11 +571,19 @@ impl ZksNamespace {
             .ensure_not_pruned(batch_number, &mut storage)
             .await?;
 
-        Ok(storage
+        let mut details = storage
             .blocks_web3_dal()
             .get_l1_batch_details(batch_number)
             .await
-            .map_err(DalError::generalize)?)
+            .map_err(DalError::generalize)?;
+        if let (Some(details), Some(finality)) =
+            (&mut details, &self.state.settlement_layer_finality)
+        {
+            details.verified_stage = finality
+                .get()
+                .and_then(|finality| finality.batch_stage(batch_number));
+        }
+        Ok(details)
     }
 
     pub async fn get_bytecode_by_hash_impl(
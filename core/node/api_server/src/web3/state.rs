@@ -23,9 +23,13 @@ use zksync_config::{
     },
     GenesisConfig,
 };
-use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
+use zksync_dal::{
+    pruning_dal::PruningRetentionRules, Connection, ConnectionPool, Core, CoreDal, DalError,
+};
 use zksync_metadata_calculator::api_server::TreeApiClient;
-use zksync_shared_resources::api::{BridgeAddressesHandle, SyncState};
+use zksync_shared_resources::api::{
+    BridgeAddressesHandle, SettlementLayerFinalityHandle, SyncState,
+};
 use zksync_types::{
     api, commitment::L1BatchCommitmentMode, l2::L2Tx, settlement::SettlementLayer,
     transaction_request::CallRequest, Address, L1BatchNumber, L1ChainId, L2BlockNumber, L2ChainId,
@@ -121,6 +125,8 @@ pub struct InternalApiConfigBase {
     pub fee_history_limit: u64,
     pub filters_disabled: bool,
     pub l1_to_l2_txs_paused: bool,
+    /// Retention rules used when pruning node data. Used to distinguish between pruned and retained data.
+    pub pruning_retention_rules: PruningRetentionRules,
 }
 
 impl InternalApiConfigBase {
@@ -139,6 +145,7 @@ impl InternalApiConfigBase {
             fee_history_limit: web3_config.fee_history_limit(),
             filters_disabled: web3_config.filters_disabled,
             l1_to_l2_txs_paused: false,
+            pruning_retention_rules: PruningRetentionRules::default(),
         }
     }
 
@@ -179,6 +186,7 @@ pub struct InternalApiConfig {
     pub l2_multicall3: Option<Address>,
     pub l1_to_l2_txs_paused: bool,
     pub settlement_layer: Option<SettlementLayer>,
+    pub pruning_retention_rules: PruningRetentionRules,
 }
 
 impl InternalApiConfig {
@@ -224,6 +232,7 @@ impl InternalApiConfig {
             l2_multicall3: l2_contracts.multicall3,
             l1_to_l2_txs_paused: base.l1_to_l2_txs_paused,
             settlement_layer,
+            pruning_retention_rules: base.pruning_retention_rules,
         }
     }
 
@@ -304,6 +313,7 @@ pub(crate) struct RpcState {
     pub(super) last_sealed_l2_block: SealedL2BlockNumber,
     pub(super) bridge_addresses_handle: BridgeAddressesHandle,
     pub(super) l2_l1_log_proof_handler: Option<Box<DynClient<L2>>>,
+    pub(super) settlement_layer_finality: Option<SettlementLayerFinalityHandle>,
 }
 
 impl RpcState {
@@ -515,6 +515,16 @@ impl HttpTest for BlockMethodsWithSnapshotRecovery {
             assert_pruned_block_error(&error, expected_block_number);
         }
 
+        let filter = Filter {
+            from_block: Some(api::BlockNumber::Number(0.into())),
+            to_block: Some(api::BlockNumber::Number(
+                StorageInitialization::SNAPSHOT_RECOVERY_BLOCK.0.into(),
+            )),
+            ..Filter::default()
+        };
+        let error = client.get_logs(filter).await.unwrap_err();
+        assert_pruned_block_error(&error, expected_block_number);
+
         Ok(())
     }
 }
@@ -910,6 +920,78 @@ async fn transaction_receipts() {
     test_http_server(TransactionReceiptsTest).await;
 }
 
+#[derive(Debug)]
+struct PrunedTransactionReceiptsTest;
+
+#[async_trait]
+impl HttpTest for PrunedTransactionReceiptsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let l2_block_number = L2BlockNumber(1);
+        let l1_batch_number = L1BatchNumber(1);
+
+        let pruned_tx = create_l2_transaction(10, 200);
+        let retained_tx = create_l2_transaction(10, 200);
+        let tx_results = [
+            mock_execute_transaction(pruned_tx.clone().into()),
+            mock_execute_transaction(retained_tx.clone().into()),
+        ];
+        let l2_block = store_l2_block(&mut storage, l2_block_number, &tx_results).await?;
+        seal_l1_batch(&mut storage, l1_batch_number).await?;
+
+        let retention_rules = zksync_dal::pruning_dal::PruningRetentionRules {
+            addresses: vec![retained_tx.initiator_account()],
+            topics: vec![],
+        };
+        storage
+            .pruning_dal()
+            .insert_soft_pruning_log(l1_batch_number, l2_block_number)
+            .await?;
+        let stats = storage
+            .pruning_dal()
+            .hard_prune_batches_range_with_retention(
+                l1_batch_number,
+                l2_block_number,
+                &retention_rules,
+            )
+            .await?;
+        assert_eq!(stats.retained_transactions, 1);
+
+        let error = client
+            .get_transaction_receipt(pruned_tx.hash())
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = &error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+            assert!(error.message().contains("is pruned"), "{error:?}");
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+
+        let receipt = client
+            .get_transaction_receipt(retained_tx.hash())
+            .await?
+            .context("no receipt for retained transaction")?;
+        assert_eq!(receipt.block_number, l2_block_number.0.into());
+        assert_eq!(receipt.block_hash, l2_block.hash);
+
+        let missing_receipt = client
+            .get_transaction_receipt(H256::repeat_byte(0xff))
+            .await?;
+        assert!(missing_receipt.is_none());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn pruned_transaction_receipts() {
+    test_http_server(PrunedTransactionReceiptsTest).await;
+}
+
 #[derive(Debug)]
 struct AllAccountBalancesTest;
 
@@ -1,6 +1,8 @@
 //! Tests for the `unstable` Web3 namespace.
 
-use zksync_types::tee_types::TeeType;
+use anyhow::Context as _;
+use zksync_system_constants::L2_BASE_TOKEN_ADDRESS;
+use zksync_types::{address_to_h256, tee_types::TeeType, web3::keccak256, L2BlockNumber};
 use zksync_web3_decl::namespaces::UnstableNamespaceClient;
 
 use super::*;
@@ -62,3 +64,163 @@ impl HttpTest for GetTeeProofsTest {
 async fn get_tee_proofs() {
     test_http_server(GetTeeProofsTest::new()).await;
 }
+
+#[derive(Debug)]
+struct GetTeeFinalizationTest;
+
+#[async_trait]
+impl HttpTest for GetTeeFinalizationTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let batch_no = L1BatchNumber(1337);
+        let finalization = client.tee_finalization(batch_no).await?;
+        assert_eq!(finalization, None);
+
+        let mut storage = pool.connection().await?;
+        storage
+            .tee_proof_generation_dal()
+            .mark_l1_batch_tee_finalized(batch_no, &[TeeType::Sgx, TeeType::Tdx], 2)
+            .await?;
+
+        let finalization = client
+            .tee_finalization(batch_no)
+            .await?
+            .context("no TEE finalization")?;
+        assert_eq!(finalization.l1_batch_number, batch_no);
+        assert_eq!(finalization.tee_types, [TeeType::Sgx, TeeType::Tdx]);
+        assert_eq!(finalization.distinct_keys, 2);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn get_tee_finalization() {
+    test_http_server(GetTeeFinalizationTest).await;
+}
+
+#[derive(Debug)]
+struct StateDiffTest;
+
+impl StateDiffTest {
+    const ACCOUNT: Address = Address::repeat_byte(0x11);
+    const CONTRACT: Address = Address::repeat_byte(0x22);
+}
+
+#[async_trait]
+impl HttpTest for StateDiffTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let diff = client
+            .get_l2_block_state_diff(L2BlockNumber(1), None, None)
+            .await?;
+        assert_eq!(diff, None);
+
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &[]).await?;
+        let balance_key = storage_key_for_eth_balance(&Self::ACCOUNT);
+        let code_key = get_code_key(&Self::CONTRACT);
+        let slot_key = StorageKey::new(AccountTreeId::new(Self::CONTRACT), H256::zero());
+        let bytecode_hash = H256::repeat_byte(0xc0);
+        let logs = [
+            StorageLog::new_write_log(balance_key, u256_to_h256(1_000.into())),
+            StorageLog::new_write_log(code_key, bytecode_hash),
+            StorageLog::new_write_log(slot_key, H256::repeat_byte(1)),
+        ];
+        storage
+            .storage_logs_dal()
+            .insert_storage_logs(L2BlockNumber(1), &logs)
+            .await?;
+        let transfer_event = VmEvent {
+            location: (L1BatchNumber(1), 0),
+            address: L2_BASE_TOKEN_ADDRESS,
+            indexed_topics: vec![
+                H256(keccak256(b"Transfer(address,address,uint256)")),
+                address_to_h256(&Address::zero()),
+                address_to_h256(&Self::ACCOUNT),
+            ],
+            value: u256_to_h256(1_000.into()).0.to_vec(),
+        };
+        let tx_location = IncludedTxLocation {
+            tx_hash: H256::repeat_byte(1),
+            tx_index_in_l2_block: 0,
+        };
+        storage
+            .events_dal()
+            .save_events(L2BlockNumber(1), &[(tx_location, vec![&transfer_event])])
+            .await?;
+
+        let diff = client
+            .get_l2_block_state_diff(L2BlockNumber(1), None, None)
+            .await?
+            .context("no state diff")?;
+        assert_eq!(diff.next_cursor, None);
+        assert_eq!(diff.storage_changes.len(), logs.len());
+        for log in &logs {
+            let change = diff
+                .storage_changes
+                .iter()
+                .find(|change| change.hashed_key == log.key.hashed_key())
+                .unwrap();
+            assert_eq!(change.address, Some(*log.key.address()));
+            assert_eq!(change.key, Some(*log.key.key()));
+            assert_eq!(change.previous_value, None);
+            assert_eq!(change.new_value, log.value);
+        }
+        assert_eq!(
+            diff.deployed_contracts,
+            [api::DeployedContract {
+                address: Self::CONTRACT,
+                bytecode_hash,
+            }]
+        );
+        assert_eq!(
+            diff.balance_changes,
+            [api::BalanceChange {
+                address: Self::ACCOUNT,
+                previous_balance: 0.into(),
+                new_balance: 1_000.into(),
+            }]
+        );
+
+        // Check pagination.
+        let mut paginated_changes = vec![];
+        let mut cursor = None;
+        loop {
+            let page = client
+                .get_l2_block_state_diff(L2BlockNumber(1), cursor, Some(1))
+                .await?
+                .context("no state diff")?;
+            assert!(page.storage_changes.len() <= 1);
+            paginated_changes.extend(page.storage_changes);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(paginated_changes, diff.storage_changes);
+
+        // Check the diff for an L1 batch.
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        let batch_diff = client
+            .get_l1_batch_state_diff(L1BatchNumber(1), None, None)
+            .await?
+            .context("no state diff")?;
+        assert_eq!(batch_diff, diff);
+        let batch_diff = client
+            .get_l1_batch_state_diff(L1BatchNumber(2), None, None)
+            .await?;
+        assert_eq!(batch_diff, None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_state_diff() {
+    test_http_server(StateDiffTest).await;
+}
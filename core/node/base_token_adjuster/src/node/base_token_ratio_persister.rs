@@ -6,10 +6,11 @@ use zksync_eth_client::{
     node::contracts::{L1ChainContractsResource, L1EcosystemContractsResource},
     web3_decl::node::EthInterfaceResource,
 };
+use zksync_health_check::{node::AppHealthCheckResource, ReactiveHealthCheck};
 use zksync_node_fee_model::node::TxParamsResource;
 use zksync_node_framework::{
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{Supervised, SupervisionPolicy, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -21,7 +22,8 @@ use crate::{BaseTokenL1Behaviour, BaseTokenRatioPersister, UpdateOnL1Params};
 /// Wiring layer for `BaseTokenRatioPersister`
 ///
 /// Responsible for orchestrating communications with external API feeds to get ETH<->BaseToken
-/// conversion ratios and persisting them both in the DB and in the L1.
+/// conversion ratios and persisting them both in the DB and in the L1. Failures of the persister
+/// don't stop the node; instead, the persister is restarted with an exponential backoff.
 #[derive(Debug)]
 pub struct BaseTokenRatioPersisterLayer {
     config: BaseTokenAdjusterConfig,
@@ -38,12 +40,14 @@ pub struct Input {
     pub tx_params: TxParamsResource,
     pub l1_contracts: L1ChainContractsResource,
     pub l1_ecosystem_contracts: L1EcosystemContractsResource,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
 pub struct Output {
     #[context(task)]
-    pub persister: BaseTokenRatioPersister,
+    pub persister: Supervised<BaseTokenRatioPersister>,
 }
 
 impl BaseTokenRatioPersisterLayer {
@@ -119,6 +123,15 @@ impl WiringLayer for BaseTokenRatioPersisterLayer {
             l1_behaviour,
         );
 
+        let (health_check, health_updater) = ReactiveHealthCheck::new("base_token_ratio_persister");
+        input
+            .app_health
+            .0
+            .insert_component(health_check)
+            .map_err(WiringError::internal)?;
+        let persister = Supervised::new(persister, SupervisionPolicy::restart())
+            .with_observer(Box::new(health_updater));
+
         Ok(Output { persister })
     }
 }
@@ -0,0 +1,598 @@
+//! Trustless verification of L1 batch statuses (proven / executed) reported by the main node.
+
+use std::time::Duration;
+
+use anyhow::Context as _;
+use serde::Serialize;
+use tokio::sync::watch;
+use zksync_circuit_breaker::{CircuitBreaker, CircuitBreakerError};
+use zksync_dal::{ConnectionPool, Core, CoreDal, DalError};
+use zksync_eth_client::{CallFunctionArgs, EthInterface};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_shared_metrics::{CheckerComponent, EN_METRICS};
+use zksync_shared_resources::api::{SettlementLayerFinality, SettlementLayerFinalityHandle};
+use zksync_types::{
+    ethabi,
+    web3::{BlockId, TransactionReceipt},
+    Address, L1BatchNumber, SLChainId, H256, U256,
+};
+
+use crate::CheckError;
+
+impl From<DalError> for CheckError {
+    fn from(err: DalError) -> Self {
+        Self::Internal(err.generalize())
+    }
+}
+
+/// Batch stage checked by [`BatchStatusVerifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BatchStage {
+    Proven,
+    Executed,
+}
+
+impl BatchStage {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Proven => "proven",
+            Self::Executed => "executed",
+        }
+    }
+
+    fn event_name(self) -> &'static str {
+        match self {
+            Self::Proven => "BlocksVerification",
+            Self::Executed => "BlockExecution",
+        }
+    }
+}
+
+/// Disagreement between the main node and the settlement layer about the status of an L1 batch.
+#[derive(Debug, Clone, Serialize)]
+struct StatusMismatch {
+    l1_batch: L1BatchNumber,
+    stage: BatchStage,
+    tx_hash: H256,
+    reason: String,
+}
+
+/// Outcome of verifying a transaction reported by the main node.
+#[derive(Debug)]
+enum TxVerification {
+    Verified,
+    /// The transaction cannot be verified yet (e.g., the settlement layer client lags behind the main node).
+    Pending,
+    Mismatch(String),
+}
+
+/// Health details reported by [`BatchStatusVerifier`].
+#[derive(Debug, Default, Serialize)]
+struct VerifierDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_committed_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_proven_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_executed_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_checked_proven_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_checked_executed_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mismatches: Vec<StatusMismatch>,
+}
+
+impl VerifierDetails {
+    fn health(&self) -> Health {
+        let status = if self.mismatches.is_empty() {
+            HealthStatus::Ready
+        } else {
+            HealthStatus::Affected
+        };
+        Health::from(status).with_details(self)
+    }
+}
+
+/// Next batches to check for each stage.
+#[derive(Debug, Clone, Copy)]
+struct Cursors {
+    proven: L1BatchNumber,
+    executed: L1BatchNumber,
+}
+
+/// Verifies L1 batch statuses reported by the main node (and persisted by the batch status updater)
+/// directly on the settlement layer, without trusting the main node.
+///
+/// The verifier reads the latest committed / proven / executed batches from the diamond proxy getters and
+/// publishes them via [`SettlementLayerFinalityHandle`]. Additionally, it checks prove and execute transactions
+/// reported by the main node: each transaction must succeed and emit the corresponding diamond proxy event
+/// (`BlocksVerification` or `BlockExecution`) covering the batch; for executed batches, the batch hash in the event
+/// must match the local state root hash. Disagreements are reported via health checks and a circuit breaker.
+#[derive(Debug)]
+pub struct BatchStatusVerifier {
+    contract: ethabi::Contract,
+    client: Box<dyn EthInterface>,
+    chain_id: SLChainId,
+    diamond_proxy_addr: Address,
+    pool: ConnectionPool<Core>,
+    poll_interval: Duration,
+    max_batches_to_recheck: u32,
+    finality: SettlementLayerFinalityHandle,
+    mismatches: watch::Sender<Vec<StatusMismatch>>,
+    details: VerifierDetails,
+    health_updater: HealthUpdater,
+}
+
+impl BatchStatusVerifier {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+    /// Maximum number of batches checked for each stage during a single iteration.
+    const MAX_BATCHES_PER_ITERATION: usize = 50;
+
+    pub async fn new(
+        client: Box<dyn EthInterface>,
+        diamond_proxy_addr: Address,
+        pool: ConnectionPool<Core>,
+        max_batches_to_recheck: u32,
+    ) -> anyhow::Result<Self> {
+        let chain_id = client.fetch_chain_id().await?;
+        let (_, health_updater) = ReactiveHealthCheck::new("batch_status_verifier");
+        Ok(Self {
+            contract: zksync_contracts::hyperchain_contract(),
+            client,
+            chain_id,
+            diamond_proxy_addr,
+            pool,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            max_batches_to_recheck,
+            finality: SettlementLayerFinalityHandle::default(),
+            mismatches: watch::channel(vec![]).0,
+            details: VerifierDetails::default(),
+            health_updater,
+        })
+    }
+
+    /// Returns the handle to the verified batch finality.
+    pub fn finality(&self) -> SettlementLayerFinalityHandle {
+        self.finality.clone()
+    }
+
+    /// Returns the health check for this verifier.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    /// Returns a circuit breaker tripping if the main node has reported a batch status contradicting the settlement layer.
+    pub fn circuit_breaker(&self) -> BatchStatusCircuitBreaker {
+        BatchStatusCircuitBreaker(self.mismatches.subscribe())
+    }
+
+    async fn call_getter(&self, name: &str, block: u64) -> Result<L1BatchNumber, CheckError> {
+        let number: U256 = CallFunctionArgs::new(name, ())
+            .for_contract(self.diamond_proxy_addr, &self.contract)
+            .with_block(BlockId::Number(block.into()))
+            .call(self.client.as_ref())
+            .await?;
+        Ok(L1BatchNumber(number.as_u32()))
+    }
+
+    /// Fetches batch finality from the diamond proxy. Returns the finality together with the settlement layer block
+    /// it was fetched at.
+    async fn fetch_finality(&self) -> Result<(SettlementLayerFinality, u64), CheckError> {
+        let block = self.client.block_number().await?.as_u64();
+        let finality = SettlementLayerFinality {
+            last_committed_batch: self.call_getter("getTotalBatchesCommitted", block).await?,
+            last_proven_batch: self.call_getter("getTotalBatchesVerified", block).await?,
+            last_executed_batch: self.call_getter("getTotalBatchesExecuted", block).await?,
+        };
+        Ok((finality, block))
+    }
+
+    fn event_signature(&self, stage: BatchStage) -> Result<H256, CheckError> {
+        let event = self
+            .contract
+            .event(stage.event_name())
+            .with_context(|| format!("`{}` event is missing in contract ABI", stage.event_name()))
+            .map_err(CheckError::Internal)?;
+        Ok(event.signature())
+    }
+
+    /// Checks whether the receipt contains a diamond proxy event for the specified batch and stage.
+    fn find_event(
+        &self,
+        receipt: &TransactionReceipt,
+        stage: BatchStage,
+        l1_batch: L1BatchNumber,
+        root_hash: Option<H256>,
+    ) -> Result<Result<(), String>, CheckError> {
+        let signature = self.event_signature(stage)?;
+        let batch_topic = H256::from_low_u64_be(l1_batch.0.into());
+        let mut events = receipt.logs.iter().filter(|log| {
+            log.address == self.diamond_proxy_addr && log.topics.first() == Some(&signature)
+        });
+
+        Ok(match stage {
+            BatchStage::Proven => {
+                let covers_batch = events.any(|log| {
+                    let [_, prev, current, ..] = log.topics.as_slice() else {
+                        return false;
+                    };
+                    let (prev, current) = (U256::from(prev.0), U256::from(current.0));
+                    let l1_batch = U256::from(l1_batch.0);
+                    prev < l1_batch && l1_batch <= current
+                });
+                if covers_batch {
+                    Ok(())
+                } else {
+                    Err(
+                        "transaction doesn't emit `BlocksVerification` event covering the batch"
+                            .into(),
+                    )
+                }
+            }
+            BatchStage::Executed => {
+                let event = events.find(|log| log.topics.get(1) == Some(&batch_topic));
+                let Some(event) = event else {
+                    return Ok(Err(
+                        "transaction doesn't emit `BlockExecution` event for the batch".into(),
+                    ));
+                };
+                match (event.topics.get(2), root_hash) {
+                    (Some(batch_hash), Some(root_hash)) if *batch_hash != root_hash => Err(format!(
+                        "executed batch hash {batch_hash:?} differs from the local root hash {root_hash:?}"
+                    )),
+                    _ => Ok(()),
+                }
+            }
+        })
+    }
+
+    async fn verify_tx(
+        &self,
+        stage: BatchStage,
+        l1_batch: L1BatchNumber,
+        tx_hash: H256,
+        root_hash: Option<H256>,
+        (finality, finality_block): (&SettlementLayerFinality, u64),
+    ) -> Result<TxVerification, CheckError> {
+        let Some(receipt) = self.client.tx_receipt(tx_hash).await? else {
+            return Ok(TxVerification::Pending);
+        };
+        let Some(tx_block) = receipt.block_number else {
+            return Ok(TxVerification::Pending);
+        };
+        if receipt.status != Some(1.into()) {
+            return Ok(TxVerification::Mismatch("transaction has failed".into()));
+        }
+        if let Err(reason) = self.find_event(&receipt, stage, l1_batch, root_hash)? {
+            return Ok(TxVerification::Mismatch(reason));
+        }
+
+        let last_batch_with_stage = match stage {
+            BatchStage::Proven => finality.last_proven_batch,
+            BatchStage::Executed => finality.last_executed_batch,
+        };
+        if l1_batch <= last_batch_with_stage {
+            Ok(TxVerification::Verified)
+        } else if tx_block.as_u64() <= finality_block {
+            // The transaction was included before the block the finality was fetched at, so it must be reflected
+            // in the finality (unless the batch was reverted).
+            Ok(TxVerification::Mismatch(format!(
+                "batch is not {} according to the diamond proxy",
+                stage.as_str()
+            )))
+        } else {
+            Ok(TxVerification::Pending)
+        }
+    }
+
+    fn report_mismatch(&mut self, mismatch: StatusMismatch) {
+        tracing::error!(
+            "Main node has reported an L1 batch status that contradicts the settlement layer: {mismatch:?}"
+        );
+        EN_METRICS.batch_status_mismatches.inc();
+        self.mismatches
+            .send_modify(|mismatches| mismatches.push(mismatch.clone()));
+        self.details.mismatches.push(mismatch);
+    }
+
+    /// Checks transactions for the specified stage starting from `next_batch`. Returns the next batch to check.
+    async fn check_stage(
+        &mut self,
+        stage: BatchStage,
+        mut next_batch: L1BatchNumber,
+        finality: (&SettlementLayerFinality, u64),
+    ) -> Result<L1BatchNumber, CheckError> {
+        for _ in 0..Self::MAX_BATCHES_PER_ITERATION {
+            let mut storage = self.pool.connection_tagged("batch_status_verifier").await?;
+            let details = storage
+                .blocks_web3_dal()
+                .get_l1_batch_details(next_batch)
+                .await?;
+            drop(storage);
+
+            let Some(details) = details else {
+                break;
+            };
+            let (tx_hash, chain_id) = match stage {
+                BatchStage::Proven => (details.base.prove_tx_hash, details.base.prove_chain_id),
+                BatchStage::Executed => {
+                    (details.base.execute_tx_hash, details.base.execute_chain_id)
+                }
+            };
+            let Some(tx_hash) = tx_hash else {
+                // The main node hasn't reported the batch to have this stage yet.
+                break;
+            };
+
+            if chain_id.is_some_and(|chain_id| chain_id != self.chain_id) {
+                tracing::debug!(
+                    "Skipping verification of {stage:?} stage for L1 batch #{next_batch}: it was settled on chain {chain_id:?}, \
+                     while the verifier checks chain {}",
+                    self.chain_id
+                );
+            } else {
+                let root_hash = details.base.root_hash;
+                match self
+                    .verify_tx(stage, next_batch, tx_hash, root_hash, finality)
+                    .await?
+                {
+                    TxVerification::Verified => {
+                        tracing::debug!("Verified {stage:?} stage for L1 batch #{next_batch}");
+                    }
+                    TxVerification::Pending => break,
+                    TxVerification::Mismatch(reason) => {
+                        self.report_mismatch(StatusMismatch {
+                            l1_batch: next_batch,
+                            stage,
+                            tx_hash,
+                            reason,
+                        });
+                    }
+                }
+            }
+
+            match stage {
+                BatchStage::Proven => self.details.last_checked_proven_batch = Some(next_batch),
+                BatchStage::Executed => {
+                    EN_METRICS.last_correct_batch[&CheckerComponent::BatchStatusVerifier]
+                        .set(next_batch.0.into());
+                    self.details.last_checked_executed_batch = Some(next_batch);
+                }
+            }
+            next_batch += 1;
+        }
+        Ok(next_batch)
+    }
+
+    async fn step(&mut self, cursors: &mut Cursors) -> Result<(), CheckError> {
+        let (finality, finality_block) = self.fetch_finality().await?;
+        self.finality.update(finality);
+        self.details.last_committed_batch = Some(finality.last_committed_batch);
+        self.details.last_proven_batch = Some(finality.last_proven_batch);
+        self.details.last_executed_batch = Some(finality.last_executed_batch);
+
+        let finality = (&finality, finality_block);
+        cursors.proven = self
+            .check_stage(BatchStage::Proven, cursors.proven, finality)
+            .await?;
+        cursors.executed = self
+            .check_stage(BatchStage::Executed, cursors.executed, finality)
+            .await?;
+        self.health_updater.update(self.details.health());
+        Ok(())
+    }
+
+    async fn initial_cursors(&self) -> anyhow::Result<Cursors> {
+        let mut storage = self.pool.connection_tagged("batch_status_verifier").await?;
+        // The genesis batch is never proven or executed on the settlement layer.
+        let earliest_batch = storage
+            .blocks_dal()
+            .get_earliest_l1_batch_number()
+            .await?
+            .unwrap_or_default()
+            .max(L1BatchNumber(1));
+        let last_proven = storage
+            .blocks_dal()
+            .get_number_of_last_l1_batch_proven_on_eth()
+            .await?
+            .unwrap_or_default();
+        let last_executed = storage
+            .blocks_dal()
+            .get_number_of_last_l1_batch_executed_on_eth()
+            .await?
+            .unwrap_or_default();
+
+        let first_batch = |last: L1BatchNumber| {
+            L1BatchNumber(last.0.saturating_sub(self.max_batches_to_recheck)).max(earliest_batch)
+        };
+        Ok(Cursors {
+            proven: first_batch(last_proven),
+            executed: first_batch(last_executed),
+        })
+    }
+
+    pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut cursors = self.initial_cursors().await?;
+        tracing::info!(
+            "Starting batch status verifier for diamond proxy {:?} on chain {} from L1 batches {cursors:?}",
+            self.diamond_proxy_addr,
+            self.chain_id
+        );
+        self.health_updater.update(self.details.health());
+
+        while !*stop_receiver.borrow_and_update() {
+            match self.step(&mut cursors).await {
+                Ok(()) => {}
+                Err(err) if err.is_retriable() => {
+                    tracing::warn!(
+                        "Transient error verifying L1 batch statuses; will retry after a delay: {:#}",
+                        anyhow::Error::from(err)
+                    );
+                }
+                Err(err) => {
+                    return Err(
+                        anyhow::Error::from(err).context("failed verifying L1 batch statuses")
+                    );
+                }
+            }
+
+            tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop request received, batch status verifier is shutting down");
+        Ok(())
+    }
+}
+
+/// Circuit breaker tripping if the main node has reported an L1 batch status contradicting the settlement layer.
+#[derive(Debug)]
+pub struct BatchStatusCircuitBreaker(watch::Receiver<Vec<StatusMismatch>>);
+
+#[async_trait::async_trait]
+impl CircuitBreaker for BatchStatusCircuitBreaker {
+    fn name(&self) -> &'static str {
+        "batch_status_mismatch"
+    }
+
+    async fn check(&self) -> Result<(), CircuitBreakerError> {
+        if let Some(mismatch) = self.0.borrow().first() {
+            return Err(CircuitBreakerError::BatchStatusMismatch {
+                l1_batch: mismatch.l1_batch.0,
+                reason: mismatch.reason.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_eth_client::clients::MockSettlementLayer;
+    use zksync_types::web3::Log;
+
+    use super::*;
+
+    const DIAMOND_PROXY_ADDR: Address = Address::repeat_byte(1);
+
+    async fn create_verifier() -> BatchStatusVerifier {
+        let client = MockSettlementLayer::builder().with_chain_id(9).build();
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        BatchStatusVerifier::new(Box::new(client.into_client()), DIAMOND_PROXY_ADDR, pool, 10)
+            .await
+            .unwrap()
+    }
+
+    fn receipt_with_event(
+        verifier: &BatchStatusVerifier,
+        stage: BatchStage,
+        topics: [H256; 2],
+    ) -> TransactionReceipt {
+        let signature = verifier.event_signature(stage).unwrap();
+        TransactionReceipt {
+            logs: vec![Log {
+                address: DIAMOND_PROXY_ADDR,
+                topics: vec![signature, topics[0], topics[1]],
+                ..Log::default()
+            }],
+            ..TransactionReceipt::default()
+        }
+    }
+
+    fn batch_topic(number: u32) -> H256 {
+        H256::from_low_u64_be(number.into())
+    }
+
+    #[tokio::test]
+    async fn matching_verification_events() {
+        let verifier = create_verifier().await;
+        let receipt = receipt_with_event(
+            &verifier,
+            BatchStage::Proven,
+            [batch_topic(3), batch_topic(6)],
+        );
+
+        for number in 4..=6 {
+            verifier
+                .find_event(&receipt, BatchStage::Proven, L1BatchNumber(number), None)
+                .unwrap()
+                .unwrap();
+        }
+        for number in [3, 7] {
+            verifier
+                .find_event(&receipt, BatchStage::Proven, L1BatchNumber(number), None)
+                .unwrap()
+                .unwrap_err();
+        }
+        // The event must not be confused with the execution event.
+        verifier
+            .find_event(&receipt, BatchStage::Executed, L1BatchNumber(4), None)
+            .unwrap()
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn matching_execution_events() {
+        let verifier = create_verifier().await;
+        let root_hash = H256::repeat_byte(0x23);
+        let receipt =
+            receipt_with_event(&verifier, BatchStage::Executed, [batch_topic(5), root_hash]);
+
+        verifier
+            .find_event(
+                &receipt,
+                BatchStage::Executed,
+                L1BatchNumber(5),
+                Some(root_hash),
+            )
+            .unwrap()
+            .unwrap();
+        verifier
+            .find_event(&receipt, BatchStage::Executed, L1BatchNumber(5), None)
+            .unwrap()
+            .unwrap();
+        let err = verifier
+            .find_event(
+                &receipt,
+                BatchStage::Executed,
+                L1BatchNumber(5),
+                Some(H256::zero()),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert!(err.contains("differs from the local root hash"), "{err}");
+        verifier
+            .find_event(
+                &receipt,
+                BatchStage::Executed,
+                L1BatchNumber(4),
+                Some(root_hash),
+            )
+            .unwrap()
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_trips_on_mismatch() {
+        let mut verifier = create_verifier().await;
+        let circuit_breaker = verifier.circuit_breaker();
+        circuit_breaker.check().await.unwrap();
+
+        verifier.report_mismatch(StatusMismatch {
+            l1_batch: L1BatchNumber(5),
+            stage: BatchStage::Executed,
+            tx_hash: H256::repeat_byte(1),
+            reason: "transaction has failed".into(),
+        });
+        let err = circuit_breaker.check().await.unwrap_err();
+        assert!(
+            matches!(
+                err,
+                CircuitBreakerError::BatchStatusMismatch { l1_batch: 5, .. }
+            ),
+            "{err:?}"
+        );
+    }
+}
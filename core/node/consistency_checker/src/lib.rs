@@ -26,6 +26,7 @@ use zksync_types::{
     try_stoppable, Address, L1BatchNumber, OrStopped, ProtocolVersionId, SLChainId, H256, U256,
 };
 
+pub mod batch_status_verifier;
 pub mod node;
 #[cfg(test)]
 mod tests;
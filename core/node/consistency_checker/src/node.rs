@@ -1,5 +1,6 @@
 //! Dependency injection for the consistency checker.
 
+use zksync_circuit_breaker::node::CircuitBreakersResource;
 use zksync_dal::node::{MasterPool, PoolResource};
 use zksync_eth_client::{
     node::contracts::SettlementLayerContractsResource,
@@ -13,8 +14,9 @@ use zksync_node_framework::{
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
+use zksync_shared_resources::api::SettlementLayerFinalityHandle;
 
-use crate::ConsistencyChecker;
+use crate::{batch_status_verifier::BatchStatusVerifier, ConsistencyChecker};
 
 /// Wiring layer for the `ConsistencyChecker` (used by the external node).
 #[derive(Debug)]
@@ -100,3 +102,110 @@ impl Task for ConsistencyChecker {
         (*self).run(stop_receiver.0).await
     }
 }
+
+/// Wiring layer for the [`BatchStatusVerifier`] (used by the external node).
+///
+/// ## Requests resources
+///
+/// - `SettlementLayerClient`
+/// - `SettlementLayerContractsResource`
+/// - `PoolResource<MasterPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+/// - `CircuitBreakersResource` (adds a circuit breaker)
+///
+/// ## Adds resources
+///
+/// - `SettlementLayerFinalityHandle`
+///
+/// ## Adds tasks
+///
+/// - `BatchStatusVerifier`
+#[derive(Debug)]
+pub struct BatchStatusVerifierLayer {
+    max_batches_to_recheck: u32,
+}
+
+impl BatchStatusVerifierLayer {
+    pub fn new(max_batches_to_recheck: u32) -> Self {
+        Self {
+            max_batches_to_recheck,
+        }
+    }
+}
+
+#[derive(Debug, FromContext)]
+pub struct BatchStatusVerifierInput {
+    pub settlement_layer_client: SettlementLayerClient,
+    pub sl_chain_contracts: SettlementLayerContractsResource,
+    pub master_pool: PoolResource<MasterPool>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+    #[context(default)]
+    pub circuit_breakers: CircuitBreakersResource,
+}
+
+#[derive(Debug, IntoContext)]
+pub struct BatchStatusVerifierOutput {
+    pub settlement_layer_finality: SettlementLayerFinalityHandle,
+    #[context(task)]
+    pub verifier: BatchStatusVerifier,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for BatchStatusVerifierLayer {
+    type Input = BatchStatusVerifierInput;
+    type Output = BatchStatusVerifierOutput;
+
+    fn layer_name(&self) -> &'static str {
+        "batch_status_verifier_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let settlement_layer_client: Box<dyn EthInterface> = match input.settlement_layer_client {
+            SettlementLayerClient::L1(client) => Box::new(client),
+            SettlementLayerClient::L2(client) => Box::new(client),
+        };
+        let diamond_proxy_addr = input
+            .sl_chain_contracts
+            .0
+            .chain_contracts_config
+            .diamond_proxy_addr;
+
+        let pool = input.master_pool.get().await?;
+        let verifier = BatchStatusVerifier::new(
+            settlement_layer_client,
+            diamond_proxy_addr,
+            pool,
+            self.max_batches_to_recheck,
+        )
+        .await
+        .map_err(WiringError::Internal)?;
+
+        input
+            .app_health
+            .0
+            .insert_component(verifier.health_check())
+            .map_err(WiringError::internal)?;
+        input
+            .circuit_breakers
+            .breakers
+            .insert(Box::new(verifier.circuit_breaker()))
+            .await;
+
+        Ok(BatchStatusVerifierOutput {
+            settlement_layer_finality: verifier.finality(),
+            verifier,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for BatchStatusVerifier {
+    fn id(&self) -> TaskId {
+        "batch_status_verifier".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use tower_http::cors::CorsLayer;
 use zksync_dal::{ConnectionPool, Core};
+use zksync_types::L2ChainId;
 
 use crate::cache::SupportedCompilersCache;
 
@@ -10,18 +11,21 @@ pub(crate) struct RestApi {
     pub(crate) master_connection_pool: ConnectionPool<Core>,
     pub(crate) replica_connection_pool: ConnectionPool<Core>,
     pub(crate) supported_compilers: Arc<SupportedCompilersCache>,
+    pub(crate) l2_chain_id: L2ChainId,
 }
 
 impl RestApi {
     pub fn new(
         master_connection_pool: ConnectionPool<Core>,
         replica_connection_pool: ConnectionPool<Core>,
+        l2_chain_id: L2ChainId,
     ) -> Self {
         let supported_compilers = SupportedCompilersCache::new(replica_connection_pool.clone());
         Self {
             supported_compilers: Arc::new(supported_compilers),
             master_connection_pool,
             replica_connection_pool,
+            l2_chain_id,
         }
     }
 
@@ -52,6 +56,19 @@ impl RestApi {
                 "/contract_verification/info/:address",
                 axum::routing::get(Self::verification_info),
             )
+            // Sourcify-compatible API
+            .route(
+                "/v2/verify/metadata/:chain_id/:address",
+                axum::routing::post(Self::sourcify_verify),
+            )
+            .route(
+                "/v2/verify/:id",
+                axum::routing::get(Self::sourcify_job_status),
+            )
+            .route(
+                "/v2/contract/:chain_id/:address",
+                axum::routing::get(Self::sourcify_contract),
+            )
             .layer(CorsLayer::permissive())
             .with_state(Arc::new(self))
     }
@@ -13,8 +13,9 @@ use zksync_types::{
     bytecode::{trim_bytecode, BytecodeHash, BytecodeMarker},
     contract_verification::{
         api::{
-            CompilerVersions, SourceCodeData, VerificationIncomingRequest, VerificationInfo,
-            VerificationProblem, VerificationRequestStatus,
+            CompilerVersions, ContractVerificationInfo, SourceCodeData,
+            VerificationIncomingRequest, VerificationInfo, VerificationProblem,
+            VerificationRequestStatus,
         },
         contract_identifier::ContractIdentifier,
         etherscan::{EtherscanRequest, EtherscanRequestPayload, EtherscanResponse},
@@ -42,6 +43,7 @@ pub(crate) enum ApiError {
     VerificationInfoNotFound,
     AlreadyVerified,
     ActiveRequestExists(usize),
+    UnsupportedChain(String),
     Internal(anyhow::Error),
     DeserializationError(anyhow::Error),
     UnsupportedContentType,
@@ -75,16 +77,15 @@ impl ApiError {
             Self::ActiveRequestExists(id) => {
                 format!("active request for this contract already exists, ID: {id}")
             }
+            Self::UnsupportedChain(chain_id) => format!("chain {chain_id} is not supported"),
             Self::Internal(_) => "internal server error".into(),
             Self::UnsupportedContentType => "Specified content type is not supported".into(),
             Self::DeserializationError(e) => format!("Failed to deserialize the request: {}", e),
         }
     }
-}
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let status_code = match &self {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
             Self::IncorrectCompilerVersions
             | Self::UnsupportedCompilerVersions
             | Self::MissingZkCompilerVersion
@@ -92,19 +93,29 @@ impl IntoResponse for ApiError {
             | Self::NoDeployedContract
             | Self::AlreadyVerified
             | Self::ActiveRequestExists(_)
+            | Self::UnsupportedChain(_)
             | Self::DeserializationError(_) => StatusCode::BAD_REQUEST,
 
             Self::UnsupportedContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
 
             Self::RequestNotFound | Self::VerificationInfoNotFound => StatusCode::NOT_FOUND,
 
-            Self::Internal(err) => {
-                // Do not expose the error details to the client, but log it.
-                tracing::warn!("Internal error: {err:#}");
-                StatusCode::INTERNAL_SERVER_ERROR
-            }
-        };
-        (status_code, self.message()).into_response()
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Logs internal errors. Their details are not exposed to the client.
+    pub(crate) fn log_internal(&self) {
+        if let Self::Internal(err) = self {
+            tracing::warn!("Internal error: {err:#}");
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        self.log_internal();
+        (self.status_code(), self.message()).into_response()
     }
 }
 
@@ -227,7 +238,7 @@ impl RestApi {
 
     /// Add a contract verification job to the queue if the requested contract wasn't previously verified.
     #[tracing::instrument(skip(self_, request))]
-    async fn verification(
+    pub(crate) async fn verification(
         State(self_): State<Arc<Self>>,
         request: VerificationIncomingRequest,
     ) -> Result<usize, ApiError> {
@@ -372,25 +383,37 @@ impl RestApi {
     pub async fn verification_info(
         State(self_): State<Arc<Self>>,
         address: Path<Address>,
-    ) -> ApiResult<VerificationInfo> {
+    ) -> ApiResult<ContractVerificationInfo> {
         let method_latency = METRICS.call[&"contract_verification_info"].start();
-        let mut conn = self_
+        let info = self_
+            .fetch_verification_info(*address)
+            .await?
+            .ok_or(ApiError::VerificationInfoNotFound)?;
+        let proxy = self_.resolve_proxy(*address).await?;
+        method_latency.observe();
+        Ok(Json(ContractVerificationInfo { info, proxy }))
+    }
+
+    /// Fetches verification info for the contract, falling back to similar and partial match lookups.
+    pub(crate) async fn fetch_verification_info(
+        &self,
+        address: Address,
+    ) -> Result<Option<VerificationInfo>, ApiError> {
+        let mut conn = self
             .replica_connection_pool
             .connection_tagged("api")
             .await?;
         let mut dal = conn.contract_verification_dal();
 
-        let info = if let Some(info) = dal.get_contract_verification_info(*address).await? {
-            info
-        } else if let Some(partial_match) =
-            get_partial_match_verification_info(&mut dal, *address).await?
-        {
-            partial_match
+        if let Some(info) = dal.get_contract_verification_info(address).await? {
+            Ok(Some(info))
+        } else if let Some(mut info) = dal.get_similar_match_verification_info(address).await? {
+            info.verification_problems
+                .push(VerificationProblem::SimilarMatch);
+            Ok(Some(info))
         } else {
-            return Err(ApiError::VerificationInfoNotFound);
-        };
-        method_latency.observe();
-        Ok(Json(info))
+            Ok(get_partial_match_verification_info(&mut dal, address).await?)
+        }
     }
 }
 
@@ -3,6 +3,7 @@ use std::net::SocketAddr;
 use anyhow::Context as _;
 use tokio::sync::watch;
 use zksync_dal::ConnectionPool;
+use zksync_types::L2ChainId;
 
 use self::api_decl::RestApi;
 
@@ -11,16 +12,20 @@ mod api_impl;
 mod cache;
 mod metrics;
 pub mod node;
+mod proxy;
+mod sourcify;
 #[cfg(test)]
 mod tests;
 
 pub async fn start_server(
     master_connection_pool: ConnectionPool<zksync_dal::Core>,
     replica_connection_pool: ConnectionPool<zksync_dal::Core>,
+    l2_chain_id: L2ChainId,
     bind_address: SocketAddr,
     mut stop_receiver: watch::Receiver<bool>,
 ) -> anyhow::Result<()> {
-    let api = RestApi::new(master_connection_pool, replica_connection_pool).into_router();
+    let api =
+        RestApi::new(master_connection_pool, replica_connection_pool, l2_chain_id).into_router();
 
     let listener = tokio::net::TcpListener::bind(bind_address)
         .await
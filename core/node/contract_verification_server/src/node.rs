@@ -9,12 +9,25 @@ use zksync_node_framework::{
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
+use zksync_types::L2ChainId;
 
 /// Wiring layer for contract verification
 ///
 /// Responsible for initialization of the contract verification server.
 #[derive(Debug)]
-pub struct ContractVerificationApiLayer(pub ContractVerifierConfig);
+pub struct ContractVerificationApiLayer {
+    config: ContractVerifierConfig,
+    l2_chain_id: L2ChainId,
+}
+
+impl ContractVerificationApiLayer {
+    pub fn new(config: ContractVerifierConfig, l2_chain_id: L2ChainId) -> Self {
+        Self {
+            config,
+            l2_chain_id,
+        }
+    }
+}
 
 #[derive(Debug, FromContext)]
 pub struct Input {
@@ -43,7 +56,8 @@ impl WiringLayer for ContractVerificationApiLayer {
         let contract_verification_api_task = ContractVerificationApiTask {
             master_pool,
             replica_pool,
-            config: self.0,
+            config: self.config,
+            l2_chain_id: self.l2_chain_id,
         };
         Ok(Output {
             contract_verification_api_task,
@@ -56,6 +70,7 @@ pub struct ContractVerificationApiTask {
     master_pool: ConnectionPool<Core>,
     replica_pool: ConnectionPool<Core>,
     config: ContractVerifierConfig,
+    l2_chain_id: L2ChainId,
 }
 
 #[async_trait::async_trait]
@@ -68,6 +83,7 @@ impl Task for ContractVerificationApiTask {
         crate::start_server(
             self.master_pool,
             self.replica_pool,
+            self.l2_chain_id,
             self.config.bind_addr(),
             stop_receiver.0,
         )
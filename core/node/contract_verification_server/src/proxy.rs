@@ -0,0 +1,82 @@
+//! Resolution of upgradeable proxy contracts.
+
+use zksync_dal::CoreDal;
+use zksync_types::{
+    contract_verification::proxy::{
+        ProxyInfo, ProxyKind, ProxySlots, BEACON_IMPLEMENTATION_SLOT, EIP1967_IMPLEMENTATION_SLOT,
+    },
+    h256_to_address, AccountTreeId, Address, StorageKey, H256,
+};
+
+use crate::{api_decl::RestApi, api_impl::ApiError};
+
+/// Maximum number of implementation changes returned for a proxy.
+const MAX_IMPLEMENTATION_CHANGES: usize = 100;
+
+fn hashed_slot_keys<const N: usize>(address: Address, slots: [H256; N]) -> [H256; N] {
+    slots.map(|slot| StorageKey::new(AccountTreeId::new(address), slot).hashed_key())
+}
+
+impl RestApi {
+    /// Detects whether the contract is a proxy using the standard proxy storage slots, and if it is, resolves
+    /// the current implementation, its ABI and the history of implementation changes.
+    pub(crate) async fn resolve_proxy(
+        &self,
+        address: Address,
+    ) -> Result<Option<ProxyInfo>, ApiError> {
+        let mut storage = self
+            .replica_connection_pool
+            .connection_tagged("api")
+            .await?;
+
+        let hashed_keys = hashed_slot_keys(address, ProxySlots::SLOTS);
+        let values = storage.storage_web3_dal().get_values(&hashed_keys).await?;
+        let slots = ProxySlots::from_values(
+            hashed_keys.map(|key| values.get(&key).copied().unwrap_or_default()),
+        );
+        let Some((kind, target)) = slots.detect() else {
+            return Ok(None);
+        };
+
+        // For beacon proxies, the implementation is stored by the beacon, and `Upgraded` events are emitted by it.
+        let (implementation_address, beacon_address, upgrades_emitter) =
+            if kind == ProxyKind::Beacon {
+                let beacon_slots = [EIP1967_IMPLEMENTATION_SLOT, BEACON_IMPLEMENTATION_SLOT];
+                let hashed_keys = hashed_slot_keys(target, beacon_slots);
+                let values = storage.storage_web3_dal().get_values(&hashed_keys).await?;
+                let implementation = hashed_keys
+                    .iter()
+                    .filter_map(|key| values.get(key))
+                    .find(|value| !value.is_zero());
+                let Some(implementation) = implementation else {
+                    tracing::debug!(
+                        proxy = ?address,
+                        beacon = ?target,
+                        "Cannot resolve implementation for beacon"
+                    );
+                    return Ok(None);
+                };
+                (h256_to_address(implementation), Some(target), target)
+            } else {
+                (target, None, address)
+            };
+
+        let implementation_history = storage
+            .contract_verification_dal()
+            .get_proxy_implementation_changes(upgrades_emitter, MAX_IMPLEMENTATION_CHANGES)
+            .await?;
+        drop(storage);
+
+        let implementation_abi = self
+            .fetch_verification_info(implementation_address)
+            .await?
+            .map(|info| info.artifacts.abi);
+        Ok(Some(ProxyInfo {
+            kind,
+            implementation_address,
+            beacon_address,
+            implementation_abi,
+            implementation_history,
+        }))
+    }
+}
@@ -0,0 +1,252 @@
+//! Sourcify-compatible (v2) verification API.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+use zksync_dal::CoreDal;
+use zksync_types::{
+    contract_verification::{
+        api::{SourceCodeData, VerificationInfo},
+        sourcify::{
+            SourcifyContract, SourcifyContractStatus, SourcifyError, SourcifyJobStatus,
+            SourcifyMatch, SourcifySource, SourcifyVerificationRequest,
+            SourcifyVerificationResponse,
+        },
+    },
+    Address,
+};
+
+use crate::{api_decl::RestApi, api_impl::ApiError, metrics::METRICS};
+
+/// Wrapper for [`ApiError`] returning errors in the Sourcify format.
+#[derive(Debug)]
+pub(crate) struct SourcifyApiError(pub ApiError);
+
+impl From<ApiError> for SourcifyApiError {
+    fn from(err: ApiError) -> Self {
+        Self(err)
+    }
+}
+
+impl From<ApiError> for SourcifyError {
+    fn from(err: ApiError) -> Self {
+        let custom_code = match &err {
+            ApiError::AlreadyVerified => "already_verified",
+            ApiError::ActiveRequestExists(_) => "duplicate_verification_request",
+            ApiError::UnsupportedChain(_) => "unsupported_chain",
+            ApiError::NoDeployedContract => "contract_not_deployed",
+            ApiError::UnsupportedCompilerVersions => "unsupported_compiler_version",
+            ApiError::IncorrectCompilerVersions
+            | ApiError::MissingZkCompilerVersion
+            | ApiError::BogusZkCompilerVersion
+            | ApiError::DeserializationError(_) => "invalid_parameter",
+            ApiError::UnsupportedContentType => "unsupported_content_type",
+            ApiError::RequestNotFound => "job_not_found",
+            ApiError::VerificationInfoNotFound => "not_found",
+            ApiError::Internal(_) => "internal_error",
+        };
+        Self {
+            custom_code: custom_code.to_owned(),
+            message: err.message(),
+        }
+    }
+}
+
+impl IntoResponse for SourcifyApiError {
+    fn into_response(self) -> Response {
+        self.0.log_internal();
+        let status_code = self.0.status_code();
+        (status_code, Json(SourcifyError::from(self.0))).into_response()
+    }
+}
+
+type SourcifyResult<T> = Result<T, SourcifyApiError>;
+
+/// Query parameters for [`RestApi::sourcify_contract()`].
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct ContractFieldsQuery {
+    /// Comma-separated list of optional fields to return, or `all`.
+    #[serde(default)]
+    fields: Option<String>,
+}
+
+impl ContractFieldsQuery {
+    fn contains(&self, field: &str) -> bool {
+        self.fields.as_deref().is_some_and(|fields| {
+            fields
+                .split(',')
+                .any(|requested| requested == field || requested == "all")
+        })
+    }
+}
+
+impl RestApi {
+    fn check_chain_id(&self, chain_id: &str) -> Result<(), ApiError> {
+        if chain_id == self.l2_chain_id.as_u64().to_string() {
+            Ok(())
+        } else {
+            Err(ApiError::UnsupportedChain(chain_id.to_owned()))
+        }
+    }
+
+    fn contract_status(
+        &self,
+        address: Address,
+        info: Option<&VerificationInfo>,
+    ) -> SourcifyContractStatus {
+        let match_status = info.map(SourcifyMatch::for_verification_info);
+        SourcifyContractStatus {
+            match_status,
+            creation_match: None,
+            runtime_match: match_status,
+            chain_id: self.l2_chain_id.as_u64().to_string(),
+            address,
+            verified_at: info.map(|info| info.verified_at),
+        }
+    }
+
+    /// Submits a verification request based on the contract metadata and sources.
+    #[tracing::instrument(skip(self_, request))]
+    pub async fn sourcify_verify(
+        State(self_): State<Arc<Self>>,
+        Path((chain_id, address)): Path<(String, Address)>,
+        Json(request): Json<SourcifyVerificationRequest>,
+    ) -> SourcifyResult<(StatusCode, Json<SourcifyVerificationResponse>)> {
+        let method_latency = METRICS.call[&"sourcify_verify"].start();
+        self_.check_chain_id(&chain_id)?;
+        let request = request
+            .to_verification_request(address)
+            .map_err(ApiError::DeserializationError)?;
+        let verification_id = Self::verification(State(self_), request).await?;
+
+        method_latency.observe();
+        let response = SourcifyVerificationResponse {
+            verification_id: verification_id.to_string(),
+        };
+        Ok((StatusCode::ACCEPTED, Json(response)))
+    }
+
+    /// Returns the status of a verification job.
+    #[tracing::instrument(skip(self_))]
+    pub async fn sourcify_job_status(
+        State(self_): State<Arc<Self>>,
+        Path(id): Path<usize>,
+    ) -> SourcifyResult<Json<SourcifyJobStatus>> {
+        let method_latency = METRICS.call[&"sourcify_job_status"].start();
+        let mut storage = self_
+            .replica_connection_pool
+            .connection_tagged("api")
+            .await
+            .map_err(ApiError::from)?;
+        let mut dal = storage.contract_verification_dal();
+        let status = dal
+            .get_verification_request_status(id)
+            .await
+            .map_err(ApiError::from)?
+            .ok_or(ApiError::RequestNotFound)?;
+        let address = dal
+            .get_verification_request_contract_address(id)
+            .await
+            .map_err(ApiError::from)?
+            .ok_or(ApiError::RequestNotFound)?;
+        drop(storage);
+
+        let (is_job_completed, info, error) = match status.status.as_str() {
+            "successful" => (true, self_.fetch_verification_info(address).await?, None),
+            "failed" => {
+                let mut message = status.error.unwrap_or_default();
+                for compilation_error in status.compilation_errors.into_iter().flatten() {
+                    message.push('\n');
+                    message.push_str(&compilation_error);
+                }
+                let error = SourcifyError {
+                    custom_code: "verification_failed".to_owned(),
+                    message,
+                };
+                (true, None, Some(error))
+            }
+            _ => (false, None, None),
+        };
+
+        method_latency.observe();
+        Ok(Json(SourcifyJobStatus {
+            is_job_completed,
+            verification_id: id.to_string(),
+            contract: self_.contract_status(address, info.as_ref()),
+            error,
+        }))
+    }
+
+    /// Returns details of a verified contract. Optional fields (`sources`, `abi`, `stdJsonInput`) are returned
+    /// if requested via the `fields` query parameter.
+    #[tracing::instrument(skip(self_))]
+    pub async fn sourcify_contract(
+        State(self_): State<Arc<Self>>,
+        Path((chain_id, address)): Path<(String, Address)>,
+        Query(query): Query<ContractFieldsQuery>,
+    ) -> SourcifyResult<Json<SourcifyContract>> {
+        let method_latency = METRICS.call[&"sourcify_contract"].start();
+        self_.check_chain_id(&chain_id)?;
+        let info = self_
+            .fetch_verification_info(address)
+            .await?
+            .ok_or(ApiError::VerificationInfoNotFound)?;
+
+        let status = self_.contract_status(address, Some(&info));
+        let abi = query.contains("abi").then(|| info.artifacts.abi.clone());
+        let (sources, std_json_input) = match info.request.req.source_code_data {
+            SourceCodeData::StandardJsonInput(input) => {
+                let sources = query.contains("sources").then(|| {
+                    input
+                        .get("sources")
+                        .and_then(serde_json::Value::as_object)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|(path, source)| {
+                            let content = source.get("content")?.as_str()?.to_owned();
+                            Some((path.clone(), SourcifySource { content }))
+                        })
+                        .collect()
+                });
+                let std_json_input = query
+                    .contains("stdJsonInput")
+                    .then_some(serde_json::Value::Object(input));
+                (sources, std_json_input)
+            }
+            SourceCodeData::SolSingleFile(content) | SourceCodeData::YulSingleFile(content) => {
+                let contract_name = &info.request.req.contract_name;
+                let path = match contract_name.rsplit_once(':') {
+                    Some((path, _)) => path.to_owned(),
+                    None => format!("{contract_name}.sol"),
+                };
+                let sources = query
+                    .contains("sources")
+                    .then(|| BTreeMap::from([(path, SourcifySource { content })]));
+                (sources, None)
+            }
+            SourceCodeData::VyperMultiFile(files) => {
+                let sources = query.contains("sources").then(|| {
+                    files
+                        .into_iter()
+                        .map(|(path, content)| (path, SourcifySource { content }))
+                        .collect()
+                });
+                (sources, None)
+            }
+        };
+
+        method_latency.observe();
+        Ok(Json(SourcifyContract {
+            status,
+            sources,
+            abi,
+            std_json_input,
+        }))
+    }
+}
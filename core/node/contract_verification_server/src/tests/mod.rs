@@ -2,6 +2,7 @@
 
 use std::{str, vec};
 
+use axum::http::StatusCode;
 use test_casing::test_casing;
 use utils::{mock_verification_info, MockApiClient, MockContractVerifier};
 use zksync_types::{
@@ -12,14 +13,20 @@ use zksync_types::{
             EtherscanBoolean, EtherscanCodeFormat, EtherscanRequest, EtherscanRequestPayload,
             EtherscanVerificationRequest,
         },
+        proxy::ProxyKind,
+        sourcify::SourcifyMatch,
     },
-    Address,
+    web3::keccak256,
+    Address, L2BlockNumber, H256,
 };
 
 use super::*;
 use crate::{
     api_impl::ApiError,
-    tests::utils::{mock_deploy_contract, prepare_storage, SOLC_VERSION, ZKSOLC_VERSION},
+    tests::utils::{
+        mock_deploy_contract, mock_deploy_events, mock_upgrade_proxy, prepare_storage, L2_CHAIN_ID,
+        SOLC_VERSION, ZKSOLC_VERSION,
+    },
 };
 
 mod utils;
@@ -277,6 +284,132 @@ async fn partial_verification(bytecode_kind: BytecodeMarker) {
     assert_eq!(info.verification_problems, vec![]);
 }
 
+#[test_casing(2, [BytecodeMarker::EraVm, BytecodeMarker::Evm])]
+#[tokio::test]
+async fn similar_match_verification(bytecode_kind: BytecodeMarker) {
+    let pool = ConnectionPool::test_pool().await;
+    let contract_verifier = MockContractVerifier::new(pool.clone());
+    let client = MockApiClient::new(pool.clone());
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let address = Address::repeat_byte(0x23);
+    let similar_address = Address::repeat_byte(0x24);
+    mock_deploy_contract(&mut storage, address, bytecode_kind).await;
+    mock_deploy_contract(&mut storage, similar_address, bytecode_kind).await;
+    mock_deploy_events(&mut storage, &[address, similar_address], bytecode_kind).await;
+
+    let verification_request = serde_json::json!({
+        "contractAddress": address,
+        "sourceCode": "contract Test {}",
+        "contractName": "Test",
+        "compilerZksolcVersion": match bytecode_kind {
+            BytecodeMarker::EraVm => Some(ZKSOLC_VERSION),
+            BytecodeMarker::Evm => None,
+        },
+        "compilerSolcVersion": SOLC_VERSION,
+        "optimizationUsed": true,
+    });
+    let id = client
+        .send_verification_request(&verification_request)
+        .await;
+    contract_verifier
+        .pick_up_next_request(id, &verification_request, bytecode_kind)
+        .await;
+    contract_verifier
+        .verify_contract(mock_verification_info(id, &verification_request))
+        .await;
+    client
+        .assert_verification_info_error(similar_address, ApiError::VerificationInfoNotFound)
+        .await;
+
+    storage
+        .contract_verification_dal()
+        .insert_similar_matches_for_l2_blocks(L2BlockNumber(0)..=L2BlockNumber(0))
+        .await
+        .unwrap();
+
+    let info = client.verification_info(similar_address).await;
+    assert_eq!(info.request.id, id);
+    assert_eq!(info.request.req.contract_address, address);
+    assert_eq!(
+        info.verification_problems,
+        vec![VerificationProblem::SimilarMatch]
+    );
+    // The verified contract itself must not be affected.
+    let info = client.verification_info(address).await;
+    assert_eq!(info.verification_problems, vec![]);
+
+    let contract = client.sourcify_contract(similar_address, "").await.unwrap();
+    assert_eq!(contract.status.match_status, Some(SourcifyMatch::Match));
+    assert_eq!(contract.status.address, similar_address);
+}
+
+#[tokio::test]
+async fn proxy_verification_info() {
+    let pool = ConnectionPool::test_pool().await;
+    let contract_verifier = MockContractVerifier::new(pool.clone());
+    let client = MockApiClient::new(pool.clone());
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let implementation_abi = serde_json::json!([{
+        "type": "function",
+        "name": "increment",
+        "inputs": [],
+        "outputs": [],
+        "stateMutability": "nonpayable",
+    }]);
+    let implementation_address = Address::repeat_byte(0x23);
+    let proxy_address = Address::repeat_byte(0x24);
+    for (id, address) in [(1, implementation_address), (2, proxy_address)] {
+        mock_deploy_contract(&mut storage, address, BytecodeMarker::EraVm).await;
+        let verification_request = serde_json::json!({
+            "contractAddress": address,
+            "sourceCode": "contract Test {}",
+            "contractName": "Test",
+            "compilerZksolcVersion": ZKSOLC_VERSION,
+            "compilerSolcVersion": SOLC_VERSION,
+            "optimizationUsed": true,
+        });
+        assert_eq!(
+            client
+                .send_verification_request(&verification_request)
+                .await,
+            id
+        );
+        contract_verifier
+            .pick_up_next_request(id, &verification_request, BytecodeMarker::EraVm)
+            .await;
+        let mut verification_info = mock_verification_info(id, &verification_request);
+        if address == implementation_address {
+            verification_info.artifacts.abi = implementation_abi.clone();
+        }
+        contract_verifier.verify_contract(verification_info).await;
+    }
+
+    assert_eq!(client.proxy_info(proxy_address).await, None);
+    assert_eq!(client.proxy_info(implementation_address).await, None);
+
+    mock_upgrade_proxy(&mut storage, proxy_address, implementation_address).await;
+    let proxy_info = client
+        .proxy_info(proxy_address)
+        .await
+        .expect("proxy not detected");
+    assert_eq!(proxy_info.kind, ProxyKind::Eip1967);
+    assert_eq!(proxy_info.implementation_address, implementation_address);
+    assert_eq!(proxy_info.beacon_address, None);
+    assert_eq!(proxy_info.implementation_abi, Some(implementation_abi));
+    assert_eq!(proxy_info.implementation_history.len(), 1);
+    let change = &proxy_info.implementation_history[0];
+    assert_eq!(change.implementation_address, implementation_address);
+    assert_eq!(change.l2_block_number, L2BlockNumber(0));
+
+    // The proxy's own data must be unaffected.
+    let info = client.verification_info(proxy_address).await;
+    assert_eq!(info.request.req.contract_address, proxy_address);
+}
+
 #[test_casing(2, [BytecodeMarker::EraVm, BytecodeMarker::Evm])]
 #[tokio::test]
 async fn submitting_request_with_invalid_compiler_type(bytecode_kind: BytecodeMarker) {
@@ -413,3 +546,113 @@ async fn mismatched_compiler_type() {
         )
         .await;
 }
+
+fn sourcify_request(bytecode_kind: BytecodeMarker) -> serde_json::Value {
+    const SOURCE: &str = "contract Test {}";
+
+    serde_json::json!({
+        "sources": { "Test.sol": SOURCE },
+        "metadata": {
+            "compiler": { "version": format!("{SOLC_VERSION}+commit.8e3bd5b3") },
+            "language": "Solidity",
+            "settings": {
+                "compilationTarget": { "Test.sol": "Test" },
+                "optimizer": { "enabled": true, "runs": 200 },
+            },
+            "sources": {
+                "Test.sol": { "keccak256": H256(keccak256(SOURCE.as_bytes())) },
+            },
+        },
+        "zksolcVersion": match bytecode_kind {
+            BytecodeMarker::EraVm => Some(ZKSOLC_VERSION),
+            BytecodeMarker::Evm => None,
+        },
+    })
+}
+
+#[test_casing(2, [BytecodeMarker::EraVm, BytecodeMarker::Evm])]
+#[tokio::test]
+async fn submitting_sourcify_request(bytecode_kind: BytecodeMarker) {
+    let pool = ConnectionPool::test_pool().await;
+    let contract_verifier = MockContractVerifier::new(pool.clone());
+    let client = MockApiClient::new(pool.clone());
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let address = Address::repeat_byte(0x23);
+    let request = sourcify_request(bytecode_kind);
+    let (status, err) = client
+        .send_sourcify_request(L2_CHAIN_ID, address, &request)
+        .await
+        .unwrap_err();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(err.custom_code, "contract_not_deployed");
+
+    mock_deploy_contract(&mut storage, address, bytecode_kind).await;
+    let (status, err) = client
+        .send_sourcify_request(L2_CHAIN_ID + 1, address, &request)
+        .await
+        .unwrap_err();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(err.custom_code, "unsupported_chain");
+
+    let id = client
+        .send_sourcify_request(L2_CHAIN_ID, address, &request)
+        .await
+        .unwrap();
+    assert_eq!(id, "1");
+    let status = client.sourcify_job_status(&id).await;
+    assert!(!status.is_job_completed);
+    assert_eq!(status.contract.match_status, None);
+
+    // The request is converted to the standard JSON input.
+    let verification_request = serde_json::json!({
+        "contractAddress": address,
+        "contractName": "Test.sol:Test",
+        "codeFormat": "solidity-standard-json-input",
+        "sourceCode": {},
+        "compilerZksolcVersion": match bytecode_kind {
+            BytecodeMarker::EraVm => Some(ZKSOLC_VERSION),
+            BytecodeMarker::Evm => None,
+        },
+        "compilerSolcVersion": SOLC_VERSION,
+        "optimizationUsed": true,
+    });
+    contract_verifier
+        .pick_up_next_request(1, &verification_request, bytecode_kind)
+        .await;
+    let mut verification_info = mock_verification_info(1, &verification_request);
+    verification_info.verification_problems = vec![VerificationProblem::IncorrectMetadata];
+    contract_verifier.verify_contract(verification_info).await;
+
+    let status = client.sourcify_job_status(&id).await;
+    assert!(status.is_job_completed);
+    assert!(status.error.is_none());
+    assert_eq!(status.contract.match_status, Some(SourcifyMatch::Match));
+    assert_eq!(status.contract.runtime_match, Some(SourcifyMatch::Match));
+    assert_eq!(status.contract.address, address);
+
+    let contract = client
+        .sourcify_contract(address, "abi,stdJsonInput")
+        .await
+        .unwrap();
+    assert_eq!(contract.status.match_status, Some(SourcifyMatch::Match));
+    assert!(contract.abi.is_some());
+    assert!(contract.std_json_input.is_some());
+    assert!(contract.sources.is_none());
+}
+
+#[tokio::test]
+async fn querying_missing_sourcify_contract() {
+    let pool = ConnectionPool::test_pool().await;
+    let client = MockApiClient::new(pool.clone());
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let (status, err) = client
+        .sourcify_contract(Address::repeat_byte(0x23), "all")
+        .await
+        .unwrap_err();
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(err.custom_code, "not_found");
+}
@@ -11,21 +11,32 @@ use tower::ServiceExt;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_node_test_utils::create_l2_block;
 use zksync_types::{
+    address_to_h256,
     bytecode::{BytecodeHash, BytecodeMarker},
     contract_verification::{
         api::{
-            CompilationArtifacts, CompilerVersions, VerificationIncomingRequest, VerificationInfo,
-            VerificationRequest, VerificationRequestStatus,
+            CompilationArtifacts, CompilerVersions, ContractVerificationInfo,
+            VerificationIncomingRequest, VerificationInfo, VerificationRequest,
+            VerificationRequestStatus,
         },
         etherscan::EtherscanResponse,
+        proxy::{ProxyInfo, EIP1967_IMPLEMENTATION_SLOT, UPGRADED_EVENT_SIGNATURE},
+        sourcify::{
+            SourcifyContract, SourcifyError, SourcifyJobStatus, SourcifyVerificationResponse,
+        },
     },
-    get_code_key, Address, L2BlockNumber, ProtocolVersion, StorageLog, H256,
+    get_code_key,
+    tx::IncludedTxLocation,
+    AccountTreeId, Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersion, StorageKey,
+    StorageLog, CONTRACT_DEPLOYER_ADDRESS, H256,
 };
+use zksync_vm_interface::VmEvent;
 
 use crate::{api_impl::ApiError, RestApi};
 
 pub(super) const SOLC_VERSION: &str = "0.8.27";
 pub(super) const ZKSOLC_VERSION: &str = "1.5.6";
+pub(super) const L2_CHAIN_ID: u64 = 270;
 
 pub(super) async fn prepare_storage(storage: &mut Connection<'_, Core>) {
     storage
@@ -68,6 +79,73 @@ pub(super) async fn mock_deploy_contract(
         .unwrap()
 }
 
+/// Saves deployment events for the specified contracts, all of which share the same bytecode.
+pub(super) async fn mock_deploy_events(
+    storage: &mut Connection<'_, Core>,
+    addresses: &[Address],
+    kind: BytecodeMarker,
+) {
+    let bytecode_hash = match kind {
+        BytecodeMarker::EraVm => BytecodeHash::for_bytecode(&[0; 32]).value(),
+        BytecodeMarker::Evm => BytecodeHash::for_evm_bytecode(0, &[0; 96]).value(),
+    };
+    let events: Vec<_> = addresses
+        .iter()
+        .map(|address| VmEvent {
+            location: (L1BatchNumber(0), 0),
+            address: CONTRACT_DEPLOYER_ADDRESS,
+            indexed_topics: vec![
+                VmEvent::DEPLOY_EVENT_SIGNATURE,
+                address_to_h256(&Address::repeat_byte(0xff)),
+                bytecode_hash,
+                address_to_h256(address),
+            ],
+            value: vec![],
+        })
+        .collect();
+    let location = IncludedTxLocation {
+        tx_hash: H256::repeat_byte(1),
+        tx_index_in_l2_block: 0,
+    };
+    storage
+        .events_dal()
+        .save_events(L2BlockNumber(0), &[(location, events.iter().collect())])
+        .await
+        .unwrap();
+}
+
+/// Points the EIP-1967 implementation slot of the proxy to the implementation and emits the corresponding
+/// `Upgraded` event.
+pub(super) async fn mock_upgrade_proxy(
+    storage: &mut Connection<'_, Core>,
+    proxy: Address,
+    implementation: Address,
+) {
+    let slot_key = StorageKey::new(AccountTreeId::new(proxy), EIP1967_IMPLEMENTATION_SLOT);
+    let upgrade_log = StorageLog::new_write_log(slot_key, address_to_h256(&implementation));
+    storage
+        .storage_logs_dal()
+        .append_storage_logs(L2BlockNumber(0), &[upgrade_log])
+        .await
+        .unwrap();
+
+    let upgraded_event = VmEvent {
+        location: (L1BatchNumber(0), 0),
+        address: proxy,
+        indexed_topics: vec![UPGRADED_EVENT_SIGNATURE, address_to_h256(&implementation)],
+        value: vec![],
+    };
+    let location = IncludedTxLocation {
+        tx_hash: H256::repeat_byte(2),
+        tx_index_in_l2_block: 0,
+    };
+    storage
+        .events_dal()
+        .save_events(L2BlockNumber(0), &[(location, vec![&upgraded_event])])
+        .await
+        .unwrap();
+}
+
 pub(super) fn mock_verification_info(
     id: usize,
     verification_request: &serde_json::Value,
@@ -163,7 +241,8 @@ pub(super) struct MockApiClient {
 impl MockApiClient {
     pub fn new(pool: ConnectionPool<Core>) -> Self {
         Self {
-            router: RestApi::new(pool.clone(), pool).into_router(),
+            router: RestApi::new(pool.clone(), pool, L2ChainId::new(L2_CHAIN_ID).unwrap())
+                .into_router(),
         }
     }
 
@@ -218,6 +297,15 @@ impl MockApiClient {
         Self::json_response::<VerificationInfo>(response).await
     }
 
+    pub async fn proxy_info(&self, address: Address) -> Option<ProxyInfo> {
+        let response = self
+            .send_request(&format!("/contract_verification/info/{address:?}"), None)
+            .await;
+        Self::json_response::<ContractVerificationInfo>(response)
+            .await
+            .proxy
+    }
+
     pub async fn assert_verification_info_error(&self, address: Address, expected_err: ApiError) {
         let response = self
             .send_request(&format!("/contract_verification/info/{address:?}"), None)
@@ -239,6 +327,53 @@ impl MockApiClient {
         Self::json_response::<Vec<String>>(response).await
     }
 
+    pub async fn send_sourcify_request(
+        &self,
+        chain_id: u64,
+        address: Address,
+        request: &serde_json::Value,
+    ) -> Result<String, (StatusCode, SourcifyError)> {
+        let response = self
+            .send_request(
+                &format!("/v2/verify/metadata/{chain_id}/{address:?}"),
+                Some(request),
+            )
+            .await;
+        if response.status() == StatusCode::ACCEPTED {
+            let body = response.collect().await.unwrap().to_bytes();
+            let response: SourcifyVerificationResponse = serde_json::from_slice(&body).unwrap();
+            Ok(response.verification_id)
+        } else {
+            Err(Self::sourcify_error(response).await)
+        }
+    }
+
+    pub async fn sourcify_job_status(&self, id: &str) -> SourcifyJobStatus {
+        let response = self.send_request(&format!("/v2/verify/{id}"), None).await;
+        Self::json_response::<SourcifyJobStatus>(response).await
+    }
+
+    pub async fn sourcify_contract(
+        &self,
+        address: Address,
+        fields: &str,
+    ) -> Result<SourcifyContract, (StatusCode, SourcifyError)> {
+        let url = format!("/v2/contract/{L2_CHAIN_ID}/{address:?}?fields={fields}");
+        let response = self.send_request(&url, None).await;
+        if response.status() == StatusCode::OK {
+            Ok(Self::json_response::<SourcifyContract>(response).await)
+        } else {
+            Err(Self::sourcify_error(response).await)
+        }
+    }
+
+    async fn sourcify_error(response: Response<Body>) -> (StatusCode, SourcifyError) {
+        let status = response.status();
+        let body = response.collect().await.unwrap().to_bytes();
+        let error = serde_json::from_slice(&body).expect("Unable to deserialize Sourcify error");
+        (status, error)
+    }
+
     async fn send_request(&self, url: &str, body: Option<&serde_json::Value>) -> Response<Body> {
         let (method, body) = match body {
             Some(body) => (Method::POST, Body::from(serde_json::to_vec(body).unwrap())),
@@ -20,7 +20,7 @@ use zksync_types::{
 
 use crate::{
     avail::sdk::{GasRelayClient, RawAvailClient},
-    utils::{to_non_retriable_da_error, to_retriable_da_error},
+    utils::{abi_encode_chunks_inclusion_data, to_non_retriable_da_error, to_retriable_da_error},
 };
 
 #[derive(Debug, Clone)]
@@ -307,6 +307,17 @@ impl DataAvailabilityClient for AvailClient {
         ClientType::Avail
     }
 
+    fn supports_chunked_inclusion(&self) -> bool {
+        true
+    }
+
+    async fn combine_inclusion_data(
+        &self,
+        chunks: Vec<InclusionData>,
+    ) -> Result<InclusionData, DAError> {
+        Ok(abi_encode_chunks_inclusion_data(chunks))
+    }
+
     async fn balance(&self) -> Result<u64, DAError> {
         match self.sdk_client.as_ref() {
             AvailClientMode::Default(client) => {
@@ -112,6 +112,23 @@ impl DataAvailabilityClient for CelestiaClient {
         ClientType::Celestia
     }
 
+    fn supports_chunked_inclusion(&self) -> bool {
+        true
+    }
+
+    async fn combine_inclusion_data(
+        &self,
+        chunks: Vec<InclusionData>,
+    ) -> Result<InclusionData, DAError> {
+        // Inclusion data isn't verified on L1 for Celestia, so it's empty for every chunk
+        if let Some(position) = chunks.iter().position(|chunk| !chunk.data.is_empty()) {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "unexpected non-empty inclusion data for chunk #{position}"
+            )));
+        }
+        Ok(InclusionData::default())
+    }
+
     async fn balance(&self) -> Result<u64, DAError> {
         self.client
             .balance()
@@ -16,7 +16,7 @@ use zksync_da_client::{
     DataAvailabilityClient,
 };
 
-use crate::utils::to_retriable_da_error;
+use crate::utils::{abi_encode_chunks_inclusion_data, to_retriable_da_error};
 
 // We can't implement DataAvailabilityClient for an outside struct, so it is needed to defined this intermediate struct
 #[derive(Debug, Clone)]
@@ -117,6 +117,17 @@ impl DataAvailabilityClient for EigenDAClient {
         ClientType::Eigen
     }
 
+    fn supports_chunked_inclusion(&self) -> bool {
+        true
+    }
+
+    async fn combine_inclusion_data(
+        &self,
+        chunks: Vec<InclusionData>,
+    ) -> Result<InclusionData, DAError> {
+        Ok(abi_encode_chunks_inclusion_data(chunks))
+    }
+
     async fn balance(&self) -> Result<u64, DAError> {
         Ok(0) // TODO fetch from API when payments are enabled in Eigen (PE-305)
     }
@@ -1,6 +1,7 @@
 pub use self::{
     avail::AvailWiringLayer, celestia::CelestiaWiringLayer, eigen::EigenWiringLayer,
     no_da::NoDAClientWiringLayer, object_store::ObjectStorageClientWiringLayer,
+    sidecar::SidecarWiringLayer,
 };
 
 mod avail;
@@ -8,3 +9,4 @@ mod celestia;
 mod eigen;
 mod no_da;
 mod object_store;
+mod sidecar;
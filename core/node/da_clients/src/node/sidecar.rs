@@ -0,0 +1,43 @@
+use zksync_config::configs::da_client::sidecar::SidecarConfig;
+use zksync_da_client::{node::DAClientResource, DataAvailabilityClient};
+use zksync_node_framework::{
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+use crate::sidecar::SidecarClient;
+
+#[derive(Debug)]
+pub struct SidecarWiringLayer {
+    config: SidecarConfig,
+}
+
+impl SidecarWiringLayer {
+    pub fn new(config: SidecarConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+pub struct Output {
+    pub client: DAClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SidecarWiringLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "sidecar_client_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let client: Box<dyn DataAvailabilityClient> =
+            Box::new(SidecarClient::new(self.config).await?);
+
+        Ok(Self::Output {
+            client: DAClientResource(client),
+        })
+    }
+}
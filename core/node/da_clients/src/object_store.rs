@@ -89,6 +89,21 @@ impl DataAvailabilityClient for ObjectStoreDAClient {
         return Ok(Some(InclusionData::default()));
     }
 
+    async fn get_pubdata(&self, batch_number: u32) -> Result<Option<Vec<u8>>, DAError> {
+        match self
+            .object_store
+            .get::<StorablePubdata>(L1BatchNumber(batch_number))
+            .await
+        {
+            Ok(pubdata) => Ok(Some(pubdata.data)),
+            Err(zksync_object_store::ObjectStoreError::KeyNotFound(_)) => Ok(None),
+            Err(err) => Err(DAError {
+                is_retriable: err.is_retriable(),
+                error: anyhow::Error::from(err),
+            }),
+        }
+    }
+
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
         Box::new(self.clone())
     }
@@ -142,10 +157,11 @@ impl StoredObject for StorablePubdata {
 #[cfg(test)]
 mod tests {
     use tokio::fs;
+    use zksync_da_client::DataAvailabilityClient;
     use zksync_object_store::{MockObjectStore, StoredObject};
     use zksync_types::L1BatchNumber;
 
-    use super::StorablePubdata;
+    use super::{ObjectStoreDAClient, StorablePubdata};
 
     #[tokio::test]
     async fn test_storable_pubdata_deserialization() {
@@ -180,4 +196,17 @@ mod tests {
 
         assert_eq!(data, resp.data);
     }
+
+    #[tokio::test]
+    async fn getting_dispatched_pubdata() {
+        let client = ObjectStoreDAClient {
+            object_store: MockObjectStore::arc(),
+        };
+        assert_eq!(client.get_pubdata(1).await.unwrap(), None);
+
+        let data = vec![1, 2, 3, 255];
+        client.dispatch_blob(1, data.clone()).await.unwrap();
+        assert_eq!(client.get_pubdata(1).await.unwrap(), Some(data));
+        assert_eq!(client.get_pubdata(2).await.unwrap(), None);
+    }
 }
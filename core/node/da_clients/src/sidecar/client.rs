@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use url::Url;
+use zksync_config::configs::da_client::sidecar::SidecarConfig;
+use zksync_da_client::{
+    types::{ClientType, DAError, DispatchResponse, FinalityResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use super::types::{
+    BalanceResponse, CombineInclusionDataRequest, CombineInclusionDataResponse,
+    DispatchBlobRequest, DispatchBlobResponse, ErrorResponse,
+    FinalityResponse as SidecarFinalityResponse, InclusionDataResponse, InfoResponse,
+};
+use crate::utils::{to_non_retriable_da_error, to_retriable_da_error};
+
+/// An implementation of the `DataAvailabilityClient` trait that forwards all calls to a DA sidecar
+/// speaking the generic HTTP / JSON protocol.
+#[derive(Debug, Clone)]
+pub struct SidecarClient {
+    config: SidecarConfig,
+    base_url: Url,
+    blob_size_limit: Option<usize>,
+    supports_chunking: bool,
+    api_client: Arc<reqwest::Client>,
+}
+
+impl SidecarClient {
+    /// Creates a new client. Queries the sidecar for its blob size limit and capabilities, so the sidecar
+    /// must be reachable.
+    pub async fn new(config: SidecarConfig) -> anyhow::Result<Self> {
+        let base_url = Url::parse(&config.server_url).context("invalid sidecar URL")?;
+        anyhow::ensure!(
+            !base_url.cannot_be_a_base(),
+            "sidecar URL `{base_url}` cannot be used as a base URL"
+        );
+        let api_client = reqwest::Client::builder()
+            .timeout(config.timeout())
+            .build()
+            .context("failed building HTTP client")?;
+
+        let mut this = Self {
+            config,
+            base_url,
+            blob_size_limit: None,
+            supports_chunking: false,
+            api_client: Arc::new(api_client),
+        };
+        let info: InfoResponse = this
+            .send(this.api_client.get(this.url(&["info"])))
+            .await
+            .map_err(|err| err.error)
+            .context("failed getting info from DA sidecar")?
+            .context("DA sidecar returned 404 for info request")?;
+        tracing::info!(
+            "Connected to DA sidecar at {}; blob size limit: {:?}, supports chunking: {}",
+            this.config.server_url,
+            info.blob_size_limit,
+            info.supports_chunking
+        );
+        this.blob_size_limit = info.blob_size_limit;
+        this.supports_chunking = info.supports_chunking;
+        Ok(this)
+    }
+
+    fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("checked in constructor")
+            .pop_if_empty()
+            .push("v1")
+            .extend(segments);
+        url
+    }
+
+    /// Sends the request and parses the response. Returns `Ok(None)` on 404 responses.
+    async fn send<R: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Option<R>, DAError> {
+        let response = request.send().await.map_err(to_retriable_da_error)?;
+        let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let default_retriable =
+                status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+            let body = response.text().await.unwrap_or_default();
+            let (message, is_retriable) = match serde_json::from_str::<ErrorResponse>(&body) {
+                Ok(err) => (err.error, err.retriable.unwrap_or(default_retriable)),
+                Err(_) => (body, default_retriable),
+            };
+            return Err(DAError {
+                error: anyhow::anyhow!("DA sidecar responded with {status}: {message}"),
+                is_retriable,
+            });
+        }
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(to_non_retriable_da_error)
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for SidecarClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let request = DispatchBlobRequest {
+            batch_number,
+            data: data.into(),
+        };
+        let response: DispatchBlobResponse = self
+            .send(self.api_client.post(self.url(&["blobs"])).json(&request))
+            .await?
+            .ok_or_else(|| {
+                to_non_retriable_da_error(anyhow::anyhow!(
+                    "DA sidecar doesn't support blob dispatching"
+                ))
+            })?;
+        Ok(DispatchResponse::from(response.request_id))
+    }
+
+    async fn ensure_finality(
+        &self,
+        dispatch_request_id: String,
+    ) -> Result<Option<FinalityResponse>, DAError> {
+        let url = self.url(&["blobs", &dispatch_request_id, "finality"]);
+        // 404 is treated as a pending request (e.g., the sidecar may not have registered it yet), so that
+        // the dispatcher checks finality again on the next poll. A sidecar that has lost the request must respond
+        // with an error instead, which makes the dispatcher re-dispatch the blob.
+        let response: Option<SidecarFinalityResponse> = self.send(self.api_client.get(url)).await?;
+        Ok(response
+            .and_then(|response| response.blob_id)
+            .map(|blob_id| FinalityResponse { blob_id }))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let url = self.url(&["blobs", blob_id, "inclusion_data"]);
+        let response: Option<InclusionDataResponse> = self.send(self.api_client.get(url)).await?;
+        Ok(response
+            .and_then(|response| response.data)
+            .map(|data| InclusionData { data: data.0 }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.blob_size_limit
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Sidecar
+    }
+
+    fn supports_chunked_inclusion(&self) -> bool {
+        self.supports_chunking
+    }
+
+    async fn combine_inclusion_data(
+        &self,
+        chunks: Vec<InclusionData>,
+    ) -> Result<InclusionData, DAError> {
+        let request = CombineInclusionDataRequest {
+            chunks: chunks.into_iter().map(|chunk| chunk.data.into()).collect(),
+        };
+        let url = self.url(&["inclusion_data", "combine"]);
+        let response: CombineInclusionDataResponse = self
+            .send(self.api_client.post(url).json(&request))
+            .await?
+            .ok_or_else(|| {
+                to_non_retriable_da_error(anyhow::anyhow!(
+                    "DA sidecar doesn't support combining inclusion data"
+                ))
+            })?;
+        Ok(InclusionData {
+            data: response.data.0,
+        })
+    }
+
+    async fn balance(&self) -> Result<u64, DAError> {
+        let response: BalanceResponse = self
+            .send(self.api_client.get(self.url(&["balance"])))
+            .await?
+            .ok_or_else(|| {
+                to_non_retriable_da_error(anyhow::anyhow!(
+                    "DA sidecar doesn't support balance queries"
+                ))
+            })?;
+        Ok(response.balance)
+    }
+}
@@ -0,0 +1,176 @@
+//! Reference implementation of a DA sidecar that keeps blobs in memory. Useful for tests and
+//! as a starting point for implementing sidecars for real DA layers.
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use anyhow::Context as _;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use tokio::{net::TcpListener, sync::watch, task::JoinHandle};
+use zksync_types::web3::keccak256;
+
+use zksync_da_client::types::InclusionData;
+
+use super::types::{
+    BalanceResponse, CombineInclusionDataRequest, CombineInclusionDataResponse,
+    DispatchBlobRequest, DispatchBlobResponse, ErrorResponse, FinalityResponse,
+    InclusionDataResponse, InfoResponse,
+};
+use crate::utils::abi_encode_chunks_inclusion_data;
+
+#[derive(Debug)]
+struct MockSidecarInner {
+    blob_size_limit: Option<usize>,
+    blobs: Mutex<HashMap<String, Vec<u8>>>,
+    /// If not set, all dispatched blobs are reported as not final.
+    finalize_blobs: AtomicBool,
+}
+
+/// In-memory DA sidecar. Blob IDs coincide with request IDs, and the inclusion data for a blob
+/// is the Keccak-256 hash of its contents. Inclusion data of chunks is combined by ABI-encoding it as `bytes[]`.
+#[derive(Debug, Clone)]
+pub struct MockSidecar {
+    inner: Arc<MockSidecarInner>,
+}
+
+impl MockSidecar {
+    pub fn new(blob_size_limit: Option<usize>) -> Self {
+        Self {
+            inner: Arc::new(MockSidecarInner {
+                blob_size_limit,
+                blobs: Mutex::default(),
+                finalize_blobs: AtomicBool::new(true),
+            }),
+        }
+    }
+
+    /// Sets whether dispatched blobs should be reported as final.
+    pub fn set_finalize_blobs(&self, finalize: bool) {
+        self.inner.finalize_blobs.store(finalize, Ordering::SeqCst);
+    }
+
+    /// Returns a copy of the blob with the specified ID, if it was dispatched.
+    pub fn blob(&self, blob_id: &str) -> Option<Vec<u8>> {
+        self.inner.blobs.lock().unwrap().get(blob_id).cloned()
+    }
+
+    pub fn into_router(self) -> Router {
+        Router::new()
+            .route("/v1/info", get(Self::info))
+            .route("/v1/blobs", post(Self::dispatch_blob))
+            .route("/v1/blobs/:request_id/finality", get(Self::finality))
+            .route(
+                "/v1/blobs/:blob_id/inclusion_data",
+                get(Self::inclusion_data),
+            )
+            .route(
+                "/v1/inclusion_data/combine",
+                post(Self::combine_inclusion_data),
+            )
+            .route("/v1/balance", get(Self::balance))
+            .with_state(self)
+    }
+
+    /// Spawns the sidecar server on the specified address. The server runs until `stop_receiver` is signaled.
+    pub async fn spawn(
+        self,
+        bind_address: SocketAddr,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<(SocketAddr, JoinHandle<anyhow::Result<()>>)> {
+        let listener = TcpListener::bind(bind_address)
+            .await
+            .context("cannot bind mock DA sidecar")?;
+        let local_addr = listener.local_addr()?;
+        let router = self.into_router();
+        let server_task = tokio::spawn(async move {
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    stop_receiver.changed().await.ok();
+                })
+                .await
+                .context("mock DA sidecar failed")
+        });
+        Ok((local_addr, server_task))
+    }
+
+    async fn info(State(this): State<Self>) -> Json<InfoResponse> {
+        Json(InfoResponse {
+            blob_size_limit: this.inner.blob_size_limit,
+            supports_chunking: true,
+        })
+    }
+
+    async fn dispatch_blob(
+        State(this): State<Self>,
+        Json(request): Json<DispatchBlobRequest>,
+    ) -> Response {
+        let data = request.data.0;
+        if let Some(limit) = this.inner.blob_size_limit {
+            if data.len() > limit {
+                let error = ErrorResponse {
+                    error: format!("blob size {} exceeds the limit {limit}", data.len()),
+                    retriable: Some(false),
+                };
+                return (StatusCode::PAYLOAD_TOO_LARGE, Json(error)).into_response();
+            }
+        }
+
+        let mut blobs = this.inner.blobs.lock().unwrap();
+        let request_id = format!("{}-{}", request.batch_number, blobs.len());
+        blobs.insert(request_id.clone(), data);
+        Json(DispatchBlobResponse { request_id }).into_response()
+    }
+
+    async fn finality(
+        State(this): State<Self>,
+        Path(request_id): Path<String>,
+    ) -> Result<Json<FinalityResponse>, StatusCode> {
+        if !this.inner.blobs.lock().unwrap().contains_key(&request_id) {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        let is_final = this.inner.finalize_blobs.load(Ordering::SeqCst);
+        Ok(Json(FinalityResponse {
+            blob_id: is_final.then_some(request_id),
+        }))
+    }
+
+    async fn inclusion_data(
+        State(this): State<Self>,
+        Path(blob_id): Path<String>,
+    ) -> Result<Json<InclusionDataResponse>, StatusCode> {
+        let blobs = this.inner.blobs.lock().unwrap();
+        let blob = blobs.get(&blob_id).ok_or(StatusCode::NOT_FOUND)?;
+        Ok(Json(InclusionDataResponse {
+            data: Some(keccak256(blob).to_vec().into()),
+        }))
+    }
+
+    async fn combine_inclusion_data(
+        Json(request): Json<CombineInclusionDataRequest>,
+    ) -> Json<CombineInclusionDataResponse> {
+        let chunks = request
+            .chunks
+            .into_iter()
+            .map(|data| InclusionData { data: data.0 })
+            .collect();
+        Json(CombineInclusionDataResponse {
+            data: abi_encode_chunks_inclusion_data(chunks).data.into(),
+        })
+    }
+
+    async fn balance() -> Json<BalanceResponse> {
+        Json(BalanceResponse { balance: 0 })
+    }
+}
@@ -0,0 +1,11 @@
+//! Generic DA client that delegates all DA-layer interaction to an external sidecar process.
+//!
+//! See `README.md` in this directory for the description of the HTTP protocol.
+
+pub use self::client::SidecarClient;
+
+mod client;
+pub mod mock;
+#[cfg(test)]
+mod tests;
+pub(crate) mod types;
@@ -0,0 +1,150 @@
+use axum::{routing::get, Json, Router};
+use tokio::{net::TcpListener, sync::watch};
+use zksync_config::configs::da_client::sidecar::SidecarConfig;
+use zksync_da_client::{types::InclusionData, DataAvailabilityClient};
+use zksync_types::web3::keccak256;
+
+use super::{mock::MockSidecar, types::InfoResponse, SidecarClient};
+use crate::utils::abi_encode_chunks_inclusion_data;
+
+async fn spawn_sidecar(sidecar: MockSidecar) -> (SidecarClient, watch::Sender<bool>) {
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let (local_addr, _) = sidecar
+        .spawn(([127, 0, 0, 1], 0).into(), stop_receiver)
+        .await
+        .unwrap();
+    let client = SidecarClient::new(SidecarConfig {
+        server_url: format!("http://{local_addr}/"),
+        timeout_ms: None,
+    })
+    .await
+    .unwrap();
+    (client, stop_sender)
+}
+
+#[tokio::test]
+async fn dispatching_blob_via_sidecar() {
+    let sidecar = MockSidecar::new(Some(1_024));
+    let (client, _stop_sender) = spawn_sidecar(sidecar.clone()).await;
+    assert_eq!(client.blob_size_limit(), Some(1_024));
+
+    let data = vec![1_u8; 100];
+    let response = client.dispatch_blob(1, data.clone()).await.unwrap();
+    assert_eq!(sidecar.blob(&response.request_id).unwrap(), data);
+
+    let finality = client
+        .ensure_finality(response.request_id.clone())
+        .await
+        .unwrap()
+        .expect("blob is not final");
+    assert_eq!(finality.blob_id, response.request_id);
+
+    let inclusion_data = client
+        .get_inclusion_data(&finality.blob_id)
+        .await
+        .unwrap()
+        .expect("no inclusion data");
+    assert_eq!(inclusion_data.data, keccak256(&data));
+
+    assert_eq!(client.balance().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn combining_chunks_inclusion_data_via_sidecar() {
+    let sidecar = MockSidecar::new(Some(10));
+    let (client, _stop_sender) = spawn_sidecar(sidecar).await;
+    assert!(client.supports_chunked_inclusion());
+
+    let chunks = [vec![1_u8; 10], vec![2_u8; 5]];
+    let mut chunks_inclusion_data = vec![];
+    for (i, chunk) in chunks.iter().enumerate() {
+        let response = client.dispatch_blob(1, chunk.clone()).await.unwrap();
+        let finality = client
+            .ensure_finality(response.request_id)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("chunk #{i} is not final"));
+        let inclusion_data = client
+            .get_inclusion_data(&finality.blob_id)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("no inclusion data for chunk #{i}"));
+        chunks_inclusion_data.push(inclusion_data);
+    }
+
+    let combined = client
+        .combine_inclusion_data(chunks_inclusion_data)
+        .await
+        .unwrap();
+    let expected = abi_encode_chunks_inclusion_data(
+        chunks
+            .iter()
+            .map(|chunk| InclusionData {
+                data: keccak256(chunk).to_vec(),
+            })
+            .collect(),
+    );
+    assert_eq!(combined.data, expected.data);
+}
+
+#[tokio::test]
+async fn non_final_and_unknown_blobs() {
+    let sidecar = MockSidecar::new(None);
+    sidecar.set_finalize_blobs(false);
+    let (client, _stop_sender) = spawn_sidecar(sidecar.clone()).await;
+    assert_eq!(client.blob_size_limit(), None);
+
+    let response = client.dispatch_blob(1, vec![1, 2, 3]).await.unwrap();
+    let finality = client
+        .ensure_finality(response.request_id.clone())
+        .await
+        .unwrap();
+    assert!(finality.is_none());
+
+    sidecar.set_finalize_blobs(true);
+    let finality = client.ensure_finality(response.request_id).await.unwrap();
+    assert!(finality.is_some());
+
+    // Unknown requests are considered pending, so that the dispatcher checks them again later.
+    let finality = client.ensure_finality("unknown".to_owned()).await.unwrap();
+    assert!(finality.is_none());
+    let inclusion_data = client.get_inclusion_data("unknown").await.unwrap();
+    assert!(inclusion_data.is_none());
+}
+
+#[tokio::test]
+async fn sidecar_errors_are_propagated() {
+    let sidecar = MockSidecar::new(Some(10));
+    let (client, _stop_sender) = spawn_sidecar(sidecar).await;
+
+    let err = client.dispatch_blob(1, vec![0; 11]).await.unwrap_err();
+    assert!(!err.is_retriable(), "{err}");
+    assert!(err.to_string().contains("exceeds the limit"), "{err}");
+}
+
+#[tokio::test]
+async fn balance_is_required() {
+    // Sidecar only implementing the info endpoint.
+    let router = Router::new().route(
+        "/v1/info",
+        get(|| async {
+            Json(InfoResponse {
+                blob_size_limit: None,
+                supports_chunking: false,
+            })
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let local_addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await });
+    let client = SidecarClient::new(SidecarConfig {
+        server_url: format!("http://{local_addr}/"),
+        timeout_ms: None,
+    })
+    .await
+    .unwrap();
+
+    let err = client.balance().await.unwrap_err();
+    assert!(!err.is_retriable(), "{err}");
+    assert!(err.to_string().contains("doesn't support balance"), "{err}");
+}
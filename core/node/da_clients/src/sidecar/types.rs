@@ -0,0 +1,71 @@
+//! Wire types of the DA sidecar protocol (v1).
+
+use serde::{Deserialize, Serialize};
+use zksync_types::web3::Bytes;
+
+/// Response of `GET /v1/info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoResponse {
+    /// Maximum size of a single blob accepted by the DA layer in bytes; `null` means no limit.
+    pub blob_size_limit: Option<usize>,
+    /// Whether the sidecar supports combining inclusion data for pubdata dispatched as several blobs
+    /// (i.e., implements `POST /v1/inclusion_data/combine`).
+    #[serde(default)]
+    pub supports_chunking: bool,
+}
+
+/// Body of `POST /v1/blobs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispatchBlobRequest {
+    pub batch_number: u32,
+    pub data: Bytes,
+}
+
+/// Response of `POST /v1/blobs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispatchBlobResponse {
+    pub request_id: String,
+}
+
+/// Response of `GET /v1/blobs/{request_id}/finality`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalityResponse {
+    /// ID of the blob used to fetch the inclusion data; `null` if the blob is not final yet.
+    pub blob_id: Option<String>,
+}
+
+/// Response of `GET /v1/blobs/{blob_id}/inclusion_data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionDataResponse {
+    /// ABI-encoded inclusion data to be passed to the L1 DA validator; `null` if it's not available yet.
+    pub data: Option<Bytes>,
+}
+
+/// Body of `POST /v1/inclusion_data/combine`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombineInclusionDataRequest {
+    /// Inclusion data of the chunks ordered by the chunk index.
+    pub chunks: Vec<Bytes>,
+}
+
+/// Response of `POST /v1/inclusion_data/combine`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombineInclusionDataResponse {
+    /// Combined inclusion data to be passed to the L1 DA validator.
+    pub data: Bytes,
+}
+
+/// Response of `GET /v1/balance`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceResponse {
+    pub balance: u64,
+}
+
+/// Body of any non-2xx response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+    /// Whether the request can be retried. If the field is missing, the error is considered
+    /// retriable for 5xx and 429 status codes.
+    pub retriable: Option<bool>,
+}
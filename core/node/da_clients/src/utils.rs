@@ -1,4 +1,5 @@
-use zksync_da_client::types::DAError;
+use zksync_da_client::types::{DAError, InclusionData};
+use zksync_types::ethabi::{self, Token};
 
 pub fn to_non_retriable_da_error(error: impl Into<anyhow::Error>) -> DAError {
     DAError {
@@ -13,3 +14,42 @@ pub fn to_retriable_da_error(error: impl Into<anyhow::Error>) -> DAError {
         is_retriable: true,
     }
 }
+
+/// Combines the inclusion data of the chunks of pubdata dispatched as several blobs by ABI-encoding it
+/// as `bytes[]` (ordered by the chunk index). L1 DA validators for chunked pubdata are expected to decode
+/// the combined data and verify the inclusion of each chunk separately.
+pub fn abi_encode_chunks_inclusion_data(chunks: Vec<InclusionData>) -> InclusionData {
+    let tokens = chunks
+        .into_iter()
+        .map(|chunk| Token::Bytes(chunk.data))
+        .collect();
+    InclusionData {
+        data: ethabi::encode(&[Token::Array(tokens)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::ethabi::ParamType;
+
+    use super::*;
+
+    #[test]
+    fn chunks_inclusion_data_is_abi_encoded() {
+        let chunks = [b"first".to_vec(), vec![], vec![0xff; 40]];
+        let combined = abi_encode_chunks_inclusion_data(
+            chunks
+                .iter()
+                .map(|data| InclusionData { data: data.clone() })
+                .collect(),
+        );
+
+        let decoded = ethabi::decode(
+            &[ParamType::Array(Box::new(ParamType::Bytes))],
+            &combined.data,
+        )
+        .unwrap();
+        let expected: Vec<_> = chunks.into_iter().map(Token::Bytes).collect();
+        assert_eq!(decoded, [Token::Array(expected)]);
+    }
+}
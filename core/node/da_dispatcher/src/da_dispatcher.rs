@@ -2,6 +2,7 @@ use std::{future::Future, sync::Arc, time::Duration};
 
 use anyhow::Context;
 use chrono::Utc;
+use futures::future;
 use rand::Rng;
 use tokio::sync::watch::Receiver;
 use zksync_config::{configs::contracts::chain::L2Contracts, DADispatcherConfig};
@@ -136,43 +137,77 @@ impl DataAvailabilityDispatcher {
 
         for batch in &batches {
             let dispatch_latency = METRICS.blob_dispatch_latency.start();
-            let dispatch_response = retry(
-                self.config.max_retries(),
-                batch.l1_batch_number,
-                "DA dispatch",
-                || {
-                    self.client
-                        .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
-                },
-            )
-            .await
-            .with_context(|| {
-                format!(
-                    "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
-                    batch.l1_batch_number,
-                    batch.pubdata.len()
-                )
-            })?;
+            let (dispatch_request_id, chunk_request_ids) = match self.chunk_size() {
+                Some(chunk_size) if batch.pubdata.len() > chunk_size => {
+                    let chunk_request_ids = self
+                        .dispatch_chunks(batch.l1_batch_number, &batch.pubdata, chunk_size)
+                        .await?;
+                    (None, chunk_request_ids)
+                }
+                _ => {
+                    let dispatch_response = retry(
+                        self.config.max_retries(),
+                        batch.l1_batch_number,
+                        "DA dispatch",
+                        || {
+                            self.client
+                                .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+                        },
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
+                            batch.l1_batch_number,
+                            batch.pubdata.len()
+                        )
+                    })?;
+                    (Some(dispatch_response.request_id), vec![])
+                }
+            };
             let dispatch_latency_duration = dispatch_latency.observe();
 
             let sent_at = Utc::now();
 
+            let pubdata_type = self.client.client_type().into_pubdata_type();
+            let l2_da_validator = find_l2_da_validator_address(batch.system_logs.as_slice())?;
             let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
-            conn.data_availability_dal()
-                .insert_l1_batch_da_request_id(
-                    batch.l1_batch_number,
-                    dispatch_response.request_id.as_str(),
-                    sent_at.naive_utc(),
-                    self.client.client_type().into_pubdata_type(),
-                    Some(find_l2_da_validator_address(batch.system_logs.as_slice())?),
-                )
-                .await?;
+            if let Some(dispatch_request_id) = dispatch_request_id {
+                conn.data_availability_dal()
+                    .insert_l1_batch_da_request_id(
+                        batch.l1_batch_number,
+                        dispatch_request_id.as_str(),
+                        sent_at.naive_utc(),
+                        pubdata_type,
+                        Some(l2_da_validator),
+                    )
+                    .await?;
+            } else {
+                let mut transaction = conn.start_transaction().await?;
+                // Remove chunks potentially left from the previous dispatch attempt
+                transaction
+                    .data_availability_dal()
+                    .remove_data_availability_chunks(batch.l1_batch_number)
+                    .await?;
+                transaction
+                    .data_availability_dal()
+                    .insert_l1_batch_da_chunks(
+                        batch.l1_batch_number,
+                        &chunk_request_ids,
+                        sent_at.naive_utc(),
+                        pubdata_type,
+                        Some(l2_da_validator),
+                    )
+                    .await?;
+                transaction.commit().await?;
+            }
             drop(conn);
 
             METRICS
                 .last_dispatched_l1_batch
                 .set(batch.l1_batch_number.0 as usize);
             METRICS.blob_size.observe(batch.pubdata.len());
+            METRICS.blob_chunks.observe(chunk_request_ids.len().max(1));
             METRICS.sealed_to_dispatched_lag.observe(
                 sent_at
                     .signed_duration_since(batch.sealed_at)
@@ -180,9 +215,10 @@ impl DataAvailabilityDispatcher {
                     .context("sent_at has to be higher than sealed_at")?,
             );
             tracing::info!(
-                "Dispatched a DA for batch_number: {}, pubdata_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
+                "Dispatched a DA for batch_number: {}, pubdata_size: {}, chunks: {}, dispatch_latency: {dispatch_latency_duration:?}",
                 batch.l1_batch_number,
                 batch.pubdata.len(),
+                chunk_request_ids.len().max(1),
             );
         }
 
@@ -210,6 +246,44 @@ impl DataAvailabilityDispatcher {
         Ok(())
     }
 
+    /// Returns the maximum size of a pubdata chunk if pubdata exceeding it should be dispatched as several blobs.
+    fn chunk_size(&self) -> Option<usize> {
+        if !self.config.use_blob_chunking() {
+            return None;
+        }
+        self.client.blob_size_limit()
+    }
+
+    /// Splits pubdata into chunks and dispatches them in parallel. Returns dispatch request IDs of the chunks
+    /// ordered by the chunk index.
+    async fn dispatch_chunks(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        pubdata: &[u8],
+        chunk_size: usize,
+    ) -> anyhow::Result<Vec<String>> {
+        let chunk_futures = pubdata
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(chunk_index, chunk)| async move {
+                let dispatch_response = retry(
+                    self.config.max_retries(),
+                    l1_batch_number,
+                    "DA chunk dispatch",
+                    || self.client.dispatch_blob(l1_batch_number.0, chunk.to_vec()),
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to dispatch chunk #{chunk_index} with batch_number: {l1_batch_number}, chunk_len: {}",
+                        chunk.len()
+                    )
+                })?;
+                anyhow::Ok(dispatch_response.request_id)
+            });
+        future::try_join_all(chunk_futures).await
+    }
+
     async fn ensure_finality(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         let blob = conn
@@ -222,6 +296,10 @@ impl DataAvailabilityDispatcher {
             return Ok(());
         };
 
+        if blob.chunk_count.is_some() {
+            return self.ensure_chunks_finality(blob.l1_batch_number).await;
+        }
+
         // TODO: add metrics for finality latency
         let finality_response = self
             .client
@@ -261,6 +339,87 @@ impl DataAvailabilityDispatcher {
         Ok(())
     }
 
+    /// Checks the finality of the chunks of a batch dispatched as several blobs. The batch becomes awaiting
+    /// inclusion once all chunks are final.
+    async fn ensure_chunks_finality(&self, l1_batch_number: L1BatchNumber) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(l1_batch_number)
+            .await?;
+        drop(conn);
+
+        if chunks.is_empty() {
+            tracing::warn!(
+                "No chunks found for a chunked blob with batch_number: {l1_batch_number}, re-dispatching it"
+            );
+            return self.remove_da_entry(l1_batch_number).await;
+        }
+
+        let pending_chunks: Vec<_> = chunks
+            .iter()
+            .filter(|chunk| chunk.blob_id.is_none())
+            .collect();
+        let finality_responses = future::join_all(pending_chunks.iter().map(|chunk| {
+            self.client
+                .ensure_finality(chunk.dispatch_request_id.clone())
+        }))
+        .await;
+
+        let mut pending_count = pending_chunks.len();
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        for (chunk, finality_response) in pending_chunks.into_iter().zip(finality_responses) {
+            match finality_response {
+                Ok(None) => {
+                    // Not final yet, do nothing
+                }
+                Ok(Some(finality_response)) => {
+                    conn.data_availability_dal()
+                        .set_chunk_blob_id(
+                            l1_batch_number,
+                            chunk.chunk_index,
+                            finality_response.blob_id.as_str(),
+                        )
+                        .await?;
+                    pending_count -= 1;
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Finality check for chunk #{} of a batch_number: {l1_batch_number} failed with an error: {}",
+                        chunk.chunk_index,
+                        err.error
+                    );
+                    drop(conn);
+                    // remove the entries from the database to resend all chunks again
+                    return self.remove_da_entry(l1_batch_number).await;
+                }
+            }
+        }
+
+        if pending_count == 0 {
+            tracing::info!(
+                "Finality check for all {} chunks of a batch_number: {l1_batch_number} is successful",
+                chunks.len()
+            );
+        }
+        Ok(())
+    }
+
+    async fn remove_da_entry(&self, l1_batch_number: L1BatchNumber) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let mut transaction = conn.start_transaction().await?;
+        transaction
+            .data_availability_dal()
+            .remove_data_availability_chunks(l1_batch_number)
+            .await?;
+        transaction
+            .data_availability_dal()
+            .remove_data_availability_entry(l1_batch_number)
+            .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
     /// Polls the data availability layer for inclusion data, and saves it in the database.
     async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
         if self.config.inclusion_verification_transition_enabled() {
@@ -293,6 +452,9 @@ impl DataAvailabilityDispatcher {
 
         let inclusion_data = if self.config.use_dummy_inclusion_data() {
             Some(InclusionData { data: vec![] })
+        } else if blob_info.chunk_count.is_some() {
+            self.get_chunks_inclusion_data(blob_info.l1_batch_number)
+                .await?
         } else {
             let Some(blob_id) = blob_info.blob_id else {
                 anyhow::bail!(
@@ -342,6 +504,77 @@ impl DataAvailabilityDispatcher {
         Ok(())
     }
 
+    /// Fetches and persists the inclusion data for the chunks of a batch dispatched as several blobs.
+    /// Returns the combined inclusion data once it's available for all chunks.
+    async fn get_chunks_inclusion_data(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Option<InclusionData>> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let mut chunks = conn
+            .data_availability_dal()
+            .get_l1_batch_da_chunks(l1_batch_number)
+            .await?;
+        drop(conn);
+        anyhow::ensure!(
+            !chunks.is_empty(),
+            "no chunks found for a chunked blob with batch_number: {l1_batch_number}"
+        );
+
+        let pending_chunks = chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| chunk.inclusion_data.is_none());
+        let inclusion_data_futures = pending_chunks.map(|(position, chunk)| async move {
+            let blob_id = chunk.blob_id.as_deref().with_context(|| {
+                format!(
+                    "Blob ID is not set for chunk #{} of batch_number: {l1_batch_number}",
+                    chunk.chunk_index
+                )
+            })?;
+            let inclusion_data = self
+                .client
+                .get_inclusion_data(blob_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to get inclusion data for blob_id: {blob_id}, chunk #{} of batch_number: {l1_batch_number}",
+                        chunk.chunk_index
+                    )
+                })?;
+            anyhow::Ok((position, inclusion_data))
+        });
+        let fetched_inclusion_data = future::try_join_all(inclusion_data_futures).await?;
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        for (position, inclusion_data) in fetched_inclusion_data {
+            let Some(inclusion_data) = inclusion_data else {
+                continue;
+            };
+            let chunk = &mut chunks[position];
+            conn.data_availability_dal()
+                .save_chunk_inclusion_data(l1_batch_number, chunk.chunk_index, &inclusion_data.data)
+                .await?;
+            chunk.inclusion_data = Some(inclusion_data.data);
+        }
+
+        let chunks_inclusion_data: Option<Vec<_>> = chunks
+            .into_iter()
+            .map(|chunk| chunk.inclusion_data.map(|data| InclusionData { data }))
+            .collect();
+        let Some(chunks_inclusion_data) = chunks_inclusion_data else {
+            return Ok(None);
+        };
+        let inclusion_data = self
+            .client
+            .combine_inclusion_data(chunks_inclusion_data)
+            .await
+            .with_context(|| {
+                format!("failed to combine inclusion data for chunks of batch_number: {l1_batch_number}")
+            })?;
+        Ok(Some(inclusion_data))
+    }
+
     async fn check_for_misconfiguration(&mut self) -> anyhow::Result<()> {
         if self.config.inclusion_verification_transition_enabled() {
             self.transitional_l2_da_validator_address = Some(
@@ -407,3 +640,341 @@ pub fn find_l2_da_validator_address(system_logs: &[L2ToL1Log]) -> anyhow::Result
         .value
         .into())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        sync::Mutex,
+    };
+
+    use async_trait::async_trait;
+    use zksync_da_client::types::{ClientType, DispatchResponse, FinalityResponse};
+    use zksync_node_test_utils::create_l1_batch;
+    use zksync_types::{
+        l2_to_l1_log::SystemL2ToL1Log, pubdata_da::DataAvailabilityChunk, ProtocolVersion,
+    };
+
+    use super::*;
+
+    const CHUNK_SIZE: usize = 10;
+    const PUBDATA: &[u8] = b"0123456789abcdefghijABCDE";
+
+    #[derive(Debug, Default)]
+    struct MockClientState {
+        dispatched_blobs: Vec<Vec<u8>>,
+        /// Remaining number of failures and whether they are retriable for dispatching specific blobs.
+        dispatch_failures: HashMap<Vec<u8>, (usize, bool)>,
+        failing_finality_requests: HashSet<String>,
+    }
+
+    /// DA client mock deriving request / blob IDs and inclusion data from the (ASCII) blob contents.
+    #[derive(Debug, Clone, Default)]
+    struct MockDAClient {
+        state: Arc<Mutex<MockClientState>>,
+    }
+
+    impl MockDAClient {
+        fn fail_dispatch(&self, blob: &[u8], times: usize, is_retriable: bool) {
+            let mut state = self.state.lock().unwrap();
+            state
+                .dispatch_failures
+                .insert(blob.to_vec(), (times, is_retriable));
+        }
+
+        fn fail_finality(&self, dispatch_request_id: &str) {
+            let mut state = self.state.lock().unwrap();
+            state
+                .failing_finality_requests
+                .insert(dispatch_request_id.to_owned());
+        }
+
+        fn dispatched_blobs(&self) -> Vec<Vec<u8>> {
+            self.state.lock().unwrap().dispatched_blobs.clone()
+        }
+    }
+
+    fn mock_error(is_retriable: bool) -> DAError {
+        DAError {
+            error: anyhow::anyhow!("mock error"),
+            is_retriable,
+        }
+    }
+
+    #[async_trait]
+    impl DataAvailabilityClient for MockDAClient {
+        async fn dispatch_blob(
+            &self,
+            _batch_number: u32,
+            data: Vec<u8>,
+        ) -> Result<DispatchResponse, DAError> {
+            let mut state = self.state.lock().unwrap();
+            if let Some((failures, is_retriable)) = state.dispatch_failures.get_mut(&data) {
+                if *failures > 0 {
+                    *failures -= 1;
+                    return Err(mock_error(*is_retriable));
+                }
+            }
+            let request_id = format!("request:{}", String::from_utf8(data.clone()).unwrap());
+            state.dispatched_blobs.push(data);
+            Ok(request_id.into())
+        }
+
+        async fn ensure_finality(
+            &self,
+            dispatch_request_id: String,
+        ) -> Result<Option<FinalityResponse>, DAError> {
+            let state = self.state.lock().unwrap();
+            if state
+                .failing_finality_requests
+                .contains(&dispatch_request_id)
+            {
+                return Err(mock_error(false));
+            }
+            let blob_id = dispatch_request_id.replacen("request:", "blob:", 1);
+            Ok(Some(FinalityResponse { blob_id }))
+        }
+
+        async fn get_inclusion_data(
+            &self,
+            blob_id: &str,
+        ) -> Result<Option<InclusionData>, DAError> {
+            let data = blob_id.replacen("blob:", "inclusion:", 1).into_bytes();
+            Ok(Some(InclusionData { data }))
+        }
+
+        fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+            Box::new(self.clone())
+        }
+
+        fn blob_size_limit(&self) -> Option<usize> {
+            Some(CHUNK_SIZE)
+        }
+
+        fn client_type(&self) -> ClientType {
+            ClientType::ObjectStore
+        }
+
+        async fn balance(&self) -> Result<u64, DAError> {
+            Ok(0)
+        }
+
+        fn supports_chunked_inclusion(&self) -> bool {
+            true
+        }
+
+        async fn combine_inclusion_data(
+            &self,
+            chunks: Vec<InclusionData>,
+        ) -> Result<InclusionData, DAError> {
+            let chunks: Vec<_> = chunks.into_iter().map(|chunk| chunk.data).collect();
+            Ok(InclusionData {
+                data: chunks.join(&b'|'),
+            })
+        }
+    }
+
+    async fn create_dispatcher(
+        pool: &ConnectionPool<Core>,
+    ) -> (DataAvailabilityDispatcher, MockDAClient) {
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let mut header = create_l1_batch(1);
+        header.pubdata_input = Some(PUBDATA.to_vec());
+        header.system_logs.push(SystemL2ToL1Log(L2ToL1Log {
+            shard_id: 0,
+            is_service: true,
+            tx_number_in_block: 0,
+            sender: Address::zero(),
+            key: H256::from_low_u64_be(u64::from(
+                zksync_system_constants::L2_DA_VALIDATOR_OUTPUT_HASH_KEY,
+            )),
+            value: H256::from(Address::repeat_byte(1)),
+        }));
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&header)
+            .await
+            .unwrap();
+
+        let config = DADispatcherConfig {
+            max_retries: Some(2),
+            use_blob_chunking: Some(true),
+            ..DADispatcherConfig::for_tests()
+        };
+        let l2_contracts = L2Contracts {
+            erc20_default_bridge: Address::zero(),
+            shared_bridge_addr: Address::zero(),
+            legacy_shared_bridge_addr: None,
+            timestamp_asserter_addr: None,
+            da_validator_addr: None,
+            testnet_paymaster_addr: None,
+            multicall3: None,
+        };
+        let client = MockDAClient::default();
+        let dispatcher = DataAvailabilityDispatcher::new(
+            pool.clone(),
+            config,
+            Box::new(client.clone()),
+            l2_contracts,
+        );
+        (dispatcher, client)
+    }
+
+    fn expected_chunks() -> Vec<&'static [u8]> {
+        PUBDATA.chunks(CHUNK_SIZE).collect()
+    }
+
+    fn chunk_ids(prefix: &str) -> Vec<String> {
+        expected_chunks()
+            .into_iter()
+            .map(|chunk| format!("{prefix}:{}", std::str::from_utf8(chunk).unwrap()))
+            .collect()
+    }
+
+    async fn get_chunks(pool: &ConnectionPool<Core>) -> Vec<DataAvailabilityChunk> {
+        let mut conn = pool.connection().await.unwrap();
+        conn.data_availability_dal()
+            .get_l1_batch_da_chunks(L1BatchNumber(1))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn dispatching_pubdata_in_chunks() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let (dispatcher, client) = create_dispatcher(&pool).await;
+
+        dispatcher.dispatch().await.unwrap();
+        let mut dispatched_blobs = client.dispatched_blobs();
+        dispatched_blobs.sort();
+        assert_eq!(dispatched_blobs, expected_chunks());
+
+        let chunks = get_chunks(&pool).await;
+        let request_ids: Vec<_> = chunks
+            .iter()
+            .map(|chunk| chunk.dispatch_request_id.clone())
+            .collect();
+        assert_eq!(request_ids, chunk_ids("request"));
+        let mut conn = pool.connection().await.unwrap();
+        let blob = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_finality()
+            .await
+            .unwrap()
+            .expect("no blob awaiting finality");
+        assert_eq!(blob.chunk_count, Some(request_ids.len() as u32));
+        assert_eq!(blob.dispatch_request_id, "");
+        assert_eq!(blob.blob_id, None);
+
+        dispatcher.ensure_finality().await.unwrap();
+        let chunks = get_chunks(&pool).await;
+        let blob_ids: Vec<_> = chunks
+            .iter()
+            .map(|chunk| chunk.blob_id.clone().unwrap())
+            .collect();
+        assert_eq!(blob_ids, chunk_ids("blob"));
+        let blob = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_inclusion()
+            .await
+            .unwrap()
+            .expect("no blob awaiting inclusion");
+        assert_eq!(blob.l1_batch_number, L1BatchNumber(1));
+        assert_eq!(blob.blob_id, None);
+
+        dispatcher.poll_for_inclusion().await.unwrap();
+        let chunks = get_chunks(&pool).await;
+        let chunks_inclusion_data: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| chunk.inclusion_data.unwrap())
+            .collect();
+        let expected_inclusion_data: Vec<_> = chunk_ids("inclusion")
+            .into_iter()
+            .map(String::into_bytes)
+            .collect();
+        assert_eq!(chunks_inclusion_data, expected_inclusion_data);
+
+        let details = conn
+            .data_availability_dal()
+            .get_da_details_by_batch_number(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .expect("no DA details");
+        assert_eq!(details.blob_id, "");
+        assert_eq!(details.chunk_blob_ids, Some(blob_ids));
+        assert_eq!(
+            details.inclusion_data.unwrap(),
+            expected_inclusion_data.join(&b'|')
+        );
+    }
+
+    #[tokio::test]
+    async fn retrying_failed_chunk_dispatch() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let (dispatcher, client) = create_dispatcher(&pool).await;
+        let failed_chunk = expected_chunks()[1];
+        client.fail_dispatch(failed_chunk, 1, true);
+
+        dispatcher.dispatch().await.unwrap();
+        // The failed chunk is retried; each chunk must be dispatched successfully exactly once.
+        let mut dispatched_blobs = client.dispatched_blobs();
+        dispatched_blobs.sort();
+        assert_eq!(dispatched_blobs, expected_chunks());
+        let request_ids: Vec<_> = get_chunks(&pool)
+            .await
+            .into_iter()
+            .map(|chunk| chunk.dispatch_request_id)
+            .collect();
+        assert_eq!(request_ids, chunk_ids("request"));
+    }
+
+    #[tokio::test]
+    async fn failed_chunk_dispatch_does_not_persist_batch() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let (dispatcher, client) = create_dispatcher(&pool).await;
+        let failed_chunk = expected_chunks()[2];
+        client.fail_dispatch(failed_chunk, 1, false);
+
+        dispatcher.dispatch().await.unwrap_err();
+        assert!(get_chunks(&pool).await.is_empty());
+        let mut conn = pool.connection().await.unwrap();
+        let ready_batches = conn
+            .data_availability_dal()
+            .get_ready_for_da_dispatch_l1_batches(10)
+            .await
+            .unwrap();
+        assert_eq!(ready_batches.len(), 1);
+
+        // The batch is dispatched completely on the next iteration.
+        dispatcher.dispatch().await.unwrap();
+        let request_ids: Vec<_> = get_chunks(&pool)
+            .await
+            .into_iter()
+            .map(|chunk| chunk.dispatch_request_id)
+            .collect();
+        assert_eq!(request_ids, chunk_ids("request"));
+    }
+
+    #[tokio::test]
+    async fn redispatching_chunks_after_finality_error() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let (dispatcher, client) = create_dispatcher(&pool).await;
+        client.fail_finality(&chunk_ids("request")[0]);
+
+        dispatcher.dispatch().await.unwrap();
+        dispatcher.ensure_finality().await.unwrap();
+        // All chunks and the DA entry of the batch should be removed so that the batch is dispatched again.
+        assert!(get_chunks(&pool).await.is_empty());
+        let mut conn = pool.connection().await.unwrap();
+        let ready_batches = conn
+            .data_availability_dal()
+            .get_ready_for_da_dispatch_l1_batches(10)
+            .await
+            .unwrap();
+        assert_eq!(ready_batches.len(), 1);
+        assert_eq!(ready_batches[0].l1_batch_number, L1BatchNumber(1));
+    }
+}
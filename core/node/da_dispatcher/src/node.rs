@@ -53,10 +53,25 @@ impl WiringLayer for DataAvailabilityDispatcherLayer {
         let da_client = input.da_client.0;
         if let Some(limit) = da_client.blob_size_limit() {
             if self.state_keeper_config.max_pubdata_per_batch > limit as u64 {
-                return Err(WiringError::Configuration(format!(
-                    "Max pubdata per batch is greater than the blob size limit: {} > {}",
-                    self.state_keeper_config.max_pubdata_per_batch, limit
-                )));
+                if !self.da_config.use_blob_chunking() {
+                    return Err(WiringError::Configuration(format!(
+                        "Max pubdata per batch is greater than the blob size limit: {} > {}; \
+                         consider enabling blob chunking",
+                        self.state_keeper_config.max_pubdata_per_batch, limit
+                    )));
+                }
+                if !da_client.supports_chunked_inclusion() {
+                    return Err(WiringError::Configuration(format!(
+                        "Blob chunking is enabled, but the DA client ({}) doesn't support it",
+                        da_client.client_type().into_pubdata_type()
+                    )));
+                }
+                tracing::info!(
+                    "Max pubdata per batch is greater than the blob size limit: {} > {}; \
+                     pubdata exceeding the limit will be dispatched as several blobs",
+                    self.state_keeper_config.max_pubdata_per_batch,
+                    limit
+                );
             }
         }
 
@@ -9,7 +9,7 @@ use anyhow::Context as _;
 use serde::{Deserialize, Serialize};
 use tokio::sync::watch;
 use zksync_dal::{
-    pruning_dal::{HardPruningInfo, PruningInfo, SoftPruningInfo},
+    pruning_dal::{HardPruningInfo, PruningInfo, PruningRetentionRules, SoftPruningInfo},
     Connection, ConnectionPool, Core, CoreDal,
 };
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
@@ -40,6 +40,8 @@ pub struct DbPrunerConfig {
     /// Minimum age of an L1 batch in order for it to be eligible for pruning. Setting this to zero
     /// will effectively disable this pruning criterion.
     pub minimum_l1_batch_age: Duration,
+    /// Rules specifying transactions and events retained indefinitely regardless of other pruning criteria.
+    pub retention_rules: PruningRetentionRules,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -251,9 +253,10 @@ impl DbPruner {
 
         let mut dal = transaction.pruning_dal();
         let stats = tokio::select! {
-            result = dal.hard_prune_batches_range(
+            result = dal.hard_prune_batches_range_with_retention(
                 soft_pruned.l1_batch,
                 soft_pruned.l2_block,
+                &self.config.retention_rules,
             ) => result?,
 
             _ = stop_receiver.changed() => {
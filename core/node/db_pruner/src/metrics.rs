@@ -56,6 +56,9 @@ pub(super) struct DbPrunerMetrics {
     /// Number of entities deleted during a single hard pruning iteration, grouped by entity type.
     #[metrics(buckets = ENTITY_COUNT_BUCKETS)]
     deleted_entities: Family<PrunedEntityType, Histogram<u64>>,
+    /// Number of transactions retained during a single hard pruning iteration because of retention rules.
+    #[metrics(buckets = ENTITY_COUNT_BUCKETS)]
+    retained_transactions: Histogram<u64>,
     /// Number of times a certain condition has resulted in a specific outcome (succeeded, failed, or errored).
     condition_outcomes: Family<ConditionOutcomeLabels, Counter>,
 }
@@ -69,11 +72,13 @@ impl DbPrunerMetrics {
             deleted_events,
             deleted_call_traces,
             deleted_l2_to_l1_logs,
+            retained_transactions,
         } = stats;
         tracing::info!(
             "Performed pruning of database, deleted {deleted_l1_batches} L1 batches, {deleted_l2_blocks} L2 blocks, \
              {deleted_storage_logs} storage logs, \
-             {deleted_events} events, {deleted_call_traces} call traces, {deleted_l2_to_l1_logs} L2-to-L1 logs"
+             {deleted_events} events, {deleted_call_traces} call traces, {deleted_l2_to_l1_logs} L2-to-L1 logs; \
+             retained {retained_transactions} transactions"
         );
 
         self.deleted_entities[&PrunedEntityType::L1Batch].observe(deleted_l1_batches);
@@ -82,6 +87,7 @@ impl DbPrunerMetrics {
         self.deleted_entities[&PrunedEntityType::Event].observe(deleted_events);
         self.deleted_entities[&PrunedEntityType::L2ToL1Log].observe(deleted_l2_to_l1_logs);
         self.deleted_entities[&PrunedEntityType::CallTrace].observe(deleted_call_traces);
+        self.retained_transactions.observe(retained_transactions);
     }
 
     pub fn observe_condition(&self, condition: &dyn PruneCondition, outcome: ConditionOutcome) {
@@ -1,6 +1,9 @@
 use std::time::Duration;
 
-use zksync_dal::node::{MasterPool, PoolResource};
+use zksync_dal::{
+    node::{MasterPool, PoolResource},
+    pruning_dal::PruningRetentionRules,
+};
 use zksync_health_check::node::AppHealthCheckResource;
 use zksync_node_framework::{
     service::StopReceiver,
@@ -17,6 +20,7 @@ pub struct PruningLayer {
     pruning_removal_delay: Duration,
     pruning_chunk_size: u32,
     minimum_l1_batch_age: Duration,
+    retention_rules: PruningRetentionRules,
 }
 
 #[derive(Debug, FromContext)]
@@ -42,8 +46,15 @@ impl PruningLayer {
             pruning_removal_delay,
             pruning_chunk_size,
             minimum_l1_batch_age,
+            retention_rules: PruningRetentionRules::default(),
         }
     }
+
+    /// Sets rules for data retained regardless of other pruning criteria.
+    pub fn with_retention_rules(mut self, retention_rules: PruningRetentionRules) -> Self {
+        self.retention_rules = retention_rules;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -63,6 +74,7 @@ impl WiringLayer for PruningLayer {
                 removal_delay: self.pruning_removal_delay,
                 pruned_batch_chunk_size: self.pruning_chunk_size,
                 minimum_l1_batch_age: self.minimum_l1_batch_age,
+                retention_rules: self.retention_rules,
             },
             main_pool,
         );
@@ -78,6 +78,7 @@ async fn is_l1_batch_prunable_works() {
             removal_delay: Duration::ZERO,
             pruned_batch_chunk_size: 1,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         ConnectionPool::test_pool().await,
         vec![failing_check, other_failing_check],
@@ -148,6 +149,7 @@ async fn hard_pruning_ignores_conditions_checks() {
             removal_delay: Duration::ZERO,
             pruned_batch_chunk_size: 5,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![nothing_prunable_check],
@@ -197,6 +199,7 @@ async fn pruner_catches_up_with_hard_pruning_up_to_soft_pruning_boundary_ignorin
             removal_delay: Duration::ZERO,
             pruned_batch_chunk_size: 5,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![], //No checks, so every batch is prunable
@@ -235,6 +238,7 @@ async fn unconstrained_pruner_with_fresh_database() {
             removal_delay: Duration::ZERO,
             pruned_batch_chunk_size: 3,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![], //No checks, so every batch is prunable
@@ -275,6 +279,7 @@ async fn pruning_blocked_after_first_chunk() {
             removal_delay: Duration::ZERO,
             pruned_batch_chunk_size: 3,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![first_chunk_prunable_check],
@@ -315,6 +320,7 @@ async fn pruner_is_resistant_to_errors() {
             removal_delay: Duration::ZERO,
             pruned_batch_chunk_size: 3,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![erroneous_condition],
@@ -485,6 +491,7 @@ async fn pruner_with_real_conditions() {
         removal_delay: Duration::from_millis(10), // non-zero to not have a tight loop in `DbPruner::run()`
         pruned_batch_chunk_size: 1,
         minimum_l1_batch_age: Duration::ZERO,
+        retention_rules: PruningRetentionRules::default(),
     };
     let pruner = DbPruner::new(config, pool.clone());
     let mut health_check = pruner.health_check();
@@ -541,6 +548,7 @@ async fn pruning_iteration_timely_shuts_down() {
             removal_delay: Duration::MAX, // intentionally chosen so that pruning iterations stuck
             pruned_batch_chunk_size: 3,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![], //No checks, so every batch is prunable
@@ -570,6 +578,7 @@ async fn pruner_timely_shuts_down() {
             removal_delay: Duration::MAX, // intentionally chosen so that pruning iterations stuck
             pruned_batch_chunk_size: 3,
             minimum_l1_batch_age: Duration::ZERO,
+            retention_rules: PruningRetentionRules::default(),
         },
         pool.clone(),
         vec![], //No checks, so every batch is prunable
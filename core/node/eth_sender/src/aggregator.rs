@@ -286,11 +286,14 @@ impl Aggregator {
             .config
             .l1_batch_min_age_before_execute_seconds
             .map(|age| unix_timestamp_ms() - age * 1_000);
-        let ready_for_execute_batches = storage
+        let mut ready_for_execute_batches = storage
             .blocks_dal()
             .get_ready_for_execute_l1_batches(limit, max_l1_batch_timestamp_millis)
             .await
             .unwrap();
+        if self.config.require_tee_finalization {
+            retain_tee_finalized_prefix(storage, &mut ready_for_execute_batches).await;
+        }
         let Some(l1_batches) = extract_ready_subrange(
             storage,
             &mut self.execute_criteria,
@@ -708,6 +711,33 @@ impl Aggregator {
     }
 }
 
+/// Truncates `l1_batches` (which must be consecutive) to the longest prefix of TEE-finalized batches.
+pub(crate) async fn retain_tee_finalized_prefix(
+    storage: &mut Connection<'_, Core>,
+    l1_batches: &mut Vec<L1BatchWithMetadata>,
+) {
+    let (Some(first), Some(last)) = (l1_batches.first(), l1_batches.last()) else {
+        return;
+    };
+    let finalized_numbers = storage
+        .tee_proof_generation_dal()
+        .get_tee_finalized_batch_numbers(first.header.number..=last.header.number)
+        .await
+        .unwrap();
+    let finalized_count = l1_batches
+        .iter()
+        .zip(&finalized_numbers)
+        .take_while(|(batch, &number)| batch.header.number == number)
+        .count();
+    if finalized_count < l1_batches.len() {
+        tracing::debug!(
+            "L1 batch #{} is not TEE-finalized; postponing its execution",
+            l1_batches[finalized_count].header.number
+        );
+        l1_batches.truncate(finalized_count);
+    }
+}
+
 async fn extract_ready_subrange(
     storage: &mut Connection<'_, Core>,
     publish_criteria: &mut [Box<dyn L1BatchPublishCriterion>],
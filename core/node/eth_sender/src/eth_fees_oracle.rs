@@ -4,6 +4,8 @@ use std::{
     sync::Arc,
 };
 
+use tokio::sync::watch;
+use zksync_config::configs::dynamic::DynamicConfig;
 use zksync_eth_client::{ClientError, EnrichedClientError};
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
 use zksync_types::eth_sender::TxHistory;
@@ -33,9 +35,24 @@ pub(crate) struct GasAdjusterFeesOracle {
     pub max_acceptable_priority_fee_in_gwei: u64,
     pub time_in_mempool_in_l1_blocks_cap: u32,
     pub max_acceptable_base_fee_in_wei: u64,
+    /// If set, overrides `max_acceptable_priority_fee_in_gwei`.
+    pub dynamic_config: Option<watch::Receiver<DynamicConfig>>,
 }
 
 impl GasAdjusterFeesOracle {
+    fn max_acceptable_priority_fee_in_gwei(&self) -> u64 {
+        let dynamic_value = self.dynamic_config.as_ref().and_then(|config| {
+            let config = config.borrow();
+            Some(
+                config
+                    .eth_sender
+                    .as_ref()?
+                    .max_acceptable_priority_fee_in_gwei,
+            )
+        });
+        dynamic_value.unwrap_or(self.max_acceptable_priority_fee_in_gwei)
+    }
+
     fn assert_fee_is_not_zero(&self, value: u64, fee_type: &'static str) {
         if value == 0 {
             panic!(
@@ -137,11 +154,12 @@ impl GasAdjusterFeesOracle {
         }
 
         // Extra check to prevent sending transaction will extremely high priority fee.
-        if priority_fee_per_gas > self.max_acceptable_priority_fee_in_gwei {
+        let max_acceptable_priority_fee_in_gwei = self.max_acceptable_priority_fee_in_gwei();
+        if priority_fee_per_gas > max_acceptable_priority_fee_in_gwei {
             panic!(
                 "Extremely high value of priority_fee_per_gas is suggested: {}, while max acceptable is {}",
                 priority_fee_per_gas,
-                self.max_acceptable_priority_fee_in_gwei
+                max_acceptable_priority_fee_in_gwei
             );
         }
 
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use tokio::sync::watch;
-use zksync_config::configs::eth_sender::SenderConfig;
+use zksync_config::configs::{dynamic::DynamicConfig, eth_sender::SenderConfig};
 use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_eth_client::{BoundEthInterface, CallFunctionArgs, ContractCallError, EthInterface};
@@ -86,6 +86,7 @@ pub struct EthTxAggregator {
     priority_tree_start_index: Option<usize>,
     settlement_layer: Option<SettlementLayer>,
     initial_pending_nonces: HashMap<Address, u64>,
+    dynamic_config: Option<watch::Receiver<DynamicConfig>>,
 }
 
 struct TxData {
@@ -109,6 +110,7 @@ impl EthTxAggregator {
         state_transition_chain_contract: Address,
         rollup_chain_id: L2ChainId,
         settlement_layer: Option<SettlementLayer>,
+        dynamic_config: Option<watch::Receiver<DynamicConfig>>,
     ) -> Self {
         let eth_client = eth_client.for_component("eth_tx_aggregator");
         let eth_client_blobs = eth_client_blobs.map(|c| c.for_component("eth_tx_aggregator"));
@@ -142,9 +144,18 @@ impl EthTxAggregator {
             priority_tree_start_index: None,
             settlement_layer,
             initial_pending_nonces,
+            dynamic_config,
         }
     }
 
+    fn tx_aggregation_paused(&self) -> bool {
+        let dynamic_value = self.dynamic_config.as_ref().and_then(|config| {
+            let config = config.borrow();
+            Some(config.eth_sender.as_ref()?.tx_aggregation_paused)
+        });
+        dynamic_value.unwrap_or(self.config.tx_aggregation_paused)
+    }
+
     pub async fn run(mut self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         self.health_updater
             .update(Health::from(HealthStatus::Ready));
@@ -645,7 +656,7 @@ impl EthTxAggregator {
             // We only disable commit operations, the rest are allowed
         }
 
-        if self.config.tx_aggregation_paused {
+        if self.tx_aggregation_paused() {
             let reason = Some("tx aggregation is paused");
             op_restrictions.commit_restriction = reason;
             op_restrictions.prove_restriction = reason;
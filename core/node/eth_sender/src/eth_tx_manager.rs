@@ -4,7 +4,10 @@ use std::{
 };
 
 use tokio::sync::watch;
-use zksync_config::configs::eth_sender::{GasLimitMode, SenderConfig};
+use zksync_config::configs::{
+    dynamic::DynamicConfig,
+    eth_sender::{GasLimitMode, SenderConfig},
+};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_eth_client::{
     encode_blob_tx_with_sidecar, BoundEthInterface, ExecutedTxStatus, RawTransactionBytes,
@@ -51,6 +54,7 @@ impl EthTxManager {
         ethereum_client: Option<Box<dyn BoundEthInterface>>,
         ethereum_client_blobs: Option<Box<dyn BoundEthInterface>>,
         l2_client: Option<Box<dyn BoundEthInterface>>,
+        dynamic_config: Option<watch::Receiver<DynamicConfig>>,
     ) -> Self {
         let ethereum_client = ethereum_client.map(|eth| eth.for_component("eth_tx_manager"));
         let ethereum_client_blobs =
@@ -60,6 +64,7 @@ impl EthTxManager {
             max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
             time_in_mempool_in_l1_blocks_cap: config.time_in_mempool_in_l1_blocks_cap,
             max_acceptable_base_fee_in_wei: config.max_acceptable_base_fee_in_wei,
+            dynamic_config,
         };
         let l1_interface = Box::new(RealL1Interface {
             ethereum_client,
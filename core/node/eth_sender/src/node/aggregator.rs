@@ -16,6 +16,7 @@ use zksync_node_framework::{
     FromContext, IntoContext,
 };
 use zksync_object_store::node::ObjectStoreResource;
+use zksync_shared_resources::dynamic_config::DynamicConfigHandle;
 use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 
 use crate::{Aggregator, EthTxAggregator};
@@ -34,6 +35,7 @@ use crate::{Aggregator, EthTxAggregator};
 /// - `BoundEthInterfaceForBlobsResource` (optional)
 /// - `ObjectStoreResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
+/// - `DynamicConfigHandle` (optional)
 ///
 /// ## Adds tasks
 ///
@@ -58,6 +60,7 @@ pub struct Input {
     pub circuit_breakers: CircuitBreakersResource,
     #[context(default)]
     pub app_health: AppHealthCheckResource,
+    pub dynamic_config: Option<DynamicConfigHandle>,
     pub sl_contracts: SettlementLayerContractsResource,
 }
 
@@ -152,6 +155,7 @@ impl WiringLayer for EthTxAggregatorLayer {
             diamond_proxy_addr,
             self.zksync_network_id,
             input.settlement_mode.settlement_layer_for_sending_txs(),
+            input.dynamic_config.map(|config| config.subscribe()),
         )
         .await;
 
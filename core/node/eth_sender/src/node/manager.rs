@@ -12,6 +12,7 @@ use zksync_node_framework::{
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
+use zksync_shared_resources::dynamic_config::DynamicConfigHandle;
 
 use crate::EthTxManager;
 
@@ -28,6 +29,7 @@ use crate::EthTxManager;
 /// - `BoundEthInterfaceForBlobsResource` (optional)
 /// - `TxParamsResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
+/// - `DynamicConfigHandle` (optional)
 ///
 /// ## Adds tasks
 ///
@@ -48,6 +50,7 @@ pub struct Input {
     pub circuit_breakers: CircuitBreakersResource,
     #[context(default)]
     pub app_health: AppHealthCheckResource,
+    pub dynamic_config: Option<DynamicConfigHandle>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -83,6 +86,7 @@ impl WiringLayer for EthTxManagerLayer {
             Some(eth_client),
             eth_client_blobs,
             l2_client,
+            input.dynamic_config.map(|config| config.subscribe()),
         );
 
         // Insert circuit breaker.
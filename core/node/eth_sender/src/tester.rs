@@ -284,6 +284,7 @@ impl EthSenderTester {
             STATE_TRANSITION_CONTRACT_ADDRESS,
             Default::default(),
             Some(SettlementLayer::L1(chain_id)),
+            None,
         )
         .await;
 
@@ -294,6 +295,7 @@ impl EthSenderTester {
             Some(gateway.clone()),
             Some(gateway_blobs.clone()),
             None,
+            None,
         );
 
         let connection_pool_clone = connection_pool.clone();
@@ -333,6 +335,7 @@ impl EthSenderTester {
             None,
             None,
             Some(self.l2_gateway.clone()),
+            None,
         );
         self.settlement_layer = SettlementLayer::Gateway(10.into());
         tracing::info!("Switched eth-sender tester to use Gateway!");
@@ -21,6 +21,7 @@ use zksync_types::{
     ethabi::{self, Token},
     helpers::unix_timestamp_ms,
     settlement::SettlementLayer,
+    tee_types::TeeType,
     web3::{self, contract::Error},
     Address, K256PrivateKey, L1BatchNumber, L2ChainId, ProtocolVersionId, SLChainId, H256, U256,
 };
@@ -29,6 +30,7 @@ use zksync_web3_decl::client::MockClient;
 use crate::{
     abstract_l1_interface::{AbstractL1Interface, OperatorType, RealL1Interface},
     aggregated_operations::AggregatedOperation,
+    aggregator::retain_tee_finalized_prefix,
     tester::{
         EthSenderTester, TestL1Batch, STATE_TRANSITION_CONTRACT_ADDRESS,
         STATE_TRANSITION_MANAGER_CONTRACT_ADDRESS,
@@ -535,6 +537,30 @@ async fn transactions_are_not_resent_on_the_same_block() {
     tester.assert_just_sent_tx_count_equals(0).await;
 }
 
+#[tokio::test]
+async fn execution_waits_for_tee_finalization() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    for number in [1, 2, 4] {
+        storage
+            .tee_proof_generation_dal()
+            .mark_l1_batch_tee_finalized(L1BatchNumber(number), &[TeeType::Sgx], 1)
+            .await
+            .unwrap();
+    }
+
+    let mut l1_batches: Vec<_> = (1..=4)
+        .map(|number| l1_batch_with_metadata(create_l1_batch(number)))
+        .collect();
+    retain_tee_finalized_prefix(&mut storage, &mut l1_batches).await;
+    let numbers: Vec<_> = l1_batches.iter().map(|batch| batch.header.number).collect();
+    assert_eq!(numbers, [L1BatchNumber(1), L1BatchNumber(2)]);
+
+    let mut l1_batches = vec![l1_batch_with_metadata(create_l1_batch(3))];
+    retain_tee_finalized_prefix(&mut storage, &mut l1_batches).await;
+    assert!(l1_batches.is_empty());
+}
+
 #[test_log::test(tokio::test)]
 async fn switching_to_gateway_works_for_most_basic_scenario() {
     let mut tester = EthSenderTester::new(
@@ -6,7 +6,7 @@ use std::{
 };
 
 use tokio::sync::watch;
-use zksync_config::GasAdjusterConfig;
+use zksync_config::{configs::dynamic::DynamicConfig, GasAdjusterConfig};
 use zksync_eth_client::EthFeeInterface;
 use zksync_types::{
     commitment::L1BatchCommitmentMode, pubdata_da::PubdataSendingMode, L1_GAS_PER_PUBDATA_BYTE,
@@ -61,6 +61,7 @@ pub struct GasAdjuster {
     pub(super) gas_per_pubdata_price_statistic: GasStatistics<u64>,
 
     pub(super) config: GasAdjusterConfig,
+    dynamic_config: Option<watch::Receiver<DynamicConfig>>,
     pubdata_sending_mode: PubdataSendingMode,
     client: GasAdjusterClient,
     commitment_mode: L1BatchCommitmentMode,
@@ -119,12 +120,31 @@ impl GasAdjuster {
             l2_pubdata_price_statistics,
             gas_per_pubdata_price_statistic,
             config,
+            dynamic_config: None,
             pubdata_sending_mode,
             client,
             commitment_mode,
         })
     }
 
+    /// Makes the adjuster use pricing params from the provided dynamic config, so that they can be updated
+    /// without restarting the node.
+    pub fn with_dynamic_config(mut self, dynamic_config: watch::Receiver<DynamicConfig>) -> Self {
+        self.dynamic_config = Some(dynamic_config);
+        self
+    }
+
+    /// Returns the config with dynamic pricing params applied.
+    fn effective_config(&self) -> GasAdjusterConfig {
+        let mut config = self.config;
+        if let Some(dynamic_config) = &self.dynamic_config {
+            if let Some(gas_adjuster) = &dynamic_config.borrow().gas_adjuster {
+                gas_adjuster.apply_to(&mut config);
+            }
+        }
+        config
+    }
+
     /// Performs an actualization routine for `GasAdjuster`.
     /// This method is intended to be invoked periodically.
     pub async fn keep_updated(&self) -> anyhow::Result<()> {
@@ -199,7 +219,7 @@ impl GasAdjuster {
     }
 
     fn bound_gas_price(&self, gas_price: u64) -> u64 {
-        let max_l1_gas_price = self.config.max_l1_gas_price();
+        let max_l1_gas_price = self.effective_config().max_l1_gas_price();
         if gas_price > max_l1_gas_price {
             tracing::warn!(
                 "Effective gas price is too high: {gas_price}, using max allowed: {}",
@@ -229,21 +249,23 @@ impl GasAdjuster {
     /// Returns the sum of base and priority fee, in wei, not considering time in mempool.
     /// Can be used to get an estimate of current gas price.
     pub(crate) fn estimate_effective_gas_price(&self) -> u64 {
-        if let Some(price) = self.config.internal_enforced_l1_gas_price {
+        let config = self.effective_config();
+        if let Some(price) = config.internal_enforced_l1_gas_price {
             return price;
         }
 
         let effective_gas_price = self.get_base_fee(0) + self.get_priority_fee();
 
         let calculated_price =
-            (self.config.internal_l1_pricing_multiplier * effective_gas_price as f64) as u64;
+            (config.internal_l1_pricing_multiplier * effective_gas_price as f64) as u64;
 
         // Bound the price if it's too high.
         self.bound_gas_price(calculated_price)
     }
 
     pub(crate) fn estimate_effective_pubdata_price(&self) -> u64 {
-        if let Some(price) = self.config.internal_enforced_pubdata_price {
+        let config = self.effective_config();
+        if let Some(price) = config.internal_enforced_pubdata_price {
             return price;
         }
 
@@ -255,7 +277,7 @@ impl GasAdjuster {
 
                 // Check if blob base fee overflows `u64` before converting. Can happen only in very extreme cases.
                 if blob_base_fee_median > U256::from(u64::MAX) {
-                    let max_allowed = self.config.max_blob_base_fee();
+                    let max_allowed = config.max_blob_base_fee();
                     tracing::error!("Blob base fee is too high: {blob_base_fee_median}, using max allowed: {max_allowed}");
                     return max_allowed;
                 }
@@ -264,7 +286,7 @@ impl GasAdjuster {
                     .set(blob_base_fee_median.as_u64());
                 let calculated_price = blob_base_fee_median.as_u64() as f64
                     * BLOB_GAS_PER_BYTE as f64
-                    * self.config.internal_pubdata_pricing_multiplier;
+                    * config.internal_pubdata_pricing_multiplier;
 
                 self.cap_pubdata_fee(calculated_price)
             }
@@ -284,7 +306,7 @@ impl GasAdjuster {
 
     fn cap_pubdata_fee(&self, pubdata_fee: f64) -> u64 {
         // We will treat the max blob base fee as the maximal fee that we can take for each byte of pubdata.
-        let max_blob_base_fee = self.config.max_blob_base_fee();
+        let max_blob_base_fee = self.effective_config().max_blob_base_fee();
         match self.commitment_mode {
             L1BatchCommitmentMode::Validium => 0,
             L1BatchCommitmentMode::Rollup => {
@@ -298,8 +320,9 @@ impl GasAdjuster {
     }
 
     fn calculate_price_with_formula(&self, time_in_mempool_in_l1_blocks: u32, value: u64) -> u64 {
-        let a = self.config.pricing_formula_parameter_a;
-        let b = self.config.pricing_formula_parameter_b;
+        let config = self.effective_config();
+        let a = config.pricing_formula_parameter_a;
+        let b = config.pricing_formula_parameter_b;
 
         // Currently we use an exponential formula.
         // The alternative is a linear one:
@@ -8,7 +8,7 @@ use zksync_node_framework::{
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
-use zksync_shared_resources::PubdataSendingModeResource;
+use zksync_shared_resources::{dynamic_config::DynamicConfigHandle, PubdataSendingModeResource};
 use zksync_web3_decl::node::SettlementLayerClient;
 
 use super::resources::GasAdjusterResource;
@@ -26,6 +26,7 @@ pub struct GasAdjusterLayer {
 pub struct Input {
     pub client: SettlementLayerClient,
     pub pubdata_sending_mode: PubdataSendingModeResource,
+    pub dynamic_config: Option<DynamicConfigHandle>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -60,7 +61,7 @@ impl WiringLayer for GasAdjusterLayer {
             SettlementLayerClient::L2(client) => client.into(),
         };
 
-        let adjuster = GasAdjuster::new(
+        let mut adjuster = GasAdjuster::new(
             client,
             self.gas_adjuster_config,
             input.pubdata_sending_mode.0,
@@ -68,6 +69,9 @@ impl WiringLayer for GasAdjusterLayer {
         )
         .await
         .context("GasAdjuster::new()")?;
+        if let Some(dynamic_config) = input.dynamic_config {
+            adjuster = adjuster.with_dynamic_config(dynamic_config.subscribe());
+        }
         let gas_adjuster = Arc::new(adjuster);
 
         Ok(Output {
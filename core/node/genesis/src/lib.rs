@@ -229,6 +229,56 @@ pub fn make_genesis_batch_params(
     )
 }
 
+/// Storage state and factory deps produced by the (non-custom) genesis L1 batch.
+#[derive(Debug)]
+pub struct GenesisStorageState {
+    /// Storage writes ordered by their enumeration indices, i.e. the first write has index 1.
+    pub storage_logs: Vec<(StorageKey, H256)>,
+    /// Factory deps keyed by their bytecode hash, including base system contracts.
+    pub factory_deps: HashMap<H256, Vec<u8>>,
+}
+
+/// Computes the storage state of the genesis L1 batch without touching Postgres. Useful to recover node state
+/// from data that only contains diffs relative to genesis (e.g., pubdata published on L1).
+///
+/// Custom genesis state is not supported; the caller is responsible for checking the root hash
+/// computed from the returned state against the one in the genesis config.
+pub fn genesis_storage_state(genesis_params: &GenesisParams) -> GenesisStorageState {
+    let storage_logs = get_storage_logs(&genesis_params.system_contracts);
+    // Uses the same ordering as `make_genesis_batch_params()`.
+    let storage_logs = get_deduped_log_queries(&storage_logs)
+        .into_iter()
+        .filter(|log_query| log_query.rw_flag)
+        .map(|log| {
+            let key = StorageKey::new(AccountTreeId::new(log.address), u256_to_h256(log.key));
+            (key, u256_to_h256(log.written_value))
+        })
+        .collect();
+
+    let base_system_contracts = &genesis_params.base_system_contracts;
+    let base_factory_deps = [
+        &base_system_contracts.bootloader,
+        &base_system_contracts.default_aa,
+    ]
+    .into_iter()
+    .chain(base_system_contracts.evm_emulator.as_ref())
+    .map(|contract| (contract.hash, contract.code.clone()));
+    let factory_deps = genesis_params
+        .system_contracts
+        .iter()
+        .map(|contract| {
+            let hash = BytecodeHash::for_bytecode(&contract.bytecode).value();
+            (hash, contract.bytecode.clone())
+        })
+        .chain(base_factory_deps)
+        .collect();
+
+    GenesisStorageState {
+        storage_logs,
+        factory_deps,
+    }
+}
+
 pub async fn insert_genesis_batch_with_custom_state(
     storage: &mut Connection<'_, Core>,
     genesis_params: &GenesisParams,
@@ -64,3 +64,34 @@ async fn running_genesis_with_non_latest_protocol_version() {
     insert_genesis_batch(&mut conn, &params).await.unwrap();
     assert!(!conn.blocks_dal().is_genesis_needed().await.unwrap());
 }
+
+#[tokio::test]
+async fn genesis_storage_state_matches_inserted_genesis() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut conn = pool.connection().await.unwrap();
+    conn.blocks_dal().delete_genesis().await.unwrap();
+
+    let params = GenesisParams::mock();
+    let genesis_batch_params = insert_genesis_batch(&mut conn, &params).await.unwrap();
+
+    let state = genesis_storage_state(&params);
+    let instructions: Vec<_> = state
+        .storage_logs
+        .iter()
+        .enumerate()
+        .map(|(i, (key, value))| {
+            TreeInstruction::write(key.hashed_key_u256(), i as u64 + 1, *value)
+        })
+        .collect();
+    let metadata = ZkSyncTree::process_genesis_batch(&instructions);
+    assert_eq!(metadata.root_hash, genesis_batch_params.root_hash);
+
+    for (hash, bytecode) in &state.factory_deps {
+        let stored_bytecode = conn
+            .factory_deps_dal()
+            .get_sealed_factory_dep(*hash)
+            .await
+            .unwrap();
+        assert_eq!(stored_bytecode.as_ref(), Some(bytecode), "{hash:?}");
+    }
+}
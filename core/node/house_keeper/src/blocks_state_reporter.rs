@@ -6,7 +6,7 @@ use zksync_shared_metrics::{BlockL1Stage, BlockStage, L1StageLatencyLabel, APP_M
 
 use crate::{metrics::FRI_PROVER_METRICS, periodic_job::PeriodicJob};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct L1BatchMetricsReporter {
     reporting_interval_ms: u64,
     connection_pool: ConnectionPool<Core>,
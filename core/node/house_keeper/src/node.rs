@@ -1,8 +1,9 @@
 use zksync_config::configs::house_keeper::HouseKeeperConfig;
 use zksync_dal::node::{PoolResource, ReplicaPool};
+use zksync_health_check::{node::AppHealthCheckResource, ReactiveHealthCheck};
 use zksync_node_framework::{
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{Supervised, SupervisionPolicy, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -10,7 +11,8 @@ use zksync_node_framework::{
 use crate::{blocks_state_reporter::L1BatchMetricsReporter, periodic_job::PeriodicJob};
 
 /// Wiring layer for `HouseKeeper` - a component responsible for managing prover jobs
-/// and auxiliary server activities.
+/// and auxiliary server activities. House keeper tasks are not critical for the node, so they are restarted
+/// on failures instead of stopping the node.
 #[derive(Debug)]
 pub struct HouseKeeperLayer {
     house_keeper_config: HouseKeeperConfig,
@@ -19,12 +21,14 @@ pub struct HouseKeeperLayer {
 #[derive(Debug, FromContext)]
 pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
 pub struct Output {
     #[context(task)]
-    pub l1_batch_metrics_reporter: L1BatchMetricsReporter,
+    pub l1_batch_metrics_reporter: Supervised<L1BatchMetricsReporter>,
 }
 
 impl HouseKeeperLayer {
@@ -54,6 +58,15 @@ impl WiringLayer for HouseKeeperLayer {
                 .l1_batch_metrics_reporting_interval_ms,
             replica_pool,
         );
+        let (health_check, health_updater) = ReactiveHealthCheck::new("l1_batch_metrics_reporter");
+        input
+            .app_health
+            .0
+            .insert_component(health_check)
+            .map_err(WiringError::internal)?;
+        let l1_batch_metrics_reporter =
+            Supervised::new(l1_batch_metrics_reporter, SupervisionPolicy::restart())
+                .with_observer(Box::new(health_updater));
 
         Ok(Output {
             l1_batch_metrics_reporter,
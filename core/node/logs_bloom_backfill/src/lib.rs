@@ -7,7 +7,7 @@ use zksync_types::{block::build_bloom, BloomInput, L2BlockNumber};
 
 pub mod node;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LogsBloomBackfill {
     connection_pool: ConnectionPool<Core>,
 }
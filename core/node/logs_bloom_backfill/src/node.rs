@@ -1,7 +1,10 @@
+use std::num::NonZeroU32;
+
 use zksync_dal::node::{MasterPool, PoolResource};
+use zksync_health_check::{node::AppHealthCheckResource, ReactiveHealthCheck};
 use zksync_node_framework::{
     service::StopReceiver,
-    task::{Task, TaskId, TaskKind},
+    task::{Supervised, SupervisionPolicy, Task, TaskId, TaskKind},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -11,18 +14,22 @@ use crate::LogsBloomBackfill;
 /// Wiring layer for ethereum watcher
 ///
 /// Responsible for initializing and running of [`LogsBloomBackfill`] task, that backfills `logsBloom` for old blocks.
+/// Backfilling is not critical for the node, so the task is restarted on failures and gives up
+/// after several failed attempts without stopping the node.
 #[derive(Debug)]
 pub struct LogsBloomBackfillLayer;
 
 #[derive(Debug, FromContext)]
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
 pub struct Output {
     #[context(task)]
-    pub logs_bloom_backfill: LogsBloomBackfill,
+    pub logs_bloom_backfill: Supervised<LogsBloomBackfill>,
 }
 
 #[async_trait::async_trait]
@@ -36,7 +43,16 @@ impl WiringLayer for LogsBloomBackfillLayer {
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let pool = input.master_pool.get_singleton().await?;
-        let logs_bloom_backfill = LogsBloomBackfill::new(pool);
+        let (health_check, health_updater) = ReactiveHealthCheck::new("logs_bloom_backfill");
+        input
+            .app_health
+            .0
+            .insert_component(health_check)
+            .map_err(WiringError::internal)?;
+
+        let policy = SupervisionPolicy::restart_with_limit(NonZeroU32::new(5).unwrap());
+        let logs_bloom_backfill = Supervised::new(LogsBloomBackfill::new(pool), policy)
+            .with_observer(Box::new(health_updater));
         Ok(Output {
             logs_bloom_backfill,
         })
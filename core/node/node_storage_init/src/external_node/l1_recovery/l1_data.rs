@@ -0,0 +1,458 @@
+//! Fetching data for committed L1 batches from L1.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use zksync_contracts::{hyperchain_contract, POST_SHARED_BRIDGE_COMMIT_FUNCTION};
+use zksync_da_client::DataAvailabilityClient;
+use zksync_eth_client::{CallFunctionArgs, EthInterface};
+use zksync_l1_contract_interface::{
+    i_executor::structures::{
+        CommitBatchInfo, StoredBatchInfo, PUBDATA_SOURCE_BLOBS, PUBDATA_SOURCE_CALLDATA,
+        SUPPORTED_ENCODING_VERSION,
+    },
+    Tokenizable,
+};
+use zksync_types::{
+    commitment::L1BatchCommitmentMode,
+    ethabi::{self, ParamType, Token},
+    web3::{keccak256, BlockNumber, FilterBuilder},
+    Address, L1BatchNumber, H256, U256,
+};
+use zksync_web3_decl::client::{DynClient, L1};
+
+use super::UnsupportedSetup;
+use crate::L1RecoveryConfig;
+
+/// Data for an L1 batch committed on L1.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct CommittedBatch {
+    pub number: L1BatchNumber,
+    /// Enumeration index to be assigned to the next initially written slot, i.e., the number of tree leaves + 1.
+    pub index_repeated_storage_changes: u64,
+    pub state_root: H256,
+    pub operator_da_input: Vec<u8>,
+}
+
+/// Decoded data of a commit transaction.
+#[derive(Debug)]
+pub(super) struct CommitTxData {
+    /// Last batch committed before the batches in this transaction. Its state root is checked by the L1 contract.
+    pub last_committed_batch: StoredBatchInfo,
+    pub batches: Vec<CommittedBatch>,
+    pub is_pre_gateway: bool,
+}
+
+/// Fetches data necessary to recover node state from L1 and, for validiums, from the DA layer.
+#[derive(Debug)]
+pub(super) struct L1DataFetcher {
+    pub eth_client: Box<DynClient<L1>>,
+    pub diamond_proxy_addr: Address,
+    pub da_client: Option<Box<dyn DataAvailabilityClient>>,
+    pub config: L1RecoveryConfig,
+}
+
+impl L1DataFetcher {
+    pub async fn last_executed_batch(&self) -> anyhow::Result<L1BatchNumber> {
+        let number: U256 = CallFunctionArgs::new("getTotalBatchesExecuted", ())
+            .for_contract(self.diamond_proxy_addr, &hyperchain_contract())
+            .call(self.eth_client.as_ref())
+            .await
+            .context("failed calling `getTotalBatchesExecuted`")?;
+        Ok(L1BatchNumber(number.as_u32()))
+    }
+
+    /// Returns hashes of the commit transactions for L1 batches up to and including `last_batch`.
+    /// If a batch was committed several times (e.g., because of a revert), the latest commitment is returned.
+    pub async fn commit_tx_hashes(
+        &self,
+        last_batch: L1BatchNumber,
+    ) -> anyhow::Result<BTreeMap<L1BatchNumber, H256>> {
+        let contract = hyperchain_contract();
+        let event = contract
+            .event("BlockCommit")
+            .context("`BlockCommit` event not found for ZKsync L1 contract")?;
+        let latest_block = self
+            .eth_client
+            .block_number()
+            .await
+            .context("failed getting latest L1 block")?
+            .as_u64();
+
+        let mut tx_hashes = BTreeMap::new();
+        let mut from_block = self.config.l1_from_block;
+        while from_block <= latest_block {
+            let to_block = (from_block + self.config.l1_block_range.get() - 1).min(latest_block);
+            let filter = FilterBuilder::default()
+                .address(vec![self.diamond_proxy_addr])
+                .from_block(BlockNumber::Number(from_block.into()))
+                .to_block(BlockNumber::Number(to_block.into()))
+                .topics(Some(vec![event.signature()]), None, None, None)
+                .build();
+            let logs = self.eth_client.logs(&filter).await.with_context(|| {
+                format!(
+                    "failed fetching `BlockCommit` events for L1 blocks {from_block}..={to_block}"
+                )
+            })?;
+
+            for log in logs {
+                let batch_number = log
+                    .topics
+                    .get(1)
+                    .context("`BlockCommit` event has unexpected number of topics")?;
+                let batch_number = L1BatchNumber(U256::from_big_endian(&batch_number.0).as_u32());
+                let tx_hash = log
+                    .transaction_hash
+                    .context("`BlockCommit` event doesn't have a transaction hash")?;
+                if batch_number <= last_batch {
+                    // Logs are ordered chronologically, so later commitments override earlier ones.
+                    tx_hashes.insert(batch_number, tx_hash);
+                }
+            }
+            tracing::debug!(
+                "Scanned L1 blocks {from_block}..={to_block}; found commit transactions for {} L1 batches",
+                tx_hashes.len()
+            );
+            from_block = to_block + 1;
+        }
+        Ok(tx_hashes)
+    }
+
+    pub async fn commit_tx_data(&self, tx_hash: H256) -> anyhow::Result<CommitTxData> {
+        let tx = self
+            .eth_client
+            .get_tx(tx_hash)
+            .await
+            .with_context(|| format!("failed fetching commit transaction {tx_hash:?}"))?
+            .with_context(|| format!("commit transaction {tx_hash:?} is missing on L1"))?;
+        decode_commit_tx_input(&tx.input.0)
+            .with_context(|| format!("failed decoding commit transaction {tx_hash:?}"))
+    }
+
+    /// Returns pubdata for the specified batch, either extracted from the operator DA input or fetched from the DA layer.
+    pub async fn pubdata(
+        &self,
+        batch: &CommittedBatch,
+        is_pre_gateway: bool,
+    ) -> anyhow::Result<Vec<u8>> {
+        match self.config.commitment_mode {
+            L1BatchCommitmentMode::Rollup => {
+                extract_rollup_pubdata(&batch.operator_da_input, is_pre_gateway)
+            }
+            L1BatchCommitmentMode::Validium => {
+                let da_client = self
+                    .da_client
+                    .as_ref()
+                    .context("DA client is required to recover validium state")?;
+                da_client
+                    .get_pubdata(batch.number.0)
+                    .await
+                    .map_err(|err| err.error)
+                    .with_context(|| {
+                        format!(
+                            "failed fetching pubdata for L1 batch #{} from DA layer",
+                            batch.number
+                        )
+                    })?
+                    .with_context(|| {
+                        format!(
+                            "pubdata for L1 batch #{} is not available from {:?} DA client",
+                            batch.number,
+                            da_client.client_type()
+                        )
+                    })
+            }
+        }
+    }
+}
+
+fn decode_commit_tx_input(input: &[u8]) -> anyhow::Result<CommitTxData> {
+    let (selector, input) = input
+        .split_first_chunk::<4>()
+        .context("transaction input is too short")?;
+    let contract = hyperchain_contract();
+    let post_gateway_function = contract
+        .function("commitBatchesSharedBridge")
+        .context("L1 contract does not have `commitBatchesSharedBridge` function")?;
+
+    let (last_committed_batch, commitments, is_pre_gateway) = if *selector
+        == post_gateway_function.short_signature()
+    {
+        let mut tokens = post_gateway_function
+            .decode_input(input)
+            .context("failed decoding calldata for L1 commit function")?;
+        let Some(Token::Bytes(commit_data)) = tokens.pop() else {
+            anyhow::bail!("unexpected signature for L1 commit function: last token is not bytes");
+        };
+        let (&version, commit_data) = commit_data.split_first().context("commit data is empty")?;
+        anyhow::ensure!(
+            version == SUPPORTED_ENCODING_VERSION,
+            "unexpected encoding version: {version}"
+        );
+        let schema = [
+            StoredBatchInfo::schema(),
+            ParamType::Array(Box::new(CommitBatchInfo::post_gateway_schema())),
+        ];
+        let [last_committed_batch, commitments] = ethabi::decode(&schema, commit_data)
+            .context("failed decoding commit data")?
+            .try_into()
+            .unwrap();
+        (last_committed_batch, commitments, false)
+    } else if *selector == POST_SHARED_BRIDGE_COMMIT_FUNCTION.short_signature() {
+        let mut tokens = POST_SHARED_BRIDGE_COMMIT_FUNCTION
+            .decode_input(input)
+            .context("failed decoding calldata for L1 commit function")?;
+        let commitments = tokens
+            .pop()
+            .context("unexpected signature for L1 commit function")?;
+        let last_committed_batch = tokens
+            .pop()
+            .context("unexpected signature for L1 commit function")?;
+        (last_committed_batch, commitments, true)
+    } else {
+        anyhow::bail!(
+                "unsupported commit function selector {selector:?}; L1 recovery only supports batches committed \
+                 after the shared bridge upgrade"
+            );
+    };
+
+    let last_committed_batch = StoredBatchInfo::from_token(last_committed_batch)
+        .context("failed decoding last committed batch info")?;
+    let batches = commitments
+        .into_array()
+        .context("unexpected format of batch commitments")?
+        .into_iter()
+        .map(decode_batch_commitment)
+        .collect::<anyhow::Result<_>>()?;
+    Ok(CommitTxData {
+        last_committed_batch,
+        batches,
+        is_pre_gateway,
+    })
+}
+
+/// Decodes the fields of `CommitBatchInfo` relevant for state recovery. The pre-gateway and post-gateway
+/// layouts coincide for these fields.
+fn decode_batch_commitment(token: Token) -> anyhow::Result<CommittedBatch> {
+    let tokens = token
+        .into_tuple()
+        .context("batch commitment is not a tuple")?;
+    let uint_field = |idx: usize, name: &str| {
+        tokens
+            .get(idx)
+            .cloned()
+            .and_then(Token::into_uint)
+            .and_then(|value| u64::try_from(value).ok())
+            .with_context(|| format!("invalid `{name}` in batch commitment"))
+    };
+    let number = uint_field(0, "batchNumber")?;
+    let index_repeated_storage_changes = uint_field(2, "indexRepeatedStorageChanges")?;
+    let state_root = tokens
+        .get(3)
+        .cloned()
+        .and_then(Token::into_fixed_bytes)
+        .filter(|bytes| bytes.len() == 32)
+        .context("invalid `newStateRoot` in batch commitment")?;
+    let operator_da_input = tokens
+        .get(9)
+        .cloned()
+        .and_then(Token::into_bytes)
+        .context("invalid `operatorDAInput` in batch commitment")?;
+
+    Ok(CommittedBatch {
+        number: L1BatchNumber(
+            u32::try_from(number).context("batch number overflow in batch commitment")?,
+        ),
+        index_repeated_storage_changes,
+        state_root: H256::from_slice(&state_root),
+        operator_da_input,
+    })
+}
+
+/// Extracts pubdata for a rollup batch published in calldata.
+fn extract_rollup_pubdata(
+    operator_da_input: &[u8],
+    is_pre_gateway: bool,
+) -> anyhow::Result<Vec<u8>> {
+    /// Size of a blob commitment / linear hash.
+    const HASH_LEN: usize = 32;
+
+    // Post-gateway inputs start with a header: state diff hash, full pubdata hash, number of blobs
+    // and linear hashes of blobs.
+    let (pubdata_hash, blob_count, operator_da_input) = if is_pre_gateway {
+        (None, 1, operator_da_input)
+    } else {
+        anyhow::ensure!(
+            operator_da_input.len() > 2 * HASH_LEN,
+            "operator DA input is too short"
+        );
+        let pubdata_hash = H256::from_slice(&operator_da_input[HASH_LEN..2 * HASH_LEN]);
+        let blob_count = usize::from(operator_da_input[2 * HASH_LEN]);
+        let header_len = 2 * HASH_LEN + 1 + blob_count * HASH_LEN;
+        let rest = operator_da_input
+            .get(header_len..)
+            .context("operator DA input is too short")?;
+        (Some(pubdata_hash), blob_count, rest)
+    };
+
+    let (&source, data) = operator_da_input
+        .split_first()
+        .context("operator DA input doesn't contain pubdata source")?;
+    match source {
+        PUBDATA_SOURCE_CALLDATA => { /* OK */ }
+        PUBDATA_SOURCE_BLOBS => return Err(UnsupportedSetup::BlobPubdata.into()),
+        _ => anyhow::bail!("unexpected pubdata source: {source}"),
+    }
+
+    // Pubdata in calldata is followed by a single blob commitment, or by a commitment per blob for relayed calldata.
+    let Some(pubdata_hash) = pubdata_hash else {
+        let pubdata_len = data
+            .len()
+            .checked_sub(HASH_LEN)
+            .context("pubdata in calldata is too short")?;
+        return Ok(data[..pubdata_len].to_vec());
+    };
+    [1, blob_count]
+        .into_iter()
+        .find_map(|commitment_count| {
+            let pubdata_len = data.len().checked_sub(commitment_count * HASH_LEN)?;
+            let pubdata = &data[..pubdata_len];
+            (H256(keccak256(pubdata)) == pubdata_hash).then(|| pubdata.to_vec())
+        })
+        .context("pubdata in calldata doesn't match the pubdata hash in the operator DA input")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post_gateway_rollup_input(
+        pubdata: &[u8],
+        blob_count: u8,
+        commitment_count: usize,
+    ) -> Vec<u8> {
+        let mut input = vec![0xaa; 32]; // state diff hash
+        input.extend(keccak256(pubdata));
+        input.push(blob_count);
+        input.extend(vec![0xbb; 32 * usize::from(blob_count)]);
+        input.push(PUBDATA_SOURCE_CALLDATA);
+        input.extend(pubdata);
+        input.extend(vec![0xcc; 32 * commitment_count]);
+        input
+    }
+
+    #[test]
+    fn extracting_post_gateway_rollup_pubdata() {
+        let pubdata = vec![1, 2, 3, 4, 5];
+        let input = post_gateway_rollup_input(&pubdata, 1, 1);
+        assert_eq!(extract_rollup_pubdata(&input, false).unwrap(), pubdata);
+
+        // Relayed calldata with a commitment per blob.
+        let input = post_gateway_rollup_input(&pubdata, 3, 3);
+        assert_eq!(extract_rollup_pubdata(&input, false).unwrap(), pubdata);
+
+        let mut input = post_gateway_rollup_input(&pubdata, 1, 1);
+        input[98] ^= 1; // first pubdata byte
+        let err = extract_rollup_pubdata(&input, false).unwrap_err();
+        assert!(err.to_string().contains("doesn't match"), "{err}");
+    }
+
+    #[test]
+    fn extracting_pre_gateway_rollup_pubdata() {
+        let pubdata = vec![1, 2, 3, 4, 5];
+        let input: Vec<_> = [PUBDATA_SOURCE_CALLDATA]
+            .into_iter()
+            .chain(pubdata.clone())
+            .chain([0xcc; 32])
+            .collect();
+        assert_eq!(extract_rollup_pubdata(&input, true).unwrap(), pubdata);
+
+        let err = extract_rollup_pubdata(&[PUBDATA_SOURCE_CALLDATA, 1], true).unwrap_err();
+        assert!(err.to_string().contains("too short"), "{err}");
+    }
+
+    #[test]
+    fn extracting_pubdata_from_blobs_is_not_supported() {
+        let input = [PUBDATA_SOURCE_BLOBS]
+            .into_iter()
+            .chain([0; 96])
+            .collect::<Vec<_>>();
+        let err = extract_rollup_pubdata(&input, true).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<UnsupportedSetup>(),
+            Some(&UnsupportedSetup::BlobPubdata)
+        );
+    }
+
+    #[test]
+    fn decoding_post_gateway_commit_tx_input() {
+        let last_committed_batch = StoredBatchInfo {
+            batch_number: 1,
+            batch_hash: H256::repeat_byte(1),
+            index_repeated_storage_changes: 10,
+            number_of_layer1_txs: 0.into(),
+            priority_operations_hash: H256::zero(),
+            l2_logs_tree_root: H256::zero(),
+            timestamp: 1.into(),
+            commitment: H256::zero(),
+        };
+        let batch_commitments = (2_u64..4).map(|number| {
+            Token::Tuple(vec![
+                Token::Uint(number.into()),
+                Token::Uint(number.into()),
+                Token::Uint((number * 10).into()),
+                Token::FixedBytes(H256::repeat_byte(number as u8).0.to_vec()),
+                Token::Uint(0.into()),
+                Token::FixedBytes(vec![0; 32]),
+                Token::FixedBytes(vec![0; 32]),
+                Token::FixedBytes(vec![0; 32]),
+                Token::Bytes(vec![]),
+                Token::Bytes(vec![number as u8; 3]),
+            ])
+        });
+        let mut commit_data = vec![SUPPORTED_ENCODING_VERSION];
+        commit_data.extend(ethabi::encode(&[
+            last_committed_batch.clone().into_token(),
+            Token::Array(batch_commitments.collect()),
+        ]));
+        let function = hyperchain_contract()
+            .function("commitBatchesSharedBridge")
+            .unwrap()
+            .clone();
+        let input = function
+            .encode_input(&[
+                Token::Uint(270.into()),
+                Token::Uint(2.into()),
+                Token::Uint(3.into()),
+                Token::Bytes(commit_data),
+            ])
+            .unwrap();
+
+        let data = decode_commit_tx_input(&input).unwrap();
+        assert!(!data.is_pre_gateway);
+        assert_eq!(data.last_committed_batch, last_committed_batch);
+        assert_eq!(
+            data.batches,
+            [
+                CommittedBatch {
+                    number: L1BatchNumber(2),
+                    index_repeated_storage_changes: 20,
+                    state_root: H256::repeat_byte(2),
+                    operator_da_input: vec![2; 3],
+                },
+                CommittedBatch {
+                    number: L1BatchNumber(3),
+                    index_repeated_storage_changes: 30,
+                    state_root: H256::repeat_byte(3),
+                    operator_da_input: vec![3; 3],
+                },
+            ]
+        );
+
+        let err = decode_commit_tx_input(&[1, 2, 3, 4, 5]).unwrap_err();
+        assert!(
+            err.to_string().contains("unsupported commit function"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,666 @@
+//! Recovery of the external node state from data published on L1 (and, for validiums, on the DA layer).
+//!
+//! Instead of downloading a snapshot produced by the main node, the node reconstructs a snapshot locally:
+//!
+//! 1. The genesis state is reconstructed from the genesis params fetched from the main node.
+//! 2. Commit transactions for all L1 batches up to the target batch are located using `BlockCommit` events
+//!    emitted by the diamond proxy contract.
+//! 3. Pubdata of each batch is extracted from the commit transaction calldata (for rollups) or fetched from
+//!    the DA layer (for validiums). Compressed state diffs from the pubdata are applied to a temporary Merkle tree,
+//!    and the resulting root hash is checked against the state root committed on L1.
+//! 4. The reconstructed state is written to the snapshot object store and is then applied using the ordinary
+//!    snapshot applier.
+//!
+//! Storage state and factory deps are thus fully verified against L1. L1 batch and L2 block headers
+//! for the snapshot are still fetched from the main node. Storage keys and values are kept on disk
+//! (in a temporary Merkle tree and key index), so memory usage doesn't grow with the state size.
+//!
+//! Recovery is limited to rollups publishing pubdata in calldata and to validiums. Unsupported setups
+//! are reported as [`UnsupportedSetup`] errors before the chain history is replayed:
+//!
+//! - Pubdata published via blobs (checked for the target batch; chains switch from calldata to blobs
+//!   rather than vice versa).
+//! - Chains with the EVM emulator enabled, since EVM bytecodes are not recovered.
+//! - Chains with a custom genesis (checked against the first commit transaction).
+//!
+//! Chains settling on Gateway are rejected when validating the node config. Batches must be committed
+//! using the `commitBatchesSharedBridge` function (i.e., legacy commitments predating the shared bridge
+//! are not supported).
+
+use std::{collections::BTreeMap, fmt};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use tokio::sync::watch;
+use zksync_da_client::DataAvailabilityClient;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_merkle_tree::RocksDBWrapper;
+use zksync_node_genesis::genesis_storage_state;
+use zksync_object_store::{ObjectStore, StoredObject};
+use zksync_snapshots_applier::SnapshotsApplierMainNodeClient;
+use zksync_system_constants::CONTRACT_DEPLOYER_ADDRESS;
+use zksync_types::{
+    api,
+    snapshots::{
+        SnapshotFactoryDependencies, SnapshotHeader, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsChunkMetadata, SnapshotStorageLogsStorageKey, SnapshotVersion,
+    },
+    tokens::TokenInfo,
+    Address, L1BatchNumber, L2BlockNumber, L2ChainId, OrStopped, H256,
+};
+use zksync_web3_decl::{
+    client::{DynClient, L1, L2},
+    error::{ClientRpcContext, EnrichedClientResult},
+    namespaces::{EthNamespaceClient, ZksNamespaceClient},
+};
+
+use self::{
+    l1_data::L1DataFetcher,
+    pubdata::ParsedPubdata,
+    state::{KeyIndex, RecoveredState},
+};
+use crate::L1RecoveryConfig;
+
+mod l1_data;
+mod pubdata;
+mod state;
+
+/// Slot in the `ContractDeployer` storage with the allowed contract types; a non-zero value means that
+/// EVM emulation is enabled.
+const ALLOWED_CONTRACT_TYPES_SLOT: u64 = 1;
+
+/// Same as the default chunk size used by the snapshot creator.
+const STORAGE_LOGS_CHUNK_SIZE: u64 = 1_000_000;
+
+/// Chain setup that cannot be recovered from L1 data. Such a node should be recovered from a snapshot
+/// produced by the main node instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum UnsupportedSetup {
+    /// Pubdata is published in EIP-4844 blobs. Blobs are not fetched from the consensus layer
+    /// (which additionally prunes them after ~18 days).
+    BlobPubdata,
+    /// EVM emulator is enabled for the chain. EVM bytecodes are not published as factory deps,
+    /// so they cannot be recovered.
+    EvmEmulator,
+    /// Genesis state reconstructed from the main node genesis config doesn't match the state committed on L1.
+    CustomGenesis,
+}
+
+impl fmt::Display for UnsupportedSetup {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::BlobPubdata => "pubdata published in blobs",
+            Self::EvmEmulator => "chains with EVM emulator",
+            Self::CustomGenesis => "chains with a custom genesis",
+        };
+        write!(
+            formatter,
+            "snapshot recovery from L1 doesn't support {description}; disable `snapshots_recovery_from_l1` \
+             to recover from a snapshot produced by the main node"
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedSetup {}
+
+/// Recovers the node state from L1 data and produces a snapshot that can be applied by the snapshot applier.
+#[derive(Debug)]
+pub struct ExternalNodeL1Recovery {
+    l2_chain_id: L2ChainId,
+    main_node_client: Box<DynClient<L2>>,
+    fetcher: L1DataFetcher,
+}
+
+impl ExternalNodeL1Recovery {
+    pub fn new(
+        l2_chain_id: L2ChainId,
+        main_node_client: Box<DynClient<L2>>,
+        eth_client: Box<DynClient<L1>>,
+        diamond_proxy_addr: Address,
+        da_client: Option<Box<dyn DataAvailabilityClient>>,
+        config: L1RecoveryConfig,
+    ) -> Self {
+        Self {
+            l2_chain_id,
+            main_node_client: main_node_client.for_component("l1_recovery"),
+            fetcher: L1DataFetcher {
+                eth_client: eth_client.for_component("l1_recovery"),
+                diamond_proxy_addr,
+                da_client,
+                config,
+            },
+        }
+    }
+
+    /// Prepares a snapshot for the specified L1 batch (or the last executed batch if not specified) in the object store
+    /// and returns a client that should be supplied to the snapshot applier in place of the main node client.
+    ///
+    /// If snapshot recovery was already started, the snapshot is assumed to be present in the object store
+    /// and is not reconstructed again.
+    pub(super) async fn prepare(
+        &self,
+        pool: &ConnectionPool<Core>,
+        object_store: &dyn ObjectStore,
+        snapshot_l1_batch: Option<L1BatchNumber>,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> Result<Box<dyn SnapshotsApplierMainNodeClient>, OrStopped> {
+        let mut storage = pool.connection_tagged("en").await?;
+        let recovery_status = storage
+            .snapshot_recovery_dal()
+            .get_applied_snapshot_status()
+            .await?;
+        drop(storage);
+
+        let (header, root_hash) = if let Some(status) = recovery_status {
+            tracing::info!(
+                "Snapshot recovery from L1 batch #{} was already started; using the previously reconstructed snapshot",
+                status.l1_batch_number
+            );
+            let chunk_count = status.storage_logs_chunks_processed.len() as u64;
+            let header = snapshot_header(
+                object_store,
+                status.l1_batch_number,
+                status.l2_block_number,
+                chunk_metadata(object_store, status.l1_batch_number, chunk_count),
+            );
+            (header, status.l1_batch_root_hash)
+        } else {
+            self.reconstruct_snapshot(object_store, snapshot_l1_batch, stop_receiver)
+                .await?
+        };
+
+        Ok(Box::new(LocalSnapshotClient {
+            main_node_client: self.main_node_client.clone(),
+            header,
+            root_hash,
+        }))
+    }
+
+    async fn reconstruct_snapshot(
+        &self,
+        object_store: &dyn ObjectStore,
+        snapshot_l1_batch: Option<L1BatchNumber>,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> Result<(SnapshotHeader, H256), OrStopped> {
+        let last_executed_batch = self.fetcher.last_executed_batch().await?;
+        let target_batch = snapshot_l1_batch.unwrap_or(last_executed_batch);
+        if target_batch == L1BatchNumber(0) || target_batch > last_executed_batch {
+            let err = anyhow::anyhow!(
+                "cannot recover from L1 batch #{target_batch}; it must be executed on L1 (last executed batch: #{last_executed_batch}) \
+                 and must not be the genesis batch"
+            );
+            return Err(err.into());
+        }
+        tracing::info!("Recovering node state at L1 batch #{target_batch} from L1 data");
+        let (_, last_l2_block) = self
+            .main_node_client
+            .get_l2_block_range(target_batch)
+            .rpc_context("get_l2_block_range")
+            .with_arg("l1_batch_number", &target_batch)
+            .await
+            .map_err(anyhow::Error::from)?
+            .with_context(|| format!("L1 batch #{target_batch} is missing on main node"))?;
+        let last_l2_block = L2BlockNumber(last_l2_block.as_u32());
+        self.check_evm_emulator(last_l2_block).await?;
+        let tx_hashes = self.fetcher.commit_tx_hashes(target_batch).await?;
+        self.check_target_batch(&tx_hashes, target_batch).await?;
+
+        let genesis_params = zksync_node_sync::genesis::create_genesis_params(
+            &self.main_node_client,
+            self.l2_chain_id,
+        )
+        .await
+        .context("failed fetching genesis params")?;
+        let genesis_state = genesis_storage_state(&genesis_params);
+        let recovery_dir = tempfile::TempDir::new()
+            .context("failed creating temporary dir for recovered state")?;
+        let db = RocksDBWrapper::new(&recovery_dir.path().join("tree"))
+            .context("failed initializing RocksDB for Merkle tree")?;
+        let keys = KeyIndex::new(&recovery_dir.path().join("keys"))?;
+        let mut state =
+            tokio::task::spawn_blocking(move || RecoveredState::genesis(db, keys, genesis_state))
+                .await
+                .context("panicked initializing genesis state")??;
+
+        let mut commit_tx: Option<(H256, l1_data::CommitTxData)> = None;
+        for batch_number in (1..=target_batch.0).map(L1BatchNumber) {
+            if *stop_receiver.borrow() {
+                return Err(OrStopped::Stopped);
+            }
+
+            let tx_hash = *tx_hashes.get(&batch_number).with_context(|| {
+                format!(
+                    "commit transaction for L1 batch #{batch_number} is not found; make sure that the L1 block \
+                     to start scanning from precedes the commitment of the first batch"
+                )
+            })?;
+            let tx_data = match commit_tx.take() {
+                Some((hash, data)) if hash == tx_hash => data,
+                _ => {
+                    let data = self.fetcher.commit_tx_data(tx_hash).await?;
+                    let mut check_result =
+                        check_commit_tx_anchor(&data, state.l1_batch_number(), state.root_hash());
+                    if batch_number == L1BatchNumber(1) {
+                        check_result = check_result.map_err(|err| {
+                            tracing::error!("Genesis state mismatch: {err:#}");
+                            UnsupportedSetup::CustomGenesis.into()
+                        });
+                    }
+                    check_result
+                        .with_context(|| format!("unexpected commit transaction {tx_hash:?}"))?;
+                    data
+                }
+            };
+            let batch = tx_data
+                .batches
+                .iter()
+                .find(|batch| batch.number == batch_number)
+                .with_context(|| {
+                    format!(
+                        "commit transaction {tx_hash:?} doesn't contain L1 batch #{batch_number}"
+                    )
+                })?
+                .clone();
+            let pubdata = self.fetcher.pubdata(&batch, tx_data.is_pre_gateway).await?;
+            commit_tx = Some((tx_hash, tx_data));
+
+            state = tokio::task::spawn_blocking(move || {
+                let pubdata = ParsedPubdata::parse(&pubdata).with_context(|| {
+                    format!("failed parsing pubdata for L1 batch #{}", batch.number)
+                })?;
+                state.apply_batch(&batch, pubdata)?;
+                anyhow::Ok(state)
+            })
+            .await
+            .context("panicked applying L1 batch")??;
+            tracing::info!(
+                "Applied L1 batch #{batch_number}, state has {} storage logs",
+                state.storage_log_count()
+            );
+        }
+
+        // Double-check the main node response using the state verified against L1.
+        if state.is_evm_emulation_enabled()? {
+            return Err(anyhow::Error::from(UnsupportedSetup::EvmEmulator).into());
+        }
+        let root_hash = state.root_hash();
+        let (factory_deps_filepath, storage_logs_chunks) = state
+            .write_snapshot(object_store, STORAGE_LOGS_CHUNK_SIZE)
+            .await?;
+        drop(state);
+
+        let header = SnapshotHeader {
+            version: SnapshotVersion::Version1.into(),
+            l1_batch_number: target_batch,
+            l2_block_number: last_l2_block,
+            base_l1_batch_number: None,
+            storage_logs_chunks,
+            factory_deps_filepath,
+        };
+        tracing::info!(
+            "Reconstructed snapshot for L1 batch #{target_batch}, L2 block #{last_l2_block} with root hash {root_hash:?}"
+        );
+        Ok((header, root_hash))
+    }
+}
+
+impl ExternalNodeL1Recovery {
+    /// Checks that EVM emulation is not enabled as of the target L2 block, based on the allowed contract types
+    /// in the `ContractDeployer` storage. Since EVM emulation cannot be disabled once enabled, checking
+    /// the target block is sufficient.
+    async fn check_evm_emulator(&self, target_l2_block: L2BlockNumber) -> anyhow::Result<()> {
+        let block =
+            api::BlockIdVariant::BlockNumber(api::BlockNumber::Number(target_l2_block.0.into()));
+        let allowed_contract_types = self
+            .main_node_client
+            .get_storage_at(
+                CONTRACT_DEPLOYER_ADDRESS,
+                ALLOWED_CONTRACT_TYPES_SLOT.into(),
+                Some(block),
+            )
+            .rpc_context("get_storage_at")
+            .with_arg("l2_block", &target_l2_block)
+            .await?;
+        if !allowed_contract_types.is_zero() {
+            return Err(UnsupportedSetup::EvmEmulator.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that pubdata for the target L1 batch can be obtained before replaying the chain history, so that
+    /// unsupported setups (e.g., pubdata published in blobs) are detected early. Chains switch from calldata to blobs
+    /// rather than vice versa, so checking the target batch is sufficient in practice.
+    async fn check_target_batch(
+        &self,
+        tx_hashes: &BTreeMap<L1BatchNumber, H256>,
+        target_batch: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        let tx_hash = *tx_hashes.get(&target_batch).with_context(|| {
+            format!("commit transaction for target L1 batch #{target_batch} is not found")
+        })?;
+        let tx_data = self.fetcher.commit_tx_data(tx_hash).await?;
+        let batch = tx_data
+            .batches
+            .iter()
+            .find(|batch| batch.number == target_batch)
+            .with_context(|| {
+                format!("commit transaction {tx_hash:?} doesn't contain L1 batch #{target_batch}")
+            })?;
+        self.fetcher
+            .pubdata(batch, tx_data.is_pre_gateway)
+            .await
+            .with_context(|| {
+                format!("L1 batch #{target_batch} cannot be recovered from L1 data")
+            })?;
+        Ok(())
+    }
+}
+
+/// Checks that a commit transaction builds on top of the state reconstructed so far. The L1 contract verifies
+/// that the last committed batch in the transaction matches the stored one, so this anchors the reconstructed state to L1.
+fn check_commit_tx_anchor(
+    data: &l1_data::CommitTxData,
+    l1_batch_number: L1BatchNumber,
+    root_hash: H256,
+) -> anyhow::Result<()> {
+    let last_committed = &data.last_committed_batch;
+    anyhow::ensure!(
+        last_committed.batch_number == u64::from(l1_batch_number.0),
+        "transaction commits batches on top of L1 batch #{}, while L1 batch #{l1_batch_number} was expected",
+        last_committed.batch_number
+    );
+    anyhow::ensure!(
+        last_committed.batch_hash == root_hash,
+        "state root hash for L1 batch #{l1_batch_number} differs from the reconstructed one: \
+         {:?} on L1, {root_hash:?} reconstructed",
+        last_committed.batch_hash
+    );
+    Ok(())
+}
+
+fn chunk_metadata(
+    object_store: &dyn ObjectStore,
+    l1_batch_number: L1BatchNumber,
+    chunk_count: u64,
+) -> Vec<SnapshotStorageLogsChunkMetadata> {
+    let prefix = object_store.get_storage_prefix::<SnapshotStorageLogsChunk>();
+    (0..chunk_count)
+        .map(|chunk_id| {
+            let key = SnapshotStorageLogsStorageKey {
+                l1_batch_number,
+                chunk_id,
+            };
+            SnapshotStorageLogsChunkMetadata {
+                chunk_id,
+                filepath: format!("{prefix}/{}", SnapshotStorageLogsChunk::encode_key(key)),
+            }
+        })
+        .collect()
+}
+
+fn snapshot_header(
+    object_store: &dyn ObjectStore,
+    l1_batch_number: L1BatchNumber,
+    l2_block_number: L2BlockNumber,
+    storage_logs_chunks: Vec<SnapshotStorageLogsChunkMetadata>,
+) -> SnapshotHeader {
+    let prefix = object_store.get_storage_prefix::<SnapshotFactoryDependencies>();
+    let factory_deps_filename = SnapshotFactoryDependencies::encode_key(l1_batch_number);
+    SnapshotHeader {
+        version: SnapshotVersion::Version1.into(),
+        l1_batch_number,
+        l2_block_number,
+        base_l1_batch_number: None,
+        storage_logs_chunks,
+        factory_deps_filepath: format!("{prefix}/{factory_deps_filename}"),
+    }
+}
+
+/// Main node client wrapper that exposes a locally reconstructed snapshot. The L1 batch root hash
+/// is taken from the reconstructed state (i.e., is verified against L1) rather than from the main node.
+struct LocalSnapshotClient {
+    main_node_client: Box<DynClient<L2>>,
+    header: SnapshotHeader,
+    root_hash: H256,
+}
+
+impl fmt::Debug for LocalSnapshotClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("LocalSnapshotClient")
+            .field("l1_batch_number", &self.header.l1_batch_number)
+            .field("l2_block_number", &self.header.l2_block_number)
+            .field("root_hash", &self.root_hash)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl SnapshotsApplierMainNodeClient for LocalSnapshotClient {
+    async fn fetch_l1_batch_details(
+        &self,
+        number: L1BatchNumber,
+    ) -> EnrichedClientResult<Option<api::L1BatchDetails>> {
+        let mut details = self.main_node_client.fetch_l1_batch_details(number).await?;
+        if number == self.header.l1_batch_number {
+            if let Some(details) = &mut details {
+                details.base.root_hash = Some(self.root_hash);
+            }
+        }
+        Ok(details)
+    }
+
+    async fn fetch_l2_block_details(
+        &self,
+        number: L2BlockNumber,
+    ) -> EnrichedClientResult<Option<api::BlockDetails>> {
+        self.main_node_client.fetch_l2_block_details(number).await
+    }
+
+    async fn fetch_newest_snapshot_l1_batch_number(
+        &self,
+    ) -> EnrichedClientResult<Option<L1BatchNumber>> {
+        Ok(Some(self.header.l1_batch_number))
+    }
+
+    async fn fetch_snapshot(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> EnrichedClientResult<Option<SnapshotHeader>> {
+        Ok((l1_batch_number == self.header.l1_batch_number).then(|| self.header.clone()))
+    }
+
+    async fn fetch_delta_snapshot_l1_batch_numbers(
+        &self,
+    ) -> EnrichedClientResult<Vec<L1BatchNumber>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_tokens(
+        &self,
+        at_l2_block: L2BlockNumber,
+    ) -> EnrichedClientResult<Vec<TokenInfo>> {
+        self.main_node_client.fetch_tokens(at_l2_block).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_object_store::MockObjectStore;
+    use zksync_types::{
+        api::{BlockDetailsBase, BlockStatus, L1BatchDetails},
+        commitment::L1BatchCommitmentMode,
+        U256,
+    };
+    use zksync_web3_decl::client::MockClient;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn local_snapshot_client_overrides_snapshot_data() {
+        let l1_batch_number = L1BatchNumber(5);
+        let main_node_root_hash = H256::repeat_byte(1);
+        let main_node_client = MockClient::builder(L2::default())
+            .method("zks_getL1BatchDetails", move |number: L1BatchNumber| {
+                let details = L1BatchDetails {
+                    number,
+                    base: BlockDetailsBase {
+                        timestamp: 100,
+                        l1_tx_count: 0,
+                        l2_tx_count: 0,
+                        root_hash: Some(main_node_root_hash),
+                        status: BlockStatus::Verified,
+                        commit_tx_hash: None,
+                        committed_at: None,
+                        commit_chain_id: None,
+                        prove_tx_hash: None,
+                        proven_at: None,
+                        prove_chain_id: None,
+                        execute_tx_hash: None,
+                        executed_at: None,
+                        execute_chain_id: None,
+                        l1_gas_price: 0,
+                        l2_fair_gas_price: 0,
+                        fair_pubdata_price: None,
+                        base_system_contracts_hashes: Default::default(),
+                    },
+                    verified_stage: None,
+                };
+                Ok(Some(details))
+            })
+            .build();
+
+        let object_store = MockObjectStore::arc();
+        let header = snapshot_header(
+            &*object_store,
+            l1_batch_number,
+            L2BlockNumber(10),
+            chunk_metadata(&*object_store, l1_batch_number, 2),
+        );
+        assert!(header.storage_logs_chunks[1]
+            .filepath
+            .ends_with("snapshot_l1_batch_5_storage_logs_part_0001.proto.gzip"));
+        let client = LocalSnapshotClient {
+            main_node_client: Box::new(main_node_client),
+            header,
+            root_hash: H256::repeat_byte(2),
+        };
+
+        assert_eq!(
+            client
+                .fetch_newest_snapshot_l1_batch_number()
+                .await
+                .unwrap(),
+            Some(l1_batch_number)
+        );
+        let header = client
+            .fetch_snapshot(l1_batch_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(header.l2_block_number, L2BlockNumber(10));
+        assert_eq!(header.storage_logs_chunks.len(), 2);
+        assert!(client
+            .fetch_snapshot(L1BatchNumber(4))
+            .await
+            .unwrap()
+            .is_none());
+        assert!(client
+            .fetch_delta_snapshot_l1_batch_numbers()
+            .await
+            .unwrap()
+            .is_empty());
+
+        let details = client
+            .fetch_l1_batch_details(l1_batch_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.base.root_hash, Some(H256::repeat_byte(2)));
+        let details = client
+            .fetch_l1_batch_details(L1BatchNumber(4))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.base.root_hash, Some(main_node_root_hash));
+    }
+
+    #[tokio::test]
+    async fn chain_with_evm_emulator_is_rejected() {
+        let main_node_client = MockClient::builder(L2::default())
+            .method(
+                "eth_getStorageAt",
+                |address: Address, slot: U256, block: Option<api::BlockIdVariant>| {
+                    assert_eq!(address, CONTRACT_DEPLOYER_ADDRESS);
+                    assert_eq!(slot, U256::one());
+                    let Some(api::BlockIdVariant::BlockNumber(api::BlockNumber::Number(number))) =
+                        block
+                    else {
+                        panic!("unexpected block: {block:?}");
+                    };
+                    // EVM emulation is enabled starting from L2 block #10.
+                    let allowed_contract_types = if number.as_u32() >= 10 { 1 } else { 0 };
+                    Ok(H256::from_low_u64_be(allowed_contract_types))
+                },
+            )
+            .build();
+        let eth_client = MockClient::builder(L1::default()).build();
+        let recovery = ExternalNodeL1Recovery::new(
+            L2ChainId::default(),
+            Box::new(main_node_client),
+            Box::new(eth_client),
+            Address::repeat_byte(1),
+            None,
+            L1RecoveryConfig {
+                l1_from_block: 0,
+                l1_block_range: 1_000.try_into().unwrap(),
+                commitment_mode: L1BatchCommitmentMode::Rollup,
+            },
+        );
+
+        recovery.check_evm_emulator(L2BlockNumber(9)).await.unwrap();
+        let err = recovery
+            .check_evm_emulator(L2BlockNumber(10))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<UnsupportedSetup>(),
+            Some(&UnsupportedSetup::EvmEmulator)
+        );
+    }
+
+    #[test]
+    fn checking_commit_tx_anchor() {
+        use zksync_l1_contract_interface::i_executor::structures::StoredBatchInfo;
+
+        let last_committed_batch = StoredBatchInfo {
+            batch_number: 3,
+            batch_hash: H256::repeat_byte(3),
+            index_repeated_storage_changes: 10,
+            number_of_layer1_txs: 0.into(),
+            priority_operations_hash: H256::zero(),
+            l2_logs_tree_root: H256::zero(),
+            timestamp: 0.into(),
+            commitment: H256::zero(),
+        };
+        let data = l1_data::CommitTxData {
+            last_committed_batch,
+            batches: vec![],
+            is_pre_gateway: false,
+        };
+
+        check_commit_tx_anchor(&data, L1BatchNumber(3), H256::repeat_byte(3)).unwrap();
+        let err =
+            check_commit_tx_anchor(&data, L1BatchNumber(2), H256::repeat_byte(3)).unwrap_err();
+        assert!(
+            err.to_string().contains("L1 batch #2 was expected"),
+            "{err:#}"
+        );
+        let err = check_commit_tx_anchor(&data, L1BatchNumber(3), H256::zero()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("differs from the reconstructed one"),
+            "{err:#}"
+        );
+    }
+}
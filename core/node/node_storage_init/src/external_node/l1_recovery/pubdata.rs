@@ -0,0 +1,411 @@
+//! Parsing of L1 batch pubdata published on the settlement layer or DA layer.
+
+use std::collections::HashSet;
+
+use anyhow::Context as _;
+use zksync_system_constants::{COMPRESSOR_ADDRESS, L1_MESSENGER_ADDRESS};
+use zksync_types::{
+    address_to_h256,
+    bytecode::validate_bytecode,
+    commitment::SerializeCommitment,
+    l2_to_l1_log::L2ToL1Log,
+    web3::keccak256,
+    writes::{
+        compression::{decompress_with_strategy, COMPRESSION_VERSION_NUMBER},
+        BYTES_PER_DERIVED_KEY, BYTES_PER_ENUMERATION_INDEX,
+    },
+    Address, H256, U256,
+};
+
+/// Key of a storage slot changed in an L1 batch, as represented in pubdata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum StateDiffKey {
+    /// Initial write identified by the hashed storage key.
+    Initial(H256),
+    /// Repeated write identified by the enumeration index of the slot.
+    Repeated(u64),
+}
+
+/// Storage slot change parsed from pubdata. The value is compressed relative to the previous slot value.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct CompressedStateDiff {
+    pub key: StateDiffKey,
+    compressed_value: Vec<u8>,
+}
+
+impl CompressedStateDiff {
+    /// Decompresses the new slot value given the previous one (zero for initial writes).
+    pub fn decompress(&self, prev_value: U256) -> anyhow::Result<U256> {
+        let (value, _) = decompress_with_strategy(prev_value, &self.compressed_value)
+            .with_context(|| format!("invalid compressed value for {:?}", self.key))?;
+        Ok(value)
+    }
+}
+
+/// Parts of L1 batch pubdata relevant for state recovery.
+#[derive(Debug)]
+pub(super) struct ParsedPubdata {
+    /// Bytecodes published in the batch, including ones published in the compressed form.
+    pub published_bytecodes: Vec<Vec<u8>>,
+    /// Initial writes in the order of their enumeration indices, followed by repeated writes.
+    pub state_diffs: Vec<CompressedStateDiff>,
+}
+
+impl ParsedPubdata {
+    /// Parses pubdata in the format produced by `L1BatchWithMetadata::construct_pubdata()`:
+    /// L2-to-L1 logs, L2-to-L1 messages, published bytecodes and compressed state diffs.
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader::new(data);
+
+        let log_count = reader.read_u32().context("L2-to-L1 log count")? as usize;
+        let logs_len = log_count
+            .checked_mul(L2ToL1Log::SERIALIZED_SIZE)
+            .context("L2-to-L1 log count overflow")?;
+        let logs = reader.take(logs_len).context("L2-to-L1 logs")?;
+        let compressed_bytecode_hashes = Self::compressed_bytecode_hashes(logs);
+
+        let message_count = reader.read_u32().context("L2-to-L1 message count")?;
+        let mut published_bytecodes = vec![];
+        for _ in 0..message_count {
+            let len = reader.read_u32().context("L2-to-L1 message length")?;
+            let message = reader.take(len as usize).context("L2-to-L1 message")?;
+            if compressed_bytecode_hashes.contains(&H256(keccak256(message))) {
+                let bytecode = decompress_bytecode(message).context("compressed bytecode")?;
+                published_bytecodes.push(bytecode);
+            }
+        }
+
+        let bytecode_count = reader.read_u32().context("published bytecode count")?;
+        for _ in 0..bytecode_count {
+            let len = reader.read_u32().context("published bytecode length")?;
+            let bytecode = reader.take(len as usize).context("published bytecode")?;
+            published_bytecodes.push(bytecode.to_vec());
+        }
+
+        let state_diffs = Self::parse_state_diffs(&mut reader).context("state diffs")?;
+        anyhow::ensure!(
+            reader.is_empty(),
+            "{} unexpected trailing bytes in pubdata",
+            reader.data.len()
+        );
+        Ok(Self {
+            published_bytecodes,
+            state_diffs,
+        })
+    }
+
+    /// Returns hashes of L2-to-L1 messages sent by the compressor contract. Such messages contain compressed bytecodes.
+    fn compressed_bytecode_hashes(logs: &[u8]) -> HashSet<H256> {
+        let compressor_key = address_to_h256(&COMPRESSOR_ADDRESS);
+        logs.chunks_exact(L2ToL1Log::SERIALIZED_SIZE)
+            .filter_map(|log| {
+                // See `L2ToL1Log::serialize_commitment()` for the log layout; for messages, the log key is the sender
+                // and the value is the message hash.
+                let sender = Address::from_slice(&log[4..24]);
+                let key = H256::from_slice(&log[24..56]);
+                (sender == L1_MESSENGER_ADDRESS && key == compressor_key)
+                    .then(|| H256::from_slice(&log[56..88]))
+            })
+            .collect()
+    }
+
+    /// Parses state diffs in the format produced by `compress_state_diffs()`.
+    fn parse_state_diffs(reader: &mut Reader<'_>) -> anyhow::Result<Vec<CompressedStateDiff>> {
+        let version = reader.read_u8().context("compression version")?;
+        anyhow::ensure!(
+            version == COMPRESSION_VERSION_NUMBER,
+            "unsupported state diff compression version: {version}"
+        );
+        let len = reader.take(3).context("compressed state diffs length")?;
+        let len = u32::from_be_bytes([0, len[0], len[1], len[2]]) as usize;
+        let index_size = reader.read_u8().context("enumeration index size")?;
+        anyhow::ensure!(
+            index_size == BYTES_PER_ENUMERATION_INDEX,
+            "unsupported enumeration index size: {index_size}"
+        );
+
+        let mut reader = Reader::new(reader.take(len).context("compressed state diffs")?);
+        let initial_write_count = reader.read_u16().context("initial write count")?;
+        let mut state_diffs = Vec::with_capacity(initial_write_count.into());
+        for _ in 0..initial_write_count {
+            let key = reader
+                .take(BYTES_PER_DERIVED_KEY.into())
+                .context("initial write key")?;
+            let key = StateDiffKey::Initial(H256::from_slice(key));
+            let compressed_value = reader.read_compressed_value(key)?;
+            state_diffs.push(CompressedStateDiff {
+                key,
+                compressed_value,
+            });
+        }
+        while !reader.is_empty() {
+            let index = reader.read_u32().context("repeated write index")?;
+            let key = StateDiffKey::Repeated(index.into());
+            let compressed_value = reader.read_compressed_value(key)?;
+            state_diffs.push(CompressedStateDiff {
+                key,
+                compressed_value,
+            });
+        }
+        Ok(state_diffs)
+    }
+}
+
+/// Decompresses a bytecode compressed by the compressor contract. The compressed format is as follows:
+///
+/// - 2 bytes: the number of 8-byte chunks in the dictionary (N)
+/// - N * 8 bytes: dictionary chunks
+/// - remaining bytes: 2-byte dictionary indices for each 8-byte chunk of the bytecode
+fn decompress_bytecode(compressed: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut reader = Reader::new(compressed);
+    let dictionary_len = reader.read_u16().context("dictionary length")?;
+    let dictionary = reader
+        .take(usize::from(dictionary_len) * 8)
+        .context("dictionary")?;
+    anyhow::ensure!(
+        reader.data.len() % 2 == 0,
+        "encoded data has odd length {}",
+        reader.data.len()
+    );
+
+    let bytecode = reader
+        .data
+        .chunks_exact(2)
+        .map(|index| {
+            let index = usize::from(u16::from_be_bytes([index[0], index[1]]));
+            dictionary
+                .get(index * 8..(index + 1) * 8)
+                .with_context(|| format!("dictionary index {index} is out of bounds"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .concat();
+    validate_bytecode(&bytecode).context("decompressed bytecode is invalid")?;
+    Ok(bytecode)
+}
+
+#[derive(Debug)]
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        anyhow::ensure!(
+            self.data.len() >= len,
+            "unexpected end of data: expected {len} bytes, got {}",
+            self.data.len()
+        );
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn read_u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> anyhow::Result<u16> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn read_u32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a value compressed with `compress_with_best_strategy()` without decompressing it.
+    fn read_compressed_value(&mut self, key: StateDiffKey) -> anyhow::Result<Vec<u8>> {
+        let metadata = *self
+            .data
+            .first()
+            .with_context(|| format!("missing compressed value for {key:?}"))?;
+        // The metadata byte contains the compressed value length in 5 high bits and the operation in 3 low bits;
+        // the zero operation means that the value is not compressed.
+        let len = if metadata & 7 == 0 {
+            32
+        } else {
+            usize::from(metadata >> 3)
+        };
+        let value = self
+            .take(len + 1)
+            .with_context(|| format!("compressed value for {key:?}"))?;
+        Ok(value.to_vec())
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use zksync_types::{
+        writes::{compress_state_diffs, StateDiffRecord},
+        Address,
+    };
+
+    use super::*;
+
+    fn state_diff(
+        key: u8,
+        enumeration_index: u64,
+        initial_value: U256,
+        final_value: U256,
+    ) -> StateDiffRecord {
+        StateDiffRecord {
+            address: Address::repeat_byte(1),
+            key: U256::from(key),
+            derived_key: H256::repeat_byte(key).0,
+            enumeration_index,
+            initial_value,
+            final_value,
+        }
+    }
+
+    /// Compresses a bytecode using a dictionary with chunks in the order of their first occurrence.
+    fn compress_bytecode(bytecode: &[u8]) -> Vec<u8> {
+        let mut dictionary: Vec<&[u8]> = vec![];
+        let mut encoded_data = vec![];
+        for chunk in bytecode.chunks(8) {
+            let index = match dictionary.iter().position(|&entry| entry == chunk) {
+                Some(index) => index,
+                None => {
+                    dictionary.push(chunk);
+                    dictionary.len() - 1
+                }
+            };
+            encoded_data.extend((index as u16).to_be_bytes());
+        }
+
+        let mut compressed = (dictionary.len() as u16).to_be_bytes().to_vec();
+        compressed.extend(dictionary.concat());
+        compressed.extend(encoded_data);
+        compressed
+    }
+
+    fn message_log(sender: Address, message: &[u8]) -> Vec<u8> {
+        let log = L2ToL1Log {
+            shard_id: 0,
+            is_service: true,
+            tx_number_in_block: 0,
+            sender: L1_MESSENGER_ADDRESS,
+            key: address_to_h256(&sender),
+            value: H256(keccak256(message)),
+        };
+        let mut buffer = vec![0; L2ToL1Log::SERIALIZED_SIZE];
+        log.serialize_commitment(&mut buffer);
+        buffer
+    }
+
+    /// Builds pubdata publishing `bytecodes` in full and `compressed_bytecodes` in the compressed form.
+    pub(in crate::external_node::l1_recovery) fn build_pubdata(
+        bytecodes: &[Vec<u8>],
+        compressed_bytecodes: &[Vec<u8>],
+        state_diffs: Vec<StateDiffRecord>,
+    ) -> Vec<u8> {
+        let user_message = vec![1, 2, 3];
+        let mut messages = vec![(Address::repeat_byte(0x10), user_message)];
+        messages.extend(
+            compressed_bytecodes
+                .iter()
+                .map(|bytecode| (COMPRESSOR_ADDRESS, compress_bytecode(bytecode))),
+        );
+
+        let mut pubdata = vec![];
+        pubdata.extend((messages.len() as u32).to_be_bytes());
+        for (sender, message) in &messages {
+            pubdata.extend(message_log(*sender, message));
+        }
+        pubdata.extend((messages.len() as u32).to_be_bytes());
+        for (_, message) in &messages {
+            pubdata.extend((message.len() as u32).to_be_bytes());
+            pubdata.extend(message);
+        }
+
+        pubdata.extend((bytecodes.len() as u32).to_be_bytes());
+        for bytecode in bytecodes {
+            pubdata.extend((bytecode.len() as u32).to_be_bytes());
+            pubdata.extend(bytecode);
+        }
+        pubdata.extend(compress_state_diffs(state_diffs));
+        pubdata
+    }
+
+    #[test]
+    fn parsing_pubdata() {
+        let bytecodes = vec![vec![1; 96], vec![2; 32]];
+        let compressed_bytecodes = vec![(0..160).collect::<Vec<u8>>()];
+        let state_diffs = vec![
+            state_diff(1, 0, U256::zero(), U256::from(123)),
+            state_diff(2, 0, U256::zero(), U256::MAX - 5),
+            state_diff(3, 10, U256::from(1_000), U256::from(999)),
+            state_diff(4, 11, U256::from(1) << 100, U256::from(1_000_000)),
+            state_diff(5, 12, U256::from(5), U256::from_big_endian(&[0xab; 32])),
+        ];
+        let pubdata = build_pubdata(&bytecodes, &compressed_bytecodes, state_diffs.clone());
+
+        let parsed = ParsedPubdata::parse(&pubdata).unwrap();
+        let expected_bytecodes: Vec<_> =
+            compressed_bytecodes.into_iter().chain(bytecodes).collect();
+        assert_eq!(parsed.published_bytecodes, expected_bytecodes);
+        assert_eq!(parsed.state_diffs.len(), state_diffs.len());
+
+        // `compress_state_diffs()` sorts diffs by `(address, key)` and puts initial writes first,
+        // which coincides with the original order.
+        for (parsed_diff, expected_diff) in parsed.state_diffs.iter().zip(&state_diffs) {
+            let expected_key = if expected_diff.enumeration_index == 0 {
+                StateDiffKey::Initial(H256(expected_diff.derived_key))
+            } else {
+                StateDiffKey::Repeated(expected_diff.enumeration_index)
+            };
+            assert_eq!(parsed_diff.key, expected_key);
+            let value = parsed_diff.decompress(expected_diff.initial_value).unwrap();
+            assert_eq!(value, expected_diff.final_value);
+        }
+    }
+
+    #[test]
+    fn parsing_pubdata_without_state_diffs() {
+        let pubdata = build_pubdata(&[], &[], vec![]);
+        let parsed = ParsedPubdata::parse(&pubdata).unwrap();
+        assert!(parsed.published_bytecodes.is_empty());
+        assert!(parsed.state_diffs.is_empty());
+    }
+
+    #[test]
+    fn parsing_malformed_pubdata() {
+        let state_diffs = vec![state_diff(1, 0, U256::zero(), U256::from(123))];
+        let pubdata = build_pubdata(&[vec![1; 32]], &[], state_diffs);
+
+        for len in [0, 10, 100, pubdata.len() - 1] {
+            let err = ParsedPubdata::parse(&pubdata[..len]).unwrap_err();
+            assert!(
+                format!("{err:#}").contains("unexpected end of data"),
+                "{err:#}"
+            );
+        }
+
+        let mut pubdata_with_trailing_bytes = pubdata.clone();
+        pubdata_with_trailing_bytes.push(0);
+        let err = ParsedPubdata::parse(&pubdata_with_trailing_bytes).unwrap_err();
+        assert!(format!("{err:#}").contains("trailing bytes"), "{err:#}");
+    }
+
+    #[test]
+    fn decompressing_bytecode() {
+        let bytecode: Vec<_> = (0..32_u8).chain([0; 32]).chain(0..32).collect();
+        let compressed = compress_bytecode(&bytecode);
+        assert_eq!(decompress_bytecode(&compressed).unwrap(), bytecode);
+
+        let mut invalid_compressed = compressed.clone();
+        *invalid_compressed.last_mut().unwrap() = 0xff;
+        let err = decompress_bytecode(&invalid_compressed).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{err}");
+
+        let err = decompress_bytecode(&compressed[..compressed.len() - 1]).unwrap_err();
+        assert!(err.to_string().contains("odd length"), "{err}");
+    }
+}
@@ -0,0 +1,546 @@
+//! Node state reconstructed from pubdata.
+
+use std::{collections::HashMap, ops::RangeInclusive, path::Path};
+
+use anyhow::Context as _;
+use zksync_merkle_tree::{Database, MerkleTree, RocksDBWrapper, TreeEntry};
+use zksync_node_genesis::GenesisStorageState;
+use zksync_object_store::ObjectStore;
+use zksync_storage::{db::NamedColumnFamily, RocksDB};
+use zksync_types::{
+    bytecode::{validate_bytecode, BytecodeHash},
+    get_deployer_key, h256_to_u256,
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotFactoryDependency,
+        SnapshotStorageLog, SnapshotStorageLogsChunk, SnapshotStorageLogsChunkMetadata,
+        SnapshotStorageLogsStorageKey,
+    },
+    u256_to_h256,
+    web3::Bytes,
+    L1BatchNumber, H256,
+};
+
+use super::{
+    l1_data::CommittedBatch,
+    pubdata::{ParsedPubdata, StateDiffKey},
+    ALLOWED_CONTRACT_TYPES_SLOT,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum KeyIndexColumnFamily {
+    /// Enumeration index (big-endian `u64`) -> hashed key.
+    ByIndex,
+    /// Hashed key -> enumeration index (big-endian `u64`) followed by the L1 batch of the initial write
+    /// (big-endian `u32`).
+    ByKey,
+}
+
+impl NamedColumnFamily for KeyIndexColumnFamily {
+    const DB_NAME: &'static str = "l1_recovery_keys";
+    const ALL: &'static [Self] = &[Self::ByIndex, Self::ByKey];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ByIndex => "default",
+            Self::ByKey => "by_key",
+        }
+    }
+}
+
+/// On-disk index of storage keys written so far. Allows resolving enumeration indices of repeated writes
+/// and iterating over keys in the hashed key order without keeping all keys in memory.
+#[derive(Debug)]
+pub(super) struct KeyIndex {
+    db: RocksDB<KeyIndexColumnFamily>,
+}
+
+impl KeyIndex {
+    /// Opens the index at the specified path. The directory must be empty or not exist.
+    pub fn new(path: &Path) -> anyhow::Result<Self> {
+        let db = RocksDB::new(path).context("failed initializing RocksDB for storage keys")?;
+        Ok(Self { db })
+    }
+
+    fn insert(
+        &self,
+        first_index: u64,
+        keys: &[H256],
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        let mut write_batch = self.db.new_write_batch();
+        for (key, index) in keys.iter().zip(first_index..) {
+            let index = index.to_be_bytes();
+            write_batch.put_cf(KeyIndexColumnFamily::ByIndex, &index, key.as_bytes());
+            let mut value = index.to_vec();
+            value.extend_from_slice(&l1_batch_number.0.to_be_bytes());
+            write_batch.put_cf(KeyIndexColumnFamily::ByKey, key.as_bytes(), &value);
+        }
+        self.db
+            .write(write_batch)
+            .context("failed writing storage keys to RocksDB")
+    }
+
+    fn keys_by_indices(&self, indices: &[u64]) -> anyhow::Result<Vec<Option<H256>>> {
+        let db_keys = indices.iter().map(|index| index.to_be_bytes());
+        self.db
+            .multi_get_cf(KeyIndexColumnFamily::ByIndex, db_keys)
+            .into_iter()
+            .map(|raw_key| {
+                let raw_key = raw_key.context("failed reading storage key from RocksDB")?;
+                Ok(raw_key.map(|raw_key| H256::from_slice(&raw_key)))
+            })
+            .collect()
+    }
+
+    /// Returns keys in the specified range ordered by key, together with their enumeration indices
+    /// and L1 batches of initial writes.
+    fn entries_in_range(&self, range: &RangeInclusive<H256>) -> Vec<(H256, u64, L1BatchNumber)> {
+        self.db
+            .from_iterator_cf(KeyIndexColumnFamily::ByKey, range.start().as_bytes()..)
+            .map(|(raw_key, raw_value)| {
+                let key = H256::from_slice(&raw_key);
+                let (index, l1_batch_number) = raw_value.split_at(8);
+                let index = u64::from_be_bytes(index.try_into().unwrap());
+                let l1_batch_number = u32::from_be_bytes(l1_batch_number.try_into().unwrap());
+                (key, index, L1BatchNumber(l1_batch_number))
+            })
+            .take_while(|(key, ..)| key <= range.end())
+            .collect()
+    }
+}
+
+/// Node state at a certain L1 batch reconstructed by sequentially applying L1 batch pubdata on top of the genesis state.
+///
+/// Storage values are kept in the Merkle tree, and storage keys are kept in an on-disk [`KeyIndex`],
+/// so the memory usage doesn't grow with the state size (except for factory deps).
+#[derive(Debug)]
+pub(super) struct RecoveredState<DB = RocksDBWrapper> {
+    tree: MerkleTree<DB>,
+    keys: KeyIndex,
+    /// Number of storage keys written so far; equal to the greatest assigned enumeration index.
+    key_count: u64,
+    factory_deps: HashMap<H256, Vec<u8>>,
+    l1_batch_number: L1BatchNumber,
+}
+
+impl<DB: Database> RecoveredState<DB> {
+    /// Initializes the state from the genesis. The provided tree database and key index must be empty.
+    pub fn genesis(db: DB, keys: KeyIndex, genesis: GenesisStorageState) -> anyhow::Result<Self> {
+        let mut tree = MerkleTree::new(db)?;
+        anyhow::ensure!(
+            tree.latest_version().is_none(),
+            "Merkle tree used for recovery is not empty"
+        );
+        let entries = genesis
+            .storage_logs
+            .iter()
+            .zip(1..)
+            .map(|((key, value), leaf_index)| {
+                TreeEntry::new(key.hashed_key_u256(), leaf_index, *value)
+            })
+            .collect();
+        tree.extend(entries)?;
+
+        let genesis_keys: Vec<_> = genesis
+            .storage_logs
+            .iter()
+            .map(|(key, _)| key.hashed_key())
+            .collect();
+        keys.insert(1, &genesis_keys, L1BatchNumber(0))?;
+        Ok(Self {
+            tree,
+            keys,
+            key_count: genesis_keys.len() as u64,
+            factory_deps: genesis.factory_deps,
+            l1_batch_number: L1BatchNumber(0),
+        })
+    }
+
+    pub fn l1_batch_number(&self) -> L1BatchNumber {
+        self.l1_batch_number
+    }
+
+    pub fn root_hash(&self) -> H256 {
+        self.tree.latest_root_hash()
+    }
+
+    pub fn storage_log_count(&self) -> u64 {
+        self.key_count
+    }
+
+    /// Checks whether EVM emulation is enabled, i.e. whether allowed contract types in the `ContractDeployer` storage
+    /// are non-zero.
+    pub fn is_evm_emulation_enabled(&self) -> anyhow::Result<bool> {
+        let key =
+            get_deployer_key(H256::from_low_u64_be(ALLOWED_CONTRACT_TYPES_SLOT)).hashed_key_u256();
+        let version = self.tree.latest_version().context("Merkle tree is empty")?;
+        let [entry] = self.tree.entries(version, &[key])?.try_into().unwrap();
+        Ok(!entry.value.is_zero())
+    }
+
+    /// Applies pubdata of the next L1 batch to the state and checks the resulting state against the L1 commitment.
+    ///
+    /// Initial writes are assigned enumeration indices in the order they are listed in pubdata, which coincides
+    /// with the order used by the state keeper. If this assumption is broken, the root hash check will fail.
+    pub fn apply_batch(
+        &mut self,
+        batch: &CommittedBatch,
+        pubdata: ParsedPubdata,
+    ) -> anyhow::Result<()> {
+        let expected_number = self.l1_batch_number + 1;
+        anyhow::ensure!(
+            batch.number == expected_number,
+            "unexpected L1 batch to apply: expected #{expected_number}, got #{}",
+            batch.number
+        );
+
+        let repeated_indices: Vec<_> = pubdata
+            .state_diffs
+            .iter()
+            .filter_map(|diff| match diff.key {
+                StateDiffKey::Initial(_) => None,
+                StateDiffKey::Repeated(index) => Some(index),
+            })
+            .collect();
+        let mut repeated_keys = self.keys.keys_by_indices(&repeated_indices)?.into_iter();
+
+        let first_new_index = self.key_count + 1;
+        let mut next_index = first_new_index;
+        let mut new_keys = vec![];
+        let keys_with_indices = pubdata
+            .state_diffs
+            .iter()
+            .map(|diff| match diff.key {
+                StateDiffKey::Initial(key) => {
+                    new_keys.push(key);
+                    next_index += 1;
+                    Ok((key, next_index - 1))
+                }
+                StateDiffKey::Repeated(index) => {
+                    let key = repeated_keys.next().flatten().with_context(|| {
+                        format!("repeated write refers to unknown enumeration index {index}")
+                    })?;
+                    Ok((key, index))
+                }
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let tree_keys: Vec<_> = keys_with_indices
+            .iter()
+            .map(|(key, _)| h256_to_u256(*key))
+            .collect();
+        let version = self.tree.latest_version().context("Merkle tree is empty")?;
+        let prev_entries = self.tree.entries(version, &tree_keys)?;
+
+        let entries = pubdata
+            .state_diffs
+            .iter()
+            .zip(keys_with_indices.into_iter().zip(prev_entries))
+            .map(|(diff, ((key, leaf_index), prev_entry))| {
+                let is_initial = matches!(diff.key, StateDiffKey::Initial(_));
+                anyhow::ensure!(
+                    prev_entry.is_empty() == is_initial,
+                    "inconsistent {diff_kind} write for key {key:?}",
+                    diff_kind = if is_initial { "initial" } else { "repeated" }
+                );
+                let value = diff.decompress(h256_to_u256(prev_entry.value))?;
+                Ok(TreeEntry::new(
+                    h256_to_u256(key),
+                    leaf_index,
+                    u256_to_h256(value),
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .with_context(|| {
+                format!("failed applying state diffs for L1 batch #{}", batch.number)
+            })?;
+        let output = self.tree.extend(entries)?;
+
+        anyhow::ensure!(
+            output.root_hash == batch.state_root,
+            "state root hash mismatch for L1 batch #{}: computed {:?}, committed on L1 {:?}",
+            batch.number,
+            output.root_hash,
+            batch.state_root
+        );
+        anyhow::ensure!(
+            output.leaf_count + 1 == batch.index_repeated_storage_changes,
+            "leaf count mismatch for L1 batch #{}: computed {}, committed on L1 {}",
+            batch.number,
+            output.leaf_count + 1,
+            batch.index_repeated_storage_changes
+        );
+
+        self.keys.insert(first_new_index, &new_keys, batch.number)?;
+        self.key_count += new_keys.len() as u64;
+        for bytecode in pubdata.published_bytecodes {
+            if let Err(err) = validate_bytecode(&bytecode) {
+                // Such bytecodes cannot be deployed, so they are not required to sync the node.
+                tracing::warn!(
+                    "Skipping bytecode published in L1 batch #{} that is not a valid bytecode: {err}",
+                    batch.number
+                );
+                continue;
+            }
+            let hash = BytecodeHash::for_bytecode(&bytecode).value();
+            self.factory_deps.insert(hash, bytecode);
+        }
+        self.l1_batch_number = batch.number;
+        Ok(())
+    }
+
+    /// Writes the state as a snapshot (storage log chunks and factory deps) to the object store.
+    /// Returns the path to the factory deps file and metadata for storage log chunks.
+    pub async fn write_snapshot(
+        &self,
+        object_store: &dyn ObjectStore,
+        chunk_size: u64,
+    ) -> anyhow::Result<(String, Vec<SnapshotStorageLogsChunkMetadata>)> {
+        let l1_batch_number = self.l1_batch_number;
+        let factory_deps = self
+            .factory_deps
+            .iter()
+            .map(|(hash, bytecode)| SnapshotFactoryDependency {
+                bytecode: Bytes(bytecode.clone()),
+                hash: Some(*hash),
+            })
+            .collect();
+        let factory_deps = SnapshotFactoryDependencies { factory_deps };
+        let filename = object_store
+            .put(l1_batch_number, &factory_deps)
+            .await
+            .context("failed persisting factory deps")?;
+        let prefix = object_store.get_storage_prefix::<SnapshotFactoryDependencies>();
+        let factory_deps_filepath = format!("{prefix}/{filename}");
+
+        let version = self.tree.latest_version().context("Merkle tree is empty")?;
+        let chunk_count = self.key_count.div_ceil(chunk_size).max(1);
+        let prefix = object_store.get_storage_prefix::<SnapshotStorageLogsChunk>();
+        let mut chunks = Vec::with_capacity(chunk_count as usize);
+        // Keys are read from the index chunk by chunk, so that only a single chunk is held in memory at a time.
+        for chunk_id in 0..chunk_count {
+            let key_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
+            let chunk_keys = self.keys.entries_in_range(&key_range);
+            let tree_keys: Vec<_> = chunk_keys
+                .iter()
+                .map(|(key, ..)| h256_to_u256(*key))
+                .collect();
+            let entries = self.tree.entries(version, &tree_keys)?;
+            let storage_logs = chunk_keys
+                .into_iter()
+                .zip(entries)
+                .map(
+                    |((key, enumeration_index, l1_batch_number), entry)| SnapshotStorageLog {
+                        key,
+                        value: entry.value,
+                        l1_batch_number_of_initial_write: l1_batch_number,
+                        enumeration_index,
+                    },
+                )
+                .collect();
+            let key = SnapshotStorageLogsStorageKey {
+                l1_batch_number,
+                chunk_id,
+            };
+            let filename = object_store
+                .put(key, &SnapshotStorageLogsChunk { storage_logs })
+                .await
+                .with_context(|| format!("failed persisting storage logs chunk #{chunk_id}"))?;
+            tracing::info!("Persisted storage logs chunk {chunk_id}/{chunk_count}");
+            chunks.push(SnapshotStorageLogsChunkMetadata {
+                chunk_id,
+                filepath: format!("{prefix}/{filename}"),
+            });
+        }
+        Ok((factory_deps_filepath, chunks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+    use zksync_merkle_tree::PatchSet;
+    use zksync_object_store::MockObjectStore;
+    use zksync_types::{writes::StateDiffRecord, AccountTreeId, Address, StorageKey};
+
+    use super::*;
+    use crate::external_node::l1_recovery::pubdata::tests::build_pubdata;
+
+    fn storage_key(key: u64) -> StorageKey {
+        StorageKey::new(
+            AccountTreeId::new(Address::repeat_byte(1)),
+            H256::from_low_u64_be(key),
+        )
+    }
+
+    fn genesis(temp_dir: &TempDir) -> RecoveredState<PatchSet> {
+        let keys = KeyIndex::new(temp_dir.path()).unwrap();
+        RecoveredState::genesis(PatchSet::default(), keys, genesis_state()).unwrap()
+    }
+
+    fn genesis_state() -> GenesisStorageState {
+        GenesisStorageState {
+            storage_logs: vec![
+                (storage_key(1), H256::from_low_u64_be(10)),
+                (storage_key(2), H256::from_low_u64_be(20)),
+            ],
+            factory_deps: HashMap::from([(H256::repeat_byte(0xfe), vec![0; 32])]),
+        }
+    }
+
+    fn state_diff(key: u64, enumeration_index: u64, initial: u64, r#final: u64) -> StateDiffRecord {
+        let key = storage_key(key);
+        StateDiffRecord {
+            address: *key.address(),
+            key: h256_to_u256(*key.key()),
+            derived_key: key.hashed_key().0,
+            enumeration_index,
+            initial_value: initial.into(),
+            final_value: r#final.into(),
+        }
+    }
+
+    /// Returns the batch together with the expected hashed keys in the order of enumeration indices.
+    fn first_batch() -> (CommittedBatch, ParsedPubdata, Vec<H256>) {
+        let expected_entries = [
+            (storage_key(1), 1, 11),
+            (storage_key(2), 2, 20),
+            (storage_key(3), 3, 30),
+            (storage_key(4), 4, 40),
+        ];
+        let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+        let entries = expected_entries
+            .iter()
+            .map(|(key, leaf_index, value)| {
+                TreeEntry::new(
+                    key.hashed_key_u256(),
+                    *leaf_index,
+                    H256::from_low_u64_be(*value),
+                )
+            })
+            .collect();
+        let output = tree.extend(entries).unwrap();
+
+        let state_diffs = vec![
+            state_diff(1, 1, 10, 11),
+            state_diff(3, 0, 0, 30),
+            state_diff(4, 0, 0, 40),
+        ];
+        let bytecode = vec![1; 96];
+        let pubdata = build_pubdata(&[bytecode], &[], state_diffs);
+        let pubdata = ParsedPubdata::parse(&pubdata).unwrap();
+        let batch = CommittedBatch {
+            number: L1BatchNumber(1),
+            index_repeated_storage_changes: 5,
+            state_root: output.root_hash,
+            operator_da_input: vec![],
+        };
+        let keys = expected_entries
+            .iter()
+            .map(|(key, ..)| key.hashed_key())
+            .collect();
+        (batch, pubdata, keys)
+    }
+
+    #[test]
+    fn applying_batch() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut state = genesis(&temp_dir);
+        assert_eq!(state.l1_batch_number(), L1BatchNumber(0));
+        assert_eq!(state.storage_log_count(), 2);
+
+        let (batch, pubdata, expected_keys) = first_batch();
+        state.apply_batch(&batch, pubdata).unwrap();
+
+        assert_eq!(state.l1_batch_number(), L1BatchNumber(1));
+        assert_eq!(state.root_hash(), batch.state_root);
+        assert_eq!(state.storage_log_count(), 4);
+        let keys = state.keys.keys_by_indices(&[1, 2, 3, 4, 5]).unwrap();
+        let expected_keys: Vec<_> = expected_keys.into_iter().map(Some).chain([None]).collect();
+        assert_eq!(keys, expected_keys);
+
+        let mut entries = state
+            .keys
+            .entries_in_range(&(H256::zero()..=H256::repeat_byte(0xff)));
+        assert!(entries.is_sorted_by_key(|(key, ..)| *key));
+        entries.sort_unstable_by_key(|(_, index, _)| *index);
+        let initial_write_batches: Vec<_> = entries
+            .iter()
+            .map(|(_, _, l1_batch_number)| *l1_batch_number)
+            .collect();
+        assert_eq!(initial_write_batches, [0, 0, 1, 1].map(L1BatchNumber));
+        let bytecode_hash = BytecodeHash::for_bytecode(&[1; 96]).value();
+        assert_eq!(state.factory_deps[&bytecode_hash], [1; 96]);
+        assert_eq!(state.factory_deps.len(), 2);
+    }
+
+    #[test]
+    fn applying_batch_with_mismatched_root_hash() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut state = genesis(&temp_dir);
+        let (mut batch, pubdata, _) = first_batch();
+        batch.state_root = H256::repeat_byte(0xff);
+        let err = state.apply_batch(&batch, pubdata).unwrap_err();
+        assert!(
+            err.to_string().contains("state root hash mismatch"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn applying_batch_with_unknown_enumeration_index() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut state = genesis(&temp_dir);
+        let (batch, _, _) = first_batch();
+        let pubdata = build_pubdata(&[], &[], vec![state_diff(5, 5, 0, 1)]);
+        let pubdata = ParsedPubdata::parse(&pubdata).unwrap();
+        let err = state.apply_batch(&batch, pubdata).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown enumeration index 5"),
+            "{err:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn writing_snapshot() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut state = genesis(&temp_dir);
+        let (batch, pubdata, expected_keys) = first_batch();
+        state.apply_batch(&batch, pubdata).unwrap();
+
+        let object_store = MockObjectStore::arc();
+        let (factory_deps_filepath, chunks) =
+            state.write_snapshot(&*object_store, 3).await.unwrap();
+        assert!(factory_deps_filepath.ends_with("snapshot_l1_batch_1_factory_deps.proto.gzip"));
+        assert_eq!(chunks.len(), 2);
+
+        let factory_deps: SnapshotFactoryDependencies =
+            object_store.get(L1BatchNumber(1)).await.unwrap();
+        assert_eq!(factory_deps.factory_deps.len(), 2);
+
+        let mut all_logs = vec![];
+        for chunk in &chunks {
+            let key = SnapshotStorageLogsStorageKey {
+                l1_batch_number: L1BatchNumber(1),
+                chunk_id: chunk.chunk_id,
+            };
+            let logs: SnapshotStorageLogsChunk = object_store.get(key).await.unwrap();
+            let key_range = uniform_hashed_keys_chunk(chunk.chunk_id, 2);
+            for log in &logs.storage_logs {
+                assert!(key_range.contains(&log.key), "{log:?}");
+            }
+            all_logs.extend(logs.storage_logs);
+        }
+
+        all_logs.sort_unstable_by_key(|log| log.enumeration_index);
+        let keys: Vec<_> = all_logs.iter().map(|log| log.key).collect();
+        assert_eq!(keys, expected_keys);
+        let values: Vec<_> = all_logs.iter().map(|log| log.value).collect();
+        assert_eq!(values, [11, 20, 30, 40].map(H256::from_low_u64_be));
+        let initial_write_batches: Vec<_> = all_logs
+            .iter()
+            .map(|log| log.l1_batch_number_of_initial_write)
+            .collect();
+        assert_eq!(initial_write_batches, [0, 0, 1, 1].map(L1BatchNumber));
+    }
+}
@@ -7,11 +7,13 @@ use zksync_health_check::AppHealthCheck;
 use zksync_object_store::ObjectStoreFactory;
 use zksync_shared_metrics::{SnapshotRecoveryStage, APP_METRICS};
 use zksync_snapshots_applier::{
-    RecoveryCompletionStatus, SnapshotsApplierConfig, SnapshotsApplierTask,
+    RecoveryCompletionStatus, SnapshotsApplierConfig, SnapshotsApplierMainNodeClient,
+    SnapshotsApplierTask,
 };
 use zksync_types::OrStopped;
 use zksync_web3_decl::client::{DynClient, L2};
 
+use super::ExternalNodeL1Recovery;
 use crate::{InitializeStorage, SnapshotRecoveryConfig};
 
 #[derive(Debug)]
@@ -20,6 +22,8 @@ pub struct ExternalNodeSnapshotRecovery {
     pub pool: ConnectionPool<Core>,
     pub max_concurrency: NonZeroUsize,
     pub recovery_config: SnapshotRecoveryConfig,
+    /// Set if the snapshot should be reconstructed from L1 data.
+    pub l1_recovery: Option<ExternalNodeL1Recovery>,
     pub app_health: Arc<AppHealthCheck>,
 }
 
@@ -48,16 +52,29 @@ impl InitializeStorage for ExternalNodeSnapshotRecovery {
             .create_store()
             .await?;
 
+        let client: Box<dyn SnapshotsApplierMainNodeClient> = match &self.l1_recovery {
+            Some(l1_recovery) => {
+                tracing::warn!(
+                    "Reconstructing snapshot from L1 data. This is an experimental feature; use at your own risk"
+                );
+                l1_recovery
+                    .prepare(
+                        &self.pool,
+                        object_store.as_ref(),
+                        self.recovery_config.snapshot_l1_batch_override,
+                        &stop_receiver,
+                    )
+                    .await?
+            }
+            None => Box::new(self.client.clone().for_component("snapshot_recovery")),
+        };
+
         let config = SnapshotsApplierConfig {
             max_concurrency: self.max_concurrency,
             ..SnapshotsApplierConfig::default()
         };
-        let mut snapshots_applier_task = SnapshotsApplierTask::new(
-            config,
-            self.pool.clone(),
-            Box::new(self.client.clone().for_component("snapshot_recovery")),
-            object_store,
-        );
+        let mut snapshots_applier_task =
+            SnapshotsApplierTask::new(config, self.pool.clone(), client, object_store);
         if let Some(snapshot_l1_batch) = self.recovery_config.snapshot_l1_batch_override {
             tracing::info!(
                 "Using a specific snapshot with L1 batch #{snapshot_l1_batch}; this may not work \
@@ -69,6 +86,10 @@ impl InitializeStorage for ExternalNodeSnapshotRecovery {
             tracing::info!("Dropping storage key preimages for snapshot storage logs");
             snapshots_applier_task.drop_storage_key_preimages();
         }
+        if self.recovery_config.apply_delta_snapshots {
+            tracing::info!("Applying delta snapshots on top of the recovered snapshot");
+            snapshots_applier_task.apply_delta_snapshots();
+        }
         self.app_health
             .insert_component(snapshots_applier_task.health_check())
             .map_err(OrStopped::internal)?;
@@ -132,8 +153,11 @@ mod tests {
             recovery_config: SnapshotRecoveryConfig {
                 snapshot_l1_batch_override: None,
                 drop_storage_key_preimages: false,
+                apply_delta_snapshots: false,
                 object_store_config: None,
+                l1_recovery: None,
             },
+            l1_recovery: None,
             app_health,
         };
 
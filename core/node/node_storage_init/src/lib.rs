@@ -1,9 +1,11 @@
-use std::{future::Future, sync::Arc, time::Duration};
+use std::{future::Future, num::NonZeroU64, sync::Arc, time::Duration};
 
 use tokio::sync::watch;
 use zksync_config::ObjectStoreConfig;
 use zksync_dal::{ConnectionPool, Core, CoreDal as _};
-use zksync_types::{try_stoppable, L1BatchNumber, OrStopped, StopContext};
+use zksync_types::{
+    commitment::L1BatchCommitmentMode, try_stoppable, L1BatchNumber, OrStopped, StopContext,
+};
 
 pub use crate::traits::{InitializeStorage, RevertStorage};
 
@@ -17,7 +19,22 @@ pub struct SnapshotRecoveryConfig {
     /// If not specified, the latest snapshot will be used.
     pub snapshot_l1_batch_override: Option<L1BatchNumber>,
     pub drop_storage_key_preimages: bool,
+    /// Whether to apply delta snapshots on top of the recovered snapshot.
+    pub apply_delta_snapshots: bool,
     pub object_store_config: Option<ObjectStoreConfig>,
+    /// If set, the snapshot will be reconstructed from L1 data and written to the object store
+    /// instead of being fetched from the main node.
+    pub l1_recovery: Option<L1RecoveryConfig>,
+}
+
+/// Configuration for reconstructing a snapshot from L1 data.
+#[derive(Debug, Clone)]
+pub struct L1RecoveryConfig {
+    /// L1 block to start looking for commit transactions from. Should precede the commitment of the first L1 batch.
+    pub l1_from_block: u64,
+    /// Maximum number of L1 blocks queried for commit events in a single request.
+    pub l1_block_range: NonZeroU64,
+    pub commitment_mode: L1BatchCommitmentMode,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1,18 +1,23 @@
 use std::{num::NonZeroUsize, sync::Arc};
 
 use zksync_block_reverter::node::BlockReverterResource;
+use zksync_da_client::node::DAClientResource;
 use zksync_dal::node::{MasterPool, PoolResource};
 use zksync_health_check::node::AppHealthCheckResource;
 use zksync_node_framework::{
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
-use zksync_types::L2ChainId;
-use zksync_web3_decl::node::MainNodeClientResource;
+use zksync_shared_resources::contracts::L1ChainContractsResource;
+use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
+use zksync_web3_decl::node::{EthInterfaceResource, MainNodeClientResource};
 
 use super::NodeInitializationStrategyResource;
 use crate::{
-    external_node::{ExternalNodeGenesis, ExternalNodeReverter, ExternalNodeSnapshotRecovery},
+    external_node::{
+        ExternalNodeGenesis, ExternalNodeL1Recovery, ExternalNodeReverter,
+        ExternalNodeSnapshotRecovery,
+    },
     InitializeStorage, NodeInitializationStrategy, RevertStorage, SnapshotRecoveryConfig,
 };
 
@@ -29,6 +34,12 @@ pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub main_node_client: MainNodeClientResource,
     pub block_reverter: Option<BlockReverterResource>,
+    /// Required for snapshot recovery from L1.
+    pub eth_client: Option<EthInterfaceResource>,
+    /// Required for snapshot recovery from L1.
+    pub l1_contracts: Option<L1ChainContractsResource>,
+    /// Required for snapshot recovery from L1 for validium chains.
+    pub da_client: Option<DAClientResource>,
     #[context(default)]
     pub app_health: AppHealthCheckResource,
 }
@@ -70,6 +81,42 @@ impl WiringLayer for ExternalNodeInitStrategyLayer {
         });
         let snapshot_recovery = match self.snapshot_recovery_config {
             Some(recovery_config) => {
+                let l1_recovery = match &recovery_config.l1_recovery {
+                    Some(l1_config) => {
+                        let EthInterfaceResource(eth_client) =
+                            input.eth_client.ok_or_else(|| {
+                                WiringError::Configuration(
+                                    "L1 client is required for snapshot recovery from L1".into(),
+                                )
+                            })?;
+                        let L1ChainContractsResource(contracts) =
+                            input.l1_contracts.ok_or_else(|| {
+                                WiringError::Configuration(
+                                    "L1 contracts are required for snapshot recovery from L1"
+                                        .into(),
+                                )
+                            })?;
+                        let da_client = input.da_client.map(|DAClientResource(client)| client);
+                        if l1_config.commitment_mode == L1BatchCommitmentMode::Validium
+                            && da_client.is_none()
+                        {
+                            return Err(WiringError::Configuration(
+                                "DA client is required for snapshot recovery from L1 for validium chains"
+                                    .into(),
+                            ));
+                        }
+                        Some(ExternalNodeL1Recovery::new(
+                            self.l2_chain_id,
+                            client.clone(),
+                            eth_client,
+                            contracts.chain_contracts_config.diamond_proxy_addr,
+                            da_client,
+                            l1_config.clone(),
+                        ))
+                    }
+                    None => None,
+                };
+
                 // Add a connection for checking whether the storage is initialized.
                 let recovery_pool = input
                     .master_pool
@@ -80,6 +127,7 @@ impl WiringLayer for ExternalNodeInitStrategyLayer {
                     pool: recovery_pool,
                     max_concurrency: self.max_postgres_concurrency,
                     recovery_config,
+                    l1_recovery,
                     app_health,
                 });
                 Some(recovery)
@@ -175,6 +175,7 @@ fn mock_batch_details(number: u32, stage: L1BatchStage) -> api::L1BatchDetails {
             fair_pubdata_price: None,
             base_system_contracts_hashes: BaseSystemContractsHashes::default(),
         },
+        verified_stage: None,
     }
 }
 
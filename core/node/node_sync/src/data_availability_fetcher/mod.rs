@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use serde::Serialize;
 use tokio::sync::watch;
-use zksync_da_client::{types::InclusionData, DataAvailabilityClient};
+use zksync_da_client::{fetch_chunks_inclusion_data, types::InclusionData, DataAvailabilityClient};
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_types::{commitment::PubdataType, L1BatchNumber};
@@ -180,13 +180,19 @@ impl DataAvailabilityFetcher {
         let inclusion_data = if expected_inclusion_data.is_empty() {
             InclusionData::default()
         } else {
-            let inclusion_data_from_rpc = self
-                .da_client
-                .get_inclusion_data(da_details.blob_id.as_str())
-                .await
-                .map_err(|err| {
-                    to_retriable_error(anyhow::anyhow!("Error fetching inclusion data: {err}"))
-                })?;
+            let inclusion_data_from_rpc = match &da_details.chunk_blob_ids {
+                Some(chunk_blob_ids) => {
+                    fetch_chunks_inclusion_data(self.da_client.as_ref(), chunk_blob_ids).await
+                }
+                None => {
+                    self.da_client
+                        .get_inclusion_data(da_details.blob_id.as_str())
+                        .await
+                }
+            }
+            .map_err(|err| {
+                to_retriable_error(anyhow::anyhow!("Error fetching inclusion data: {err}"))
+            })?;
 
             match inclusion_data_from_rpc {
                 Some(data) => data,
@@ -201,8 +207,9 @@ impl DataAvailabilityFetcher {
         // - if inclusion data is `Some` and not empty - it has to match the one retrieved from the DA layer
         if !expected_inclusion_data.is_empty() && expected_inclusion_data != inclusion_data.data {
             return Err(to_fatal_error(anyhow::anyhow!(
-                "Inclusion data mismatch for DA blob id: {}; expected: {:?}, got: {:?}",
+                "Inclusion data mismatch for DA blob id: {}, chunk blob ids: {:?}; expected: {:?}, got: {:?}",
                 da_details.blob_id,
+                da_details.chunk_blob_ids,
                 expected_inclusion_data,
                 inclusion_data.data
             )));
@@ -213,24 +220,53 @@ impl DataAvailabilityFetcher {
             .connection_tagged("data_availability_fetcher")
             .await
             .map_err(|err| to_fatal_error(err.generalize()))?;
-        connection
-            .data_availability_dal()
-            .insert_l1_batch_da(
+        if let Some(chunk_blob_ids) = &da_details.chunk_blob_ids {
+            let mut transaction = connection
+                .start_transaction()
+                .await
+                .map_err(|err| to_retriable_error(err.generalize()))?;
+            transaction
+                .data_availability_dal()
+                .insert_l1_batch_da_with_chunks(
+                    l1_batch_to_fetch,
+                    chunk_blob_ids,
+                    da_details.sent_at.naive_utc(),
+                    pubdata_type,
+                    Some(expected_inclusion_data.as_slice()),
+                    da_details.l2_da_validator,
+                )
+                .await
+                .map_err(|err| to_retriable_error(err.generalize()))?;
+            transaction
+                .commit()
+                .await
+                .map_err(|err| to_retriable_error(err.generalize()))?;
+
+            tracing::debug!(
+                "Updated L1 batch #{} with DA chunk blob ids: {:?}",
                 l1_batch_to_fetch,
-                da_details.blob_id.as_str(),
-                da_details.sent_at.naive_utc(),
-                pubdata_type,
-                Some(expected_inclusion_data.as_slice()),
-                da_details.l2_da_validator,
-            )
-            .await
-            .map_err(|err| to_retriable_error(err.generalize()))?;
+                chunk_blob_ids
+            );
+        } else {
+            connection
+                .data_availability_dal()
+                .insert_l1_batch_da(
+                    l1_batch_to_fetch,
+                    da_details.blob_id.as_str(),
+                    da_details.sent_at.naive_utc(),
+                    pubdata_type,
+                    Some(expected_inclusion_data.as_slice()),
+                    da_details.l2_da_validator,
+                )
+                .await
+                .map_err(|err| to_retriable_error(err.generalize()))?;
 
-        tracing::debug!(
-            "Updated L1 batch #{} with DA blob id: {}",
-            l1_batch_to_fetch,
-            da_details.blob_id
-        );
+            tracing::debug!(
+                "Updated L1 batch #{} with DA blob id: {}",
+                l1_batch_to_fetch,
+                da_details.blob_id
+            );
+        }
         self.last_scanned_batch = l1_batch_to_fetch;
 
         Ok(StepOutcome::UpdatedBatch(l1_batch_to_fetch))
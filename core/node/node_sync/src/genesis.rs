@@ -31,7 +31,7 @@ pub async fn perform_genesis_if_needed(
     Ok(())
 }
 
-async fn create_genesis_params(
+pub async fn create_genesis_params(
     client: &dyn MainNodeClient,
     zksync_chain_id: L2ChainId,
 ) -> anyhow::Result<GenesisParams> {
@@ -2,7 +2,8 @@ pub use self::{
     batch_status_updater::BatchStatusUpdaterLayer,
     data_availability_fetcher::DataAvailabilityFetcherLayer, external_io::ExternalIOLayer,
     resources::ActionQueueSenderResource, sync_state_updater::SyncStateUpdaterLayer,
-    tree_data_fetcher::TreeDataFetcherLayer, validate_chain_ids::ValidateChainIdsLayer,
+    tree_data_fetcher::TreeDataFetcherLayer, upstream_health::UpstreamHealthLayer,
+    validate_chain_ids::ValidateChainIdsLayer,
 };
 
 mod batch_status_updater;
@@ -11,4 +12,5 @@ mod external_io;
 mod resources;
 mod sync_state_updater;
 mod tree_data_fetcher;
+mod upstream_health;
 mod validate_chain_ids;
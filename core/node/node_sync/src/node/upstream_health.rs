@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use anyhow::Context as _;
+use zksync_dal::{
+    node::{MasterPool, PoolResource},
+    ConnectionPool, Core, CoreDal,
+};
+use zksync_node_framework::{
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+use zksync_types::{L1BatchNumber, H256};
+use zksync_web3_decl::{client::FailoverClient, node::FailoverClientResource};
+
+/// Wiring layer for health checks of main node client upstreams. Upstreams are checked against the genesis
+/// of the local node.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `FailoverClientResource` (the layer does nothing if it's not provided)
+///
+/// ## Adds tasks
+///
+/// - `UpstreamHealthTask` (if `FailoverClientResource` is provided)
+#[derive(Debug)]
+pub struct UpstreamHealthLayer;
+
+#[derive(Debug, FromContext)]
+pub struct Input {
+    pub master_pool: PoolResource<MasterPool>,
+    pub failover_client: Option<FailoverClientResource>,
+}
+
+#[derive(Debug, IntoContext)]
+pub struct Output {
+    #[context(task)]
+    pub task: Option<UpstreamHealthTask>,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for UpstreamHealthLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "upstream_health_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let Some(FailoverClientResource(client)) = input.failover_client else {
+            return Ok(Output { task: None });
+        };
+        let pool = input.master_pool.get_singleton().await?;
+        Ok(Output {
+            task: Some(UpstreamHealthTask { client, pool }),
+        })
+    }
+}
+
+/// Task periodically checking health, lag and consistency of upstreams used by the [`FailoverClient`].
+///
+/// Checks start once the genesis L1 batch is present in the local storage. If the node was recovered
+/// from a snapshot, its genesis is unknown, so upstreams other than the main node are never used.
+#[derive(Debug)]
+pub struct UpstreamHealthTask {
+    client: FailoverClient,
+    pool: ConnectionPool<Core>,
+}
+
+impl UpstreamHealthTask {
+    const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// Waits until the genesis root hash is available in the local storage. Returns `None` if the node
+    /// was recovered from a snapshot, or if a stop request was received.
+    async fn wait_for_genesis_root_hash(
+        &self,
+        stop_receiver: &mut StopReceiver,
+    ) -> anyhow::Result<Option<H256>> {
+        while !*stop_receiver.0.borrow_and_update() {
+            let mut storage = self.pool.connection_tagged("upstream_health").await?;
+            let genesis_root_hash = storage
+                .blocks_dal()
+                .get_l1_batch_state_root(L1BatchNumber(0))
+                .await?;
+            if genesis_root_hash.is_some() {
+                return Ok(genesis_root_hash);
+            }
+            let snapshot_recovery = storage
+                .snapshot_recovery_dal()
+                .get_applied_snapshot_status()
+                .await?;
+            drop(storage);
+            if snapshot_recovery.is_some() {
+                tracing::warn!(
+                    "Node was recovered from a snapshot, so its genesis is unknown; fallback upstreams \
+                     for the main node client will not be used"
+                );
+                return Ok(None);
+            }
+
+            tokio::time::timeout(Self::CHECK_INTERVAL, stop_receiver.0.changed())
+                .await
+                .ok();
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for UpstreamHealthTask {
+    fn id(&self) -> TaskId {
+        "upstream_health_checks".into()
+    }
+
+    async fn run(self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        let genesis_root_hash = self
+            .wait_for_genesis_root_hash(&mut stop_receiver)
+            .await
+            .context("failed loading genesis root hash")?;
+        let Some(genesis_root_hash) = genesis_root_hash else {
+            // Don't stop the node; the main node client continues using the main node only.
+            stop_receiver.0.changed().await.ok();
+            return Ok(());
+        };
+
+        tracing::info!("Checking upstreams against genesis root hash {genesis_root_hash:?}");
+        self.client
+            .run_health_checks(Self::CHECK_INTERVAL, genesis_root_hash, stop_receiver.0)
+            .await
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{de::DeserializeOwned, Serialize};
 use zksync_prover_interface::api::{
     PollGeneratedProofsRequest, PollGeneratedProofsResponse, ProofGenerationData,
-    SubmitProofGenerationDataResponse,
+    RejectProofRequest, RejectProofResponse, SubmitProofGenerationDataResponse,
 };
 use zksync_types::L1BatchId;
 
@@ -12,6 +12,7 @@ pub(crate) struct HttpClient {
 
 const SUBMIT_REQUEST_FOR_PROOFS_ENDPOINT: &str = "/submit_request_for_proofs";
 const POLL_GENERATED_PROOFS_ENDPOINT: &str = "/poll_generated_proofs";
+const REJECT_PROOF_ENDPOINT: &str = "/reject_proof";
 
 impl HttpClient {
     pub(crate) fn new(api_url: String) -> Self {
@@ -45,6 +46,19 @@ impl HttpClient {
         self.send_http_request(request, &endpoint).await
     }
 
+    pub(crate) async fn reject_proof(
+        &self,
+        l1_batch_id: L1BatchId,
+    ) -> Result<RejectProofResponse, reqwest::Error> {
+        tracing::info!("Sending request to {}", REJECT_PROOF_ENDPOINT);
+
+        let endpoint = self.api_url.clone() + REJECT_PROOF_ENDPOINT;
+
+        let request = RejectProofRequest { l1_batch_id };
+
+        self.send_http_request(request, &endpoint).await
+    }
+
     async fn send_http_request<Req, Resp>(
         &self,
         request: Req,
@@ -8,6 +8,8 @@ use zksync_dal::{ConnectionPool, Core};
 use zksync_object_store::ObjectStore;
 use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 
+use crate::verifier::ProofVerifier;
+
 mod http_client;
 mod proof_fetcher;
 mod proof_gen_data_submitter;
@@ -18,6 +20,7 @@ pub struct ProofDataHandlerClient {
     pub config: ProofDataHandlerConfig,
     pub batch_commitment_mode: L1BatchCommitmentMode,
     pub l2_chain_id: L2ChainId,
+    pub proof_verifier: Option<Arc<ProofVerifier>>,
 }
 
 impl ProofDataHandlerClient {
@@ -27,6 +30,7 @@ impl ProofDataHandlerClient {
         config: ProofDataHandlerConfig,
         batch_commitment_mode: L1BatchCommitmentMode,
         l2_chain_id: L2ChainId,
+        proof_verifier: Option<Arc<ProofVerifier>>,
     ) -> Self {
         Self {
             blob_store,
@@ -34,6 +38,7 @@ impl ProofDataHandlerClient {
             config,
             batch_commitment_mode,
             l2_chain_id,
+            proof_verifier,
         }
     }
 
@@ -54,6 +59,7 @@ impl ProofDataHandlerClient {
             self.config,
             self.batch_commitment_mode,
             self.l2_chain_id,
+            self.proof_verifier,
         )
         .run(stop_receiver);
 
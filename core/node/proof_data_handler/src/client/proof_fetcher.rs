@@ -1,18 +1,27 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use tokio::sync::watch;
 use zksync_config::configs::ProofDataHandlerConfig;
 use zksync_dal::{ConnectionPool, Core};
 use zksync_object_store::ObjectStore;
-use zksync_types::{commitment::L1BatchCommitmentMode, L1BatchId, L2ChainId};
+use zksync_types::{
+    commitment::L1BatchCommitmentMode, web3::keccak256, L1BatchId, L1BatchNumber, L2ChainId, H256,
+};
 
 use super::http_client::HttpClient;
-use crate::processor::{Locking, Processor};
+use crate::{
+    errors::ProcessorError,
+    processor::{Locking, Processor},
+    verifier::ProofVerifier,
+};
 
 pub(crate) struct ProofFetcher {
     processor: Processor<Locking>,
     config: ProofDataHandlerConfig,
     client: HttpClient,
+    /// Hashes of proofs rejected by the proof verifier. The gateway keeps returning a rejected proof
+    /// until it's notified about the rejection, so these proofs are skipped instead of being re-verified.
+    rejected_proofs: HashMap<(L2ChainId, L1BatchNumber), H256>,
 }
 
 impl ProofFetcher {
@@ -22,6 +31,7 @@ impl ProofFetcher {
         config: ProofDataHandlerConfig,
         commitment_mode: L1BatchCommitmentMode,
         l2_chain_id: L2ChainId,
+        proof_verifier: Option<Arc<ProofVerifier>>,
     ) -> Self {
         let processor = Processor::new(
             blob_store.clone(),
@@ -29,7 +39,8 @@ impl ProofFetcher {
             config.clone(),
             commitment_mode,
             l2_chain_id,
-        );
+        )
+        .with_proof_verifier(proof_verifier);
 
         let Some(api_url) = config.gateway_api_url.clone() else {
             panic!("Gateway API URL should be set if running in prover cluster mode");
@@ -40,10 +51,11 @@ impl ProofFetcher {
             processor,
             config,
             client,
+            rejected_proofs: HashMap::new(),
         }
     }
 
-    pub async fn run(self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+    pub async fn run(mut self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         tracing::info!("Starting proof fetcher");
 
         loop {
@@ -86,7 +98,7 @@ impl ProofFetcher {
         Ok(())
     }
 
-    async fn fetch_proof(&self, l1_batch_id: L1BatchId) -> anyhow::Result<()> {
+    async fn fetch_proof(&mut self, l1_batch_id: L1BatchId) -> anyhow::Result<()> {
         match self.client.fetch_proof(l1_batch_id).await {
             Ok(Some(response)) => {
                 if l1_batch_id.chain_id() != L2ChainId::zero()
@@ -98,13 +110,36 @@ impl ProofFetcher {
                     ));
                 }
 
+                let batch_key = (l1_batch_id.chain_id(), l1_batch_id.batch_number());
+                let proof_hash = H256(keccak256(&serde_json::to_vec(&response.proof)?));
+                if self.rejected_proofs.get(&batch_key) == Some(&proof_hash) {
+                    tracing::info!(
+                        "Proof for batch {l1_batch_id} was already rejected, waiting for a new one"
+                    );
+                    // The gateway stops serving the proof once it has requeued the batch, so getting the same proof
+                    // means that the previous notification didn't go through.
+                    self.notify_proof_rejected(l1_batch_id).await;
+                    return Ok(());
+                }
+
                 tracing::info!("Received proof for batch {l1_batch_id}");
 
-                self.processor
+                match self
+                    .processor
                     .save_proof(l1_batch_id, response.proof.into())
                     .await
-                    .map_err(|e| anyhow::anyhow!(e))?;
-                Ok(())
+                {
+                    Ok(()) => {
+                        self.rejected_proofs.remove(&batch_key);
+                        Ok(())
+                    }
+                    Err(err @ ProcessorError::ProofRejected(..)) => {
+                        self.rejected_proofs.insert(batch_key, proof_hash);
+                        self.notify_proof_rejected(l1_batch_id).await;
+                        Err(anyhow::anyhow!(err))
+                    }
+                    Err(err) => Err(anyhow::anyhow!(err)),
+                }
             }
             Ok(None) => {
                 tracing::info!("No proof for batch {l1_batch_id} is ready yet");
@@ -113,4 +148,14 @@ impl ProofFetcher {
             Err(e) => Err(anyhow::anyhow!(e)),
         }
     }
+
+    /// Asks the gateway to prove the batch again. Failures are only logged; the notification is repeated
+    /// when the rejected proof is fetched next time.
+    async fn notify_proof_rejected(&self, l1_batch_id: L1BatchId) {
+        if let Err(err) = self.client.reject_proof(l1_batch_id).await {
+            tracing::error!(
+                "Failed to notify the gateway about rejected proof for batch {l1_batch_id}: {err}"
+            );
+        }
+    }
 }
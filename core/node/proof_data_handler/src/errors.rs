@@ -6,6 +6,8 @@ use zksync_dal::DalError;
 use zksync_object_store::{ObjectStoreError, _reexports::BoxedError};
 use zksync_types::L1BatchNumber;
 
+use crate::verifier::ProofVerificationError;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ProcessorError {
     #[error("General error: {0}")]
@@ -22,6 +24,8 @@ pub enum ProcessorError {
     Internal,
     #[error("Proof verification not possible anymore, batch is too old")]
     ProofIsGone,
+    #[error("Proof for batch {0} is rejected: {1}. The batch is requeued for proving")]
+    ProofRejected(L1BatchNumber, ProofVerificationError),
 }
 
 impl ProcessorError {
@@ -34,6 +38,7 @@ impl ProcessorError {
             Self::InvalidProof => StatusCode::BAD_REQUEST,
             Self::BatchNotReady(_) => StatusCode::NOT_FOUND,
             Self::ProofIsGone => StatusCode::GONE,
+            Self::ProofRejected(..) => StatusCode::UNPROCESSABLE_ENTITY,
         }
     }
 }
@@ -17,6 +17,7 @@ pub use crate::{
     client::ProofDataHandlerClient,
     errors::ProcessorError,
     processor::{Processor, Readonly},
+    verifier::{ProofVerificationError, ProofVerifier},
 };
 
 mod client;
@@ -24,6 +25,9 @@ mod errors;
 mod metrics;
 pub mod node;
 mod processor;
+#[cfg(test)]
+mod tests;
+mod verifier;
 
 pub async fn run_server(
     config: ProofDataHandlerConfig,
@@ -32,6 +36,7 @@ pub async fn run_server(
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
     api_mode: ApiMode,
+    proof_verifier: Option<Arc<ProofVerifier>>,
     mut stop_receiver: watch::Receiver<bool>,
 ) -> anyhow::Result<()> {
     let bind_address = SocketAddr::from(([0, 0, 0, 0], config.http_port));
@@ -43,6 +48,7 @@ pub async fn run_server(
         api_mode,
         commitment_mode,
         l2_chain_id,
+        proof_verifier,
     );
 
     let listener = tokio::net::TcpListener::bind(bind_address)
@@ -68,6 +74,7 @@ fn create_proof_processing_router(
     api_mode: ApiMode,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    proof_verifier: Option<Arc<ProofVerifier>>,
 ) -> Router {
     let mut router = Router::new();
 
@@ -78,7 +85,8 @@ fn create_proof_processing_router(
             config.clone(),
             commitment_mode,
             l2_chain_id,
-        );
+        )
+        .with_proof_verifier(proof_verifier);
         let submit_proof_processor = get_proof_gen_processor.clone();
 
         router = router.route(
@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
+
+use anyhow::Context as _;
 
 use zksync_config::configs::{fri_prover_gateway::ApiMode, ProofDataHandlerConfig};
 use zksync_dal::{
@@ -14,7 +16,7 @@ use zksync_node_framework::{
 use zksync_object_store::{node::ObjectStoreResource, ObjectStore};
 use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 
-use crate::ProofDataHandlerClient;
+use crate::{ProofDataHandlerClient, ProofVerifier};
 
 /// Wiring layer for proof data handler server.
 #[derive(Debug)]
@@ -23,6 +25,7 @@ pub struct ProofDataHandlerLayer {
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
     api_mode: ApiMode,
+    is_verifier_pre_fflonk: bool,
 }
 
 #[derive(Debug, FromContext)]
@@ -43,12 +46,14 @@ impl ProofDataHandlerLayer {
         commitment_mode: L1BatchCommitmentMode,
         l2_chain_id: L2ChainId,
         api_mode: ApiMode,
+        is_verifier_pre_fflonk: bool,
     ) -> Self {
         Self {
             proof_data_handler_config,
             commitment_mode,
             l2_chain_id,
             api_mode,
+            is_verifier_pre_fflonk,
         }
     }
 }
@@ -65,6 +70,14 @@ impl WiringLayer for ProofDataHandlerLayer {
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let main_pool = input.master_pool.get().await?;
         let blob_store = input.object_store.0;
+        let proof_verifier = self
+            .proof_data_handler_config
+            .verification_keys_path
+            .as_ref()
+            .map(|path| ProofVerifier::new(Path::new(path), self.is_verifier_pre_fflonk))
+            .transpose()
+            .context("failed loading verification keys")?
+            .map(Arc::new);
 
         let task = ProofDataHandlerTask {
             proof_data_handler_config: self.proof_data_handler_config,
@@ -73,6 +86,7 @@ impl WiringLayer for ProofDataHandlerLayer {
             commitment_mode: self.commitment_mode,
             l2_chain_id: self.l2_chain_id,
             api_mode: self.api_mode,
+            proof_verifier,
         };
 
         Ok(Output { task })
@@ -87,6 +101,7 @@ pub struct ProofDataHandlerTask {
     api_mode: ApiMode,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    proof_verifier: Option<Arc<ProofVerifier>>,
 }
 
 #[async_trait::async_trait]
@@ -103,6 +118,7 @@ impl Task for ProofDataHandlerTask {
             self.commitment_mode,
             self.l2_chain_id,
             self.api_mode.clone(),
+            self.proof_verifier.clone(),
             stop_receiver.clone().0,
         );
 
@@ -115,6 +131,7 @@ impl Task for ProofDataHandlerTask {
                 self.proof_data_handler_config,
                 self.commitment_mode,
                 self.l2_chain_id,
+                self.proof_verifier,
             );
 
             let client_task = client.run(stop_receiver.0);
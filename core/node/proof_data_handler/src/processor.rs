@@ -19,7 +19,11 @@ use zksync_types::{
     L1BatchId, L1BatchNumber, L2ChainId, ProtocolVersionId, H256, STATE_DIFF_HASH_KEY_PRE_GATEWAY,
 };
 
-use crate::{errors::ProcessorError, metrics::METRICS};
+use crate::{
+    errors::ProcessorError,
+    metrics::METRICS,
+    verifier::{batch_public_input, ProofVerifier},
+};
 
 pub trait ProcessorMode {}
 
@@ -43,6 +47,7 @@ pub struct Processor<PM: ProcessorMode> {
     config: ProofDataHandlerConfig,
     commitment_mode: L1BatchCommitmentMode,
     chain_id: L2ChainId,
+    proof_verifier: Option<Arc<ProofVerifier>>,
     _marker: std::marker::PhantomData<PM>,
 }
 
@@ -60,10 +65,17 @@ impl<PM: ProcessorMode> Processor<PM> {
             config,
             commitment_mode,
             chain_id,
+            proof_verifier: None,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Enables verification of final proofs before they are saved.
+    pub fn with_proof_verifier(mut self, proof_verifier: Option<Arc<ProofVerifier>>) -> Self {
+        self.proof_verifier = proof_verifier;
+        self
+    }
+
     pub fn chain_id(&self) -> L2ChainId {
         self.chain_id
     }
@@ -244,6 +256,11 @@ impl Processor<Locking> {
     ) -> Result<(), ProcessorError> {
         tracing::info!("Received proof for block number: {l1_batch_id}");
 
+        if let Some(proof_verifier) = &self.proof_verifier {
+            self.verify_final_proof(proof_verifier, l1_batch_id.batch_number(), &proof)
+                .await?;
+        }
+
         let blob_url = self
             .blob_store
             .put(
@@ -331,6 +348,73 @@ impl Processor<Locking> {
         Ok(())
     }
 
+    /// Verifies the proof the same way as the L1 verifier would. If the proof is invalid, the batch is
+    /// unlocked so that it's picked for proving again.
+    async fn verify_final_proof(
+        &self,
+        proof_verifier: &Arc<ProofVerifier>,
+        l1_batch_number: L1BatchNumber,
+        proof: &L1BatchProofForL1,
+    ) -> Result<(), ProcessorError> {
+        let mut storage = self.pool.connection().await?;
+        let prev_l1_batch = storage
+            .blocks_dal()
+            .get_l1_batch_metadata(l1_batch_number - 1)
+            .await?
+            .ok_or(ProcessorError::GeneralError(format!(
+                "Missing metadata for batch {}",
+                l1_batch_number - 1
+            )))?;
+        let l1_batch = storage
+            .blocks_dal()
+            .get_l1_batch_metadata(l1_batch_number)
+            .await?
+            .ok_or(ProcessorError::GeneralError(format!(
+                "Missing metadata for batch {l1_batch_number}"
+            )))?;
+        let l1_verifier_config = storage
+            .protocol_versions_dal()
+            .l1_verifier_config_for_version(proof.protocol_version())
+            .await
+            .ok_or(ProcessorError::GeneralError(format!(
+                "Missing L1 verifier config for protocol version {}",
+                proof.protocol_version()
+            )))?;
+        drop(storage);
+
+        let protocol_version = l1_batch
+            .header
+            .protocol_version
+            .unwrap_or_else(ProtocolVersionId::last_potentially_undefined);
+        let public_input = batch_public_input(
+            prev_l1_batch.metadata.commitment,
+            l1_batch.metadata.commitment,
+        );
+
+        let verifier = proof_verifier.clone();
+        let proof = proof.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            verifier.verify(&proof, protocol_version, &l1_verifier_config, public_input)
+        })
+        .await
+        .map_err(|err| {
+            ProcessorError::GeneralError(format!("Proof verification panicked: {err}"))
+        })?;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(err) if err.is_invalid_proof() => {
+                tracing::error!("Rejected proof for batch {l1_batch_number}: {err}");
+                METRICS.rejected_proofs.inc();
+                self.unlock_batch(l1_batch_number).await?;
+                Err(ProcessorError::ProofRejected(l1_batch_number, err))
+            }
+            Err(err) => Err(ProcessorError::GeneralError(format!(
+                "Cannot verify proof for batch {l1_batch_number}: {err}"
+            ))),
+        }
+    }
+
     pub async fn save_skipped_proof(&self, l1_batch_id: L1BatchId) -> Result<(), ProcessorError> {
         tracing::info!("Received skipped proof for block number: {l1_batch_id}");
         self.pool
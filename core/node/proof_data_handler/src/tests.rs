@@ -0,0 +1,158 @@
+use std::{path::Path, time::Duration};
+
+use assert_matches::assert_matches;
+use axum::http::StatusCode;
+use bellman::{bn256::Fr, plonk::better_better_cs::proof::Proof, PrimeField};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_node_test_utils::{
+    create_l1_batch, create_l1_batch_metadata, l1_batch_metadata_to_commitment_artifacts,
+};
+use zksync_object_store::MockObjectStore;
+use zksync_prover_interface::outputs::{L1BatchProofForL1, PlonkL1BatchProofForL1};
+use zksync_types::{
+    commitment::L1BatchCommitmentMode,
+    protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+    L1BatchId, L1BatchNumber, L2ChainId, ProtocolVersion, ProtocolVersionId, H256,
+};
+
+use super::*;
+
+fn keystore_path() -> &'static Path {
+    Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../../prover/data/keys"
+    ))
+}
+
+fn snark_wrapper_vk_hash() -> H256 {
+    let commitments = std::fs::read_to_string(keystore_path().join("commitments.json")).unwrap();
+    let commitments: serde_json::Value = serde_json::from_str(&commitments).unwrap();
+    commitments["snark_wrapper"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap()
+}
+
+async fn prepare_storage(pool: &ConnectionPool<Core>, protocol_version: ProtocolSemanticVersion) {
+    let mut storage = pool.connection().await.unwrap();
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion {
+            version: protocol_version,
+            l1_verifier_config: L1VerifierConfig {
+                snark_wrapper_vk_hash: snark_wrapper_vk_hash(),
+                fflonk_snark_wrapper_vk_hash: None,
+            },
+            ..ProtocolVersion::default()
+        })
+        .await
+        .unwrap();
+
+    for number in [0, 1] {
+        storage
+            .blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch(number))
+            .await
+            .unwrap();
+        let metadata = create_l1_batch_metadata(number);
+        storage
+            .blocks_dal()
+            .save_l1_batch_tree_data(L1BatchNumber(number), &metadata.tree_data())
+            .await
+            .unwrap();
+        storage
+            .blocks_dal()
+            .save_l1_batch_commitment_artifacts(
+                L1BatchNumber(number),
+                &l1_batch_metadata_to_commitment_artifacts(&metadata),
+            )
+            .await
+            .unwrap();
+    }
+
+    let batch = L1BatchNumber(1);
+    let mut proof_dal = storage.proof_generation_dal();
+    proof_dal
+        .insert_proof_generation_details(batch)
+        .await
+        .unwrap();
+    proof_dal
+        .save_vm_runner_artifacts_metadata(batch, "vm_run_data")
+        .await
+        .unwrap();
+    proof_dal
+        .save_merkle_paths_artifacts_metadata(batch, "merkle_paths")
+        .await
+        .unwrap();
+    let locked_batch = proof_dal
+        .lock_batch_for_proving(Duration::from_secs(600))
+        .await
+        .unwrap();
+    assert_eq!(locked_batch, Some(batch));
+}
+
+#[tokio::test]
+async fn corrupted_proof_is_rejected_and_batch_is_requeued() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let protocol_version = ProtocolSemanticVersion {
+        minor: ProtocolVersionId::latest(),
+        patch: 0.into(),
+    };
+    prepare_storage(&pool, protocol_version).await;
+
+    let config = ProofDataHandlerConfig {
+        http_port: 0,
+        proof_generation_timeout_in_secs: 600,
+        gateway_api_url: None,
+        proof_fetch_interval_in_secs: 10,
+        proof_gen_data_submit_interval_in_secs: 10,
+        fetch_zero_chain_id_proofs: false,
+        verification_keys_path: None,
+    };
+    let proof_verifier = ProofVerifier::new(keystore_path(), true).unwrap();
+    let blob_store = MockObjectStore::arc();
+    let processor = Processor::<Locking>::new(
+        blob_store,
+        pool.clone(),
+        config,
+        L1BatchCommitmentMode::Rollup,
+        L2ChainId::default(),
+    )
+    .with_proof_verifier(Some(Arc::new(proof_verifier)));
+
+    // The proof is corrupted so that its public input doesn't correspond to the batch.
+    let mut scheduler_proof = Proof::empty();
+    scheduler_proof.inputs = vec![Fr::from_str("1").unwrap()];
+    let proof = L1BatchProofForL1::new_plonk(PlonkL1BatchProofForL1 {
+        aggregation_result_coords: [[0; 32]; 4],
+        scheduler_proof,
+        protocol_version,
+    });
+
+    let l1_batch_id = L1BatchId::new(L2ChainId::default(), L1BatchNumber(1));
+    let err = processor.save_proof(l1_batch_id, proof).await.unwrap_err();
+    assert_matches!(
+        &err,
+        ProcessorError::ProofRejected(
+            L1BatchNumber(1),
+            ProofVerificationError::PublicInputMismatch { .. }
+        )
+    );
+    assert_eq!(err.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    // The batch must be unlocked for proving and must not be marked as proven.
+    let mut storage = pool.connection().await.unwrap();
+    let unpicked_batch = storage
+        .proof_generation_dal()
+        .get_oldest_unpicked_batch()
+        .await
+        .unwrap();
+    assert_eq!(unpicked_batch, Some(L1BatchNumber(1)));
+    let not_proven_batch = storage
+        .proof_generation_dal()
+        .get_oldest_not_generated_batch()
+        .await
+        .unwrap();
+    assert_eq!(not_proven_batch, Some(L1BatchNumber(1)));
+}
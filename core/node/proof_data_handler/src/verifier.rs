@@ -0,0 +1,305 @@
+//! Local verification of final proofs, mirroring the checks done by the L1 verifier contract.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::Context as _;
+use bellman::{
+    bn256::{Bn256, Fr},
+    plonk::{
+        better_better_cs::{setup::VerificationKey, verifier::verify},
+        commitments::transcript::keccak_transcript::RollingKeccakTranscript,
+    },
+    CurveAffine, Engine, PrimeField, PrimeFieldRepr,
+};
+use circuit_definitions::circuit_definitions::aux_layer::{
+    ZkSyncSnarkWrapperCircuit, ZkSyncSnarkWrapperCircuitNoLookupCustomGate,
+};
+use fflonk::FflonkVerificationKey;
+use zksync_prover_interface::outputs::{L1BatchProofForL1, TypedL1BatchProofForL1};
+use zksync_types::{
+    protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+    web3::keccak256,
+    ProtocolVersionId, H256, U256,
+};
+
+type SnarkWrapperVk = VerificationKey<Bn256, ZkSyncSnarkWrapperCircuit>;
+type FflonkSnarkWrapperVk =
+    FflonkVerificationKey<Bn256, ZkSyncSnarkWrapperCircuitNoLookupCustomGate>;
+
+/// File names of the verification keys in the prover keystore.
+const SNARK_VK_FILE: &str = "verification_snark_key.json";
+const FFLONK_SNARK_VK_FILE: &str = "fflonk_verification_snark_key.json";
+
+/// Number of bits the batch public input is shifted by, so that it fits into the field.
+const PUBLIC_INPUT_SHIFT: usize = 32;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProofVerificationError {
+    #[error(
+        "FFLONK proof can't be submitted to the pre-FFLONK verifier used for protocol version {0}"
+    )]
+    FflonkNotSupported(ProtocolVersionId),
+    #[error("No FFLONK verification key hash is set for protocol version {0}")]
+    MissingFflonkVkHash(ProtocolSemanticVersion),
+    #[error("Proof has {0} public inputs, expected exactly 1")]
+    UnexpectedInputCount(usize),
+    #[error("Proof public input {actual:#x} doesn't match the batch public input {expected:#x}")]
+    PublicInputMismatch { expected: U256, actual: U256 },
+    #[error("{0} proof is invalid")]
+    InvalidProof(&'static str),
+    #[error("Failed to verify {0} proof: {1}")]
+    Malformed(&'static str, String),
+    #[error("No {0} verification key with hash {1:?} found in keystore")]
+    UnknownVerificationKey(&'static str, H256),
+}
+
+impl ProofVerificationError {
+    /// Returns `true` if the proof itself is faulty, i.e. the batch has to be proven again. Otherwise,
+    /// the error is caused by the local setup (e.g., an outdated keystore).
+    pub fn is_invalid_proof(&self) -> bool {
+        !matches!(self, Self::UnknownVerificationKey(..))
+    }
+}
+
+/// Verifies final PLONK and FFLONK proofs against the verification keys from the prover keystore.
+/// The keys are selected by the hashes set in the L1 verifier config of the proof's protocol version.
+pub struct ProofVerifier {
+    plonk_vks: HashMap<H256, SnarkWrapperVk>,
+    fflonk_vks: HashMap<H256, FflonkSnarkWrapperVk>,
+    is_verifier_pre_fflonk: bool,
+}
+
+impl std::fmt::Debug for ProofVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProofVerifier")
+            .field("plonk_vks", &self.plonk_vks.keys().collect::<Vec<_>>())
+            .field("fflonk_vks", &self.fflonk_vks.keys().collect::<Vec<_>>())
+            .field("is_verifier_pre_fflonk", &self.is_verifier_pre_fflonk)
+            .finish()
+    }
+}
+
+impl ProofVerifier {
+    /// Loads verification keys from the keystore directory. The FFLONK key is optional; if it's missing,
+    /// all FFLONK proofs are rejected.
+    pub fn new(keystore_path: &Path, is_verifier_pre_fflonk: bool) -> anyhow::Result<Self> {
+        let path = keystore_path.join(SNARK_VK_FILE);
+        let vk: SnarkWrapperVk = read_json(&path)?;
+        let vk_hash = snark_vk_hash(&vk);
+        tracing::info!("Loaded PLONK verification key with hash {vk_hash:?} from {path:?}");
+        let plonk_vks = HashMap::from([(vk_hash, vk)]);
+
+        let path = keystore_path.join(FFLONK_SNARK_VK_FILE);
+        let mut fflonk_vks = HashMap::new();
+        if path.exists() {
+            let vk: FflonkSnarkWrapperVk = read_json(&path)?;
+            let vk_hash = fflonk_snark_vk_hash(&vk)?;
+            tracing::info!("Loaded FFLONK verification key with hash {vk_hash:?} from {path:?}");
+            fflonk_vks.insert(vk_hash, vk);
+        } else {
+            tracing::warn!(
+                "FFLONK verification key is missing at {path:?}, FFLONK proofs will be rejected"
+            );
+        }
+
+        Ok(Self {
+            plonk_vks,
+            fflonk_vks,
+            is_verifier_pre_fflonk,
+        })
+    }
+
+    /// Checks that `proof` is accepted by the L1 verifier for a batch with the given `public_input`.
+    pub fn verify(
+        &self,
+        proof: &L1BatchProofForL1,
+        protocol_version: ProtocolVersionId,
+        l1_verifier_config: &L1VerifierConfig,
+        public_input: U256,
+    ) -> Result<(), ProofVerificationError> {
+        // Same condition as used for encoding `proveBatches` calls in `eth_sender`.
+        let should_use_fflonk = !self.is_verifier_pre_fflonk || !protocol_version.is_pre_fflonk();
+
+        match proof.inner() {
+            TypedL1BatchProofForL1::Fflonk(proof) => {
+                if !should_use_fflonk {
+                    return Err(ProofVerificationError::FflonkNotSupported(protocol_version));
+                }
+                let vk_hash = l1_verifier_config.fflonk_snark_wrapper_vk_hash.ok_or(
+                    ProofVerificationError::MissingFflonkVkHash(proof.protocol_version),
+                )?;
+                let vk = self.fflonk_vks.get(&vk_hash).ok_or(
+                    ProofVerificationError::UnknownVerificationKey("FFLONK", vk_hash),
+                )?;
+                check_public_input(&proof.scheduler_proof.inputs, public_input)?;
+
+                let is_valid = fflonk::verify::<_, _, RollingKeccakTranscript<Fr>>(
+                    vk,
+                    &proof.scheduler_proof,
+                    None,
+                )
+                .map_err(|err| ProofVerificationError::Malformed("FFLONK", format!("{err:?}")))?;
+                if !is_valid {
+                    return Err(ProofVerificationError::InvalidProof("FFLONK"));
+                }
+            }
+            TypedL1BatchProofForL1::Plonk(proof) => {
+                let vk_hash = l1_verifier_config.snark_wrapper_vk_hash;
+                let vk = self.plonk_vks.get(&vk_hash).ok_or(
+                    ProofVerificationError::UnknownVerificationKey("PLONK", vk_hash),
+                )?;
+                check_public_input(&proof.scheduler_proof.inputs, public_input)?;
+
+                let is_valid =
+                    verify::<_, _, RollingKeccakTranscript<Fr>>(vk, &proof.scheduler_proof, None)
+                        .map_err(|err| {
+                        ProofVerificationError::Malformed("PLONK", format!("{err:?}"))
+                    })?;
+                if !is_valid {
+                    return Err(ProofVerificationError::InvalidProof("PLONK"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Computes the public input of the batch proof the same way as the `Executor` L1 contract does.
+pub fn batch_public_input(prev_batch_commitment: H256, batch_commitment: H256) -> U256 {
+    let hash = keccak256(
+        &[
+            prev_batch_commitment.as_bytes(),
+            batch_commitment.as_bytes(),
+        ]
+        .concat(),
+    );
+    U256::from_big_endian(&hash) >> PUBLIC_INPUT_SHIFT
+}
+
+fn check_public_input(inputs: &[Fr], expected: U256) -> Result<(), ProofVerificationError> {
+    let [input] = inputs else {
+        return Err(ProofVerificationError::UnexpectedInputCount(inputs.len()));
+    };
+    let actual = fr_to_u256(input);
+    if actual != expected {
+        return Err(ProofVerificationError::PublicInputMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+fn fr_to_u256(field_element: &Fr) -> U256 {
+    let mut bytes = [0_u8; 32];
+    field_element
+        .into_repr()
+        .write_be(&mut bytes[..])
+        .expect("failed serializing field element");
+    U256::from_big_endian(&bytes)
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed reading verification key from {path:?}"))?;
+    serde_json::from_str(&text)
+        .with_context(|| format!("failed deserializing verification key from {path:?}"))
+}
+
+fn write_g1(res: &mut Vec<u8>, point: &<Bn256 as Engine>::G1Affine) {
+    let (x, y) = point.as_xy();
+    x.into_repr().write_be(&mut *res).unwrap();
+    y.into_repr().write_be(&mut *res).unwrap();
+}
+
+/// Hash of a PLONK verification key as computed by the L1 verifier contract.
+// Corresponds 1:1 to `calculate_snark_vk_hash()` in the prover keystore.
+fn snark_vk_hash(vk: &SnarkWrapperVk) -> H256 {
+    let mut res = vec![];
+    for point in vk
+        .gate_setup_commitments
+        .iter()
+        .chain(&vk.gate_selectors_commitments)
+        .chain(&vk.permutation_commitments)
+        .chain(&vk.lookup_selector_commitment)
+        .chain(&vk.lookup_tables_commitments)
+        .chain(&vk.lookup_table_type_commitment)
+    {
+        write_g1(&mut res, point);
+    }
+    // Flag for using the recursive part.
+    res.extend([0_u8; 32]);
+    H256(keccak256(&res))
+}
+
+/// Hash of an FFLONK verification key as computed by the L1 verifier contract.
+// Corresponds 1:1 to `calculate_fflonk_snark_vk_hash()` in the prover keystore.
+fn fflonk_snark_vk_hash(vk: &FflonkSnarkWrapperVk) -> anyhow::Result<H256> {
+    let mut res = vec![0_u8; 32];
+    U256::from(vk.num_inputs).to_big_endian(&mut res[0..32]);
+    write_g1(&mut res, &vk.c0);
+    for non_residue in &vk.non_residues {
+        non_residue.into_repr().write_be(&mut res)?;
+    }
+    for g2_element in &vk.g2_elements {
+        res.extend(g2_element.into_uncompressed().as_ref());
+    }
+    Ok(H256(keccak256(&res)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_input_is_shifted_keccak_of_commitments() {
+        let prev = H256::repeat_byte(1);
+        let current = H256::repeat_byte(2);
+        let input = batch_public_input(prev, current);
+
+        let hash = keccak256(&[[1_u8; 32], [2_u8; 32]].concat());
+        let mut expected = [0_u8; 32];
+        expected[4..].copy_from_slice(&hash[..28]);
+        assert_eq!(input, U256::from_big_endian(&expected));
+        // The input must fit into the BN254 scalar field.
+        assert!(input.bits() <= 224);
+    }
+
+    #[test]
+    fn public_input_check() {
+        let expected = U256::from(42);
+        let input = Fr::from_str("42").unwrap();
+        check_public_input(&[input], expected).unwrap();
+
+        let err = check_public_input(&[input], U256::from(43)).unwrap_err();
+        assert!(matches!(
+            err,
+            ProofVerificationError::PublicInputMismatch { .. }
+        ));
+        assert!(err.is_invalid_proof());
+
+        let err = check_public_input(&[input, input], expected).unwrap_err();
+        assert!(matches!(
+            err,
+            ProofVerificationError::UnexpectedInputCount(2)
+        ));
+    }
+
+    #[test]
+    fn loading_keystore_keys() {
+        let keystore_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../prover/data/keys");
+        let verifier = ProofVerifier::new(&keystore_path, true).unwrap();
+
+        // Hashes must match the ones committed to in the keystore.
+        let commitments: serde_json::Value =
+            read_json(&keystore_path.join("commitments.json")).unwrap();
+        let expected_hash =
+            |name: &str| -> H256 { commitments[name].as_str().unwrap().parse().unwrap() };
+        assert!(verifier
+            .plonk_vks
+            .contains_key(&expected_hash("snark_wrapper")));
+        assert!(verifier
+            .fflonk_vks
+            .contains_key(&expected_hash("fflonk_snark_wrapper")));
+
+        let err = ProofVerifier::new(Path::new("/non/existing"), true).unwrap_err();
+        assert!(format!("{err:#}").contains(SNARK_VK_FILE), "{err:#}");
+    }
+}
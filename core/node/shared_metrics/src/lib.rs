@@ -175,6 +175,7 @@ pub enum InteractionType {
 pub enum CheckerComponent {
     ConsistencyChecker,
     ReorgDetector,
+    BatchStatusVerifier,
 }
 
 /// General-purpose external node metrics.
@@ -189,8 +190,10 @@ pub struct ExternalNodeMetrics {
     pub synced: Gauge<u64>,
     /// Current sync lag of the external node.
     pub sync_lag: Gauge<u64>,
-    /// Number of the last L1 batch checked by the re-org detector or consistency checker.
+    /// Number of the last L1 batch checked by the re-org detector, consistency checker or batch status verifier.
     pub last_correct_batch: Family<CheckerComponent, Gauge<u64>>,
+    /// Number of disagreements between L1 batch statuses reported by the main node and the settlement layer.
+    pub batch_status_mismatches: Counter,
     /// Number of the last L2 block checked by the re-org detector.
     pub last_correct_l2_block: Family<CheckerComponent, Gauge<u64>>,
 }
@@ -0,0 +1,119 @@
+//! Runtime controls for the state keeper exposed to node operators.
+
+use std::sync::{Arc, Mutex};
+
+use zksync_dal::{ConnectionPool, Core, CoreDal, DalError};
+use zksync_types::H256;
+
+use crate::{metrics::KEEPER_METRICS, seal_criteria::UnexecutableReason, MempoolGuard};
+
+/// Errors that can occur when evicting a transaction using [`StateKeeperControl`].
+#[derive(Debug, thiserror::Error)]
+pub enum EvictionError {
+    #[error("transaction {0:?} is not in the mempool")]
+    NotFound(H256),
+    #[error("failed marking transaction as rejected: {0}")]
+    Dal(#[from] DalError),
+}
+
+#[derive(Debug, Default)]
+struct ControlState {
+    paused: bool,
+    l1_batch_seal_requested: bool,
+}
+
+/// Handle allowing to control [`MempoolIO`](crate::MempoolIO) at runtime, e.g. from the admin API.
+///
+/// Pausing and sealing are advisory and are applied by the state keeper on its next iteration.
+#[derive(Debug, Clone)]
+pub struct StateKeeperControl {
+    state: Arc<Mutex<ControlState>>,
+    mempool: MempoolGuard,
+    pool: ConnectionPool<Core>,
+}
+
+impl StateKeeperControl {
+    pub fn new(mempool: MempoolGuard, pool: ConnectionPool<Core>) -> Self {
+        Self {
+            state: Arc::default(),
+            mempool,
+            pool,
+        }
+    }
+
+    /// Pauses the state keeper. While paused, the state keeper doesn't take new transactions from the mempool;
+    /// blocks are still sealed according to sealing rules.
+    pub fn pause(&self) {
+        self.state.lock().unwrap().paused = true;
+    }
+
+    /// Resumes the state keeper after [`Self::pause()`].
+    pub fn resume(&self) {
+        self.state.lock().unwrap().paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().paused
+    }
+
+    /// Requests the state keeper to seal the current L1 batch. The request is ignored if the batch is empty.
+    pub fn request_l1_batch_seal(&self) {
+        self.state.lock().unwrap().l1_batch_seal_requested = true;
+    }
+
+    pub(crate) fn take_l1_batch_seal_request(&self) -> bool {
+        std::mem::take(&mut self.state.lock().unwrap().l1_batch_seal_requested)
+    }
+
+    /// Evicts an L2 transaction with the specified hash from the mempool and marks it as rejected in the storage.
+    /// Subsequent transactions of the same account stay in the mempool until the nonce gap is filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvictionError::NotFound`] if the transaction is not in the mempool, e.g. because it's already
+    /// taken by the state keeper for execution.
+    pub async fn evict_transaction(&self, tx_hash: H256) -> Result<(), EvictionError> {
+        self.mempool
+            .remove_l2_transaction(tx_hash)
+            .ok_or(EvictionError::NotFound(tx_hash))?;
+
+        let reason = UnexecutableReason::EvictedByOperator;
+        KEEPER_METRICS.inc_rejected_txs(reason.as_metric_label());
+        tracing::warn!("Transaction {tx_hash:?} is rejected with error: {reason}");
+        let mut storage = self.pool.connection_tagged("state_keeper").await?;
+        storage
+            .transactions_dal()
+            .mark_tx_as_rejected(tx_hash, &format!("rejected: {reason}"))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use zksync_types::PriorityOpId;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn state_keeper_control_basics() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mempool = MempoolGuard::new(PriorityOpId(0), 100);
+        let control = StateKeeperControl::new(mempool, pool);
+        assert!(!control.is_paused());
+        control.pause();
+        assert!(control.clone().is_paused());
+        control.resume();
+        assert!(!control.is_paused());
+
+        assert!(!control.take_l1_batch_seal_request());
+        control.request_l1_batch_seal();
+        assert!(control.take_l1_batch_seal_request());
+        assert!(!control.take_l1_batch_seal_request());
+
+        let tx_hash = H256::repeat_byte(1);
+        let err = control.evict_transaction(tx_hash).await.unwrap_err();
+        assert_matches!(err, EvictionError::NotFound(hash) if hash == tx_hash);
+    }
+}
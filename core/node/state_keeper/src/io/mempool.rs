@@ -36,7 +36,7 @@ use crate::{
     },
     updates::UpdatesManager,
     utils::millis_since_epoch,
-    MempoolGuard,
+    MempoolGuard, StateKeeperControl,
 };
 
 /// Mempool-based sequencer for the state keeper.
@@ -62,6 +62,7 @@ pub struct MempoolIO {
     chain_id: L2ChainId,
     l2_da_validator_address: Option<Address>,
     pubdata_type: PubdataType,
+    control: StateKeeperControl,
 }
 
 #[async_trait]
@@ -86,6 +87,21 @@ impl IoSealCriteria for MempoolIO {
             return Ok(true);
         }
 
+        if self.control.take_l1_batch_seal_request() {
+            if manager.pending_executed_transactions_len() == 0 {
+                tracing::info!(
+                    "Ignoring operator request to seal L1 batch since the batch is empty"
+                );
+            } else {
+                AGGREGATION_METRICS.l1_batch_reason_inc_criterion("operator_request");
+                tracing::info!(
+                    "Sealing L1 batch #{} as requested by operator",
+                    manager.l1_batch.number
+                );
+                return Ok(true);
+            }
+        }
+
         Ok(false)
     }
 
@@ -332,6 +348,11 @@ impl StateKeeperIO for MempoolIO {
     ) -> anyhow::Result<Option<Transaction>> {
         let started_at = Instant::now();
         while started_at.elapsed() <= max_wait {
+            if self.control.is_paused() {
+                tokio::time::sleep(self.delay_interval).await;
+                continue;
+            }
+
             let get_latency = KEEPER_METRICS.get_tx_from_mempool.start();
             let maybe_tx = self.mempool.next_transaction(&self.filter);
             get_latency.observe();
@@ -532,6 +553,7 @@ impl MempoolIO {
         pubdata_type: PubdataType,
     ) -> anyhow::Result<Self> {
         Ok(Self {
+            control: StateKeeperControl::new(mempool.clone(), pool.clone()),
             mempool,
             pool: pool.clone(),
             timeout_sealer: TimeoutSealer::new(config),
@@ -551,6 +573,11 @@ impl MempoolIO {
         })
     }
 
+    /// Returns a handle to control this IO at runtime.
+    pub fn control(&self) -> StateKeeperControl {
+        self.control.clone()
+    }
+
     fn pubdata_params(&self, protocol_version: ProtocolVersionId) -> anyhow::Result<PubdataParams> {
         let pubdata_params = match (
             protocol_version.is_pre_gateway(),
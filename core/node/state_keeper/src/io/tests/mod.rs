@@ -1,5 +1,6 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use assert_matches::assert_matches;
 use test_casing::test_casing;
 use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
@@ -31,7 +32,7 @@ use crate::{
     testonly::BASE_SYSTEM_CONTRACTS,
     tests::{create_execution_result, create_transaction, seconds_since_epoch, Query},
     updates::{L2BlockSealCommand, L2BlockUpdates, UpdatesManager},
-    StateKeeperOutputHandler, StateKeeperPersistence,
+    EvictionError, StateKeeperOutputHandler, StateKeeperPersistence,
 };
 
 mod tester;
@@ -850,6 +851,56 @@ async fn test_mempool_with_timestamp_assertion() {
     );
 }
 
+#[tokio::test]
+async fn evicting_transaction() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(2).await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&connection_pool).await;
+    let mut storage = connection_pool.connection().await.unwrap();
+
+    let (mut mempool, mut guard) = tester.create_test_mempool_io(connection_pool).await;
+    mempool.initialize().await.unwrap();
+    let control = mempool.control();
+
+    let filter = mempool.filter().clone();
+    let evicted_tx = tester.insert_tx(
+        &mut guard,
+        filter.fee_per_gas,
+        filter.gas_per_pubdata,
+        TransactionTimeRangeConstraint::default(),
+    );
+    insert_l2_transaction(&mut storage, &evicted_tx).await;
+
+    let unknown_hash = H256::repeat_byte(0xff);
+    let err = control.evict_transaction(unknown_hash).await.unwrap_err();
+    assert_matches!(err, EvictionError::NotFound(hash) if hash == unknown_hash);
+
+    control.evict_transaction(evicted_tx.hash()).await.unwrap();
+    assert_eq!(guard.stats().l2_transaction_count, 0);
+    let evicted_storage_tx = storage
+        .transactions_dal()
+        .get_storage_tx_by_hash(evicted_tx.hash())
+        .await
+        .unwrap()
+        .expect("Failed to find transaction");
+    assert_eq!(
+        evicted_storage_tx.error.as_deref(),
+        Some("rejected: Evicted by operator")
+    );
+
+    let next_tx = mempool
+        .wait_for_next_tx(Duration::from_millis(100), seconds_since_epoch())
+        .await
+        .unwrap();
+    assert!(next_tx.is_none());
+    // The transaction can only be evicted once.
+    let err = control
+        .evict_transaction(evicted_tx.hash())
+        .await
+        .unwrap_err();
+    assert_matches!(err, EvictionError::NotFound(_));
+}
+
 #[tokio::test]
 async fn test_batch_params_with_protocol_upgrade_tx() {
     let connection_pool = ConnectionPool::<Core>::constrained_test_pool(2).await;
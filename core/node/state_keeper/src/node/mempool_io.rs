@@ -11,10 +11,15 @@ use zksync_node_framework::{
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
-use zksync_shared_resources::contracts::{L2ContractsResource, SettlementLayerContractsResource};
+use zksync_shared_resources::{
+    contracts::{L2ContractsResource, SettlementLayerContractsResource},
+    dynamic_config::DynamicConfigHandle,
+};
 use zksync_types::{commitment::PubdataType, L2ChainId};
 
-use super::resources::{ConditionalSealerResource, StateKeeperIOResource};
+use super::resources::{
+    ConditionalSealerResource, StateKeeperControlResource, StateKeeperIOResource,
+};
 use crate::{MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer};
 
 /// Wiring layer for `MempoolIO`, an IO part of state keeper used by the main node.
@@ -23,11 +28,13 @@ use crate::{MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer};
 ///
 /// - `FeeInputResource`
 /// - `PoolResource<MasterPool>`
+/// - `DynamicConfigHandle` (optional; used for seal criteria)
 ///
 /// ## Adds resources
 ///
 /// - `StateKeeperIOResource`
 /// - `ConditionalSealerResource`
+/// - `StateKeeperControlResource`
 ///
 /// ## Adds tasks
 ///
@@ -47,12 +54,14 @@ pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub sl_contracts: SettlementLayerContractsResource,
     pub l2_contracts: L2ContractsResource,
+    pub dynamic_config: Option<DynamicConfigHandle>,
 }
 
 #[derive(Debug, IntoContext)]
 pub struct Output {
     pub state_keeper_io: StateKeeperIOResource,
     pub conditional_sealer: ConditionalSealerResource,
+    pub state_keeper_control: StateKeeperControlResource,
     #[context(task)]
     pub mempool_fetcher: MempoolFetcher,
 }
@@ -134,13 +143,18 @@ impl WiringLayer for MempoolIOLayer {
             input.l2_contracts.0.da_validator_addr,
             self.pubdata_type,
         )?;
+        let state_keeper_control = io.control();
 
         // Create sealer.
-        let sealer = SequencerSealer::new(self.state_keeper_config);
+        let mut sealer = SequencerSealer::new(self.state_keeper_config);
+        if let Some(dynamic_config) = input.dynamic_config {
+            sealer = sealer.with_dynamic_config(dynamic_config.subscribe());
+        }
 
         Ok(Output {
             state_keeper_io: io.into(),
             conditional_sealer: sealer.into(),
+            state_keeper_control: state_keeper_control.into(),
             mempool_fetcher,
         })
     }
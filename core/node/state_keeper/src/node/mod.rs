@@ -6,7 +6,7 @@ pub use self::{
     output_handler::OutputHandlerLayer,
     resources::{
         BatchExecutorResource, ConditionalSealerResource, OutputHandlerResource,
-        StateKeeperIOResource,
+        StateKeeperControlResource, StateKeeperIOResource,
     },
     state_keeper::StateKeeperLayer,
 };
@@ -4,7 +4,7 @@ use zksync_node_framework::resource::{Resource, Unique};
 use zksync_state::OwnedStorage;
 use zksync_vm_executor::interface::BatchExecutorFactory;
 
-use crate::{seal_criteria::ConditionalSealer, OutputHandler, StateKeeperIO};
+use crate::{seal_criteria::ConditionalSealer, OutputHandler, StateKeeperControl, StateKeeperIO};
 
 /// A resource that provides [`StateKeeperIO`] implementation to the service.
 /// This resource is unique, e.g. it's expected to be consumed by a single service.
@@ -78,3 +78,19 @@ where
         Self(Arc::new(sealer))
     }
 }
+
+/// A resource that provides [`StateKeeperControl`] for the main node state keeper.
+#[derive(Debug, Clone)]
+pub struct StateKeeperControlResource(pub StateKeeperControl);
+
+impl Resource for StateKeeperControlResource {
+    fn name() -> String {
+        "state_keeper/control".into()
+    }
+}
+
+impl From<StateKeeperControl> for StateKeeperControlResource {
+    fn from(control: StateKeeperControl) -> Self {
+        Self(control)
+    }
+}
@@ -4,9 +4,13 @@
 //! The conditional sealer abstraction allows to implement different sealing strategies, e.g. the actual
 //! sealing strategy for the main node or noop sealer for the external node.
 
-use std::fmt;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
 
-use zksync_config::configs::chain::StateKeeperConfig;
+use tokio::sync::watch;
+use zksync_config::configs::{chain::StateKeeperConfig, dynamic::DynamicConfig};
 use zksync_types::ProtocolVersionId;
 
 use super::{criteria, SealCriterion, SealData, SealResolution, AGGREGATION_METRICS};
@@ -51,27 +55,61 @@ pub trait ConditionalSealer: 'static + fmt::Debug + Send + Sync {
 /// Non-deterministic seal criteria are expressed using [`IoSealCriteria`](super::IoSealCriteria).
 #[derive(Debug, Default)]
 pub struct SequencerSealer {
-    config: StateKeeperConfig,
+    config: Arc<StateKeeperConfig>,
+    dynamic_config: Option<Mutex<DynamicSealConfig>>,
     sealers: Vec<Box<dyn SealCriterion>>,
 }
 
+/// Dynamic config receiver together with the effective config cached for the last observed dynamic config value.
+#[derive(Debug)]
+struct DynamicSealConfig {
+    receiver: watch::Receiver<DynamicConfig>,
+    effective: Arc<StateKeeperConfig>,
+}
+
+impl DynamicSealConfig {
+    fn new(base: &Arc<StateKeeperConfig>, mut receiver: watch::Receiver<DynamicConfig>) -> Self {
+        let effective = Self::build(base, &receiver.borrow_and_update());
+        Self {
+            receiver,
+            effective,
+        }
+    }
+
+    fn build(
+        base: &Arc<StateKeeperConfig>,
+        dynamic_config: &DynamicConfig,
+    ) -> Arc<StateKeeperConfig> {
+        let Some(seal_criteria) = &dynamic_config.state_keeper else {
+            return base.clone();
+        };
+        let mut config = StateKeeperConfig::clone(base);
+        seal_criteria.apply_to(&mut config);
+        Arc::new(config)
+    }
+
+    /// Returns the effective config, rebuilding it only if the dynamic config has changed since the last call.
+    fn get(&mut self, base: &Arc<StateKeeperConfig>) -> Arc<StateKeeperConfig> {
+        // An error means that the sender is dropped, so the dynamic config will not change anymore.
+        if self.receiver.has_changed().unwrap_or(false) {
+            self.effective = Self::build(base, &self.receiver.borrow_and_update());
+        }
+        self.effective.clone()
+    }
+}
+
 impl ConditionalSealer for SequencerSealer {
     fn find_unexecutable_reason(
         &self,
         data: &SealData,
         protocol_version: ProtocolVersionId,
     ) -> Option<&'static str> {
+        let config = self.effective_config();
         for sealer in &self.sealers {
             const TX_COUNT: usize = 1;
 
-            let resolution = sealer.should_seal(
-                &self.config,
-                TX_COUNT,
-                TX_COUNT,
-                data,
-                data,
-                protocol_version,
-            );
+            let resolution =
+                sealer.should_seal(&config, TX_COUNT, TX_COUNT, data, data, protocol_version);
             if matches!(resolution, SealResolution::Unexecutable(_)) {
                 return Some(sealer.prom_criterion_name());
             }
@@ -94,10 +132,11 @@ impl ConditionalSealer for SequencerSealer {
             block_data.execution_metrics
         );
 
+        let config = self.effective_config();
         let mut final_seal_resolution = SealResolution::NoSeal;
         for sealer in &self.sealers {
             let seal_resolution = sealer.should_seal(
-                &self.config,
+                &config,
                 tx_count,
                 l1_tx_count,
                 block_data,
@@ -130,16 +169,12 @@ impl ConditionalSealer for SequencerSealer {
         block_data: &SealData,
         protocol_version: ProtocolVersionId,
     ) -> Vec<(&'static str, f64)> {
+        let config = self.effective_config();
         self.sealers
             .iter()
             .filter_map(|s| {
-                let filled = s.capacity_filled(
-                    &self.config,
-                    tx_count,
-                    l1_tx_count,
-                    block_data,
-                    protocol_version,
-                );
+                let filled =
+                    s.capacity_filled(&config, tx_count, l1_tx_count, block_data, protocol_version);
                 filled.map(|f| (s.prom_criterion_name(), f))
             })
             .collect()
@@ -149,7 +184,21 @@ impl ConditionalSealer for SequencerSealer {
 impl SequencerSealer {
     pub fn new(config: StateKeeperConfig) -> Self {
         let sealers = Self::default_sealers(&config);
-        Self { config, sealers }
+        Self {
+            config: Arc::new(config),
+            dynamic_config: None,
+            sealers,
+        }
+    }
+
+    /// Makes the sealer use seal criteria params from the provided dynamic config, so that they can be updated
+    /// without restarting the node.
+    pub fn with_dynamic_config(mut self, dynamic_config: watch::Receiver<DynamicConfig>) -> Self {
+        self.dynamic_config = Some(Mutex::new(DynamicSealConfig::new(
+            &self.config,
+            dynamic_config,
+        )));
+        self
     }
 
     #[cfg(test)]
@@ -157,7 +206,22 @@ impl SequencerSealer {
         config: StateKeeperConfig,
         sealers: Vec<Box<dyn SealCriterion>>,
     ) -> Self {
-        Self { config, sealers }
+        Self {
+            config: Arc::new(config),
+            dynamic_config: None,
+            sealers,
+        }
+    }
+
+    /// Returns the config with dynamic seal criteria params applied.
+    fn effective_config(&self) -> Arc<StateKeeperConfig> {
+        match &self.dynamic_config {
+            Some(dynamic_config) => dynamic_config
+                .lock()
+                .expect("dynamic seal config is poisoned")
+                .get(&self.config),
+            None => self.config.clone(),
+        }
     }
 
     fn default_sealers(config: &StateKeeperConfig) -> Vec<Box<dyn SealCriterion>> {
@@ -213,3 +277,47 @@ impl ConditionalSealer for NoopSealer {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::configs::dynamic::DynamicSealCriteriaConfig;
+
+    use super::*;
+
+    #[test]
+    fn sealer_uses_updated_dynamic_config() {
+        let config = StateKeeperConfig {
+            transaction_slots: 2,
+            ..StateKeeperConfig::for_tests()
+        };
+        let (dynamic_config_sender, dynamic_config) = watch::channel(DynamicConfig {
+            state_keeper: Some(DynamicSealCriteriaConfig::from(&config)),
+            ..DynamicConfig::default()
+        });
+        let sealer =
+            SequencerSealer::with_sealers(config, vec![Box::new(criteria::SlotsCriterion)])
+                .with_dynamic_config(dynamic_config);
+        let should_seal = |tx_count| {
+            sealer.should_seal_l1_batch(
+                1,
+                tx_count,
+                0,
+                &SealData::default(),
+                &SealData::default(),
+                ProtocolVersionId::latest(),
+            )
+        };
+
+        assert_eq!(should_seal(1), SealResolution::NoSeal);
+        assert_eq!(should_seal(2), SealResolution::IncludeAndSeal);
+        let cached_config = sealer.effective_config();
+        assert!(Arc::ptr_eq(&cached_config, &sealer.effective_config()));
+
+        dynamic_config_sender.send_modify(|config| {
+            config.state_keeper.as_mut().unwrap().transaction_slots = 3;
+        });
+        assert_eq!(should_seal(2), SealResolution::NoSeal);
+        assert_eq!(should_seal(3), SealResolution::IncludeAndSeal);
+        assert_eq!(sealer.effective_config().transaction_slots, 3);
+    }
+}
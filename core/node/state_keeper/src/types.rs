@@ -5,7 +5,9 @@ use std::{
 
 use zksync_dal::{Connection, Core, CoreDal};
 use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore};
-use zksync_types::{Address, Nonce, PriorityOpId, Transaction, TransactionTimeRangeConstraint};
+use zksync_types::{
+    l2::L2Tx, Address, Nonce, PriorityOpId, Transaction, TransactionTimeRangeConstraint, H256,
+};
 
 use super::metrics::StateKeeperGauges;
 
@@ -76,6 +78,13 @@ impl MempoolGuard {
             .rollback(rejected)
     }
 
+    pub fn remove_l2_transaction(&self, tx_hash: H256) -> Option<L2Tx> {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .remove_l2_transaction(tx_hash)
+    }
+
     pub fn get_mempool_info(&mut self) -> MempoolInfo {
         self.0
             .lock()
@@ -0,0 +1,450 @@
+//! Verification of TEE attestation quotes and proof signatures.
+
+use std::{
+    collections::HashSet,
+    fmt, fs,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use dcap_qvl::{
+    quote::{Report, TDReport10},
+    QuoteCollateralV3,
+};
+use secp256k1::{
+    ecdsa::{RecoverableSignature, RecoveryId},
+    Message, PublicKey, SECP256K1,
+};
+use zksync_config::configs::TeeAttestationVerificationConfig;
+use zksync_types::{tee_types::TeeType, web3::keccak256, Address, H256};
+
+use crate::metrics::RejectionReason;
+
+/// TCB status of an attested platform that is always accepted.
+const UP_TO_DATE_TCB_STATUS: &str = "UpToDate";
+/// Length of a TDX measurement register.
+const TDX_MEASUREMENT_LEN: usize = 48;
+
+/// Measurements of the attested TEE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum QuoteMeasurements {
+    Sgx {
+        mr_enclave: [u8; 32],
+        mr_signer: [u8; 32],
+    },
+    Tdx {
+        mr_td: [u8; TDX_MEASUREMENT_LEN],
+        rtmrs: [[u8; TDX_MEASUREMENT_LEN]; 4],
+    },
+}
+
+impl QuoteMeasurements {
+    fn tee_type(&self) -> TeeType {
+        match self {
+            Self::Sgx { .. } => TeeType::Sgx,
+            Self::Tdx { .. } => TeeType::Tdx,
+        }
+    }
+
+    fn from_tdx_report(report: &TDReport10) -> Self {
+        Self::Tdx {
+            mr_td: report.mr_td,
+            rtmrs: [report.rt_mr0, report.rt_mr1, report.rt_mr2, report.rt_mr3],
+        }
+    }
+}
+
+/// Contents of a cryptographically verified attestation quote.
+#[derive(Debug, Clone)]
+pub(crate) struct VerifiedQuote {
+    pub tcb_status: String,
+    pub measurements: QuoteMeasurements,
+    pub report_data: [u8; 64],
+}
+
+/// Cryptographic verification of attestation quotes (i.e., checking the quote signature and the certificate chain
+/// up to the Intel root CA).
+pub(crate) trait QuoteVerifier: fmt::Debug + Send + Sync {
+    /// Verifies the quote at the specified Unix timestamp (in seconds).
+    fn verify_quote(&self, quote: &[u8], now: u64) -> anyhow::Result<VerifiedQuote>;
+}
+
+/// [`QuoteVerifier`] for SGX and TDX DCAP quotes using pre-fetched collateral, so that no requests to Intel PCS
+/// are required.
+#[derive(Debug)]
+struct DcapQuoteVerifier {
+    collaterals: Vec<QuoteCollateralV3>,
+}
+
+impl DcapQuoteVerifier {
+    fn load(path: &str) -> anyhow::Result<Self> {
+        let collaterals = fs::read_to_string(path)
+            .with_context(|| format!("failed reading quote collateral from `{path}`"))?;
+        let collaterals: Vec<QuoteCollateralV3> = serde_json::from_str(&collaterals)
+            .with_context(|| format!("failed parsing quote collateral from `{path}`"))?;
+        anyhow::ensure!(
+            !collaterals.is_empty(),
+            "no quote collateral provided in `{path}`"
+        );
+        Ok(Self { collaterals })
+    }
+}
+
+impl QuoteVerifier for DcapQuoteVerifier {
+    fn verify_quote(&self, quote: &[u8], now: u64) -> anyhow::Result<VerifiedQuote> {
+        // Collateral is platform-specific; the collateral for a different platform will fail verification.
+        let mut last_err = None;
+        for collateral in &self.collaterals {
+            let verified = match dcap_qvl::verify::verify(quote, collateral, now) {
+                Ok(verified) => verified,
+                Err(err) => {
+                    last_err = Some(anyhow::anyhow!("{err:?}"));
+                    continue;
+                }
+            };
+            let (measurements, report_data) = match &verified.report {
+                Report::SgxEnclave(report) => {
+                    let measurements = QuoteMeasurements::Sgx {
+                        mr_enclave: report.mr_enclave,
+                        mr_signer: report.mr_signer,
+                    };
+                    (measurements, report.report_data)
+                }
+                Report::TD10(report) => (
+                    QuoteMeasurements::from_tdx_report(report),
+                    report.report_data,
+                ),
+                Report::TD15(report) => (
+                    QuoteMeasurements::from_tdx_report(&report.base),
+                    report.base.report_data,
+                ),
+            };
+            return Ok(VerifiedQuote {
+                tcb_status: verified.status,
+                measurements,
+                report_data,
+            });
+        }
+        Err(last_err.expect("no collateral"))
+    }
+}
+
+/// Reasons to reject a submitted attestation or proof.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum VerificationError {
+    #[error("invalid attestation quote: {0:#}")]
+    InvalidQuote(anyhow::Error),
+    #[error("TCB status `{0}` of the attested platform is not allowed")]
+    DisallowedTcbStatus(String),
+    #[error("measurements of the attested TEE are not trusted")]
+    UntrustedMeasurements,
+    #[error("attestation report data doesn't commit to the public key")]
+    PubkeyMismatch,
+    #[error("no attestation is registered for the public key")]
+    UnattestedPubkey,
+    #[error("proof is submitted for {proof} TEE, but the attestation is for {attestation} TEE")]
+    TeeTypeMismatch {
+        proof: TeeType,
+        attestation: TeeType,
+    },
+    #[error("invalid proof signature: {0}")]
+    InvalidSignature(String),
+    #[error("proof root hash {proof:?} differs from the L1 batch root hash {expected:?}")]
+    RootHashMismatch { proof: H256, expected: H256 },
+}
+
+impl VerificationError {
+    pub fn reason(&self) -> RejectionReason {
+        match self {
+            Self::InvalidQuote(_) => RejectionReason::InvalidQuote,
+            Self::DisallowedTcbStatus(_) => RejectionReason::DisallowedTcbStatus,
+            Self::UntrustedMeasurements => RejectionReason::UntrustedMeasurements,
+            Self::PubkeyMismatch => RejectionReason::PubkeyMismatch,
+            Self::UnattestedPubkey => RejectionReason::UnattestedPubkey,
+            Self::TeeTypeMismatch { .. } => RejectionReason::TeeTypeMismatch,
+            Self::InvalidSignature(_) => RejectionReason::InvalidSignature,
+            Self::RootHashMismatch { .. } => RejectionReason::RootHashMismatch,
+        }
+    }
+}
+
+fn parse_hex_list<const N: usize>(
+    values: &[String],
+    name: &str,
+) -> anyhow::Result<HashSet<[u8; N]>> {
+    values
+        .iter()
+        .map(|value| {
+            let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+                .with_context(|| format!("invalid hex in `{name}`: {value}"))?;
+            <[u8; N]>::try_from(bytes).map_err(|bytes| {
+                anyhow::anyhow!(
+                    "invalid length of `{name}` entry {value}: expected {N} bytes, got {}",
+                    bytes.len()
+                )
+            })
+        })
+        .collect()
+}
+
+/// Verifies TEE attestations against the trusted measurements, and proof signatures against attested keys.
+///
+/// The attestation quote must commit to the public key used to sign proofs: the first 20 bytes of the report data
+/// must be the Ethereum address corresponding to the key.
+#[derive(Debug)]
+pub(crate) struct AttestationVerifier {
+    quote_verifier: Arc<dyn QuoteVerifier>,
+    trusted_mrenclaves: HashSet<[u8; 32]>,
+    trusted_mrsigners: HashSet<[u8; 32]>,
+    trusted_tdx_measurements: HashSet<[u8; 5 * TDX_MEASUREMENT_LEN]>,
+    allowed_tcb_levels: HashSet<String>,
+}
+
+impl AttestationVerifier {
+    pub fn new(config: &TeeAttestationVerificationConfig) -> anyhow::Result<Self> {
+        let quote_verifier = DcapQuoteVerifier::load(&config.collateral_path)?;
+        Self::with_quote_verifier(config, Arc::new(quote_verifier))
+    }
+
+    pub(crate) fn with_quote_verifier(
+        config: &TeeAttestationVerificationConfig,
+        quote_verifier: Arc<dyn QuoteVerifier>,
+    ) -> anyhow::Result<Self> {
+        let mut allowed_tcb_levels: HashSet<_> =
+            config.allowed_tcb_levels.iter().cloned().collect();
+        allowed_tcb_levels.insert(UP_TO_DATE_TCB_STATUS.to_owned());
+        Ok(Self {
+            quote_verifier,
+            trusted_mrenclaves: parse_hex_list(&config.sgx_mrenclaves, "sgx_mrenclaves")?,
+            trusted_mrsigners: parse_hex_list(&config.sgx_mrsigners, "sgx_mrsigners")?,
+            trusted_tdx_measurements: parse_hex_list(&config.tdx_measurements, "tdx_measurements")?,
+            allowed_tcb_levels,
+        })
+    }
+
+    fn is_trusted(&self, measurements: &QuoteMeasurements) -> bool {
+        match measurements {
+            QuoteMeasurements::Sgx {
+                mr_enclave,
+                mr_signer,
+            } => {
+                self.trusted_mrenclaves.contains(mr_enclave)
+                    || self.trusted_mrsigners.contains(mr_signer)
+            }
+            QuoteMeasurements::Tdx { mr_td, rtmrs } => {
+                let mut concatenated = [0_u8; 5 * TDX_MEASUREMENT_LEN];
+                let registers = std::iter::once(mr_td).chain(rtmrs);
+                for (chunk, register) in concatenated.chunks_mut(TDX_MEASUREMENT_LEN).zip(registers)
+                {
+                    chunk.copy_from_slice(register);
+                }
+                self.trusted_tdx_measurements.contains(&concatenated)
+            }
+        }
+    }
+
+    /// Verifies the attestation quote for the specified public key. Returns the type of the attested TEE.
+    pub fn verify_attestation(
+        &self,
+        quote: &[u8],
+        pubkey: &[u8],
+    ) -> Result<TeeType, VerificationError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("incorrect system time")
+            .as_secs();
+        let verified = self
+            .quote_verifier
+            .verify_quote(quote, now)
+            .map_err(VerificationError::InvalidQuote)?;
+
+        if !self.allowed_tcb_levels.contains(&verified.tcb_status) {
+            return Err(VerificationError::DisallowedTcbStatus(verified.tcb_status));
+        }
+        if !self.is_trusted(&verified.measurements) {
+            return Err(VerificationError::UntrustedMeasurements);
+        }
+        let pubkey =
+            PublicKey::from_slice(pubkey).map_err(|_| VerificationError::PubkeyMismatch)?;
+        if verified.report_data[..20] != *pubkey_to_address(&pubkey).as_bytes() {
+            return Err(VerificationError::PubkeyMismatch);
+        }
+        Ok(verified.measurements.tee_type())
+    }
+}
+
+fn pubkey_to_address(pubkey: &PublicKey) -> Address {
+    let hash = keccak256(&pubkey.serialize_uncompressed()[1..]);
+    Address::from_slice(&hash[12..])
+}
+
+/// Verifies that `signature` (65 bytes, RSV with V in Electrum notation) is a signature of `root_hash`
+/// by `pubkey`.
+pub(crate) fn verify_proof_signature(
+    pubkey: &[u8],
+    signature: &[u8],
+    root_hash: &[u8],
+) -> Result<(), VerificationError> {
+    let to_error = |err: secp256k1::Error| VerificationError::InvalidSignature(err.to_string());
+
+    let pubkey = PublicKey::from_slice(pubkey).map_err(to_error)?;
+    let message = Message::from_slice(root_hash).map_err(to_error)?;
+    let [compact @ .., v] = signature else {
+        return Err(VerificationError::InvalidSignature(
+            "empty signature".to_owned(),
+        ));
+    };
+    if compact.len() != 64 || *v < 27 {
+        return Err(VerificationError::InvalidSignature(format!(
+            "expected 65-byte RSV signature, got {} bytes",
+            signature.len()
+        )));
+    }
+    let recovery_id = RecoveryId::from_i32(i32::from(*v - 27)).map_err(to_error)?;
+    let signature = RecoverableSignature::from_compact(compact, recovery_id).map_err(to_error)?;
+    let recovered = SECP256K1
+        .recover_ecdsa(&message, &signature)
+        .map_err(to_error)?;
+    if recovered == pubkey {
+        Ok(())
+    } else {
+        Err(VerificationError::InvalidSignature(
+            "signature is not produced by the attested key".to_owned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod testonly {
+    use secp256k1::SecretKey;
+
+    use super::*;
+
+    pub(crate) const TRUSTED_MRENCLAVE: [u8; 32] = [0x11; 32];
+
+    /// Mock quote verifier accepting quotes of the form `report_data || mrenclave`.
+    #[derive(Debug)]
+    pub(crate) struct MockQuoteVerifier;
+
+    impl QuoteVerifier for MockQuoteVerifier {
+        fn verify_quote(&self, quote: &[u8], _now: u64) -> anyhow::Result<VerifiedQuote> {
+            anyhow::ensure!(quote.len() == 96, "malformed quote");
+            Ok(VerifiedQuote {
+                tcb_status: UP_TO_DATE_TCB_STATUS.to_owned(),
+                measurements: QuoteMeasurements::Sgx {
+                    mr_enclave: quote[64..].try_into().unwrap(),
+                    mr_signer: [0; 32],
+                },
+                report_data: quote[..64].try_into().unwrap(),
+            })
+        }
+    }
+
+    pub(crate) fn mock_verifier() -> AttestationVerifier {
+        let config = TeeAttestationVerificationConfig {
+            collateral_path: String::new(),
+            sgx_mrenclaves: vec![hex::encode(TRUSTED_MRENCLAVE)],
+            sgx_mrsigners: vec![],
+            tdx_measurements: vec![],
+            allowed_tcb_levels: vec![],
+        };
+        AttestationVerifier::with_quote_verifier(&config, Arc::new(MockQuoteVerifier)).unwrap()
+    }
+
+    /// Creates a mock quote for the specified key.
+    pub(crate) fn mock_quote(secret_key: &SecretKey, mr_enclave: [u8; 32]) -> Vec<u8> {
+        let pubkey = PublicKey::from_secret_key(SECP256K1, secret_key);
+        let mut quote = vec![0; 96];
+        quote[..20].copy_from_slice(pubkey_to_address(&pubkey).as_bytes());
+        quote[64..].copy_from_slice(&mr_enclave);
+        quote
+    }
+
+    pub(crate) fn sign_root_hash(secret_key: &SecretKey, root_hash: H256) -> Vec<u8> {
+        let message = Message::from_slice(root_hash.as_bytes()).unwrap();
+        let signature = SECP256K1.sign_ecdsa_recoverable(&message, secret_key);
+        let (recovery_id, compact) = signature.serialize_compact();
+        let mut signature = compact.to_vec();
+        signature.push(recovery_id.to_i32() as u8 + 27);
+        signature
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use secp256k1::SecretKey;
+
+    use super::{testonly::*, *};
+
+    #[test]
+    fn verifying_attestation() {
+        let verifier = mock_verifier();
+        let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+        let pubkey = PublicKey::from_secret_key(SECP256K1, &secret_key).serialize();
+
+        let quote = mock_quote(&secret_key, TRUSTED_MRENCLAVE);
+        let tee_type = verifier.verify_attestation(&quote, &pubkey).unwrap();
+        assert_eq!(tee_type, TeeType::Sgx);
+
+        let err = verifier.verify_attestation(&[0; 10], &pubkey).unwrap_err();
+        assert_matches!(err, VerificationError::InvalidQuote(_));
+
+        let untrusted_quote = mock_quote(&secret_key, [0x22; 32]);
+        let err = verifier
+            .verify_attestation(&untrusted_quote, &pubkey)
+            .unwrap_err();
+        assert_matches!(err, VerificationError::UntrustedMeasurements);
+
+        let other_key = SecretKey::from_slice(&[2; 32]).unwrap();
+        let other_pubkey = PublicKey::from_secret_key(SECP256K1, &other_key).serialize();
+        let err = verifier
+            .verify_attestation(&quote, &other_pubkey)
+            .unwrap_err();
+        assert_matches!(err, VerificationError::PubkeyMismatch);
+    }
+
+    #[test]
+    fn checking_tdx_measurements() {
+        let mr_td = [1; TDX_MEASUREMENT_LEN];
+        let rtmrs = [[2; TDX_MEASUREMENT_LEN]; 4];
+        let mut trusted = mr_td.to_vec();
+        for rtmr in &rtmrs {
+            trusted.extend_from_slice(rtmr);
+        }
+        let config = TeeAttestationVerificationConfig {
+            collateral_path: String::new(),
+            sgx_mrenclaves: vec![],
+            sgx_mrsigners: vec![],
+            tdx_measurements: vec![format!("0x{}", hex::encode(trusted))],
+            allowed_tcb_levels: vec![],
+        };
+        let verifier =
+            AttestationVerifier::with_quote_verifier(&config, Arc::new(MockQuoteVerifier)).unwrap();
+
+        assert!(verifier.is_trusted(&QuoteMeasurements::Tdx { mr_td, rtmrs }));
+        let mut other_rtmrs = rtmrs;
+        other_rtmrs[3] = [3; TDX_MEASUREMENT_LEN];
+        assert!(!verifier.is_trusted(&QuoteMeasurements::Tdx {
+            mr_td,
+            rtmrs: other_rtmrs
+        }));
+    }
+
+    #[test]
+    fn verifying_proof_signature() {
+        let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+        let pubkey = PublicKey::from_secret_key(SECP256K1, &secret_key).serialize();
+        let root_hash = H256::repeat_byte(0x42);
+        let signature = sign_root_hash(&secret_key, root_hash);
+
+        verify_proof_signature(&pubkey, &signature, root_hash.as_bytes()).unwrap();
+
+        let err = verify_proof_signature(&pubkey, &signature, H256::zero().as_bytes()).unwrap_err();
+        assert_matches!(err, VerificationError::InvalidSignature(_));
+        let err =
+            verify_proof_signature(&pubkey, &signature[..64], root_hash.as_bytes()).unwrap_err();
+        assert_matches!(err, VerificationError::InvalidSignature(_));
+    }
+}
@@ -4,6 +4,9 @@ use axum::{
 };
 use zksync_dal::DalError;
 use zksync_object_store::ObjectStoreError;
+use zksync_types::L1BatchNumber;
+
+use crate::attestation::VerificationError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum TeeProcessorError {
@@ -16,6 +19,10 @@ pub enum TeeProcessorError {
     },
     #[error("Failed fetching/saving from db: {0}")]
     Dal(#[from] DalError),
+    #[error("Verification failed: {0}")]
+    Verification(#[from] VerificationError),
+    #[error("Root hash for L1 batch #{0} is not computed yet; retry later")]
+    RootHashNotReady(L1BatchNumber),
 }
 
 impl TeeProcessorError {
@@ -24,6 +31,8 @@ impl TeeProcessorError {
             Self::GeneralError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ObjectStore { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             Self::Dal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Verification(_) => StatusCode::BAD_REQUEST,
+            Self::RootHashNotReady(_) => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 }
@@ -1,7 +1,9 @@
 use std::{net::SocketAddr, sync::Arc};
 
 use anyhow::Context as _;
+use attestation::AttestationVerifier;
 use axum::{extract::Path, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+pub use quorum::TeeQuorumTracker;
 use tee_request_processor::TeeRequestProcessor;
 use tokio::sync::watch;
 use zksync_config::configs::TeeProofDataHandlerConfig;
@@ -12,9 +14,11 @@ use zksync_tee_prover_interface::api::{
 };
 use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 
+mod attestation;
 mod errors;
 mod metrics;
 pub mod node;
+mod quorum;
 mod tee_request_processor;
 #[cfg(test)]
 mod tests;
@@ -28,6 +32,16 @@ pub async fn run_server(
     mut stop_receiver: watch::Receiver<bool>,
 ) -> anyhow::Result<()> {
     let bind_address = SocketAddr::from(([0, 0, 0, 0], config.http_port));
+    let attestation_verifier = config
+        .attestation_verification
+        .as_ref()
+        .map(AttestationVerifier::new)
+        .transpose()
+        .context("failed initializing attestation verifier")?;
+    if attestation_verifier.is_none() {
+        tracing::warn!("TEE attestation verification is disabled; attestations and proofs are accepted without checks");
+    }
+
     tracing::info!("Starting proof data handler server on {bind_address}");
     let app = create_proof_processing_router(
         blob_store,
@@ -35,6 +49,7 @@ pub async fn run_server(
         config,
         commitment_mode,
         l2_chain_id,
+        attestation_verifier.map(Arc::new),
     );
 
     let listener = tokio::net::TcpListener::bind(bind_address)
@@ -59,9 +74,15 @@ fn create_proof_processing_router(
     config: TeeProofDataHandlerConfig,
     _commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    attestation_verifier: Option<Arc<AttestationVerifier>>,
 ) -> Router {
-    let get_tee_proof_gen_processor =
-        TeeRequestProcessor::new(blob_store, connection_pool, config.clone(), l2_chain_id);
+    let get_tee_proof_gen_processor = TeeRequestProcessor::new(
+        blob_store,
+        connection_pool,
+        config.clone(),
+        l2_chain_id,
+        attestation_verifier,
+    );
     let submit_tee_proof_processor = get_tee_proof_gen_processor.clone();
     let register_tee_attestation_processor = get_tee_proof_gen_processor.clone();
 
@@ -1,12 +1,44 @@
 use std::{fmt, time::Duration};
 
-use vise::{EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics, Unit};
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit};
 use zksync_types::tee_types::TeeType;
 
 #[derive(Debug, Metrics)]
 pub(super) struct TeeProofDataHandlerMetrics {
     #[metrics(buckets = vise::Buckets::LATENCIES, unit = Unit::Seconds)]
     pub tee_proof_roundtrip_time: Family<MetricsTeeType, Histogram<Duration>>,
+    /// Number of rejected attestations and proofs.
+    pub rejected_submissions: Family<RejectionLabels, Counter>,
+    /// Number of L1 batches marked as TEE-finalized.
+    pub tee_finalized_batches: Counter,
+    /// Number of the last TEE-finalized L1 batch.
+    pub last_tee_finalized_batch: Gauge<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum SubmissionKind {
+    Attestation,
+    Proof,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum RejectionReason {
+    InvalidQuote,
+    DisallowedTcbStatus,
+    UntrustedMeasurements,
+    PubkeyMismatch,
+    UnattestedPubkey,
+    TeeTypeMismatch,
+    InvalidSignature,
+    RootHashMismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub(crate) struct RejectionLabels {
+    pub kind: SubmissionKind,
+    pub reason: RejectionReason,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
@@ -14,6 +14,8 @@ use zksync_node_framework::{
 use zksync_object_store::{node::ObjectStoreResource, ObjectStore};
 use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 
+use crate::TeeQuorumTracker;
+
 /// Wiring layer for proof data handler server.
 #[derive(Debug)]
 pub struct TeeProofDataHandlerLayer {
@@ -32,6 +34,9 @@ pub struct Input {
 pub struct Output {
     #[context(task)]
     pub task: TeeProofDataHandlerTask,
+    /// Only present if the TEE quorum policy is configured.
+    #[context(task)]
+    pub quorum_tracker: Option<TeeQuorumTracker>,
 }
 
 impl TeeProofDataHandlerLayer {
@@ -60,6 +65,19 @@ impl WiringLayer for TeeProofDataHandlerLayer {
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let main_pool = input.master_pool.get().await?;
         let blob_store = input.object_store.0;
+        let quorum_tracker = self
+            .proof_data_handler_config
+            .quorum
+            .as_ref()
+            .map(|config| {
+                TeeQuorumTracker::new(
+                    main_pool.clone(),
+                    config,
+                    self.proof_data_handler_config.first_processed_batch,
+                )
+            })
+            .transpose()
+            .map_err(|err| WiringError::Configuration(format!("{err:#}")))?;
 
         let task = TeeProofDataHandlerTask {
             proof_data_handler_config: self.proof_data_handler_config,
@@ -69,7 +87,10 @@ impl WiringLayer for TeeProofDataHandlerLayer {
             l2_chain_id: self.l2_chain_id,
         };
 
-        Ok(Output { task })
+        Ok(Output {
+            task,
+            quorum_tracker,
+        })
     }
 }
 
@@ -100,3 +121,14 @@ impl Task for TeeProofDataHandlerTask {
         .await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for TeeQuorumTracker {
+    fn id(&self) -> TaskId {
+        "tee_quorum_tracker".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
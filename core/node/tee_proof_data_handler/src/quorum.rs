@@ -0,0 +1,265 @@
+//! Tracking of TEE-finalized L1 batches.
+
+use std::{collections::HashSet, time::Duration};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::watch;
+use zksync_config::configs::TeeQuorumConfig;
+use zksync_dal::{tee_proof_generation_dal::GeneratedTeeProof, ConnectionPool, Core, CoreDal};
+use zksync_types::{tee_types::TeeType, L1BatchNumber};
+
+use crate::metrics::METRICS;
+
+/// Proofs generated within this interval (in seconds) before the previous check are re-checked. This accounts
+/// for proofs committed to Postgres later than their `updated_at` timestamp.
+const RECHECK_INTERVAL_SECS: i64 = 60;
+
+/// Quorum policy for TEE proofs of an L1 batch.
+#[derive(Debug, Clone)]
+pub(crate) struct TeeQuorumPolicy {
+    required_tee_types: HashSet<TeeType>,
+    min_distinct_keys: usize,
+}
+
+/// Proofs of an L1 batch satisfying [`TeeQuorumPolicy`].
+#[derive(Debug, PartialEq)]
+pub(crate) struct TeeQuorum {
+    pub tee_types: Vec<TeeType>,
+    pub distinct_keys: usize,
+}
+
+impl TeeQuorumPolicy {
+    pub fn new(config: &TeeQuorumConfig) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.min_distinct_keys > 0 || !config.required_tee_types.is_empty(),
+            "TEE quorum policy must require at least one TEE type or one key"
+        );
+        Ok(Self {
+            required_tee_types: config.required_tee_types.iter().copied().collect(),
+            min_distinct_keys: config.min_distinct_keys,
+        })
+    }
+
+    /// Evaluates the policy for the proofs of a single L1 batch.
+    pub fn evaluate(&self, proofs: &[GeneratedTeeProof]) -> Option<TeeQuorum> {
+        let tee_types: HashSet<_> = proofs.iter().map(|proof| proof.tee_type).collect();
+        let distinct_keys = proofs
+            .iter()
+            .map(|proof| &proof.pubkey)
+            .collect::<HashSet<_>>()
+            .len();
+        let is_satisfied = !proofs.is_empty()
+            && tee_types.is_superset(&self.required_tee_types)
+            && distinct_keys >= self.min_distinct_keys;
+        is_satisfied.then(|| {
+            let mut tee_types: Vec<_> = tee_types.into_iter().collect();
+            tee_types.sort_unstable_by_key(|tee_type| tee_type.to_string());
+            TeeQuorum {
+                tee_types,
+                distinct_keys,
+            }
+        })
+    }
+}
+
+/// Background task marking L1 batches as TEE-finalized once their proofs satisfy the [`TeeQuorumPolicy`].
+#[derive(Debug)]
+pub struct TeeQuorumTracker {
+    pool: ConnectionPool<Core>,
+    policy: TeeQuorumPolicy,
+    first_processed_batch: L1BatchNumber,
+    poll_interval: Duration,
+    last_checked_at: Option<DateTime<Utc>>,
+}
+
+impl TeeQuorumTracker {
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        config: &TeeQuorumConfig,
+        first_processed_batch: L1BatchNumber,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            pool,
+            policy: TeeQuorumPolicy::new(config)?,
+            first_processed_batch,
+            poll_interval: config.poll_interval(),
+            last_checked_at: None,
+        })
+    }
+
+    /// Returns the number of newly finalized batches.
+    async fn run_once(&mut self) -> anyhow::Result<usize> {
+        let checked_at = Utc::now();
+        let mut connection = self.pool.connection_tagged("tee_quorum_tracker").await?;
+        let updated_since = self
+            .last_checked_at
+            .map(|timestamp| timestamp - chrono::Duration::seconds(RECHECK_INTERVAL_SECS));
+        let proofs = connection
+            .tee_proof_generation_dal()
+            .get_generated_proofs_for_unfinalized_batches(updated_since, self.first_processed_batch)
+            .await?;
+
+        let mut finalized_count = 0;
+        for batch_proofs in proofs.chunk_by(|a, b| a.l1_batch_number == b.l1_batch_number) {
+            let l1_batch_number = batch_proofs[0].l1_batch_number;
+            let Some(quorum) = self.policy.evaluate(batch_proofs) else {
+                continue;
+            };
+            let inserted = connection
+                .tee_proof_generation_dal()
+                .mark_l1_batch_tee_finalized(
+                    l1_batch_number,
+                    &quorum.tee_types,
+                    quorum.distinct_keys,
+                )
+                .await?;
+            if inserted {
+                tracing::info!(
+                    %l1_batch_number,
+                    tee_types = ?quorum.tee_types,
+                    distinct_keys = quorum.distinct_keys,
+                    "L1 batch is TEE-finalized"
+                );
+                finalized_count += 1;
+            }
+        }
+
+        if finalized_count > 0 {
+            METRICS.tee_finalized_batches.inc_by(finalized_count as u64);
+        }
+        let last_finalized_batch = connection
+            .tee_proof_generation_dal()
+            .get_last_tee_finalized_batch()
+            .await?;
+        if let Some(number) = last_finalized_batch {
+            METRICS.last_tee_finalized_batch.set(number.0.into());
+        }
+        self.last_checked_at = Some(checked_at);
+        Ok(finalized_count)
+    }
+
+    pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow() {
+            self.run_once().await?;
+            tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop request received, TEE quorum tracker is shutting down");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_dal::Connection;
+
+    use super::*;
+
+    fn proof(tee_type: TeeType, key: u8) -> GeneratedTeeProof {
+        GeneratedTeeProof {
+            l1_batch_number: L1BatchNumber(1),
+            tee_type,
+            pubkey: vec![key; 33],
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn policy(required_tee_types: Vec<TeeType>, min_distinct_keys: usize) -> TeeQuorumPolicy {
+        TeeQuorumPolicy::new(&TeeQuorumConfig {
+            required_tee_types,
+            min_distinct_keys,
+            poll_interval_ms: 100,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn evaluating_quorum_policy() {
+        let both_types = policy(vec![TeeType::Sgx, TeeType::Tdx], 1);
+        assert_eq!(both_types.evaluate(&[]), None);
+        assert_eq!(both_types.evaluate(&[proof(TeeType::Sgx, 1)]), None);
+        assert_eq!(
+            both_types.evaluate(&[proof(TeeType::Sgx, 1), proof(TeeType::Tdx, 2)]),
+            Some(TeeQuorum {
+                tee_types: vec![TeeType::Sgx, TeeType::Tdx],
+                distinct_keys: 2,
+            })
+        );
+
+        let two_keys = policy(vec![], 2);
+        assert_eq!(two_keys.evaluate(&[proof(TeeType::Sgx, 1)]), None);
+        assert_eq!(
+            two_keys.evaluate(&[proof(TeeType::Sgx, 1), proof(TeeType::Tdx, 1)]),
+            None
+        );
+        assert!(two_keys
+            .evaluate(&[proof(TeeType::Sgx, 1), proof(TeeType::Tdx, 2)])
+            .is_some());
+
+        TeeQuorumPolicy::new(&TeeQuorumConfig {
+            required_tee_types: vec![],
+            min_distinct_keys: 0,
+            poll_interval_ms: 100,
+        })
+        .unwrap_err();
+    }
+
+    async fn mock_proof(
+        storage: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+        tee_type: TeeType,
+        key: u8,
+    ) {
+        let pubkey = [key; 33];
+        let mut dal = storage.tee_proof_generation_dal();
+        dal.save_attestation(&pubkey, &[key]).await.unwrap();
+        dal.insert_tee_proof_generation_job(l1_batch_number, tee_type)
+            .await
+            .unwrap();
+        dal.save_proof_artifacts_metadata(l1_batch_number, tee_type, &pubkey, &[0; 65], &[0; 32])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn tracking_tee_finalized_batches() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let config = TeeQuorumConfig {
+            required_tee_types: vec![TeeType::Sgx, TeeType::Tdx],
+            min_distinct_keys: 2,
+            poll_interval_ms: 100,
+        };
+        let mut tracker = TeeQuorumTracker::new(pool.clone(), &config, L1BatchNumber(0)).unwrap();
+
+        mock_proof(&mut storage, L1BatchNumber(1), TeeType::Sgx, 1).await;
+        mock_proof(&mut storage, L1BatchNumber(2), TeeType::Sgx, 1).await;
+        mock_proof(&mut storage, L1BatchNumber(2), TeeType::Tdx, 2).await;
+        assert_eq!(tracker.run_once().await.unwrap(), 1);
+
+        let mut dal = storage.tee_proof_generation_dal();
+        assert_eq!(
+            dal.get_tee_finalized_batch(L1BatchNumber(1)).await.unwrap(),
+            None
+        );
+        let finalized = dal
+            .get_tee_finalized_batch(L1BatchNumber(2))
+            .await
+            .unwrap()
+            .expect("batch is not finalized");
+        assert_eq!(finalized.tee_types, [TeeType::Sgx, TeeType::Tdx]);
+        assert_eq!(finalized.distinct_keys, 2);
+
+        mock_proof(&mut storage, L1BatchNumber(1), TeeType::Tdx, 2).await;
+        assert_eq!(tracker.run_once().await.unwrap(), 1);
+        assert_eq!(tracker.run_once().await.unwrap(), 0);
+
+        let finalized_numbers = storage
+            .tee_proof_generation_dal()
+            .get_tee_finalized_batch_numbers(L1BatchNumber(0)..=L1BatchNumber(10))
+            .await
+            .unwrap();
+        assert_eq!(finalized_numbers, [L1BatchNumber(1), L1BatchNumber(2)]);
+    }
+}
@@ -19,10 +19,14 @@ use zksync_tee_prover_interface::{
     },
     inputs::{TeeVerifierInput, V1TeeVerifierInput},
 };
-use zksync_types::{tee_types::TeeType, L1BatchNumber, L2ChainId};
+use zksync_types::{tee_types::TeeType, L1BatchNumber, L2ChainId, H256};
 use zksync_vm_executor::storage::L1BatchParamsProvider;
 
-use crate::{errors::TeeProcessorError, metrics::METRICS};
+use crate::{
+    attestation::{verify_proof_signature, AttestationVerifier, VerificationError},
+    errors::TeeProcessorError,
+    metrics::{RejectionLabels, SubmissionKind, METRICS},
+};
 
 #[derive(Clone)]
 pub(crate) struct TeeRequestProcessor {
@@ -30,6 +34,7 @@ pub(crate) struct TeeRequestProcessor {
     pool: ConnectionPool<Core>,
     config: TeeProofDataHandlerConfig,
     l2_chain_id: L2ChainId,
+    attestation_verifier: Option<Arc<AttestationVerifier>>,
 }
 
 impl TeeRequestProcessor {
@@ -38,15 +43,65 @@ impl TeeRequestProcessor {
         pool: ConnectionPool<Core>,
         config: TeeProofDataHandlerConfig,
         l2_chain_id: L2ChainId,
+        attestation_verifier: Option<Arc<AttestationVerifier>>,
     ) -> Self {
         Self {
             blob_store,
             pool,
             config,
             l2_chain_id,
+            attestation_verifier,
         }
     }
 
+    fn reject(kind: SubmissionKind, err: VerificationError) -> TeeProcessorError {
+        tracing::warn!("Rejected {kind:?} submission: {err}");
+        let labels = RejectionLabels {
+            kind,
+            reason: err.reason(),
+        };
+        METRICS.rejected_submissions[&labels].inc();
+        err.into()
+    }
+
+    /// Checks that the proof is signed by an attested key of the matching TEE type, and that it matches
+    /// the L1 batch root hash. If the root hash is not computed yet, the prover is asked to retry later.
+    async fn verify_proof(
+        &self,
+        verifier: &AttestationVerifier,
+        l1_batch_number: L1BatchNumber,
+        proof: &SubmitTeeProofRequest,
+    ) -> Result<(), TeeProcessorError> {
+        let proof = &proof.0;
+        let mut connection = self.pool.connection_tagged("tee_request_processor").await?;
+        let attestation = connection
+            .tee_proof_generation_dal()
+            .get_attestation(&proof.pubkey)
+            .await?
+            .ok_or(VerificationError::UnattestedPubkey)?;
+        let attested_tee_type = verifier.verify_attestation(&attestation, &proof.pubkey)?;
+        if attested_tee_type != proof.tee_type {
+            return Err(VerificationError::TeeTypeMismatch {
+                proof: proof.tee_type,
+                attestation: attested_tee_type,
+            }
+            .into());
+        }
+        verify_proof_signature(&proof.pubkey, &proof.signature, &proof.proof)?;
+
+        let expected = connection
+            .blocks_dal()
+            .get_l1_batch_state_root(l1_batch_number)
+            .await?
+            .ok_or(TeeProcessorError::RootHashNotReady(l1_batch_number))?;
+        // The signature check above guarantees that the proof is 32 bytes long.
+        let proof = H256::from_slice(&proof.proof);
+        if proof != expected {
+            return Err(VerificationError::RootHashMismatch { proof, expected }.into());
+        }
+        Ok(())
+    }
+
     pub(crate) async fn get_proof_generation_data(
         &self,
         request: Json<TeeProofGenerationDataRequest>,
@@ -222,6 +277,15 @@ impl TeeRequestProcessor {
         Json(proof): Json<SubmitTeeProofRequest>,
     ) -> Result<Json<SubmitTeeProofResponse>, TeeProcessorError> {
         let l1_batch_number = L1BatchNumber(l1_batch_number);
+        if let Some(verifier) = &self.attestation_verifier {
+            match self.verify_proof(verifier, l1_batch_number, &proof).await {
+                Err(TeeProcessorError::Verification(err)) => {
+                    return Err(Self::reject(SubmissionKind::Proof, err));
+                }
+                res => res?,
+            }
+        }
+
         let mut connection = self.pool.connection_tagged("tee_request_processor").await?;
         let mut dal = connection.tee_proof_generation_dal();
         dal.save_proof_artifacts_metadata(
@@ -263,6 +327,12 @@ impl TeeRequestProcessor {
     ) -> Result<Json<RegisterTeeAttestationResponse>, TeeProcessorError> {
         tracing::info!("Received attestation: {:?}", payload);
 
+        if let Some(verifier) = &self.attestation_verifier {
+            verifier
+                .verify_attestation(&payload.attestation, &payload.pubkey)
+                .map_err(|err| Self::reject(SubmissionKind::Attestation, err))?;
+        }
+
         let mut connection = self.pool.connection_tagged("tee_request_processor").await?;
         let mut dal = connection.tee_proof_generation_dal();
 
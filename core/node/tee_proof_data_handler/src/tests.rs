@@ -1,20 +1,30 @@
+use std::sync::Arc;
+
 use axum::{
     body::Body,
     http::{self, Method, Request, StatusCode},
     response::Response,
     Router,
 };
+use secp256k1::{PublicKey, SecretKey, SECP256K1};
 use serde_json::json;
 use tower::ServiceExt;
 use zksync_config::configs::TeeProofDataHandlerConfig;
 use zksync_dal::{ConnectionPool, CoreDal};
 use zksync_object_store::MockObjectStore;
-use zksync_tee_prover_interface::api::SubmitTeeProofRequest;
+use zksync_tee_prover_interface::{
+    api::{RegisterTeeAttestationRequest, SubmitTeeProofRequest},
+    outputs::L1BatchTeeProofForL1,
+};
 use zksync_types::{
-    commitment::L1BatchCommitmentMode, tee_types::TeeType, L1BatchNumber, L2ChainId,
+    block::L1BatchHeader, commitment::L1BatchCommitmentMode, protocol_upgrade::ProtocolVersion,
+    tee_types::TeeType, L1BatchNumber, L2ChainId, ProtocolVersionId, H256,
 };
 
-use crate::create_proof_processing_router;
+use crate::{
+    attestation::testonly::{mock_quote, mock_verifier, sign_root_hash, TRUSTED_MRENCLAVE},
+    create_proof_processing_router,
+};
 
 #[tokio::test]
 async fn request_tee_proof_inputs() {
@@ -28,9 +38,12 @@ async fn request_tee_proof_inputs() {
             first_processed_batch: L1BatchNumber(0),
             proof_generation_timeout_in_secs: 600,
             batch_permanently_ignored_timeout_in_hours: 10 * 24,
+            attestation_verification: None,
+            quorum: None,
         },
         L1BatchCommitmentMode::Rollup,
         L2ChainId::default(),
+        None,
     );
     let test_cases = vec![
         (json!({ "tee_type": "sgx" }), StatusCode::NO_CONTENT),
@@ -84,9 +97,12 @@ async fn submit_tee_proof() {
             first_processed_batch: L1BatchNumber(0),
             proof_generation_timeout_in_secs: 600,
             batch_permanently_ignored_timeout_in_hours: 10 * 24,
+            attestation_verification: None,
+            quorum: None,
         },
         L1BatchCommitmentMode::Rollup,
         L2ChainId::default(),
+        None,
     );
 
     // this should fail because we haven't saved the attestation for the pubkey yet
@@ -141,6 +157,211 @@ async fn submit_tee_proof() {
     assert_eq!(proof.pubkey.as_ref().unwrap(), &tee_proof_request.0.pubkey);
 }
 
+fn router_with_verification(db_conn_pool: ConnectionPool<zksync_dal::Core>) -> Router {
+    create_proof_processing_router(
+        MockObjectStore::arc(),
+        db_conn_pool,
+        TeeProofDataHandlerConfig {
+            http_port: 1337,
+            first_processed_batch: L1BatchNumber(0),
+            proof_generation_timeout_in_secs: 600,
+            batch_permanently_ignored_timeout_in_hours: 10 * 24,
+            attestation_verification: None,
+            quorum: None,
+        },
+        L1BatchCommitmentMode::Rollup,
+        L2ChainId::default(),
+        Some(Arc::new(mock_verifier())),
+    )
+}
+
+async fn register_attestation(app: &Router, request: &RegisterTeeAttestationRequest) -> Response {
+    let req_body = Body::from(serde_json::to_vec(request).unwrap());
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::POST)
+                .uri("/tee/register_attestation")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(req_body)
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn rejecting_invalid_attestations() {
+    let db_conn_pool = ConnectionPool::test_pool().await;
+    let app = router_with_verification(db_conn_pool.clone());
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+    let pubkey = PublicKey::from_secret_key(SECP256K1, &secret_key)
+        .serialize()
+        .to_vec();
+
+    let invalid_attestations = [
+        vec![1, 2, 3],
+        mock_quote(&secret_key, [0x22; 32]),
+        mock_quote(&SecretKey::from_slice(&[2; 32]).unwrap(), TRUSTED_MRENCLAVE),
+    ];
+    for attestation in invalid_attestations {
+        let request = RegisterTeeAttestationRequest {
+            attestation,
+            pubkey: pubkey.clone(),
+        };
+        let response = register_attestation(&app, &request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let mut storage = db_conn_pool.connection().await.unwrap();
+    let attestation = storage
+        .tee_proof_generation_dal()
+        .get_attestation(&pubkey)
+        .await
+        .unwrap();
+    assert!(attestation.is_none());
+}
+
+#[tokio::test]
+async fn submitting_verified_tee_proof() {
+    let batch_number = L1BatchNumber(1);
+    let db_conn_pool = ConnectionPool::test_pool().await;
+    mock_tee_batch_status(db_conn_pool.clone(), batch_number).await;
+    let root_hash = H256::repeat_byte(0x42);
+    mock_l1_batch_root_hash(db_conn_pool.clone(), batch_number, root_hash).await;
+    let app = router_with_verification(db_conn_pool.clone());
+    let uri = format!("/tee/submit_proofs/{}", batch_number.0);
+
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+    let pubkey = PublicKey::from_secret_key(SECP256K1, &secret_key)
+        .serialize()
+        .to_vec();
+    let create_request = |signed_hash: H256, tee_type: TeeType| {
+        SubmitTeeProofRequest(Box::new(L1BatchTeeProofForL1 {
+            signature: sign_root_hash(&secret_key, signed_hash),
+            pubkey: pubkey.clone(),
+            proof: root_hash.as_bytes().to_vec(),
+            tee_type,
+        }))
+    };
+    let proof_request = create_request(root_hash, TeeType::Sgx);
+
+    // The key is not attested yet.
+    let response = send_submit_tee_proof_request(&app, &uri, &proof_request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let request = RegisterTeeAttestationRequest {
+        attestation: mock_quote(&secret_key, TRUSTED_MRENCLAVE),
+        pubkey: pubkey.clone(),
+    };
+    let response = register_attestation(&app, &request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Proofs with a signature for another root hash or with a mismatched TEE type must be rejected.
+    let invalid_request = create_request(H256::zero(), TeeType::Sgx);
+    let response = send_submit_tee_proof_request(&app, &uri, &invalid_request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let invalid_request = create_request(root_hash, TeeType::Tdx);
+    let response = send_submit_tee_proof_request(&app, &uri, &invalid_request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_submit_tee_proof_request(&app, &uri, &proof_request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let mut storage = db_conn_pool.connection().await.unwrap();
+    let proofs = storage
+        .tee_proof_generation_dal()
+        .get_tee_proofs(batch_number, Some(TeeType::Sgx))
+        .await
+        .unwrap();
+    assert_eq!(proofs.len(), 1);
+    assert_eq!(proofs[0].proof.as_deref(), Some(root_hash.as_bytes()));
+}
+
+#[tokio::test]
+async fn proofs_for_batches_without_root_hash_are_not_accepted() {
+    let batch_number = L1BatchNumber(1);
+    let db_conn_pool = ConnectionPool::test_pool().await;
+    mock_tee_batch_status(db_conn_pool.clone(), batch_number).await;
+    let app = router_with_verification(db_conn_pool.clone());
+    let uri = format!("/tee/submit_proofs/{}", batch_number.0);
+
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+    let pubkey = PublicKey::from_secret_key(SECP256K1, &secret_key)
+        .serialize()
+        .to_vec();
+    let request = RegisterTeeAttestationRequest {
+        attestation: mock_quote(&secret_key, TRUSTED_MRENCLAVE),
+        pubkey: pubkey.clone(),
+    };
+    let response = register_attestation(&app, &request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let root_hash = H256::repeat_byte(0x42);
+    let proof_request = SubmitTeeProofRequest(Box::new(L1BatchTeeProofForL1 {
+        signature: sign_root_hash(&secret_key, root_hash),
+        pubkey,
+        proof: root_hash.as_bytes().to_vec(),
+        tee_type: TeeType::Sgx,
+    }));
+
+    // The root hash of the batch is not computed yet, so the proof cannot be checked.
+    let response = send_submit_tee_proof_request(&app, &uri, &proof_request).await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let mut storage = db_conn_pool.connection().await.unwrap();
+    let proofs = storage
+        .tee_proof_generation_dal()
+        .get_tee_proofs(batch_number, Some(TeeType::Sgx))
+        .await
+        .unwrap();
+    assert!(
+        proofs.iter().all(|proof| proof.proof.is_none()),
+        "{proofs:?}"
+    );
+    drop(storage);
+
+    mock_l1_batch_root_hash(db_conn_pool.clone(), batch_number, root_hash).await;
+    let response = send_submit_tee_proof_request(&app, &uri, &proof_request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut storage = db_conn_pool.connection().await.unwrap();
+    let proofs = storage
+        .tee_proof_generation_dal()
+        .get_tee_proofs(batch_number, Some(TeeType::Sgx))
+        .await
+        .unwrap();
+    assert_eq!(proofs.len(), 1);
+    assert_eq!(proofs[0].proof.as_deref(), Some(root_hash.as_bytes()));
+}
+
+async fn mock_l1_batch_root_hash(
+    db_conn_pool: ConnectionPool<zksync_dal::Core>,
+    batch_number: L1BatchNumber,
+    root_hash: H256,
+) {
+    let mut storage = db_conn_pool.connection().await.unwrap();
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    let header = L1BatchHeader::new(
+        batch_number,
+        batch_number.0.into(),
+        Default::default(),
+        ProtocolVersionId::latest(),
+    );
+    storage
+        .blocks_dal()
+        .insert_mock_l1_batch(&header)
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .set_l1_batch_hash(batch_number, root_hash)
+        .await
+        .unwrap();
+}
+
 // Mock SQL db with information about the status of the TEE proof generation
 async fn mock_tee_batch_status(
     db_conn_pool: ConnectionPool<zksync_dal::Core>,
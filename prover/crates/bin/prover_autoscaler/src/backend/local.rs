@@ -0,0 +1,502 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use futures::future;
+use nix::{
+    sys::signal::{self, Signal},
+    unistd::Pid,
+};
+use tokio::{
+    process::{Child, Command},
+    sync::{watch, Mutex},
+    time::interval,
+};
+
+use super::Backend;
+use crate::{
+    cluster_types::{
+        Cluster, ClusterName, Deployment, DeploymentName, Namespace, NamespaceName, Pod,
+    },
+    config::{LocalBackendConfig, LocalDeploymentConfig},
+};
+
+struct Worker {
+    child: Child,
+    started: DateTime<Utc>,
+}
+
+impl Worker {
+    /// Sends SIGTERM to the worker and kills it if it doesn't exit within `grace_period`.
+    async fn stop(mut self, grace_period: Duration, namespace: &NamespaceName, pod_name: &str) {
+        // `id()` returns `None` if the worker has already exited.
+        if let Some(pid) = self.child.id() {
+            match signal::kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
+                Ok(()) => {
+                    match tokio::time::timeout(grace_period, self.child.wait()).await {
+                        Ok(Ok(status)) => {
+                            tracing::info!("Stopped worker {namespace}/{pod_name}: {status}");
+                            return;
+                        }
+                        Ok(Err(err)) => {
+                            tracing::warn!("Failed to wait for worker {namespace}/{pod_name}: {err}")
+                        }
+                        Err(_) => tracing::warn!(
+                            "Worker {namespace}/{pod_name} didn't exit within {grace_period:?} after SIGTERM, killing it"
+                        ),
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("Failed to send SIGTERM to worker {namespace}/{pod_name}: {err}")
+                }
+            }
+        }
+
+        match self.child.kill().await {
+            Ok(()) => tracing::info!("Killed worker {namespace}/{pod_name}"),
+            Err(err) => tracing::warn!("Failed to kill worker {namespace}/{pod_name}: {err}"),
+        }
+    }
+}
+
+struct LocalDeployment {
+    config: LocalDeploymentConfig,
+    desired: usize,
+    /// Time of the last change of desired replicas.
+    scaled: DateTime<Utc>,
+    /// Running workers by replica index.
+    workers: BTreeMap<usize, Worker>,
+}
+
+fn pod_name(name: &DeploymentName, replica: usize) -> String {
+    format!("{name}-local-{replica}")
+}
+
+impl LocalDeployment {
+    fn new(config: LocalDeploymentConfig) -> Self {
+        Self {
+            config,
+            desired: 0,
+            scaled: Utc::now(),
+            workers: BTreeMap::new(),
+        }
+    }
+
+    fn max_replicas(&self) -> usize {
+        self.config.max_replicas.unwrap_or(usize::MAX)
+    }
+
+    fn command(&self, namespace: &NamespaceName, name: &DeploymentName, replica: usize) -> Command {
+        let substitute = |s: &str| {
+            s.replace("{namespace}", namespace.to_str())
+                .replace("{deployment}", name.to_str())
+                .replace("{replica}", &replica.to_string())
+        };
+        let mut args = self.config.command.iter().map(|arg| substitute(arg));
+        // Command is checked to be non-empty in `LocalBackend::new()`.
+        let mut command = Command::new(args.next().unwrap_or_default());
+        command
+            .args(args)
+            .envs(self.config.env.iter().map(|(k, v)| (k, substitute(v))))
+            .kill_on_drop(true);
+        command
+    }
+
+    /// Removes exited workers.
+    fn reap(&mut self, namespace: &NamespaceName, name: &DeploymentName) {
+        self.workers
+            .retain(|replica, worker| match worker.child.try_wait() {
+                Ok(None) => true,
+                Ok(Some(status)) => {
+                    tracing::warn!(
+                        "Worker {}/{} exited: {}",
+                        namespace,
+                        pod_name(name, *replica),
+                        status
+                    );
+                    false
+                }
+                Err(err) => {
+                    tracing::error!(
+                        "Failed to check worker {}/{}: {}",
+                        namespace,
+                        pod_name(name, *replica),
+                        err
+                    );
+                    true
+                }
+            });
+    }
+
+    /// Stops workers above the desired number of replicas and starts the missing ones.
+    async fn reconcile(
+        &mut self,
+        namespace: &NamespaceName,
+        name: &DeploymentName,
+    ) -> anyhow::Result<()> {
+        self.reap(namespace, name);
+
+        let target = self.desired.min(self.max_replicas());
+        let extra: Vec<usize> = self.workers.range(target..).map(|(r, _)| *r).collect();
+        for replica in extra {
+            let worker = self.workers.remove(&replica).unwrap();
+            // Stopping is done in the background so that the backend isn't blocked for the grace period.
+            let grace_period = self.config.termination_grace_period;
+            let namespace = namespace.clone();
+            let pod_name = pod_name(name, replica);
+            tokio::spawn(async move { worker.stop(grace_period, &namespace, &pod_name).await });
+        }
+
+        for replica in 0..target {
+            if self.workers.contains_key(&replica) {
+                continue;
+            }
+            let child = self
+                .command(namespace, name, replica)
+                .spawn()
+                .with_context(|| {
+                    format!(
+                        "failed to start worker {}/{}",
+                        namespace,
+                        pod_name(name, replica)
+                    )
+                })?;
+            tracing::info!("Started worker {}/{}", namespace, pod_name(name, replica));
+            self.workers.insert(
+                replica,
+                Worker {
+                    child,
+                    started: Utc::now(),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn to_namespace_entries(&self, name: &DeploymentName) -> (Deployment, Vec<(String, Pod)>) {
+        let owner = format!("ReplicaSet/{name}-local");
+        let mut pods: Vec<_> = self
+            .workers
+            .iter()
+            .map(|(replica, worker)| {
+                (
+                    pod_name(name, *replica),
+                    Pod {
+                        owner: owner.clone(),
+                        status: "Running".into(),
+                        changed: worker.started,
+                        out_of_resources: false,
+                    },
+                )
+            })
+            .collect();
+        // Replicas above the limit can't be started on the host.
+        pods.extend((self.max_replicas()..self.desired).map(|replica| {
+            (
+                pod_name(name, replica),
+                Pod {
+                    owner: owner.clone(),
+                    status: "Pending".into(),
+                    changed: self.scaled,
+                    out_of_resources: true,
+                },
+            )
+        }));
+
+        let deployment = Deployment {
+            running: self.workers.len(),
+            desired: self.desired,
+        };
+        (deployment, pods)
+    }
+}
+
+/// Backend managing worker processes on the local host. Each replica of a deployment is a process
+/// started from the configured command. Exited workers are restarted every `check_interval`.
+pub struct LocalBackend {
+    cluster_name: ClusterName,
+    check_interval: Duration,
+    dry_run: bool,
+    deployments: Mutex<HashMap<NamespaceName, HashMap<DeploymentName, LocalDeployment>>>,
+}
+
+impl LocalBackend {
+    pub fn new(
+        cluster_name: ClusterName,
+        config: LocalBackendConfig,
+        dry_run: bool,
+    ) -> anyhow::Result<Self> {
+        let mut deployments = HashMap::new();
+        for (namespace, namespace_deployments) in config.deployments {
+            let mut ns = HashMap::new();
+            for (name, deployment) in namespace_deployments {
+                anyhow::ensure!(
+                    !deployment.command.is_empty(),
+                    "empty command for deployment {namespace}/{name}"
+                );
+                ns.insert(name, LocalDeployment::new(deployment));
+            }
+            deployments.insert(namespace, ns);
+        }
+
+        Ok(Self {
+            cluster_name,
+            check_interval: config.check_interval,
+            dry_run,
+            deployments: Mutex::new(deployments),
+        })
+    }
+
+    async fn reconcile(&self) {
+        let mut deployments = self.deployments.lock().await;
+        for (namespace, namespace_deployments) in deployments.iter_mut() {
+            for (name, deployment) in namespace_deployments.iter_mut() {
+                if let Err(err) = deployment.reconcile(namespace, name).await {
+                    tracing::error!("Failed to reconcile deployment {namespace}/{name}: {err:#}");
+                }
+            }
+        }
+    }
+
+    async fn stop_all(&self) {
+        let mut deployments = self.deployments.lock().await;
+        let mut stops = vec![];
+        for (namespace, namespace_deployments) in deployments.iter_mut() {
+            for (name, deployment) in namespace_deployments.iter_mut() {
+                let grace_period = deployment.config.termination_grace_period;
+                for (replica, worker) in std::mem::take(&mut deployment.workers) {
+                    let pod_name = pod_name(name, replica);
+                    stops.push(async move { worker.stop(grace_period, namespace, &pod_name).await });
+                }
+            }
+        }
+        future::join_all(stops).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for LocalBackend {
+    async fn cluster(&self) -> Cluster {
+        let mut deployments = self.deployments.lock().await;
+        let namespaces = deployments
+            .iter_mut()
+            .map(|(namespace, namespace_deployments)| {
+                let mut ns = Namespace::default();
+                for (name, deployment) in namespace_deployments.iter_mut() {
+                    deployment.reap(namespace, name);
+                    let (d, pods) = deployment.to_namespace_entries(name);
+                    ns.deployments.insert(name.clone(), d);
+                    ns.pods.extend(pods);
+                }
+                (namespace.clone(), ns)
+            })
+            .collect();
+
+        Cluster {
+            name: self.cluster_name.clone(),
+            namespaces,
+        }
+    }
+
+    async fn scale(
+        &self,
+        namespace: &NamespaceName,
+        name: &DeploymentName,
+        size: usize,
+    ) -> anyhow::Result<()> {
+        let mut deployments = self.deployments.lock().await;
+        let Some(deployment) = deployments
+            .get_mut(namespace)
+            .and_then(|ns| ns.get_mut(name))
+        else {
+            anyhow::bail!("Unknown deployment {namespace}/{name}");
+        };
+
+        if self.dry_run {
+            tracing::info!(
+                "Dry run of scaled deployment/{} to {} replica(s).",
+                name,
+                size
+            );
+            return Ok(());
+        }
+
+        if deployment.desired != size {
+            deployment.desired = size;
+            deployment.scaled = Utc::now();
+        }
+        deployment.reconcile(namespace, name).await?;
+        tracing::info!("Scaled deployment/{} to {} replica(s).", name, size);
+
+        Ok(())
+    }
+
+    async fn run(&self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut ticker = interval(self.check_interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => self.reconcile().await,
+                _ = stop_receiver.changed() => {
+                    tracing::info!("Local backend stopping due to stop signal.");
+                    break;
+                }
+            }
+        }
+        self.stop_all().await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        cluster_types::Clusters,
+        config::QueueReportFields,
+        global::scaler::{Scaler, ScalerConfig, ScalerTrait},
+        key::NoKey,
+    };
+
+    fn backend() -> LocalBackend {
+        LocalBackend::new(
+            "local".into(),
+            LocalBackendConfig {
+                check_interval: Duration::from_secs(1),
+                deployments: [(
+                    "prover".into(),
+                    [(
+                        "witness-generator".into(),
+                        LocalDeploymentConfig {
+                            command: vec!["sleep".into(), "600".into()],
+                            env: HashMap::new(),
+                            max_replicas: Some(2),
+                            termination_grace_period: Duration::from_secs(1),
+                        },
+                    )]
+                    .into(),
+                )]
+                .into(),
+            },
+            false,
+        )
+        .unwrap()
+    }
+
+    async fn run_scaler(backend: &LocalBackend, scaler: &Scaler<NoKey>, queue: usize) {
+        let clusters = Clusters {
+            clusters: [("local".into(), backend.cluster().await)].into(),
+            ..Default::default()
+        };
+        let mut requests = HashMap::new();
+        scaler.run(&"prover".into(), queue, &clusters, &mut requests);
+        for request in requests.into_values() {
+            for d in request.deployments {
+                backend.scale(&d.namespace, &d.name, d.size).await.unwrap();
+            }
+        }
+    }
+
+    /// Returns desired replicas, running workers and pods out of resources.
+    async fn state(backend: &LocalBackend) -> (usize, usize, usize) {
+        let cluster = backend.cluster().await;
+        let namespace = &cluster.namespaces[&NamespaceName::from("prover")];
+        let deployment = &namespace.deployments[&DeploymentName::from("witness-generator")];
+        let out_of_resources = namespace
+            .pods
+            .values()
+            .filter(|p| p.out_of_resources)
+            .count();
+        (deployment.desired, deployment.running, out_of_resources)
+    }
+
+    #[tracing_test::traced_test]
+    #[tokio::test]
+    async fn test_scale_local_workers() {
+        let backend = backend();
+        let scaler = Scaler::<NoKey>::new(
+            QueueReportFields::basic_witness_jobs,
+            "witness-generator".into(),
+            0,
+            [("local".into(), [(NoKey(), 5)].into())].into(),
+            [(NoKey(), 10)].into(),
+            Arc::new(ScalerConfig {
+                cluster_priorities: [("local".into(), 0)].into(),
+                apply_min_to_namespace: None,
+                long_pending_duration: chrono::Duration::seconds(600),
+                scale_errors_duration: chrono::Duration::seconds(3600),
+            }),
+            None,
+        );
+
+        // Only 2 out of 3 requested workers fit the host.
+        run_scaler(&backend, &scaler, 30).await;
+        assert_eq!(state(&backend).await, (3, 2, 1));
+
+        // Scaler doesn't keep replicas which are out of resources.
+        run_scaler(&backend, &scaler, 30).await;
+        assert_eq!(state(&backend).await, (2, 2, 0));
+
+        run_scaler(&backend, &scaler, 0).await;
+        assert_eq!(state(&backend).await, (0, 0, 0));
+        assert!(
+            backend.cluster().await.namespaces[&NamespaceName::from("prover")]
+                .pods
+                .is_empty()
+        );
+    }
+
+    async fn wait_for_file(path: &std::path::Path) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !path.exists() {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("{path:?} was not created"));
+    }
+
+    #[tokio::test]
+    async fn scaled_down_worker_receives_sigterm() {
+        let dir = tempfile::TempDir::new().unwrap();
+        // The worker records receiving SIGTERM and exits gracefully.
+        let script = "trap 'touch \"$DIR/terminated\"; exit 0' TERM; \
+            touch \"$DIR/started\"; \
+            while true; do sleep 0.1; done";
+        let backend = LocalBackend::new(
+            "local".into(),
+            LocalBackendConfig {
+                check_interval: Duration::from_secs(1),
+                deployments: [(
+                    "prover".into(),
+                    [(
+                        "witness-generator".into(),
+                        LocalDeploymentConfig {
+                            command: vec!["sh".into(), "-c".into(), script.into()],
+                            env: [("DIR".into(), dir.path().to_str().unwrap().into())].into(),
+                            max_replicas: None,
+                            termination_grace_period: Duration::from_secs(10),
+                        },
+                    )]
+                    .into(),
+                )]
+                .into(),
+            },
+            false,
+        )
+        .unwrap();
+        let namespace = NamespaceName::from("prover");
+        let name = DeploymentName::from("witness-generator");
+
+        backend.scale(&namespace, &name, 1).await.unwrap();
+        wait_for_file(&dir.path().join("started")).await;
+
+        backend.scale(&namespace, &name, 0).await.unwrap();
+        assert_eq!(state(&backend).await, (0, 0, 0));
+        wait_for_file(&dir.path().join("terminated")).await;
+    }
+}
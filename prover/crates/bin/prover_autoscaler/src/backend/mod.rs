@@ -0,0 +1,26 @@
+//! Backends used by Agent to watch and scale deployments.
+
+use tokio::sync::watch;
+
+pub use self::{local::LocalBackend, webhook::WebhookBackend};
+use crate::cluster_types::{Cluster, DeploymentName, NamespaceName};
+
+mod local;
+mod webhook;
+
+#[async_trait::async_trait]
+pub trait Backend: Send + Sync {
+    /// Returns the current state of deployments and their pods.
+    async fn cluster(&self) -> Cluster;
+
+    /// Sets the number of replicas of the deployment.
+    async fn scale(
+        &self,
+        namespace: &NamespaceName,
+        name: &DeploymentName,
+        size: usize,
+    ) -> anyhow::Result<()>;
+
+    /// Keeps the cluster state up to date until the stop signal is received.
+    async fn run(&self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()>;
+}
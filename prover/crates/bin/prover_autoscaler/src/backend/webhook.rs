@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    Method,
+};
+use serde::Serialize;
+use tokio::sync::{watch, Mutex};
+
+use super::Backend;
+use crate::{
+    cluster_types::{
+        Cluster, ClusterName, Deployment, DeploymentName, Namespace, NamespaceName, Pod,
+    },
+    config::WebhookBackendConfig,
+    http_client::HttpClient,
+};
+
+/// Body of the webhook request.
+#[derive(Debug, Serialize)]
+pub struct WebhookScaleRequest {
+    pub cluster: ClusterName,
+    pub namespace: NamespaceName,
+    pub deployment: DeploymentName,
+    pub replicas: usize,
+}
+
+#[derive(Debug, Default, Clone)]
+struct WebhookDeployment {
+    replicas: usize,
+    /// Time of the last successful webhook call.
+    scaled: DateTime<Utc>,
+}
+
+/// Backend sending target replica counts to an HTTP webhook, leaving starting and stopping of the
+/// workers to the receiving side. Workers are not observed, so the replicas accepted by the
+/// webhook are reported as running.
+pub struct WebhookBackend {
+    http_client: HttpClient,
+    cluster_name: ClusterName,
+    url: String,
+    dry_run: bool,
+    deployments: Mutex<HashMap<NamespaceName, HashMap<DeploymentName, WebhookDeployment>>>,
+}
+
+impl WebhookBackend {
+    pub fn new(
+        http_client: HttpClient,
+        cluster_name: ClusterName,
+        config: WebhookBackendConfig,
+        dry_run: bool,
+    ) -> Self {
+        let deployments = config
+            .deployments
+            .into_iter()
+            .map(|(namespace, names)| {
+                (
+                    namespace,
+                    names
+                        .into_iter()
+                        .map(|name| (name, WebhookDeployment::default()))
+                        .collect(),
+                )
+            })
+            .collect();
+        Self {
+            http_client,
+            cluster_name,
+            url: config.url,
+            dry_run,
+            deployments: Mutex::new(deployments),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for WebhookBackend {
+    async fn cluster(&self) -> Cluster {
+        let deployments = self.deployments.lock().await;
+        let namespaces = deployments
+            .iter()
+            .map(|(namespace, namespace_deployments)| {
+                let mut ns = Namespace::default();
+                for (name, deployment) in namespace_deployments {
+                    ns.deployments.insert(
+                        name.clone(),
+                        Deployment {
+                            running: deployment.replicas,
+                            desired: deployment.replicas,
+                        },
+                    );
+                    ns.pods.extend((0..deployment.replicas).map(|replica| {
+                        (
+                            format!("{name}-webhook-{replica}"),
+                            Pod {
+                                owner: format!("ReplicaSet/{name}-webhook"),
+                                status: "Running".into(),
+                                changed: deployment.scaled,
+                                out_of_resources: false,
+                            },
+                        )
+                    }));
+                }
+                (namespace.clone(), ns)
+            })
+            .collect();
+
+        Cluster {
+            name: self.cluster_name.clone(),
+            namespaces,
+        }
+    }
+
+    async fn scale(
+        &self,
+        namespace: &NamespaceName,
+        name: &DeploymentName,
+        size: usize,
+    ) -> anyhow::Result<()> {
+        let mut deployments = self.deployments.lock().await;
+        let Some(deployment) = deployments
+            .get_mut(namespace)
+            .and_then(|ns| ns.get_mut(name))
+        else {
+            anyhow::bail!("Unknown deployment {namespace}/{name}");
+        };
+
+        if self.dry_run {
+            tracing::info!(
+                "Dry run of scaled deployment/{} to {} replica(s).",
+                name,
+                size
+            );
+            return Ok(());
+        }
+
+        let request = WebhookScaleRequest {
+            cluster: self.cluster_name.clone(),
+            namespace: namespace.clone(),
+            deployment: name.clone(),
+            replicas: size,
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self.http_client
+            .send_request_with_retries(
+                &self.url,
+                Method::POST,
+                Some(headers),
+                Some(serde_json::to_vec(&request).context("Failed to serialize request")?),
+            )
+            .await
+            .map_err(|err| {
+                anyhow::anyhow!("Failed sending scale request to {}: {err:?}", self.url)
+            })?;
+
+        deployment.replicas = size;
+        deployment.scaled = Utc::now();
+        tracing::info!("Scaled deployment/{} to {} replica(s).", name, size);
+
+        Ok(())
+    }
+
+    async fn run(&self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        // Nothing to watch, the state is updated by scale requests.
+        stop_receiver.changed().await.ok();
+        Ok(())
+    }
+}
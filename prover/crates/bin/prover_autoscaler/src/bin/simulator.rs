@@ -0,0 +1,67 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use structopt::StructOpt;
+use zksync_prover_autoscaler::{
+    config::{config_from_yaml, ProverAutoscalerConfig},
+    simulator::{read_history, Simulator},
+};
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Prover Autoscaler Simulator",
+    about = "Replay recorded queue history through Prover Autoscaler Scaler"
+)]
+struct Opt {
+    /// Path to the configuration file. Uses `scaler_config` and `simulator_config` sections.
+    #[structopt(long)]
+    config_path: PathBuf,
+    /// Path to the recorded history in JSON lines format.
+    #[structopt(long)]
+    history_path: PathBuf,
+    /// Path to write the state after each Scaler run to, in JSON lines format.
+    #[structopt(long)]
+    output_path: Option<PathBuf>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let opt = Opt::from_args();
+    let general_config =
+        config_from_yaml::<ProverAutoscalerConfig>(&opt.config_path).context("general config")?;
+    let scaler_config = general_config.scaler_config.context("scaler_config")?;
+    let simulator_config = general_config
+        .simulator_config
+        .context("simulator_config")?;
+    let history = read_history(&opt.history_path).context("history")?;
+
+    let mut output = opt
+        .output_path
+        .map(|path| {
+            File::create(&path)
+                .with_context(|| format!("failed to create {}", path.display()))
+                .map(BufWriter::new)
+        })
+        .transpose()?;
+
+    let summary = Simulator::new(&scaler_config, simulator_config).run(
+        &history,
+        scaler_config.scaler_run_interval,
+        |step| {
+            if let Some(output) = &mut output {
+                serde_json::to_writer(&mut *output, step)?;
+                writeln!(output)?;
+            }
+            Ok(())
+        },
+    )?;
+    if let Some(output) = &mut output {
+        output.flush()?;
+    }
+
+    println!("{}", serde_json::to_string_pretty(&summary)?);
+    Ok(())
+}
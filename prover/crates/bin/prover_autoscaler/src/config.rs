@@ -22,6 +22,8 @@ pub struct ProverAutoscalerConfig {
     pub graceful_shutdown_timeout: Duration,
     pub agent_config: Option<ProverAutoscalerAgentConfig>,
     pub scaler_config: Option<ProverAutoscalerScalerConfig>,
+    /// Model used by the simulator to replay recorded history through the Scaler.
+    pub simulator_config: Option<ProverAutoscalerSimulatorConfig>,
     pub observability: Option<ObservabilityConfig>,
 }
 
@@ -34,7 +36,7 @@ pub struct ProverAutoscalerAgentConfig {
     /// List of namespaces to watch.
     #[serde(default = "ProverAutoscalerAgentConfig::default_namespaces")]
     pub namespaces: Vec<NamespaceName>,
-    /// If dry-run enabled don't do any k8s updates, just report success.
+    /// If dry-run enabled don't change number of replicas, just report success.
     #[serde(default = "ProverAutoscalerAgentConfig::default_dry_run")]
     pub dry_run: bool,
     /// Interval for periodic pod checks against the K8s API to remove stale pods.
@@ -43,6 +45,61 @@ pub struct ProverAutoscalerAgentConfig {
         default = "ProverAutoscalerAgentConfig::default_pod_check_interval"
     )]
     pub pod_check_interval: Duration,
+    /// Backend used to watch and scale deployments. Default: k8s.
+    #[serde(default)]
+    pub backend: AgentBackendConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AgentBackendConfig {
+    /// Deployments in the K8s cluster the Agent is running in.
+    #[default]
+    K8s,
+    /// Worker processes on the local host.
+    Local(LocalBackendConfig),
+    /// Replica counts are sent to an HTTP webhook.
+    Webhook(WebhookBackendConfig),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LocalBackendConfig {
+    /// Interval to check worker processes and restart the exited ones.
+    #[serde(
+        with = "humantime_serde",
+        default = "LocalBackendConfig::default_check_interval"
+    )]
+    pub check_interval: Duration,
+    /// Deployments managed by the backend, per namespace.
+    pub deployments: HashMap<NamespaceName, HashMap<DeploymentName, LocalDeploymentConfig>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LocalDeploymentConfig {
+    /// Command to start a worker: program followed by arguments. `{namespace}`, `{deployment}`
+    /// and `{replica}` placeholders are substituted in arguments and env values.
+    pub command: Vec<String>,
+    /// Environment variables of the worker.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Max number of workers which can run on the host. Replicas above the limit are reported as
+    /// out of resources. No limit if not specified.
+    #[serde(default)]
+    pub max_replicas: Option<usize>,
+    /// Time given to a worker to exit after SIGTERM before it's killed.
+    #[serde(
+        with = "humantime_serde",
+        default = "LocalDeploymentConfig::default_termination_grace_period"
+    )]
+    pub termination_grace_period: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookBackendConfig {
+    /// URL to POST target replica counts to.
+    pub url: String,
+    /// Deployments managed by the webhook, per namespace.
+    pub deployments: HashMap<NamespaceName, Vec<DeploymentName>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -86,6 +143,30 @@ pub struct ProverAutoscalerScalerConfig {
     pub dry_run: bool,
 }
 
+/// Model of pods and jobs used by the simulator. Scaler settings are taken from `scaler_config`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ProverAutoscalerSimulatorConfig {
+    /// Time between the pod is requested and it starts processing jobs.
+    #[serde(
+        with = "humantime_serde",
+        default = "ProverAutoscalerSimulatorConfig::default_pod_startup_delay"
+    )]
+    pub pod_startup_delay: Duration,
+    /// Number of jobs processed by a single replica per minute, per deployment.
+    #[serde(default)]
+    pub throughput_per_minute: HashMap<DeploymentName, f64>,
+    /// Throughput of deployments missing in `throughput_per_minute`.
+    #[serde(default = "ProverAutoscalerSimulatorConfig::default_throughput_per_minute")]
+    pub default_throughput_per_minute: f64,
+    /// Cost of a single replica per hour, per deployment. Starting replicas are accounted for as well.
+    #[serde(default)]
+    pub cost_per_hour: HashMap<DeploymentName, f64>,
+    /// Max number of replicas which can be started in a cluster, per deployment. Replicas above
+    /// the limit are kept Pending and reported as out of resources. No limit if not specified.
+    #[serde(default)]
+    pub capacity: HashMap<ClusterName, HashMap<DeploymentName, usize>>,
+}
+
 // TODO: generate this enum by QueueReport from https://github.com/matter-labs/zksync-era/blob/main/prover/crates/bin/prover_job_monitor/src/autoscaler_queue_reporter.rs#L23
 // and remove allowing of non_camel_case_types by generating field name parser.
 #[derive(Debug, Display, PartialEq, Eq, Hash, Clone, Copy, Deserialize, EnumString, Default)]
@@ -190,6 +271,20 @@ impl ProverAutoscalerAgentConfig {
     }
 }
 
+impl LocalBackendConfig {
+    /// Default check_interval -- 10s
+    pub fn default_check_interval() -> Duration {
+        Duration::from_secs(10)
+    }
+}
+
+impl LocalDeploymentConfig {
+    /// Default termination_grace_period -- 30s
+    pub fn default_termination_grace_period() -> Duration {
+        Duration::from_secs(30)
+    }
+}
+
 impl ProverAutoscalerScalerConfig {
     /// Default scaler_run_interval -- 10s
     pub fn default_scaler_run_interval() -> Duration {
@@ -212,6 +307,33 @@ impl ProverAutoscalerScalerConfig {
     }
 }
 
+impl ProverAutoscalerSimulatorConfig {
+    /// Default pod_startup_delay -- 5m
+    pub fn default_pod_startup_delay() -> Duration {
+        Duration::from_secs(300)
+    }
+
+    /// Default default_throughput_per_minute -- 1 job per minute
+    pub fn default_throughput_per_minute() -> f64 {
+        1.0
+    }
+
+    pub fn throughput_per_minute(&self, deployment: &DeploymentName) -> f64 {
+        self.throughput_per_minute
+            .get(deployment)
+            .copied()
+            .unwrap_or(self.default_throughput_per_minute)
+    }
+
+    pub fn cost_per_hour(&self, deployment: &DeploymentName) -> f64 {
+        self.cost_per_hour.get(deployment).copied().unwrap_or(0.0)
+    }
+
+    pub fn capacity(&self, cluster: &ClusterName, deployment: &DeploymentName) -> Option<usize> {
+        self.capacity.get(cluster)?.get(deployment).copied()
+    }
+}
+
 impl ScalerTarget {
     pub fn default_speed() -> ScalarOrMap {
         ScalarOrMap::Scalar(1)
@@ -49,16 +49,21 @@ impl Queuer {
             .json::<Vec<VersionedQueueReport>>()
             .await
             .context("Failed to read response as json")?;
-        Ok(response
-            .iter()
-            .flat_map(|versioned_report| {
-                jobs.iter().map(move |j| {
-                    (
-                        (versioned_report.version.to_string(), *j),
-                        target_to_queue(*j, &versioned_report.report),
-                    )
-                })
-            })
-            .collect::<HashMap<_, _>>())
+        Ok(queue_from_reports(&response, jobs))
     }
 }
+
+/// Parses queue reports into Queue HashMap for provided list of jobs.
+pub fn queue_from_reports(reports: &[VersionedQueueReport], jobs: &[QueueReportFields]) -> Queue {
+    reports
+        .iter()
+        .flat_map(|versioned_report| {
+            jobs.iter().map(move |j| {
+                (
+                    (versioned_report.version.to_string(), *j),
+                    target_to_queue(*j, &versioned_report.report),
+                )
+            })
+        })
+        .collect::<HashMap<_, _>>()
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt::Debug, hash::Hash, str::FromStr, sync::Arc};
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use debug_map_sorted::SortedOutputExt;
 
 use crate::{
@@ -83,7 +83,12 @@ impl<K: Key> Scaler<K> {
         }
     }
 
-    fn convert_to_pool(&self, namespace: &NamespaceName, cluster: &Cluster) -> Vec<Pool<K>> {
+    fn convert_to_pool(
+        &self,
+        namespace: &NamespaceName,
+        cluster: &Cluster,
+        now: DateTime<Utc>,
+    ) -> Vec<Pool<K>> {
         let Some(namespace_value) = &cluster.namespaces.get(namespace) else {
             // No namespace in config, ignoring.
             return vec![];
@@ -107,7 +112,7 @@ impl<K: Key> Scaler<K> {
                 scale_errors: namespace_value
                     .scale_errors
                     .iter()
-                    .filter(|v| v.time > Utc::now() - self.config.scale_errors_duration)
+                    .filter(|v| v.time > now - self.config.scale_errors_duration)
                     .count(),
                 ..Default::default()
             });
@@ -130,7 +135,7 @@ impl<K: Key> Scaler<K> {
             if status == PodStatus::Pending {
                 if pod_value.out_of_resources {
                     status = PodStatus::NeedToMove;
-                } else if pod_value.changed < Utc::now() - self.config.long_pending_duration {
+                } else if pod_value.changed < now - self.config.long_pending_duration {
                     status = PodStatus::LongPending;
                 }
             }
@@ -148,11 +153,16 @@ impl<K: Key> Scaler<K> {
         pool_map.into_values().collect()
     }
 
-    fn sorted_clusters(&self, namespace: &NamespaceName, clusters: &Clusters) -> Vec<Pool<K>> {
+    fn sorted_clusters(
+        &self,
+        namespace: &NamespaceName,
+        clusters: &Clusters,
+        now: DateTime<Utc>,
+    ) -> Vec<Pool<K>> {
         let mut pools: Vec<Pool<K>> = clusters
             .clusters
             .values()
-            .flat_map(|c| self.convert_to_pool(namespace, c))
+            .flat_map(|c| self.convert_to_pool(namespace, c, now))
             .collect();
 
         // If a pool has NeedToMove pod, max_pool_size is set to number of Running+Pending pods.
@@ -267,7 +277,18 @@ impl<K: Key> Scaler<K> {
         queue: usize,
         clusters: &Clusters,
     ) -> HashMap<PoolKey<K>, usize> {
-        let sorted_clusters = self.sorted_clusters(namespace, clusters);
+        self.calculate_at(namespace, queue, clusters, Utc::now())
+    }
+
+    /// Same as [`Self::calculate()`], but pod and scale error ages are measured relative to `now`.
+    pub fn calculate_at(
+        &self,
+        namespace: &NamespaceName,
+        queue: usize,
+        clusters: &Clusters,
+        now: DateTime<Utc>,
+    ) -> HashMap<PoolKey<K>, usize> {
+        let sorted_clusters = self.sorted_clusters(namespace, clusters, now);
         tracing::debug!(
             "Sorted clusters for namespace {}: {:?}",
             namespace,
@@ -413,6 +434,18 @@ pub trait ScalerTrait {
         queue: usize,
         clusters: &Clusters,
         requests: &mut HashMap<ClusterName, ScaleRequest>,
+    ) {
+        self.run_at(namespace, queue, clusters, Utc::now(), requests);
+    }
+    /// Same as [`Self::run()`], but with an explicit current time. Used to replay recorded
+    /// history.
+    fn run_at(
+        &self,
+        namespace: &NamespaceName,
+        queue: usize,
+        clusters: &Clusters,
+        now: DateTime<Utc>,
+        requests: &mut HashMap<ClusterName, ScaleRequest>,
     );
 }
 
@@ -424,14 +457,15 @@ impl<K: Key> ScalerTrait for Scaler<K> {
         self.queue_report_field
     }
 
-    fn run(
+    fn run_at(
         &self,
         namespace: &NamespaceName,
         queue: usize,
         clusters: &Clusters,
+        now: DateTime<Utc>,
         requests: &mut HashMap<ClusterName, ScaleRequest>,
     ) {
-        let replicas = self.calculate(namespace, queue, clusters);
+        let replicas = self.calculate_at(namespace, queue, clusters, now);
         for (k, num) in &replicas {
             let labels = JobLabels {
                 job: self.deployment.clone(),
@@ -1537,7 +1571,7 @@ mod tests {
             .into(),
         };
         assert_eq!(
-            scaler.convert_to_pool(&"prover".into(), cluster),
+            scaler.convert_to_pool(&"prover".into(), cluster, Utc::now()),
             vec![Pool {
                 name: "foo".into(),
                 key: GpuKey(Gpu::L4),
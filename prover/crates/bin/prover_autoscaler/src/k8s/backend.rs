@@ -0,0 +1,40 @@
+use tokio::sync::watch;
+
+use super::{Scaler, Watcher};
+use crate::{
+    backend::Backend,
+    cluster_types::{Cluster, DeploymentName, NamespaceName},
+};
+
+/// Backend managing deployments in the K8s cluster.
+#[derive(Clone)]
+pub struct K8sBackend {
+    watcher: Watcher,
+    scaler: Scaler,
+}
+
+impl K8sBackend {
+    pub fn new(watcher: Watcher, scaler: Scaler) -> Self {
+        Self { watcher, scaler }
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for K8sBackend {
+    async fn cluster(&self) -> Cluster {
+        self.watcher.cluster.lock().await.clone()
+    }
+
+    async fn scale(
+        &self,
+        namespace: &NamespaceName,
+        name: &DeploymentName,
+        size: usize,
+    ) -> anyhow::Result<()> {
+        self.scaler.scale(namespace, name, size).await
+    }
+
+    async fn run(&self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        self.watcher.clone().run(stop_receiver).await
+    }
+}
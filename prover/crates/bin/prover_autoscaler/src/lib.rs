@@ -1,6 +1,7 @@
 #[macro_use]
 mod macros;
 pub mod agent;
+pub mod backend;
 pub mod cluster_types;
 pub mod config;
 pub mod global;
@@ -8,3 +9,4 @@ pub mod http_client;
 pub mod k8s;
 pub(crate) mod key;
 pub(crate) mod metrics;
+pub mod simulator;
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use anyhow::Context;
 use structopt::StructOpt;
@@ -8,11 +8,12 @@ use tokio::{
 };
 use zksync_prover_autoscaler::{
     agent,
+    backend::{Backend, LocalBackend, WebhookBackend},
     cluster_types::ClusterName,
-    config::{config_from_yaml, ProverAutoscalerConfig},
+    config::{config_from_yaml, AgentBackendConfig, ProverAutoscalerConfig},
     global::{manager::Manager, queuer::Queuer, watcher},
     http_client::HttpClient,
-    k8s::{Scaler, Watcher},
+    k8s::{K8sBackend, Scaler, Watcher},
 };
 use zksync_prover_task::TaskRunner;
 use zksync_task_management::ManagedTasks;
@@ -85,23 +86,44 @@ async fn main() -> anyhow::Result<()> {
             let exporter_config = PrometheusExporterConfig::pull(agent_config.prometheus_port);
             tasks.push(tokio::spawn(exporter_config.run(stop_receiver.clone())));
 
-            let _ = rustls::crypto::ring::default_provider().install_default();
-            let client = kube::Client::try_default().await?;
-
-            let watcher = Watcher::new(
-                http_client,
-                client.clone(),
-                opt.cluster_name,
-                agent_config.namespaces,
-                agent_config.pod_check_interval,
-            )
-            .await;
-            let scaler = Scaler::new(client, agent_config.dry_run);
-            tasks.push(tokio::spawn(watcher.clone().run(stop_receiver.clone())));
+            let backend: Arc<dyn Backend> = match agent_config.backend {
+                AgentBackendConfig::K8s => {
+                    let _ = rustls::crypto::ring::default_provider().install_default();
+                    let client = kube::Client::try_default().await?;
+
+                    let watcher = Watcher::new(
+                        http_client,
+                        client.clone(),
+                        opt.cluster_name,
+                        agent_config.namespaces,
+                        agent_config.pod_check_interval,
+                    )
+                    .await;
+                    let scaler = Scaler::new(client, agent_config.dry_run);
+                    Arc::new(K8sBackend::new(watcher, scaler))
+                }
+                AgentBackendConfig::Local(config) => Arc::new(LocalBackend::new(
+                    opt.cluster_name
+                        .context("cluster_name is required for local backend")?,
+                    config,
+                    agent_config.dry_run,
+                )?),
+                AgentBackendConfig::Webhook(config) => Arc::new(WebhookBackend::new(
+                    http_client,
+                    opt.cluster_name
+                        .context("cluster_name is required for webhook backend")?,
+                    config,
+                    agent_config.dry_run,
+                )),
+            };
+            let watch_backend = backend.clone();
+            let watch_stop_receiver = stop_receiver.clone();
+            tasks.push(tokio::spawn(async move {
+                watch_backend.run(watch_stop_receiver).await
+            }));
             tasks.push(tokio::spawn(agent::run_server(
                 agent_config.http_port,
-                watcher,
-                scaler,
+                backend,
                 stop_receiver.clone(),
             )))
         }
@@ -0,0 +1,652 @@
+//! Offline simulation of the Scaler against recorded queue reports and cluster states.
+//!
+//! Simulator replays recorded queue reports through the same Scaler code used in production. Pods
+//! are modeled instead of being scaled in real clusters: requested pods start processing jobs after
+//! `pod_startup_delay`, if the cluster has capacity for them, and each running pod processes
+//! `throughput_per_minute` jobs. New jobs are derived from increases of the recorded queue.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io::BufRead,
+    path::Path,
+    time::Duration,
+};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use zksync_prover_job_monitor::autoscaler_queue_reporter::VersionedQueueReport;
+
+use crate::{
+    agent::ScaleRequest,
+    cluster_types::{
+        Cluster, ClusterName, Clusters, Deployment, DeploymentName, Namespace, NamespaceName, Pod,
+    },
+    config::{ProverAutoscalerScalerConfig, ProverAutoscalerSimulatorConfig, QueueReportFields},
+    global::{
+        manager::create_scalers,
+        queuer::{queue_from_reports, Queue},
+        scaler::ScalerTrait,
+    },
+};
+
+/// Recorded state of the prover subsystem at a point in time.
+#[derive(Debug, Deserialize)]
+pub struct HistoryRecord {
+    pub time: DateTime<Utc>,
+    /// Queue report as returned by prover-job-monitor.
+    pub queue_report: Vec<VersionedQueueReport>,
+    /// Cluster states as returned by Agents. Required in the first record; later records update the
+    /// list of deployments and scale errors, while pods are simulated.
+    #[serde(default)]
+    pub clusters: Vec<Cluster>,
+}
+
+/// Reads history recorded in JSON lines format, one [`HistoryRecord`] per line.
+pub fn read_history(path: &Path) -> anyhow::Result<Vec<HistoryRecord>> {
+    let file =
+        std::fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let mut history = vec![];
+    for (i, line) in std::io::BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: HistoryRecord = serde_json::from_str(&line)
+            .with_context(|| format!("failed to parse line {}", i + 1))?;
+        history.push(record);
+    }
+    Ok(history)
+}
+
+#[derive(Debug, Serialize)]
+pub struct QueueSample {
+    pub namespace: NamespaceName,
+    pub queue_report_field: String,
+    pub recorded_queue: usize,
+    pub queue: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeploymentSample {
+    pub cluster: ClusterName,
+    pub namespace: NamespaceName,
+    pub deployment: DeploymentName,
+    pub desired: usize,
+    pub running: usize,
+}
+
+/// State of the simulation after a single Scaler run.
+#[derive(Debug, Serialize)]
+pub struct SimulationStep {
+    pub time: DateTime<Utc>,
+    pub queues: Vec<QueueSample>,
+    pub deployments: Vec<DeploymentSample>,
+    /// Cost of the replicas during the step.
+    pub cost: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TargetSummary {
+    pub namespace: NamespaceName,
+    pub queue_report_field: String,
+    pub processed_jobs: f64,
+    pub remaining_jobs: usize,
+    /// Average time between job arrival and its completion.
+    pub average_latency_secs: f64,
+    pub max_latency_secs: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimulationSummary {
+    pub steps: usize,
+    pub total_cost: f64,
+    pub targets: Vec<TargetSummary>,
+}
+
+/// FIFO queue of jobs for a single namespace and queue report field.
+#[derive(Debug, Default)]
+struct JobQueue {
+    /// Batches of jobs with their arrival time.
+    batches: VecDeque<(DateTime<Utc>, f64)>,
+    processed_jobs: f64,
+    total_latency_secs: f64,
+    max_latency_secs: f64,
+}
+
+impl JobQueue {
+    /// Remainders smaller than this are considered processed.
+    const EPSILON: f64 = 1e-6;
+
+    fn push(&mut self, time: DateTime<Utc>, count: usize) {
+        if count > 0 {
+            self.batches.push_back((time, count as f64));
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.batches
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<f64>()
+            .ceil() as usize
+    }
+
+    /// Processes up to `capacity` jobs, which are considered completed at `completed_at`.
+    fn process(&mut self, completed_at: DateTime<Utc>, mut capacity: f64) {
+        while capacity > Self::EPSILON {
+            let Some((arrived_at, count)) = self.batches.front_mut() else {
+                break;
+            };
+            let processed = count.min(capacity);
+            let latency_secs = (completed_at - *arrived_at).num_milliseconds() as f64 / 1000.0;
+            self.processed_jobs += processed;
+            self.total_latency_secs += processed * latency_secs;
+            self.max_latency_secs = self.max_latency_secs.max(latency_secs);
+            *count -= processed;
+            capacity -= processed;
+            if *count < Self::EPSILON {
+                self.batches.pop_front();
+            }
+        }
+    }
+}
+
+/// Returns the deployment owning the pod, based on the name of its ReplicaSet.
+fn pod_deployment(pod: &Pod) -> Option<DeploymentName> {
+    let replica_set = pod.owner.split(':').next()?.strip_prefix("ReplicaSet/")?;
+    let (deployment, _) = replica_set.rsplit_once('-')?;
+    Some(deployment.into())
+}
+
+fn is_running(pod: &Pod) -> bool {
+    pod.status == "Running"
+}
+
+/// Pods which are running or starting, i.e. occupying resources.
+fn is_billed(pod: &Pod) -> bool {
+    is_running(pod) || (pod.status == "Pending" && !pod.out_of_resources)
+}
+
+fn update_running(namespace: &mut Namespace) {
+    for (name, deployment) in namespace.deployments.iter_mut() {
+        deployment.running = namespace
+            .pods
+            .values()
+            .filter(|pod| is_running(pod) && pod_deployment(pod).as_ref() == Some(name))
+            .count();
+    }
+}
+
+pub struct Simulator {
+    /// Namespace to Protocol Version configuration.
+    namespaces: HashMap<NamespaceName, String>,
+    scalers: Vec<Box<dyn ScalerTrait + Sync + Send>>,
+    jobs: Vec<QueueReportFields>,
+    config: ProverAutoscalerSimulatorConfig,
+
+    clusters: Clusters,
+    recorded_queue: Queue,
+    queues: HashMap<(NamespaceName, QueueReportFields), JobQueue>,
+    total_cost: f64,
+    next_pod_id: usize,
+}
+
+impl Simulator {
+    pub fn new(
+        scaler_config: &ProverAutoscalerScalerConfig,
+        config: ProverAutoscalerSimulatorConfig,
+    ) -> Self {
+        let scalers = create_scalers(scaler_config);
+        let jobs = scalers.iter().map(|s| s.queue_report_field()).collect();
+        Self {
+            namespaces: scaler_config.protocol_versions.clone(),
+            scalers,
+            jobs,
+            config,
+            clusters: Clusters::default(),
+            recorded_queue: Queue::default(),
+            queues: HashMap::new(),
+            total_cost: 0.0,
+            next_pod_id: 0,
+        }
+    }
+
+    /// Replays `history` running the Scaler every `interval`. `on_step` is called after each run.
+    pub fn run(
+        mut self,
+        history: &[HistoryRecord],
+        interval: Duration,
+        mut on_step: impl FnMut(&SimulationStep) -> anyhow::Result<()>,
+    ) -> anyhow::Result<SimulationSummary> {
+        let (Some(first), Some(last)) = (history.first(), history.last()) else {
+            anyhow::bail!("history is empty");
+        };
+        anyhow::ensure!(
+            !first.clusters.is_empty(),
+            "first history record must contain cluster states"
+        );
+        anyhow::ensure!(
+            history.windows(2).all(|w| w[0].time <= w[1].time),
+            "history records must be sorted by time"
+        );
+        let interval = chrono::Duration::from_std(interval).context("interval")?;
+        anyhow::ensure!(
+            interval > chrono::Duration::zero(),
+            "interval must be positive"
+        );
+
+        let mut steps = 0;
+        let mut records = history.iter().peekable();
+        let mut now = first.time;
+        while now <= last.time {
+            while let Some(record) = records.next_if(|record| record.time <= now) {
+                self.apply_record(record);
+            }
+            let step = self.step(now, interval);
+            on_step(&step)?;
+            steps += 1;
+            now += interval;
+        }
+
+        Ok(self.summary(steps))
+    }
+
+    fn apply_record(&mut self, record: &HistoryRecord) {
+        // Jobs are considered arrived when the recorded queue grows.
+        let queue = queue_from_reports(&record.queue_report, &self.jobs);
+        for (ns, ppv) in &self.namespaces {
+            for job in &self.jobs {
+                let key = (ppv.clone(), *job);
+                let new = queue.get(&key).copied().unwrap_or(0);
+                let old = self.recorded_queue.get(&key).copied().unwrap_or(0);
+                self.queues
+                    .entry((ns.clone(), *job))
+                    .or_default()
+                    .push(record.time, new.saturating_sub(old));
+            }
+        }
+        self.recorded_queue = queue;
+
+        for recorded in &record.clusters {
+            let Some(cluster) = self.clusters.clusters.get_mut(&recorded.name) else {
+                self.clusters
+                    .clusters
+                    .insert(recorded.name.clone(), recorded.clone());
+                continue;
+            };
+            for (ns, recorded_ns) in &recorded.namespaces {
+                let namespace = cluster.namespaces.entry(ns.clone()).or_default();
+                namespace
+                    .deployments
+                    .retain(|name, _| recorded_ns.deployments.contains_key(name));
+                for (name, deployment) in &recorded_ns.deployments {
+                    namespace
+                        .deployments
+                        .entry(name.clone())
+                        .or_insert_with(|| deployment.clone());
+                }
+                namespace.scale_errors = recorded_ns.scale_errors.clone();
+            }
+        }
+    }
+
+    fn step(&mut self, now: DateTime<Utc>, interval: chrono::Duration) -> SimulationStep {
+        self.start_pods(now);
+
+        let minutes = interval.num_milliseconds() as f64 / 60_000.0;
+        for (ns, _) in &self.namespaces {
+            for scaler in &self.scalers {
+                let capacity = self.throughput(ns, &scaler.deployment()) * minutes;
+                self.queues
+                    .entry((ns.clone(), scaler.queue_report_field()))
+                    .or_default()
+                    .process(now + interval, capacity);
+            }
+        }
+
+        let hours = minutes / 60.0;
+        let cost: f64 = self
+            .pods()
+            .filter(|(_, _, pod)| is_billed(pod))
+            .filter_map(|(_, _, pod)| pod_deployment(pod))
+            .map(|deployment| self.config.cost_per_hour(&deployment) * hours)
+            .sum();
+        self.total_cost += cost;
+
+        let mut scale_requests: HashMap<ClusterName, ScaleRequest> = HashMap::new();
+        for (ns, _) in &self.namespaces {
+            for scaler in &self.scalers {
+                let queue = self
+                    .queues
+                    .get(&(ns.clone(), scaler.queue_report_field()))
+                    .map_or(0, JobQueue::len);
+                scaler.run_at(ns, queue, &self.clusters, now, &mut scale_requests);
+            }
+        }
+        self.scale(now, scale_requests);
+
+        self.sample(now, cost)
+    }
+
+    fn pods(&self) -> impl Iterator<Item = (&ClusterName, &NamespaceName, &Pod)> {
+        self.clusters.clusters.values().flat_map(|cluster| {
+            cluster.namespaces.iter().flat_map(move |(ns, namespace)| {
+                namespace
+                    .pods
+                    .values()
+                    .map(move |pod| (&cluster.name, ns, pod))
+            })
+        })
+    }
+
+    /// Total throughput of running pods of deployments with the prefix in the namespace.
+    fn throughput(&self, namespace: &NamespaceName, deployment_prefix: &DeploymentName) -> f64 {
+        self.pods()
+            .filter(|(_, ns, pod)| *ns == namespace && is_running(pod))
+            .filter_map(|(_, _, pod)| pod_deployment(pod))
+            .filter(|deployment| deployment.to_str().starts_with(deployment_prefix.to_str()))
+            .map(|deployment| self.config.throughput_per_minute(&deployment))
+            .sum()
+    }
+
+    /// Starts pending pods which passed the startup delay, if there is capacity for them.
+    fn start_pods(&mut self, now: DateTime<Utc>) {
+        let startup_delay = chrono::Duration::from_std(self.config.pod_startup_delay)
+            .unwrap_or(chrono::Duration::MAX);
+        for cluster in self.clusters.clusters.values_mut() {
+            let mut running: HashMap<DeploymentName, usize> = HashMap::new();
+            for namespace in cluster.namespaces.values() {
+                for pod in namespace.pods.values().filter(|pod| is_running(pod)) {
+                    if let Some(deployment) = pod_deployment(pod) {
+                        *running.entry(deployment).or_default() += 1;
+                    }
+                }
+            }
+
+            for namespace in cluster.namespaces.values_mut() {
+                // Sorting pods to make the simulation deterministic.
+                let mut pods: Vec<_> = namespace.pods.iter_mut().collect();
+                pods.sort_by(|(a_name, a), (b_name, b)| {
+                    a.changed.cmp(&b.changed).then(a_name.cmp(b_name))
+                });
+                for (_, pod) in pods {
+                    if pod.status != "Pending" || now - pod.changed < startup_delay {
+                        continue;
+                    }
+                    let Some(deployment) = pod_deployment(pod) else {
+                        continue;
+                    };
+                    let running = running.entry(deployment.clone()).or_default();
+                    let capacity = self.config.capacity(&cluster.name, &deployment);
+                    if capacity.is_none_or(|capacity| *running < capacity) {
+                        pod.status = "Running".into();
+                        pod.changed = now;
+                        pod.out_of_resources = false;
+                        *running += 1;
+                    } else {
+                        pod.out_of_resources = true;
+                    }
+                }
+
+                update_running(namespace);
+            }
+        }
+    }
+
+    /// Applies scale requests and adds or removes pods to match the desired number of replicas.
+    fn scale(&mut self, now: DateTime<Utc>, requests: HashMap<ClusterName, ScaleRequest>) {
+        for (cluster, request) in requests {
+            let Some(cluster) = self.clusters.clusters.get_mut(&cluster) else {
+                continue;
+            };
+            for d in request.deployments {
+                if let Some(deployment) = cluster
+                    .namespaces
+                    .get_mut(&d.namespace)
+                    .and_then(|ns| ns.deployments.get_mut(&d.name))
+                {
+                    deployment.desired = d.size;
+                }
+            }
+        }
+
+        for cluster in self.clusters.clusters.values_mut() {
+            for namespace in cluster.namespaces.values_mut() {
+                for (name, Deployment { desired, .. }) in &namespace.deployments {
+                    let mut pods: Vec<_> = namespace
+                        .pods
+                        .iter()
+                        .filter(|(_, pod)| pod_deployment(pod).as_ref() == Some(name))
+                        .map(|(pod_name, pod)| (pod_name.clone(), pod.clone()))
+                        .collect();
+                    if pods.len() > *desired {
+                        // Removing pods which are out of resources first, then pending, then the newest ones.
+                        pods.sort_by(|(a_name, a), (b_name, b)| {
+                            b.out_of_resources
+                                .cmp(&a.out_of_resources)
+                                .then(is_running(a).cmp(&is_running(b)))
+                                .then(b.changed.cmp(&a.changed))
+                                .then(a_name.cmp(b_name))
+                        });
+                        for (pod_name, _) in &pods[..pods.len() - desired] {
+                            namespace.pods.remove(pod_name);
+                        }
+                    }
+                    for _ in pods.len()..*desired {
+                        self.next_pod_id += 1;
+                        namespace.pods.insert(
+                            format!("{name}-sim-{}", self.next_pod_id),
+                            Pod {
+                                owner: format!("ReplicaSet/{name}-sim"),
+                                status: "Pending".into(),
+                                changed: now,
+                                out_of_resources: false,
+                            },
+                        );
+                    }
+                }
+                update_running(namespace);
+            }
+        }
+    }
+
+    fn sample(&self, now: DateTime<Utc>, cost: f64) -> SimulationStep {
+        let mut queues = vec![];
+        for (ns, ppv) in &self.namespaces {
+            for job in &self.jobs {
+                queues.push(QueueSample {
+                    namespace: ns.clone(),
+                    queue_report_field: job.to_string(),
+                    recorded_queue: self
+                        .recorded_queue
+                        .get(&(ppv.clone(), *job))
+                        .copied()
+                        .unwrap_or(0),
+                    queue: self
+                        .queues
+                        .get(&(ns.clone(), *job))
+                        .map_or(0, JobQueue::len),
+                });
+            }
+        }
+        queues.sort_by(|a, b| {
+            (&a.namespace, &a.queue_report_field).cmp(&(&b.namespace, &b.queue_report_field))
+        });
+
+        let mut deployments = vec![];
+        for cluster in self.clusters.clusters.values() {
+            for (ns, namespace) in &cluster.namespaces {
+                for (name, deployment) in &namespace.deployments {
+                    deployments.push(DeploymentSample {
+                        cluster: cluster.name.clone(),
+                        namespace: ns.clone(),
+                        deployment: name.clone(),
+                        desired: deployment.desired,
+                        running: deployment.running,
+                    });
+                }
+            }
+        }
+        deployments.sort_by(|a, b| {
+            (&a.cluster, &a.namespace, &a.deployment).cmp(&(
+                &b.cluster,
+                &b.namespace,
+                &b.deployment,
+            ))
+        });
+
+        SimulationStep {
+            time: now,
+            queues,
+            deployments,
+            cost,
+        }
+    }
+
+    fn summary(&self, steps: usize) -> SimulationSummary {
+        let mut targets: Vec<_> = self
+            .queues
+            .iter()
+            .map(|((ns, job), queue)| TargetSummary {
+                namespace: ns.clone(),
+                queue_report_field: job.to_string(),
+                processed_jobs: queue.processed_jobs,
+                remaining_jobs: queue.len(),
+                average_latency_secs: if queue.processed_jobs > 0.0 {
+                    queue.total_latency_secs / queue.processed_jobs
+                } else {
+                    0.0
+                },
+                max_latency_secs: queue.max_latency_secs,
+            })
+            .collect();
+        targets.sort_by(|a, b| {
+            (&a.namespace, &a.queue_report_field).cmp(&(&b.namespace, &b.queue_report_field))
+        });
+
+        SimulationSummary {
+            steps,
+            total_cost: self.total_cost,
+            targets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_prover_job_monitor::autoscaler_queue_reporter::QueueReport;
+
+    use super::*;
+    use crate::config::ProverAutoscalerConfig;
+
+    fn queue_report(basic_witness_jobs: usize) -> Vec<VersionedQueueReport> {
+        vec![VersionedQueueReport {
+            report: QueueReport {
+                basic_witness_jobs: serde_json::from_value(serde_json::json!({
+                    "queued": basic_witness_jobs,
+                    "in_progress": 0,
+                }))
+                .unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }]
+    }
+
+    fn config() -> ProverAutoscalerConfig {
+        let version = VersionedQueueReport::default().version;
+        let yaml = format!(
+            r#"
+scaler_config:
+  prometheus_port: 8080
+  scaler_run_interval: 1m
+  agents: []
+  protocol_versions:
+    prover: "{version}"
+  cluster_priorities:
+    foo: 0
+  scaler_targets:
+    - queue_report_field: basic_witness_jobs
+      deployment: witness-generator
+      max_replicas:
+        foo: 5
+      speed: 10
+simulator_config:
+  pod_startup_delay: 2m
+  throughput_per_minute:
+    witness-generator: 5
+  cost_per_hour:
+    witness-generator: 6
+"#
+        );
+        serde_yaml::from_str(&yaml).unwrap()
+    }
+
+    #[tracing_test::traced_test]
+    #[test]
+    fn test_simulation() {
+        let config = config();
+        let scaler_config = config.scaler_config.unwrap();
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let cluster = Cluster {
+            name: "foo".into(),
+            namespaces: [(
+                "prover".into(),
+                Namespace {
+                    deployments: [("witness-generator".into(), Deployment::default())].into(),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+        };
+        let history = [
+            HistoryRecord {
+                time: start,
+                queue_report: queue_report(0),
+                clusters: vec![cluster],
+            },
+            HistoryRecord {
+                time: start + chrono::Duration::minutes(1),
+                queue_report: queue_report(40),
+                clusters: vec![],
+            },
+            HistoryRecord {
+                time: start + chrono::Duration::minutes(10),
+                queue_report: queue_report(40),
+                clusters: vec![],
+            },
+        ];
+
+        let mut steps = vec![];
+        let summary = Simulator::new(&scaler_config, config.simulator_config.unwrap())
+            .run(&history, scaler_config.scaler_run_interval, |step| {
+                let deployment = &step.deployments[0];
+                steps.push((step.queues[0].queue, deployment.desired, deployment.running));
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(summary.steps, 11);
+        assert_eq!(
+            steps[..8],
+            [
+                (0, 0, 0),
+                (40, 4, 0), // Queue of 40 jobs requires 4 replicas with speed 10.
+                (40, 4, 0),
+                (20, 2, 2), // Replicas started after 2 minutes and processed 20 jobs.
+                (10, 1, 1),
+                (5, 1, 1),
+                (0, 0, 0),
+                (0, 0, 0), // Recorded queue didn't grow, no new jobs.
+            ]
+        );
+        let target = &summary.targets[0];
+        assert_eq!(target.processed_jobs, 40.0);
+        assert_eq!(target.remaining_jobs, 0);
+        assert_eq!(target.max_latency_secs, 360.0);
+        assert_eq!(target.average_latency_secs, 232.5);
+        // Replica-minutes: 4 + 4 + 2 + 1 + 1, including startup.
+        assert!((summary.total_cost - 12.0 / 60.0 * 6.0).abs() < 1e-9);
+    }
+}
@@ -2,8 +2,8 @@ use clap::{command, Args, Parser, Subcommand};
 use zksync_types::url::SensitiveUrl;
 
 use crate::commands::{
-    config, debug_proof, delete, get_file_info, insert_batch, insert_version, requeue, restart,
-    stats, status::StatusCommand,
+    config, debug_proof, delete, get_file_info, insert_batch, insert_version, report, requeue,
+    restart, stats, status::StatusCommand,
 };
 
 pub const VERSION_STRING: &str = env!("CARGO_PKG_VERSION");
@@ -28,6 +28,7 @@ impl ProverCLI {
             ProverCommand::Restart(args) => restart::run(args).await?,
             ProverCommand::DebugProof(args) => debug_proof::run(args).await?,
             ProverCommand::Stats(args) => stats::run(args, self.config).await?,
+            ProverCommand::Report(args) => report::run(args, self.config).await?,
             ProverCommand::InsertVersion(args) => insert_version::run(args, self.config).await?,
             ProverCommand::InsertBatch(args) => insert_batch::run(args, self.config).await?,
         };
@@ -60,6 +61,10 @@ pub enum ProverCommand {
     Restart(restart::Args),
     #[command(about = "Displays L1 Batch proving stats for a given period")]
     Stats(stats::Options),
+    #[command(
+        about = "Displays proving timeline and cost of L1 Batches with percentile summaries"
+    )]
+    Report(report::Args),
     InsertVersion(insert_version::Args),
     InsertBatch(insert_batch::Args),
 }
@@ -0,0 +1,605 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use chrono::{DateTime, NaiveDateTime, NaiveTime, Timelike, Utc};
+use clap::{Args as ClapArgs, ValueEnum};
+use serde::Serialize;
+use zksync_dal::{ConnectionPool as CoreConnectionPool, Core, CoreDal};
+use zksync_prover_dal::{Connection, ConnectionPool, Prover, ProverDal};
+use zksync_types::{
+    basic_fri_types::AggregationRound, url::SensitiveUrl, L1BatchId, L1BatchNumber, L2ChainId,
+};
+
+use crate::cli::ProverCLIConfig;
+
+#[derive(ValueEnum, Clone, Copy)]
+enum ReportFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// First batch of the report.
+    #[clap(long)]
+    from: L1BatchNumber,
+    /// Last batch of the report, inclusive. Defaults to `--from`.
+    #[clap(long)]
+    to: Option<L1BatchNumber>,
+    #[clap(short, long, value_enum, default_value = "table")]
+    format: ReportFormat,
+    /// Output only percentile summaries over the batches.
+    #[clap(long, default_value("false"))]
+    summary_only: bool,
+    /// Core database URL, used to get the time proofs were submitted to L1. L1 submission is not
+    /// reported if not set.
+    #[clap(long, env("PLI__CORE_DB_URL"))]
+    core_db_url: Option<SensitiveUrl>,
+    /// Price of a GPU machine hour, applied to compute time of provers and compressor.
+    #[clap(long, default_value = "0")]
+    gpu_hour_price: f64,
+    /// Price of a CPU machine hour, applied to compute time of witness generators.
+    #[clap(long, default_value = "0")]
+    cpu_hour_price: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+enum Stage {
+    BasicWitnessGenerator,
+    LeafWitnessGenerator,
+    NodeWitnessGenerator,
+    RecursionTipWitnessGenerator,
+    SchedulerWitnessGenerator,
+    Prover,
+    Compressor,
+    /// Time between the proof is compressed and it's proven on L1.
+    L1Submission,
+    /// Whole batch, from basic witness generator job creation until the proof is compressed.
+    Total,
+}
+
+/// Timings of a single job.
+struct JobTimes {
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    time_taken_secs: Option<f64>,
+}
+
+impl JobTimes {
+    fn new(
+        created_at: NaiveDateTime,
+        processing_started_at: Option<NaiveDateTime>,
+        time_taken: Option<NaiveTime>,
+    ) -> Self {
+        Self {
+            created_at: created_at.and_utc(),
+            started_at: processing_started_at.map(|t| t.and_utc()),
+            time_taken_secs: time_taken.map(|t| {
+                t.num_seconds_from_midnight() as f64 + t.nanosecond() as f64 / 1_000_000_000.0
+            }),
+        }
+    }
+
+    fn finished_at(&self) -> Option<DateTime<Utc>> {
+        let time_taken = chrono::Duration::milliseconds((self.time_taken_secs? * 1000.0) as i64);
+        Some(self.started_at? + time_taken)
+    }
+}
+
+fn secs_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).num_milliseconds() as f64 / 1000.0
+}
+
+/// Serializes the round the same way as it's displayed, e.g. `basic_circuits`.
+fn serialize_round<S: serde::Serializer>(
+    round: &Option<AggregationRound>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match round {
+        Some(round) => serializer.collect_str(round),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Part of the batch proving timeline: a single job or a group of jobs of the same kind.
+#[derive(Debug, Clone, Serialize)]
+struct TimelineEntry {
+    batch: u32,
+    stage: Stage,
+    #[serde(serialize_with = "serialize_round")]
+    aggregation_round: Option<AggregationRound>,
+    circuit_id: Option<u32>,
+    jobs: usize,
+    created_at: Option<DateTime<Utc>>,
+    /// Not set until all the jobs are finished.
+    finished_at: Option<DateTime<Utc>>,
+    /// Average time between job creation and its pick up.
+    queue_wait_secs: Option<f64>,
+    /// Sum of compute time of all the jobs.
+    compute_secs: f64,
+    /// Time from the creation of the first job until the last job is finished.
+    wall_secs: Option<f64>,
+    cost: f64,
+}
+
+impl TimelineEntry {
+    fn from_jobs(
+        batch: L1BatchNumber,
+        stage: Stage,
+        aggregation_round: Option<AggregationRound>,
+        circuit_id: Option<u32>,
+        jobs: &[JobTimes],
+        hour_price: f64,
+    ) -> Self {
+        let created_at = jobs.iter().map(|j| j.created_at).min();
+        let finished: Option<Vec<_>> = jobs.iter().map(JobTimes::finished_at).collect();
+        let finished_at = finished.and_then(|f| f.into_iter().max());
+
+        let waits: Vec<_> = jobs
+            .iter()
+            .filter_map(|j| Some(secs_between(j.created_at, j.started_at?)))
+            .collect();
+        let queue_wait_secs =
+            (!waits.is_empty()).then(|| waits.iter().sum::<f64>() / waits.len() as f64);
+        let compute_secs: f64 = jobs.iter().filter_map(|j| j.time_taken_secs).sum();
+
+        Self {
+            batch: batch.0,
+            stage,
+            aggregation_round,
+            circuit_id,
+            jobs: jobs.len(),
+            created_at,
+            finished_at,
+            queue_wait_secs,
+            compute_secs,
+            wall_secs: created_at.zip(finished_at).map(|(c, f)| secs_between(c, f)),
+            cost: compute_secs / 3600.0 * hour_price,
+        }
+    }
+
+    /// Key to group entries of different batches.
+    fn key(&self) -> (Stage, Option<u8>, Option<u32>) {
+        (
+            self.stage,
+            self.aggregation_round.map(|r| r as u8),
+            self.circuit_id,
+        )
+    }
+}
+
+fn label(
+    stage: Stage,
+    aggregation_round: Option<AggregationRound>,
+    circuit_id: Option<u32>,
+) -> String {
+    match (aggregation_round, circuit_id) {
+        (Some(round), Some(circuit_id)) => format!("{stage}[{round}:{circuit_id}]"),
+        (None, Some(circuit_id)) => format!("{stage}[{circuit_id}]"),
+        (Some(round), None) => format!("{stage}[{round}]"),
+        (None, None) => stage.to_string(),
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Percentiles {
+    p50: f64,
+    p90: f64,
+    p99: f64,
+    max: f64,
+}
+
+impl Percentiles {
+    /// Nearest-rank percentiles of the values. Returns `None` if there are no values.
+    fn new(mut values: Vec<f64>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let percentile = |p: f64| {
+            let rank = ((p / 100.0) * values.len() as f64).ceil() as usize;
+            values[rank.clamp(1, values.len()) - 1]
+        };
+        Some(Self {
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p99: percentile(99.0),
+            max: values[values.len() - 1],
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct StageSummary {
+    stage: Stage,
+    #[serde(serialize_with = "serialize_round")]
+    aggregation_round: Option<AggregationRound>,
+    circuit_id: Option<u32>,
+    batches: usize,
+    queue_wait_secs: Option<Percentiles>,
+    compute_secs: Option<Percentiles>,
+    wall_secs: Option<Percentiles>,
+    total_cost: f64,
+}
+
+fn summarize(entries: &[TimelineEntry]) -> Vec<StageSummary> {
+    let mut groups: BTreeMap<_, Vec<&TimelineEntry>> = BTreeMap::new();
+    for entry in entries {
+        groups.entry(entry.key()).or_default().push(entry);
+    }
+
+    groups
+        .into_iter()
+        .map(
+            |((stage, aggregation_round, circuit_id), entries)| StageSummary {
+                stage,
+                aggregation_round: aggregation_round.map(AggregationRound::from),
+                circuit_id,
+                batches: entries.len(),
+                queue_wait_secs: Percentiles::new(
+                    entries.iter().filter_map(|e| e.queue_wait_secs).collect(),
+                ),
+                compute_secs: Percentiles::new(entries.iter().map(|e| e.compute_secs).collect()),
+                wall_secs: Percentiles::new(entries.iter().filter_map(|e| e.wall_secs).collect()),
+                total_cost: entries.iter().map(|e| e.cost).sum(),
+            },
+        )
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeline: Option<Vec<TimelineEntry>>,
+    summary: Vec<StageSummary>,
+}
+
+pub(crate) async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
+    let to = args.to.unwrap_or(args.from);
+    anyhow::ensure!(args.from <= to, "--from must not be greater than --to");
+
+    let prover_connection_pool = ConnectionPool::<Prover>::singleton(config.db_url)
+        .build()
+        .await
+        .context("failed to build a prover_connection_pool")?;
+    let mut conn = prover_connection_pool
+        .connection()
+        .await
+        .context("failed to get a connection")?;
+
+    let core_connection_pool;
+    let mut core_conn = None;
+    if let Some(core_db_url) = args.core_db_url.clone() {
+        core_connection_pool = CoreConnectionPool::<Core>::singleton(core_db_url)
+            .build()
+            .await
+            .context("failed to build a core connection pool")?;
+        core_conn = Some(
+            core_connection_pool
+                .connection()
+                .await
+                .context("failed to get a core connection")?,
+        );
+    }
+
+    let mut timeline = vec![];
+    for batch in args.from.0..=to.0 {
+        let batch = L1BatchNumber(batch);
+        let mut entries = get_batch_timeline(batch, &args, &mut conn).await;
+        if entries.is_empty() {
+            continue;
+        }
+
+        if let Some(core_conn) = &mut core_conn {
+            let proven_at = core_conn
+                .blocks_web3_dal()
+                .get_l1_batch_details(batch)
+                .await?
+                .and_then(|details| details.base.proven_at);
+            let compressed_at = entries
+                .iter()
+                .find(|e| e.stage == Stage::Compressor)
+                .and_then(|e| e.finished_at);
+            if let Some(compressed_at) = compressed_at {
+                entries.push(TimelineEntry {
+                    batch: batch.0,
+                    stage: Stage::L1Submission,
+                    aggregation_round: None,
+                    circuit_id: None,
+                    jobs: 0,
+                    created_at: Some(compressed_at),
+                    finished_at: proven_at,
+                    queue_wait_secs: None,
+                    compute_secs: 0.0,
+                    wall_secs: proven_at.map(|p| secs_between(compressed_at, p)),
+                    cost: 0.0,
+                });
+            }
+        }
+        timeline.extend(entries);
+    }
+
+    let report = Report {
+        summary: summarize(&timeline),
+        timeline: (!args.summary_only).then_some(timeline),
+    };
+    match args.format {
+        ReportFormat::Table => print_table(&report),
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        ReportFormat::Csv => print_csv(&report),
+    }
+    Ok(())
+}
+
+/// Returns timeline entries of the batch. Empty if there are no jobs for the batch.
+async fn get_batch_timeline(
+    batch: L1BatchNumber,
+    args: &Args,
+    conn: &mut Connection<'_, Prover>,
+) -> Vec<TimelineEntry> {
+    let batch_id = L1BatchId::new(L2ChainId::zero(), batch);
+    let Some(basic) = conn
+        .fri_basic_witness_generator_dal()
+        .get_basic_witness_generator_job_for_batch(batch_id)
+        .await
+    else {
+        return vec![];
+    };
+
+    let cpu_stage = |stage, round, circuit_id, jobs: &[JobTimes]| {
+        TimelineEntry::from_jobs(batch, stage, round, circuit_id, jobs, args.cpu_hour_price)
+    };
+    let mut entries = vec![cpu_stage(
+        Stage::BasicWitnessGenerator,
+        None,
+        None,
+        &[JobTimes::new(
+            basic.created_at,
+            basic.processing_started_at,
+            basic.time_taken,
+        )],
+    )];
+
+    let leaf: Vec<_> = conn
+        .fri_leaf_witness_generator_dal()
+        .get_leaf_witness_generator_jobs_for_batch(batch_id)
+        .await
+        .into_iter()
+        .map(|j| JobTimes::new(j.created_at, j.processing_started_at, j.time_taken))
+        .collect();
+    if !leaf.is_empty() {
+        entries.push(cpu_stage(Stage::LeafWitnessGenerator, None, None, &leaf));
+    }
+
+    let node: Vec<_> = conn
+        .fri_node_witness_generator_dal()
+        .get_node_witness_generator_jobs_for_batch(batch_id)
+        .await
+        .into_iter()
+        .map(|j| JobTimes::new(j.created_at, j.processing_started_at, j.time_taken))
+        .collect();
+    if !node.is_empty() {
+        entries.push(cpu_stage(Stage::NodeWitnessGenerator, None, None, &node));
+    }
+
+    if let Some(j) = conn
+        .fri_recursion_tip_witness_generator_dal()
+        .get_recursion_tip_witness_generator_jobs_for_batch(batch_id)
+        .await
+    {
+        entries.push(cpu_stage(
+            Stage::RecursionTipWitnessGenerator,
+            None,
+            None,
+            &[JobTimes::new(
+                j.created_at,
+                j.processing_started_at,
+                j.time_taken,
+            )],
+        ));
+    }
+
+    if let Some(j) = conn
+        .fri_scheduler_witness_generator_dal()
+        .get_scheduler_witness_generator_jobs_for_batch(batch_id)
+        .await
+    {
+        entries.push(cpu_stage(
+            Stage::SchedulerWitnessGenerator,
+            None,
+            None,
+            &[JobTimes::new(
+                j.created_at,
+                j.processing_started_at,
+                j.time_taken,
+            )],
+        ));
+    }
+
+    for round in AggregationRound::ALL_ROUNDS {
+        let mut circuits: BTreeMap<u32, Vec<JobTimes>> = BTreeMap::new();
+        for j in conn
+            .fri_prover_jobs_dal()
+            .get_prover_jobs_stats_for_batch(batch_id, round)
+            .await
+        {
+            circuits
+                .entry(j.circuit_id)
+                .or_default()
+                .push(JobTimes::new(
+                    j.created_at,
+                    j.processing_started_at,
+                    j.time_taken,
+                ));
+        }
+        for (circuit_id, jobs) in circuits {
+            entries.push(TimelineEntry::from_jobs(
+                batch,
+                Stage::Prover,
+                Some(round),
+                Some(circuit_id),
+                &jobs,
+                args.gpu_hour_price,
+            ));
+        }
+    }
+
+    let mut finished_at = None;
+    if let Some(j) = conn
+        .fri_proof_compressor_dal()
+        .get_proof_compression_job_for_batch(batch_id)
+        .await
+    {
+        let compressor = TimelineEntry::from_jobs(
+            batch,
+            Stage::Compressor,
+            None,
+            None,
+            &[JobTimes::new(
+                j.created_at,
+                j.processing_started_at,
+                j.time_taken,
+            )],
+            args.gpu_hour_price,
+        );
+        finished_at = compressor.finished_at;
+        entries.push(compressor);
+    }
+
+    let created_at = entries[0].created_at;
+    // Showing stages in the order they were started.
+    entries.sort_by_key(|e| e.created_at);
+    entries.push(TimelineEntry {
+        batch: batch.0,
+        stage: Stage::Total,
+        aggregation_round: None,
+        circuit_id: None,
+        jobs: entries.iter().map(|e| e.jobs).sum(),
+        created_at,
+        finished_at,
+        queue_wait_secs: None,
+        compute_secs: entries.iter().map(|e| e.compute_secs).sum(),
+        wall_secs: created_at.zip(finished_at).map(|(c, f)| secs_between(c, f)),
+        cost: entries.iter().map(|e| e.cost).sum(),
+    });
+    entries
+}
+
+fn format_secs(secs: Option<f64>) -> String {
+    secs.map_or_else(|| "-".to_owned(), |s| format!("{s:.1}"))
+}
+
+fn format_percentiles(p: &Option<Percentiles>) -> String {
+    p.as_ref().map_or_else(
+        || "-".to_owned(),
+        |p| format!("{:.1}/{:.1}/{:.1}/{:.1}", p.p50, p.p90, p.p99, p.max),
+    )
+}
+
+fn print_table(report: &Report) {
+    if let Some(timeline) = &report.timeline {
+        println!(
+            "{:<8} {:<40} {:>6} {:>12} {:>12} {:>12} {:>10}",
+            "Batch", "Stage", "Jobs", "Wait (s)", "Compute (s)", "Wall (s)", "Cost"
+        );
+        for e in timeline {
+            println!(
+                "{:<8} {:<40} {:>6} {:>12} {:>12.1} {:>12} {:>10.2}",
+                e.batch,
+                label(e.stage, e.aggregation_round, e.circuit_id),
+                e.jobs,
+                format_secs(e.queue_wait_secs),
+                e.compute_secs,
+                format_secs(e.wall_secs),
+                e.cost
+            );
+        }
+        println!();
+    }
+
+    println!(
+        "{:<40} {:>7} {:>28} {:>28} {:>28} {:>10}",
+        "Stage (p50/p90/p99/max)", "Batches", "Wait (s)", "Compute (s)", "Wall (s)", "Cost"
+    );
+    for s in &report.summary {
+        println!(
+            "{:<40} {:>7} {:>28} {:>28} {:>28} {:>10.2}",
+            label(s.stage, s.aggregation_round, s.circuit_id),
+            s.batches,
+            format_percentiles(&s.queue_wait_secs),
+            format_percentiles(&s.compute_secs),
+            format_percentiles(&s.wall_secs),
+            s.total_cost
+        );
+    }
+}
+
+fn csv_value<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn print_csv(report: &Report) {
+    if let Some(timeline) = &report.timeline {
+        println!("batch,stage,aggregation_round,circuit_id,jobs,created_at,finished_at,queue_wait_secs,compute_secs,wall_secs,cost");
+        for e in timeline {
+            println!(
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                e.batch,
+                e.stage,
+                csv_value(e.aggregation_round),
+                csv_value(e.circuit_id),
+                e.jobs,
+                csv_value(e.created_at.map(|t| t.to_rfc3339())),
+                csv_value(e.finished_at.map(|t| t.to_rfc3339())),
+                csv_value(e.queue_wait_secs),
+                e.compute_secs,
+                csv_value(e.wall_secs),
+                e.cost
+            );
+        }
+        println!();
+    }
+
+    let percentiles = |p: &Option<Percentiles>| match p {
+        Some(p) => format!("{},{},{},{}", p.p50, p.p90, p.p99, p.max),
+        None => ",,,".to_owned(),
+    };
+    println!("stage,aggregation_round,circuit_id,batches,queue_wait_p50,queue_wait_p90,queue_wait_p99,queue_wait_max,compute_p50,compute_p90,compute_p99,compute_max,wall_p50,wall_p90,wall_p99,wall_max,total_cost");
+    for s in &report.summary {
+        println!(
+            "{},{},{},{},{},{},{},{}",
+            s.stage,
+            csv_value(s.aggregation_round),
+            csv_value(s.circuit_id),
+            s.batches,
+            percentiles(&s.queue_wait_secs),
+            percentiles(&s.compute_secs),
+            percentiles(&s.wall_secs),
+            s.total_cost
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        assert!(Percentiles::new(vec![]).is_none());
+
+        let p = Percentiles::new(vec![7.0]).unwrap();
+        assert_eq!((p.p50, p.p90, p.p99, p.max), (7.0, 7.0, 7.0, 7.0));
+
+        let values = (1..=10).rev().map(f64::from).collect();
+        let p = Percentiles::new(values).unwrap();
+        assert_eq!((p.p50, p.p90, p.p99, p.max), (5.0, 9.0, 10.0, 10.0));
+
+        let values = (1..=200).map(f64::from).collect();
+        let p = Percentiles::new(values).unwrap();
+        assert_eq!((p.p50, p.p90, p.p99, p.max), (100.0, 180.0, 198.0, 200.0));
+    }
+}
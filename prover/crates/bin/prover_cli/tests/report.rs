@@ -0,0 +1,208 @@
+use assert_cmd::Command;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use zksync_dal::ConnectionPool;
+use zksync_prover_dal::{Connection, Prover, ProverDal};
+use zksync_types::{
+    basic_fri_types::AggregationRound,
+    protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+    L1BatchId, L1BatchNumber, L2ChainId,
+};
+
+const SEEDED_REPORT_CSV_STDOUT: &str = "batch,stage,aggregation_round,circuit_id,jobs,created_at,finished_at,queue_wait_secs,compute_secs,wall_secs,cost
+1,basic_witness_generator,,,1,2024-01-01T00:00:00+00:00,2024-01-01T00:00:30+00:00,10,20,30,0
+1,prover,basic_circuits,1,2,2024-01-01T00:01:00+00:00,2024-01-01T00:02:30+00:00,45,90,90,0
+1,total,,,3,2024-01-01T00:00:00+00:00,,,110,,0
+2,basic_witness_generator,,,1,2024-01-01T01:00:00+00:00,2024-01-01T01:01:10+00:00,30,40,70,0
+2,prover,basic_circuits,1,1,2024-01-01T01:01:00+00:00,2024-01-01T01:02:40+00:00,10,90,100,0
+2,total,,,2,2024-01-01T01:00:00+00:00,,,130,,0
+
+stage,aggregation_round,circuit_id,batches,queue_wait_p50,queue_wait_p90,queue_wait_p99,queue_wait_max,compute_p50,compute_p90,compute_p99,compute_max,wall_p50,wall_p90,wall_p99,wall_max,total_cost
+basic_witness_generator,,,2,10,30,30,30,20,40,40,40,30,70,70,70,0
+prover,basic_circuits,1,2,10,45,45,45,90,90,90,90,90,100,100,100,0
+total,,,2,,,,,110,130,130,130,,,,,0
+";
+
+#[test]
+#[doc = "prover_cli report"]
+fn pli_report_empty_fails() {
+    Command::cargo_bin("prover_cli")
+        .unwrap()
+        .arg("report")
+        .assert()
+        .failure();
+}
+
+#[test]
+#[doc = "prover_cli report --help"]
+fn pli_report_help_succeeds() {
+    Command::cargo_bin("prover_cli")
+        .unwrap()
+        .arg("report")
+        .arg("--help")
+        .assert()
+        .success();
+}
+
+#[test]
+#[doc = "prover_cli report --from 10 --to 5"]
+fn pli_report_invalid_range_fails() {
+    Command::cargo_bin("prover_cli")
+        .unwrap()
+        .arg("report")
+        .args(["--from", "10", "--to", "5"])
+        .assert()
+        .failure();
+}
+
+#[tokio::test]
+#[doc = "prover_cli report --from 10000 --to 10001 --format json"]
+async fn pli_report_of_non_existing_batches_succeeds() {
+    let connection_pool = ConnectionPool::<Prover>::prover_test_pool().await;
+    let mut connection = connection_pool.connection().await.unwrap();
+
+    connection
+        .fri_protocol_versions_dal()
+        .save_prover_protocol_version(
+            ProtocolSemanticVersion::default(),
+            L1VerifierConfig::default(),
+        )
+        .await
+        .unwrap();
+
+    Command::cargo_bin("prover_cli")
+        .unwrap()
+        .arg(connection_pool.database_url().expose_str())
+        .arg("report")
+        .args(["--from", "10000", "--to", "10001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout("{\n  \"timeline\": [],\n  \"summary\": []\n}\n");
+}
+
+fn at(hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 1, 1)
+        .unwrap()
+        .and_hms_opt(hour, min, sec)
+        .unwrap()
+}
+
+fn secs(secs: u32) -> NaiveTime {
+    NaiveTime::from_num_seconds_from_midnight_opt(secs, 0).unwrap()
+}
+
+async fn insert_bwg_job(
+    batch_number: L1BatchNumber,
+    created_at: NaiveDateTime,
+    processing_started_at: NaiveDateTime,
+    time_taken: NaiveTime,
+    connection: &mut Connection<'_, Prover>,
+) {
+    connection
+        .fri_basic_witness_generator_dal()
+        .save_witness_inputs(
+            L1BatchId::new(L2ChainId::zero(), batch_number),
+            "",
+            ProtocolSemanticVersion::default(),
+            DateTime::<Utc>::default(),
+        )
+        .await
+        .unwrap();
+    connection
+        .cli_test_dal()
+        .update_bwg_job_times(batch_number, created_at, processing_started_at, time_taken)
+        .await;
+}
+
+async fn insert_prover_job(
+    batch_number: L1BatchNumber,
+    sequence_number: usize,
+    created_at: NaiveDateTime,
+    processing_started_at: NaiveDateTime,
+    time_taken: NaiveTime,
+    connection: &mut Connection<'_, Prover>,
+) {
+    connection
+        .fri_prover_jobs_dal()
+        .insert_prover_job(
+            L1BatchId::new(L2ChainId::zero(), batch_number),
+            1,
+            0,
+            sequence_number,
+            AggregationRound::BasicCircuits,
+            "",
+            false,
+            ProtocolSemanticVersion::default(),
+            DateTime::<Utc>::default(),
+        )
+        .await;
+    connection
+        .cli_test_dal()
+        .update_prover_job_times(
+            1,
+            AggregationRound::BasicCircuits as i64,
+            batch_number,
+            sequence_number,
+            created_at,
+            processing_started_at,
+            time_taken,
+        )
+        .await;
+}
+
+#[tokio::test]
+#[doc = "prover_cli report --from 1 --to 2 --format csv"]
+async fn pli_report_of_seeded_batches_succeeds() {
+    let connection_pool = ConnectionPool::<Prover>::prover_test_pool().await;
+    let mut connection = connection_pool.connection().await.unwrap();
+
+    connection
+        .fri_protocol_versions_dal()
+        .save_prover_protocol_version(
+            ProtocolSemanticVersion::default(),
+            L1VerifierConfig::default(),
+        )
+        .await
+        .unwrap();
+
+    let batch = L1BatchNumber(1);
+    insert_bwg_job(batch, at(0, 0, 0), at(0, 0, 10), secs(20), &mut connection).await;
+    insert_prover_job(
+        batch,
+        0,
+        at(0, 1, 0),
+        at(0, 1, 30),
+        secs(60),
+        &mut connection,
+    )
+    .await;
+    insert_prover_job(
+        batch,
+        1,
+        at(0, 1, 0),
+        at(0, 2, 0),
+        secs(30),
+        &mut connection,
+    )
+    .await;
+
+    let batch = L1BatchNumber(2);
+    insert_bwg_job(batch, at(1, 0, 0), at(1, 0, 30), secs(40), &mut connection).await;
+    insert_prover_job(
+        batch,
+        0,
+        at(1, 1, 0),
+        at(1, 1, 10),
+        secs(90),
+        &mut connection,
+    )
+    .await;
+
+    Command::cargo_bin("prover_cli")
+        .unwrap()
+        .arg(connection_pool.database_url().expose_str())
+        .arg("report")
+        .args(["--from", "1", "--to", "2", "--format", "csv"])
+        .assert()
+        .success()
+        .stdout(SEEDED_REPORT_CSV_STDOUT);
+}
@@ -4,15 +4,22 @@ use async_trait::async_trait;
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover};
 use zksync_prover_interface::api::{ProofGenerationDataRequest, ProofGenerationDataResponse};
+use zksync_types::L2ChainId;
 
-use crate::{client::ProverApiClient, proof_data_manager::ProofDataManager, traits::PeriodicApi};
+use crate::{
+    client::ProverApiClient, metrics::METRICS, proof_data_manager::ProofDataManager,
+    traits::PeriodicApi,
+};
 
 /// Poller structure that will periodically check the prover API for new proof generation data.
 /// Fetched data is stored to the database/object store for further processing.
+///
+/// If `chain_id` is set, data returned by the API must belong to this chain.
 #[derive(Debug)]
 pub struct ProofGenDataFetcher {
     manager: ProofDataManager,
     client: ProverApiClient,
+    chain_id: Option<L2ChainId>,
 }
 
 /// The path to the API endpoint that returns the next proof generation data.
@@ -23,11 +30,16 @@ impl ProofGenDataFetcher {
         blob_store: Arc<dyn ObjectStore>,
         base_url: String,
         pool: ConnectionPool<Prover>,
+        chain_id: Option<L2ChainId>,
     ) -> Self {
         let api_url = format!("{base_url}{PROOF_GENERATION_DATA_PATH}");
         let client = ProverApiClient::new(api_url);
         let manager = ProofDataManager::new(blob_store.clone(), pool.clone());
-        Self { manager, client }
+        Self {
+            manager,
+            client,
+            chain_id,
+        }
     }
 }
 
@@ -58,8 +70,26 @@ impl PeriodicApi for ProofGenDataFetcher {
     async fn handle_response(&self, _: (), response: Self::Response) -> anyhow::Result<()> {
         match response {
             ProofGenerationDataResponse::Success(Some(data)) => {
-                tracing::info!("Received proof gen data for: {:?}", data.l1_batch_number);
+                if let Some(expected_chain_id) = self.chain_id {
+                    if data.chain_id != expected_chain_id {
+                        tracing::error!(
+                            "Received proof gen data for batch {} of chain {} from the API of chain {}, skipping",
+                            data.l1_batch_number,
+                            data.chain_id,
+                            expected_chain_id
+                        );
+                        METRICS.chain_id_mismatch[&expected_chain_id.to_string()].inc();
+                        return Ok(());
+                    }
+                }
+                tracing::info!(
+                    "Received proof gen data for: {:?} (chain {})",
+                    data.l1_batch_number,
+                    data.chain_id
+                );
+                let chain_id = data.chain_id;
                 self.manager.save_proof_gen_data(*data).await?;
+                METRICS.received_proof_gen_data[&chain_id.to_string()].inc();
             }
             ProofGenerationDataResponse::Success(None) => {
                 tracing::info!("There are currently no pending batches to be proven");
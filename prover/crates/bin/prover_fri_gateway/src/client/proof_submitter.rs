@@ -1,22 +1,30 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover};
 use zksync_prover_interface::api::{SubmitProofRequest, SubmitProofResponse};
-use zksync_types::L1BatchId;
+use zksync_types::{L1BatchId, L2ChainId};
 
-use crate::{client::ProverApiClient, proof_data_manager::ProofDataManager, traits::PeriodicApi};
+use crate::{
+    client::ProverApiClient, metrics::METRICS, proof_data_manager::ProofDataManager,
+    traits::PeriodicApi,
+};
 
 /// The path to the API endpoint that submits the proof.
 const SUBMIT_PROOF_PATH: &str = "/submit_proof";
 
 /// Poller structure that will periodically check the database for new proofs to submit.
 /// Once a new proof is detected, it will be sent to the prover API.
+///
+/// If `chain_id` is set, only proofs for this chain are sent; otherwise, proofs for all chains are sent
+/// to the same API.
 #[derive(Debug)]
 pub struct ProofSubmitter {
     manager: ProofDataManager,
     client: ProverApiClient,
+    chain_id: Option<L2ChainId>,
 }
 
 impl ProofSubmitter {
@@ -24,11 +32,16 @@ impl ProofSubmitter {
         blob_store: Arc<dyn ObjectStore>,
         base_url: String,
         pool: ConnectionPool<Prover>,
+        chain_id: Option<L2ChainId>,
     ) -> Self {
         let api_url = format!("{base_url}{SUBMIT_PROOF_PATH}");
         let client = ProverApiClient::new(api_url);
         let manager = ProofDataManager::new(blob_store.clone(), pool.clone());
-        Self { manager, client }
+        Self {
+            manager,
+            client,
+            chain_id,
+        }
     }
 }
 
@@ -42,7 +55,7 @@ impl PeriodicApi for ProofSubmitter {
     async fn get_next_request(&self) -> anyhow::Result<Option<(Self::JobId, SubmitProofRequest)>> {
         let Some((l1_batch_id, proof)) = self
             .manager
-            .get_next_proof()
+            .get_next_proof(self.chain_id)
             .await
             .map_err(|e| anyhow::anyhow!(e))?
         else {
@@ -75,6 +88,16 @@ impl PeriodicApi for ProofSubmitter {
     ) -> anyhow::Result<()> {
         tracing::info!("Received response: {:?}", response);
         self.manager.save_successful_sent_proof(job_id).await?;
+        METRICS.submitted_proofs[&job_id.chain_id().to_string()].inc();
+        Ok(())
+    }
+
+    async fn handle_error(&self, job_id: L1BatchId, err: &reqwest::Error) -> anyhow::Result<()> {
+        // The server responds with 422 if the proof didn't pass verification. Resending the same proof
+        // is pointless, so the batch is proven again.
+        if err.status() == Some(StatusCode::UNPROCESSABLE_ENTITY) {
+            self.manager.requeue_rejected_proof(job_id).await?;
+        }
         Ok(())
     }
 }
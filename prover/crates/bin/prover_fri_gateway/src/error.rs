@@ -3,6 +3,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use zksync_object_store::ObjectStoreError;
+use zksync_types::L2ChainId;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ProcessorError {
@@ -10,6 +11,10 @@ pub enum ProcessorError {
     ObjectStoreErr(#[from] ObjectStoreError),
     #[error("Database query failed: {0}")]
     DalErr(#[from] zksync_prover_dal::DalError),
+    #[error("Failed to requeue batch for proving: {0:#}")]
+    RequeueErr(anyhow::Error),
+    #[error("Chain {0} is not served by the prover cluster")]
+    UnknownChain(L2ChainId),
 }
 
 impl ProcessorError {
@@ -17,6 +22,8 @@ impl ProcessorError {
         match self {
             Self::ObjectStoreErr(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::DalErr(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::RequeueErr(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::UnknownChain(_) => StatusCode::BAD_REQUEST,
         }
     }
 }
@@ -10,7 +10,7 @@ use zksync_config::configs::fri_prover_gateway::ApiMode;
 use zksync_core_leftovers::temp_config_store::{load_database_secrets, load_general_config};
 use zksync_env_config::object_store::ProverObjectStoreConfig;
 use zksync_object_store::ObjectStoreFactory;
-use zksync_prover_dal::{ConnectionPool, Prover};
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_task_management::ManagedTasks;
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 
@@ -19,6 +19,8 @@ mod error;
 mod metrics;
 mod proof_data_manager;
 mod server;
+#[cfg(test)]
+mod tests;
 mod traits;
 
 #[tokio::main]
@@ -67,36 +69,71 @@ async fn main() -> anyhow::Result<()> {
 
     tracing::info!("Starting Fri Prover Gateway in mode {:?}", config.api_mode);
 
+    let mut connection = pool
+        .connection()
+        .await
+        .context("failed to get connection")?;
+    let chain_priorities = config
+        .chains
+        .iter()
+        .map(|chain| (chain.chain_id, chain.priority))
+        .collect();
+    connection
+        .fri_chains_dal()
+        .set_chain_priorities(&chain_priorities)
+        .await
+        .context("failed to set chain priorities")?;
+    drop(connection);
+
     let tasks = match &config.api_mode {
         ApiMode::Legacy => {
-            let proof_submitter = ProofSubmitter::new(
-                store_factory.create_store().await?,
-                config.api_url.clone(),
-                pool.clone(),
-            );
-            let proof_gen_data_fetcher = ProofGenDataFetcher::new(
-                store_factory.create_store().await?,
-                config.api_url.clone(),
-                pool,
-            );
-
-            vec![
-                tokio::spawn(
-                    PrometheusExporterConfig::pull(config.prometheus_listener_port)
-                        .run(stop_receiver.clone()),
-                ),
-                tokio::spawn(
+            // Without explicitly configured chains, proofs for all chains are sent to `api_url`.
+            let upstreams: Vec<_> = if config.chains.is_empty() {
+                vec![(None, config.api_url.clone())]
+            } else {
+                config
+                    .chains
+                    .iter()
+                    .map(|chain| (Some(chain.chain_id), chain.api_url.clone()))
+                    .collect()
+            };
+
+            let mut tasks = vec![tokio::spawn(
+                PrometheusExporterConfig::pull(config.prometheus_listener_port)
+                    .run(stop_receiver.clone()),
+            )];
+            for (chain_id, api_url) in upstreams {
+                tracing::info!("Serving chain {chain_id:?} with proof data handler at {api_url}");
+                let proof_submitter = ProofSubmitter::new(
+                    store_factory.create_store().await?,
+                    api_url.clone(),
+                    pool.clone(),
+                    chain_id,
+                );
+                let proof_gen_data_fetcher = ProofGenDataFetcher::new(
+                    store_factory.create_store().await?,
+                    api_url,
+                    pool.clone(),
+                    chain_id,
+                );
+                tasks.push(tokio::spawn(
                     proof_gen_data_fetcher.run(config.api_poll_duration(), stop_receiver.clone()),
-                ),
-                tokio::spawn(proof_submitter.run(config.api_poll_duration(), stop_receiver)),
-            ]
+                ));
+                tasks.push(tokio::spawn(
+                    proof_submitter.run(config.api_poll_duration(), stop_receiver.clone()),
+                ));
+            }
+            tasks
         }
         ApiMode::ProverCluster => {
             let port = config
                 .port
                 .expect("Port must be specified in ProverCluster mode");
 
-            let processor = ProofDataManager::new(store_factory.create_store().await?, pool);
+            // Without explicitly configured chains, proof generation data for all chains is accepted.
+            let served_chains = config.chains.iter().map(|chain| chain.chain_id).collect();
+            let processor = ProofDataManager::new(store_factory.create_store().await?, pool)
+                .with_served_chains(served_chains);
 
             let api = server::Api::new(processor.clone(), port);
 
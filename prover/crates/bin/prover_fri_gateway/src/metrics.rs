@@ -5,6 +5,18 @@ use vise::{Counter, LabeledFamily, Metrics};
 pub(crate) struct ProverFriGatewayMetrics {
     #[metrics(labels = ["service_name"])]
     pub http_error: LabeledFamily<&'static str, Counter>,
+    /// Number of batches received from the proof data handlers.
+    #[metrics(labels = ["chain_id"])]
+    pub received_proof_gen_data: LabeledFamily<String, Counter>,
+    /// Number of proofs submitted to the proof data handlers.
+    #[metrics(labels = ["chain_id"])]
+    pub submitted_proofs: LabeledFamily<String, Counter>,
+    /// Number of proofs rejected by the proof data handlers and queued for proving again.
+    #[metrics(labels = ["chain_id"])]
+    pub rejected_proofs: LabeledFamily<String, Counter>,
+    /// Number of batches received from the proof data handler of a chain that belong to another chain.
+    #[metrics(labels = ["chain_id"])]
+    pub chain_id_mismatch: LabeledFamily<String, Counter>,
 }
 
 #[vise::register]
@@ -1,5 +1,6 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
+use anyhow::Context as _;
 use zksync_object_store::{ObjectStore, ObjectStoreError};
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_interface::{
@@ -7,23 +8,49 @@ use zksync_prover_interface::{
     outputs::{L1BatchProofForL1, L1BatchProofForL1Key},
     Bincode,
 };
-use zksync_types::{prover_dal::ProofCompressionJobStatus, L1BatchId};
+use zksync_types::{
+    basic_fri_types::AggregationRound, prover_dal::ProofCompressionJobStatus, L1BatchId, L2ChainId,
+};
 
-use super::error::ProcessorError;
+use super::{error::ProcessorError, metrics::METRICS};
 
 #[derive(Debug, Clone)]
 pub struct ProofDataManager {
     blob_store: Arc<dyn ObjectStore>,
     pool: ConnectionPool<Prover>,
+    /// Chains served by the prover cluster. If empty, all chains are served.
+    served_chains: HashSet<L2ChainId>,
 }
 
 impl ProofDataManager {
     pub fn new(blob_store: Arc<dyn ObjectStore>, pool: ConnectionPool<Prover>) -> Self {
-        Self { blob_store, pool }
+        Self {
+            blob_store,
+            pool,
+            served_chains: HashSet::new(),
+        }
+    }
+
+    /// Restricts requests accepted by the manager to the specified chains. An empty set means that all chains
+    /// are served.
+    pub fn with_served_chains(mut self, served_chains: HashSet<L2ChainId>) -> Self {
+        self.served_chains = served_chains;
+        self
     }
 
+    fn ensure_chain_is_served(&self, chain_id: L2ChainId) -> Result<(), ProcessorError> {
+        if self.served_chains.is_empty() || self.served_chains.contains(&chain_id) {
+            Ok(())
+        } else {
+            Err(ProcessorError::UnknownChain(chain_id))
+        }
+    }
+
+    /// Returns the next proof to be sent to the server. If `chain_id` is specified, only proofs for this chain
+    /// are considered.
     pub(crate) async fn get_next_proof(
         &self,
+        chain_id: Option<L2ChainId>,
     ) -> Result<Option<(L1BatchId, L1BatchProofForL1)>, ProcessorError> {
         let Some((l1_batch_id, protocol_version, status)) = self
             .pool
@@ -31,7 +58,7 @@ impl ProofDataManager {
             .await
             .unwrap()
             .fri_proof_compressor_dal()
-            .get_least_proven_block_not_sent_to_server()
+            .get_least_proven_block_not_sent_to_server(chain_id)
             .await
         else {
             return Ok(None);
@@ -70,18 +97,34 @@ impl ProofDataManager {
         Ok(Some((l1_batch_id, proof)))
     }
 
+    /// Returns the final proof for the batch if it was generated. A proof left in the blob store by a previous
+    /// (rejected) proving attempt is not returned until the batch is proven again.
     pub(crate) async fn get_proof_for_batch(
         &self,
         batch_id: L1BatchId,
     ) -> Result<Option<L1BatchProofForL1>, ProcessorError> {
-        let protocol_version = self
-            .pool
-            .connection()
-            .await
-            .unwrap()
+        self.ensure_chain_is_served(batch_id.chain_id())?;
+
+        let mut connection = self.pool.connection().await.unwrap();
+        let compression_job = connection
+            .fri_proof_compressor_dal()
+            .get_proof_compression_job_for_batch(batch_id)
+            .await;
+        let is_proven = compression_job.is_some_and(|job| {
+            matches!(
+                job.status,
+                ProofCompressionJobStatus::Successful | ProofCompressionJobStatus::SentToServer
+            )
+        });
+        if !is_proven {
+            return Ok(None);
+        }
+
+        let protocol_version = connection
             .fri_basic_witness_generator_dal()
             .protocol_version_for_l1_batch(batch_id)
             .await;
+        drop(connection);
 
         let Some(protocol_version) = protocol_version else {
             return Ok(None);
@@ -118,10 +161,59 @@ impl ProofDataManager {
         Ok(())
     }
 
+    /// Queues the batch for proving from scratch after its final proof was rejected by the server.
+    /// Jobs of all aggregation rounds after the basic witness generation and the compression job are removed,
+    /// so that the rejected proof is neither served nor sent again.
+    pub(crate) async fn requeue_rejected_proof(
+        &self,
+        batch_id: L1BatchId,
+    ) -> Result<(), ProcessorError> {
+        self.ensure_chain_is_served(batch_id.chain_id())?;
+        tracing::warn!("Proof for batch {batch_id} was rejected, requeueing the batch for proving");
+
+        let mut connection = self.pool.connection().await.unwrap();
+        let mut transaction = connection.start_transaction().await?;
+
+        transaction
+            .fri_proof_compressor_dal()
+            .delete_batch_data(batch_id)
+            .await
+            .context("failed to delete proof compression job for batch")
+            .map_err(ProcessorError::RequeueErr)?;
+        transaction
+            .fri_prover_jobs_dal()
+            .delete_batch_data(batch_id)
+            .await
+            .context("failed to delete prover jobs for batch")
+            .map_err(ProcessorError::RequeueErr)?;
+        for round in [
+            AggregationRound::LeafAggregation,
+            AggregationRound::NodeAggregation,
+            AggregationRound::RecursionTip,
+            AggregationRound::Scheduler,
+        ] {
+            transaction
+                .fri_witness_generator_dal()
+                .delete_witness_generator_data_for_batch(batch_id, round)
+                .await
+                .with_context(|| format!("failed to delete {round:?} witness jobs for batch"))
+                .map_err(ProcessorError::RequeueErr)?;
+        }
+        transaction
+            .fri_basic_witness_generator_dal()
+            .requeue_witness_inputs_job(batch_id)
+            .await?;
+        transaction.commit().await?;
+
+        METRICS.rejected_proofs[&batch_id.chain_id().to_string()].inc();
+        Ok(())
+    }
+
     pub(crate) async fn save_proof_gen_data(
         &self,
         data: ProofGenerationData,
     ) -> Result<(), ProcessorError> {
+        self.ensure_chain_is_served(data.chain_id)?;
         let batch_id = L1BatchId::new(data.chain_id, data.l1_batch_number);
 
         let witness_inputs = self
@@ -9,7 +9,7 @@ use axum::{
 use tokio::sync::watch;
 use zksync_prover_interface::api::{
     PollGeneratedProofsRequest, PollGeneratedProofsResponse, ProofGenerationData,
-    SubmitProofGenerationDataResponse,
+    RejectProofRequest, RejectProofResponse, SubmitProofGenerationDataResponse,
 };
 
 use crate::{error::ProcessorError, proof_data_manager::ProofDataManager};
@@ -23,6 +23,7 @@ impl Api {
     pub fn new(processor: ProofDataManager, port: u16) -> Self {
         let router = Router::new()
             .route("/poll_generated_proofs", post(Api::get_generated_proofs))
+            .route("/reject_proof", post(Api::reject_proof))
             .route(
                 "/submit_request_for_proofs",
                 post(Api::save_proof_generation_data),
@@ -78,6 +79,19 @@ impl Api {
         Ok(Json(response))
     }
 
+    async fn reject_proof(
+        State(processor): State<ProofDataManager>,
+        Json(request): Json<RejectProofRequest>,
+    ) -> Result<Json<RejectProofResponse>, ProcessorError> {
+        tracing::info!("Received proof rejection for batch {}", request.l1_batch_id);
+
+        processor
+            .requeue_rejected_proof(request.l1_batch_id)
+            .await?;
+
+        Ok(Json(RejectProofResponse))
+    }
+
     async fn save_proof_generation_data(
         State(processor): State<ProofDataManager>,
         Json(data): Json<ProofGenerationData>,
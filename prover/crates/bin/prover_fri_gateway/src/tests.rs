@@ -0,0 +1,262 @@
+use std::time::Duration;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use circuit_definitions::snark_wrapper::franklin_crypto::bellman::plonk::better_better_cs::proof::Proof;
+use zksync_object_store::{MockObjectStore, ObjectStore};
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+use zksync_prover_interface::{
+    api::{SubmitProofRequest, SubmitProofResponse},
+    outputs::{L1BatchProofForL1, L1BatchProofForL1Key, PlonkL1BatchProofForL1},
+};
+use zksync_types::{
+    protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+    prover_dal::ProofCompressionJobStatus,
+    L1BatchId, L1BatchNumber, L2ChainId,
+};
+
+use crate::{
+    client::proof_submitter::ProofSubmitter, error::ProcessorError,
+    proof_data_manager::ProofDataManager, traits::PeriodicApi,
+};
+
+const REJECTED_PROOF_COORDS: [[u8; 32]; 4] = [[1; 32]; 4];
+const VALID_PROOF_COORDS: [[u8; 32]; 4] = [[2; 32]; 4];
+
+fn mock_proof(
+    aggregation_result_coords: [[u8; 32]; 4],
+    protocol_version: ProtocolSemanticVersion,
+) -> L1BatchProofForL1 {
+    L1BatchProofForL1::new_plonk(PlonkL1BatchProofForL1 {
+        aggregation_result_coords,
+        scheduler_proof: Proof::empty(),
+        protocol_version,
+    })
+}
+
+/// Emulates the proof data handler, which rejects proofs with `REJECTED_PROOF_COORDS`.
+async fn submit_proof(Json(request): Json<SubmitProofRequest>) -> Response {
+    let SubmitProofRequest::Proof(proof) = request;
+    let proof: L1BatchProofForL1 = (*proof).into();
+    if proof.aggregation_result_coords() == REJECTED_PROOF_COORDS {
+        (StatusCode::UNPROCESSABLE_ENTITY, "proof is rejected").into_response()
+    } else {
+        Json(SubmitProofResponse::Success).into_response()
+    }
+}
+
+async fn spawn_proof_data_handler() -> String {
+    let router = Router::new().route("/submit_proof/:l1_batch_number", post(submit_proof));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let local_addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await });
+    format!("http://{local_addr}")
+}
+
+/// Emulates the prover subsystem processing the batch: the basic witness generation job is executed, and the final
+/// proof is compressed and stored.
+async fn prove_batch(
+    pool: &ConnectionPool<Prover>,
+    blob_store: &dyn ObjectStore,
+    batch_id: L1BatchId,
+    protocol_version: ProtocolSemanticVersion,
+    aggregation_result_coords: [[u8; 32]; 4],
+) {
+    let mut connection = pool.connection().await.unwrap();
+    let picked_batch = connection
+        .fri_basic_witness_generator_dal()
+        .get_next_basic_circuit_witness_job(protocol_version, "test")
+        .await;
+    assert_eq!(picked_batch, Some(batch_id));
+    connection
+        .fri_basic_witness_generator_dal()
+        .mark_witness_job_as_successful(batch_id, Duration::from_secs(1))
+        .await;
+
+    connection
+        .fri_proof_compressor_dal()
+        .insert_proof_compression_job(batch_id, "fri_proof", protocol_version, Default::default())
+        .await;
+    let proof_key = L1BatchProofForL1Key::Prover((batch_id, protocol_version));
+    let proof_url = blob_store
+        .put(
+            proof_key,
+            &mock_proof(aggregation_result_coords, protocol_version),
+        )
+        .await
+        .unwrap();
+    connection
+        .fri_proof_compressor_dal()
+        .mark_proof_compression_job_successful(batch_id, Duration::from_secs(1), &proof_url)
+        .await;
+}
+
+#[tokio::test]
+async fn rejected_proof_is_requeued_and_proven_again() {
+    let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+    let blob_store = MockObjectStore::arc();
+    let protocol_version = ProtocolSemanticVersion::default();
+    let batch_id = L1BatchId::new(L2ChainId::default(), L1BatchNumber(1));
+
+    let mut connection = pool.connection().await.unwrap();
+    connection
+        .fri_protocol_versions_dal()
+        .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+        .await
+        .unwrap();
+    connection
+        .fri_basic_witness_generator_dal()
+        .save_witness_inputs(
+            batch_id,
+            "witness_inputs",
+            protocol_version,
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    drop(connection);
+
+    let api_url = spawn_proof_data_handler().await;
+    let submitter = ProofSubmitter::new(blob_store.clone(), api_url, pool.clone(), None);
+    let manager = ProofDataManager::new(blob_store.clone(), pool.clone());
+
+    prove_batch(
+        &pool,
+        &*blob_store,
+        batch_id,
+        protocol_version,
+        REJECTED_PROOF_COORDS,
+    )
+    .await;
+
+    // The first proof is rejected by the server.
+    let (job_id, request) = submitter.get_next_request().await.unwrap().unwrap();
+    assert_eq!(job_id, batch_id);
+    let err = submitter.send_request(job_id, request).await.unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::UNPROCESSABLE_ENTITY));
+    submitter.handle_error(job_id, &err).await.unwrap();
+
+    // The rejected proof must be neither submitted nor served anymore.
+    assert!(submitter.get_next_request().await.unwrap().is_none());
+    assert!(manager
+        .get_proof_for_batch(batch_id)
+        .await
+        .unwrap()
+        .is_none());
+    let mut connection = pool.connection().await.unwrap();
+    let compression_job = connection
+        .fri_proof_compressor_dal()
+        .get_proof_compression_job_for_batch(batch_id)
+        .await;
+    assert!(compression_job.is_none());
+    drop(connection);
+
+    // `prove_batch` checks that the basic witness generation job is queued again.
+    prove_batch(
+        &pool,
+        &*blob_store,
+        batch_id,
+        protocol_version,
+        VALID_PROOF_COORDS,
+    )
+    .await;
+
+    let proof = manager
+        .get_proof_for_batch(batch_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.aggregation_result_coords(), VALID_PROOF_COORDS);
+
+    let (job_id, request) = submitter.get_next_request().await.unwrap().unwrap();
+    assert_eq!(job_id, batch_id);
+    let response = submitter.send_request(job_id, request).await.unwrap();
+    submitter.handle_response(job_id, response).await.unwrap();
+
+    let mut connection = pool.connection().await.unwrap();
+    let compression_job = connection
+        .fri_proof_compressor_dal()
+        .get_proof_compression_job_for_batch(batch_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        compression_job.status,
+        ProofCompressionJobStatus::SentToServer
+    );
+}
+
+#[tokio::test]
+async fn proof_is_not_served_before_compression() {
+    let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+    let blob_store = MockObjectStore::arc();
+    let protocol_version = ProtocolSemanticVersion::default();
+    let batch_id = L1BatchId::new(L2ChainId::default(), L1BatchNumber(1));
+
+    let mut connection = pool.connection().await.unwrap();
+    connection
+        .fri_protocol_versions_dal()
+        .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+        .await
+        .unwrap();
+    connection
+        .fri_basic_witness_generator_dal()
+        .save_witness_inputs(
+            batch_id,
+            "witness_inputs",
+            protocol_version,
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    drop(connection);
+
+    // A proof left over from a previous proving attempt.
+    blob_store
+        .put(
+            L1BatchProofForL1Key::Prover((batch_id, protocol_version)),
+            &mock_proof(REJECTED_PROOF_COORDS, protocol_version),
+        )
+        .await
+        .unwrap();
+
+    let manager = ProofDataManager::new(blob_store, pool);
+    assert!(manager
+        .get_proof_for_batch(batch_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn requests_for_unknown_chains_are_rejected() {
+    let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+    let served_chain_id = L2ChainId::from(270_u32);
+    let manager = ProofDataManager::new(MockObjectStore::arc(), pool)
+        .with_served_chains([served_chain_id].into());
+
+    let unknown_batch_id = L1BatchId::new(L2ChainId::from(271_u32), L1BatchNumber(1));
+    let err = manager
+        .get_proof_for_batch(unknown_batch_id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ProcessorError::UnknownChain(chain_id) if chain_id == unknown_batch_id.chain_id()),
+        "{err:?}"
+    );
+    let err = manager
+        .requeue_rejected_proof(unknown_batch_id)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProcessorError::UnknownChain(_)), "{err:?}");
+
+    let served_batch_id = L1BatchId::new(served_chain_id, L1BatchNumber(1));
+    assert!(manager
+        .get_proof_for_batch(served_batch_id)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -30,6 +30,16 @@ pub(crate) trait PeriodicApi: Sync + Send + 'static + Sized {
         response: Self::Response,
     ) -> anyhow::Result<()>;
 
+    /// Handles a failed request to the API. By default, the error is only logged and the request
+    /// is retried on the next iteration.
+    async fn handle_error(
+        &self,
+        _job_id: Self::JobId,
+        _err: &reqwest::Error,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Runs `get_next_request` -> `send_request` -> `handle_response` in a loop.
     async fn run(
         self,
@@ -59,6 +69,7 @@ pub(crate) trait PeriodicApi: Sync + Send + 'static + Sized {
                     Err(err) => {
                         METRICS.http_error[&Self::SERVICE_NAME].inc();
                         tracing::error!("HTTP request failed due to error: {}", err);
+                        self.handle_error(job_id, &err).await?;
                     }
                 }
             }
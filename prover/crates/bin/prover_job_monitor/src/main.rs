@@ -17,7 +17,8 @@ use zksync_prover_job_monitor::{
     job_requeuer::{ProofCompressorJobRequeuer, ProverJobRequeuer, WitnessGeneratorJobRequeuer},
     prover_jobs_archiver::ProverJobsArchiver,
     queue_reporter::{
-        ProofCompressorQueueReporter, ProverQueueReporter, WitnessGeneratorQueueReporter,
+        ChainQueueReporter, ProofCompressorQueueReporter, ProverQueueReporter,
+        WitnessGeneratorQueueReporter,
     },
     witness_job_queuer::WitnessJobQueuer,
 };
@@ -206,6 +207,13 @@ fn get_tasks(
         witness_generator_queue_reporter,
     );
 
+    let chain_queue_reporter = ChainQueueReporter::new(connection_pool.clone());
+    task_runner.add(
+        "ChainQueueReporter",
+        prover_job_monitor_config.prover_queue_reporter_run_interval(),
+        chain_queue_reporter,
+    );
+
     // witness job queuer
     let witness_job_queuer = WitnessJobQueuer::new(connection_pool.clone());
     task_runner.add(
@@ -8,6 +8,9 @@ pub(crate) struct ProverJobMonitorMetrics {
     pub gpu_prover_archived: Counter,
     #[metrics(labels = ["job_type"])]
     pub reached_max_attempts: LabeledFamily<JobType, Gauge>,
+    /// Number of jobs per chain, job type and status (`queued` or `in_progress`).
+    #[metrics(labels = ["chain_id", "job_type", "type"])]
+    pub chain_jobs: LabeledFamily<(String, String, &'static str), Gauge<u64>, 3>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
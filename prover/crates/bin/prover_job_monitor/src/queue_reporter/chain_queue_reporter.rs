@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+use zksync_prover_task::Task;
+
+use crate::metrics::PROVER_JOB_MONITOR_METRICS;
+
+/// `ChainQueueReporter` is a task that reports queued and in-progress jobs for each chain
+/// served by the prover cluster.
+#[derive(Debug)]
+pub struct ChainQueueReporter {
+    pool: ConnectionPool<Prover>,
+}
+
+impl ChainQueueReporter {
+    pub fn new(pool: ConnectionPool<Prover>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for ChainQueueReporter {
+    async fn invoke(&self) -> anyhow::Result<()> {
+        let mut connection = self
+            .pool
+            .connection()
+            .await
+            .context("failed to get database connection")?;
+        let stats = connection.fri_chains_dal().get_jobs_stats_by_chain().await;
+
+        let mut stale_entries: HashMap<_, _> =
+            PROVER_JOB_MONITOR_METRICS.chain_jobs.to_entries().collect();
+        for ((chain_id, job_type), stats) in stats {
+            for (status, count) in [("queued", stats.queued), ("in_progress", stats.in_progress)] {
+                let labels = (chain_id.to_string(), job_type.clone(), status);
+                stale_entries.remove(&labels);
+                PROVER_JOB_MONITOR_METRICS.chain_jobs[&labels].set(count as u64);
+            }
+        }
+
+        // Chains with no more jobs of a certain type are reported as having zero jobs.
+        for labels in stale_entries.keys() {
+            PROVER_JOB_MONITOR_METRICS.chain_jobs[labels].set(0);
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+pub use chain_queue_reporter::ChainQueueReporter;
 pub use proof_compressor_queue_reporter::ProofCompressorQueueReporter;
 pub use prover_queue_reporter::ProverQueueReporter;
 pub use witness_generator_queue_reporter::WitnessGeneratorQueueReporter;
 
+mod chain_queue_reporter;
 mod proof_compressor_queue_reporter;
 mod prover_queue_reporter;
 mod witness_generator_queue_reporter;
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS prover_chain_priorities
+(
+    chain_id   INTEGER PRIMARY KEY,
+    priority   INTEGER   NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
@@ -0,0 +1,59 @@
+DROP TRIGGER IF EXISTS prover_chain_priorities_insert_or_delete ON prover_chain_priorities;
+DROP TRIGGER IF EXISTS prover_chain_priorities_update ON prover_chain_priorities;
+DROP FUNCTION IF EXISTS update_effective_batch_sealed_at;
+
+DROP INDEX IF EXISTS idx_witness_inputs_fri_priority;
+DROP INDEX IF EXISTS idx_leaf_aggregation_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_node_aggregation_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_recursion_tip_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_scheduler_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_proof_compression_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_prover_jobs_fri_priority;
+
+DROP TRIGGER IF EXISTS witness_inputs_fri_effective_batch_sealed_at ON witness_inputs_fri;
+DROP TRIGGER IF EXISTS leaf_aggregation_witness_jobs_fri_effective_batch_sealed_at ON leaf_aggregation_witness_jobs_fri;
+DROP TRIGGER IF EXISTS node_aggregation_witness_jobs_fri_effective_batch_sealed_at ON node_aggregation_witness_jobs_fri;
+DROP TRIGGER IF EXISTS recursion_tip_witness_jobs_fri_effective_batch_sealed_at ON recursion_tip_witness_jobs_fri;
+DROP TRIGGER IF EXISTS scheduler_witness_jobs_fri_effective_batch_sealed_at ON scheduler_witness_jobs_fri;
+DROP TRIGGER IF EXISTS proof_compression_jobs_fri_effective_batch_sealed_at ON proof_compression_jobs_fri;
+DROP TRIGGER IF EXISTS prover_jobs_fri_effective_batch_sealed_at ON prover_jobs_fri;
+ALTER TABLE witness_inputs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE leaf_aggregation_witness_jobs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE node_aggregation_witness_jobs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE recursion_tip_witness_jobs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE scheduler_witness_jobs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE proof_compression_jobs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE prover_jobs_fri DROP COLUMN IF EXISTS effective_batch_sealed_at;
+ALTER TABLE prover_jobs_fri_archive DROP COLUMN IF EXISTS effective_batch_sealed_at;
+
+DROP FUNCTION IF EXISTS set_effective_batch_sealed_at;
+DROP FUNCTION IF EXISTS prover_effective_batch_sealed_at;
+DROP FUNCTION IF EXISTS prover_chain_priority_aging_interval;
+
+CREATE INDEX IF NOT EXISTS idx_witness_inputs_fri_priority
+    ON witness_inputs_fri USING btree (priority, batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_leaf_aggregation_witness_jobs_fri_priority
+    ON leaf_aggregation_witness_jobs_fri USING btree (priority, batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_node_aggregation_witness_jobs_fri_priority
+    ON node_aggregation_witness_jobs_fri USING btree (priority, batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_recursion_tip_witness_jobs_fri_priority
+    ON recursion_tip_witness_jobs_fri USING btree (priority, batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_scheduler_witness_jobs_fri_priority
+    ON scheduler_witness_jobs_fri USING btree (priority, batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_proof_compression_jobs_fri_priority
+    ON proof_compression_jobs_fri USING btree (priority, batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_prover_jobs_fri_priority
+    ON prover_jobs_fri USING btree (priority, batch_sealed_at, aggregation_round, circuit_id)
+    WHERE (status = 'queued'::text);
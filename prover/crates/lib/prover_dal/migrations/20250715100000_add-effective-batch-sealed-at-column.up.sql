@@ -0,0 +1,151 @@
+-- Batch age equivalent to a single point of chain priority. This is the only place where the aging factor is defined.
+CREATE OR REPLACE FUNCTION prover_chain_priority_aging_interval() RETURNS INTERVAL
+    LANGUAGE sql IMMUTABLE AS $$ SELECT INTERVAL '10 minutes' $$;
+
+-- Batch seal time shifted back by the priority of the batch chain. Job pickers order jobs by this value,
+-- so that jobs of higher-priority chains are preferred, but jobs of lower-priority chains are not starved.
+CREATE OR REPLACE FUNCTION prover_effective_batch_sealed_at(
+    job_chain_id INTEGER,
+    job_batch_sealed_at TIMESTAMP
+) RETURNS TIMESTAMP
+    LANGUAGE sql STABLE AS $$
+    SELECT job_batch_sealed_at - COALESCE(
+        (SELECT priority FROM prover_chain_priorities WHERE chain_id = job_chain_id),
+        0
+    ) * prover_chain_priority_aging_interval()
+$$;
+
+CREATE OR REPLACE FUNCTION set_effective_batch_sealed_at() RETURNS TRIGGER
+    LANGUAGE plpgsql AS $$
+BEGIN
+    NEW.effective_batch_sealed_at := prover_effective_batch_sealed_at(NEW.chain_id, NEW.batch_sealed_at);
+    RETURN NEW;
+END;
+$$;
+
+ALTER TABLE witness_inputs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE witness_inputs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE witness_inputs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER witness_inputs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON witness_inputs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+ALTER TABLE leaf_aggregation_witness_jobs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE leaf_aggregation_witness_jobs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE leaf_aggregation_witness_jobs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER leaf_aggregation_witness_jobs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON leaf_aggregation_witness_jobs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+ALTER TABLE node_aggregation_witness_jobs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE node_aggregation_witness_jobs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE node_aggregation_witness_jobs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER node_aggregation_witness_jobs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON node_aggregation_witness_jobs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+ALTER TABLE recursion_tip_witness_jobs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE recursion_tip_witness_jobs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE recursion_tip_witness_jobs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER recursion_tip_witness_jobs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON recursion_tip_witness_jobs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+ALTER TABLE scheduler_witness_jobs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE scheduler_witness_jobs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE scheduler_witness_jobs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER scheduler_witness_jobs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON scheduler_witness_jobs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+ALTER TABLE proof_compression_jobs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE proof_compression_jobs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE proof_compression_jobs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER proof_compression_jobs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON proof_compression_jobs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+ALTER TABLE prover_jobs_fri ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+UPDATE prover_jobs_fri SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at);
+ALTER TABLE prover_jobs_fri ALTER COLUMN effective_batch_sealed_at SET NOT NULL;
+CREATE TRIGGER prover_jobs_fri_effective_batch_sealed_at
+    BEFORE INSERT OR UPDATE OF chain_id, batch_sealed_at ON prover_jobs_fri
+    FOR EACH ROW EXECUTE FUNCTION set_effective_batch_sealed_at();
+
+-- Archived prover jobs are copied as is, so the archive must have the same columns as `prover_jobs_fri`.
+ALTER TABLE prover_jobs_fri_archive ADD COLUMN IF NOT EXISTS effective_batch_sealed_at TIMESTAMP;
+
+-- Recomputes the effective seal time of unfinished jobs when the chain priority changes.
+CREATE OR REPLACE FUNCTION update_effective_batch_sealed_at() RETURNS TRIGGER
+    LANGUAGE plpgsql AS $$
+DECLARE
+    changed_chain_id INTEGER := COALESCE(NEW.chain_id, OLD.chain_id);
+BEGIN
+    UPDATE witness_inputs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    UPDATE leaf_aggregation_witness_jobs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    UPDATE node_aggregation_witness_jobs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    UPDATE recursion_tip_witness_jobs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    UPDATE scheduler_witness_jobs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    UPDATE proof_compression_jobs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    UPDATE prover_jobs_fri
+    SET effective_batch_sealed_at = prover_effective_batch_sealed_at(chain_id, batch_sealed_at)
+    WHERE chain_id = changed_chain_id AND status NOT IN ('successful', 'sent_to_server');
+    RETURN NULL;
+END;
+$$;
+
+CREATE TRIGGER prover_chain_priorities_insert_or_delete
+    AFTER INSERT OR DELETE ON prover_chain_priorities
+    FOR EACH ROW EXECUTE FUNCTION update_effective_batch_sealed_at();
+CREATE TRIGGER prover_chain_priorities_update
+    AFTER UPDATE OF priority ON prover_chain_priorities
+    FOR EACH ROW WHEN (OLD.priority IS DISTINCT FROM NEW.priority)
+    EXECUTE FUNCTION update_effective_batch_sealed_at();
+
+DROP INDEX IF EXISTS idx_witness_inputs_fri_priority;
+DROP INDEX IF EXISTS idx_leaf_aggregation_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_node_aggregation_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_recursion_tip_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_scheduler_witness_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_proof_compression_jobs_fri_priority;
+DROP INDEX IF EXISTS idx_prover_jobs_fri_priority;
+
+CREATE INDEX IF NOT EXISTS idx_witness_inputs_fri_priority
+    ON witness_inputs_fri USING btree (priority DESC, effective_batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_leaf_aggregation_witness_jobs_fri_priority
+    ON leaf_aggregation_witness_jobs_fri USING btree (priority DESC, effective_batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_node_aggregation_witness_jobs_fri_priority
+    ON node_aggregation_witness_jobs_fri USING btree (priority DESC, effective_batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_recursion_tip_witness_jobs_fri_priority
+    ON recursion_tip_witness_jobs_fri USING btree (priority DESC, effective_batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_scheduler_witness_jobs_fri_priority
+    ON scheduler_witness_jobs_fri USING btree (priority DESC, effective_batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_proof_compression_jobs_fri_priority
+    ON proof_compression_jobs_fri USING btree (priority DESC, effective_batch_sealed_at)
+    WHERE (status = 'queued'::text);
+
+CREATE INDEX IF NOT EXISTS idx_prover_jobs_fri_priority
+    ON prover_jobs_fri USING btree (priority DESC, effective_batch_sealed_at, aggregation_round, circuit_id)
+    WHERE (status = 'queued'::text);
@@ -1,3 +1,4 @@
+use sqlx::types::chrono::{NaiveDateTime, NaiveTime};
 use zksync_basic_types::{
     prover_dal::{ProofCompressionJobStatus, ProverJobStatus, WitnessJobStatus},
     L1BatchNumber,
@@ -238,4 +239,68 @@ impl CliTestDal<'_, '_> {
         .await
         .unwrap();
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_prover_job_times(
+        &mut self,
+        circuit_id: u8,
+        aggregation_round: i64,
+        batch_number: L1BatchNumber,
+        sequence_number: usize,
+        created_at: NaiveDateTime,
+        processing_started_at: NaiveDateTime,
+        time_taken: NaiveTime,
+    ) {
+        sqlx::query!(
+            r#"
+            UPDATE prover_jobs_fri
+            SET
+                created_at = $1,
+                processing_started_at = $2,
+                time_taken = $3
+            WHERE
+                l1_batch_number = $4
+                AND sequence_number = $5
+                AND aggregation_round = $6
+                AND circuit_id = $7
+            "#,
+            created_at,
+            processing_started_at,
+            time_taken,
+            batch_number.0 as i64,
+            sequence_number as i64,
+            aggregation_round as i16,
+            circuit_id as i16,
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
+
+    pub async fn update_bwg_job_times(
+        &mut self,
+        batch_number: L1BatchNumber,
+        created_at: NaiveDateTime,
+        processing_started_at: NaiveDateTime,
+        time_taken: NaiveTime,
+    ) {
+        sqlx::query!(
+            r#"
+            UPDATE witness_inputs_fri
+            SET
+                created_at = $1,
+                processing_started_at = $2,
+                time_taken = $3
+            WHERE
+                l1_batch_number = $4
+            "#,
+            created_at,
+            processing_started_at,
+            time_taken,
+            batch_number.0 as i64,
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
 }
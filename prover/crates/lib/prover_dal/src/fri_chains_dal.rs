@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use zksync_basic_types::{prover_dal::JobCountStatistics, L2ChainId};
+use zksync_db_connection::{connection::Connection, error::DalError, instrument::InstrumentExt};
+
+use crate::Prover;
+
+/// Per-chain data for a prover cluster serving multiple chains.
+///
+/// Chain priorities are used by job pickers: each priority point is equivalent to 10 minutes of batch age, i.e.,
+/// a job for a batch of a chain with priority 1 is picked before jobs for batches sealed less than 10 minutes earlier
+/// on chains with priority 0. Chains without an explicit priority have priority 0. This way, jobs of higher-priority
+/// chains are preferred, but lower-priority chains are not starved: their jobs are eventually picked as they age.
+///
+/// The aging factor is defined by the `prover_chain_priority_aging_interval()` SQL function. Job tables store
+/// the resulting `effective_batch_sealed_at`, which is maintained by DB triggers both on job insertion
+/// and on chain priority changes, so that job pickers can use an index for ordering.
+#[derive(Debug)]
+pub struct FriChainsDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Prover>,
+}
+
+impl FriChainsDal<'_, '_> {
+    /// Replaces chain priorities with the provided ones. Chains missing from `priorities` are removed, so that
+    /// chains no longer served by the prover cluster don't retain their priority.
+    pub async fn set_chain_priorities(
+        &mut self,
+        priorities: &HashMap<L2ChainId, i32>,
+    ) -> Result<(), DalError> {
+        let (chain_ids, priorities): (Vec<_>, Vec<_>) = priorities
+            .iter()
+            .map(|(chain_id, priority)| (chain_id.inner() as i32, *priority))
+            .unzip();
+        let mut transaction = self.storage.start_transaction().await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM prover_chain_priorities
+            WHERE
+                NOT (chain_id = ANY($1))
+            "#,
+            &chain_ids,
+        )
+        .instrument("set_chain_priorities#delete")
+        .with_arg("chain_ids.len", &chain_ids.len())
+        .execute(&mut transaction)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            prover_chain_priorities (chain_id, priority, created_at, updated_at)
+            SELECT
+                u.chain_id,
+                u.priority,
+                NOW(),
+                NOW()
+            FROM
+                UNNEST($1::INT [], $2::INT []) AS u (chain_id, priority)
+            ON CONFLICT (chain_id) DO
+            UPDATE
+            SET
+            priority = excluded.priority,
+            updated_at = NOW()
+            "#,
+            &chain_ids,
+            &priorities,
+        )
+        .instrument("set_chain_priorities#upsert")
+        .with_arg("chain_ids.len", &chain_ids.len())
+        .execute(&mut transaction)
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_chain_priorities(&mut self) -> Result<HashMap<L2ChainId, i32>, DalError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                chain_id,
+                priority
+            FROM
+                prover_chain_priorities
+            "#
+        )
+        .instrument("get_chain_priorities")
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (L2ChainId::from(row.chain_id as u32), row.priority))
+            .collect())
+    }
+
+    /// Returns queued and in-progress job counts for each chain and job type.
+    pub async fn get_jobs_stats_by_chain(
+        &mut self,
+    ) -> HashMap<(L2ChainId, String), JobCountStatistics> {
+        sqlx::query!(
+            r#"
+            SELECT
+                chain_id AS "chain_id!",
+                job_type AS "job_type!",
+                COUNT(*) FILTER (
+                    WHERE
+                    status = 'queued'
+                ) AS "queued!",
+                COUNT(*) FILTER (
+                    WHERE
+                    status = 'in_progress'
+                ) AS "in_progress!"
+            FROM
+                (
+                    SELECT
+                        chain_id,
+                        'basic_witness_generator' AS job_type,
+                        status
+                    FROM
+                        witness_inputs_fri
+                    UNION ALL
+                    SELECT
+                        chain_id,
+                        'leaf_witness_generator' AS job_type,
+                        status
+                    FROM
+                        leaf_aggregation_witness_jobs_fri
+                    UNION ALL
+                    SELECT
+                        chain_id,
+                        'node_witness_generator' AS job_type,
+                        status
+                    FROM
+                        node_aggregation_witness_jobs_fri
+                    UNION ALL
+                    SELECT
+                        chain_id,
+                        'recursion_tip_witness_generator' AS job_type,
+                        status
+                    FROM
+                        recursion_tip_witness_jobs_fri
+                    UNION ALL
+                    SELECT
+                        chain_id,
+                        'scheduler_witness_generator' AS job_type,
+                        status
+                    FROM
+                        scheduler_witness_jobs_fri
+                    UNION ALL
+                    SELECT
+                        chain_id,
+                        'prover' AS job_type,
+                        status
+                    FROM
+                        prover_jobs_fri
+                    UNION ALL
+                    SELECT
+                        chain_id,
+                        'proof_compressor' AS job_type,
+                        status
+                    FROM
+                        proof_compression_jobs_fri
+                ) jobs
+            WHERE
+                status IN ('queued', 'in_progress')
+            GROUP BY
+                chain_id,
+                job_type
+            "#
+        )
+        .fetch_all(self.storage.conn())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| {
+            (
+                (L2ChainId::from(row.chain_id as u32), row.job_type),
+                JobCountStatistics {
+                    queued: row.queued as usize,
+                    in_progress: row.in_progress as usize,
+                },
+            )
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::types::chrono::{DateTime, Duration, Utc};
+    use zksync_basic_types::{
+        protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+        L1BatchId,
+    };
+    use zksync_db_connection::connection_pool::ConnectionPool;
+
+    use super::*;
+    use crate::ProverDal;
+
+    #[tokio::test]
+    async fn setting_chain_priorities() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let priorities = HashMap::from([(L2ChainId::from(1_u32), 1), (L2ChainId::from(2_u32), 5)]);
+        conn.fri_chains_dal()
+            .set_chain_priorities(&priorities)
+            .await
+            .unwrap();
+        assert_eq!(
+            conn.fri_chains_dal().get_chain_priorities().await.unwrap(),
+            priorities
+        );
+
+        // Chain 2 is removed from the config and chain 1 has its priority changed.
+        let priorities = HashMap::from([(L2ChainId::from(1_u32), 2), (L2ChainId::from(3_u32), 1)]);
+        conn.fri_chains_dal()
+            .set_chain_priorities(&priorities)
+            .await
+            .unwrap();
+        assert_eq!(
+            conn.fri_chains_dal().get_chain_priorities().await.unwrap(),
+            priorities
+        );
+
+        conn.fri_chains_dal()
+            .set_chain_priorities(&HashMap::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            conn.fri_chains_dal().get_chain_priorities().await.unwrap(),
+            HashMap::new()
+        );
+    }
+
+    #[tokio::test]
+    async fn picking_jobs_by_chain_priority() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let protocol_version = ProtocolSemanticVersion::default();
+        conn.fri_protocol_versions_dal()
+            .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+            .await
+            .unwrap();
+
+        let old_low_priority_batch = L1BatchId::from_raw(1, 1);
+        let low_priority_batch = L1BatchId::from_raw(1, 2);
+        let high_priority_batch = L1BatchId::from_raw(2, 1);
+        let sealed_at = DateTime::<Utc>::from_timestamp(10_000, 0).unwrap();
+        for (batch_id, sealed_at) in [
+            // Aged enough to be picked before the high-priority batch.
+            (old_low_priority_batch, sealed_at - Duration::minutes(20)),
+            (low_priority_batch, sealed_at - Duration::minutes(5)),
+            (high_priority_batch, sealed_at),
+        ] {
+            conn.fri_basic_witness_generator_dal()
+                .save_witness_inputs(batch_id, "", protocol_version, sealed_at)
+                .await
+                .unwrap();
+        }
+        conn.fri_chains_dal()
+            .set_chain_priorities(&HashMap::from([(high_priority_batch.chain_id(), 1)]))
+            .await
+            .unwrap();
+
+        let mut dal = conn.fri_basic_witness_generator_dal();
+        for expected_batch in [
+            old_low_priority_batch,
+            high_priority_batch,
+            low_priority_batch,
+        ] {
+            let picked = dal
+                .get_next_basic_circuit_witness_job(protocol_version, "test")
+                .await
+                .expect("no job picked");
+            assert_eq!(picked.chain_id(), expected_batch.chain_id());
+            assert_eq!(picked.batch_number(), expected_batch.batch_number());
+        }
+    }
+
+    #[tokio::test]
+    async fn chain_priority_changes_are_applied_to_queued_jobs() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let protocol_version = ProtocolSemanticVersion::default();
+        conn.fri_protocol_versions_dal()
+            .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+            .await
+            .unwrap();
+
+        let first_chain_batch = L1BatchId::from_raw(1, 1);
+        let second_chain_batch = L1BatchId::from_raw(2, 1);
+        // Priorities are set before jobs are created.
+        conn.fri_chains_dal()
+            .set_chain_priorities(&HashMap::from([(second_chain_batch.chain_id(), 1)]))
+            .await
+            .unwrap();
+        let sealed_at = DateTime::<Utc>::from_timestamp(10_000, 0).unwrap();
+        for (batch_id, sealed_at) in [
+            (first_chain_batch, sealed_at - Duration::minutes(5)),
+            (second_chain_batch, sealed_at),
+        ] {
+            conn.fri_proof_compressor_dal()
+                .insert_proof_compression_job(batch_id, "", protocol_version, sealed_at)
+                .await;
+        }
+        let picked = conn
+            .fri_proof_compressor_dal()
+            .get_next_proof_compression_job("test", protocol_version)
+            .await;
+        assert_eq!(picked, Some(second_chain_batch));
+
+        // Priorities are changed after jobs are created.
+        let next_second_chain_batch = L1BatchId::from_raw(2, 2);
+        conn.fri_proof_compressor_dal()
+            .insert_proof_compression_job(
+                next_second_chain_batch,
+                "",
+                protocol_version,
+                sealed_at - Duration::minutes(10),
+            )
+            .await;
+        conn.fri_chains_dal()
+            .set_chain_priorities(&HashMap::from([(first_chain_batch.chain_id(), 1)]))
+            .await
+            .unwrap();
+        for expected_batch in [first_chain_batch, next_second_chain_batch] {
+            let picked = conn
+                .fri_proof_compressor_dal()
+                .get_next_proof_compression_job("test", protocol_version)
+                .await;
+            assert_eq!(picked, Some(expected_batch));
+        }
+    }
+}
@@ -83,7 +83,7 @@ impl FriProofCompressorDal<'_, '_> {
                         AND protocol_version_patch = $5
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC
+                        effective_batch_sealed_at ASC
                     LIMIT
                         1
                     FOR UPDATE
@@ -184,9 +184,11 @@ impl FriProofCompressorDal<'_, '_> {
         .unwrap();
     }
 
-    // todo: this should be grouped by chain_id
+    /// Returns the earliest sealed batch with a proof that wasn't sent to the server yet.
+    /// If `chain_id` is specified, only batches of this chain are considered.
     pub async fn get_least_proven_block_not_sent_to_server(
         &mut self,
+        chain_id: Option<L2ChainId>,
     ) -> Option<(
         L1BatchId,
         ProtocolSemanticVersion,
@@ -204,13 +206,18 @@ impl FriProofCompressorDal<'_, '_> {
                 proof_compression_jobs_fri
             WHERE
                 status IN ($1, $2)
+                AND (
+                    $3::INT IS NULL
+                    OR chain_id = $3
+                )
             ORDER BY
                 batch_sealed_at ASC
             LIMIT
                 1
             "#,
             ProofCompressionJobStatus::Successful.to_string(),
-            ProofCompressionJobStatus::Skipped.to_string()
+            ProofCompressionJobStatus::Skipped.to_string(),
+            chain_id.map(|id| id.inner() as i32),
         )
         .fetch_optional(self.storage.conn())
         .await
@@ -184,7 +184,7 @@ impl FriProverDal<'_, '_> {
                         AND circuit_id = ANY($5)
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC,
+                        effective_batch_sealed_at ASC,
                         circuit_id ASC,
                         id ASC
                     LIMIT
@@ -266,7 +266,7 @@ impl FriProverDal<'_, '_> {
                         AND NOT (aggregation_round = $4 AND circuit_id = ANY($5))
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC,
+                        effective_batch_sealed_at ASC,
                         aggregation_round ASC,
                         circuit_id ASC,
                         id ASC
@@ -112,7 +112,7 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
                         AND protocol_version_patch = $3
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC
+                        effective_batch_sealed_at ASC
                     LIMIT
                         1
                     FOR UPDATE
@@ -347,6 +347,38 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
         .collect()
     }
 
+    /// Queues the basic witness generation job for the batch again, e.g. after the final proof for the batch
+    /// was rejected. Jobs of the subsequent stages must be removed separately.
+    pub async fn requeue_witness_inputs_job(
+        &mut self,
+        batch_id: L1BatchId,
+    ) -> Result<(), DalError> {
+        sqlx::query!(
+            r#"
+            UPDATE witness_inputs_fri
+            SET
+                status = 'queued',
+                attempts = 0,
+                error = NULL,
+                picked_by = NULL,
+                processing_started_at = NULL,
+                time_taken = NULL,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+                AND chain_id = $2
+            "#,
+            batch_id.batch_number().0 as i64,
+            batch_id.chain_id().inner() as i64,
+        )
+        .instrument("requeue_witness_inputs_job")
+        .with_arg("batch_id", &batch_id)
+        .execute(self.storage)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn check_reached_max_attempts(&mut self, max_attempts: u32) -> usize {
         sqlx::query_scalar!(
             r#"
@@ -82,7 +82,7 @@ impl FriNodeWitnessGeneratorDal<'_, '_> {
                         AND protocol_version_patch = $2
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC,
+                        effective_batch_sealed_at ASC,
                         depth ASC,
                         id ASC
                     LIMIT
@@ -142,7 +142,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
                         AND protocol_version_patch = $2
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC
+                        effective_batch_sealed_at ASC
                     LIMIT
                         1
                     FOR UPDATE
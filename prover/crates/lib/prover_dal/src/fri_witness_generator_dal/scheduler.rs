@@ -155,7 +155,7 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
                         AND protocol_version_patch = $3
                     ORDER BY
                         priority DESC,
-                        batch_sealed_at ASC
+                        effective_batch_sealed_at ASC
                     LIMIT
                         1
                     FOR UPDATE